@@ -0,0 +1,56 @@
+//! Selection of the vendored header of the AMD SMI library.
+//!
+//! By default, the crate uses the bindings committed in `src/versions/latest.rs`, and this script does nothing.
+//! With the feature `generate`, if `AMD_SMI_HEADER_VERSION` is set, for instance to `7.2.0`, the bindings are
+//! generated at build time from the vendored header of that ROCm version (`headers/amdsmi-rocm-<version>.h`),
+//! with the same configuration and whitelist as the bindings generator, and replace the committed ones.
+//! This requires libclang. Setting `AMD_SMI_HEADER_VERSION` without the feature is an error, so that the
+//! committed bindings are not used by mistake.
+
+#[cfg(feature = "generate")]
+mod generate;
+
+use std::env;
+#[cfg(feature = "generate")]
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "generate")]
+use generate::HEADER_VERSION_VAR;
+
+#[cfg(not(feature = "generate"))]
+const HEADER_VERSION_VAR: &str = "AMD_SMI_HEADER_VERSION";
+
+fn main() {
+    println!("cargo::rerun-if-env-changed={HEADER_VERSION_VAR}");
+    println!("cargo::rustc-check-cfg=cfg(amd_smi_header_override)");
+
+    let Ok(version) = env::var(HEADER_VERSION_VAR) else {
+        return;
+    };
+    #[cfg(not(feature = "generate"))]
+    panic!(
+        "{HEADER_VERSION_VAR}={version} requires the feature `generate` of amd-smi-wrapper-sys \
+         (or `generate-bindings` of amd-smi-wrapper), which generates the bindings with bindgen and libclang"
+    );
+    #[cfg(feature = "generate")]
+    generate_bindings(&version);
+}
+
+/// Generates the bindings from the vendored header of `version`, in place of the committed ones.
+#[cfg(feature = "generate")]
+fn generate_bindings(version: &str) {
+    let headers = generate::headers_dir(Path::new(env!("CARGO_MANIFEST_DIR")));
+    let header = generate::vendored_header(&headers, version)
+        .unwrap_or_else(|e| panic!("{HEADER_VERSION_VAR}={version}: {e}"));
+    let whitelist = generate::whitelist(&headers);
+    println!("cargo::rerun-if-changed={}", header.display());
+    println!("cargo::rerun-if-changed={}", whitelist.display());
+
+    let output = PathBuf::from(env::var("OUT_DIR").unwrap()).join("bindings.rs");
+    generate::builder(&header, &whitelist)
+        .generate()
+        .expect("failed to generate the bindings")
+        .write_to_file(output)
+        .expect("failed to write the bindings");
+    println!("cargo::rustc-cfg=amd_smi_header_override");
+}
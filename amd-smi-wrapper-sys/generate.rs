@@ -0,0 +1,91 @@
+//! Generation of the bindings, shared by the build script of this crate and by the bindings generator,
+//! so that both produce the same bindings from the same header.
+//!
+//! The vendored headers and the whitelist are stored in the `headers` directory of this crate.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use bindgen::{Builder, EnumVariation, callbacks::ParseCallbacks};
+
+/// Environment variable that selects the vendored header by ROCm version.
+pub const HEADER_VERSION_VAR: &str = "AMD_SMI_HEADER_VERSION";
+
+const LIB: &str = "libamd_smi";
+
+/// Returns the directory of the vendored headers, given the directory of this crate.
+pub fn headers_dir(crate_dir: &Path) -> PathBuf {
+    crate_dir.join("headers")
+}
+
+/// Returns the whitelist stored in the directory of the vendored headers.
+pub fn whitelist(headers_dir: &Path) -> PathBuf {
+    headers_dir.join("whitelist.txt")
+}
+
+/// Returns the vendored header of a ROCm version, or an error that lists the available versions.
+pub fn vendored_header(headers_dir: &Path, version: &str) -> Result<PathBuf, String> {
+    let header = headers_dir.join(format!("amdsmi-rocm-{version}.h"));
+    if header.is_file() {
+        Ok(header)
+    } else {
+        Err(format!(
+            "no vendored header for version {version}, available versions: {}",
+            available_versions(headers_dir).join(", ")
+        ))
+    }
+}
+
+/// Lists the versions of the vendored headers, sorted.
+pub fn available_versions(headers_dir: &Path) -> Vec<String> {
+    let mut versions: Vec<String> = fs::read_dir(headers_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| {
+                    let name = e.ok()?.file_name().into_string().ok()?;
+                    Some(
+                        name.strip_prefix("amdsmi-rocm-")?
+                            .strip_suffix(".h")?
+                            .to_owned(),
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    versions.sort();
+    versions
+}
+
+/// Returns the bindgen configuration for a header, restricted to the symbols of the whitelist.
+pub fn builder(header: &Path, whitelist: &Path) -> Builder {
+    let mut builder = Builder::default()
+        .header(header.to_str().unwrap())
+        // the functions of the EPYC CPUs are only declared with this definition
+        .clang_arg("-DENABLE_ESMI_LIB")
+        .parse_callbacks(Box::new(DocFix))
+        .dynamic_library_name(LIB)
+        .default_enum_style(EnumVariation::NewType {
+            is_bitfield: false,
+            is_global: false,
+        });
+    let content = fs::read_to_string(whitelist).expect("failed to read whitelist");
+    for line in content.lines() {
+        let line = line.trim_ascii();
+        if !line.is_empty() && !line.starts_with('#') {
+            builder = builder.allowlist_item(line);
+        }
+    }
+    builder
+}
+
+#[derive(Debug)]
+struct DocFix;
+
+impl ParseCallbacks for DocFix {
+    fn process_comment(&self, comment: &str) -> Option<String> {
+        // Transform C/C++ documentation to avoid Rust doc-test errors
+        Some(format!("```text\n{comment}\n```"))
+    }
+}
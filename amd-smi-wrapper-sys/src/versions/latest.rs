@@ -1,10 +1,186 @@
 /* automatically generated by rust-bindgen 0.72.1 */
 
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct __BindgenBitfieldUnit<Storage> {
+    storage: Storage,
+}
+
+impl<Storage> __BindgenBitfieldUnit<Storage> {
+    #[inline]
+    pub const fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
+
+impl<Storage> __BindgenBitfieldUnit<Storage>
+where
+    Storage: AsRef<[u8]> + AsMut<[u8]>,
+{
+    #[inline]
+    fn extract_bit(byte: u8, index: usize) -> bool {
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+
+        let mask = 1 << bit_index;
+
+        byte & mask == mask
+    }
+
+    #[inline]
+    pub fn get_bit(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < self.storage.as_ref().len());
+
+        let byte_index = index / 8;
+        let byte = self.storage.as_ref()[byte_index];
+
+        Self::extract_bit(byte, index)
+    }
+
+    #[inline]
+    pub unsafe fn raw_get_bit(this: *const Self, index: usize) -> bool {
+        debug_assert!(index / 8 < core::mem::size_of::<Storage>());
+
+        let byte_index = index / 8;
+        let byte = unsafe {
+            *(core::ptr::addr_of!((*this).storage) as *const u8).offset(byte_index as isize)
+        };
+
+        Self::extract_bit(byte, index)
+    }
+
+    #[inline]
+    fn change_bit(byte: u8, index: usize, val: bool) -> u8 {
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+
+        let mask = 1 << bit_index;
+        if val { byte | mask } else { byte & !mask }
+    }
+
+    #[inline]
+    pub fn set_bit(&mut self, index: usize, val: bool) {
+        debug_assert!(index / 8 < self.storage.as_ref().len());
+
+        let byte_index = index / 8;
+        let byte = &mut self.storage.as_mut()[byte_index];
+
+        *byte = Self::change_bit(*byte, index, val);
+    }
+
+    #[inline]
+    pub unsafe fn raw_set_bit(this: *mut Self, index: usize, val: bool) {
+        debug_assert!(index / 8 < core::mem::size_of::<Storage>());
+
+        let byte_index = index / 8;
+        let byte = unsafe {
+            (core::ptr::addr_of_mut!((*this).storage) as *mut u8).offset(byte_index as isize)
+        };
+
+        unsafe { *byte = Self::change_bit(*byte, index, val) };
+    }
+
+    #[inline]
+    pub fn get(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self.storage.as_ref().len());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= self.storage.as_ref().len());
+
+        let mut val = 0;
+
+        for i in 0..(bit_width as usize) {
+            if self.get_bit(i + bit_offset) {
+                let index = if cfg!(target_endian = "big") {
+                    bit_width as usize - 1 - i
+                } else {
+                    i
+                };
+                val |= 1 << index;
+            }
+        }
+
+        val
+    }
+
+    #[inline]
+    pub unsafe fn raw_get(this: *const Self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < core::mem::size_of::<Storage>());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= core::mem::size_of::<Storage>());
+
+        let mut val = 0;
+
+        for i in 0..(bit_width as usize) {
+            if unsafe { Self::raw_get_bit(this, i + bit_offset) } {
+                let index = if cfg!(target_endian = "big") {
+                    bit_width as usize - 1 - i
+                } else {
+                    i
+                };
+                val |= 1 << index;
+            }
+        }
+
+        val
+    }
+
+    #[inline]
+    pub fn set(&mut self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self.storage.as_ref().len());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= self.storage.as_ref().len());
+
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index = if cfg!(target_endian = "big") {
+                bit_width as usize - 1 - i
+            } else {
+                i
+            };
+            self.set_bit(index + bit_offset, val_bit_is_set);
+        }
+    }
+
+    #[inline]
+    pub unsafe fn raw_set(this: *mut Self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < core::mem::size_of::<Storage>());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= core::mem::size_of::<Storage>());
+
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index = if cfg!(target_endian = "big") {
+                bit_width as usize - 1 - i
+            } else {
+                i
+            };
+            unsafe { Self::raw_set_bit(this, index + bit_offset, val_bit_is_set) };
+        }
+    }
+}
+pub const AMDSMI_MAX_STRING_LENGTH: u32 = 256;
+pub const AMDSMI_MAX_DEVICES: u32 = 32;
 pub const AMDSMI_GPU_UUID_SIZE: u32 = 38;
+pub const AMDSMI_MAX_NUM_VCN: u32 = 4;
+pub const AMDSMI_MAX_NUM_XGMI_LINKS: u32 = 8;
+pub const AMDSMI_MAX_NUM_JPEG: u32 = 32;
+pub const AMDSMI_MAX_NUM_JPEG_ENG_V1: u32 = 40;
+pub const AMDSMI_MAX_NUM_XCC: u32 = 8;
+pub const AMDSMI_MAX_NUM_XCP: u32 = 8;
 pub const AMDSMI_LIB_VERSION_MAJOR: u32 = 26;
 pub const AMDSMI_LIB_VERSION_MINOR: u32 = 2;
 pub const AMDSMI_LIB_VERSION_RELEASE: u32 = 1;
+pub const AMDSMI_MAX_NUM_FREQUENCIES: u32 = 33;
 pub const AMDSMI_MAX_FAN_SPEED: u32 = 255;
+pub const AMDSMI_MAX_NUM_PM_POLICIES: u32 = 32;
 impl amdsmi_init_flags_t {
     #[doc = "```text\n!< Initialize all processors\n```"]
     pub const AMDSMI_INIT_ALL_PROCESSORS: amdsmi_init_flags_t = amdsmi_init_flags_t(4294967295);
@@ -26,6 +202,46 @@ pub struct amdsmi_init_flags_t(pub ::std::os::raw::c_uint);
 #[doc = "```text\n @brief opaque handler point to underlying implementation\n\n @cond @tag{gpu_bm_linux} @tag{host} @tag{cpu_bm} @tag{guest_windows} @endcond\n```"]
 pub type amdsmi_processor_handle = *mut ::std::os::raw::c_void;
 pub type amdsmi_socket_handle = *mut ::std::os::raw::c_void;
+#[doc = "```text\n @brief This structure holds HSMP Driver version information.\n\n @cond @tag{cpu_bm} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_hsmp_driver_version_t {
+    #[doc = "```text\n!< Major version number\n```"]
+    pub major: u32,
+    #[doc = "```text\n!< Minor version number\n```"]
+    pub minor: u32,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_hsmp_driver_version_t"]
+        [::std::mem::size_of::<amdsmi_hsmp_driver_version_t>() - 8usize];
+    ["Alignment of amdsmi_hsmp_driver_version_t"]
+        [::std::mem::align_of::<amdsmi_hsmp_driver_version_t>() - 4usize];
+    ["Offset of field: amdsmi_hsmp_driver_version_t::major"]
+        [::std::mem::offset_of!(amdsmi_hsmp_driver_version_t, major) - 0usize];
+    ["Offset of field: amdsmi_hsmp_driver_version_t::minor"]
+        [::std::mem::offset_of!(amdsmi_hsmp_driver_version_t, minor) - 4usize];
+};
+impl processor_type_t {
+    #[doc = "```text\n!< Unknown processor type\n```"]
+    pub const AMDSMI_PROCESSOR_TYPE_UNKNOWN: processor_type_t = processor_type_t(0);
+    #[doc = "```text\n!< AMD Graphics processor type\n```"]
+    pub const AMDSMI_PROCESSOR_TYPE_AMD_GPU: processor_type_t = processor_type_t(1);
+    #[doc = "```text\n!< AMD CPU processor type\n```"]
+    pub const AMDSMI_PROCESSOR_TYPE_AMD_CPU: processor_type_t = processor_type_t(2);
+    #[doc = "```text\n!< Non-AMD Graphics processor type\n```"]
+    pub const AMDSMI_PROCESSOR_TYPE_NON_AMD_GPU: processor_type_t = processor_type_t(3);
+    #[doc = "```text\n!< Non-AMD CPU processor type\n```"]
+    pub const AMDSMI_PROCESSOR_TYPE_NON_AMD_CPU: processor_type_t = processor_type_t(4);
+    #[doc = "```text\n!< AMD CPU-Core processor type\n```"]
+    pub const AMDSMI_PROCESSOR_TYPE_AMD_CPU_CORE: processor_type_t = processor_type_t(5);
+    #[doc = "```text\n!< AMD Accelerated processor type (GPU and CPU)\n```"]
+    pub const AMDSMI_PROCESSOR_TYPE_AMD_APU: processor_type_t = processor_type_t(6);
+}
+#[repr(transparent)]
+#[doc = "```text\n @brief Processor types detectable by AMD SMI\n\n AMDSMI_PROCESSOR_TYPE_AMD_CPU      - CPU Socket is a physical component that holds the CPU.\n AMDSMI_PROCESSOR_TYPE_AMD_CPU_CORE - CPU Cores are number of individual processing units within the CPU.\n AMDSMI_PROCESSOR_TYPE_AMD_APU      - Combination of AMDSMI_PROCESSOR_TYPE_AMD_CPU and integrated GPU on single die\n\n @cond @tag{gpu_bm_linux} @tag{host} @tag{cpu_bm} @tag{guest_windows} @endcond\n```"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct processor_type_t(pub ::std::os::raw::c_uint);
 impl amdsmi_status_t {
     #[doc = "```text\n!< Call succeeded\n```"]
     pub const AMDSMI_STATUS_SUCCESS: amdsmi_status_t = amdsmi_status_t(0);
@@ -154,6 +370,50 @@ impl amdsmi_clk_type_t {
 #[doc = "```text\n @brief Clock types\n\n @cond @tag{gpu_bm_linux} @tag{host} @tag{guest_windows} @endcond\n```"]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
 pub struct amdsmi_clk_type_t(pub ::std::os::raw::c_uint);
+impl amdsmi_compute_partition_type_t {
+    #[doc = "```text\n!< Invalid compute partition type\n```"]
+    pub const AMDSMI_COMPUTE_PARTITION_INVALID: amdsmi_compute_partition_type_t =
+        amdsmi_compute_partition_type_t(0);
+    #[doc = "```text\n< Single GPU mode (SPX)- All XCCs work\ntogether with shared memory\n```"]
+    pub const AMDSMI_COMPUTE_PARTITION_SPX: amdsmi_compute_partition_type_t =
+        amdsmi_compute_partition_type_t(1);
+    #[doc = "```text\n< Dual GPU mode (DPX)- Half XCCs work\ntogether with shared memory\n```"]
+    pub const AMDSMI_COMPUTE_PARTITION_DPX: amdsmi_compute_partition_type_t =
+        amdsmi_compute_partition_type_t(2);
+    #[doc = "```text\n< Triple GPU mode (TPX)- One-third XCCs\nwork together with shared memory\n```"]
+    pub const AMDSMI_COMPUTE_PARTITION_TPX: amdsmi_compute_partition_type_t =
+        amdsmi_compute_partition_type_t(3);
+    #[doc = "```text\n< Quad GPU mode (QPX)- Quarter XCCs\nwork together with shared memory\n```"]
+    pub const AMDSMI_COMPUTE_PARTITION_QPX: amdsmi_compute_partition_type_t =
+        amdsmi_compute_partition_type_t(4);
+    #[doc = "```text\n< Core mode (CPX)- Per-chip XCC with\nshared memory\n```"]
+    pub const AMDSMI_COMPUTE_PARTITION_CPX: amdsmi_compute_partition_type_t =
+        amdsmi_compute_partition_type_t(5);
+}
+#[repr(transparent)]
+#[doc = "```text\n @brief Compute Partition. This enum is used to identify\n various compute partitioning settings.\n\n @cond @tag{gpu_bm_linux} @tag{guest_windows} @endcond\n```"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct amdsmi_compute_partition_type_t(pub ::std::os::raw::c_uint);
+impl amdsmi_memory_partition_type_t {
+    pub const AMDSMI_MEMORY_PARTITION_UNKNOWN: amdsmi_memory_partition_type_t =
+        amdsmi_memory_partition_type_t(0);
+    #[doc = "```text\n< NPS1 - All CCD & XCD data is interleaved\nacross all 8 HBM stacks (all stacks/1)\n```"]
+    pub const AMDSMI_MEMORY_PARTITION_NPS1: amdsmi_memory_partition_type_t =
+        amdsmi_memory_partition_type_t(1);
+    #[doc = "```text\n< NPS2 - 2 sets of CCDs or 4 XCD interleaved\nacross the 4 HBM stacks per AID pair\n(8 stacks/2)\n```"]
+    pub const AMDSMI_MEMORY_PARTITION_NPS2: amdsmi_memory_partition_type_t =
+        amdsmi_memory_partition_type_t(2);
+    #[doc = "```text\n< NPS4 - Each XCD data is interleaved\nacross 2 (or single) HBM stacks\n(8 stacks/8 or 8 stacks/4)\n```"]
+    pub const AMDSMI_MEMORY_PARTITION_NPS4: amdsmi_memory_partition_type_t =
+        amdsmi_memory_partition_type_t(4);
+    #[doc = "```text\n< NPS8 - Each XCD uses a single HBM stack\n(8 stacks/8). Or each XCD uses a single\nHBM stack & CCDs share 2 non-interleaved\nHBM stacks on its AID\n(AID[1,2,3] = 6 stacks/6)\n```"]
+    pub const AMDSMI_MEMORY_PARTITION_NPS8: amdsmi_memory_partition_type_t =
+        amdsmi_memory_partition_type_t(8);
+}
+#[repr(transparent)]
+#[doc = "```text\n @brief Memory Partitions\n\n @cond @tag{gpu_bm_linux} @tag{host} @endcond\n```"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct amdsmi_memory_partition_type_t(pub ::std::os::raw::c_uint);
 impl amdsmi_temperature_type_t {
     #[doc = "```text\n!< Edge temperature\n```"]
     pub const AMDSMI_TEMPERATURE_TYPE_EDGE: amdsmi_temperature_type_t =
@@ -327,6 +587,841 @@ impl amdsmi_temperature_type_t {
 #[doc = "```text\n @brief This enumeration is used to indicate from which part of the processor a\n temperature reading should be obtained.\n\n @cond @tag{gpu_bm_linux} @tag{host} @tag{guest_windows} @endcond\n```"]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
 pub struct amdsmi_temperature_type_t(pub ::std::os::raw::c_uint);
+impl amdsmi_fw_block_t {
+    #[doc = "```text\n< System Management Unit (power management,\nclock control, thermal monitoring, etc...)\n```"]
+    pub const AMDSMI_FW_ID_SMU: amdsmi_fw_block_t = amdsmi_fw_block_t(1);
+    pub const AMDSMI_FW_ID_FIRST: amdsmi_fw_block_t = amdsmi_fw_block_t(1);
+    #[doc = "```text\n!< Compute Processor - Command_Engine (fetch, decode, dispatch)\n```"]
+    pub const AMDSMI_FW_ID_CP_CE: amdsmi_fw_block_t = amdsmi_fw_block_t(2);
+    #[doc = "```text\n!< Compute Processor - Pixel Front End Processor (pixelating process)\n```"]
+    pub const AMDSMI_FW_ID_CP_PFP: amdsmi_fw_block_t = amdsmi_fw_block_t(3);
+    #[doc = "```text\n!< Compute Processor - Micro Engine (specialize processing)\n```"]
+    pub const AMDSMI_FW_ID_CP_ME: amdsmi_fw_block_t = amdsmi_fw_block_t(4);
+    #[doc = "```text\n!< Compute Processor - Micro Engine Controler Job Table 1 (queues, scheduling)\n```"]
+    pub const AMDSMI_FW_ID_CP_MEC_JT1: amdsmi_fw_block_t = amdsmi_fw_block_t(5);
+    #[doc = "```text\n!< Compute Processor - Micro Engine Controler Job Table 2 (queues, scheduling)\n```"]
+    pub const AMDSMI_FW_ID_CP_MEC_JT2: amdsmi_fw_block_t = amdsmi_fw_block_t(6);
+    #[doc = "```text\n!< Compute Processor - Micro Engine Controler 1 (scheduling, managing resources)\n```"]
+    pub const AMDSMI_FW_ID_CP_MEC1: amdsmi_fw_block_t = amdsmi_fw_block_t(7);
+    #[doc = "```text\n!< Compute Processor - Micro Engine Controler 2 (scheduling, managing resources)\n```"]
+    pub const AMDSMI_FW_ID_CP_MEC2: amdsmi_fw_block_t = amdsmi_fw_block_t(8);
+    #[doc = "```text\n!< Rasterizer and L2 Cache (rasterization processs)\n```"]
+    pub const AMDSMI_FW_ID_RLC: amdsmi_fw_block_t = amdsmi_fw_block_t(9);
+    #[doc = "```text\n!< System Direct Memory Access 0 (high speed data transfers)\n```"]
+    pub const AMDSMI_FW_ID_SDMA0: amdsmi_fw_block_t = amdsmi_fw_block_t(10);
+    #[doc = "```text\n!< System Direct Memory Access 1 (high speed data transfers)\n```"]
+    pub const AMDSMI_FW_ID_SDMA1: amdsmi_fw_block_t = amdsmi_fw_block_t(11);
+    #[doc = "```text\n!< System Direct Memory Access 2 (high speed data transfers)\n```"]
+    pub const AMDSMI_FW_ID_SDMA2: amdsmi_fw_block_t = amdsmi_fw_block_t(12);
+    #[doc = "```text\n!< System Direct Memory Access 3 (high speed data transfers)\n```"]
+    pub const AMDSMI_FW_ID_SDMA3: amdsmi_fw_block_t = amdsmi_fw_block_t(13);
+    #[doc = "```text\n!< System Direct Memory Access 4 (high speed data transfers)\n```"]
+    pub const AMDSMI_FW_ID_SDMA4: amdsmi_fw_block_t = amdsmi_fw_block_t(14);
+    #[doc = "```text\n!< System Direct Memory Access 5 (high speed data transfers)\n```"]
+    pub const AMDSMI_FW_ID_SDMA5: amdsmi_fw_block_t = amdsmi_fw_block_t(15);
+    #[doc = "```text\n!< System Direct Memory Access 6 (high speed data transfers)\n```"]
+    pub const AMDSMI_FW_ID_SDMA6: amdsmi_fw_block_t = amdsmi_fw_block_t(16);
+    #[doc = "```text\n!< System Direct Memory Access 7 (high speed data transfers)\n```"]
+    pub const AMDSMI_FW_ID_SDMA7: amdsmi_fw_block_t = amdsmi_fw_block_t(17);
+    #[doc = "```text\n!< Video Core Next (encoding and decoding)\n```"]
+    pub const AMDSMI_FW_ID_VCN: amdsmi_fw_block_t = amdsmi_fw_block_t(18);
+    #[doc = "```text\n!< Unified Video Decoder (decode specific video formats)\n```"]
+    pub const AMDSMI_FW_ID_UVD: amdsmi_fw_block_t = amdsmi_fw_block_t(19);
+    #[doc = "```text\n!< Video Coding Engine (Encoding video)\n```"]
+    pub const AMDSMI_FW_ID_VCE: amdsmi_fw_block_t = amdsmi_fw_block_t(20);
+    #[doc = "```text\n!< Image Signal Processor (processing raw image data from sensors)\n```"]
+    pub const AMDSMI_FW_ID_ISP: amdsmi_fw_block_t = amdsmi_fw_block_t(21);
+    #[doc = "```text\n!< Digital Micro Controller Unit - Embedded RAM (memory used by DMU)\n```"]
+    pub const AMDSMI_FW_ID_DMCU_ERAM: amdsmi_fw_block_t = amdsmi_fw_block_t(22);
+    #[doc = "```text\n!< Digital Micro Controller Unit - Interrupt Service Routine (interrupt handlers)\n```"]
+    pub const AMDSMI_FW_ID_DMCU_ISR: amdsmi_fw_block_t = amdsmi_fw_block_t(23);
+    #[doc = "```text\n!< Rasterizier and L2 Cache Restore List Graphics Processor Memory\n```"]
+    pub const AMDSMI_FW_ID_RLC_RESTORE_LIST_GPM_MEM: amdsmi_fw_block_t = amdsmi_fw_block_t(24);
+    #[doc = "```text\n!< Rasterizier and L2 Cache Restore List System RAM Memory\n```"]
+    pub const AMDSMI_FW_ID_RLC_RESTORE_LIST_SRM_MEM: amdsmi_fw_block_t = amdsmi_fw_block_t(25);
+    #[doc = "```text\n!< Rasterizier and L2 Cache Restore List Control\n```"]
+    pub const AMDSMI_FW_ID_RLC_RESTORE_LIST_CNTL: amdsmi_fw_block_t = amdsmi_fw_block_t(26);
+    #[doc = "```text\n!< Rasterizier and L2 Cache Virtual memory\n```"]
+    pub const AMDSMI_FW_ID_RLC_V: amdsmi_fw_block_t = amdsmi_fw_block_t(27);
+    #[doc = "```text\n!< Multi-Media Shader Hardware Scheduler\n```"]
+    pub const AMDSMI_FW_ID_MMSCH: amdsmi_fw_block_t = amdsmi_fw_block_t(28);
+    #[doc = "```text\n!< Platform Security Processor System Driver\n```"]
+    pub const AMDSMI_FW_ID_PSP_SYSDRV: amdsmi_fw_block_t = amdsmi_fw_block_t(29);
+    #[doc = "```text\n!< Platform Security Processor Secure Operating System Driver\n```"]
+    pub const AMDSMI_FW_ID_PSP_SOSDRV: amdsmi_fw_block_t = amdsmi_fw_block_t(30);
+    #[doc = "```text\n!< Platform Security Processor Table of Contents\n```"]
+    pub const AMDSMI_FW_ID_PSP_TOC: amdsmi_fw_block_t = amdsmi_fw_block_t(31);
+    #[doc = "```text\n!< Platform Security Processor Table of Contents\n```"]
+    pub const AMDSMI_FW_ID_PSP_KEYDB: amdsmi_fw_block_t = amdsmi_fw_block_t(32);
+    #[doc = "```text\n!< Data Fabric Controler (bandwidth and coherency)\n```"]
+    pub const AMDSMI_FW_ID_DFC: amdsmi_fw_block_t = amdsmi_fw_block_t(33);
+    #[doc = "```text\n!< Platform Security Processor Secure Program Loader\n```"]
+    pub const AMDSMI_FW_ID_PSP_SPL: amdsmi_fw_block_t = amdsmi_fw_block_t(34);
+    #[doc = "```text\n!< Driver Capabilities (capabilities, features)\n```"]
+    pub const AMDSMI_FW_ID_DRV_CAP: amdsmi_fw_block_t = amdsmi_fw_block_t(35);
+    #[doc = "```text\n!< Memory Contoller (RAM and VRAM)\n```"]
+    pub const AMDSMI_FW_ID_MC: amdsmi_fw_block_t = amdsmi_fw_block_t(36);
+    #[doc = "```text\n!< Platform Security Processor Bootloader (initial firmware)\n```"]
+    pub const AMDSMI_FW_ID_PSP_BL: amdsmi_fw_block_t = amdsmi_fw_block_t(37);
+    #[doc = "```text\n!< Compute Processor Packet Processor 4 (processing command packets)\n```"]
+    pub const AMDSMI_FW_ID_CP_PM4: amdsmi_fw_block_t = amdsmi_fw_block_t(38);
+    #[doc = "```text\n!< Rasterizier and L2 Cache Partition\n```"]
+    pub const AMDSMI_FW_ID_RLC_P: amdsmi_fw_block_t = amdsmi_fw_block_t(39);
+    #[doc = "```text\n!< Security Policy Stage 2 (security features)\n```"]
+    pub const AMDSMI_FW_ID_SEC_POLICY_STAGE2: amdsmi_fw_block_t = amdsmi_fw_block_t(40);
+    #[doc = "```text\n!< Register Access Whitelist (Prevent unathorizied access)\n```"]
+    pub const AMDSMI_FW_ID_REG_ACCESS_WHITELIST: amdsmi_fw_block_t = amdsmi_fw_block_t(41);
+    #[doc = "```text\n!< Input/Output Memory Management Unit - Dynamic RAM\n```"]
+    pub const AMDSMI_FW_ID_IMU_DRAM: amdsmi_fw_block_t = amdsmi_fw_block_t(42);
+    #[doc = "```text\n!< Input/Output Memory Management Unit - Instruction RAM\n```"]
+    pub const AMDSMI_FW_ID_IMU_IRAM: amdsmi_fw_block_t = amdsmi_fw_block_t(43);
+    #[doc = "```text\n!< System Direct Memory Access - Thread Handler 0\n```"]
+    pub const AMDSMI_FW_ID_SDMA_TH0: amdsmi_fw_block_t = amdsmi_fw_block_t(44);
+    #[doc = "```text\n!< System Direct Memory Access - Thread Handler 1\n```"]
+    pub const AMDSMI_FW_ID_SDMA_TH1: amdsmi_fw_block_t = amdsmi_fw_block_t(45);
+    #[doc = "```text\n!< Compute Processor - Micro Engine Scheduler\n```"]
+    pub const AMDSMI_FW_ID_CP_MES: amdsmi_fw_block_t = amdsmi_fw_block_t(46);
+    #[doc = "```text\n!< Micro Engine Scheduler - Kernel Indirect Queue\n```"]
+    pub const AMDSMI_FW_ID_MES_KIQ: amdsmi_fw_block_t = amdsmi_fw_block_t(47);
+    #[doc = "```text\n!< Micro Engine Scheduler - Stack\n```"]
+    pub const AMDSMI_FW_ID_MES_STACK: amdsmi_fw_block_t = amdsmi_fw_block_t(48);
+    #[doc = "```text\n!< Micro Engine Scheduler - Thread 1\n```"]
+    pub const AMDSMI_FW_ID_MES_THREAD1: amdsmi_fw_block_t = amdsmi_fw_block_t(49);
+    #[doc = "```text\n!< Micro Engine Scheduler - Thread 1 Stack\n```"]
+    pub const AMDSMI_FW_ID_MES_THREAD1_STACK: amdsmi_fw_block_t = amdsmi_fw_block_t(50);
+    #[doc = "```text\n!< Hardware Block RLX6\n```"]
+    pub const AMDSMI_FW_ID_RLX6: amdsmi_fw_block_t = amdsmi_fw_block_t(51);
+    #[doc = "```text\n!< Hardware Block RLX6 - Dynamic Ram Boot\n```"]
+    pub const AMDSMI_FW_ID_RLX6_DRAM_BOOT: amdsmi_fw_block_t = amdsmi_fw_block_t(52);
+    #[doc = "```text\n!< Hardware Block RS64 - Micro Engine\n```"]
+    pub const AMDSMI_FW_ID_RS64_ME: amdsmi_fw_block_t = amdsmi_fw_block_t(53);
+    #[doc = "```text\n!< Hardware Block RS64 - Micro Engine Partition 0 Data\n```"]
+    pub const AMDSMI_FW_ID_RS64_ME_P0_DATA: amdsmi_fw_block_t = amdsmi_fw_block_t(54);
+    #[doc = "```text\n!< Hardware Block RS64 - Micro Engine Partition 1 Data\n```"]
+    pub const AMDSMI_FW_ID_RS64_ME_P1_DATA: amdsmi_fw_block_t = amdsmi_fw_block_t(55);
+    #[doc = "```text\n!< Hardware Block RS64 - Pixel Front End Processor\n```"]
+    pub const AMDSMI_FW_ID_RS64_PFP: amdsmi_fw_block_t = amdsmi_fw_block_t(56);
+    #[doc = "```text\n!< Hardware Block RS64 - Pixel Front End Processor Partition 0 Data\n```"]
+    pub const AMDSMI_FW_ID_RS64_PFP_P0_DATA: amdsmi_fw_block_t = amdsmi_fw_block_t(57);
+    #[doc = "```text\n!< Hardware Block RS64 - Pixel Front End Processor Partition 1 Data\n```"]
+    pub const AMDSMI_FW_ID_RS64_PFP_P1_DATA: amdsmi_fw_block_t = amdsmi_fw_block_t(58);
+    #[doc = "```text\n!< Hardware Block RS64 - Micro Engine Controller\n```"]
+    pub const AMDSMI_FW_ID_RS64_MEC: amdsmi_fw_block_t = amdsmi_fw_block_t(59);
+    #[doc = "```text\n!< Hardware Block RS64 - Micro Engine Controller Partition 0 Data\n```"]
+    pub const AMDSMI_FW_ID_RS64_MEC_P0_DATA: amdsmi_fw_block_t = amdsmi_fw_block_t(60);
+    #[doc = "```text\n!< Hardware Block RS64 - Micro Engine Controller Partition 1 Data\n```"]
+    pub const AMDSMI_FW_ID_RS64_MEC_P1_DATA: amdsmi_fw_block_t = amdsmi_fw_block_t(61);
+    #[doc = "```text\n!< Hardware Block RS64 - Micro Engine Controller Partition 2 Data\n```"]
+    pub const AMDSMI_FW_ID_RS64_MEC_P2_DATA: amdsmi_fw_block_t = amdsmi_fw_block_t(62);
+    #[doc = "```text\n!< Hardware Block RS64 - Micro Engine Controller Partition 3 Data\n```"]
+    pub const AMDSMI_FW_ID_RS64_MEC_P3_DATA: amdsmi_fw_block_t = amdsmi_fw_block_t(63);
+    #[doc = "```text\n!< Power Policy Table (power management policies)\n```"]
+    pub const AMDSMI_FW_ID_PPTABLE: amdsmi_fw_block_t = amdsmi_fw_block_t(64);
+    #[doc = "```text\n!< Platform Security Processor - System On a Chip\n```"]
+    pub const AMDSMI_FW_ID_PSP_SOC: amdsmi_fw_block_t = amdsmi_fw_block_t(65);
+    #[doc = "```text\n!< Platform Security Processor - Debug\n```"]
+    pub const AMDSMI_FW_ID_PSP_DBG: amdsmi_fw_block_t = amdsmi_fw_block_t(66);
+    #[doc = "```text\n!< Platform Security Processor - Interface\n```"]
+    pub const AMDSMI_FW_ID_PSP_INTF: amdsmi_fw_block_t = amdsmi_fw_block_t(67);
+    #[doc = "```text\n!< Hardware Block RLX6 - Core 1\n```"]
+    pub const AMDSMI_FW_ID_RLX6_CORE1: amdsmi_fw_block_t = amdsmi_fw_block_t(68);
+    #[doc = "```text\n!< Hardware Block RLX6 Core 1 - Dynamic RAM Boot\n```"]
+    pub const AMDSMI_FW_ID_RLX6_DRAM_BOOT_CORE1: amdsmi_fw_block_t = amdsmi_fw_block_t(69);
+    #[doc = "```text\n!< Hardware Block RLCV - Subsystem LX7\n```"]
+    pub const AMDSMI_FW_ID_RLCV_LX7: amdsmi_fw_block_t = amdsmi_fw_block_t(70);
+    #[doc = "```text\n!< Rasterizier and L2 Cache - Save Restore List\n```"]
+    pub const AMDSMI_FW_ID_RLC_SAVE_RESTORE_LIST: amdsmi_fw_block_t = amdsmi_fw_block_t(71);
+    #[doc = "```text\n!< Asynchronous Shader Dispatcher\n```"]
+    pub const AMDSMI_FW_ID_ASD: amdsmi_fw_block_t = amdsmi_fw_block_t(72);
+    #[doc = "```text\n!< Trusted Applications - Reliablity Availability and Serviceability\n```"]
+    pub const AMDSMI_FW_ID_TA_RAS: amdsmi_fw_block_t = amdsmi_fw_block_t(73);
+    #[doc = "```text\n!< Trusted Applications - Reliablity XGMI\n```"]
+    pub const AMDSMI_FW_ID_TA_XGMI: amdsmi_fw_block_t = amdsmi_fw_block_t(74);
+    #[doc = "```text\n!< Rasterizier and L2 Cache - Shared Resource Local Group\n```"]
+    pub const AMDSMI_FW_ID_RLC_SRLG: amdsmi_fw_block_t = amdsmi_fw_block_t(75);
+    #[doc = "```text\n!< Rasterizier and L2 Cache - Shared Resource Local Segment\n```"]
+    pub const AMDSMI_FW_ID_RLC_SRLS: amdsmi_fw_block_t = amdsmi_fw_block_t(76);
+    #[doc = "```text\n!< Power Management Firmware\n```"]
+    pub const AMDSMI_FW_ID_PM: amdsmi_fw_block_t = amdsmi_fw_block_t(77);
+    #[doc = "```text\n!< Display Micro-Controller Unit\n```"]
+    pub const AMDSMI_FW_ID_DMCU: amdsmi_fw_block_t = amdsmi_fw_block_t(78);
+    #[doc = "```text\n!< Platform Level Data Model Firmware Bundle\n```"]
+    pub const AMDSMI_FW_ID_PLDM_BUNDLE: amdsmi_fw_block_t = amdsmi_fw_block_t(79);
+    pub const AMDSMI_FW_ID__MAX: amdsmi_fw_block_t = amdsmi_fw_block_t(80);
+}
+#[repr(transparent)]
+#[doc = "```text\n @brief The values of this enum are used to identify the various firmware\n blocks.\n\n @cond @tag{gpu_bm_linux} @tag{guest_windows} @tag{host} @endcond\n```"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct amdsmi_fw_block_t(pub ::std::os::raw::c_uint);
+#[doc = "```text\n @brief This structure represents a range (e.g., frequencies or voltages).\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_range_t {
+    #[doc = "```text\n!< Lower bound of range\n```"]
+    pub lower_bound: u64,
+    #[doc = "```text\n!< Upper bound of range\n```"]
+    pub upper_bound: u64,
+    pub reserved: [u64; 2usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_range_t"][::std::mem::size_of::<amdsmi_range_t>() - 32usize];
+    ["Alignment of amdsmi_range_t"][::std::mem::align_of::<amdsmi_range_t>() - 8usize];
+    ["Offset of field: amdsmi_range_t::lower_bound"]
+        [::std::mem::offset_of!(amdsmi_range_t, lower_bound) - 0usize];
+    ["Offset of field: amdsmi_range_t::upper_bound"]
+        [::std::mem::offset_of!(amdsmi_range_t, upper_bound) - 8usize];
+    ["Offset of field: amdsmi_range_t::reserved"]
+        [::std::mem::offset_of!(amdsmi_range_t, reserved) - 16usize];
+};
+#[doc = "```text\n @brief XGMI Information\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_xgmi_info_t {
+    pub xgmi_lanes: u8,
+    pub xgmi_hive_id: u64,
+    pub xgmi_node_id: u64,
+    pub index: u32,
+    pub reserved: [u32; 9usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_xgmi_info_t"][::std::mem::size_of::<amdsmi_xgmi_info_t>() - 64usize];
+    ["Alignment of amdsmi_xgmi_info_t"][::std::mem::align_of::<amdsmi_xgmi_info_t>() - 8usize];
+    ["Offset of field: amdsmi_xgmi_info_t::xgmi_lanes"]
+        [::std::mem::offset_of!(amdsmi_xgmi_info_t, xgmi_lanes) - 0usize];
+    ["Offset of field: amdsmi_xgmi_info_t::xgmi_hive_id"]
+        [::std::mem::offset_of!(amdsmi_xgmi_info_t, xgmi_hive_id) - 8usize];
+    ["Offset of field: amdsmi_xgmi_info_t::xgmi_node_id"]
+        [::std::mem::offset_of!(amdsmi_xgmi_info_t, xgmi_node_id) - 16usize];
+    ["Offset of field: amdsmi_xgmi_info_t::index"]
+        [::std::mem::offset_of!(amdsmi_xgmi_info_t, index) - 24usize];
+    ["Offset of field: amdsmi_xgmi_info_t::reserved"]
+        [::std::mem::offset_of!(amdsmi_xgmi_info_t, reserved) - 28usize];
+};
+#[doc = "```text\n @brief bdf types\n\n @cond @tag{gpu_bm_linux} @tag{host} @endcond\n```"]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union amdsmi_bdf_t {
+    pub bdf: amdsmi_bdf_t_bdf_,
+    pub __bindgen_anon_1: amdsmi_bdf_t__bindgen_ty_1,
+    pub as_uint: u64,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_bdf_t_bdf_ {
+    pub _bitfield_align_1: [u64; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 8usize]>,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_bdf_t_bdf_"][::std::mem::size_of::<amdsmi_bdf_t_bdf_>() - 8usize];
+    ["Alignment of amdsmi_bdf_t_bdf_"][::std::mem::align_of::<amdsmi_bdf_t_bdf_>() - 8usize];
+};
+impl amdsmi_bdf_t_bdf_ {
+    #[inline]
+    pub fn function_number(&self) -> u64 {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 3u8) as u64) }
+    }
+    #[inline]
+    pub fn set_function_number(&mut self, val: u64) {
+        unsafe {
+            let val: u64 = ::std::mem::transmute(val);
+            self._bitfield_1.set(0usize, 3u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn function_number_raw(this: *const Self) -> u64 {
+        unsafe {
+            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::std::ptr::addr_of!((*this)._bitfield_1),
+                0usize,
+                3u8,
+            ) as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_function_number_raw(this: *mut Self, val: u64) {
+        unsafe {
+            let val: u64 = ::std::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
+                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                0usize,
+                3u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn device_number(&self) -> u64 {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(3usize, 5u8) as u64) }
+    }
+    #[inline]
+    pub fn set_device_number(&mut self, val: u64) {
+        unsafe {
+            let val: u64 = ::std::mem::transmute(val);
+            self._bitfield_1.set(3usize, 5u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn device_number_raw(this: *const Self) -> u64 {
+        unsafe {
+            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::std::ptr::addr_of!((*this)._bitfield_1),
+                3usize,
+                5u8,
+            ) as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_device_number_raw(this: *mut Self, val: u64) {
+        unsafe {
+            let val: u64 = ::std::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
+                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                3usize,
+                5u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn bus_number(&self) -> u64 {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(8usize, 8u8) as u64) }
+    }
+    #[inline]
+    pub fn set_bus_number(&mut self, val: u64) {
+        unsafe {
+            let val: u64 = ::std::mem::transmute(val);
+            self._bitfield_1.set(8usize, 8u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn bus_number_raw(this: *const Self) -> u64 {
+        unsafe {
+            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::std::ptr::addr_of!((*this)._bitfield_1),
+                8usize,
+                8u8,
+            ) as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_bus_number_raw(this: *mut Self, val: u64) {
+        unsafe {
+            let val: u64 = ::std::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
+                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                8usize,
+                8u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn domain_number(&self) -> u64 {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(16usize, 48u8) as u64) }
+    }
+    #[inline]
+    pub fn set_domain_number(&mut self, val: u64) {
+        unsafe {
+            let val: u64 = ::std::mem::transmute(val);
+            self._bitfield_1.set(16usize, 48u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn domain_number_raw(this: *const Self) -> u64 {
+        unsafe {
+            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::std::ptr::addr_of!((*this)._bitfield_1),
+                16usize,
+                48u8,
+            ) as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_domain_number_raw(this: *mut Self, val: u64) {
+        unsafe {
+            let val: u64 = ::std::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
+                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                16usize,
+                48u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        function_number: u64,
+        device_number: u64,
+        bus_number: u64,
+        domain_number: u64,
+    ) -> __BindgenBitfieldUnit<[u8; 8usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 8usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 3u8, {
+            let function_number: u64 = unsafe { ::std::mem::transmute(function_number) };
+            function_number as u64
+        });
+        __bindgen_bitfield_unit.set(3usize, 5u8, {
+            let device_number: u64 = unsafe { ::std::mem::transmute(device_number) };
+            device_number as u64
+        });
+        __bindgen_bitfield_unit.set(8usize, 8u8, {
+            let bus_number: u64 = unsafe { ::std::mem::transmute(bus_number) };
+            bus_number as u64
+        });
+        __bindgen_bitfield_unit.set(16usize, 48u8, {
+            let domain_number: u64 = unsafe { ::std::mem::transmute(domain_number) };
+            domain_number as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_bdf_t__bindgen_ty_1 {
+    pub _bitfield_align_1: [u64; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 8usize]>,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_bdf_t__bindgen_ty_1"]
+        [::std::mem::size_of::<amdsmi_bdf_t__bindgen_ty_1>() - 8usize];
+    ["Alignment of amdsmi_bdf_t__bindgen_ty_1"]
+        [::std::mem::align_of::<amdsmi_bdf_t__bindgen_ty_1>() - 8usize];
+};
+impl amdsmi_bdf_t__bindgen_ty_1 {
+    #[inline]
+    pub fn function_number(&self) -> u64 {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 3u8) as u64) }
+    }
+    #[inline]
+    pub fn set_function_number(&mut self, val: u64) {
+        unsafe {
+            let val: u64 = ::std::mem::transmute(val);
+            self._bitfield_1.set(0usize, 3u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn function_number_raw(this: *const Self) -> u64 {
+        unsafe {
+            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::std::ptr::addr_of!((*this)._bitfield_1),
+                0usize,
+                3u8,
+            ) as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_function_number_raw(this: *mut Self, val: u64) {
+        unsafe {
+            let val: u64 = ::std::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
+                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                0usize,
+                3u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn device_number(&self) -> u64 {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(3usize, 5u8) as u64) }
+    }
+    #[inline]
+    pub fn set_device_number(&mut self, val: u64) {
+        unsafe {
+            let val: u64 = ::std::mem::transmute(val);
+            self._bitfield_1.set(3usize, 5u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn device_number_raw(this: *const Self) -> u64 {
+        unsafe {
+            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::std::ptr::addr_of!((*this)._bitfield_1),
+                3usize,
+                5u8,
+            ) as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_device_number_raw(this: *mut Self, val: u64) {
+        unsafe {
+            let val: u64 = ::std::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
+                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                3usize,
+                5u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn bus_number(&self) -> u64 {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(8usize, 8u8) as u64) }
+    }
+    #[inline]
+    pub fn set_bus_number(&mut self, val: u64) {
+        unsafe {
+            let val: u64 = ::std::mem::transmute(val);
+            self._bitfield_1.set(8usize, 8u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn bus_number_raw(this: *const Self) -> u64 {
+        unsafe {
+            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::std::ptr::addr_of!((*this)._bitfield_1),
+                8usize,
+                8u8,
+            ) as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_bus_number_raw(this: *mut Self, val: u64) {
+        unsafe {
+            let val: u64 = ::std::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
+                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                8usize,
+                8u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn domain_number(&self) -> u64 {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(16usize, 48u8) as u64) }
+    }
+    #[inline]
+    pub fn set_domain_number(&mut self, val: u64) {
+        unsafe {
+            let val: u64 = ::std::mem::transmute(val);
+            self._bitfield_1.set(16usize, 48u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn domain_number_raw(this: *const Self) -> u64 {
+        unsafe {
+            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::std::ptr::addr_of!((*this)._bitfield_1),
+                16usize,
+                48u8,
+            ) as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_domain_number_raw(this: *mut Self, val: u64) {
+        unsafe {
+            let val: u64 = ::std::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
+                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                16usize,
+                48u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        function_number: u64,
+        device_number: u64,
+        bus_number: u64,
+        domain_number: u64,
+    ) -> __BindgenBitfieldUnit<[u8; 8usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 8usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 3u8, {
+            let function_number: u64 = unsafe { ::std::mem::transmute(function_number) };
+            function_number as u64
+        });
+        __bindgen_bitfield_unit.set(3usize, 5u8, {
+            let device_number: u64 = unsafe { ::std::mem::transmute(device_number) };
+            device_number as u64
+        });
+        __bindgen_bitfield_unit.set(8usize, 8u8, {
+            let bus_number: u64 = unsafe { ::std::mem::transmute(bus_number) };
+            bus_number as u64
+        });
+        __bindgen_bitfield_unit.set(16usize, 48u8, {
+            let domain_number: u64 = unsafe { ::std::mem::transmute(domain_number) };
+            domain_number as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_bdf_t"][::std::mem::size_of::<amdsmi_bdf_t>() - 8usize];
+    ["Alignment of amdsmi_bdf_t"][::std::mem::align_of::<amdsmi_bdf_t>() - 8usize];
+    ["Offset of field: amdsmi_bdf_t::bdf"][::std::mem::offset_of!(amdsmi_bdf_t, bdf) - 0usize];
+    ["Offset of field: amdsmi_bdf_t::as_uint"]
+        [::std::mem::offset_of!(amdsmi_bdf_t, as_uint) - 0usize];
+};
+#[doc = "```text\n @brief Structure holds enumeration information\n\n @cond @tag{gpu_bm_linux} @tag{guest_1vf} @tag{guest_mvf} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_enumeration_info_t {
+    #[doc = "```text\n!< the render node under /sys/class/drm/renderD*\n```"]
+    pub drm_render: u32,
+    #[doc = "```text\n!< the graphic card device under /sys/class/drm/card*\n```"]
+    pub drm_card: u32,
+    #[doc = "```text\n!< the HSA enumeration ID\n```"]
+    pub hsa_id: u32,
+    #[doc = "```text\n!< the HIP enumeration ID\n```"]
+    pub hip_id: u32,
+    #[doc = "```text\n!< the HIP unique identifer\n```"]
+    pub hip_uuid: [::std::os::raw::c_char; 256usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_enumeration_info_t"]
+        [::std::mem::size_of::<amdsmi_enumeration_info_t>() - 272usize];
+    ["Alignment of amdsmi_enumeration_info_t"]
+        [::std::mem::align_of::<amdsmi_enumeration_info_t>() - 4usize];
+    ["Offset of field: amdsmi_enumeration_info_t::drm_render"]
+        [::std::mem::offset_of!(amdsmi_enumeration_info_t, drm_render) - 0usize];
+    ["Offset of field: amdsmi_enumeration_info_t::drm_card"]
+        [::std::mem::offset_of!(amdsmi_enumeration_info_t, drm_card) - 4usize];
+    ["Offset of field: amdsmi_enumeration_info_t::hsa_id"]
+        [::std::mem::offset_of!(amdsmi_enumeration_info_t, hsa_id) - 8usize];
+    ["Offset of field: amdsmi_enumeration_info_t::hip_id"]
+        [::std::mem::offset_of!(amdsmi_enumeration_info_t, hip_id) - 12usize];
+    ["Offset of field: amdsmi_enumeration_info_t::hip_uuid"]
+        [::std::mem::offset_of!(amdsmi_enumeration_info_t, hip_uuid) - 16usize];
+};
+impl amdsmi_card_form_factor_t {
+    #[doc = "```text\n!< PCIE card form factor\n```"]
+    pub const AMDSMI_CARD_FORM_FACTOR_PCIE: amdsmi_card_form_factor_t =
+        amdsmi_card_form_factor_t(0);
+    #[doc = "```text\n!< OAM form factor\n```"]
+    pub const AMDSMI_CARD_FORM_FACTOR_OAM: amdsmi_card_form_factor_t = amdsmi_card_form_factor_t(1);
+    #[doc = "```text\n!< CEM form factor\n```"]
+    pub const AMDSMI_CARD_FORM_FACTOR_CEM: amdsmi_card_form_factor_t = amdsmi_card_form_factor_t(2);
+    #[doc = "```text\n!< Unknown Form factor\n```"]
+    pub const AMDSMI_CARD_FORM_FACTOR_UNKNOWN: amdsmi_card_form_factor_t =
+        amdsmi_card_form_factor_t(3);
+}
+#[repr(transparent)]
+#[doc = "```text\n @brief Card Form Factor\n\n @cond @tag{gpu_bm_linux} @tag{host} @tag{guest_windows} @endcond\n```"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct amdsmi_card_form_factor_t(pub ::std::os::raw::c_uint);
+#[doc = "```text\n @brief pcie information\n\n @cond @tag{gpu_bm_linux} @tag{host} @tag{guest_windows} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_pcie_info_t {
+    pub pcie_static: amdsmi_pcie_info_t_pcie_static_,
+    pub pcie_metric: amdsmi_pcie_info_t_pcie_metric_,
+    pub reserved: [u64; 32usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_pcie_info_t_pcie_static_ {
+    #[doc = "```text\n!< maximum number of PCIe lanes\n```"]
+    pub max_pcie_width: u16,
+    #[doc = "```text\n!< maximum PCIe speed in GT/s\n```"]
+    pub max_pcie_speed: u32,
+    #[doc = "```text\n!< PCIe interface version\n```"]
+    pub pcie_interface_version: u32,
+    #[doc = "```text\n!< card form factor\n```"]
+    pub slot_type: amdsmi_card_form_factor_t,
+    #[doc = "```text\n!< maximum PCIe link generation\n```"]
+    pub max_pcie_interface_version: u32,
+    pub reserved: [u64; 9usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_pcie_info_t_pcie_static_"]
+        [::std::mem::size_of::<amdsmi_pcie_info_t_pcie_static_>() - 96usize];
+    ["Alignment of amdsmi_pcie_info_t_pcie_static_"]
+        [::std::mem::align_of::<amdsmi_pcie_info_t_pcie_static_>() - 8usize];
+    ["Offset of field: amdsmi_pcie_info_t_pcie_static_::max_pcie_width"]
+        [::std::mem::offset_of!(amdsmi_pcie_info_t_pcie_static_, max_pcie_width) - 0usize];
+    ["Offset of field: amdsmi_pcie_info_t_pcie_static_::max_pcie_speed"]
+        [::std::mem::offset_of!(amdsmi_pcie_info_t_pcie_static_, max_pcie_speed) - 4usize];
+    ["Offset of field: amdsmi_pcie_info_t_pcie_static_::pcie_interface_version"]
+        [::std::mem::offset_of!(amdsmi_pcie_info_t_pcie_static_, pcie_interface_version) - 8usize];
+    ["Offset of field: amdsmi_pcie_info_t_pcie_static_::slot_type"]
+        [::std::mem::offset_of!(amdsmi_pcie_info_t_pcie_static_, slot_type) - 12usize];
+    ["Offset of field: amdsmi_pcie_info_t_pcie_static_::max_pcie_interface_version"][::std::mem::offset_of!(
+        amdsmi_pcie_info_t_pcie_static_,
+        max_pcie_interface_version
+    ) - 16usize];
+    ["Offset of field: amdsmi_pcie_info_t_pcie_static_::reserved"]
+        [::std::mem::offset_of!(amdsmi_pcie_info_t_pcie_static_, reserved) - 24usize];
+};
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_pcie_info_t_pcie_metric_ {
+    #[doc = "```text\n!< current PCIe width\n```"]
+    pub pcie_width: u16,
+    #[doc = "```text\n!< current PCIe speed in MT/s\n```"]
+    pub pcie_speed: u32,
+    #[doc = "```text\n!< current instantaneous PCIe bandwidth in Mb/s\n```"]
+    pub pcie_bandwidth: u32,
+    #[doc = "```text\n!< total number of the replays issued on the PCIe link\n```"]
+    pub pcie_replay_count: u64,
+    #[doc = "```text\n!< total number of times the PCIe link transitioned from L0 to the recovery state\n```"]
+    pub pcie_l0_to_recovery_count: u64,
+    #[doc = "```text\n!< total number of replay rollovers issued on the PCIe link\n```"]
+    pub pcie_replay_roll_over_count: u64,
+    #[doc = "```text\n!< total number of NAKs issued on the PCIe link by the device\n```"]
+    pub pcie_nak_sent_count: u64,
+    #[doc = "```text\n!< total number of NAKs issued on the PCIe link by the receiver\n```"]
+    pub pcie_nak_received_count: u64,
+    #[doc = "```text\n!< PCIe other end recovery counter\n```"]
+    pub pcie_lc_perf_other_end_recovery_count: u32,
+    pub reserved: [u64; 12usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_pcie_info_t_pcie_metric_"]
+        [::std::mem::size_of::<amdsmi_pcie_info_t_pcie_metric_>() - 160usize];
+    ["Alignment of amdsmi_pcie_info_t_pcie_metric_"]
+        [::std::mem::align_of::<amdsmi_pcie_info_t_pcie_metric_>() - 8usize];
+    ["Offset of field: amdsmi_pcie_info_t_pcie_metric_::pcie_width"]
+        [::std::mem::offset_of!(amdsmi_pcie_info_t_pcie_metric_, pcie_width) - 0usize];
+    ["Offset of field: amdsmi_pcie_info_t_pcie_metric_::pcie_speed"]
+        [::std::mem::offset_of!(amdsmi_pcie_info_t_pcie_metric_, pcie_speed) - 4usize];
+    ["Offset of field: amdsmi_pcie_info_t_pcie_metric_::pcie_bandwidth"]
+        [::std::mem::offset_of!(amdsmi_pcie_info_t_pcie_metric_, pcie_bandwidth) - 8usize];
+    ["Offset of field: amdsmi_pcie_info_t_pcie_metric_::pcie_replay_count"]
+        [::std::mem::offset_of!(amdsmi_pcie_info_t_pcie_metric_, pcie_replay_count) - 16usize];
+    ["Offset of field: amdsmi_pcie_info_t_pcie_metric_::pcie_l0_to_recovery_count"][::std::mem::offset_of!(
+        amdsmi_pcie_info_t_pcie_metric_,
+        pcie_l0_to_recovery_count
+    ) - 24usize];
+    ["Offset of field: amdsmi_pcie_info_t_pcie_metric_::pcie_replay_roll_over_count"][::std::mem::offset_of!(
+        amdsmi_pcie_info_t_pcie_metric_,
+        pcie_replay_roll_over_count
+    ) - 32usize];
+    ["Offset of field: amdsmi_pcie_info_t_pcie_metric_::pcie_nak_sent_count"]
+        [::std::mem::offset_of!(amdsmi_pcie_info_t_pcie_metric_, pcie_nak_sent_count) - 40usize];
+    ["Offset of field: amdsmi_pcie_info_t_pcie_metric_::pcie_nak_received_count"][::std::mem::offset_of!(
+        amdsmi_pcie_info_t_pcie_metric_,
+        pcie_nak_received_count
+    ) - 48usize];
+    ["Offset of field: amdsmi_pcie_info_t_pcie_metric_::pcie_lc_perf_other_end_recovery_count"][::std::mem::offset_of!(
+        amdsmi_pcie_info_t_pcie_metric_,
+        pcie_lc_perf_other_end_recovery_count
+    )
+        - 56usize];
+    ["Offset of field: amdsmi_pcie_info_t_pcie_metric_::reserved"]
+        [::std::mem::offset_of!(amdsmi_pcie_info_t_pcie_metric_, reserved) - 64usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_pcie_info_t"][::std::mem::size_of::<amdsmi_pcie_info_t>() - 512usize];
+    ["Alignment of amdsmi_pcie_info_t"][::std::mem::align_of::<amdsmi_pcie_info_t>() - 8usize];
+    ["Offset of field: amdsmi_pcie_info_t::pcie_static"]
+        [::std::mem::offset_of!(amdsmi_pcie_info_t, pcie_static) - 0usize];
+    ["Offset of field: amdsmi_pcie_info_t::pcie_metric"]
+        [::std::mem::offset_of!(amdsmi_pcie_info_t, pcie_metric) - 96usize];
+    ["Offset of field: amdsmi_pcie_info_t::reserved"]
+        [::std::mem::offset_of!(amdsmi_pcie_info_t, reserved) - 256usize];
+};
+#[doc = "```text\n @brief Power Cap Information\n\n @cond @tag{gpu_bm_linux} @tag{host} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_power_cap_info_t {
+    #[doc = "```text\n!< current power cap Units uW {@linux_bm} or W {@host}\n```"]
+    pub power_cap: u64,
+    #[doc = "```text\n!< default power cap Units uW {@linux_bm} or W {@host}\n```"]
+    pub default_power_cap: u64,
+    #[doc = "```text\n!< dpm power cap Units MHz {@linux_bm} or Hz {@host}\n```"]
+    pub dpm_cap: u64,
+    #[doc = "```text\n!< minimum power cap Units uW {@linux_bm} or W {@host}\n```"]
+    pub min_power_cap: u64,
+    #[doc = "```text\n!< maximum power cap Units uW {@linux_bm} or W {@host}\n```"]
+    pub max_power_cap: u64,
+    pub reserved: [u64; 3usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_power_cap_info_t"][::std::mem::size_of::<amdsmi_power_cap_info_t>() - 64usize];
+    ["Alignment of amdsmi_power_cap_info_t"]
+        [::std::mem::align_of::<amdsmi_power_cap_info_t>() - 8usize];
+    ["Offset of field: amdsmi_power_cap_info_t::power_cap"]
+        [::std::mem::offset_of!(amdsmi_power_cap_info_t, power_cap) - 0usize];
+    ["Offset of field: amdsmi_power_cap_info_t::default_power_cap"]
+        [::std::mem::offset_of!(amdsmi_power_cap_info_t, default_power_cap) - 8usize];
+    ["Offset of field: amdsmi_power_cap_info_t::dpm_cap"]
+        [::std::mem::offset_of!(amdsmi_power_cap_info_t, dpm_cap) - 16usize];
+    ["Offset of field: amdsmi_power_cap_info_t::min_power_cap"]
+        [::std::mem::offset_of!(amdsmi_power_cap_info_t, min_power_cap) - 24usize];
+    ["Offset of field: amdsmi_power_cap_info_t::max_power_cap"]
+        [::std::mem::offset_of!(amdsmi_power_cap_info_t, max_power_cap) - 32usize];
+    ["Offset of field: amdsmi_power_cap_info_t::reserved"]
+        [::std::mem::offset_of!(amdsmi_power_cap_info_t, reserved) - 40usize];
+};
+#[doc = "```text\n @brief VBios Information\n\n @cond @tag{gpu_bm_linux} @tag{guest_windows} @tag{host} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_vbios_info_t {
+    pub name: [::std::os::raw::c_char; 256usize],
+    pub build_date: [::std::os::raw::c_char; 256usize],
+    pub part_number: [::std::os::raw::c_char; 256usize],
+    pub version: [::std::os::raw::c_char; 256usize],
+    pub boot_firmware: [::std::os::raw::c_char; 256usize],
+    pub reserved: [u64; 36usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_vbios_info_t"][::std::mem::size_of::<amdsmi_vbios_info_t>() - 1568usize];
+    ["Alignment of amdsmi_vbios_info_t"][::std::mem::align_of::<amdsmi_vbios_info_t>() - 8usize];
+    ["Offset of field: amdsmi_vbios_info_t::name"]
+        [::std::mem::offset_of!(amdsmi_vbios_info_t, name) - 0usize];
+    ["Offset of field: amdsmi_vbios_info_t::build_date"]
+        [::std::mem::offset_of!(amdsmi_vbios_info_t, build_date) - 256usize];
+    ["Offset of field: amdsmi_vbios_info_t::part_number"]
+        [::std::mem::offset_of!(amdsmi_vbios_info_t, part_number) - 512usize];
+    ["Offset of field: amdsmi_vbios_info_t::version"]
+        [::std::mem::offset_of!(amdsmi_vbios_info_t, version) - 768usize];
+    ["Offset of field: amdsmi_vbios_info_t::boot_firmware"]
+        [::std::mem::offset_of!(amdsmi_vbios_info_t, boot_firmware) - 1024usize];
+    ["Offset of field: amdsmi_vbios_info_t::reserved"]
+        [::std::mem::offset_of!(amdsmi_vbios_info_t, reserved) - 1280usize];
+};
+#[doc = "```text\n @brief Firmware Information\n\n @cond @tag{gpu_bm_linux} @tag{host} @tag{guest_windows} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_fw_info_t {
+    pub num_fw_info: u8,
+    pub fw_info_list: [amdsmi_fw_info_t_fw_info_list_; 80usize],
+    pub reserved: [u32; 7usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_fw_info_t_fw_info_list_ {
+    pub fw_id: amdsmi_fw_block_t,
+    pub fw_version: u64,
+    pub reserved: [u64; 2usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_fw_info_t_fw_info_list_"]
+        [::std::mem::size_of::<amdsmi_fw_info_t_fw_info_list_>() - 32usize];
+    ["Alignment of amdsmi_fw_info_t_fw_info_list_"]
+        [::std::mem::align_of::<amdsmi_fw_info_t_fw_info_list_>() - 8usize];
+    ["Offset of field: amdsmi_fw_info_t_fw_info_list_::fw_id"]
+        [::std::mem::offset_of!(amdsmi_fw_info_t_fw_info_list_, fw_id) - 0usize];
+    ["Offset of field: amdsmi_fw_info_t_fw_info_list_::fw_version"]
+        [::std::mem::offset_of!(amdsmi_fw_info_t_fw_info_list_, fw_version) - 8usize];
+    ["Offset of field: amdsmi_fw_info_t_fw_info_list_::reserved"]
+        [::std::mem::offset_of!(amdsmi_fw_info_t_fw_info_list_, reserved) - 16usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_fw_info_t"][::std::mem::size_of::<amdsmi_fw_info_t>() - 2600usize];
+    ["Alignment of amdsmi_fw_info_t"][::std::mem::align_of::<amdsmi_fw_info_t>() - 8usize];
+    ["Offset of field: amdsmi_fw_info_t::num_fw_info"]
+        [::std::mem::offset_of!(amdsmi_fw_info_t, num_fw_info) - 0usize];
+    ["Offset of field: amdsmi_fw_info_t::fw_info_list"]
+        [::std::mem::offset_of!(amdsmi_fw_info_t, fw_info_list) - 8usize];
+    ["Offset of field: amdsmi_fw_info_t::reserved"]
+        [::std::mem::offset_of!(amdsmi_fw_info_t, reserved) - 2568usize];
+};
 #[doc = "```text\n @brief ASIC Information\n\n @cond @tag{gpu_bm_linux} @tag{guest_windows} @tag{host} @endcond\n```"]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -381,6 +1476,153 @@ const _: () = {
     ["Offset of field: amdsmi_asic_info_t::reserved"]
         [::std::mem::offset_of!(amdsmi_asic_info_t, reserved) - 812usize];
 };
+#[doc = "```text\n @brief Structure holds kfd information\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_kfd_info_t {
+    #[doc = "```text\n!< 0xFFFFFFFFFFFFFFFF if not supported\n```"]
+    pub kfd_id: u64,
+    #[doc = "```text\n!< 0xFFFFFFFF if not supported\n```"]
+    pub node_id: u32,
+    #[doc = "```text\n!< 0xFFFFFFFF if not supported\n```"]
+    pub current_partition_id: u32,
+    pub reserved: [u32; 12usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_kfd_info_t"][::std::mem::size_of::<amdsmi_kfd_info_t>() - 64usize];
+    ["Alignment of amdsmi_kfd_info_t"][::std::mem::align_of::<amdsmi_kfd_info_t>() - 8usize];
+    ["Offset of field: amdsmi_kfd_info_t::kfd_id"]
+        [::std::mem::offset_of!(amdsmi_kfd_info_t, kfd_id) - 0usize];
+    ["Offset of field: amdsmi_kfd_info_t::node_id"]
+        [::std::mem::offset_of!(amdsmi_kfd_info_t, node_id) - 8usize];
+    ["Offset of field: amdsmi_kfd_info_t::current_partition_id"]
+        [::std::mem::offset_of!(amdsmi_kfd_info_t, current_partition_id) - 12usize];
+    ["Offset of field: amdsmi_kfd_info_t::reserved"]
+        [::std::mem::offset_of!(amdsmi_kfd_info_t, reserved) - 16usize];
+};
+impl amdsmi_link_type_t {
+    #[doc = "```text\n!< Internal Link Type, within chip\n```"]
+    pub const AMDSMI_LINK_TYPE_INTERNAL: amdsmi_link_type_t = amdsmi_link_type_t(0);
+    #[doc = "```text\n!< Peripheral Component Interconnect Express Link Type\n```"]
+    pub const AMDSMI_LINK_TYPE_PCIE: amdsmi_link_type_t = amdsmi_link_type_t(1);
+    #[doc = "```text\n!< GPU Memory Interconnect (multi GPU communication)\n```"]
+    pub const AMDSMI_LINK_TYPE_XGMI: amdsmi_link_type_t = amdsmi_link_type_t(2);
+    #[doc = "```text\n!< Not Applicable Link Type\n```"]
+    pub const AMDSMI_LINK_TYPE_NOT_APPLICABLE: amdsmi_link_type_t = amdsmi_link_type_t(3);
+    #[doc = "```text\n!< Unknown Link Type\n```"]
+    pub const AMDSMI_LINK_TYPE_UNKNOWN: amdsmi_link_type_t = amdsmi_link_type_t(4);
+}
+#[repr(transparent)]
+#[doc = "```text\n @brief Link type\n\n @cond @tag{gpu_bm_linux} @tag{host} @endcond\n```"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct amdsmi_link_type_t(pub ::std::os::raw::c_uint);
+#[doc = "```text\n @brief Link Metrics\n\n @cond @tag{gpu_bm_linux} @tag{host} @endcond\n```"]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct amdsmi_link_metrics_t {
+    #[doc = "```text\n!< number of links\n```"]
+    pub num_links: u32,
+    pub links: [amdsmi_link_metrics_t__links; 64usize],
+    pub reserved: [u64; 7usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct amdsmi_link_metrics_t__links {
+    #[doc = "```text\n!< bdf of the destination gpu\n```"]
+    pub bdf: amdsmi_bdf_t,
+    #[doc = "```text\n!< current link speed in Gb/s\n```"]
+    pub bit_rate: u32,
+    #[doc = "```text\n!< max bandwidth of the link in Gb/s\n```"]
+    pub max_bandwidth: u32,
+    #[doc = "```text\n!< type of the link\n```"]
+    pub link_type: amdsmi_link_type_t,
+    #[doc = "```text\n!< total data received for each link in KB\n```"]
+    pub read: u64,
+    #[doc = "```text\n!< total data transfered for each link in KB\n```"]
+    pub write: u64,
+    pub reserved: [u64; 1usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_link_metrics_t__links"]
+        [::std::mem::size_of::<amdsmi_link_metrics_t__links>() - 48usize];
+    ["Alignment of amdsmi_link_metrics_t__links"]
+        [::std::mem::align_of::<amdsmi_link_metrics_t__links>() - 8usize];
+    ["Offset of field: amdsmi_link_metrics_t__links::bdf"]
+        [::std::mem::offset_of!(amdsmi_link_metrics_t__links, bdf) - 0usize];
+    ["Offset of field: amdsmi_link_metrics_t__links::bit_rate"]
+        [::std::mem::offset_of!(amdsmi_link_metrics_t__links, bit_rate) - 8usize];
+    ["Offset of field: amdsmi_link_metrics_t__links::max_bandwidth"]
+        [::std::mem::offset_of!(amdsmi_link_metrics_t__links, max_bandwidth) - 12usize];
+    ["Offset of field: amdsmi_link_metrics_t__links::link_type"]
+        [::std::mem::offset_of!(amdsmi_link_metrics_t__links, link_type) - 16usize];
+    ["Offset of field: amdsmi_link_metrics_t__links::read"]
+        [::std::mem::offset_of!(amdsmi_link_metrics_t__links, read) - 24usize];
+    ["Offset of field: amdsmi_link_metrics_t__links::write"]
+        [::std::mem::offset_of!(amdsmi_link_metrics_t__links, write) - 32usize];
+    ["Offset of field: amdsmi_link_metrics_t__links::reserved"]
+        [::std::mem::offset_of!(amdsmi_link_metrics_t__links, reserved) - 40usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_link_metrics_t"][::std::mem::size_of::<amdsmi_link_metrics_t>() - 3136usize];
+    ["Alignment of amdsmi_link_metrics_t"]
+        [::std::mem::align_of::<amdsmi_link_metrics_t>() - 8usize];
+    ["Offset of field: amdsmi_link_metrics_t::num_links"]
+        [::std::mem::offset_of!(amdsmi_link_metrics_t, num_links) - 0usize];
+    ["Offset of field: amdsmi_link_metrics_t::links"]
+        [::std::mem::offset_of!(amdsmi_link_metrics_t, links) - 8usize];
+    ["Offset of field: amdsmi_link_metrics_t::reserved"]
+        [::std::mem::offset_of!(amdsmi_link_metrics_t, reserved) - 3080usize];
+};
+#[doc = "```text\n @brief Driver Information\n\n @cond @tag{gpu_bm_linux} @tag{guest_windows} @tag{host} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_driver_info_t {
+    pub driver_version: [::std::os::raw::c_char; 256usize],
+    pub driver_date: [::std::os::raw::c_char; 256usize],
+    pub driver_name: [::std::os::raw::c_char; 256usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_driver_info_t"][::std::mem::size_of::<amdsmi_driver_info_t>() - 768usize];
+    ["Alignment of amdsmi_driver_info_t"][::std::mem::align_of::<amdsmi_driver_info_t>() - 1usize];
+    ["Offset of field: amdsmi_driver_info_t::driver_version"]
+        [::std::mem::offset_of!(amdsmi_driver_info_t, driver_version) - 0usize];
+    ["Offset of field: amdsmi_driver_info_t::driver_date"]
+        [::std::mem::offset_of!(amdsmi_driver_info_t, driver_date) - 256usize];
+    ["Offset of field: amdsmi_driver_info_t::driver_name"]
+        [::std::mem::offset_of!(amdsmi_driver_info_t, driver_name) - 512usize];
+};
+#[doc = "```text\n @brief Board Information\n\n @cond @tag{gpu_bm_linux} @tag{host} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_board_info_t {
+    pub model_number: [::std::os::raw::c_char; 256usize],
+    pub product_serial: [::std::os::raw::c_char; 256usize],
+    pub fru_id: [::std::os::raw::c_char; 256usize],
+    pub product_name: [::std::os::raw::c_char; 256usize],
+    pub manufacturer_name: [::std::os::raw::c_char; 256usize],
+    pub reserved: [u64; 64usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_board_info_t"][::std::mem::size_of::<amdsmi_board_info_t>() - 1792usize];
+    ["Alignment of amdsmi_board_info_t"][::std::mem::align_of::<amdsmi_board_info_t>() - 8usize];
+    ["Offset of field: amdsmi_board_info_t::model_number"]
+        [::std::mem::offset_of!(amdsmi_board_info_t, model_number) - 0usize];
+    ["Offset of field: amdsmi_board_info_t::product_serial"]
+        [::std::mem::offset_of!(amdsmi_board_info_t, product_serial) - 256usize];
+    ["Offset of field: amdsmi_board_info_t::fru_id"]
+        [::std::mem::offset_of!(amdsmi_board_info_t, fru_id) - 512usize];
+    ["Offset of field: amdsmi_board_info_t::product_name"]
+        [::std::mem::offset_of!(amdsmi_board_info_t, product_name) - 768usize];
+    ["Offset of field: amdsmi_board_info_t::manufacturer_name"]
+        [::std::mem::offset_of!(amdsmi_board_info_t, manufacturer_name) - 1024usize];
+    ["Offset of field: amdsmi_board_info_t::reserved"]
+        [::std::mem::offset_of!(amdsmi_board_info_t, reserved) - 1280usize];
+};
 #[doc = "```text\n @brief Power Information\n\n Unsupported struct members are set to UINT32_MAX\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -573,6 +1815,233 @@ const _: () = {
     ["Offset of field: amdsmi_proc_info_t::reserved"]
         [::std::mem::offset_of!(amdsmi_proc_info_t, reserved) - 664usize];
 };
+#[doc = "```text\n @brief IO Link P2P Capability\n\n @cond @tag{gpu_bm_linux} @tag{host} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_p2p_capability_t {
+    #[doc = "```text\n!< 1 = true, 0 = false, UINT8_MAX = Not defined\n```"]
+    pub is_iolink_coherent: u8,
+    #[doc = "```text\n!< 1 = true, 0 = false, UINT8_MAX = Not defined\n```"]
+    pub is_iolink_atomics_32bit: u8,
+    #[doc = "```text\n!< 1 = true, 0 = false, UINT8_MAX = Not defined\n```"]
+    pub is_iolink_atomics_64bit: u8,
+    #[doc = "```text\n!< 1 = true, 0 = false, UINT8_MAX = Not defined\n```"]
+    pub is_iolink_dma: u8,
+    #[doc = "```text\n!< 1 = true, 0 = false, UINT8_MAX = Not defined\n```"]
+    pub is_iolink_bi_directional: u8,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_p2p_capability_t"][::std::mem::size_of::<amdsmi_p2p_capability_t>() - 5usize];
+    ["Alignment of amdsmi_p2p_capability_t"]
+        [::std::mem::align_of::<amdsmi_p2p_capability_t>() - 1usize];
+    ["Offset of field: amdsmi_p2p_capability_t::is_iolink_coherent"]
+        [::std::mem::offset_of!(amdsmi_p2p_capability_t, is_iolink_coherent) - 0usize];
+    ["Offset of field: amdsmi_p2p_capability_t::is_iolink_atomics_32bit"]
+        [::std::mem::offset_of!(amdsmi_p2p_capability_t, is_iolink_atomics_32bit) - 1usize];
+    ["Offset of field: amdsmi_p2p_capability_t::is_iolink_atomics_64bit"]
+        [::std::mem::offset_of!(amdsmi_p2p_capability_t, is_iolink_atomics_64bit) - 2usize];
+    ["Offset of field: amdsmi_p2p_capability_t::is_iolink_dma"]
+        [::std::mem::offset_of!(amdsmi_p2p_capability_t, is_iolink_dma) - 3usize];
+    ["Offset of field: amdsmi_p2p_capability_t::is_iolink_bi_directional"]
+        [::std::mem::offset_of!(amdsmi_p2p_capability_t, is_iolink_bi_directional) - 4usize];
+};
+impl amdsmi_dev_perf_level_t {
+    #[doc = "```text\n!< Performance level is \"auto\"\n```"]
+    pub const AMDSMI_DEV_PERF_LEVEL_AUTO: amdsmi_dev_perf_level_t = amdsmi_dev_perf_level_t(0);
+    pub const AMDSMI_DEV_PERF_LEVEL_FIRST: amdsmi_dev_perf_level_t = amdsmi_dev_perf_level_t(0);
+    #[doc = "```text\n!< Keep PowerPlay levels \"low\", regardless of workload\n```"]
+    pub const AMDSMI_DEV_PERF_LEVEL_LOW: amdsmi_dev_perf_level_t = amdsmi_dev_perf_level_t(1);
+    #[doc = "```text\n!< Keep PowerPlay levels \"high\", regardless of workload\n```"]
+    pub const AMDSMI_DEV_PERF_LEVEL_HIGH: amdsmi_dev_perf_level_t = amdsmi_dev_perf_level_t(2);
+    #[doc = "```text\n< Only use values defined by manually\nsetting the AMDSMI_CLK_TYPE_SYS speed\n```"]
+    pub const AMDSMI_DEV_PERF_LEVEL_MANUAL: amdsmi_dev_perf_level_t = amdsmi_dev_perf_level_t(3);
+    #[doc = "```text\n!< Stable power state with profiling clocks\n```"]
+    pub const AMDSMI_DEV_PERF_LEVEL_STABLE_STD: amdsmi_dev_perf_level_t =
+        amdsmi_dev_perf_level_t(4);
+    #[doc = "```text\n!< Stable power state with peak clocks\n```"]
+    pub const AMDSMI_DEV_PERF_LEVEL_STABLE_PEAK: amdsmi_dev_perf_level_t =
+        amdsmi_dev_perf_level_t(5);
+    #[doc = "```text\n!< Stable power state with minimum memory clock\n```"]
+    pub const AMDSMI_DEV_PERF_LEVEL_STABLE_MIN_MCLK: amdsmi_dev_perf_level_t =
+        amdsmi_dev_perf_level_t(6);
+    #[doc = "```text\n!< Stable power state with minimum system clock\n```"]
+    pub const AMDSMI_DEV_PERF_LEVEL_STABLE_MIN_SCLK: amdsmi_dev_perf_level_t =
+        amdsmi_dev_perf_level_t(7);
+    #[doc = "```text\n!< Performance determinism state\n```"]
+    pub const AMDSMI_DEV_PERF_LEVEL_DETERMINISM: amdsmi_dev_perf_level_t =
+        amdsmi_dev_perf_level_t(8);
+    pub const AMDSMI_DEV_PERF_LEVEL_LAST: amdsmi_dev_perf_level_t = amdsmi_dev_perf_level_t(8);
+    #[doc = "```text\n!< Unknown performance level\n```"]
+    pub const AMDSMI_DEV_PERF_LEVEL_UNKNOWN: amdsmi_dev_perf_level_t = amdsmi_dev_perf_level_t(256);
+}
+#[repr(transparent)]
+#[doc = "```text\n @brief PowerPlay performance levels\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct amdsmi_dev_perf_level_t(pub ::std::os::raw::c_uint);
+#[doc = "```text\n @brief Handle to performance event counter\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+pub type amdsmi_event_handle_t = usize;
+impl amdsmi_event_group_t {
+    #[doc = "```text\n!< Data Fabric (XGMI) related events\n```"]
+    pub const AMDSMI_EVNT_GRP_XGMI: amdsmi_event_group_t = amdsmi_event_group_t(0);
+    #[doc = "```text\n!< XGMI Outbound data\n```"]
+    pub const AMDSMI_EVNT_GRP_XGMI_DATA_OUT: amdsmi_event_group_t = amdsmi_event_group_t(10);
+    #[doc = "```text\n!< Unknown Event Group\n```"]
+    pub const AMDSMI_EVNT_GRP_INVALID: amdsmi_event_group_t = amdsmi_event_group_t(4294967295);
+}
+#[repr(transparent)]
+#[doc = "```text\n @brief Event Groups\n Enum denoting an event group. The value of the enum is the\n base value for all the event enums in the group.\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct amdsmi_event_group_t(pub ::std::os::raw::c_uint);
+impl amdsmi_event_type_t {
+    pub const AMDSMI_EVNT_FIRST: amdsmi_event_type_t = amdsmi_event_type_t(0);
+    pub const AMDSMI_EVNT_XGMI_FIRST: amdsmi_event_type_t = amdsmi_event_type_t(0);
+    #[doc = "```text\n!< NOPs sent to neighbor 0\n```"]
+    pub const AMDSMI_EVNT_XGMI_0_NOP_TX: amdsmi_event_type_t = amdsmi_event_type_t(0);
+    #[doc = "```text\n!< Outgoing requests to neighbor 0\n```"]
+    pub const AMDSMI_EVNT_XGMI_0_REQUEST_TX: amdsmi_event_type_t = amdsmi_event_type_t(1);
+    #[doc = "```text\n!< Outgoing responses to neighbor 0\n```"]
+    pub const AMDSMI_EVNT_XGMI_0_RESPONSE_TX: amdsmi_event_type_t = amdsmi_event_type_t(2);
+    #[doc = "```text\n!< Throughput = BEATS/time_running 10^9 bytes/sec\n```"]
+    pub const AMDSMI_EVNT_XGMI_0_BEATS_TX: amdsmi_event_type_t = amdsmi_event_type_t(3);
+    #[doc = "```text\n!< NOPs sent to neighbor 1\n```"]
+    pub const AMDSMI_EVNT_XGMI_1_NOP_TX: amdsmi_event_type_t = amdsmi_event_type_t(4);
+    #[doc = "```text\n!< Outgoing requests to neighbor 1\n```"]
+    pub const AMDSMI_EVNT_XGMI_1_REQUEST_TX: amdsmi_event_type_t = amdsmi_event_type_t(5);
+    #[doc = "```text\n!< Outgoing responses to neighbor 1\n```"]
+    pub const AMDSMI_EVNT_XGMI_1_RESPONSE_TX: amdsmi_event_type_t = amdsmi_event_type_t(6);
+    #[doc = "```text\n!< Data beats sent to neighbor 1; Each beat represents 32 bytes\n```"]
+    pub const AMDSMI_EVNT_XGMI_1_BEATS_TX: amdsmi_event_type_t = amdsmi_event_type_t(7);
+    pub const AMDSMI_EVNT_XGMI_LAST: amdsmi_event_type_t = amdsmi_event_type_t(7);
+    pub const AMDSMI_EVNT_XGMI_DATA_OUT_FIRST: amdsmi_event_type_t = amdsmi_event_type_t(10);
+    #[doc = "```text\n!< Outbound beats to neighbor 0\n```"]
+    pub const AMDSMI_EVNT_XGMI_DATA_OUT_0: amdsmi_event_type_t = amdsmi_event_type_t(10);
+    #[doc = "```text\n!< Outbound beats to neighbor 1\n```"]
+    pub const AMDSMI_EVNT_XGMI_DATA_OUT_1: amdsmi_event_type_t = amdsmi_event_type_t(11);
+    #[doc = "```text\n!< Outbound beats to neighbor 2\n```"]
+    pub const AMDSMI_EVNT_XGMI_DATA_OUT_2: amdsmi_event_type_t = amdsmi_event_type_t(12);
+    #[doc = "```text\n!< Outbound beats to neighbor 3\n```"]
+    pub const AMDSMI_EVNT_XGMI_DATA_OUT_3: amdsmi_event_type_t = amdsmi_event_type_t(13);
+    #[doc = "```text\n!< Outbound beats to neighbor 4\n```"]
+    pub const AMDSMI_EVNT_XGMI_DATA_OUT_4: amdsmi_event_type_t = amdsmi_event_type_t(14);
+    #[doc = "```text\n!< Outbound beats to neighbor 5\n```"]
+    pub const AMDSMI_EVNT_XGMI_DATA_OUT_5: amdsmi_event_type_t = amdsmi_event_type_t(15);
+    pub const AMDSMI_EVNT_XGMI_DATA_OUT_LAST: amdsmi_event_type_t = amdsmi_event_type_t(15);
+    pub const AMDSMI_EVNT_LAST: amdsmi_event_type_t = amdsmi_event_type_t(15);
+}
+#[repr(transparent)]
+#[doc = "```text\n @brief Event types\n Event type enum. Events belonging to a particular event group\n ::amdsmi_event_group_t should begin enumerating at the ::amdsmi_event_group_t\n value for that group.\n\n Data beats sent to neighbor 0; Each beat represents 32 bytes.<br><br>\n\n XGMI throughput can be calculated by multiplying a BEATs event\n such as ::AMDSMI_EVNT_XGMI_0_BEATS_TX by 32 and dividing by\n the time for which event collection occurred,\n ::amdsmi_counter_value_t.time_running (which is in nanoseconds). To get\n bytes per second, multiply this value by 10<sup>9</sup>.<br>\n <br>\n Throughput = BEATS/time_running * 10<sup>9</sup>  (bytes/second)<br>\n\n Events in the AMDSMI_EVNT_GRP_XGMI_DATA_OUT group measure\n the number of beats sent on an XGMI link. Each beat represents\n 32 bytes. AMDSMI_EVNT_XGMI_DATA_OUT_n represents the number of\n outbound beats (each representing 32 bytes) on link n.<br><br>\n\n XGMI throughput can be calculated by multiplying a event\n such as ::AMDSMI_EVNT_XGMI_DATA_OUT_n by 32 and dividing by\n the time for which event collection occurred,\n ::amdsmi_counter_value_t.time_running (which is in nanoseconds). To get\n bytes per second, multiply this value by 10<sup>9</sup>.<br>\n <br>\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct amdsmi_event_type_t(pub ::std::os::raw::c_uint);
+impl amdsmi_counter_command_t {
+    #[doc = "```text\n!< Start the counter\n```"]
+    pub const AMDSMI_CNTR_CMD_START: amdsmi_counter_command_t = amdsmi_counter_command_t(0);
+    #[doc = "```text\n< Stop the counter; note that this should not\nbe used before reading\n```"]
+    pub const AMDSMI_CNTR_CMD_STOP: amdsmi_counter_command_t = amdsmi_counter_command_t(1);
+}
+#[repr(transparent)]
+#[doc = "```text\n @brief Event counter commands\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct amdsmi_counter_command_t(pub ::std::os::raw::c_uint);
+#[doc = "```text\n @brief Counter value\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_counter_value_t {
+    #[doc = "```text\n!< Counter value\n```"]
+    pub value: u64,
+    #[doc = "```text\n!< Time that the counter was enabled in nanoseconds\n```"]
+    pub time_enabled: u64,
+    #[doc = "```text\n!< Time that the counter was running in nanoseconds\n```"]
+    pub time_running: u64,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_counter_value_t"][::std::mem::size_of::<amdsmi_counter_value_t>() - 24usize];
+    ["Alignment of amdsmi_counter_value_t"]
+        [::std::mem::align_of::<amdsmi_counter_value_t>() - 8usize];
+    ["Offset of field: amdsmi_counter_value_t::value"]
+        [::std::mem::offset_of!(amdsmi_counter_value_t, value) - 0usize];
+    ["Offset of field: amdsmi_counter_value_t::time_enabled"]
+        [::std::mem::offset_of!(amdsmi_counter_value_t, time_enabled) - 8usize];
+    ["Offset of field: amdsmi_counter_value_t::time_running"]
+        [::std::mem::offset_of!(amdsmi_counter_value_t, time_running) - 16usize];
+};
+impl amdsmi_evt_notification_type_t {
+    #[doc = "```text\n!< No events\n```"]
+    pub const AMDSMI_EVT_NOTIF_NONE: amdsmi_evt_notification_type_t =
+        amdsmi_evt_notification_type_t(0);
+    #[doc = "```text\n!< Virtual Memory Page Fault Event\n```"]
+    pub const AMDSMI_EVT_NOTIF_VMFAULT: amdsmi_evt_notification_type_t =
+        amdsmi_evt_notification_type_t(1);
+    pub const AMDSMI_EVT_NOTIF_FIRST: amdsmi_evt_notification_type_t =
+        amdsmi_evt_notification_type_t(1);
+    #[doc = "```text\n!< thermal throttle\n```"]
+    pub const AMDSMI_EVT_NOTIF_THERMAL_THROTTLE: amdsmi_evt_notification_type_t =
+        amdsmi_evt_notification_type_t(2);
+    #[doc = "```text\n!< pre-reset\n```"]
+    pub const AMDSMI_EVT_NOTIF_GPU_PRE_RESET: amdsmi_evt_notification_type_t =
+        amdsmi_evt_notification_type_t(3);
+    #[doc = "```text\n!< post-reset\n```"]
+    pub const AMDSMI_EVT_NOTIF_GPU_POST_RESET: amdsmi_evt_notification_type_t =
+        amdsmi_evt_notification_type_t(4);
+    #[doc = "```text\n!< migrate start\n```"]
+    pub const AMDSMI_EVT_NOTIF_MIGRATE_START: amdsmi_evt_notification_type_t =
+        amdsmi_evt_notification_type_t(5);
+    #[doc = "```text\n!< migrate end\n```"]
+    pub const AMDSMI_EVT_NOTIF_MIGRATE_END: amdsmi_evt_notification_type_t =
+        amdsmi_evt_notification_type_t(6);
+    #[doc = "```text\n!< page fault start\n```"]
+    pub const AMDSMI_EVT_NOTIF_PAGE_FAULT_START: amdsmi_evt_notification_type_t =
+        amdsmi_evt_notification_type_t(7);
+    #[doc = "```text\n!< page fault end\n```"]
+    pub const AMDSMI_EVT_NOTIF_PAGE_FAULT_END: amdsmi_evt_notification_type_t =
+        amdsmi_evt_notification_type_t(8);
+    #[doc = "```text\n!< queue eviction\n```"]
+    pub const AMDSMI_EVT_NOTIF_QUEUE_EVICTION: amdsmi_evt_notification_type_t =
+        amdsmi_evt_notification_type_t(9);
+    #[doc = "```text\n!< queue restore\n```"]
+    pub const AMDSMI_EVT_NOTIF_QUEUE_RESTORE: amdsmi_evt_notification_type_t =
+        amdsmi_evt_notification_type_t(10);
+    #[doc = "```text\n!< unmap from GPU\n```"]
+    pub const AMDSMI_EVT_NOTIF_UNMAP_FROM_GPU: amdsmi_evt_notification_type_t =
+        amdsmi_evt_notification_type_t(11);
+    #[doc = "```text\n!< KFD process start\n```"]
+    pub const AMDSMI_EVT_NOTIF_PROCESS_START: amdsmi_evt_notification_type_t =
+        amdsmi_evt_notification_type_t(12);
+    #[doc = "```text\n!< KFD process end\n```"]
+    pub const AMDSMI_EVT_NOTIF_PROCESS_END: amdsmi_evt_notification_type_t =
+        amdsmi_evt_notification_type_t(13);
+    pub const AMDSMI_EVT_NOTIF_LAST: amdsmi_evt_notification_type_t =
+        amdsmi_evt_notification_type_t(13);
+}
+#[repr(transparent)]
+#[doc = "```text\n @brief Event notification event types\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct amdsmi_evt_notification_type_t(pub ::std::os::raw::c_uint);
+#[doc = "```text\n @brief Event notification data returned from event notification API\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_evt_notification_data_t {
+    #[doc = "```text\n!< Handler of device that corresponds to the event\n```"]
+    pub processor_handle: amdsmi_processor_handle,
+    #[doc = "```text\n!< Event type\n```"]
+    pub event: amdsmi_evt_notification_type_t,
+    #[doc = "```text\n!< Event message\n```"]
+    pub message: [::std::os::raw::c_char; 256usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_evt_notification_data_t"]
+        [::std::mem::size_of::<amdsmi_evt_notification_data_t>() - 272usize];
+    ["Alignment of amdsmi_evt_notification_data_t"]
+        [::std::mem::align_of::<amdsmi_evt_notification_data_t>() - 8usize];
+    ["Offset of field: amdsmi_evt_notification_data_t::processor_handle"]
+        [::std::mem::offset_of!(amdsmi_evt_notification_data_t, processor_handle) - 0usize];
+    ["Offset of field: amdsmi_evt_notification_data_t::event"]
+        [::std::mem::offset_of!(amdsmi_evt_notification_data_t, event) - 8usize];
+    ["Offset of field: amdsmi_evt_notification_data_t::message"]
+        [::std::mem::offset_of!(amdsmi_evt_notification_data_t, message) - 12usize];
+};
 impl amdsmi_temperature_metric_t {
     #[doc = "```text\n!< Current temperature\n```"]
     pub const AMDSMI_TEMP_CURRENT: amdsmi_temperature_metric_t = amdsmi_temperature_metric_t(0);
@@ -652,20 +2121,1427 @@ impl amdsmi_voltage_type_t {
 #[doc = "```text\n @brief This ennumeration is used to indicate which type of\n voltage reading should be obtained.\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
 pub struct amdsmi_voltage_type_t(pub ::std::os::raw::c_uint);
-impl amdsmi_memory_type_t {
-    pub const AMDSMI_MEM_TYPE_FIRST: amdsmi_memory_type_t = amdsmi_memory_type_t(0);
-    #[doc = "```text\n!< VRAM memory\n```"]
-    pub const AMDSMI_MEM_TYPE_VRAM: amdsmi_memory_type_t = amdsmi_memory_type_t(0);
-    #[doc = "```text\n!< VRAM memory that is visible\n```"]
-    pub const AMDSMI_MEM_TYPE_VIS_VRAM: amdsmi_memory_type_t = amdsmi_memory_type_t(1);
-    #[doc = "```text\n!< GTT memory\n```"]
-    pub const AMDSMI_MEM_TYPE_GTT: amdsmi_memory_type_t = amdsmi_memory_type_t(2);
+impl amdsmi_power_profile_preset_masks_t {
+    #[doc = "```text\n!< Custom Power Profile\n```"]
+    pub const AMDSMI_PWR_PROF_PRST_CUSTOM_MASK: amdsmi_power_profile_preset_masks_t =
+        amdsmi_power_profile_preset_masks_t(1);
+    #[doc = "```text\n!< Video Power Profile\n```"]
+    pub const AMDSMI_PWR_PROF_PRST_VIDEO_MASK: amdsmi_power_profile_preset_masks_t =
+        amdsmi_power_profile_preset_masks_t(2);
+    #[doc = "```text\n!< Power Saving Profile\n```"]
+    pub const AMDSMI_PWR_PROF_PRST_POWER_SAVING_MASK: amdsmi_power_profile_preset_masks_t =
+        amdsmi_power_profile_preset_masks_t(4);
+    #[doc = "```text\n!< Compute Saving Profile\n```"]
+    pub const AMDSMI_PWR_PROF_PRST_COMPUTE_MASK: amdsmi_power_profile_preset_masks_t =
+        amdsmi_power_profile_preset_masks_t(8);
+    #[doc = "```text\n!< VR Power Profile\n```"]
+    pub const AMDSMI_PWR_PROF_PRST_VR_MASK: amdsmi_power_profile_preset_masks_t =
+        amdsmi_power_profile_preset_masks_t(16);
+    #[doc = "```text\n!< 3D Full Screen Profile\n```"]
+    pub const AMDSMI_PWR_PROF_PRST_3D_FULL_SCR_MASK: amdsmi_power_profile_preset_masks_t =
+        amdsmi_power_profile_preset_masks_t(32);
+    #[doc = "```text\n!< Default Boot Up Profile\n```"]
+    pub const AMDSMI_PWR_PROF_PRST_BOOTUP_DEFAULT: amdsmi_power_profile_preset_masks_t =
+        amdsmi_power_profile_preset_masks_t(64);
+    pub const AMDSMI_PWR_PROF_PRST_LAST: amdsmi_power_profile_preset_masks_t =
+        amdsmi_power_profile_preset_masks_t(64);
+    #[doc = "```text\n!< Invalid Power Profile\n```"]
+    pub const AMDSMI_PWR_PROF_PRST_INVALID: amdsmi_power_profile_preset_masks_t =
+        amdsmi_power_profile_preset_masks_t(18446744073709551615);
+}
+#[repr(transparent)]
+#[doc = "```text\n @brief Pre-set Profile Selections. These bitmasks can be AND'd with the\n ::amdsmi_power_profile_status_t.available_profiles returned from\n :: amdsmi_get_gpu_power_profile_presets to determine which power profiles\n are supported by the system.\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct amdsmi_power_profile_preset_masks_t(pub ::std::os::raw::c_ulong);
+impl amdsmi_gpu_block_t {
+    #[doc = "```text\n!< Invalid block\n```"]
+    pub const AMDSMI_GPU_BLOCK_INVALID: amdsmi_gpu_block_t = amdsmi_gpu_block_t(0);
+    pub const AMDSMI_GPU_BLOCK_FIRST: amdsmi_gpu_block_t = amdsmi_gpu_block_t(1);
+    #[doc = "```text\n!< UMC block\n```"]
+    pub const AMDSMI_GPU_BLOCK_UMC: amdsmi_gpu_block_t = amdsmi_gpu_block_t(1);
+    #[doc = "```text\n!< SDMA block\n```"]
+    pub const AMDSMI_GPU_BLOCK_SDMA: amdsmi_gpu_block_t = amdsmi_gpu_block_t(2);
+    #[doc = "```text\n!< GFX block\n```"]
+    pub const AMDSMI_GPU_BLOCK_GFX: amdsmi_gpu_block_t = amdsmi_gpu_block_t(4);
+    #[doc = "```text\n!< MMHUB block\n```"]
+    pub const AMDSMI_GPU_BLOCK_MMHUB: amdsmi_gpu_block_t = amdsmi_gpu_block_t(8);
+    #[doc = "```text\n!< ATHUB block\n```"]
+    pub const AMDSMI_GPU_BLOCK_ATHUB: amdsmi_gpu_block_t = amdsmi_gpu_block_t(16);
+    #[doc = "```text\n!< PCIE_BIF block\n```"]
+    pub const AMDSMI_GPU_BLOCK_PCIE_BIF: amdsmi_gpu_block_t = amdsmi_gpu_block_t(32);
+    #[doc = "```text\n!< HDP block\n```"]
+    pub const AMDSMI_GPU_BLOCK_HDP: amdsmi_gpu_block_t = amdsmi_gpu_block_t(64);
+    #[doc = "```text\n!< XGMI block\n```"]
+    pub const AMDSMI_GPU_BLOCK_XGMI_WAFL: amdsmi_gpu_block_t = amdsmi_gpu_block_t(128);
+    #[doc = "```text\n!< DF block\n```"]
+    pub const AMDSMI_GPU_BLOCK_DF: amdsmi_gpu_block_t = amdsmi_gpu_block_t(256);
+    #[doc = "```text\n!< SMN block\n```"]
+    pub const AMDSMI_GPU_BLOCK_SMN: amdsmi_gpu_block_t = amdsmi_gpu_block_t(512);
+    #[doc = "```text\n!< SEM block\n```"]
+    pub const AMDSMI_GPU_BLOCK_SEM: amdsmi_gpu_block_t = amdsmi_gpu_block_t(1024);
+    #[doc = "```text\n!< MP0 block\n```"]
+    pub const AMDSMI_GPU_BLOCK_MP0: amdsmi_gpu_block_t = amdsmi_gpu_block_t(2048);
+    #[doc = "```text\n!< MP1 block\n```"]
+    pub const AMDSMI_GPU_BLOCK_MP1: amdsmi_gpu_block_t = amdsmi_gpu_block_t(4096);
+    #[doc = "```text\n!< Fuse block\n```"]
+    pub const AMDSMI_GPU_BLOCK_FUSE: amdsmi_gpu_block_t = amdsmi_gpu_block_t(8192);
+    #[doc = "```text\n!< MCA block\n```"]
+    pub const AMDSMI_GPU_BLOCK_MCA: amdsmi_gpu_block_t = amdsmi_gpu_block_t(16384);
+    #[doc = "```text\n!< VCN block\n```"]
+    pub const AMDSMI_GPU_BLOCK_VCN: amdsmi_gpu_block_t = amdsmi_gpu_block_t(32768);
+    #[doc = "```text\n!< JPEG block\n```"]
+    pub const AMDSMI_GPU_BLOCK_JPEG: amdsmi_gpu_block_t = amdsmi_gpu_block_t(65536);
+    #[doc = "```text\n!< IH block\n```"]
+    pub const AMDSMI_GPU_BLOCK_IH: amdsmi_gpu_block_t = amdsmi_gpu_block_t(131072);
+    #[doc = "```text\n!< MPIO block\n```"]
+    pub const AMDSMI_GPU_BLOCK_MPIO: amdsmi_gpu_block_t = amdsmi_gpu_block_t(262144);
+    pub const AMDSMI_GPU_BLOCK_LAST: amdsmi_gpu_block_t = amdsmi_gpu_block_t(262144);
+    pub const AMDSMI_GPU_BLOCK_RESERVED: amdsmi_gpu_block_t =
+        amdsmi_gpu_block_t(9223372036854775808);
+}
+#[repr(transparent)]
+#[doc = "```text\n @brief This enum is used to identify different GPU blocks.\n\n @cond @tag{gpu_bm_linux} @tag{host} @endcond\n```"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct amdsmi_gpu_block_t(pub ::std::os::raw::c_ulong);
+impl amdsmi_cper_sev_t {
+    #[doc = "```text\n!< CPER Non-Fatal Uncorrected severity\n```"]
+    pub const AMDSMI_CPER_SEV_NON_FATAL_UNCORRECTED: amdsmi_cper_sev_t = amdsmi_cper_sev_t(0);
+    #[doc = "```text\n!< CPER Fatal severity\n```"]
+    pub const AMDSMI_CPER_SEV_FATAL: amdsmi_cper_sev_t = amdsmi_cper_sev_t(1);
+    #[doc = "```text\n!< CPER Non-Fatal Corrected severity\n```"]
+    pub const AMDSMI_CPER_SEV_NON_FATAL_CORRECTED: amdsmi_cper_sev_t = amdsmi_cper_sev_t(2);
+    #[doc = "```text\n!< CPER severity Number\n```"]
+    pub const AMDSMI_CPER_SEV_NUM: amdsmi_cper_sev_t = amdsmi_cper_sev_t(3);
+    #[doc = "```text\n!< CPER Unused severity\n```"]
+    pub const AMDSMI_CPER_SEV_UNUSED: amdsmi_cper_sev_t = amdsmi_cper_sev_t(10);
+}
+#[repr(transparent)]
+#[doc = "```text\n @brief Cper sev\n\n @cond @tag{gpu_bm_linux} @tag{host} @endcond\n```"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct amdsmi_cper_sev_t(pub ::std::os::raw::c_uint);
+impl amdsmi_ras_err_state_t {
+    #[doc = "```text\n!< No current errors\n```"]
+    pub const AMDSMI_RAS_ERR_STATE_NONE: amdsmi_ras_err_state_t = amdsmi_ras_err_state_t(0);
+    #[doc = "```text\n!< ECC is disabled\n```"]
+    pub const AMDSMI_RAS_ERR_STATE_DISABLED: amdsmi_ras_err_state_t = amdsmi_ras_err_state_t(1);
+    #[doc = "```text\n!< ECC errors present, but type unknown\n```"]
+    pub const AMDSMI_RAS_ERR_STATE_PARITY: amdsmi_ras_err_state_t = amdsmi_ras_err_state_t(2);
+    #[doc = "```text\n!< Single correctable error\n```"]
+    pub const AMDSMI_RAS_ERR_STATE_SING_C: amdsmi_ras_err_state_t = amdsmi_ras_err_state_t(3);
+    #[doc = "```text\n!< Multiple uncorrectable errors\n```"]
+    pub const AMDSMI_RAS_ERR_STATE_MULT_UC: amdsmi_ras_err_state_t = amdsmi_ras_err_state_t(4);
+    #[doc = "```text\n< Firmware detected error and isolated\npage. Treat as uncorrectable\n```"]
+    pub const AMDSMI_RAS_ERR_STATE_POISON: amdsmi_ras_err_state_t = amdsmi_ras_err_state_t(5);
+    #[doc = "```text\n!< ECC is enabled\n```"]
+    pub const AMDSMI_RAS_ERR_STATE_ENABLED: amdsmi_ras_err_state_t = amdsmi_ras_err_state_t(6);
+    pub const AMDSMI_RAS_ERR_STATE_LAST: amdsmi_ras_err_state_t = amdsmi_ras_err_state_t(6);
+    pub const AMDSMI_RAS_ERR_STATE_INVALID: amdsmi_ras_err_state_t =
+        amdsmi_ras_err_state_t(4294967295);
+}
+#[repr(transparent)]
+#[doc = "```text\n @brief The current ECC state\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct amdsmi_ras_err_state_t(pub ::std::os::raw::c_uint);
+impl amdsmi_memory_type_t {
+    pub const AMDSMI_MEM_TYPE_FIRST: amdsmi_memory_type_t = amdsmi_memory_type_t(0);
+    #[doc = "```text\n!< VRAM memory\n```"]
+    pub const AMDSMI_MEM_TYPE_VRAM: amdsmi_memory_type_t = amdsmi_memory_type_t(0);
+    #[doc = "```text\n!< VRAM memory that is visible\n```"]
+    pub const AMDSMI_MEM_TYPE_VIS_VRAM: amdsmi_memory_type_t = amdsmi_memory_type_t(1);
+    #[doc = "```text\n!< GTT memory\n```"]
+    pub const AMDSMI_MEM_TYPE_GTT: amdsmi_memory_type_t = amdsmi_memory_type_t(2);
     pub const AMDSMI_MEM_TYPE_LAST: amdsmi_memory_type_t = amdsmi_memory_type_t(2);
 }
 #[repr(transparent)]
 #[doc = "```text\n @brief Types of memory\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
 pub struct amdsmi_memory_type_t(pub ::std::os::raw::c_uint);
+impl amdsmi_xgmi_status_t {
+    #[doc = "```text\n!< XGMI No Errors\n```"]
+    pub const AMDSMI_XGMI_STATUS_NO_ERRORS: amdsmi_xgmi_status_t = amdsmi_xgmi_status_t(0);
+    #[doc = "```text\n!< XGMI Errors\n```"]
+    pub const AMDSMI_XGMI_STATUS_ERROR: amdsmi_xgmi_status_t = amdsmi_xgmi_status_t(1);
+    #[doc = "```text\n!< XGMI Multiple Errors\n```"]
+    pub const AMDSMI_XGMI_STATUS_MULTIPLE_ERRORS: amdsmi_xgmi_status_t = amdsmi_xgmi_status_t(2);
+}
+#[repr(transparent)]
+#[doc = "```text\n @brief XGMI Status\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct amdsmi_xgmi_status_t(pub ::std::os::raw::c_uint);
+#[doc = "```text\n @brief Bitfield used in various AMDSMI calls\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+pub type amdsmi_bit_field_t = u64;
+impl amdsmi_memory_page_status_t {
+    #[doc = "```text\n!< Reserved. This gpu page is reserved and not available for use\n```"]
+    pub const AMDSMI_MEM_PAGE_STATUS_RESERVED: amdsmi_memory_page_status_t =
+        amdsmi_memory_page_status_t(0);
+    #[doc = "```text\n< Pending. This gpu page is marked as bad and will be marked\nreserved at the next window\n```"]
+    pub const AMDSMI_MEM_PAGE_STATUS_PENDING: amdsmi_memory_page_status_t =
+        amdsmi_memory_page_status_t(1);
+    #[doc = "```text\n!< Unable to reserve this page\n```"]
+    pub const AMDSMI_MEM_PAGE_STATUS_UNRESERVABLE: amdsmi_memory_page_status_t =
+        amdsmi_memory_page_status_t(2);
+}
+#[repr(transparent)]
+#[doc = "```text\n @brief Reserved Memory Page States\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct amdsmi_memory_page_status_t(pub ::std::os::raw::c_uint);
+impl amdsmi_utilization_counter_type_t {
+    pub const AMDSMI_UTILIZATION_COUNTER_FIRST: amdsmi_utilization_counter_type_t =
+        amdsmi_utilization_counter_type_t(0);
+    #[doc = "```text\n!< Course Grain Graphic Activity\n```"]
+    pub const AMDSMI_COARSE_GRAIN_GFX_ACTIVITY: amdsmi_utilization_counter_type_t =
+        amdsmi_utilization_counter_type_t(0);
+    #[doc = "```text\n!< Course Grain Memory Activity\n```"]
+    pub const AMDSMI_COARSE_GRAIN_MEM_ACTIVITY: amdsmi_utilization_counter_type_t =
+        amdsmi_utilization_counter_type_t(1);
+    #[doc = "```text\n!< Course Grain Decoder Activity\n```"]
+    pub const AMDSMI_COARSE_DECODER_ACTIVITY: amdsmi_utilization_counter_type_t =
+        amdsmi_utilization_counter_type_t(2);
+    #[doc = "```text\n!< Fine Grain Graphic Activity\n```"]
+    pub const AMDSMI_FINE_GRAIN_GFX_ACTIVITY: amdsmi_utilization_counter_type_t =
+        amdsmi_utilization_counter_type_t(100);
+    #[doc = "```text\n!< Fine Grain Memory Activity\n```"]
+    pub const AMDSMI_FINE_GRAIN_MEM_ACTIVITY: amdsmi_utilization_counter_type_t =
+        amdsmi_utilization_counter_type_t(101);
+    #[doc = "```text\n!< Fine Grain Decoder Activity\n```"]
+    pub const AMDSMI_FINE_DECODER_ACTIVITY: amdsmi_utilization_counter_type_t =
+        amdsmi_utilization_counter_type_t(102);
+    pub const AMDSMI_UTILIZATION_COUNTER_LAST: amdsmi_utilization_counter_type_t =
+        amdsmi_utilization_counter_type_t(102);
+}
+#[repr(transparent)]
+#[doc = "```text\n @brief The utilization counter type\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct amdsmi_utilization_counter_type_t(pub ::std::os::raw::c_uint);
+#[doc = "```text\n @brief The utilization counter data\n\n The max number of values per counter type\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_utilization_counter_t {
+    #[doc = "```text\n!< Utilization counter type\n```"]
+    pub type_: amdsmi_utilization_counter_type_t,
+    #[doc = "```text\n!< Coarse grain activity counter value (average)\n```"]
+    pub value: u64,
+    #[doc = "```text\n!< Utilization counter value\n```"]
+    pub fine_value: [u64; 4usize],
+    pub fine_value_count: u16,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_utilization_counter_t"]
+        [::std::mem::size_of::<amdsmi_utilization_counter_t>() - 56usize];
+    ["Alignment of amdsmi_utilization_counter_t"]
+        [::std::mem::align_of::<amdsmi_utilization_counter_t>() - 8usize];
+    ["Offset of field: amdsmi_utilization_counter_t::type_"]
+        [::std::mem::offset_of!(amdsmi_utilization_counter_t, type_) - 0usize];
+    ["Offset of field: amdsmi_utilization_counter_t::value"]
+        [::std::mem::offset_of!(amdsmi_utilization_counter_t, value) - 8usize];
+    ["Offset of field: amdsmi_utilization_counter_t::fine_value"]
+        [::std::mem::offset_of!(amdsmi_utilization_counter_t, fine_value) - 16usize];
+    ["Offset of field: amdsmi_utilization_counter_t::fine_value_count"]
+        [::std::mem::offset_of!(amdsmi_utilization_counter_t, fine_value_count) - 48usize];
+};
+#[doc = "```text\n @brief Reserved Memory Page Record\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_retired_page_record_t {
+    #[doc = "```text\n!< Start address of page\n```"]
+    pub page_address: u64,
+    #[doc = "```text\n!< Page size\n```"]
+    pub page_size: u64,
+    #[doc = "```text\n!< Page \"reserved\" status\n```"]
+    pub status: amdsmi_memory_page_status_t,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_retired_page_record_t"]
+        [::std::mem::size_of::<amdsmi_retired_page_record_t>() - 24usize];
+    ["Alignment of amdsmi_retired_page_record_t"]
+        [::std::mem::align_of::<amdsmi_retired_page_record_t>() - 8usize];
+    ["Offset of field: amdsmi_retired_page_record_t::page_address"]
+        [::std::mem::offset_of!(amdsmi_retired_page_record_t, page_address) - 0usize];
+    ["Offset of field: amdsmi_retired_page_record_t::page_size"]
+        [::std::mem::offset_of!(amdsmi_retired_page_record_t, page_size) - 8usize];
+    ["Offset of field: amdsmi_retired_page_record_t::status"]
+        [::std::mem::offset_of!(amdsmi_retired_page_record_t, status) - 16usize];
+};
+#[doc = "```text\n @brief This structure contains information about which power profiles are\n supported by the system for a given device, and which power profile is\n currently active.\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_power_profile_status_t {
+    #[doc = "```text\n!< Which profiles are supported by this system\n```"]
+    pub available_profiles: amdsmi_bit_field_t,
+    #[doc = "```text\n!< Which power profile is currently active\n```"]
+    pub current: amdsmi_power_profile_preset_masks_t,
+    #[doc = "```text\n!< How many power profiles are available\n```"]
+    pub num_profiles: u32,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_power_profile_status_t"]
+        [::std::mem::size_of::<amdsmi_power_profile_status_t>() - 24usize];
+    ["Alignment of amdsmi_power_profile_status_t"]
+        [::std::mem::align_of::<amdsmi_power_profile_status_t>() - 8usize];
+    ["Offset of field: amdsmi_power_profile_status_t::available_profiles"]
+        [::std::mem::offset_of!(amdsmi_power_profile_status_t, available_profiles) - 0usize];
+    ["Offset of field: amdsmi_power_profile_status_t::current"]
+        [::std::mem::offset_of!(amdsmi_power_profile_status_t, current) - 8usize];
+    ["Offset of field: amdsmi_power_profile_status_t::num_profiles"]
+        [::std::mem::offset_of!(amdsmi_power_profile_status_t, num_profiles) - 16usize];
+};
+#[doc = "```text\n @brief This structure holds information about clock frequencies.\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_frequencies_t {
+    #[doc = "```text\n!< Deep Sleep frequency is only supported by some GPUs\n```"]
+    pub has_deep_sleep: bool,
+    #[doc = "```text\n!< The number of supported frequencies\n```"]
+    pub num_supported: u32,
+    #[doc = "```text\n!< The current frequency index in MHz\n```"]
+    pub current: u32,
+    #[doc = "```text\n< List of frequencies in MHz.\nOnly the first num_supported frequencies are valid\n```"]
+    pub frequency: [u64; 33usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_frequencies_t"][::std::mem::size_of::<amdsmi_frequencies_t>() - 280usize];
+    ["Alignment of amdsmi_frequencies_t"][::std::mem::align_of::<amdsmi_frequencies_t>() - 8usize];
+    ["Offset of field: amdsmi_frequencies_t::has_deep_sleep"]
+        [::std::mem::offset_of!(amdsmi_frequencies_t, has_deep_sleep) - 0usize];
+    ["Offset of field: amdsmi_frequencies_t::num_supported"]
+        [::std::mem::offset_of!(amdsmi_frequencies_t, num_supported) - 4usize];
+    ["Offset of field: amdsmi_frequencies_t::current"]
+        [::std::mem::offset_of!(amdsmi_frequencies_t, current) - 8usize];
+    ["Offset of field: amdsmi_frequencies_t::frequency"]
+        [::std::mem::offset_of!(amdsmi_frequencies_t, frequency) - 16usize];
+};
+#[doc = "```text\n @brief The dpm policy.\n\n @cond @tag{gpu_bm_linux} @tag{host} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_dpm_policy_entry_t {
+    pub policy_id: u32,
+    pub policy_description: [::std::os::raw::c_char; 256usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_dpm_policy_entry_t"]
+        [::std::mem::size_of::<amdsmi_dpm_policy_entry_t>() - 260usize];
+    ["Alignment of amdsmi_dpm_policy_entry_t"]
+        [::std::mem::align_of::<amdsmi_dpm_policy_entry_t>() - 4usize];
+    ["Offset of field: amdsmi_dpm_policy_entry_t::policy_id"]
+        [::std::mem::offset_of!(amdsmi_dpm_policy_entry_t, policy_id) - 0usize];
+    ["Offset of field: amdsmi_dpm_policy_entry_t::policy_description"]
+        [::std::mem::offset_of!(amdsmi_dpm_policy_entry_t, policy_description) - 4usize];
+};
+#[doc = "```text\n @brief DPM Policy\n\n Only the first num_supported policies are valid.\n\n @cond @tag{gpu_bm_linux} @tag{host} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_dpm_policy_t {
+    #[doc = "```text\n!< The number of supported policies\n```"]
+    pub num_supported: u32,
+    #[doc = "```text\n!< The current policy index\n```"]
+    pub current: u32,
+    #[doc = "```text\n!< List of policies.\n```"]
+    pub policies: [amdsmi_dpm_policy_entry_t; 32usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_dpm_policy_t"][::std::mem::size_of::<amdsmi_dpm_policy_t>() - 8328usize];
+    ["Alignment of amdsmi_dpm_policy_t"][::std::mem::align_of::<amdsmi_dpm_policy_t>() - 4usize];
+    ["Offset of field: amdsmi_dpm_policy_t::num_supported"]
+        [::std::mem::offset_of!(amdsmi_dpm_policy_t, num_supported) - 0usize];
+    ["Offset of field: amdsmi_dpm_policy_t::current"]
+        [::std::mem::offset_of!(amdsmi_dpm_policy_t, current) - 4usize];
+    ["Offset of field: amdsmi_dpm_policy_t::policies"]
+        [::std::mem::offset_of!(amdsmi_dpm_policy_t, policies) - 8usize];
+};
+#[doc = "```text\n @brief This structure holds version information.\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_version_t {
+    #[doc = "```text\n!< Major version\n```"]
+    pub major: u32,
+    #[doc = "```text\n!< Minor version\n```"]
+    pub minor: u32,
+    #[doc = "```text\n!< Patch, build or stepping version\n```"]
+    pub release: u32,
+    #[doc = "```text\n!< Full Build version string\n```"]
+    pub build: *const ::std::os::raw::c_char,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_version_t"][::std::mem::size_of::<amdsmi_version_t>() - 24usize];
+    ["Alignment of amdsmi_version_t"][::std::mem::align_of::<amdsmi_version_t>() - 8usize];
+    ["Offset of field: amdsmi_version_t::major"]
+        [::std::mem::offset_of!(amdsmi_version_t, major) - 0usize];
+    ["Offset of field: amdsmi_version_t::minor"]
+        [::std::mem::offset_of!(amdsmi_version_t, minor) - 4usize];
+    ["Offset of field: amdsmi_version_t::release"]
+        [::std::mem::offset_of!(amdsmi_version_t, release) - 8usize];
+    ["Offset of field: amdsmi_version_t::build"]
+        [::std::mem::offset_of!(amdsmi_version_t, build) - 16usize];
+};
+#[doc = "```text\n @brief This structure represents a point on the frequency-voltage plane.\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_od_vddc_point_t {
+    #[doc = "```text\n!< Frequency coordinate (in Hz)\n```"]
+    pub frequency: u64,
+    #[doc = "```text\n!< Voltage coordinate (in mV)\n```"]
+    pub voltage: u64,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_od_vddc_point_t"][::std::mem::size_of::<amdsmi_od_vddc_point_t>() - 16usize];
+    ["Alignment of amdsmi_od_vddc_point_t"]
+        [::std::mem::align_of::<amdsmi_od_vddc_point_t>() - 8usize];
+    ["Offset of field: amdsmi_od_vddc_point_t::frequency"]
+        [::std::mem::offset_of!(amdsmi_od_vddc_point_t, frequency) - 0usize];
+    ["Offset of field: amdsmi_od_vddc_point_t::voltage"]
+        [::std::mem::offset_of!(amdsmi_od_vddc_point_t, voltage) - 8usize];
+};
+#[doc = "```text\n @brief OD Vold Curve\n ::AMDSMI_NUM_VOLTAGE_CURVE_POINTS number of ::amdsmi_od_vddc_point_t's\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_od_volt_curve_t {
+    #[doc = "```text\n!< make up the voltage frequency curve points.\n```"]
+    pub vc_points: [amdsmi_od_vddc_point_t; 3usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_od_volt_curve_t"][::std::mem::size_of::<amdsmi_od_volt_curve_t>() - 48usize];
+    ["Alignment of amdsmi_od_volt_curve_t"]
+        [::std::mem::align_of::<amdsmi_od_volt_curve_t>() - 8usize];
+    ["Offset of field: amdsmi_od_volt_curve_t::vc_points"]
+        [::std::mem::offset_of!(amdsmi_od_volt_curve_t, vc_points) - 0usize];
+};
+#[doc = "```text\n @brief This structure holds the frequency-voltage values for a device.\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_od_volt_freq_data_t {
+    #[doc = "```text\n!< The current SCLK frequency range in MHz\n```"]
+    pub curr_sclk_range: amdsmi_range_t,
+    #[doc = "```text\n!< The current MCLK frequency range, upper bound only in MHz\n```"]
+    pub curr_mclk_range: amdsmi_range_t,
+    #[doc = "```text\n!< The range possible of SCLK values in MHz\n```"]
+    pub sclk_freq_limits: amdsmi_range_t,
+    #[doc = "```text\n!< The range possible of MCLK values in MHz\n```"]
+    pub mclk_freq_limits: amdsmi_range_t,
+    #[doc = "```text\n!< The current voltage curve\n```"]
+    pub curve: amdsmi_od_volt_curve_t,
+    #[doc = "```text\n!< The number of voltage curve regions\n```"]
+    pub num_regions: u32,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_od_volt_freq_data_t"]
+        [::std::mem::size_of::<amdsmi_od_volt_freq_data_t>() - 184usize];
+    ["Alignment of amdsmi_od_volt_freq_data_t"]
+        [::std::mem::align_of::<amdsmi_od_volt_freq_data_t>() - 8usize];
+    ["Offset of field: amdsmi_od_volt_freq_data_t::curr_sclk_range"]
+        [::std::mem::offset_of!(amdsmi_od_volt_freq_data_t, curr_sclk_range) - 0usize];
+    ["Offset of field: amdsmi_od_volt_freq_data_t::curr_mclk_range"]
+        [::std::mem::offset_of!(amdsmi_od_volt_freq_data_t, curr_mclk_range) - 32usize];
+    ["Offset of field: amdsmi_od_volt_freq_data_t::sclk_freq_limits"]
+        [::std::mem::offset_of!(amdsmi_od_volt_freq_data_t, sclk_freq_limits) - 64usize];
+    ["Offset of field: amdsmi_od_volt_freq_data_t::mclk_freq_limits"]
+        [::std::mem::offset_of!(amdsmi_od_volt_freq_data_t, mclk_freq_limits) - 96usize];
+    ["Offset of field: amdsmi_od_volt_freq_data_t::curve"]
+        [::std::mem::offset_of!(amdsmi_od_volt_freq_data_t, curve) - 128usize];
+    ["Offset of field: amdsmi_od_volt_freq_data_t::num_regions"]
+        [::std::mem::offset_of!(amdsmi_od_volt_freq_data_t, num_regions) - 176usize];
+};
+#[doc = "```text\n @brief Structure holds the gpu metrics table header for a device\n\n Size and version information of metrics data\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amd_metrics_table_header_t {
+    #[doc = "```text\n \\cond Ignore in docs.\n```"]
+    pub structure_size: u16,
+    pub format_revision: u8,
+    pub content_revision: u8,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amd_metrics_table_header_t"]
+        [::std::mem::size_of::<amd_metrics_table_header_t>() - 4usize];
+    ["Alignment of amd_metrics_table_header_t"]
+        [::std::mem::align_of::<amd_metrics_table_header_t>() - 2usize];
+    ["Offset of field: amd_metrics_table_header_t::structure_size"]
+        [::std::mem::offset_of!(amd_metrics_table_header_t, structure_size) - 0usize];
+    ["Offset of field: amd_metrics_table_header_t::format_revision"]
+        [::std::mem::offset_of!(amd_metrics_table_header_t, format_revision) - 2usize];
+    ["Offset of field: amd_metrics_table_header_t::content_revision"]
+        [::std::mem::offset_of!(amd_metrics_table_header_t, content_revision) - 3usize];
+};
+#[doc = "```text\n @brief The following structures hold the gpu statistics for a device.\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_gpu_xcp_metrics_t {
+    #[doc = "```text\n!< Utilization Instantaneous in %\n```"]
+    pub gfx_busy_inst: [u32; 8usize],
+    #[doc = "```text\n!< Utilization Instantaneous in % (UPDATED: to 40 in v1.8)\n```"]
+    pub jpeg_busy: [u16; 40usize],
+    #[doc = "```text\n!< Utilization Instantaneous in %\n```"]
+    pub vcn_busy: [u16; 4usize],
+    #[doc = "```text\n!< Utilization Accumulated in %\n```"]
+    pub gfx_busy_acc: [u64; 8usize],
+    #[doc = "```text\n!< Total App Clock Counter Accumulated\n```"]
+    pub gfx_below_host_limit_acc: [u64; 8usize],
+    pub gfx_below_host_limit_ppt_acc: [u64; 8usize],
+    pub gfx_below_host_limit_thm_acc: [u64; 8usize],
+    pub gfx_low_utilization_acc: [u64; 8usize],
+    pub gfx_below_host_limit_total_acc: [u64; 8usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_gpu_xcp_metrics_t"]
+        [::std::mem::size_of::<amdsmi_gpu_xcp_metrics_t>() - 504usize];
+    ["Alignment of amdsmi_gpu_xcp_metrics_t"]
+        [::std::mem::align_of::<amdsmi_gpu_xcp_metrics_t>() - 8usize];
+    ["Offset of field: amdsmi_gpu_xcp_metrics_t::gfx_busy_inst"]
+        [::std::mem::offset_of!(amdsmi_gpu_xcp_metrics_t, gfx_busy_inst) - 0usize];
+    ["Offset of field: amdsmi_gpu_xcp_metrics_t::jpeg_busy"]
+        [::std::mem::offset_of!(amdsmi_gpu_xcp_metrics_t, jpeg_busy) - 32usize];
+    ["Offset of field: amdsmi_gpu_xcp_metrics_t::vcn_busy"]
+        [::std::mem::offset_of!(amdsmi_gpu_xcp_metrics_t, vcn_busy) - 112usize];
+    ["Offset of field: amdsmi_gpu_xcp_metrics_t::gfx_busy_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_xcp_metrics_t, gfx_busy_acc) - 120usize];
+    ["Offset of field: amdsmi_gpu_xcp_metrics_t::gfx_below_host_limit_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_xcp_metrics_t, gfx_below_host_limit_acc) - 184usize];
+    ["Offset of field: amdsmi_gpu_xcp_metrics_t::gfx_below_host_limit_ppt_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_xcp_metrics_t, gfx_below_host_limit_ppt_acc) - 248usize];
+    ["Offset of field: amdsmi_gpu_xcp_metrics_t::gfx_below_host_limit_thm_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_xcp_metrics_t, gfx_below_host_limit_thm_acc) - 312usize];
+    ["Offset of field: amdsmi_gpu_xcp_metrics_t::gfx_low_utilization_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_xcp_metrics_t, gfx_low_utilization_acc) - 376usize];
+    ["Offset of field: amdsmi_gpu_xcp_metrics_t::gfx_below_host_limit_total_acc"][::std::mem::offset_of!(
+        amdsmi_gpu_xcp_metrics_t,
+        gfx_below_host_limit_total_acc
+    ) - 440usize];
+};
+#[doc = "```text\n @brief Structure holds the gpu metrics values for a device\n\n This structure is extended to fit the needs of different GPU metric\n versions when exposing data through the structure.\n Depending on the version, some data members will hold data, and\n some will not. A good example is the set of 'current clocks':\n current_gfxclk, current_socclk, current_vclk0, current_dclk0.\n These are single-valued data members, up to version 1.3.\n For version 1.4 and up these are multi-valued data members (arrays)\n and their counterparts; current_gfxclks[], current_socclks[],\n current_vclk0s[], current_dclk0s[], will hold the data\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_gpu_metrics_t {
+    pub common_header: amd_metrics_table_header_t,
+    #[doc = "```text\n @brief v1.0 Base\n\n Temperature in C\n```"]
+    pub temperature_edge: u16,
+    pub temperature_hotspot: u16,
+    pub temperature_mem: u16,
+    pub temperature_vrgfx: u16,
+    pub temperature_vrsoc: u16,
+    pub temperature_vrmem: u16,
+    #[doc = "```text\n!< gfx\n```"]
+    pub average_gfx_activity: u16,
+    #[doc = "```text\n!< memory controller\n```"]
+    pub average_umc_activity: u16,
+    #[doc = "```text\n!< UVD or VCN\n```"]
+    pub average_mm_activity: u16,
+    #[doc = "```text\n @brief Power (W) /Energy (15.259uJ per 1ns)\n```"]
+    pub average_socket_power: u16,
+    #[doc = "```text\n!< v1 mod. (32->64)\n```"]
+    pub energy_accumulator: u64,
+    #[doc = "```text\n!< v1 mod. (moved from top of struct)\n```"]
+    pub system_clock_counter: u64,
+    #[doc = "```text\n @brief Average clocks (MHz)\n```"]
+    pub average_gfxclk_frequency: u16,
+    pub average_socclk_frequency: u16,
+    pub average_uclk_frequency: u16,
+    pub average_vclk0_frequency: u16,
+    pub average_dclk0_frequency: u16,
+    pub average_vclk1_frequency: u16,
+    pub average_dclk1_frequency: u16,
+    #[doc = "```text\n @brief Current clocks (MHz)\n```"]
+    pub current_gfxclk: u16,
+    pub current_socclk: u16,
+    pub current_uclk: u16,
+    pub current_vclk0: u16,
+    pub current_dclk0: u16,
+    pub current_vclk1: u16,
+    pub current_dclk1: u16,
+    #[doc = "```text\n!< Throttle status\n```"]
+    pub throttle_status: u32,
+    #[doc = "```text\n!< Fans (RPM)\n```"]
+    pub current_fan_speed: u16,
+    #[doc = "```text\n!< v1 mod.(8->16)\n```"]
+    pub pcie_link_width: u16,
+    #[doc = "```text\n!< in 0.1 GT/s; v1 mod. (8->16)\n```"]
+    pub pcie_link_speed: u16,
+    #[doc = "```text\n!< new in v1\n```"]
+    pub gfx_activity_acc: u32,
+    #[doc = "```text\n!< new in v1\n```"]
+    pub mem_activity_acc: u32,
+    #[doc = "```text\n!< new in v1\n```"]
+    pub temperature_hbm: [u16; 4usize],
+    #[doc = "```text\n!< PMFW attached timestamp (10ns resolution)\n```"]
+    pub firmware_timestamp: u64,
+    #[doc = "```text\n!< In mV\n```"]
+    pub voltage_soc: u16,
+    #[doc = "```text\n!< In mV\n```"]
+    pub voltage_gfx: u16,
+    #[doc = "```text\n!< In mV\n```"]
+    pub voltage_mem: u16,
+    #[doc = "```text\n!< Throttle status\n```"]
+    pub indep_throttle_status: u64,
+    #[doc = "```text\n!< In Watts\n```"]
+    pub current_socket_power: u16,
+    #[doc = "```text\n!< Utilization (%)\n```"]
+    pub vcn_activity: [u16; 4usize],
+    #[doc = "```text\n!< Clock Lock Status. Each bit corresponds to clock instance\n```"]
+    pub gfxclk_lock_status: u32,
+    #[doc = "```text\n!< XGMI bus width in GB/s\n```"]
+    pub xgmi_link_width: u16,
+    #[doc = "```text\n!< XGMI bus bitrate in GB/s\n```"]
+    pub xgmi_link_speed: u16,
+    #[doc = "```text\n!< PCIE accumulated bandwidth (GB/sec)\n```"]
+    pub pcie_bandwidth_acc: u64,
+    #[doc = "```text\n!< PCIE instantaneous bandwidth (GB/sec)\n```"]
+    pub pcie_bandwidth_inst: u64,
+    #[doc = "```text\n!< PCIE L0 to recovery state transition accumulated count\n```"]
+    pub pcie_l0_to_recov_count_acc: u64,
+    #[doc = "```text\n!< PCIE replay accumulated count\n```"]
+    pub pcie_replay_count_acc: u64,
+    #[doc = "```text\n!< PCIE replay rollover accumulated count\n```"]
+    pub pcie_replay_rover_count_acc: u64,
+    #[doc = "```text\n!< In KB\n```"]
+    pub xgmi_read_data_acc: [u64; 8usize],
+    #[doc = "```text\n!< In KB\n```"]
+    pub xgmi_write_data_acc: [u64; 8usize],
+    #[doc = "```text\n!< In KB\n```"]
+    pub current_gfxclks: [u16; 8usize],
+    #[doc = "```text\n!< In KB\n```"]
+    pub current_socclks: [u16; 4usize],
+    #[doc = "```text\n!< In KB\n```"]
+    pub current_vclk0s: [u16; 4usize],
+    #[doc = "```text\n!< In KB\n```"]
+    pub current_dclk0s: [u16; 4usize],
+    #[doc = "```text\n!< JPEG activity percent (encode/decode)\n```"]
+    pub jpeg_activity: [u16; 32usize],
+    #[doc = "```text\n!< PCIE NAK sent accumulated count\n```"]
+    pub pcie_nak_sent_count_acc: u32,
+    #[doc = "```text\n!< PCIE NAK received accumulated count\n```"]
+    pub pcie_nak_rcvd_count_acc: u32,
+    #[doc = "```text\n!< Accumulation cycle counter\n```"]
+    pub accumulation_counter: u64,
+    #[doc = "```text\n @brief Accumulated throttler residencies\n```"]
+    pub prochot_residency_acc: u64,
+    #[doc = "```text\n @brief Accumulated throttler residencies\n\n Prochot (thermal) - PPT (power)\n Package Power Tracking (PPT) violation % (greater than 0% is a violation);\n aka PVIOL\n\n Ex. PVIOL/TVIOL calculations\n Where A and B are measurments recorded at prior points in time.\n Typically A is the earlier measured value and B is the latest measured value.\n\n PVIOL % = (PptResidencyAcc (B) - PptResidencyAcc (A)) * 100/ (AccumulationCounter (B) - AccumulationCounter (A))\n TVIOL % = (SocketThmResidencyAcc (B) -  SocketThmResidencyAcc (A)) * 100 / (AccumulationCounter (B) - AccumulationCounter (A))\n```"]
+    pub ppt_residency_acc: u64,
+    #[doc = "```text\n @brief Accumulated throttler residencies\n\n Socket (thermal) -\n Socket thermal violation % (greater than 0% is a violation);\n aka TVIOL\n\n Ex. PVIOL/TVIOL calculations\n Where A and B are measurments recorded at prior points in time.\n Typically A is the earlier measured value and B is the latest measured value.\n\n PVIOL % = (PptResidencyAcc (B) - PptResidencyAcc (A)) * 100/ (AccumulationCounter (B) - AccumulationCounter (A))\n TVIOL % = (SocketThmResidencyAcc (B) -  SocketThmResidencyAcc (A)) * 100 / (AccumulationCounter (B) - AccumulationCounter (A))\n```"]
+    pub socket_thm_residency_acc: u64,
+    pub vr_thm_residency_acc: u64,
+    pub hbm_thm_residency_acc: u64,
+    #[doc = "```text\n!< Number of current partition\n```"]
+    pub num_partition: u16,
+    #[doc = "```text\n!< XCP (Graphic Cluster Partitions) metrics stats\n```"]
+    pub xcp_stats: [amdsmi_gpu_xcp_metrics_t; 8usize],
+    #[doc = "```text\n!< PCIE other end recovery counter\n```"]
+    pub pcie_lc_perf_other_end_recovery: u32,
+    #[doc = "```text\n!< VRAM max bandwidth at max memory clock (GB/s)\n```"]
+    pub vram_max_bandwidth: u64,
+    #[doc = "```text\n!< XGMI link status(up/down)\n```"]
+    pub xgmi_link_status: [u16; 8usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_gpu_metrics_t"][::std::mem::size_of::<amdsmi_gpu_metrics_t>() - 4544usize];
+    ["Alignment of amdsmi_gpu_metrics_t"][::std::mem::align_of::<amdsmi_gpu_metrics_t>() - 8usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::common_header"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, common_header) - 0usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::temperature_edge"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, temperature_edge) - 4usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::temperature_hotspot"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, temperature_hotspot) - 6usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::temperature_mem"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, temperature_mem) - 8usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::temperature_vrgfx"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, temperature_vrgfx) - 10usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::temperature_vrsoc"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, temperature_vrsoc) - 12usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::temperature_vrmem"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, temperature_vrmem) - 14usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::average_gfx_activity"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, average_gfx_activity) - 16usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::average_umc_activity"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, average_umc_activity) - 18usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::average_mm_activity"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, average_mm_activity) - 20usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::average_socket_power"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, average_socket_power) - 22usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::energy_accumulator"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, energy_accumulator) - 24usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::system_clock_counter"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, system_clock_counter) - 32usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::average_gfxclk_frequency"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, average_gfxclk_frequency) - 40usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::average_socclk_frequency"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, average_socclk_frequency) - 42usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::average_uclk_frequency"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, average_uclk_frequency) - 44usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::average_vclk0_frequency"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, average_vclk0_frequency) - 46usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::average_dclk0_frequency"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, average_dclk0_frequency) - 48usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::average_vclk1_frequency"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, average_vclk1_frequency) - 50usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::average_dclk1_frequency"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, average_dclk1_frequency) - 52usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::current_gfxclk"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, current_gfxclk) - 54usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::current_socclk"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, current_socclk) - 56usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::current_uclk"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, current_uclk) - 58usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::current_vclk0"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, current_vclk0) - 60usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::current_dclk0"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, current_dclk0) - 62usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::current_vclk1"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, current_vclk1) - 64usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::current_dclk1"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, current_dclk1) - 66usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::throttle_status"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, throttle_status) - 68usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::current_fan_speed"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, current_fan_speed) - 72usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::pcie_link_width"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, pcie_link_width) - 74usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::pcie_link_speed"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, pcie_link_speed) - 76usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::gfx_activity_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, gfx_activity_acc) - 80usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::mem_activity_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, mem_activity_acc) - 84usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::temperature_hbm"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, temperature_hbm) - 88usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::firmware_timestamp"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, firmware_timestamp) - 96usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::voltage_soc"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, voltage_soc) - 104usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::voltage_gfx"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, voltage_gfx) - 106usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::voltage_mem"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, voltage_mem) - 108usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::indep_throttle_status"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, indep_throttle_status) - 112usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::current_socket_power"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, current_socket_power) - 120usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::vcn_activity"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, vcn_activity) - 122usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::gfxclk_lock_status"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, gfxclk_lock_status) - 132usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::xgmi_link_width"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, xgmi_link_width) - 136usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::xgmi_link_speed"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, xgmi_link_speed) - 138usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::pcie_bandwidth_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, pcie_bandwidth_acc) - 144usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::pcie_bandwidth_inst"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, pcie_bandwidth_inst) - 152usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::pcie_l0_to_recov_count_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, pcie_l0_to_recov_count_acc) - 160usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::pcie_replay_count_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, pcie_replay_count_acc) - 168usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::pcie_replay_rover_count_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, pcie_replay_rover_count_acc) - 176usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::xgmi_read_data_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, xgmi_read_data_acc) - 184usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::xgmi_write_data_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, xgmi_write_data_acc) - 248usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::current_gfxclks"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, current_gfxclks) - 312usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::current_socclks"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, current_socclks) - 328usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::current_vclk0s"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, current_vclk0s) - 336usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::current_dclk0s"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, current_dclk0s) - 344usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::jpeg_activity"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, jpeg_activity) - 352usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::pcie_nak_sent_count_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, pcie_nak_sent_count_acc) - 416usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::pcie_nak_rcvd_count_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, pcie_nak_rcvd_count_acc) - 420usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::accumulation_counter"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, accumulation_counter) - 424usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::prochot_residency_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, prochot_residency_acc) - 432usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::ppt_residency_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, ppt_residency_acc) - 440usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::socket_thm_residency_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, socket_thm_residency_acc) - 448usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::vr_thm_residency_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, vr_thm_residency_acc) - 456usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::hbm_thm_residency_acc"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, hbm_thm_residency_acc) - 464usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::num_partition"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, num_partition) - 472usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::xcp_stats"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, xcp_stats) - 480usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::pcie_lc_perf_other_end_recovery"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, pcie_lc_perf_other_end_recovery) - 4512usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::vram_max_bandwidth"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, vram_max_bandwidth) - 4520usize];
+    ["Offset of field: amdsmi_gpu_metrics_t::xgmi_link_status"]
+        [::std::mem::offset_of!(amdsmi_gpu_metrics_t, xgmi_link_status) - 4528usize];
+};
+impl amdsmi_xgmi_link_status_type_t {
+    #[doc = "```text\n!< XGMI link status is down\n```"]
+    pub const AMDSMI_XGMI_LINK_DOWN: amdsmi_xgmi_link_status_type_t =
+        amdsmi_xgmi_link_status_type_t(0);
+    #[doc = "```text\n!< XGMI link status is up\n```"]
+    pub const AMDSMI_XGMI_LINK_UP: amdsmi_xgmi_link_status_type_t =
+        amdsmi_xgmi_link_status_type_t(1);
+    #[doc = "```text\n!< XGMI link status is disabled\n```"]
+    pub const AMDSMI_XGMI_LINK_DISABLE: amdsmi_xgmi_link_status_type_t =
+        amdsmi_xgmi_link_status_type_t(2);
+}
+#[repr(transparent)]
+#[doc = "```text\n @brief XGMI Link Status Type\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct amdsmi_xgmi_link_status_type_t(pub ::std::os::raw::c_uint);
+#[doc = "```text\n @brief XGMI Link Status\n\n @cond @tag{gpu_bm_linux} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_xgmi_link_status_t {
+    #[doc = "```text\n!< The total links in the status array\n```"]
+    pub total_links: u32,
+    pub status: [amdsmi_xgmi_link_status_type_t; 8usize],
+    pub reserved: [u64; 7usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_xgmi_link_status_t"]
+        [::std::mem::size_of::<amdsmi_xgmi_link_status_t>() - 96usize];
+    ["Alignment of amdsmi_xgmi_link_status_t"]
+        [::std::mem::align_of::<amdsmi_xgmi_link_status_t>() - 8usize];
+    ["Offset of field: amdsmi_xgmi_link_status_t::total_links"]
+        [::std::mem::offset_of!(amdsmi_xgmi_link_status_t, total_links) - 0usize];
+    ["Offset of field: amdsmi_xgmi_link_status_t::status"]
+        [::std::mem::offset_of!(amdsmi_xgmi_link_status_t, status) - 4usize];
+    ["Offset of field: amdsmi_xgmi_link_status_t::reserved"]
+        [::std::mem::offset_of!(amdsmi_xgmi_link_status_t, reserved) - 40usize];
+};
+#[doc = "```text\n @brief This structure holds ras feature\n\n @cond @tag{gpu_bm_linux} @platform{guest_windows} @tag{host} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_ras_feature_t {
+    #[doc = "```text\n< PARITY error(bit 0), Single Bit correctable (bit1),\nDouble bit error detection (bit2), Poison (bit 3).\n```"]
+    pub ras_eeprom_version: u32,
+    #[doc = "```text\n< ecc_correction_schema mask.\nPARITY error(bit 0), Single Bit correctable (bit1),\nDouble bit error detection (bit2), Poison (bit 3)\n```"]
+    pub ecc_correction_schema_flag: u32,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_ras_feature_t"][::std::mem::size_of::<amdsmi_ras_feature_t>() - 8usize];
+    ["Alignment of amdsmi_ras_feature_t"][::std::mem::align_of::<amdsmi_ras_feature_t>() - 4usize];
+    ["Offset of field: amdsmi_ras_feature_t::ras_eeprom_version"]
+        [::std::mem::offset_of!(amdsmi_ras_feature_t, ras_eeprom_version) - 0usize];
+    ["Offset of field: amdsmi_ras_feature_t::ecc_correction_schema_flag"]
+        [::std::mem::offset_of!(amdsmi_ras_feature_t, ecc_correction_schema_flag) - 4usize];
+};
+#[doc = "```text\n @brief This structure holds error counts.\n\n @cond @tag{gpu_bm_linux} @tag{guest_windows} @tag{host} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_error_count_t {
+    #[doc = "```text\n!< Accumulated correctable errors\n```"]
+    pub correctable_count: u64,
+    #[doc = "```text\n!< Accumulated uncorrectable errors\n```"]
+    pub uncorrectable_count: u64,
+    #[doc = "```text\n!< Accumulated deferred errors\n```"]
+    pub deferred_count: u64,
+    pub reserved: [u64; 5usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_error_count_t"][::std::mem::size_of::<amdsmi_error_count_t>() - 64usize];
+    ["Alignment of amdsmi_error_count_t"][::std::mem::align_of::<amdsmi_error_count_t>() - 8usize];
+    ["Offset of field: amdsmi_error_count_t::correctable_count"]
+        [::std::mem::offset_of!(amdsmi_error_count_t, correctable_count) - 0usize];
+    ["Offset of field: amdsmi_error_count_t::uncorrectable_count"]
+        [::std::mem::offset_of!(amdsmi_error_count_t, uncorrectable_count) - 8usize];
+    ["Offset of field: amdsmi_error_count_t::deferred_count"]
+        [::std::mem::offset_of!(amdsmi_error_count_t, deferred_count) - 16usize];
+    ["Offset of field: amdsmi_error_count_t::reserved"]
+        [::std::mem::offset_of!(amdsmi_error_count_t, reserved) - 24usize];
+};
+#[doc = "```text\n @brief This structure holds SMU Firmware version information.\n\n @cond @tag{cpu_bm} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_smu_fw_version_t {
+    #[doc = "```text\n!< SMU fw Debug version number\n```"]
+    pub debug: u8,
+    #[doc = "```text\n!< SMU fw Minor version number\n```"]
+    pub minor: u8,
+    #[doc = "```text\n!< SMU fw Major version number\n```"]
+    pub major: u8,
+    #[doc = "```text\n!< reserved fields\n```"]
+    pub unused: u8,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_smu_fw_version_t"][::std::mem::size_of::<amdsmi_smu_fw_version_t>() - 4usize];
+    ["Alignment of amdsmi_smu_fw_version_t"]
+        [::std::mem::align_of::<amdsmi_smu_fw_version_t>() - 1usize];
+    ["Offset of field: amdsmi_smu_fw_version_t::debug"]
+        [::std::mem::offset_of!(amdsmi_smu_fw_version_t, debug) - 0usize];
+    ["Offset of field: amdsmi_smu_fw_version_t::minor"]
+        [::std::mem::offset_of!(amdsmi_smu_fw_version_t, minor) - 1usize];
+    ["Offset of field: amdsmi_smu_fw_version_t::major"]
+        [::std::mem::offset_of!(amdsmi_smu_fw_version_t, major) - 2usize];
+    ["Offset of field: amdsmi_smu_fw_version_t::unused"]
+        [::std::mem::offset_of!(amdsmi_smu_fw_version_t, unused) - 3usize];
+};
+#[doc = "```text\n @brief HSMP Metrics table (supported only with hsmp proto version 6).\n\n @cond @tag{cpu_bm} @endcond\n```"]
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_hsmp_metrics_table_t {
+    #[doc = "```text\n!< Incremented every time the accumulator values are updated in this table\n```"]
+    pub accumulation_counter: u32,
+    #[doc = "```text\n!< Maximum temperature reported by all on-die thermal sensors on all AIDs, CCDs, and XCDs in the socket\n```"]
+    pub max_socket_temperature: u32,
+    #[doc = "```text\n!< Maximum temperature reported by SVI3 telemetry for all slave addresses\n```"]
+    pub max_vr_temperature: u32,
+    #[doc = "```text\n!< Maximum temperature reported by all HBM stacks in the socket\n```"]
+    pub max_hbm_temperature: u32,
+    #[doc = "```text\n!< Accumulated version of \"max_socket_temperature\"\n```"]
+    pub max_socket_temperature_acc: u64,
+    #[doc = "```text\n!< Accumulated version of \"max_vr_temperature\"\n```"]
+    pub max_vr_temperature_acc: u64,
+    #[doc = "```text\n!< Accumulated version of \"max_hbm_temperature\"\n```"]
+    pub max_hbm_temperature_acc: u64,
+    #[doc = "```text\n!< Power limit currently being enforced by the power throttling controller\n```"]
+    pub socket_power_limit: u32,
+    #[doc = "```text\n!< Maximum power limit the power throttling controller is allowed to be configured to\n```"]
+    pub max_socket_power_limit: u32,
+    #[doc = "```text\n!< Power consumption of all die in the socket (AID+CCD+XCD+HBM)\n```"]
+    pub socket_power: u32,
+    #[doc = "```text\n!< Timestamp corresponding to the energy accumulators in 10ns units\n```"]
+    pub timestamp: u64,
+    #[doc = "```text\n!< Energy accumulator of all die in the socket (AID+CCD+XCD+HBM)\n```"]
+    pub socket_energy_acc: u64,
+    #[doc = "```text\n!< Energy accumulator of VDDCR_VDD rails powering CCDs\n```"]
+    pub ccd_energy_acc: u64,
+    #[doc = "```text\n!< Energy accumulator of VDDCR_VDD rails powering XCDs\n```"]
+    pub xcd_energy_acc: u64,
+    #[doc = "```text\n!< Energy accumulator of rails powering the AIDs\n```"]
+    pub aid_energy_acc: u64,
+    #[doc = "```text\n!< Energy accumulator of all HBM stacks in the socket\n```"]
+    pub hbm_energy_acc: u64,
+    #[doc = "```text\n!< Minimum CCLK frequency limit enforced from the infrastructure controllers\n```"]
+    pub cclk_frequency_limit: u32,
+    #[doc = "```text\n!< Minimum GFXCLK frequency limit enforced from the infrastructure controllers\n```"]
+    pub gfxclk_frequency_limit: u32,
+    #[doc = "```text\n!< Effective FCLK frequency\n```"]
+    pub fclk_frequency: u32,
+    #[doc = "```text\n!< Effective UCLK frequency\n```"]
+    pub uclk_frequency: u32,
+    #[doc = "```text\n!< Effective SOCCLK frequency per AID\n```"]
+    pub socclk_frequency: [u32; 4usize],
+    #[doc = "```text\n!< Effective VCLK frequency per AID\n```"]
+    pub vclk_frequency: [u32; 4usize],
+    #[doc = "```text\n!< Effective DCLK frequency per AID\n```"]
+    pub dclk_frequency: [u32; 4usize],
+    #[doc = "```text\n!< Effective LCLK frequency per AID\n```"]
+    pub lclk_frequency: [u32; 4usize],
+    #[doc = "```text\n!< GFXCLK frequency for the target XCC\n```"]
+    pub gfxclk_frequency_acc: [u64; 8usize],
+    #[doc = "```text\n!< CCLK frequency for the target CPU\n```"]
+    pub cclk_frequency_acc: [u64; 96usize],
+    #[doc = "```text\n!< Maximum CCLK frequency supported by the CPU\n```"]
+    pub max_cclk_frequency: u32,
+    #[doc = "```text\n!< Minimum CCLK frequency supported by the CPU\n```"]
+    pub min_cclk_frequency: u32,
+    #[doc = "```text\n!< Maximum GFXCLK frequency supported by the accelerator\n```"]
+    pub max_gfxclk_frequency: u32,
+    #[doc = "```text\n!< Minimum GFXCLK frequency supported by the accelerator\n```"]
+    pub min_gfxclk_frequency: u32,
+    #[doc = "```text\n!< List of supported FCLK frequencies (0 means that state is not supported)\n```"]
+    pub fclk_frequency_table: [u32; 4usize],
+    #[doc = "```text\n!< List of supported UCLK frequencies (0 means that state is not supported)\n```"]
+    pub uclk_frequency_table: [u32; 4usize],
+    #[doc = "```text\n!< List of supported SOCCLK frequencies (0 means that state is not supported)\n```"]
+    pub socclk_frequency_table: [u32; 4usize],
+    #[doc = "```text\n!< List of supported VCLK frequencies (0 means that state is not supported)\n```"]
+    pub vclk_frequency_table: [u32; 4usize],
+    #[doc = "```text\n!< List of supported DCLK frequencies (0 means that state is not supported)\n```"]
+    pub dclk_frequency_table: [u32; 4usize],
+    #[doc = "```text\n!< List of supported LCLK frequencies (0 means that state is not supported)\n```"]
+    pub lclk_frequency_table: [u32; 4usize],
+    #[doc = "```text\n!< Maximum LCLK DPM state constraint defined by the user\n```"]
+    pub max_lclk_dpm_range: u32,
+    #[doc = "```text\n!< Minimum LCLK DPM state constraint defined by the user\n```"]
+    pub min_lclk_dpm_range: u32,
+    #[doc = "```text\n!< Current operating XGMI link width\n```"]
+    pub xgmi_width: u32,
+    #[doc = "```text\n!< Current operating XGMI link bitrate\n```"]
+    pub xgmi_bitrate: u32,
+    #[doc = "```text\n!< XGMI read bandwidth for the target XGMI link in the local socket\n```"]
+    pub xgmi_read_bandwidth_acc: [u64; 8usize],
+    #[doc = "```text\n!< XGMI write bandwidth for the target XGMI link in the local socket\n```"]
+    pub xgmi_write_bandwidth_acc: [u64; 8usize],
+    #[doc = "```text\n!< Average CPU C0 residency of all enabled cores in the socket\n```"]
+    pub socket_c0_residency: u32,
+    #[doc = "```text\n!< Average XCC busy for all enabled XCCs in the socket\n```"]
+    pub socket_gfx_busy: u32,
+    #[doc = "```text\n!< HBM bandwidth utilization for all HBM stacks in the socket\n```"]
+    pub dram_bandwidth_utilization: u32,
+    #[doc = "```text\n!< Accumulated value of \"socket_c0_residency\"\n```"]
+    pub socket_c0_residency_acc: u64,
+    #[doc = "```text\n!< Accumulated value of \"socket_gfx_busy\"\n```"]
+    pub socket_gfx_busy_acc: u64,
+    #[doc = "```text\n!< HBM bandwidth for all HBM stacks in the socket\n```"]
+    pub dram_bandwidth_acc: u64,
+    #[doc = "```text\n!< Maximum supported HBM bandwidth for all HBM stacks running at the maximum supported UCLK frequency\n```"]
+    pub max_dram_bandwidth: u32,
+    #[doc = "```text\n!< Accumulated value of \"dram_bandwidth_utilization\"\n```"]
+    pub dram_bandwidth_utilization_acc: u64,
+    #[doc = "```text\n!< PCIe bandwidth for all PCIe devices connected to the target AID\n```"]
+    pub pcie_bandwidth_acc: [u64; 4usize],
+    #[doc = "```text\n!< Incremented every iteration PROCHOT is active\n```"]
+    pub prochot_residency_acc: u32,
+    #[doc = "```text\n!< Incremented every iteration the PPT controller is active\n```"]
+    pub ppt_residency_acc: u32,
+    #[doc = "```text\n!< Incremented every iteration the socket thermal throttling controller is active\n```"]
+    pub socket_thm_residency_acc: u32,
+    #[doc = "```text\n!< Incremented every iteration the VR thermal throttling controller is active\n```"]
+    pub vr_thm_residency_acc: u32,
+    #[doc = "```text\n!< Incremented every iteration the HBM thermal throttling controller is active\n```"]
+    pub hbm_thm_residency_acc: u32,
+    #[doc = "```text\n!< spare\n```"]
+    pub spare: u32,
+    #[doc = "```text\n!< Effective GFXCLK frequency per XCD\n```"]
+    pub gfxclk_frequency: [u32; 8usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_hsmp_metrics_table_t"]
+        [::std::mem::size_of::<amdsmi_hsmp_metrics_table_t>() - 1404usize];
+    ["Alignment of amdsmi_hsmp_metrics_table_t"]
+        [::std::mem::align_of::<amdsmi_hsmp_metrics_table_t>() - 1usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::accumulation_counter"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, accumulation_counter) - 0usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::max_socket_temperature"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, max_socket_temperature) - 4usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::max_vr_temperature"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, max_vr_temperature) - 8usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::max_hbm_temperature"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, max_hbm_temperature) - 12usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::max_socket_temperature_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, max_socket_temperature_acc) - 16usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::max_vr_temperature_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, max_vr_temperature_acc) - 24usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::max_hbm_temperature_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, max_hbm_temperature_acc) - 32usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::socket_power_limit"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, socket_power_limit) - 40usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::max_socket_power_limit"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, max_socket_power_limit) - 44usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::socket_power"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, socket_power) - 48usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::timestamp"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, timestamp) - 52usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::socket_energy_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, socket_energy_acc) - 60usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::ccd_energy_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, ccd_energy_acc) - 68usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::xcd_energy_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, xcd_energy_acc) - 76usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::aid_energy_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, aid_energy_acc) - 84usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::hbm_energy_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, hbm_energy_acc) - 92usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::cclk_frequency_limit"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, cclk_frequency_limit) - 100usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::gfxclk_frequency_limit"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, gfxclk_frequency_limit) - 104usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::fclk_frequency"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, fclk_frequency) - 108usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::uclk_frequency"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, uclk_frequency) - 112usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::socclk_frequency"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, socclk_frequency) - 116usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::vclk_frequency"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, vclk_frequency) - 132usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::dclk_frequency"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, dclk_frequency) - 148usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::lclk_frequency"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, lclk_frequency) - 164usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::gfxclk_frequency_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, gfxclk_frequency_acc) - 180usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::cclk_frequency_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, cclk_frequency_acc) - 244usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::max_cclk_frequency"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, max_cclk_frequency) - 1012usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::min_cclk_frequency"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, min_cclk_frequency) - 1016usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::max_gfxclk_frequency"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, max_gfxclk_frequency) - 1020usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::min_gfxclk_frequency"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, min_gfxclk_frequency) - 1024usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::fclk_frequency_table"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, fclk_frequency_table) - 1028usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::uclk_frequency_table"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, uclk_frequency_table) - 1044usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::socclk_frequency_table"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, socclk_frequency_table) - 1060usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::vclk_frequency_table"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, vclk_frequency_table) - 1076usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::dclk_frequency_table"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, dclk_frequency_table) - 1092usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::lclk_frequency_table"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, lclk_frequency_table) - 1108usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::max_lclk_dpm_range"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, max_lclk_dpm_range) - 1124usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::min_lclk_dpm_range"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, min_lclk_dpm_range) - 1128usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::xgmi_width"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, xgmi_width) - 1132usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::xgmi_bitrate"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, xgmi_bitrate) - 1136usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::xgmi_read_bandwidth_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, xgmi_read_bandwidth_acc) - 1140usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::xgmi_write_bandwidth_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, xgmi_write_bandwidth_acc) - 1204usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::socket_c0_residency"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, socket_c0_residency) - 1268usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::socket_gfx_busy"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, socket_gfx_busy) - 1272usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::dram_bandwidth_utilization"][::std::mem::offset_of!(
+        amdsmi_hsmp_metrics_table_t,
+        dram_bandwidth_utilization
+    ) - 1276usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::socket_c0_residency_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, socket_c0_residency_acc) - 1280usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::socket_gfx_busy_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, socket_gfx_busy_acc) - 1288usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::dram_bandwidth_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, dram_bandwidth_acc) - 1296usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::max_dram_bandwidth"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, max_dram_bandwidth) - 1304usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::dram_bandwidth_utilization_acc"][::std::mem::offset_of!(
+        amdsmi_hsmp_metrics_table_t,
+        dram_bandwidth_utilization_acc
+    ) - 1308usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::pcie_bandwidth_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, pcie_bandwidth_acc) - 1316usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::prochot_residency_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, prochot_residency_acc) - 1348usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::ppt_residency_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, ppt_residency_acc) - 1352usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::socket_thm_residency_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, socket_thm_residency_acc) - 1356usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::vr_thm_residency_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, vr_thm_residency_acc) - 1360usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::hbm_thm_residency_acc"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, hbm_thm_residency_acc) - 1364usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::spare"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, spare) - 1368usize];
+    ["Offset of field: amdsmi_hsmp_metrics_table_t::gfxclk_frequency"]
+        [::std::mem::offset_of!(amdsmi_hsmp_metrics_table_t, gfxclk_frequency) - 1372usize];
+};
+#[doc = "```text\n @brief Cper\n\n @cond @tag{gpu_bm_linux} @tag{host} @endcond\n```"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_cper_guid_t {
+    pub b: [::std::os::raw::c_uchar; 16usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_cper_guid_t"][::std::mem::size_of::<amdsmi_cper_guid_t>() - 16usize];
+    ["Alignment of amdsmi_cper_guid_t"][::std::mem::align_of::<amdsmi_cper_guid_t>() - 1usize];
+    ["Offset of field: amdsmi_cper_guid_t::b"]
+        [::std::mem::offset_of!(amdsmi_cper_guid_t, b) - 0usize];
+};
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_cper_timestamp_t {
+    pub seconds: u8,
+    pub minutes: u8,
+    pub hours: u8,
+    pub flag: u8,
+    pub day: u8,
+    pub month: u8,
+    pub year: u8,
+    pub century: u8,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_cper_timestamp_t"][::std::mem::size_of::<amdsmi_cper_timestamp_t>() - 8usize];
+    ["Alignment of amdsmi_cper_timestamp_t"]
+        [::std::mem::align_of::<amdsmi_cper_timestamp_t>() - 1usize];
+    ["Offset of field: amdsmi_cper_timestamp_t::seconds"]
+        [::std::mem::offset_of!(amdsmi_cper_timestamp_t, seconds) - 0usize];
+    ["Offset of field: amdsmi_cper_timestamp_t::minutes"]
+        [::std::mem::offset_of!(amdsmi_cper_timestamp_t, minutes) - 1usize];
+    ["Offset of field: amdsmi_cper_timestamp_t::hours"]
+        [::std::mem::offset_of!(amdsmi_cper_timestamp_t, hours) - 2usize];
+    ["Offset of field: amdsmi_cper_timestamp_t::flag"]
+        [::std::mem::offset_of!(amdsmi_cper_timestamp_t, flag) - 3usize];
+    ["Offset of field: amdsmi_cper_timestamp_t::day"]
+        [::std::mem::offset_of!(amdsmi_cper_timestamp_t, day) - 4usize];
+    ["Offset of field: amdsmi_cper_timestamp_t::month"]
+        [::std::mem::offset_of!(amdsmi_cper_timestamp_t, month) - 5usize];
+    ["Offset of field: amdsmi_cper_timestamp_t::year"]
+        [::std::mem::offset_of!(amdsmi_cper_timestamp_t, year) - 6usize];
+    ["Offset of field: amdsmi_cper_timestamp_t::century"]
+        [::std::mem::offset_of!(amdsmi_cper_timestamp_t, century) - 7usize];
+};
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub union amdsmi_cper_valid_bits_t {
+    pub valid_bits: amdsmi_cper_valid_bits_t_valid_bits_,
+    pub valid_mask: u32,
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct amdsmi_cper_valid_bits_t_valid_bits_ {
+    pub _bitfield_align_1: [u8; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize]>,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_cper_valid_bits_t_valid_bits_"]
+        [::std::mem::size_of::<amdsmi_cper_valid_bits_t_valid_bits_>() - 4usize];
+    ["Alignment of amdsmi_cper_valid_bits_t_valid_bits_"]
+        [::std::mem::align_of::<amdsmi_cper_valid_bits_t_valid_bits_>() - 1usize];
+};
+impl amdsmi_cper_valid_bits_t_valid_bits_ {
+    #[inline]
+    pub fn platform_id(&self) -> u32 {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_platform_id(&mut self, val: u32) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(0usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn platform_id_raw(this: *const Self) -> u32 {
+        unsafe {
+            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::std::ptr::addr_of!((*this)._bitfield_1),
+                0usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_platform_id_raw(this: *mut Self, val: u32) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                0usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn timestamp(&self) -> u32 {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_timestamp(&mut self, val: u32) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(1usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn timestamp_raw(this: *const Self) -> u32 {
+        unsafe {
+            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::std::ptr::addr_of!((*this)._bitfield_1),
+                1usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_timestamp_raw(this: *mut Self, val: u32) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                1usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn partition_id(&self) -> u32 {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_partition_id(&mut self, val: u32) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(2usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn partition_id_raw(this: *const Self) -> u32 {
+        unsafe {
+            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::std::ptr::addr_of!((*this)._bitfield_1),
+                2usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_partition_id_raw(this: *mut Self, val: u32) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                2usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn reserved(&self) -> u32 {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(3usize, 29u8) as u32) }
+    }
+    #[inline]
+    pub fn set_reserved(&mut self, val: u32) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(3usize, 29u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn reserved_raw(this: *const Self) -> u32 {
+        unsafe {
+            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::std::ptr::addr_of!((*this)._bitfield_1),
+                3usize,
+                29u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_reserved_raw(this: *mut Self, val: u32) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                3usize,
+                29u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        platform_id: u32,
+        timestamp: u32,
+        partition_id: u32,
+        reserved: u32,
+    ) -> __BindgenBitfieldUnit<[u8; 4usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 4usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 1u8, {
+            let platform_id: u32 = unsafe { ::std::mem::transmute(platform_id) };
+            platform_id as u64
+        });
+        __bindgen_bitfield_unit.set(1usize, 1u8, {
+            let timestamp: u32 = unsafe { ::std::mem::transmute(timestamp) };
+            timestamp as u64
+        });
+        __bindgen_bitfield_unit.set(2usize, 1u8, {
+            let partition_id: u32 = unsafe { ::std::mem::transmute(partition_id) };
+            partition_id as u64
+        });
+        __bindgen_bitfield_unit.set(3usize, 29u8, {
+            let reserved: u32 = unsafe { ::std::mem::transmute(reserved) };
+            reserved as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_cper_valid_bits_t"]
+        [::std::mem::size_of::<amdsmi_cper_valid_bits_t>() - 4usize];
+    ["Alignment of amdsmi_cper_valid_bits_t"]
+        [::std::mem::align_of::<amdsmi_cper_valid_bits_t>() - 1usize];
+    ["Offset of field: amdsmi_cper_valid_bits_t::valid_bits"]
+        [::std::mem::offset_of!(amdsmi_cper_valid_bits_t, valid_bits) - 0usize];
+    ["Offset of field: amdsmi_cper_valid_bits_t::valid_mask"]
+        [::std::mem::offset_of!(amdsmi_cper_valid_bits_t, valid_mask) - 0usize];
+};
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub struct amdsmi_cper_hdr_t {
+    #[doc = "```text\n!< \"CPER\"\n```"]
+    pub signature: [::std::os::raw::c_char; 4usize],
+    pub revision: u16,
+    #[doc = "```text\n!< 0xFFFFFFFF\n```"]
+    pub signature_end: u32,
+    pub sec_cnt: u16,
+    pub error_severity: amdsmi_cper_sev_t,
+    pub cper_valid_bits: amdsmi_cper_valid_bits_t,
+    #[doc = "```text\n!< Total size of CPER Entry\n```"]
+    pub record_length: u32,
+    pub timestamp: amdsmi_cper_timestamp_t,
+    pub platform_id: [::std::os::raw::c_char; 16usize],
+    #[doc = "```text\n!< Reserved\n```"]
+    pub partition_id: amdsmi_cper_guid_t,
+    pub creator_id: [::std::os::raw::c_char; 16usize],
+    #[doc = "```text\n!< CMC, MCE, can use amdsmi_cper_notifiy_type_t to decode\n```"]
+    pub notify_type: amdsmi_cper_guid_t,
+    #[doc = "```text\n!< Unique CPER Entry ID\n```"]
+    pub record_id: [::std::os::raw::c_char; 8usize],
+    #[doc = "```text\n!< Reserved\n```"]
+    pub flags: u32,
+    #[doc = "```text\n!< Reserved\n```"]
+    pub persistence_info: u64,
+    #[doc = "```text\n!< Reserved\n```"]
+    pub reserved: [u8; 12usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of amdsmi_cper_hdr_t"][::std::mem::size_of::<amdsmi_cper_hdr_t>() - 128usize];
+    ["Alignment of amdsmi_cper_hdr_t"][::std::mem::align_of::<amdsmi_cper_hdr_t>() - 1usize];
+    ["Offset of field: amdsmi_cper_hdr_t::signature"]
+        [::std::mem::offset_of!(amdsmi_cper_hdr_t, signature) - 0usize];
+    ["Offset of field: amdsmi_cper_hdr_t::revision"]
+        [::std::mem::offset_of!(amdsmi_cper_hdr_t, revision) - 4usize];
+    ["Offset of field: amdsmi_cper_hdr_t::signature_end"]
+        [::std::mem::offset_of!(amdsmi_cper_hdr_t, signature_end) - 6usize];
+    ["Offset of field: amdsmi_cper_hdr_t::sec_cnt"]
+        [::std::mem::offset_of!(amdsmi_cper_hdr_t, sec_cnt) - 10usize];
+    ["Offset of field: amdsmi_cper_hdr_t::error_severity"]
+        [::std::mem::offset_of!(amdsmi_cper_hdr_t, error_severity) - 12usize];
+    ["Offset of field: amdsmi_cper_hdr_t::cper_valid_bits"]
+        [::std::mem::offset_of!(amdsmi_cper_hdr_t, cper_valid_bits) - 16usize];
+    ["Offset of field: amdsmi_cper_hdr_t::record_length"]
+        [::std::mem::offset_of!(amdsmi_cper_hdr_t, record_length) - 20usize];
+    ["Offset of field: amdsmi_cper_hdr_t::timestamp"]
+        [::std::mem::offset_of!(amdsmi_cper_hdr_t, timestamp) - 24usize];
+    ["Offset of field: amdsmi_cper_hdr_t::platform_id"]
+        [::std::mem::offset_of!(amdsmi_cper_hdr_t, platform_id) - 32usize];
+    ["Offset of field: amdsmi_cper_hdr_t::partition_id"]
+        [::std::mem::offset_of!(amdsmi_cper_hdr_t, partition_id) - 48usize];
+    ["Offset of field: amdsmi_cper_hdr_t::creator_id"]
+        [::std::mem::offset_of!(amdsmi_cper_hdr_t, creator_id) - 64usize];
+    ["Offset of field: amdsmi_cper_hdr_t::notify_type"]
+        [::std::mem::offset_of!(amdsmi_cper_hdr_t, notify_type) - 80usize];
+    ["Offset of field: amdsmi_cper_hdr_t::record_id"]
+        [::std::mem::offset_of!(amdsmi_cper_hdr_t, record_id) - 96usize];
+    ["Offset of field: amdsmi_cper_hdr_t::flags"]
+        [::std::mem::offset_of!(amdsmi_cper_hdr_t, flags) - 104usize];
+    ["Offset of field: amdsmi_cper_hdr_t::persistence_info"]
+        [::std::mem::offset_of!(amdsmi_cper_hdr_t, persistence_info) - 108usize];
+    ["Offset of field: amdsmi_cper_hdr_t::reserved"]
+        [::std::mem::offset_of!(amdsmi_cper_hdr_t, reserved) - 116usize];
+};
 pub struct libamd_smi {
     __library: ::libloading::Library,
     pub amdsmi_init:
@@ -678,6 +3554,23 @@ pub struct libamd_smi {
         ) -> amdsmi_status_t,
         ::libloading::Error,
     >,
+    pub amdsmi_get_socket_info: Result<
+        unsafe extern "C" fn(
+            socket_handle: amdsmi_socket_handle,
+            len: usize,
+            name: *mut ::std::os::raw::c_char,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_processor_handles_by_type: Result<
+        unsafe extern "C" fn(
+            socket_handle: amdsmi_socket_handle,
+            processor_type: processor_type_t,
+            processor_handles: *mut amdsmi_processor_handle,
+            processor_count: *mut u32,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
     pub amdsmi_get_processor_handles: Result<
         unsafe extern "C" fn(
             socket_handle: amdsmi_socket_handle,
@@ -686,6 +3579,13 @@ pub struct libamd_smi {
         ) -> amdsmi_status_t,
         ::libloading::Error,
     >,
+    pub amdsmi_get_processor_type: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            processor_type: *mut processor_type_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
     pub amdsmi_get_gpu_device_uuid: Result<
         unsafe extern "C" fn(
             processor_handle: amdsmi_processor_handle,
@@ -694,319 +3594,1865 @@ pub struct libamd_smi {
         ) -> amdsmi_status_t,
         ::libloading::Error,
     >,
-    pub amdsmi_get_gpu_pci_throughput: Result<
+    pub amdsmi_get_gpu_enumeration_info: Result<
         unsafe extern "C" fn(
             processor_handle: amdsmi_processor_handle,
-            sent: *mut u64,
-            received: *mut u64,
-            max_pkt_sz: *mut u64,
+            info: *mut amdsmi_enumeration_info_t,
         ) -> amdsmi_status_t,
         ::libloading::Error,
     >,
-    pub amdsmi_get_energy_count: Result<
+    pub amdsmi_get_gpu_vendor_name: Result<
         unsafe extern "C" fn(
             processor_handle: amdsmi_processor_handle,
-            energy_accumulator: *mut u64,
-            counter_resolution: *mut f32,
-            timestamp: *mut u64,
+            name: *mut ::std::os::raw::c_char,
+            len: usize,
         ) -> amdsmi_status_t,
         ::libloading::Error,
     >,
-    pub amdsmi_get_gpu_memory_usage: Result<
+    pub amdsmi_get_gpu_vram_vendor: Result<
         unsafe extern "C" fn(
             processor_handle: amdsmi_processor_handle,
-            mem_type: amdsmi_memory_type_t,
-            used: *mut u64,
+            brand: *mut ::std::os::raw::c_char,
+            len: u32,
         ) -> amdsmi_status_t,
         ::libloading::Error,
     >,
-    pub amdsmi_get_gpu_fan_speed: Result<
+    pub amdsmi_get_gpu_subsystem_name: Result<
         unsafe extern "C" fn(
             processor_handle: amdsmi_processor_handle,
-            sensor_ind: u32,
-            speed: *mut i64,
+            name: *mut ::std::os::raw::c_char,
+            len: usize,
         ) -> amdsmi_status_t,
         ::libloading::Error,
     >,
-    pub amdsmi_get_gpu_volt_metric: Result<
+    pub amdsmi_get_gpu_bdf_id: Result<
         unsafe extern "C" fn(
             processor_handle: amdsmi_processor_handle,
-            sensor_type: amdsmi_voltage_type_t,
-            metric: amdsmi_voltage_metric_t,
-            voltage: *mut i64,
+            bdfid: *mut u64,
         ) -> amdsmi_status_t,
         ::libloading::Error,
     >,
-    pub amdsmi_status_code_to_string: Result<
+    pub amdsmi_get_gpu_pci_throughput: Result<
         unsafe extern "C" fn(
-            status: amdsmi_status_t,
-            status_string: *mut *const ::std::os::raw::c_char,
+            processor_handle: amdsmi_processor_handle,
+            sent: *mut u64,
+            received: *mut u64,
+            max_pkt_sz: *mut u64,
         ) -> amdsmi_status_t,
         ::libloading::Error,
     >,
-    pub amdsmi_get_gpu_asic_info: Result<
+    pub amdsmi_get_energy_count: Result<
         unsafe extern "C" fn(
             processor_handle: amdsmi_processor_handle,
-            info: *mut amdsmi_asic_info_t,
+            energy_accumulator: *mut u64,
+            counter_resolution: *mut f32,
+            timestamp: *mut u64,
         ) -> amdsmi_status_t,
         ::libloading::Error,
     >,
-    pub amdsmi_get_temp_metric: Result<
+    pub amdsmi_set_power_cap: Result<
         unsafe extern "C" fn(
             processor_handle: amdsmi_processor_handle,
-            sensor_type: amdsmi_temperature_type_t,
-            metric: amdsmi_temperature_metric_t,
-            temperature: *mut i64,
+            sensor_ind: u32,
+            cap: u64,
         ) -> amdsmi_status_t,
         ::libloading::Error,
     >,
-    pub amdsmi_get_gpu_activity: Result<
+    pub amdsmi_get_cpu_socket_power: Result<
         unsafe extern "C" fn(
             processor_handle: amdsmi_processor_handle,
-            info: *mut amdsmi_engine_usage_t,
+            ppower: *mut u32,
         ) -> amdsmi_status_t,
         ::libloading::Error,
     >,
-    pub amdsmi_get_power_info: Result<
+    pub amdsmi_get_cpu_socket_power_cap: Result<
         unsafe extern "C" fn(
             processor_handle: amdsmi_processor_handle,
-            info: *mut amdsmi_power_info_t,
+            pcap: *mut u32,
         ) -> amdsmi_status_t,
         ::libloading::Error,
     >,
-    pub amdsmi_is_gpu_power_management_enabled: Result<
+    pub amdsmi_get_cpu_socket_power_cap_max: Result<
         unsafe extern "C" fn(
             processor_handle: amdsmi_processor_handle,
-            enabled: *mut bool,
+            pmax: *mut u32,
         ) -> amdsmi_status_t,
         ::libloading::Error,
     >,
-    pub amdsmi_get_clock_info: Result<
+    pub amdsmi_get_gpu_memory_total: Result<
         unsafe extern "C" fn(
             processor_handle: amdsmi_processor_handle,
-            clk_type: amdsmi_clk_type_t,
-            info: *mut amdsmi_clk_info_t,
+            mem_type: amdsmi_memory_type_t,
+            total: *mut u64,
         ) -> amdsmi_status_t,
         ::libloading::Error,
     >,
-    pub amdsmi_get_gpu_process_list: Result<
+    pub amdsmi_get_gpu_memory_usage: Result<
         unsafe extern "C" fn(
             processor_handle: amdsmi_processor_handle,
-            max_processes: *mut u32,
-            list: *mut amdsmi_proc_info_t,
+            mem_type: amdsmi_memory_type_t,
+            used: *mut u64,
         ) -> amdsmi_status_t,
         ::libloading::Error,
     >,
-}
-impl libamd_smi {
-    pub unsafe fn new<P>(path: P) -> Result<Self, ::libloading::Error>
-    where
+    pub amdsmi_get_gpu_bad_page_info: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            num_pages: *mut u32,
+            info: *mut amdsmi_retired_page_record_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_gpu_validate_ras_eeprom: Result<
+        unsafe extern "C" fn(processor_handle: amdsmi_processor_handle) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_ras_block_features_enabled: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            block: amdsmi_gpu_block_t,
+            state: *mut amdsmi_ras_err_state_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_fan_rpms: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            sensor_ind: u32,
+            speed: *mut i64,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_fan_speed: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            sensor_ind: u32,
+            speed: *mut i64,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_fan_speed_max: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            sensor_ind: u32,
+            max_speed: *mut u64,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_volt_metric: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            sensor_type: amdsmi_voltage_type_t,
+            metric: amdsmi_voltage_metric_t,
+            voltage: *mut i64,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_reset_gpu_fan: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            sensor_ind: u32,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_set_gpu_fan_speed: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            sensor_ind: u32,
+            speed: u64,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_utilization_count: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            utilization_counters: *mut amdsmi_utilization_counter_t,
+            count: u32,
+            timestamp: *mut u64,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_perf_level: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            perf: *mut amdsmi_dev_perf_level_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_set_gpu_perf_determinism_mode: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            clkvalue: u64,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_clk_freq: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            clk_type: amdsmi_clk_type_t,
+            f: *mut amdsmi_frequencies_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_od_volt_info: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            odv: *mut amdsmi_od_volt_freq_data_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_metrics_header_info: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            header_value: *mut amd_metrics_table_header_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_metrics_info: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            pgpu_metrics: *mut amdsmi_gpu_metrics_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_set_gpu_clk_range: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            minclkvalue: u64,
+            maxclkvalue: u64,
+            clkType: amdsmi_clk_type_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_power_profile_presets: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            sensor_ind: u32,
+            status: *mut amdsmi_power_profile_status_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_set_gpu_perf_level: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            perf_lvl: amdsmi_dev_perf_level_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_set_clk_freq: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            clk_type: amdsmi_clk_type_t,
+            freq_bitmask: u64,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_soc_pstate: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            policy: *mut amdsmi_dpm_policy_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_xgmi_plpd: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            xgmi_plpd: *mut amdsmi_dpm_policy_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_lib_version: Result<
+        unsafe extern "C" fn(version: *mut amdsmi_version_t) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_ecc_count: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            block: amdsmi_gpu_block_t,
+            ec: *mut amdsmi_error_count_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_total_ecc_count: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            ec: *mut amdsmi_error_count_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_cper_entries: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            severity_mask: u32,
+            cper_data: *mut ::std::os::raw::c_char,
+            buf_size: *mut u64,
+            cper_hdrs: *mut *mut amdsmi_cper_hdr_t,
+            entry_count: *mut u64,
+            cursor: *mut u64,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_ras_feature_info: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            ras_feature: *mut amdsmi_ras_feature_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_status_code_to_string: Result<
+        unsafe extern "C" fn(
+            status: amdsmi_status_t,
+            status_string: *mut *const ::std::os::raw::c_char,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_gpu_counter_group_supported: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            group: amdsmi_event_group_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_gpu_create_counter: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            type_: amdsmi_event_type_t,
+            evnt_handle: *mut amdsmi_event_handle_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_gpu_destroy_counter: Result<
+        unsafe extern "C" fn(evnt_handle: amdsmi_event_handle_t) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_gpu_control_counter: Result<
+        unsafe extern "C" fn(
+            evt_handle: amdsmi_event_handle_t,
+            cmd: amdsmi_counter_command_t,
+            cmd_args: *mut ::std::os::raw::c_void,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_gpu_read_counter: Result<
+        unsafe extern "C" fn(
+            evt_handle: amdsmi_event_handle_t,
+            value: *mut amdsmi_counter_value_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_available_counters: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            grp: amdsmi_event_group_t,
+            available: *mut u32,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_gpu_xgmi_error_status: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            status: *mut amdsmi_xgmi_status_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_xgmi_info: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            info: *mut amdsmi_xgmi_info_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_xgmi_link_status: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            link_status: *mut amdsmi_xgmi_link_status_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_link_metrics: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            link_metrics: *mut amdsmi_link_metrics_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_topo_get_numa_node_number: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            numa_node: *mut u32,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_topo_get_link_weight: Result<
+        unsafe extern "C" fn(
+            processor_handle_src: amdsmi_processor_handle,
+            processor_handle_dst: amdsmi_processor_handle,
+            weight: *mut u64,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_topo_get_link_type: Result<
+        unsafe extern "C" fn(
+            processor_handle_src: amdsmi_processor_handle,
+            processor_handle_dst: amdsmi_processor_handle,
+            hops: *mut u64,
+            type_: *mut amdsmi_link_type_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_is_P2P_accessible: Result<
+        unsafe extern "C" fn(
+            processor_handle_src: amdsmi_processor_handle,
+            processor_handle_dst: amdsmi_processor_handle,
+            accessible: *mut bool,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_topo_get_p2p_status: Result<
+        unsafe extern "C" fn(
+            processor_handle_src: amdsmi_processor_handle,
+            processor_handle_dst: amdsmi_processor_handle,
+            type_: *mut amdsmi_link_type_t,
+            cap: *mut amdsmi_p2p_capability_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_compute_partition: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            compute_partition: *mut ::std::os::raw::c_char,
+            len: u32,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_memory_partition: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            memory_partition: *mut ::std::os::raw::c_char,
+            len: u32,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_init_gpu_event_notification: Result<
+        unsafe extern "C" fn(processor_handle: amdsmi_processor_handle) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_set_gpu_event_notification_mask: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            mask: u64,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_event_notification: Result<
+        unsafe extern "C" fn(
+            timeout_ms: ::std::os::raw::c_int,
+            num_elem: *mut u32,
+            data: *mut amdsmi_evt_notification_data_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_stop_gpu_event_notification: Result<
+        unsafe extern "C" fn(processor_handle: amdsmi_processor_handle) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_driver_info: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            info: *mut amdsmi_driver_info_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_asic_info: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            info: *mut amdsmi_asic_info_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_kfd_info: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            info: *mut amdsmi_kfd_info_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_board_info: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            info: *mut amdsmi_board_info_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_power_cap_info: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            sensor_ind: u32,
+            info: *mut amdsmi_power_cap_info_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_pcie_info: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            info: *mut amdsmi_pcie_info_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_fw_info: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            info: *mut amdsmi_fw_info_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_vbios_info: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            info: *mut amdsmi_vbios_info_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_temp_metric: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            sensor_type: amdsmi_temperature_type_t,
+            metric: amdsmi_temperature_metric_t,
+            temperature: *mut i64,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_activity: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            info: *mut amdsmi_engine_usage_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_power_info: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            info: *mut amdsmi_power_info_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_is_gpu_power_management_enabled: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            enabled: *mut bool,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_clock_info: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            clk_type: amdsmi_clk_type_t,
+            info: *mut amdsmi_clk_info_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_gpu_process_list: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            max_processes: *mut u32,
+            list: *mut amdsmi_proc_info_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_cpu_core_energy: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            penergy: *mut u64,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_cpu_socket_energy: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            penergy: *mut u64,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_cpu_hsmp_driver_version: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            amdsmi_hsmp_driver_ver: *mut amdsmi_hsmp_driver_version_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_cpu_smu_fw_version: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            amdsmi_smu_fw: *mut amdsmi_smu_fw_version_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_cpu_prochot_status: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            prochot: *mut u32,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_cpu_cclk_limit: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            cclk: *mut u32,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_cpu_core_current_freq_limit: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            freq: *mut u32,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_cpu_core_boostlimit: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            pboostlimit: *mut u32,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_cpu_socket_temperature: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            ptmon: *mut u32,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_hsmp_metrics_table_version: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            metrics_version: *mut u32,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+    pub amdsmi_get_hsmp_metrics_table: Result<
+        unsafe extern "C" fn(
+            processor_handle: amdsmi_processor_handle,
+            metrics_table: *mut amdsmi_hsmp_metrics_table_t,
+        ) -> amdsmi_status_t,
+        ::libloading::Error,
+    >,
+}
+impl libamd_smi {
+    pub unsafe fn new<P>(path: P) -> Result<Self, ::libloading::Error>
+    where
         P: AsRef<::std::ffi::OsStr>,
     {
         let library = ::libloading::Library::new(path)?;
         Self::from_library(library)
     }
-    pub unsafe fn from_library<L>(library: L) -> Result<Self, ::libloading::Error>
-    where
-        L: Into<::libloading::Library>,
-    {
-        let __library = library.into();
-        let amdsmi_init = __library.get(b"amdsmi_init\0").map(|sym| *sym);
-        let amdsmi_shut_down = __library.get(b"amdsmi_shut_down\0").map(|sym| *sym);
-        let amdsmi_get_socket_handles = __library
-            .get(b"amdsmi_get_socket_handles\0")
-            .map(|sym| *sym);
-        let amdsmi_get_processor_handles = __library
-            .get(b"amdsmi_get_processor_handles\0")
-            .map(|sym| *sym);
-        let amdsmi_get_gpu_device_uuid = __library
-            .get(b"amdsmi_get_gpu_device_uuid\0")
-            .map(|sym| *sym);
-        let amdsmi_get_gpu_pci_throughput = __library
-            .get(b"amdsmi_get_gpu_pci_throughput\0")
-            .map(|sym| *sym);
-        let amdsmi_get_energy_count = __library.get(b"amdsmi_get_energy_count\0").map(|sym| *sym);
-        let amdsmi_get_gpu_memory_usage = __library
-            .get(b"amdsmi_get_gpu_memory_usage\0")
-            .map(|sym| *sym);
-        let amdsmi_get_gpu_fan_speed = __library.get(b"amdsmi_get_gpu_fan_speed\0").map(|sym| *sym);
-        let amdsmi_get_gpu_volt_metric = __library
-            .get(b"amdsmi_get_gpu_volt_metric\0")
-            .map(|sym| *sym);
-        let amdsmi_status_code_to_string = __library
-            .get(b"amdsmi_status_code_to_string\0")
-            .map(|sym| *sym);
-        let amdsmi_get_gpu_asic_info = __library.get(b"amdsmi_get_gpu_asic_info\0").map(|sym| *sym);
-        let amdsmi_get_temp_metric = __library.get(b"amdsmi_get_temp_metric\0").map(|sym| *sym);
-        let amdsmi_get_gpu_activity = __library.get(b"amdsmi_get_gpu_activity\0").map(|sym| *sym);
-        let amdsmi_get_power_info = __library.get(b"amdsmi_get_power_info\0").map(|sym| *sym);
-        let amdsmi_is_gpu_power_management_enabled = __library
-            .get(b"amdsmi_is_gpu_power_management_enabled\0")
-            .map(|sym| *sym);
-        let amdsmi_get_clock_info = __library.get(b"amdsmi_get_clock_info\0").map(|sym| *sym);
-        let amdsmi_get_gpu_process_list = __library
-            .get(b"amdsmi_get_gpu_process_list\0")
-            .map(|sym| *sym);
-        Ok(libamd_smi {
-            __library,
-            amdsmi_init,
-            amdsmi_shut_down,
-            amdsmi_get_socket_handles,
-            amdsmi_get_processor_handles,
-            amdsmi_get_gpu_device_uuid,
-            amdsmi_get_gpu_pci_throughput,
-            amdsmi_get_energy_count,
-            amdsmi_get_gpu_memory_usage,
-            amdsmi_get_gpu_fan_speed,
-            amdsmi_get_gpu_volt_metric,
-            amdsmi_status_code_to_string,
-            amdsmi_get_gpu_asic_info,
-            amdsmi_get_temp_metric,
-            amdsmi_get_gpu_activity,
-            amdsmi_get_power_info,
-            amdsmi_is_gpu_power_management_enabled,
-            amdsmi_get_clock_info,
-            amdsmi_get_gpu_process_list,
-        })
+    pub unsafe fn from_library<L>(library: L) -> Result<Self, ::libloading::Error>
+    where
+        L: Into<::libloading::Library>,
+    {
+        let __library = library.into();
+        let amdsmi_init = __library.get(b"amdsmi_init\0").map(|sym| *sym);
+        let amdsmi_shut_down = __library.get(b"amdsmi_shut_down\0").map(|sym| *sym);
+        let amdsmi_get_socket_handles = __library
+            .get(b"amdsmi_get_socket_handles\0")
+            .map(|sym| *sym);
+        let amdsmi_get_socket_info = __library.get(b"amdsmi_get_socket_info\0").map(|sym| *sym);
+        let amdsmi_get_processor_handles_by_type = __library
+            .get(b"amdsmi_get_processor_handles_by_type\0")
+            .map(|sym| *sym);
+        let amdsmi_get_processor_handles = __library
+            .get(b"amdsmi_get_processor_handles\0")
+            .map(|sym| *sym);
+        let amdsmi_get_processor_type = __library
+            .get(b"amdsmi_get_processor_type\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_device_uuid = __library
+            .get(b"amdsmi_get_gpu_device_uuid\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_enumeration_info = __library
+            .get(b"amdsmi_get_gpu_enumeration_info\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_vendor_name = __library
+            .get(b"amdsmi_get_gpu_vendor_name\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_vram_vendor = __library
+            .get(b"amdsmi_get_gpu_vram_vendor\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_subsystem_name = __library
+            .get(b"amdsmi_get_gpu_subsystem_name\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_bdf_id = __library.get(b"amdsmi_get_gpu_bdf_id\0").map(|sym| *sym);
+        let amdsmi_get_gpu_pci_throughput = __library
+            .get(b"amdsmi_get_gpu_pci_throughput\0")
+            .map(|sym| *sym);
+        let amdsmi_get_energy_count = __library.get(b"amdsmi_get_energy_count\0").map(|sym| *sym);
+        let amdsmi_set_power_cap = __library.get(b"amdsmi_set_power_cap\0").map(|sym| *sym);
+        let amdsmi_get_cpu_socket_power = __library
+            .get(b"amdsmi_get_cpu_socket_power\0")
+            .map(|sym| *sym);
+        let amdsmi_get_cpu_socket_power_cap = __library
+            .get(b"amdsmi_get_cpu_socket_power_cap\0")
+            .map(|sym| *sym);
+        let amdsmi_get_cpu_socket_power_cap_max = __library
+            .get(b"amdsmi_get_cpu_socket_power_cap_max\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_memory_total = __library
+            .get(b"amdsmi_get_gpu_memory_total\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_memory_usage = __library
+            .get(b"amdsmi_get_gpu_memory_usage\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_bad_page_info = __library
+            .get(b"amdsmi_get_gpu_bad_page_info\0")
+            .map(|sym| *sym);
+        let amdsmi_gpu_validate_ras_eeprom = __library
+            .get(b"amdsmi_gpu_validate_ras_eeprom\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_ras_block_features_enabled = __library
+            .get(b"amdsmi_get_gpu_ras_block_features_enabled\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_fan_rpms = __library.get(b"amdsmi_get_gpu_fan_rpms\0").map(|sym| *sym);
+        let amdsmi_get_gpu_fan_speed = __library.get(b"amdsmi_get_gpu_fan_speed\0").map(|sym| *sym);
+        let amdsmi_get_gpu_fan_speed_max = __library
+            .get(b"amdsmi_get_gpu_fan_speed_max\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_volt_metric = __library
+            .get(b"amdsmi_get_gpu_volt_metric\0")
+            .map(|sym| *sym);
+        let amdsmi_reset_gpu_fan = __library.get(b"amdsmi_reset_gpu_fan\0").map(|sym| *sym);
+        let amdsmi_set_gpu_fan_speed = __library.get(b"amdsmi_set_gpu_fan_speed\0").map(|sym| *sym);
+        let amdsmi_get_utilization_count = __library
+            .get(b"amdsmi_get_utilization_count\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_perf_level = __library
+            .get(b"amdsmi_get_gpu_perf_level\0")
+            .map(|sym| *sym);
+        let amdsmi_set_gpu_perf_determinism_mode = __library
+            .get(b"amdsmi_set_gpu_perf_determinism_mode\0")
+            .map(|sym| *sym);
+        let amdsmi_get_clk_freq = __library.get(b"amdsmi_get_clk_freq\0").map(|sym| *sym);
+        let amdsmi_get_gpu_od_volt_info = __library
+            .get(b"amdsmi_get_gpu_od_volt_info\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_metrics_header_info = __library
+            .get(b"amdsmi_get_gpu_metrics_header_info\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_metrics_info = __library
+            .get(b"amdsmi_get_gpu_metrics_info\0")
+            .map(|sym| *sym);
+        let amdsmi_set_gpu_clk_range = __library.get(b"amdsmi_set_gpu_clk_range\0").map(|sym| *sym);
+        let amdsmi_get_gpu_power_profile_presets = __library
+            .get(b"amdsmi_get_gpu_power_profile_presets\0")
+            .map(|sym| *sym);
+        let amdsmi_set_gpu_perf_level = __library
+            .get(b"amdsmi_set_gpu_perf_level\0")
+            .map(|sym| *sym);
+        let amdsmi_set_clk_freq = __library.get(b"amdsmi_set_clk_freq\0").map(|sym| *sym);
+        let amdsmi_get_soc_pstate = __library.get(b"amdsmi_get_soc_pstate\0").map(|sym| *sym);
+        let amdsmi_get_xgmi_plpd = __library.get(b"amdsmi_get_xgmi_plpd\0").map(|sym| *sym);
+        let amdsmi_get_lib_version = __library.get(b"amdsmi_get_lib_version\0").map(|sym| *sym);
+        let amdsmi_get_gpu_ecc_count = __library.get(b"amdsmi_get_gpu_ecc_count\0").map(|sym| *sym);
+        let amdsmi_get_gpu_total_ecc_count = __library
+            .get(b"amdsmi_get_gpu_total_ecc_count\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_cper_entries = __library
+            .get(b"amdsmi_get_gpu_cper_entries\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_ras_feature_info = __library
+            .get(b"amdsmi_get_gpu_ras_feature_info\0")
+            .map(|sym| *sym);
+        let amdsmi_status_code_to_string = __library
+            .get(b"amdsmi_status_code_to_string\0")
+            .map(|sym| *sym);
+        let amdsmi_gpu_counter_group_supported = __library
+            .get(b"amdsmi_gpu_counter_group_supported\0")
+            .map(|sym| *sym);
+        let amdsmi_gpu_create_counter = __library
+            .get(b"amdsmi_gpu_create_counter\0")
+            .map(|sym| *sym);
+        let amdsmi_gpu_destroy_counter = __library
+            .get(b"amdsmi_gpu_destroy_counter\0")
+            .map(|sym| *sym);
+        let amdsmi_gpu_control_counter = __library
+            .get(b"amdsmi_gpu_control_counter\0")
+            .map(|sym| *sym);
+        let amdsmi_gpu_read_counter = __library.get(b"amdsmi_gpu_read_counter\0").map(|sym| *sym);
+        let amdsmi_get_gpu_available_counters = __library
+            .get(b"amdsmi_get_gpu_available_counters\0")
+            .map(|sym| *sym);
+        let amdsmi_gpu_xgmi_error_status = __library
+            .get(b"amdsmi_gpu_xgmi_error_status\0")
+            .map(|sym| *sym);
+        let amdsmi_get_xgmi_info = __library.get(b"amdsmi_get_xgmi_info\0").map(|sym| *sym);
+        let amdsmi_get_gpu_xgmi_link_status = __library
+            .get(b"amdsmi_get_gpu_xgmi_link_status\0")
+            .map(|sym| *sym);
+        let amdsmi_get_link_metrics = __library.get(b"amdsmi_get_link_metrics\0").map(|sym| *sym);
+        let amdsmi_topo_get_numa_node_number = __library
+            .get(b"amdsmi_topo_get_numa_node_number\0")
+            .map(|sym| *sym);
+        let amdsmi_topo_get_link_weight = __library
+            .get(b"amdsmi_topo_get_link_weight\0")
+            .map(|sym| *sym);
+        let amdsmi_topo_get_link_type = __library
+            .get(b"amdsmi_topo_get_link_type\0")
+            .map(|sym| *sym);
+        let amdsmi_is_P2P_accessible = __library.get(b"amdsmi_is_P2P_accessible\0").map(|sym| *sym);
+        let amdsmi_topo_get_p2p_status = __library
+            .get(b"amdsmi_topo_get_p2p_status\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_compute_partition = __library
+            .get(b"amdsmi_get_gpu_compute_partition\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_memory_partition = __library
+            .get(b"amdsmi_get_gpu_memory_partition\0")
+            .map(|sym| *sym);
+        let amdsmi_init_gpu_event_notification = __library
+            .get(b"amdsmi_init_gpu_event_notification\0")
+            .map(|sym| *sym);
+        let amdsmi_set_gpu_event_notification_mask = __library
+            .get(b"amdsmi_set_gpu_event_notification_mask\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_event_notification = __library
+            .get(b"amdsmi_get_gpu_event_notification\0")
+            .map(|sym| *sym);
+        let amdsmi_stop_gpu_event_notification = __library
+            .get(b"amdsmi_stop_gpu_event_notification\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_driver_info = __library
+            .get(b"amdsmi_get_gpu_driver_info\0")
+            .map(|sym| *sym);
+        let amdsmi_get_gpu_asic_info = __library.get(b"amdsmi_get_gpu_asic_info\0").map(|sym| *sym);
+        let amdsmi_get_gpu_kfd_info = __library.get(b"amdsmi_get_gpu_kfd_info\0").map(|sym| *sym);
+        let amdsmi_get_gpu_board_info = __library
+            .get(b"amdsmi_get_gpu_board_info\0")
+            .map(|sym| *sym);
+        let amdsmi_get_power_cap_info = __library
+            .get(b"amdsmi_get_power_cap_info\0")
+            .map(|sym| *sym);
+        let amdsmi_get_pcie_info = __library.get(b"amdsmi_get_pcie_info\0").map(|sym| *sym);
+        let amdsmi_get_fw_info = __library.get(b"amdsmi_get_fw_info\0").map(|sym| *sym);
+        let amdsmi_get_gpu_vbios_info = __library
+            .get(b"amdsmi_get_gpu_vbios_info\0")
+            .map(|sym| *sym);
+        let amdsmi_get_temp_metric = __library.get(b"amdsmi_get_temp_metric\0").map(|sym| *sym);
+        let amdsmi_get_gpu_activity = __library.get(b"amdsmi_get_gpu_activity\0").map(|sym| *sym);
+        let amdsmi_get_power_info = __library.get(b"amdsmi_get_power_info\0").map(|sym| *sym);
+        let amdsmi_is_gpu_power_management_enabled = __library
+            .get(b"amdsmi_is_gpu_power_management_enabled\0")
+            .map(|sym| *sym);
+        let amdsmi_get_clock_info = __library.get(b"amdsmi_get_clock_info\0").map(|sym| *sym);
+        let amdsmi_get_gpu_process_list = __library
+            .get(b"amdsmi_get_gpu_process_list\0")
+            .map(|sym| *sym);
+        let amdsmi_get_cpu_core_energy = __library
+            .get(b"amdsmi_get_cpu_core_energy\0")
+            .map(|sym| *sym);
+        let amdsmi_get_cpu_socket_energy = __library
+            .get(b"amdsmi_get_cpu_socket_energy\0")
+            .map(|sym| *sym);
+        let amdsmi_get_cpu_hsmp_driver_version = __library
+            .get(b"amdsmi_get_cpu_hsmp_driver_version\0")
+            .map(|sym| *sym);
+        let amdsmi_get_cpu_smu_fw_version = __library
+            .get(b"amdsmi_get_cpu_smu_fw_version\0")
+            .map(|sym| *sym);
+        let amdsmi_get_cpu_prochot_status = __library
+            .get(b"amdsmi_get_cpu_prochot_status\0")
+            .map(|sym| *sym);
+        let amdsmi_get_cpu_cclk_limit = __library
+            .get(b"amdsmi_get_cpu_cclk_limit\0")
+            .map(|sym| *sym);
+        let amdsmi_get_cpu_core_current_freq_limit = __library
+            .get(b"amdsmi_get_cpu_core_current_freq_limit\0")
+            .map(|sym| *sym);
+        let amdsmi_get_cpu_core_boostlimit = __library
+            .get(b"amdsmi_get_cpu_core_boostlimit\0")
+            .map(|sym| *sym);
+        let amdsmi_get_cpu_socket_temperature = __library
+            .get(b"amdsmi_get_cpu_socket_temperature\0")
+            .map(|sym| *sym);
+        let amdsmi_get_hsmp_metrics_table_version = __library
+            .get(b"amdsmi_get_hsmp_metrics_table_version\0")
+            .map(|sym| *sym);
+        let amdsmi_get_hsmp_metrics_table = __library
+            .get(b"amdsmi_get_hsmp_metrics_table\0")
+            .map(|sym| *sym);
+        Ok(libamd_smi {
+            __library,
+            amdsmi_init,
+            amdsmi_shut_down,
+            amdsmi_get_socket_handles,
+            amdsmi_get_socket_info,
+            amdsmi_get_processor_handles_by_type,
+            amdsmi_get_processor_handles,
+            amdsmi_get_processor_type,
+            amdsmi_get_gpu_device_uuid,
+            amdsmi_get_gpu_enumeration_info,
+            amdsmi_get_gpu_vendor_name,
+            amdsmi_get_gpu_vram_vendor,
+            amdsmi_get_gpu_subsystem_name,
+            amdsmi_get_gpu_bdf_id,
+            amdsmi_get_gpu_pci_throughput,
+            amdsmi_get_energy_count,
+            amdsmi_set_power_cap,
+            amdsmi_get_cpu_socket_power,
+            amdsmi_get_cpu_socket_power_cap,
+            amdsmi_get_cpu_socket_power_cap_max,
+            amdsmi_get_gpu_memory_total,
+            amdsmi_get_gpu_memory_usage,
+            amdsmi_get_gpu_bad_page_info,
+            amdsmi_gpu_validate_ras_eeprom,
+            amdsmi_get_gpu_ras_block_features_enabled,
+            amdsmi_get_gpu_fan_rpms,
+            amdsmi_get_gpu_fan_speed,
+            amdsmi_get_gpu_fan_speed_max,
+            amdsmi_get_gpu_volt_metric,
+            amdsmi_reset_gpu_fan,
+            amdsmi_set_gpu_fan_speed,
+            amdsmi_get_utilization_count,
+            amdsmi_get_gpu_perf_level,
+            amdsmi_set_gpu_perf_determinism_mode,
+            amdsmi_get_clk_freq,
+            amdsmi_get_gpu_od_volt_info,
+            amdsmi_get_gpu_metrics_header_info,
+            amdsmi_get_gpu_metrics_info,
+            amdsmi_set_gpu_clk_range,
+            amdsmi_get_gpu_power_profile_presets,
+            amdsmi_set_gpu_perf_level,
+            amdsmi_set_clk_freq,
+            amdsmi_get_soc_pstate,
+            amdsmi_get_xgmi_plpd,
+            amdsmi_get_lib_version,
+            amdsmi_get_gpu_ecc_count,
+            amdsmi_get_gpu_total_ecc_count,
+            amdsmi_get_gpu_cper_entries,
+            amdsmi_get_gpu_ras_feature_info,
+            amdsmi_status_code_to_string,
+            amdsmi_gpu_counter_group_supported,
+            amdsmi_gpu_create_counter,
+            amdsmi_gpu_destroy_counter,
+            amdsmi_gpu_control_counter,
+            amdsmi_gpu_read_counter,
+            amdsmi_get_gpu_available_counters,
+            amdsmi_gpu_xgmi_error_status,
+            amdsmi_get_xgmi_info,
+            amdsmi_get_gpu_xgmi_link_status,
+            amdsmi_get_link_metrics,
+            amdsmi_topo_get_numa_node_number,
+            amdsmi_topo_get_link_weight,
+            amdsmi_topo_get_link_type,
+            amdsmi_is_P2P_accessible,
+            amdsmi_topo_get_p2p_status,
+            amdsmi_get_gpu_compute_partition,
+            amdsmi_get_gpu_memory_partition,
+            amdsmi_init_gpu_event_notification,
+            amdsmi_set_gpu_event_notification_mask,
+            amdsmi_get_gpu_event_notification,
+            amdsmi_stop_gpu_event_notification,
+            amdsmi_get_gpu_driver_info,
+            amdsmi_get_gpu_asic_info,
+            amdsmi_get_gpu_kfd_info,
+            amdsmi_get_gpu_board_info,
+            amdsmi_get_power_cap_info,
+            amdsmi_get_pcie_info,
+            amdsmi_get_fw_info,
+            amdsmi_get_gpu_vbios_info,
+            amdsmi_get_temp_metric,
+            amdsmi_get_gpu_activity,
+            amdsmi_get_power_info,
+            amdsmi_is_gpu_power_management_enabled,
+            amdsmi_get_clock_info,
+            amdsmi_get_gpu_process_list,
+            amdsmi_get_cpu_core_energy,
+            amdsmi_get_cpu_socket_energy,
+            amdsmi_get_cpu_hsmp_driver_version,
+            amdsmi_get_cpu_smu_fw_version,
+            amdsmi_get_cpu_prochot_status,
+            amdsmi_get_cpu_cclk_limit,
+            amdsmi_get_cpu_core_current_freq_limit,
+            amdsmi_get_cpu_core_boostlimit,
+            amdsmi_get_cpu_socket_temperature,
+            amdsmi_get_hsmp_metrics_table_version,
+            amdsmi_get_hsmp_metrics_table,
+        })
+    }
+    #[doc = "```text\n  @brief Initialize the AMD SMI library\n\n  @ingroup tagInitShutdown\n\n  @platform{gpu_bm_linux} @platform{host} @platform{cpu_bm} @platform{guest_1vf}\n  @platform{guest_mvf} @platform{guest_windows}\n\n  @details This function initializes the library and the internal data structures,\n  including those corresponding to sources of information that SMI provides.\n  Singleton Design, requires the same number of inits as shutdowns.\n\n  The @p init_flags decides which type of processor\n  can be discovered by ::amdsmi_get_socket_handles(). AMDSMI_INIT_AMD_GPUS returns\n  sockets with AMD GPUS, and AMDSMI_INIT_AMD_GPUS | AMDSMI_INIT_AMD_CPUS returns\n  sockets with either AMD GPUS or CPUS.\n  Currently, only AMDSMI_INIT_AMD_GPUS is supported.\n\n  @param[in] init_flags Bit flags that tell SMI how to initialze. Values of\n  ::amdsmi_init_flags_t may be OR'd together and passed through @p init_flags\n  to modify how AMDSMI initializes.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_init(&self, init_flags: u64) -> amdsmi_status_t {
+        (self
+            .amdsmi_init
+            .as_ref()
+            .expect("Expected function, got error."))(init_flags)
+    }
+    #[doc = "```text\n  @brief Shutdown the AMD SMI library\n\n  @ingroup tagInitShutdown\n\n  @platform{gpu_bm_linux} @platform{host} @platform{cpu_bm} @platform{guest_1vf}\n  @platform{guest_mvf} @platform{guest_windows}\n\n  @details This function shuts down the library and internal data structures and\n  performs any necessary clean ups. Singleton Design, requires the same number\n  of inits as shutdowns.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_shut_down(&self) -> amdsmi_status_t {
+        (self
+            .amdsmi_shut_down
+            .as_ref()
+            .expect("Expected function, got error."))()
+    }
+    #[doc = "```text\n  @brief Get the list of socket handles in the system.\n\n  @ingroup tagProcDiscovery\n\n  @platform{gpu_bm_linux} @platform{host} @platform{cpu_bm} @platform{guest_1vf}\n  @platform{guest_mvf} @platform{guest_windows}\n\n  @details Depends on what flag is passed to ::amdsmi_init.  AMDSMI_INIT_AMD_GPUS\n  returns sockets with AMD GPUS, and AMDSMI_INIT_AMD_GPUS | AMDSMI_INIT_AMD_CPUS returns\n  sockets with either AMD GPUS or CPUS.\n  The socket handles can be used to query the processor handles in that socket, which\n  will be used in other APIs to get processor detail information or telemtries.\n\n  @param[in,out] socket_count As input, the value passed\n  through this parameter is the number of ::amdsmi_socket_handle that\n  may be safely written to the memory pointed to by @p socket_handles. This is the\n  limit on how many socket handles will be written to @p socket_handles. On return, @p\n  socket_count will contain the number of socket handles written to @p socket_handles,\n  or the number of socket handles that could have been written if enough memory had been\n  provided.\n  If @p socket_handles is NULL, as output, @p socket_count will contain\n  how many sockets are available to read in the system.\n\n  @param[in,out] socket_handles A pointer to a block of memory to which the\n  ::amdsmi_socket_handle values will be written. This value may be NULL.\n  In this case, this function can be used to query how many sockets are\n  available to read in the system.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_socket_handles(
+        &self,
+        socket_count: *mut u32,
+        socket_handles: *mut amdsmi_socket_handle,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_socket_handles
+            .as_ref()
+            .expect("Expected function, got error."))(socket_count, socket_handles)
+    }
+    #[doc = "```text\n  @brief Get information about the given socket\n\n  @ingroup tagProcDiscovery\n\n  @platform{gpu_bm_linux} @platform{host} @platform{guest_1vf}\n  @platform{guest_mvf} @platform{guest_windows}\n\n  @details This function retrieves socket information. The @p socket_handle must\n  be provided to retrieve the Socket ID.\n\n  @param[in] socket_handle a socket handle\n\n  @param[in] len the length of the caller provided buffer @p name.\n\n  @param[out] name The id of the socket.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_socket_info(
+        &self,
+        socket_handle: amdsmi_socket_handle,
+        len: usize,
+        name: *mut ::std::os::raw::c_char,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_socket_info
+            .as_ref()
+            .expect("Expected function, got error."))(socket_handle, len, name)
+    }
+    #[doc = "```text\n  @brief Get processor list as per processor type\n\n  @ingroup tagProcDiscovery\n\n  @platform{cpu_bm}\n\n  @details This function retrieves processor list as per the processor type\n  from the total processor handles list.\n  The @p list of processor_handles and processor type must be provided.\n\n  @param[in] socket_handle socket handle\n\n  @param[in] processor_type processor type\n\n  @param[out] processor_handles list of processor handles as per processor type\n\n  @param[out] processor_count processor count as per processor type selected\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_processor_handles_by_type(
+        &self,
+        socket_handle: amdsmi_socket_handle,
+        processor_type: processor_type_t,
+        processor_handles: *mut amdsmi_processor_handle,
+        processor_count: *mut u32,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_processor_handles_by_type
+            .as_ref()
+            .expect("Expected function, got error."))(
+            socket_handle,
+            processor_type,
+            processor_handles,
+            processor_count,
+        )
+    }
+    #[doc = "```text\n  @brief Get the list of the processor handles associated to a socket.\n\n  @ingroup tagProcDiscovery\n\n  @platform{gpu_bm_linux} @platform{host} @platform{guest_1vf}\n  @platform{guest_mvf} @platform{guest_windows}\n\n  @details This function retrieves the processor handles of a socket. The\n  @p socket_handle must be provided for the processor. A socket may have mulitple different\n  type processors: An APU on a socket have both CPUs and GPUs.\n  Currently, only AMD GPUs are supported.\n\n  @note Sockets are not supported on the @platform{host}.\n\n  The number of processor count is returned through @p processor_count\n  if @p processor_handles is NULL. Then the number of @p processor_count can be pass\n  as input to retrieval all processors on the socket to @p processor_handles.\n\n  @param[in] socket_handle The socket to query\n\n  @param[in,out] processor_count As input, the value passed\n  through this parameter is the number of ::amdsmi_processor_handle's that\n  may be safely written to the memory pointed to by @p processor_handles. This is the\n  limit on how many processor handles will be written to @p processor_handles. On return, @p\n  processor_count will contain the number of processor handles written to @p processor_handles,\n  or the number of processor handles that could have been written if enough memory had been\n  provided.\n  If @p processor_handles is NULL, as output, @p processor_count will contain\n  how many processors are available to read for the socket.\n\n  @param[in,out] processor_handles A pointer to a block of memory to which the\n  ::amdsmi_processor_handle values will be written. This value may be NULL.\n  In this case, this function can be used to query how many processors are\n  available to read.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_processor_handles(
+        &self,
+        socket_handle: amdsmi_socket_handle,
+        processor_count: *mut u32,
+        processor_handles: *mut amdsmi_processor_handle,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_processor_handles
+            .as_ref()
+            .expect("Expected function, got error."))(
+            socket_handle,
+            processor_count,
+            processor_handles,
+        )
+    }
+    #[doc = "```text\n  @brief Get the processor type of the processor_handle\n\n  @ingroup tagProcDiscovery\n\n  @platform{gpu_bm_linux} @platform{host} @platform{cpu_bm} @platform{guest_1vf}\n  @platform{guest_mvf} @platform{guest_windows}\n\n  @details This function retrieves the processor type. A processor_handle must be provided\n  for that processor.\n\n  @param[in] processor_handle a processor handle\n\n  @param[out] processor_type a pointer to processor_type_t to which the processor type\n  will be written. If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_processor_type(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        processor_type: *mut processor_type_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_processor_type
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, processor_type)
+    }
+    #[doc = "```text\n  @brief Returns the UUID of the device\n\n  @ingroup tagProcDiscovery\n\n  @platform{gpu_bm_linux} @platform{host} @platform{guest_1vf} @platform{guest_mvf}\n  @platform{guest_windows}\n\n  @param[in] processor_handle Device which to query\n\n  @param[in,out] uuid_length Length of the uuid string. As input, must be\n                 equal or greater than AMDSMI_GPU_UUID_SIZE and be allocated by\n                 user. As output it is the length of the uuid string.\n\n  @param[out] uuid Pointer to string to store the UUID. Must be\n              allocated by user.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_device_uuid(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        uuid_length: *mut ::std::os::raw::c_uint,
+        uuid: *mut ::std::os::raw::c_char,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_device_uuid
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, uuid_length, uuid)
+    }
+    #[doc = "```text\n  @brief          Returns the Enumeration information for the device\n\n  @ingroup tagProcDiscovery\n\n  @platform{gpu_bm_linux} @platform{guest_1vf} @platform{guest_mvf}\n\n  @details        This function returns Enumeration information of the corresponding\n                  processor_handle. It will return the render number, card number,\n                  HSA ID, HIP ID, and the HIP UUID.\n\n  @param[in]      processor_handle Device which to query\n\n  @param[out]     info Reference to Enumeration information structure.\n                  Must be allocated by user.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_enumeration_info(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        info: *mut amdsmi_enumeration_info_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_enumeration_info
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, info)
+    }
+    #[doc = "```text\n  @brief Get the name string for a give vendor ID\n\n  @ingroup tagIdentQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle, a pointer to a caller provided\n  char buffer @p name, and a length of this buffer @p len, this function will\n  write the name of the vendor (up to @p len characters) buffer @p name. The\n  @p id may be a device vendor or subsystem vendor ID.\n\n  If the integer ID associated with the vendor is not found in one of the\n  system files containing device name information (e.g.\n  /usr/share/misc/pci.ids), then this function will return the hex vendor ID\n  as a string. Updating the system name files can be accompplished with\n  \"sudo update-pciids\".\n\n  @param[in] processor_handle a processor handle\n\n  @param[in,out] name a pointer to a caller provided char buffer to which the\n  name will be written\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @param[in] len the length of the caller provided buffer @p name.\n\n  @note ::AMDSMI_STATUS_INSUFFICIENT_SIZE is returned if @p len bytes is not\n  large enough to hold the entire name. In this case, only @p len bytes will\n  be written.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_vendor_name(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        name: *mut ::std::os::raw::c_char,
+        len: usize,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_vendor_name
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, name, len)
+    }
+    #[doc = "```text\n  @brief Get the vram vendor string of a device.\n\n  @ingroup tagIdentQuery\n\n  @platform{gpu_bm_linux}\n\n  @details This function retrieves the vram vendor name given a processor handle\n  @p processor_handle, a pointer to a caller provided\n  char buffer @p brand, and a length of this buffer @p len, this function\n  will write the vram vendor of the device (up to @p len characters) to the\n  buffer @p brand.\n\n  If the vram vendor for the device is not found as one of the values\n  contained within amdsmi_get_gpu_vram_vendor, then this function will return\n  the string 'unknown' instead of the vram vendor.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in,out] brand a pointer to a caller provided char buffer to which the\n  vram vendor will be written\n\n  @param[in] len the length of the caller provided buffer @p brand.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_vram_vendor(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        brand: *mut ::std::os::raw::c_char,
+        len: u32,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_vram_vendor
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, brand, len)
+    }
+    #[doc = "```text\n  @brief Get the name string for the device subsytem\n\n  @ingroup tagIdentQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle, a pointer to a caller provided\n  char buffer @p name, and a length of this buffer @p len, this function\n  will write the name of the device subsystem (up to @p len characters)\n  to the buffer @p name.\n\n  If the integer ID associated with the sub-system is not found in one of the\n  system files containing device name information (e.g.\n  /usr/share/misc/pci.ids), then this function will return the hex sub-system\n  ID as a string. Updating the system name files can be accompplished with\n  \"sudo update-pciids\".\n\n  @param[in] processor_handle a processor handle\n\n  @param[in,out] name a pointer to a caller provided char buffer to which the\n  name will be written\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @param[in] len the length of the caller provided buffer @p name.\n\n  @note ::AMDSMI_STATUS_INSUFFICIENT_SIZE is returned if @p len bytes is not\n  large enough to hold the entire name. In this case, only @p len bytes will\n  be written.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_subsystem_name(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        name: *mut ::std::os::raw::c_char,
+        len: usize,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_subsystem_name
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, name, len)
+    }
+    #[doc = "```text\n  @brief Get the unique PCI device identifier associated for a device\n\n  @ingroup tagPCIeQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Give a processor handle @p processor_handle and a pointer to a uint64_t @p\n  bdfid, this function will write the Bus/Device/Function PCI identifier\n  (BDFID) associated with device @p processor_handle to the value pointed to by\n  @p bdfid.\n\n  The format of @p bdfid will be as follows:\n\n      BDFID = ((DOMAIN & 0xFFFFFFFF) << 32) | ((Partition & 0xF) << 28)\n              | ((BUS & 0xFF) << 8) | ((DEVICE & 0x1F) <<3 )\n              | (FUNCTION & 0x7)\n\n  | Name         | Field   | KFD property     | KFD -> PCIe ID (uint64_t)    |\n  -------------- | ------- | ---------------- | ---------------------------- |\n  | Domain       | [63:32] | \"domain\"         | (DOMAIN & 0xFFFFFFFF) << 32  |\n  | Partition id | [31:28] | \"location id\"    | (LOCATION & 0xF0000000)      |\n  | Reserved     | [27:16] | \"location id\"    | N/A                          |\n  | Bus          | [15: 8] | \"location id\"    | (LOCATION & 0xFF00)          |\n  | Device       | [ 7: 3] | \"location id\"    | (LOCATION & 0xF8)            |\n  | Function     | [ 2: 0] | \"location id\"    | (LOCATION & 0x7)             |\n\n  @param[in] processor_handle a processor handle\n\n  @param[in,out] bdfid a pointer to uint64_t to which the device bdfid value\n  will be written\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_bdf_id(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        bdfid: *mut u64,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_bdf_id
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, bdfid)
+    }
+    #[doc = "```text\n  @brief Get PCIe traffic information. It is not supported on virtual machine guest\n\n  @ingroup tagPCIeQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Give a processor handle @p processor_handle and pointers to a uint64_t's, @p\n  sent, @p received and @p max_pkt_sz, this function will write the number\n  of bytes sent and received in 1 second to @p sent and @p received,\n  respectively. The maximum possible packet size will be written to\n  @p max_pkt_sz.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in,out] sent a pointer to uint64_t to which the number of bytes sent\n  will be written in 1 second. If pointer is NULL, it will be ignored.\n\n  @param[in,out] received a pointer to uint64_t to which the number of bytes\n  received will be written. If pointer is NULL, it will be ignored.\n\n  @param[in,out] max_pkt_sz a pointer to uint64_t to which the maximum packet\n  size will be written. If pointer is NULL, it will be ignored.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_pci_throughput(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        sent: *mut u64,
+        received: *mut u64,
+        max_pkt_sz: *mut u64,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_pci_throughput
+            .as_ref()
+            .expect("Expected function, got error."))(
+            processor_handle, sent, received, max_pkt_sz
+        )
+    }
+    #[doc = "```text\n  @brief Get the energy accumulator counter of the processor with provided\n  processor handle. It is not supported on virtual machine guest\n\n  @ingroup tagPowerQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle, a pointer to a uint64_t\n  @p energy_accumulator, and a pointer to a uint64_t @p timestamp, this function\n  will write amount of energy consumed to the uint64_t pointed to by\n  @p energy_accumulator, and the timestamp to the uint64_t pointed to by @p timestamp.\n  This function accumulates all energy consumed.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in,out] energy_accumulator a pointer to uint64_t to which the energy\n  counter will be written\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @param[in,out] counter_resolution resolution of the counter @p energy_accumulator in\n  micro Joules\n\n  @param[in,out] timestamp a pointer to uint64_t to which the timestamp\n  will be written. Resolution: 1 ns.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_energy_count(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        energy_accumulator: *mut u64,
+        counter_resolution: *mut f32,
+        timestamp: *mut u64,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_energy_count
+            .as_ref()
+            .expect("Expected function, got error."))(
+            processor_handle,
+            energy_accumulator,
+            counter_resolution,
+            timestamp,
+        )
+    }
+    #[doc = "```text\n  @brief Set the maximum gpu power cap value. It is not supported on virtual\n  machine guest\n\n  @ingroup tagPowerControl\n\n  @platform{host} @platform{gpu_bm_linux} @platform{guest_1vf}\n\n  @details Set the power cap to the provided value @p cap.\n  @p cap must be between the minimum and maximum power cap values set by the\n  system, which can be obtained from ::amdsmi_dev_power_cap_range_get.\n\n  @param[in] processor_handle A processor handle\n\n  @param[in] sensor_ind a 0-based sensor index. Normally, this will be 0.\n  If a processor has more than one sensor, it could be greater than 0.\n\n  @param[in] cap a uint64_t that indicates the desired power cap.\n  The @p cap value must be greater than 0.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_set_power_cap(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        sensor_ind: u32,
+        cap: u64,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_set_power_cap
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, sensor_ind, cap)
+    }
+    #[doc = "```text\n  @brief Get the socket power.\n\n  @ingroup tagPowerControl\n\n  @platform{cpu_bm}\n\n  @param[in]      processor_handle Cpu socket which to query\n\n  @param[in,out]    ppower - Input buffer to return socket power\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_cpu_socket_power(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        ppower: *mut u32,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_cpu_socket_power
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, ppower)
+    }
+    #[doc = "```text\n  @brief Get the socket power cap.\n\n  @ingroup tagPowerControl\n\n  @platform{cpu_bm}\n\n  @param[in]      processor_handle Cpu socket which to query\n\n  @param[in,out]    pcap - Input buffer to return power cap.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_cpu_socket_power_cap(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        pcap: *mut u32,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_cpu_socket_power_cap
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, pcap)
+    }
+    #[doc = "```text\n  @brief Get the maximum power cap value for a given socket.\n\n  @ingroup tagPowerControl\n\n  @platform{cpu_bm}\n\n  @param[in]      processor_handle Cpu socket which to query\n\n  @param[in,out]    pmax - Input buffer to return maximum power limit value\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_cpu_socket_power_cap_max(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        pmax: *mut u32,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_cpu_socket_power_cap_max
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, pmax)
+    }
+    #[doc = "```text\n  @brief Get the total amount of memory that exists\n\n  @ingroup tagMemoryQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle, a type of memory @p mem_type, and\n  a pointer to a uint64_t @p total, this function will write the total amount\n  of @p mem_type memory that exists to the location pointed to by @p total.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] mem_type The type of memory for which the total amount will be\n  found\n\n  @param[in,out] total a pointer to uint64_t to which the total amount of\n  memory will be written\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_memory_total(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        mem_type: amdsmi_memory_type_t,
+        total: *mut u64,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_memory_total
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, mem_type, total)
+    }
+    #[doc = "```text\n  @brief Get the current memory usage\n\n  @ingroup tagMemoryQuery\n\n  @platform{gpu_bm_linux}\n\n  @details This function will write the amount of @p mem_type memory that\n  that is currently being used to the location pointed to by @p used.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] mem_type The type of memory for which the amount being used will\n  be found\n\n  @param[in,out] used a pointer to uint64_t to which the amount of memory\n  currently being used will be written\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_memory_usage(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        mem_type: amdsmi_memory_type_t,
+        used: *mut u64,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_memory_usage
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, mem_type, used)
+    }
+    #[doc = "```text\n  @brief Get the bad pages of a processor. It is not supported on virtual\n  machine guest\n\n  @ingroup tagMemoryQuery\n\n  @platform{gpu_bm_linux}\n\n  @details This call will query the device @p processor_handle for the\n  number of bad pages (written to @p num_pages address). The results are\n  written to address held by the @p info pointer.\n  The first call to this API returns the number of bad pages which\n  should be used to allocate the buffer that should contain the bad page\n  records.\n\n  @param[in] processor_handle a processor handle\n\n  @param[out] num_pages Number of bad page records.\n\n  @param[out] info The results will be written to the\n  amdsmi_retired_page_record_t pointer.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_bad_page_info(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        num_pages: *mut u32,
+        info: *mut amdsmi_retired_page_record_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_bad_page_info
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, num_pages, info)
+    }
+    #[doc = "```text\n  @brief Verify the checksum of RAS EEPROM. It is not supported on virtual\n  machine guest\n\n  @ingroup tagMemoryQuery\n\n  @platform{gpu_bm_linux}\n\n  @details This call will verify the device @p processor_handle for the\n  checksum of RAS EEPROM.\n\n  @param[in] processor_handle a processor handle\n\n  @note This function requires root access\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success\n          AMDSMI_STATUS_CORRUPTED_EEPROM on the device's EEPROM corruption\n          others on fail\n```"]
+    pub unsafe fn amdsmi_gpu_validate_ras_eeprom(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_gpu_validate_ras_eeprom
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle)
+    }
+    #[doc = "```text\n  @brief Returns if RAS features are enabled or disabled for given block. It is not\n  supported on virtual machine guest\n\n  @ingroup tagMemoryQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle, this function queries the\n  state of RAS features for a specific block @p block. Result will be written\n  to address held by pointer @p state.\n\n  @param[in] processor_handle Device handle which to query\n\n  @param[in] block Block which to query\n\n  @param[in,out] state A pointer to amdsmi_ras_err_state_t to which the state\n  of block will be written.\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_ras_block_features_enabled(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        block: amdsmi_gpu_block_t,
+        state: *mut amdsmi_ras_err_state_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_ras_block_features_enabled
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, block, state)
+    }
+    #[doc = "```text\n  @brief Get the fan speed in RPMs of the device with the specified processor\n  handle and 0-based sensor index. It is not supported on virtual machine guest\n\n  @ingroup tagPhysicalStateQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle and a pointer to a uint32_t\n  @p speed, this function will write the current fan speed in RPMs to the\n  uint32_t pointed to by @p speed\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] sensor_ind a 0-based sensor index. Normally, this will be 0.\n  If a device has more than one sensor, it could be greater than 0.\n\n  @param[in,out] speed a pointer to uint32_t to which the speed will be\n  written\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_fan_rpms(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        sensor_ind: u32,
+        speed: *mut i64,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_fan_rpms
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, sensor_ind, speed)
+    }
+    #[doc = "```text\n  @brief Get the fan speed for the specified device as a value relative to\n  ::AMDSMI_MAX_FAN_SPEED. It is not supported on virtual machine guest\n\n  @ingroup tagPhysicalStateQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle and a pointer to a uint32_t\n  @p speed, this function will write the current fan speed (a value\n  between 0 and the maximum fan speed, ::AMDSMI_MAX_FAN_SPEED) to the uint32_t\n  pointed to by @p speed\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] sensor_ind a 0-based sensor index. Normally, this will be 0.\n  If a device has more than one sensor, it could be greater than 0.\n\n  @param[in,out] speed a pointer to uint32_t to which the speed will be\n  written\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_fan_speed(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        sensor_ind: u32,
+        speed: *mut i64,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_fan_speed
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, sensor_ind, speed)
+    }
+    #[doc = "```text\n  @brief Get the max. fan speed of the device with provided processor handle. It is\n  not supported on virtual machine guest\n\n  @ingroup tagPhysicalStateQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle and a pointer to a uint32_t\n  @p max_speed, this function will write the maximum fan speed possible to\n  the uint32_t pointed to by @p max_speed\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] sensor_ind a 0-based sensor index. Normally, this will be 0.\n  If a device has more than one sensor, it could be greater than 0.\n\n  @param[in,out] max_speed a pointer to uint32_t to which the maximum speed\n  will be written\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_fan_speed_max(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        sensor_ind: u32,
+        max_speed: *mut u64,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_fan_speed_max
+            .as_ref()
+            .expect("Expected function, got error."))(
+            processor_handle, sensor_ind, max_speed
+        )
+    }
+    #[doc = "```text\n  @brief Get the voltage metric value for the specified metric, from the\n  specified voltage sensor on the specified device. It is not supported on\n  virtual machine guest\n\n  @ingroup tagPhysicalStateQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle, a sensor type @p sensor_type, a\n  ::amdsmi_voltage_metric_t @p metric and a pointer to an int64_t @p\n  voltage, this function will write the value of the metric indicated by\n  @p metric and @p sensor_type to the memory location @p voltage.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] sensor_type part of device from which voltage should be\n  obtained. This should come from the enum ::amdsmi_voltage_type_t\n\n  @param[in] metric enum indicated which voltage value should be\n  retrieved\n\n  @param[in,out] voltage a pointer to int64_t to which the voltage\n  will be written, in millivolts.\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_volt_metric(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        sensor_type: amdsmi_voltage_type_t,
+        metric: amdsmi_voltage_metric_t,
+        voltage: *mut i64,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_volt_metric
+            .as_ref()
+            .expect("Expected function, got error."))(
+            processor_handle,
+            sensor_type,
+            metric,
+            voltage,
+        )
+    }
+    #[doc = "```text\n  @brief Reset the fan to automatic driver control. It is not supported on virtual\n  machine guest\n\n  @ingroup tagPhysicalStateControl\n\n  @platform{gpu_bm_linux}\n\n  @details This function returns control of the fan to the system\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] sensor_ind a 0-based sensor index. Normally, this will be 0.\n  If a device has more than one sensor, it could be greater than 0.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_reset_gpu_fan(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        sensor_ind: u32,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_reset_gpu_fan
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, sensor_ind)
+    }
+    #[doc = "```text\n  @brief Set the fan speed for the specified device with the provided speed,\n  in RPMs. It is not supported on virtual machine guest\n\n  @ingroup tagPhysicalStateControl\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle and a integer value indicating\n  speed @p speed, this function will attempt to set the fan speed to @p speed.\n  An error will be returned if the specified speed is outside the allowable\n  range for the device. The maximum value is 255 and the minimum is 0.\n\n  @note This function requires root access\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] sensor_ind a 0-based sensor index. Normally, this will be 0.\n  If a device has more than one sensor, it could be greater than 0.\n\n  @param[in] speed the speed to which the function will attempt to set the fan\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_set_gpu_fan_speed(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        sensor_ind: u32,
+        speed: u64,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_set_gpu_fan_speed
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, sensor_ind, speed)
+    }
+    #[doc = "```text\n  @brief Get coarse grain utilization counter of the specified device\n\n  @ingroup tagClkPowerPerfQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle, the array of the utilization counters,\n  the size of the array, this function returns the coarse grain utilization counters\n  and timestamp.\n  The counter is the accumulated percentages. Every milliseconds the firmware calculates\n  % busy count and then accumulates that value in the counter. This provides minimally\n  invasive coarse grain GPU usage information.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in,out] utilization_counters Multiple utilization counters can be retreived with a single\n  call. The caller must allocate enough space to the utilization_counters array. The caller also\n  needs to set valid AMDSMI_UTILIZATION_COUNTER_TYPE type for each element of the array.\n  ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the provided arguments.\n\n  If the function reutrns AMDSMI_STATUS_SUCCESS, the counter will be set in the value field of\n  the amdsmi_utilization_counter_t.\n\n  @param[in] count The size of @p utilization_counters array.\n\n  @param[in,out] timestamp The timestamp when the counter is retrieved. Resolution: 1 ns.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_utilization_count(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        utilization_counters: *mut amdsmi_utilization_counter_t,
+        count: u32,
+        timestamp: *mut u64,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_utilization_count
+            .as_ref()
+            .expect("Expected function, got error."))(
+            processor_handle,
+            utilization_counters,
+            count,
+            timestamp,
+        )
+    }
+    #[doc = "```text\n  @brief Get the performance level of the device. It is not supported on virtual\n  machine guest\n\n  @ingroup tagClkPowerPerfQuery\n\n  @platform{gpu_bm_linux}\n\n  @details This function will write the ::amdsmi_dev_perf_level_t to the uint32_t\n  pointed to by @p perf, for a given processor handle @p processor_handle and a pointer\n  to a uint32_t @p perf.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in,out] perf a pointer to ::amdsmi_dev_perf_level_t to which the\n  performance level will be written\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_perf_level(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        perf: *mut amdsmi_dev_perf_level_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_perf_level
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, perf)
+    }
+    #[doc = "```text\n  @brief Enter performance determinism mode with provided processor handle. It is\n  not supported on virtual machine guest\n\n  @ingroup tagClkPowerPerfQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle and @p clkvalue this function\n  will enable performance determinism mode, which enforces a GFXCLK frequency\n  SoftMax limit per GPU set by the user. This prevents the GFXCLK PLL from\n  stretching when running the same workload on different GPUS, making\n  performance variation minimal. This call will result in the performance\n  level ::amdsmi_dev_perf_level_t of the device being\n  ::AMDSMI_DEV_PERF_LEVEL_DETERMINISM.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] clkvalue Softmax value for GFXCLK in MHz.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_set_gpu_perf_determinism_mode(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        clkvalue: u64,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_set_gpu_perf_determinism_mode
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, clkvalue)
+    }
+    #[doc = "```text\n  @brief Get the list of possible system clock speeds of device for a\n  specified clock type. It is not supported on virtual machine guest\n\n  @ingroup tagClkPowerPerfQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle, a clock type @p clk_type, and a\n  pointer to a to an ::amdsmi_frequencies_t structure @p f, this function will\n  fill in @p f with the possible clock speeds, and indication of the current\n  clock speed selection.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] clk_type the type of clock for which the frequency is desired\n\n  @param[in,out] f a pointer to a caller provided ::amdsmi_frequencies_t structure\n  to which the frequency information will be written. Frequency values are in\n  Hz.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_clk_freq(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        clk_type: amdsmi_clk_type_t,
+        f: *mut amdsmi_frequencies_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_clk_freq
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, clk_type, f)
+    }
+    #[doc = "```text\n  @brief This function retrieves the overdrive GFX & MCLK information. If valid\n  for the GPU it will also populate the voltage curve data. It is not supported\n  on virtual machine guest\n\n  @ingroup tagClkPowerPerfQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle and a pointer to a\n  ::amdsmi_od_volt_freq_data_t structure @p odv, this function will populate @p\n  odv. See ::amdsmi_od_volt_freq_data_t for more details.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in,out] odv a pointer to an ::amdsmi_od_volt_freq_data_t structure\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_od_volt_info(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        odv: *mut amdsmi_od_volt_freq_data_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_od_volt_info
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, odv)
+    }
+    #[doc = "```text\n  @brief Get the 'metrics_header_info' from the GPU metrics associated with the device\n\n  @ingroup tagClkPowerPerfQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle and a pointer to a amd_metrics_table_header_t in which\n  the 'metrics_header_info' will stored\n\n  @param[in] processor_handle Device which to query\n\n  @param[inout] header_value a pointer to amd_metrics_table_header_t to which the device gpu\n  metric unit will be stored\n\n  @retval ::AMDSMI_STATUS_SUCCESS is returned upon successful call.\n          ::AMDSMI_STATUS_NOT_SUPPORTED is returned in case the metric unit\n            does not exist for the given device\n  @return ::amdsmi_status_t\n```"]
+    pub unsafe fn amdsmi_get_gpu_metrics_header_info(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        header_value: *mut amd_metrics_table_header_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_metrics_header_info
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, header_value)
+    }
+    #[doc = "```text\n  @brief This function retrieves the gpu metrics information. It is not supported\n  on virtual machine guest\n\n  @ingroup tagClkPowerPerfQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle and a pointer to a\n  ::amdsmi_gpu_metrics_t structure @p pgpu_metrics, this function will populate\n  @p pgpu_metrics. See ::amdsmi_gpu_metrics_t for more details.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in,out] pgpu_metrics a pointer to an ::amdsmi_gpu_metrics_t structure\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_metrics_info(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        pgpu_metrics: *mut amdsmi_gpu_metrics_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_metrics_info
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, pgpu_metrics)
+    }
+    #[doc = "```text\n  @brief This function sets the clock range information. It is not supported on virtual\n  machine guest\n\n  @deprecated ::amdsmi_set_gpu_clk_limit() should be used, with an\n  interface that set the min_value and then max_value.\n\n  @ingroup tagClkPowerPerfQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle, a minimum clock value @p minclkvalue,\n  a maximum clock value @p maxclkvalue and a clock type @p clkType this function\n  will set the sclk|mclk range\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] minclkvalue value to apply to the clock range. Frequency values\n  are in MHz.\n\n  @param[in] maxclkvalue value to apply to the clock range. Frequency values\n  are in MHz.\n\n  @param[in] clkType AMDSMI_CLK_TYPE_SYS | AMDSMI_CLK_TYPE_MEM range type\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_set_gpu_clk_range(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        minclkvalue: u64,
+        maxclkvalue: u64,
+        clkType: amdsmi_clk_type_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_set_gpu_clk_range
+            .as_ref()
+            .expect("Expected function, got error."))(
+            processor_handle,
+            minclkvalue,
+            maxclkvalue,
+            clkType,
+        )
+    }
+    #[doc = "```text\n  @brief Get the list of available preset power profiles and an indication of\n  which profile is currently active. It is not supported on virtual machine guest\n\n  @ingroup tagClkPowerPerfQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle and a pointer to a\n  ::amdsmi_power_profile_status_t @p status, this function will set the bits of\n  the ::amdsmi_power_profile_status_t.available_profiles bit field of @p status to\n  1 if the profile corresponding to the respective\n  ::amdsmi_power_profile_preset_masks_t profiles are enabled. For example, if both\n  the VIDEO and VR power profiles are available selections, then\n  ::AMDSMI_PWR_PROF_PRST_VIDEO_MASK AND'ed with\n  ::amdsmi_power_profile_status_t.available_profiles will be non-zero as will\n  ::AMDSMI_PWR_PROF_PRST_VR_MASK AND'ed with\n  ::amdsmi_power_profile_status_t.available_profiles. Additionally,\n  ::amdsmi_power_profile_status_t.current will be set to the\n  ::amdsmi_power_profile_preset_masks_t of the profile that is currently active.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] sensor_ind a 0-based sensor index. Normally, this will be 0.\n  If a device has more than one sensor, it could be greater than 0.\n\n  @param[in,out] status a pointer to ::amdsmi_power_profile_status_t that will be\n  populated by a call to this function\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_power_profile_presets(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        sensor_ind: u32,
+        status: *mut amdsmi_power_profile_status_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_power_profile_presets
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, sensor_ind, status)
+    }
+    #[doc = "```text\n  @brief Set the PowerPlay performance level associated with the device with\n  provided processor handle with the provided value. It is not supported\n  on virtual machine guest\n\n  @ingroup tagClkPowerPerfControl\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle and an ::amdsmi_dev_perf_level_t @p\n  perf_level, this function will set the PowerPlay performance level for the\n  device to the value @p perf_lvl.\n\n  @note This function requires root access\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] perf_lvl the value to which the performance level should be set\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_set_gpu_perf_level(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        perf_lvl: amdsmi_dev_perf_level_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_set_gpu_perf_level
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, perf_lvl)
+    }
+    #[doc = "```text\n  @brief Control the set of allowed frequencies that can be used for the\n  specified clock. It is not supported on virtual machine guest\n\n  @ingroup tagClkPowerPerfControl\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle, a clock type @p clk_type, and a\n  64 bit bitmask @p freq_bitmask, this function will limit the set of\n  allowable frequencies. If a bit in @p freq_bitmask has a value of 1, then\n  the frequency (as ordered in an ::amdsmi_frequencies_t returned by\n  amdsmi_get_clk_freq()) corresponding to that bit index will be\n  allowed.\n\n  This function will change the performance level to\n  ::AMDSMI_DEV_PERF_LEVEL_MANUAL in order to modify the set of allowable\n  frequencies. Caller will need to set to ::AMDSMI_DEV_PERF_LEVEL_AUTO in order\n  to get back to default state.\n\n  All bits with indices greater than or equal to\n  ::amdsmi_frequencies_t::num_supported will be ignored.\n\n  @note This function requires root access\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] clk_type the type of clock for which the set of frequencies\n  will be modified\n\n  @param[in] freq_bitmask A bitmask indicating the indices of the\n  frequencies that are to be enabled (1) and disabled (0). Only the lowest\n  ::amdsmi_frequencies_t.num_supported bits of this mask are relevant.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_set_clk_freq(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        clk_type: amdsmi_clk_type_t,
+        freq_bitmask: u64,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_set_clk_freq
+            .as_ref()
+            .expect("Expected function, got error."))(
+            processor_handle, clk_type, freq_bitmask
+        )
+    }
+    #[doc = "```text\n  @brief Get the soc pstate policy for the processor\n\n  @ingroup tagClkPowerPerfControl\n\n  @platform{gpu_bm_linux} @platform{guest_1vf} @platform{host}\n\n  @details Given a processor handle @p processor_handle, this function will write\n  current soc pstate  policy settings to @p policy. All the processors at the same socket\n  will have the same policy.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in,out] policy the soc pstate policy for this processor.\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_soc_pstate(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        policy: *mut amdsmi_dpm_policy_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_soc_pstate
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, policy)
+    }
+    #[doc = "```text\n  @brief Get the xgmi per-link power down policy parameter for the processor\n\n  @ingroup tagClkPowerPerfControl\n\n  @platform{gpu_bm_linux} @platform{guest_1vf} @platform{host}\n\n  @details Given a processor handle @p processor_handle, this function will write\n  current xgmi plpd settings to @p policy. All the processors at the same socket\n  will have the same policy.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in,out] xgmi_plpd the xgmi plpd for this processor.\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_xgmi_plpd(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        xgmi_plpd: *mut amdsmi_dpm_policy_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_xgmi_plpd
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, xgmi_plpd)
+    }
+    #[doc = "```text\n  @brief Get the build version information for the currently running build of AMDSMI\n\n  @ingroup tagVersionQuery\n\n  @platform{gpu_bm_linux} @platform{cpu_bm} @platform{guest_1vf} @platform{guest_mvf}\n  @platform{guest_windows}\n\n  @details  Get the major, minor, patch and build string for AMDSMI build\n  currently in use through @p version\n\n  @param[in,out] version A pointer to an ::amdsmi_version_t structure that will\n  be updated with the version information upon return.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_lib_version(&self, version: *mut amdsmi_version_t) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_lib_version
+            .as_ref()
+            .expect("Expected function, got error."))(version)
+    }
+    #[doc = "```text\n  @brief Retrieve the error counts for a GPU block. It is not supported on virtual\n  machine guest\n\n  See [RAS Error Count sysfs Interface (AMDGPU RAS Support - Linux Kernel\n  documentation)](https://docs.kernel.org/gpu/amdgpu/ras.html#ras-error-count-sysfs-interface)\n  to learn how these error counts are accessed.\n\n  @ingroup tagECCInfo\n\n  @platform{gpu_bm_linux} @platform{host}\n\n  @details Given a processor handle @p processor_handle, an ::amdsmi_gpu_block_t @p block and a\n  pointer to an ::amdsmi_error_count_t @p ec, this function will write the error\n  count values for the GPU block indicated by @p block to memory pointed to by\n  @p ec.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] block The block for which error counts should be retrieved\n\n  @param[in,out] ec A pointer to an ::amdsmi_error_count_t to which the error\n  counts should be written\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_ecc_count(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        block: amdsmi_gpu_block_t,
+        ec: *mut amdsmi_error_count_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_ecc_count
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, block, ec)
+    }
+    #[doc = "```text\n  @brief Returns the total number of ECC errors (correctable,\n         uncorrectable and deferred) in the given GPU. It is not supported on\n         virtual machine guest\n\n  See [RAS Error Count sysfs Interface (AMDGPU RAS Support - Linux Kernel\n  documentation)](https://docs.kernel.org/gpu/amdgpu/ras.html#ras-error-count-sysfs-interface)\n  to learn how these error counts are accessed.\n\n  @ingroup tagECCInfo\n\n  @platform{gpu_bm_linux} @platform{host} @platform{guest_windows}\n\n  @param[in] processor_handle Device which to query\n\n  @param[out] ec Reference to ecc error count structure.\n              Must be allocated by user.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_total_ecc_count(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        ec: *mut amdsmi_error_count_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_total_ecc_count
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, ec)
+    }
+    #[doc = "```text\n @brief Retrieve CPER entries cached in the driver.\n\n The user will pass buffers to hold the CPER data and CPER headers. The library will\n fill the buffer based on the severity_mask user passed. It will also parse the CPER header\n and stored in the cper_hdrs array. The user can use the cper_hdrs to get the timestamp and other header information.\n A cursor is also returned to the user, which can be used to get the next set of CPER entries.\n\n If there are more data than any of the buffers user pass, the library will return AMDSMI_STATUS_MORE_DATA.\n User can call the API again with the cursor returned at previous call to get more data.\n If the buffer size is too small to even hold one entry, the library\n will return AMDSMI_STATUS_OUT_OF_RESOURCES.\n\n Even if the API returns AMDSMI_STATUS_MORE_DATA, the 2nd call may still get the entry_count == 0 as the driver\n cache may not contain the serverity user is interested in. The API should return AMDSMI_STATUS_SUCCESS in this case\n so that user can ignore that call.\n\n @ingroup tagECCInfo\n\n @platform{gpu_bm_linux} @platform{host} @platform{guest_1vf}\n\n @param[in] processor_handle Handle to the processor for which CPER entries are to be retrieved.\n @param[in] severity_mask The severity mask of the entries to be retrieved.\n @param[in,out] cper_data Pointer to a buffer where the CPER data will be stored. User must allocate the buffer\n                and set the buf_size correctly.\n @param[in,out] buf_size Pointer to a variable that specifies the size of the cper_data.\n                On return, it will contain the actual size of the data written to the cper_data.\n @param[in,out] cper_hdrs Array of the parsed headers of the cper_data. The user must allocate\n                the array of pointers to cper_hdr. The library will fill the array with the pointers to the parsed\n                headers. The underlying data is in the cper_data buffer and only pointer is stored in this array.\n @param[in,out] entry_count Pointer to a variable that specifies the array length of the cper_hdrs user allocated.\n                On return, it will contain the actual entries written to the cper_hdrs.\n @param[in,out] cursor Pointer to a variable that will contain the  cursor  for the next call.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_cper_entries(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        severity_mask: u32,
+        cper_data: *mut ::std::os::raw::c_char,
+        buf_size: *mut u64,
+        cper_hdrs: *mut *mut amdsmi_cper_hdr_t,
+        entry_count: *mut u64,
+        cursor: *mut u64,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_cper_entries
+            .as_ref()
+            .expect("Expected function, got error."))(
+            processor_handle,
+            severity_mask,
+            cper_data,
+            buf_size,
+            cper_hdrs,
+            entry_count,
+            cursor,
+        )
+    }
+    #[doc = "```text\n  @brief Returns RAS features info.\n\n  @ingroup tagRasInfo\n\n  @platform{gpu_bm_linux} @platform{host}\n\n  @param[in] processor_handle Device handle which to query\n\n  @param[out] ras_feature RAS features that are currently enabled and supported on\n  the processor. Must be allocated by user.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_ras_feature_info(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        ras_feature: *mut amdsmi_ras_feature_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_ras_feature_info
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, ras_feature)
+    }
+    #[doc = "```text\n  @brief Get a description of a provided AMDSMI error status\n\n  @ingroup tagErrorQuery\n\n  @platform{gpu_bm_linux} @platform{host} @platform{cpu_bm} @platform{guest_1vf} @platform{guest_mvf}\n\n  @details Set the provided pointer to a const char *, @p status_string, to\n  a string containing a description of the provided error code @p status.\n\n  @param[in] status The error status for which a description is desired\n\n  @param[in,out] status_string A pointer to a const char * which will be made\n  to point to a description of the provided error code\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_status_code_to_string(
+        &self,
+        status: amdsmi_status_t,
+        status_string: *mut *const ::std::os::raw::c_char,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_status_code_to_string
+            .as_ref()
+            .expect("Expected function, got error."))(status, status_string)
+    }
+    #[doc = "```text\n  @brief Tell if an event group is supported by a given device. It is not supported\n  on virtual machine guest\n\n  @ingroup tagPerfCounter\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle and an event group specifier @p\n  group, tell if @p group type events are supported by the device associated\n  with @p processor_handle\n\n  @param[in] processor_handle processor handle of device being queried\n\n  @param[in] group ::amdsmi_event_group_t identifier of group for which support\n  is being queried\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_gpu_counter_group_supported(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        group: amdsmi_event_group_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_gpu_counter_group_supported
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, group)
+    }
+    #[doc = "```text\n  @brief Create a performance counter object\n\n  @ingroup tagPerfCounter\n\n  @platform{gpu_bm_linux}\n\n  @details Create a performance counter object of type @p type for the device\n  with a processor handle of @p processor_handle, and write a handle to the object to the\n  memory location pointed to by @p evnt_handle. @p evnt_handle can be used\n  with other performance event operations. The handle should be deallocated\n  with ::amdsmi_gpu_destroy_counter() when no longer needed.\n\n  @note This function requires root access\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] type the ::amdsmi_event_type_t of performance event to create\n\n  @param[in,out] evnt_handle A pointer to a ::amdsmi_event_handle_t which will be\n  associated with a newly allocated counter\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_gpu_create_counter(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        type_: amdsmi_event_type_t,
+        evnt_handle: *mut amdsmi_event_handle_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_gpu_create_counter
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, type_, evnt_handle)
     }
-    #[doc = "```text\n  @brief Initialize the AMD SMI library\n\n  @ingroup tagInitShutdown\n\n  @platform{gpu_bm_linux} @platform{host} @platform{cpu_bm} @platform{guest_1vf}\n  @platform{guest_mvf} @platform{guest_windows}\n\n  @details This function initializes the library and the internal data structures,\n  including those corresponding to sources of information that SMI provides.\n  Singleton Design, requires the same number of inits as shutdowns.\n\n  The @p init_flags decides which type of processor\n  can be discovered by ::amdsmi_get_socket_handles(). AMDSMI_INIT_AMD_GPUS returns\n  sockets with AMD GPUS, and AMDSMI_INIT_AMD_GPUS | AMDSMI_INIT_AMD_CPUS returns\n  sockets with either AMD GPUS or CPUS.\n  Currently, only AMDSMI_INIT_AMD_GPUS is supported.\n\n  @param[in] init_flags Bit flags that tell SMI how to initialze. Values of\n  ::amdsmi_init_flags_t may be OR'd together and passed through @p init_flags\n  to modify how AMDSMI initializes.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
-    pub unsafe fn amdsmi_init(&self, init_flags: u64) -> amdsmi_status_t {
+    #[doc = "```text\n  @brief Deallocate a performance counter object\n\n  @ingroup tagPerfCounter\n\n  @platform{gpu_bm_linux}\n\n  @details Deallocate the performance counter object with the provided\n  ::amdsmi_event_handle_t @p evnt_handle\n\n  @note This function requires root access\n\n  @param[in] evnt_handle handle to event object to be deallocated\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_gpu_destroy_counter(
+        &self,
+        evnt_handle: amdsmi_event_handle_t,
+    ) -> amdsmi_status_t {
         (self
-            .amdsmi_init
+            .amdsmi_gpu_destroy_counter
             .as_ref()
-            .expect("Expected function, got error."))(init_flags)
+            .expect("Expected function, got error."))(evnt_handle)
     }
-    #[doc = "```text\n  @brief Shutdown the AMD SMI library\n\n  @ingroup tagInitShutdown\n\n  @platform{gpu_bm_linux} @platform{host} @platform{cpu_bm} @platform{guest_1vf}\n  @platform{guest_mvf} @platform{guest_windows}\n\n  @details This function shuts down the library and internal data structures and\n  performs any necessary clean ups. Singleton Design, requires the same number\n  of inits as shutdowns.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
-    pub unsafe fn amdsmi_shut_down(&self) -> amdsmi_status_t {
+    #[doc = "```text\n  @brief Issue performance counter control commands. It is not supported on\n  virtual machine guest\n\n  @ingroup tagPerfCounter\n\n  @platform{gpu_bm_linux}\n\n  @details Issue a command @p cmd on the event counter associated with the\n  provided handle @p evt_handle.\n\n  @note This function requires root access\n\n  @param[in] evt_handle an event handle\n\n  @param[in] cmd The event counter command to be issued\n\n  @param[in,out] cmd_args Currently not used. Should be set to NULL.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_gpu_control_counter(
+        &self,
+        evt_handle: amdsmi_event_handle_t,
+        cmd: amdsmi_counter_command_t,
+        cmd_args: *mut ::std::os::raw::c_void,
+    ) -> amdsmi_status_t {
         (self
-            .amdsmi_shut_down
+            .amdsmi_gpu_control_counter
             .as_ref()
-            .expect("Expected function, got error."))()
+            .expect("Expected function, got error."))(evt_handle, cmd, cmd_args)
     }
-    #[doc = "```text\n  @brief Get the list of socket handles in the system.\n\n  @ingroup tagProcDiscovery\n\n  @platform{gpu_bm_linux} @platform{host} @platform{cpu_bm} @platform{guest_1vf}\n  @platform{guest_mvf} @platform{guest_windows}\n\n  @details Depends on what flag is passed to ::amdsmi_init.  AMDSMI_INIT_AMD_GPUS\n  returns sockets with AMD GPUS, and AMDSMI_INIT_AMD_GPUS | AMDSMI_INIT_AMD_CPUS returns\n  sockets with either AMD GPUS or CPUS.\n  The socket handles can be used to query the processor handles in that socket, which\n  will be used in other APIs to get processor detail information or telemtries.\n\n  @param[in,out] socket_count As input, the value passed\n  through this parameter is the number of ::amdsmi_socket_handle that\n  may be safely written to the memory pointed to by @p socket_handles. This is the\n  limit on how many socket handles will be written to @p socket_handles. On return, @p\n  socket_count will contain the number of socket handles written to @p socket_handles,\n  or the number of socket handles that could have been written if enough memory had been\n  provided.\n  If @p socket_handles is NULL, as output, @p socket_count will contain\n  how many sockets are available to read in the system.\n\n  @param[in,out] socket_handles A pointer to a block of memory to which the\n  ::amdsmi_socket_handle values will be written. This value may be NULL.\n  In this case, this function can be used to query how many sockets are\n  available to read in the system.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
-    pub unsafe fn amdsmi_get_socket_handles(
+    #[doc = "```text\n  @brief Read the current value of a performance counter\n\n  @ingroup tagPerfCounter\n\n  @platform{gpu_bm_linux}\n\n  @details Read the current counter value of the counter associated with the\n  provided handle @p evt_handle and write the value to the location pointed\n  to by @p value.\n\n  @note This function requires root access\n\n  @param[in] evt_handle an event handle\n\n  @param[in,out] value pointer to memory of size of ::amdsmi_counter_value_t to\n  which the counter value will be written\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_gpu_read_counter(
         &self,
-        socket_count: *mut u32,
-        socket_handles: *mut amdsmi_socket_handle,
+        evt_handle: amdsmi_event_handle_t,
+        value: *mut amdsmi_counter_value_t,
     ) -> amdsmi_status_t {
         (self
-            .amdsmi_get_socket_handles
+            .amdsmi_gpu_read_counter
             .as_ref()
-            .expect("Expected function, got error."))(socket_count, socket_handles)
+            .expect("Expected function, got error."))(evt_handle, value)
     }
-    #[doc = "```text\n  @brief Get the list of the processor handles associated to a socket.\n\n  @ingroup tagProcDiscovery\n\n  @platform{gpu_bm_linux} @platform{host} @platform{guest_1vf}\n  @platform{guest_mvf} @platform{guest_windows}\n\n  @details This function retrieves the processor handles of a socket. The\n  @p socket_handle must be provided for the processor. A socket may have mulitple different\n  type processors: An APU on a socket have both CPUs and GPUs.\n  Currently, only AMD GPUs are supported.\n\n  @note Sockets are not supported on the @platform{host}.\n\n  The number of processor count is returned through @p processor_count\n  if @p processor_handles is NULL. Then the number of @p processor_count can be pass\n  as input to retrieval all processors on the socket to @p processor_handles.\n\n  @param[in] socket_handle The socket to query\n\n  @param[in,out] processor_count As input, the value passed\n  through this parameter is the number of ::amdsmi_processor_handle's that\n  may be safely written to the memory pointed to by @p processor_handles. This is the\n  limit on how many processor handles will be written to @p processor_handles. On return, @p\n  processor_count will contain the number of processor handles written to @p processor_handles,\n  or the number of processor handles that could have been written if enough memory had been\n  provided.\n  If @p processor_handles is NULL, as output, @p processor_count will contain\n  how many processors are available to read for the socket.\n\n  @param[in,out] processor_handles A pointer to a block of memory to which the\n  ::amdsmi_processor_handle values will be written. This value may be NULL.\n  In this case, this function can be used to query how many processors are\n  available to read.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
-    pub unsafe fn amdsmi_get_processor_handles(
+    #[doc = "```text\n  @brief Get the number of currently available counters. It is not supported on\n  virtual machine guest\n\n  @ingroup tagPerfCounter\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle, a performance event group @p grp,\n  and a pointer to a uint32_t @p available, this function will write the\n  number of @p grp type counters that are available on the device with handle\n  @p processor_handle to the memory that @p available points to.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] grp an event device group\n\n  @param[in,out] available A pointer to a uint32_t to which the number of\n  available counters will be written\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_available_counters(
         &self,
-        socket_handle: amdsmi_socket_handle,
-        processor_count: *mut u32,
-        processor_handles: *mut amdsmi_processor_handle,
+        processor_handle: amdsmi_processor_handle,
+        grp: amdsmi_event_group_t,
+        available: *mut u32,
     ) -> amdsmi_status_t {
         (self
-            .amdsmi_get_processor_handles
+            .amdsmi_get_gpu_available_counters
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, grp, available)
+    }
+    #[doc = "```text\n  @brief Retrieve the XGMI error status for a device. It is not supported on\n  virtual machine guest\n\n  @ingroup tagXGMI\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle, and a pointer to an\n  ::amdsmi_xgmi_status_t @p status, this function will write the current XGMI\n  error state ::amdsmi_xgmi_status_t for the device @p processor_handle to the memory\n  pointed to by @p status.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in,out] status A pointer to an ::amdsmi_xgmi_status_t to which the\n  XGMI error state should be written\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_gpu_xgmi_error_status(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        status: *mut amdsmi_xgmi_status_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_gpu_xgmi_error_status
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, status)
+    }
+    #[doc = "```text\n  @brief          Returns XGMI information for the GPU.\n\n  @ingroup tagXGMI\n\n  @platform{gpu_bm_linux}\n\n  @param[in]      processor_handle Device which to query\n\n  @param[out]     info Reference to xgmi information structure. Must be\n                  allocated by user.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_xgmi_info(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        info: *mut amdsmi_xgmi_info_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_xgmi_info
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, info)
+    }
+    #[doc = "```text\n  @brief Get the XGMI link status\n\n  @ingroup tagXGMI\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle,  this function\n  will return the link status for each XGMI link connect to this processor.\n  If the processor link type is not XGMI, it should return AMDSMI_STATUS_NOT_SUPPORTED.\n\n  @param[in] processor_handle a processor handle\n\n  @param[out] link_status The link status of the XGMI connect to this processor.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_xgmi_link_status(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        link_status: *mut amdsmi_xgmi_link_status_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_xgmi_link_status
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, link_status)
+    }
+    #[doc = "```text\n  @brief Return link metric information\n\n  @ingroup tagHWTopology\n\n  @platform{gpu_bm_linux} @platform{host}\n\n  @param[in] processor_handle PF of a processor for which to query\n\n  @param[out] link_metrics reference to the link metrics struct.\n  Must be allocated by user.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_link_metrics(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        link_metrics: *mut amdsmi_link_metrics_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_link_metrics
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, link_metrics)
+    }
+    #[doc = "```text\n  @brief Retrieve the NUMA CPU node number for a device\n\n  @ingroup tagHWTopology\n\n  @platform{gpu_bm_linux} @platform{host}\n\n  @details Given a processor handle @p processor_handle, and a pointer to an\n  uint32_t @p numa_node, this function will write the\n  node number of NUMA CPU for the device @p processor_handle to the memory\n  pointed to by @p numa_node.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in,out] numa_node A pointer to an uint32_t to which the\n  numa node number should be written.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_topo_get_numa_node_number(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        numa_node: *mut u32,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_topo_get_numa_node_number
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, numa_node)
+    }
+    #[doc = "```text\n  @brief Retrieve the weight for a connection between 2 GPUs\n\n  @ingroup tagHWTopology\n\n  @platform{gpu_bm_linux}\n\n  @details Given a source processor handle @p processor_handle_src and\n  a destination processor handle @p processor_handle_dst, and a pointer to an\n  uint64_t @p weight, this function will write the\n  weight for the connection between the device @p processor_handle_src\n  and @p processor_handle_dst to the memory pointed to by @p weight.\n\n  @param[in] processor_handle_src the source processor handle\n\n  @param[in] processor_handle_dst the destination processor handle\n\n  @param[in,out] weight A pointer to an uint64_t to which the\n  weight for the connection should be written.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_topo_get_link_weight(
+        &self,
+        processor_handle_src: amdsmi_processor_handle,
+        processor_handle_dst: amdsmi_processor_handle,
+        weight: *mut u64,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_topo_get_link_weight
             .as_ref()
             .expect("Expected function, got error."))(
-            socket_handle,
-            processor_count,
-            processor_handles,
+            processor_handle_src,
+            processor_handle_dst,
+            weight,
         )
     }
-    #[doc = "```text\n  @brief Returns the UUID of the device\n\n  @ingroup tagProcDiscovery\n\n  @platform{gpu_bm_linux} @platform{host} @platform{guest_1vf} @platform{guest_mvf}\n  @platform{guest_windows}\n\n  @param[in] processor_handle Device which to query\n\n  @param[in,out] uuid_length Length of the uuid string. As input, must be\n                 equal or greater than AMDSMI_GPU_UUID_SIZE and be allocated by\n                 user. As output it is the length of the uuid string.\n\n  @param[out] uuid Pointer to string to store the UUID. Must be\n              allocated by user.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
-    pub unsafe fn amdsmi_get_gpu_device_uuid(
+    #[doc = "```text\n  @brief Retrieve the hops and the connection type between 2 GPUs\n\n  @ingroup tagHWTopology\n\n  @platform{gpu_bm_linux}\n\n  @details Given a source processor handle @p processor_handle_src and\n  a destination processor handle @p processor_handle_dst, and a pointer to an\n  uint64_t @p hops and a pointer to an AMDSMI_INK_TYPE @p type,\n  this function will write the number of hops and the connection type\n  between the device @p processor_handle_src and @p processor_handle_dst to the memory\n  pointed to by @p hops and @p type.\n\n  @param[in] processor_handle_src the source processor handle\n\n  @param[in] processor_handle_dst the destination processor handle\n\n  @param[in,out] hops A pointer to an uint64_t to which the\n  hops for the connection should be written.\n\n  @param[in,out] type A pointer to an ::AMDSMI_LINK_TYPE to which the\n  type for the connection should be written.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_topo_get_link_type(
         &self,
-        processor_handle: amdsmi_processor_handle,
-        uuid_length: *mut ::std::os::raw::c_uint,
-        uuid: *mut ::std::os::raw::c_char,
+        processor_handle_src: amdsmi_processor_handle,
+        processor_handle_dst: amdsmi_processor_handle,
+        hops: *mut u64,
+        type_: *mut amdsmi_link_type_t,
     ) -> amdsmi_status_t {
         (self
-            .amdsmi_get_gpu_device_uuid
+            .amdsmi_topo_get_link_type
             .as_ref()
-            .expect("Expected function, got error."))(processor_handle, uuid_length, uuid)
+            .expect("Expected function, got error."))(
+            processor_handle_src,
+            processor_handle_dst,
+            hops,
+            type_,
+        )
     }
-    #[doc = "```text\n  @brief Get PCIe traffic information. It is not supported on virtual machine guest\n\n  @ingroup tagPCIeQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Give a processor handle @p processor_handle and pointers to a uint64_t's, @p\n  sent, @p received and @p max_pkt_sz, this function will write the number\n  of bytes sent and received in 1 second to @p sent and @p received,\n  respectively. The maximum possible packet size will be written to\n  @p max_pkt_sz.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in,out] sent a pointer to uint64_t to which the number of bytes sent\n  will be written in 1 second. If pointer is NULL, it will be ignored.\n\n  @param[in,out] received a pointer to uint64_t to which the number of bytes\n  received will be written. If pointer is NULL, it will be ignored.\n\n  @param[in,out] max_pkt_sz a pointer to uint64_t to which the maximum packet\n  size will be written. If pointer is NULL, it will be ignored.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
-    pub unsafe fn amdsmi_get_gpu_pci_throughput(
+    #[doc = "```text\n  @brief Return P2P availability status between 2 GPUs\n\n  @ingroup tagHWTopology\n\n  @platform{gpu_bm_linux}\n\n  @details Given a source processor handle @p processor_handle_src and\n  a destination processor handle @p processor_handle_dst, and a pointer to a\n  bool @p accessible, this function will write the P2P connection status\n  between the device @p processor_handle_src and @p processor_handle_dst to the memory\n  pointed to by @p accessible.\n\n  @param[in] processor_handle_src the source processor handle\n\n  @param[in] processor_handle_dst the destination processor handle\n\n  @param[in,out] accessible A pointer to a bool to which the status for\n  the P2P connection availablity should be written.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_is_P2P_accessible(
+        &self,
+        processor_handle_src: amdsmi_processor_handle,
+        processor_handle_dst: amdsmi_processor_handle,
+        accessible: *mut bool,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_is_P2P_accessible
+            .as_ref()
+            .expect("Expected function, got error."))(
+            processor_handle_src,
+            processor_handle_dst,
+            accessible,
+        )
+    }
+    #[doc = "```text\n  @brief Retrieve connection type and P2P capabilities between 2 GPUs\n\n  @ingroup tagHWTopology\n\n  @platform{gpu_bm_linux} @platform{host} @platform{guest_1vf} @platform{guest_mvf}\n\n  @details Given a source processor handle @p processor_handle_src and\n  a destination processor handle @p processor_handle_dst, a pointer to an amdsmi_link_type_t @p type,\n  and a pointer to amdsmi_p2p_capability_t @p cap. This function will write the connection type,\n  and io link capabilities between the device\n  @p processor_handle_src and @p processor_handle_dst to the memory\n  pointed to by @p cap and @p type.\n\n  @param[in] processor_handle_src the source processor handle\n\n  @param[in] processor_handle_dst the destination processor handle\n\n  @param[in,out] type A pointer to an ::amdsmi_link_type_t to which the\n  type for the connection should be written.\n\n  @param[in,out] cap A pointer to an ::amdsmi_p2p_capability_t to which the\n  io link capabilities should be written.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_topo_get_p2p_status(
+        &self,
+        processor_handle_src: amdsmi_processor_handle,
+        processor_handle_dst: amdsmi_processor_handle,
+        type_: *mut amdsmi_link_type_t,
+        cap: *mut amdsmi_p2p_capability_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_topo_get_p2p_status
+            .as_ref()
+            .expect("Expected function, got error."))(
+            processor_handle_src,
+            processor_handle_dst,
+            type_,
+            cap,
+        )
+    }
+    #[doc = "```text\n  @brief Retrieves the current compute partitioning for a desired device\n\n  @ingroup tagComputePartition\n\n  @platform{gpu_bm_linux}\n\n  @details\n  Given a processor handle @p processor_handle and a string @p compute_partition ,\n  and uint32 @p len , this function will attempt to obtain the device's\n  current compute partition setting string. Upon successful retreival,\n  the obtained device's compute partition settings string shall be stored in\n  the passed @p compute_partition char string variable.\n\n  @param[in] processor_handle Device which to query\n\n  @param[inout] compute_partition a pointer to a char string variable,\n  which the device's current compute partition will be written to.\n\n  @param[in] len the length of the caller provided buffer @p compute_partition,\n  suggested length is 4 or greater.\n\n  @retval ::AMDSMI_STATUS_SUCCESS call was successful\n  @retval ::AMDSMI_STATUS_INVAL the provided arguments are not valid\n  @retval ::AMDSMI_STATUS_UNEXPECTED_DATA data provided to function is not valid\n  @retval ::AMDSMI_STATUS_NOT_SUPPORTED installed software or hardware does not\n  support this function\n  @retval ::AMDSMI_STATUS_INSUFFICIENT_SIZE is returned if @p len bytes is not\n  large enough to hold the entire compute partition value. In this case,\n  only @p len bytes will be written.\n  @return ::amdsmi_status_t\n```"]
+    pub unsafe fn amdsmi_get_gpu_compute_partition(
         &self,
         processor_handle: amdsmi_processor_handle,
-        sent: *mut u64,
-        received: *mut u64,
-        max_pkt_sz: *mut u64,
+        compute_partition: *mut ::std::os::raw::c_char,
+        len: u32,
     ) -> amdsmi_status_t {
         (self
-            .amdsmi_get_gpu_pci_throughput
+            .amdsmi_get_gpu_compute_partition
             .as_ref()
             .expect("Expected function, got error."))(
-            processor_handle, sent, received, max_pkt_sz
+            processor_handle, compute_partition, len
         )
     }
-    #[doc = "```text\n  @brief Get the energy accumulator counter of the processor with provided\n  processor handle. It is not supported on virtual machine guest\n\n  @ingroup tagPowerQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle, a pointer to a uint64_t\n  @p energy_accumulator, and a pointer to a uint64_t @p timestamp, this function\n  will write amount of energy consumed to the uint64_t pointed to by\n  @p energy_accumulator, and the timestamp to the uint64_t pointed to by @p timestamp.\n  This function accumulates all energy consumed.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in,out] energy_accumulator a pointer to uint64_t to which the energy\n  counter will be written\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @param[in,out] counter_resolution resolution of the counter @p energy_accumulator in\n  micro Joules\n\n  @param[in,out] timestamp a pointer to uint64_t to which the timestamp\n  will be written. Resolution: 1 ns.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
-    pub unsafe fn amdsmi_get_energy_count(
+    #[doc = "```text\n  @brief Retrieves the current memory partition for a desired device\n\n  @ingroup tagMemoryPartition\n\n  @platform{gpu_bm_linux}\n\n  @details\n  Given a processor handle @p processor_handle and a string @p memory_partition ,\n  and uint32 @p len , this function will attempt to obtain the device's\n  memory partition string. Upon successful retreival, the obtained device's\n  memory partition string shall be stored in the passed @p memory_partition\n  char string variable.\n\n  @param[in] processor_handle Device which to query\n\n  @param[inout] memory_partition a pointer to a char string variable,\n  which the device's memory partition will be written to.\n\n  @param[in] len the length of the caller provided buffer @p memory_partition ,\n  suggested length is 5 or greater.\n\n  @retval ::AMDSMI_STATUS_SUCCESS call was successful\n  @retval ::AMDSMI_STATUS_INVAL the provided arguments are not valid\n  @retval ::AMDSMI_STATUS_UNEXPECTED_DATA data provided to function is not valid\n  @retval ::AMDSMI_STATUS_NOT_SUPPORTED installed software or hardware does not\n  support this function\n  @retval ::AMDSMI_STATUS_INSUFFICIENT_SIZE is returned if @p len bytes is not\n  large enough to hold the entire memory partition value. In this case,\n  only @p len bytes will be written.\n  @return ::amdsmi_status_t\n```"]
+    pub unsafe fn amdsmi_get_gpu_memory_partition(
         &self,
         processor_handle: amdsmi_processor_handle,
-        energy_accumulator: *mut u64,
-        counter_resolution: *mut f32,
-        timestamp: *mut u64,
+        memory_partition: *mut ::std::os::raw::c_char,
+        len: u32,
     ) -> amdsmi_status_t {
         (self
-            .amdsmi_get_energy_count
+            .amdsmi_get_gpu_memory_partition
             .as_ref()
             .expect("Expected function, got error."))(
-            processor_handle,
-            energy_accumulator,
-            counter_resolution,
-            timestamp,
+            processor_handle, memory_partition, len
         )
     }
-    #[doc = "```text\n  @brief Get the current memory usage\n\n  @ingroup tagMemoryQuery\n\n  @platform{gpu_bm_linux}\n\n  @details This function will write the amount of @p mem_type memory that\n  that is currently being used to the location pointed to by @p used.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] mem_type The type of memory for which the amount being used will\n  be found\n\n  @param[in,out] used a pointer to uint64_t to which the amount of memory\n  currently being used will be written\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
-    pub unsafe fn amdsmi_get_gpu_memory_usage(
+    #[doc = "```text\n  @brief Prepare to collect event notifications for a GPU\n\n  @ingroup tagEventNotification\n\n  @platform{gpu_bm_linux}\n\n  @details This function prepares to collect events for the GPU with device\n  ID @p processor_handle, by initializing any required system parameters. This call\n  may open files which will remain open until ::amdsmi_stop_gpu_event_notification()\n  is called.\n\n  @param[in] processor_handle a processor handle corresponding to the device on which to\n  listen for events\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_init_gpu_event_notification(
         &self,
         processor_handle: amdsmi_processor_handle,
-        mem_type: amdsmi_memory_type_t,
-        used: *mut u64,
     ) -> amdsmi_status_t {
         (self
-            .amdsmi_get_gpu_memory_usage
+            .amdsmi_init_gpu_event_notification
             .as_ref()
-            .expect("Expected function, got error."))(processor_handle, mem_type, used)
+            .expect("Expected function, got error."))(processor_handle)
     }
-    #[doc = "```text\n  @brief Get the fan speed for the specified device as a value relative to\n  ::AMDSMI_MAX_FAN_SPEED. It is not supported on virtual machine guest\n\n  @ingroup tagPhysicalStateQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle and a pointer to a uint32_t\n  @p speed, this function will write the current fan speed (a value\n  between 0 and the maximum fan speed, ::AMDSMI_MAX_FAN_SPEED) to the uint32_t\n  pointed to by @p speed\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] sensor_ind a 0-based sensor index. Normally, this will be 0.\n  If a device has more than one sensor, it could be greater than 0.\n\n  @param[in,out] speed a pointer to uint32_t to which the speed will be\n  written\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
-    pub unsafe fn amdsmi_get_gpu_fan_speed(
+    #[doc = "```text\n  @brief Specify which events to collect for a device\n\n  @ingroup tagEventNotification\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle and a @p mask consisting of\n  elements of ::amdsmi_evt_notification_type_t OR'd together, this function\n  will listen for the events specified in @p mask on the device\n  corresponding to @p processor_handle.\n\n  @param[in] processor_handle a processor handle corresponding to the device on which to\n  listen for events\n\n  @param[in] mask Bitmask generated by OR'ing 1 or more elements of\n  ::amdsmi_evt_notification_type_t indicating which event types to listen for,\n  where the amdsmi_evt_notification_type_t value indicates the bit field, with\n  bit position starting from 1.\n  For example, if the mask field is 0x0000000000000003, which means first bit,\n  bit 1 (bit position start from 1) and bit 2 are set, which indicate interest\n  in receiving AMDSMI_EVT_NOTIF_VMFAULT (which has a value of 1) and\n  AMDSMI_EVT_NOTIF_THERMAL_THROTTLE event (which has a value of 2).\n\n  @note ::AMDSMI_STATUS_INIT_ERROR is returned if\n  ::amdsmi_init_gpu_event_notification() has not been called before a call to this\n  function\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_set_gpu_event_notification_mask(
         &self,
         processor_handle: amdsmi_processor_handle,
-        sensor_ind: u32,
-        speed: *mut i64,
+        mask: u64,
     ) -> amdsmi_status_t {
         (self
-            .amdsmi_get_gpu_fan_speed
+            .amdsmi_set_gpu_event_notification_mask
             .as_ref()
-            .expect("Expected function, got error."))(processor_handle, sensor_ind, speed)
+            .expect("Expected function, got error."))(processor_handle, mask)
     }
-    #[doc = "```text\n  @brief Get the voltage metric value for the specified metric, from the\n  specified voltage sensor on the specified device. It is not supported on\n  virtual machine guest\n\n  @ingroup tagPhysicalStateQuery\n\n  @platform{gpu_bm_linux}\n\n  @details Given a processor handle @p processor_handle, a sensor type @p sensor_type, a\n  ::amdsmi_voltage_metric_t @p metric and a pointer to an int64_t @p\n  voltage, this function will write the value of the metric indicated by\n  @p metric and @p sensor_type to the memory location @p voltage.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] sensor_type part of device from which voltage should be\n  obtained. This should come from the enum ::amdsmi_voltage_type_t\n\n  @param[in] metric enum indicated which voltage value should be\n  retrieved\n\n  @param[in,out] voltage a pointer to int64_t to which the voltage\n  will be written, in millivolts.\n  If this parameter is nullptr, this function will return\n  ::AMDSMI_STATUS_INVAL if the function is supported with the provided,\n  arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not supported with the\n  provided arguments.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
-    pub unsafe fn amdsmi_get_gpu_volt_metric(
+    #[doc = "```text\n  @brief Collect event notifications, waiting a specified amount of time\n\n  @ingroup tagEventNotification\n\n  @platform{gpu_bm_linux}\n\n  @details Given a time period @p timeout_ms in milliseconds and a caller-\n  provided buffer of ::amdsmi_evt_notification_data_t's @p data with a length\n  (in ::amdsmi_evt_notification_data_t's, also specified by the caller) in the\n  memory location pointed to by @p num_elem, this function will collect\n  ::amdsmi_evt_notification_type_t events for up to @p timeout_ms milliseconds,\n  and write up to *@p num_elem event items to @p data. Upon return @p num_elem\n  is updated with the number of events that were actually written. If events\n  are already present when this function is called, it will write the events\n  to the buffer then poll for new events if there is still caller-provided\n  buffer available to write any new events that would be found.\n\n  This function requires prior calls to ::amdsmi_init_gpu_event_notification() and\n  :: amdsmi_set_gpu_event_notification_mask(). This function polls for the\n  occurrance of the events on the respective devices that were previously\n  specified by :: amdsmi_set_gpu_event_notification_mask().\n\n  @param[in] timeout_ms number of milliseconds to wait for an event\n  to occur\n\n  @param[in,out] num_elem pointer to uint32_t, provided by the caller. On\n  input, this value tells how many ::amdsmi_evt_notification_data_t elements\n  are being provided by the caller with @p data. On output, the location\n  pointed to by @p num_elem will contain the number of items written to\n  the provided buffer.\n\n  @param[out] data pointer to a caller-provided memory buffer of size\n  @p num_elem ::amdsmi_evt_notification_data_t to which this function may safely\n  write. If there are events found, up to @p num_elem event items will be\n  written to @p data.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_event_notification(
+        &self,
+        timeout_ms: ::std::os::raw::c_int,
+        num_elem: *mut u32,
+        data: *mut amdsmi_evt_notification_data_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_event_notification
+            .as_ref()
+            .expect("Expected function, got error."))(timeout_ms, num_elem, data)
+    }
+    #[doc = "```text\n  @brief Close any file handles and free any resources used by event\n  notification for a GPU\n\n  @ingroup tagEventNotification\n\n  @platform{gpu_bm_linux}\n\n  @details Any resources used by event notification for the GPU with\n  processor handle @p processor_handle will be free with this\n  function. This includes freeing any memory and closing file handles. This\n  should be called for every call to ::amdsmi_init_gpu_event_notification()\n\n  @param[in] processor_handle The processor handle of the GPU for which event\n  notification resources will be free\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_stop_gpu_event_notification(
         &self,
         processor_handle: amdsmi_processor_handle,
-        sensor_type: amdsmi_voltage_type_t,
-        metric: amdsmi_voltage_metric_t,
-        voltage: *mut i64,
     ) -> amdsmi_status_t {
         (self
-            .amdsmi_get_gpu_volt_metric
+            .amdsmi_stop_gpu_event_notification
             .as_ref()
-            .expect("Expected function, got error."))(
-            processor_handle,
-            sensor_type,
-            metric,
-            voltage,
-        )
+            .expect("Expected function, got error."))(processor_handle)
     }
-    #[doc = "```text\n  @brief Get a description of a provided AMDSMI error status\n\n  @ingroup tagErrorQuery\n\n  @platform{gpu_bm_linux} @platform{host} @platform{cpu_bm} @platform{guest_1vf} @platform{guest_mvf}\n\n  @details Set the provided pointer to a const char *, @p status_string, to\n  a string containing a description of the provided error code @p status.\n\n  @param[in] status The error status for which a description is desired\n\n  @param[in,out] status_string A pointer to a const char * which will be made\n  to point to a description of the provided error code\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
-    pub unsafe fn amdsmi_status_code_to_string(
+    #[doc = "```text\n  @brief Returns the driver version information\n\n  @ingroup tagSoftwareVersion\n\n  @platform{gpu_bm_linux} @platform{host} @platform{guest_1vf} @platform{guest_mvf}\n  @platform{guest_windows}\n\n  @param[in] processor_handle Device which to query\n\n  @param[out] info Reference to driver information structure. Must be\n              allocated by user.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_driver_info(
         &self,
-        status: amdsmi_status_t,
-        status_string: *mut *const ::std::os::raw::c_char,
+        processor_handle: amdsmi_processor_handle,
+        info: *mut amdsmi_driver_info_t,
     ) -> amdsmi_status_t {
         (self
-            .amdsmi_status_code_to_string
+            .amdsmi_get_gpu_driver_info
             .as_ref()
-            .expect("Expected function, got error."))(status, status_string)
+            .expect("Expected function, got error."))(processor_handle, info)
     }
     #[doc = "```text\n  @brief Returns the ASIC information for the device\n\n  @ingroup tagAsicBoardInfo\n\n  @platform{gpu_bm_linux} @platform{host} @platform{guest_1vf} @platform{guest_mvf}\n  @platform{guest_windows}\n\n  @details This function returns ASIC information such as the product name,\n           the vendor ID, the subvendor ID, the device ID,\n           the revision ID and the serial number.\n\n  @param[in] processor_handle Device which to query\n\n  @param[out] info Reference to static asic information structure.\n              Must be allocated by user.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
     pub unsafe fn amdsmi_get_gpu_asic_info(
@@ -1019,6 +5465,73 @@ impl libamd_smi {
             .as_ref()
             .expect("Expected function, got error."))(processor_handle, info)
     }
+    #[doc = "```text\n  @brief          Returns the KFD (Kernel Fusion Driver) information for the device\n\n  @ingroup tagAsicBoardInfo\n\n  @platform{gpu_bm_linux}\n\n  @details        This function returns KFD information populated into the amdsmi_kfd_info_t.\n                  This contains the kfd_id and node_id which allow for the ID and\n                  index of this device in the KFD.\n\n  @param[in]      processor_handle Device which to query\n\n  @param[out]     info Reference to kfd information structure.\n                  Must be allocated by user.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_kfd_info(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        info: *mut amdsmi_kfd_info_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_kfd_info
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, info)
+    }
+    #[doc = "```text\n  @brief Returns the board part number and board information for the requested device\n\n  @ingroup tagAsicBoardInfo\n\n  @platform{gpu_bm_linux} @platform{host} @platform{guest_1vf} @platform{guest_mvf}\n\n  @param[in] processor_handle Device which to query\n\n  @param[out] info Reference to board info structure.\n              Must be allocated by user.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_board_info(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        info: *mut amdsmi_board_info_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_board_info
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, info)
+    }
+    #[doc = "```text\n  @brief Returns the power caps as currently configured in the system.\n\n  @ingroup tagAsicBoardInfo\n\n  @platform{gpu_bm_linux} @platform{host}\n\n  @param[in] processor_handle Device which to query\n\n  @param[in] sensor_ind A 0-based sensor index. Normally, this will be 0.\n  If a device has more than one sensor, it could be greater than 0.\n  Parameter @p sensor_ind is unused on @platform{host}.\n\n  @param[out] info Reference to power caps information structure. Must be\n  allocated by user.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_power_cap_info(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        sensor_ind: u32,
+        info: *mut amdsmi_power_cap_info_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_power_cap_info
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, sensor_ind, info)
+    }
+    #[doc = "```text\n  @brief Returns the PCIe info for the GPU.\n\n  @ingroup tagAsicBoardInfo\n\n  @platform{gpu_bm_linux} @platform{host} @platform{guest_1vf} @platform{guest_windows}\n\n  @param[in] processor_handle Device which to query\n\n  @param[out] info Reference to the PCIe information\n  returned by the library. Must be allocated by user.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_pcie_info(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        info: *mut amdsmi_pcie_info_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_pcie_info
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, info)
+    }
+    #[doc = "```text\n  @brief Returns the firmware versions running on the device.\n\n  @ingroup tagFWVbiosQuery\n\n  @platform{gpu_bm_linux} @platform{host} @platform{guest_1vf} @platform{guest_mvf}\n  @platform{guest_windows}\n\n  @param[in] processor_handle Device which to query\n\n  @param[out] info Reference to the fw info. Must be allocated by user.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_fw_info(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        info: *mut amdsmi_fw_info_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_fw_info
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, info)
+    }
+    #[doc = "```text\n  @brief Returns the static information for the vBIOS on the device.\n\n  @ingroup tagFWVbiosQuery\n\n  @platform{gpu_bm_linux} @platform{host} @platform{guest_1vf} @platform{guest_mvf}\n  @platform{guest_windows}\n\n  @param[in] processor_handle Device which to query\n\n  @param[out] info Reference to static vBIOS information.\n              Must be allocated by user.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_gpu_vbios_info(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        info: *mut amdsmi_vbios_info_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_gpu_vbios_info
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, info)
+    }
     #[doc = "```text\n  @brief Get the temperature metric value for the specified metric, from the\n  specified temperature sensor on the specified device. It is not supported on\n  virtual machine guest\n\n  @ingroup tagGPUMonitor\n\n  @platform{gpu_bm_linux} @platform{host} @platform{guest_windows}\n\n  @details Given a processor handle @p processor_handle, a sensor type @p sensor_type, a\n  ::amdsmi_temperature_metric_t @p metric and a pointer to an int64_t @p\n  temperature, this function will write the value of the metric indicated by\n  @p metric and @p sensor_type to the memory location @p temperature.\n\n  @param[in] processor_handle a processor handle\n\n  @param[in] sensor_type part of device from which temperature should be\n  obtained. This should come from the enum ::amdsmi_temperature_type_t\n\n  @param[in] metric enum indicated which temperature value should be\n  retrieved\n\n  @param[in,out] temperature a pointer to int64_t to which the temperature is in Celsius.\n  If this parameter is nullptr, this function will return ::AMDSMI_STATUS_INVAL if the function\n  is supported with the provided, arguments and ::AMDSMI_STATUS_NOT_SUPPORTED if it is not\n  supported with the provided arguments.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
     pub unsafe fn amdsmi_get_temp_metric(
         &self,
@@ -1094,4 +5607,127 @@ impl libamd_smi {
             .as_ref()
             .expect("Expected function, got error."))(processor_handle, max_processes, list)
     }
+    #[doc = "```text\n  @brief Get the core energy for a given core.\n\n  @ingroup tagEnergyInfo\n\n  @platform{cpu_bm}\n\n  @param[in]      processor_handle Cpu core which to query\n\n  @param[in,out]    penergy - Input buffer to return the core energy\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_cpu_core_energy(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        penergy: *mut u64,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_cpu_core_energy
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, penergy)
+    }
+    #[doc = "```text\n  @brief Get the socket energy for a given socket.\n\n  @ingroup tagEnergyInfo\n\n  @platform{cpu_bm}\n\n  @param[in]      processor_handle Cpu socket which to query\n\n  @param[in,out]    penergy - Input buffer to return the socket energy\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_cpu_socket_energy(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        penergy: *mut u64,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_cpu_socket_energy
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, penergy)
+    }
+    #[doc = "```text\n  @brief Get HSMP Driver Version.\n\n  @ingroup tagHSMPSystemStats\n\n  @platform{cpu_bm}\n\n  @param[in]      processor_handle Cpu socket which to query\n  @param[in,out]  amdsmi_hsmp_driver_ver - Input buffer to return the HSMP Driver version\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_cpu_hsmp_driver_version(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        amdsmi_hsmp_driver_ver: *mut amdsmi_hsmp_driver_version_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_cpu_hsmp_driver_version
+            .as_ref()
+            .expect("Expected function, got error."))(
+            processor_handle, amdsmi_hsmp_driver_ver
+        )
+    }
+    #[doc = "```text\n  @brief Get SMU Firmware Version.\n\n  @ingroup tagHSMPSystemStats\n\n  @platform{cpu_bm}\n\n  @param[in]      processor_handle Cpu socket which to query\n  @param[in,out]    amdsmi_smu_fw - Input buffer to return the firmware version\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_cpu_smu_fw_version(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        amdsmi_smu_fw: *mut amdsmi_smu_fw_version_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_cpu_smu_fw_version
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, amdsmi_smu_fw)
+    }
+    #[doc = "```text\n  @brief Get normalized status of the processor's PROCHOT status.\n\n  @ingroup tagHSMPSystemStats\n\n  @platform{cpu_bm}\n\n  @param[in]      processor_handle Cpu socket which to query\n\n  @param[in,out]    prochot - Input buffer to return the procohot status.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_cpu_prochot_status(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        prochot: *mut u32,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_cpu_prochot_status
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, prochot)
+    }
+    #[doc = "```text\n  @brief Get core clock in MHz.\n\n  @ingroup tagHSMPSystemStats\n\n  @platform{cpu_bm}\n\n  @param[in]      processor_handle Cpu socket which to query\n\n  @param[in,out]    cclk - Input buffer to return core clock\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_cpu_cclk_limit(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        cclk: *mut u32,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_cpu_cclk_limit
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, cclk)
+    }
+    #[doc = "```text\n  @brief Get socket frequency limit of the core.\n\n  @ingroup tagHSMPSystemStats\n\n  @platform{cpu_bm}\n\n  @param[in]      processor_handle Cpu core which to query\n\n  @param[in,out]    freq - Input buffer to return frequency.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_cpu_core_current_freq_limit(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        freq: *mut u32,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_cpu_core_current_freq_limit
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, freq)
+    }
+    #[doc = "```text\n  @brief Get the core boost limit.\n\n  @ingroup tagPerfBoostControl\n\n  @platform{cpu_bm}\n\n  @param[in]     processor_handle Cpu core which to query\n\n  @param[in,out] pboostlimit - Input buffer to fill the boostlimit value\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_cpu_core_boostlimit(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        pboostlimit: *mut u32,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_cpu_core_boostlimit
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, pboostlimit)
+    }
+    #[doc = "```text\n  @brief Get socket temperature.\n\n  @ingroup tagTempQuery\n\n  @platform{cpu_bm}\n\n  @param[in]     processor_handle Cpu socket which to query\n\n  @param[in,out] ptmon - Input buffer to fill temperature value\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_cpu_socket_temperature(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        ptmon: *mut u32,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_cpu_socket_temperature
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, ptmon)
+    }
+    #[doc = "```text\n  @brief Get HSMP metrics table version\n\n  @ingroup tagHSMPMetricsTable\n\n  @platform{cpu_bm}\n\n  @param[in]      processor_handle Cpu socket which to query\n\n  @param[in,out]  metrics_version input buffer to return the HSMP metrics table version.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_hsmp_metrics_table_version(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        metrics_version: *mut u32,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_hsmp_metrics_table_version
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, metrics_version)
+    }
+    #[doc = "```text\n  @brief Get HSMP metrics table\n\n  @ingroup tagHSMPMetricsTable\n\n  @platform{cpu_bm}\n\n  @param[in]      processor_handle Cpu socket which to query\n\n  @param[in,out]  metrics_table input buffer to return the HSMP metrics table.\n\n  @return ::amdsmi_status_t | ::AMDSMI_STATUS_SUCCESS on success, non-zero on fail\n```"]
+    pub unsafe fn amdsmi_get_hsmp_metrics_table(
+        &self,
+        processor_handle: amdsmi_processor_handle,
+        metrics_table: *mut amdsmi_hsmp_metrics_table_t,
+    ) -> amdsmi_status_t {
+        (self
+            .amdsmi_get_hsmp_metrics_table
+            .as_ref()
+            .expect("Expected function, got error."))(processor_handle, metrics_table)
+    }
 }
@@ -1,3 +1,11 @@
 /// Full bindings for the latest version (that we generated, to update, run the bindings generator).
+#[cfg(not(amd_smi_header_override))]
 #[allow(warnings)]
 pub mod latest;
+
+/// Bindings generated at build time from the vendored header selected by `AMD_SMI_HEADER_VERSION`.
+#[cfg(amd_smi_header_override)]
+#[allow(warnings)]
+pub mod latest {
+    include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+}
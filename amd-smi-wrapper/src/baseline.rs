@@ -0,0 +1,195 @@
+//! Measurement of the idle power of the devices, to subtract it in energy attribution.
+
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::{AmdError, ResultExt},
+    handles::ProcessorHandle,
+    metrics::{AmdEnergyReading, AmdPciAddress},
+    stats::{RollingWindow, WindowStats},
+};
+
+/// Default interval between two power samples of [`measure_idle_power`].
+pub const DEFAULT_SAMPLE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Power of a device during a quiet period, see [`measure_idle_power`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdleBaseline {
+    /// PCI address of the device.
+    pub address: AmdPciAddress,
+    /// Actual duration of the measurement.
+    pub duration: Duration,
+    /// Mean power computed from the energy counter, in W, or `None` if the device has no energy counter.
+    pub energy_power: Option<f64>,
+    /// Statistics of the power samples, in W, or `None` if the device does not report its power.
+    pub power: Option<WindowStats>,
+}
+
+impl IdleBaseline {
+    /// Returns the best estimate of the idle power, in W: the mean power computed from the energy counter
+    /// if available, which accounts for the whole period, else the mean of the power samples.
+    pub fn mean_power(&self) -> Option<f64> {
+        self.energy_power.or(self.power.map(|p| p.mean))
+    }
+}
+
+/// Measures the power of each device during `duration`, sampling it every `interval`.
+///
+/// The devices must be idle during the measurement: this function only observes them.
+/// The baselines are returned in the order of the devices.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use amd_smi_wrapper::{AmdSmi, AmdInitFlags, baseline::{self, DEFAULT_SAMPLE_INTERVAL}};
+///
+/// let amdsmi = AmdSmi::init(AmdInitFlags::AMDSMI_INIT_AMD_GPUS).unwrap();
+/// let devices: Vec<_> = amdsmi.iter_processors().unwrap().filter_map(Result::ok).collect();
+/// let baselines =
+///     baseline::measure_idle_power(&devices, Duration::from_secs(10), DEFAULT_SAMPLE_INTERVAL).unwrap();
+/// for b in baselines {
+///     println!("{}: {:?} W", b.address, b.mean_power());
+/// }
+/// ```
+pub fn measure_idle_power<P: ProcessorHandle>(
+    devices: &[P],
+    duration: Duration,
+    interval: Duration,
+) -> Result<Vec<IdleBaseline>, AmdError> {
+    struct Measurement {
+        address: AmdPciAddress,
+        start_energy: Option<AmdEnergyReading>,
+        samples: RollingWindow,
+    }
+
+    let start = Instant::now();
+    let mut measurements = Vec::with_capacity(devices.len());
+    for device in devices {
+        measurements.push(Measurement {
+            address: device.device_pci_address()?,
+            start_energy: device.device_energy_joules().optional()?,
+            // keep all the samples of the measurement
+            samples: RollingWindow::new(duration + interval),
+        });
+    }
+
+    loop {
+        let now = Instant::now();
+        for (device, m) in devices.iter().zip(&mut measurements) {
+            if let Some(power) = device.device_power_consumption().optional()? {
+                m.samples.push(now, power.socket_power as f64);
+            }
+        }
+        let elapsed = start.elapsed();
+        if elapsed >= duration {
+            break;
+        }
+        thread::sleep(interval.min(duration - elapsed));
+    }
+
+    let duration = start.elapsed();
+    let mut baselines = Vec::with_capacity(devices.len());
+    for (device, m) in devices.iter().zip(measurements) {
+        let end_energy = device.device_energy_joules().optional()?;
+        let energy_power = match (m.start_energy, end_energy) {
+            (Some(start), Some(end)) => end
+                .joules_since(&start)
+                .map(|joules| joules / duration.as_secs_f64()),
+            _ => None,
+        };
+        baselines.push(IdleBaseline {
+            address: m.address,
+            duration,
+            energy_power,
+            power: m.samples.stats(),
+        });
+    }
+    Ok(baselines)
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicU64, Ordering},
+        },
+        time::Duration,
+    };
+
+    use super::measure_idle_power;
+    use crate::{
+        error::{AmdError, AmdStatus},
+        handles::MockProcessorHandle,
+        metrics::{AmdEnergyReading, AmdPciAddress, AmdPowerConsumption},
+    };
+
+    fn unavailable() -> AmdError {
+        AmdError::Status {
+            status: AmdStatus::AMDSMI_STATUS_NOT_SUPPORTED,
+            message: None,
+        }
+    }
+
+    #[test]
+    fn idle_power() {
+        // device that reports its power and its energy
+        let mut measured = MockProcessorHandle::new();
+        measured
+            .expect_device_pci_address()
+            .returning(|| Ok(AmdPciAddress::from_bdf_id(0x0c00)));
+        let counter = Arc::new(AtomicU64::new(0));
+        measured
+            .expect_device_energy_joules()
+            .times(2)
+            .returning(move || {
+                Ok(AmdEnergyReading::from_microjoules(
+                    counter.fetch_add(1_000_000, Ordering::Relaxed),
+                ))
+            });
+        measured.expect_device_power_consumption().returning(|| {
+            Ok(AmdPowerConsumption {
+                socket_power: 50,
+                ..Default::default()
+            })
+        });
+
+        // device that reports neither
+        let mut unmeasured = MockProcessorHandle::new();
+        unmeasured
+            .expect_device_pci_address()
+            .returning(|| Ok(AmdPciAddress::from_bdf_id(0x2200)));
+        unmeasured
+            .expect_device_energy_joules()
+            .returning(|| Err(unavailable()));
+        unmeasured
+            .expect_device_power_consumption()
+            .returning(|| Err(unavailable()));
+
+        let duration = Duration::from_millis(20);
+        let baselines =
+            measure_idle_power(&[measured, unmeasured], duration, Duration::from_millis(5))
+                .unwrap();
+        assert_eq!(baselines.len(), 2);
+
+        let b = &baselines[0];
+        assert_eq!(b.address, AmdPciAddress::from_bdf_id(0x0c00));
+        assert!(b.duration >= duration);
+        // 1 J during the measurement
+        let energy_power = b.energy_power.unwrap();
+        assert!((energy_power - 1.0 / b.duration.as_secs_f64()).abs() < 1e-9);
+        assert_eq!(b.mean_power(), Some(energy_power));
+        let power = b.power.unwrap();
+        assert!(power.count > 1);
+        assert_eq!(power.mean, 50.0);
+
+        let b = &baselines[1];
+        assert_eq!(b.address, AmdPciAddress::from_bdf_id(0x2200));
+        assert_eq!(b.energy_power, None);
+        assert_eq!(b.power, None);
+        assert_eq!(b.mean_power(), None);
+    }
+}
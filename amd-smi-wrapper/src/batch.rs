@@ -0,0 +1,192 @@
+//! Batched reads of the metrics of several devices.
+//!
+//! The AMD SMI library serializes some calls internally. When several threads scrape the devices
+//! concurrently, their calls are interleaved call by call, and each scrape waits for all the others.
+//! A [`BatchReader`] instead reads all the metrics of one device in a single critical section,
+//! and lets the other threads take their turn between two devices.
+//!
+//! With the `serialize-ffi` feature, the critical section holds the lock of the library (see the
+//! [`serialize`](crate::serialize) module): all the other calls to the library wait for the end of the device,
+//! not only the other batches. Without the feature, the library is not locked by the wrapper,
+//! and only the batches of the readers are serialized.
+
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    AmdSmi,
+    dynamic::{MetricKind, MetricValue},
+    error::AmdError,
+    handles::ProcessorHandle,
+    serialize::FfiLock,
+};
+
+/// Reads a set of metrics on several devices, one device at a time.
+///
+/// The clones of a `BatchReader` share the same critical section: give a clone to each scraping thread.
+#[derive(Debug, Clone)]
+pub struct BatchReader {
+    metrics: Vec<MetricKind>,
+    lock: Arc<Mutex<()>>,
+    /// Lock of the library, held with `lock` (does nothing without the `serialize-ffi` feature).
+    ffi_lock: FfiLock,
+}
+
+impl BatchReader {
+    /// Creates a reader of the given metrics, on the devices of `amdsmi`.
+    pub fn new(amdsmi: &AmdSmi, metrics: impl IntoIterator<Item = MetricKind>) -> Self {
+        Self::with_ffi_lock(amdsmi.amdsmi.ffi_lock.clone(), metrics)
+    }
+
+    fn with_ffi_lock(ffi_lock: FfiLock, metrics: impl IntoIterator<Item = MetricKind>) -> Self {
+        Self {
+            metrics: metrics.into_iter().collect(),
+            lock: Arc::new(Mutex::new(())),
+            ffi_lock,
+        }
+    }
+
+    /// Returns the metrics that are read on each device.
+    pub fn metrics(&self) -> &[MetricKind] {
+        &self.metrics
+    }
+
+    /// Reads the metrics of every device, in order.
+    ///
+    /// The errors are reported per metric: a metric that cannot be read does not prevent the others from being read.
+    pub fn read<P: ProcessorHandle>(&self, devices: &[P]) -> BatchReport {
+        let start = Instant::now();
+        let devices = devices
+            .iter()
+            .map(|device| {
+                let wait_start = Instant::now();
+                // a panic while holding the lock does not corrupt anything: ignore the poisoning
+                let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+                // the calls of the device re-enter the lock of the library, which is acquired once
+                let _ffi = self.ffi_lock.lock();
+                let read_start = Instant::now();
+                let values = self
+                    .metrics
+                    .iter()
+                    .map(|&kind| (kind, device.device_read_metric(kind)))
+                    .collect();
+                DeviceBatch {
+                    values,
+                    wait: read_start - wait_start,
+                    duration: read_start.elapsed(),
+                }
+            })
+            .collect();
+        BatchReport {
+            devices,
+            total: start.elapsed(),
+        }
+    }
+}
+
+/// Metrics read on one device by a [`BatchReader`].
+#[derive(Debug)]
+pub struct DeviceBatch {
+    /// Value of each metric, in the order of [`BatchReader::metrics`].
+    pub values: Vec<(MetricKind, Result<MetricValue, AmdError>)>,
+    /// Time spent waiting for the other threads before reading the device.
+    pub wait: Duration,
+    /// Time spent reading the metrics of the device.
+    pub duration: Duration,
+}
+
+/// Result of [`BatchReader::read`], with timing statistics.
+#[derive(Debug)]
+pub struct BatchReport {
+    /// Metrics of each device, in the order of the devices.
+    pub devices: Vec<DeviceBatch>,
+    /// Total duration of the read, waits included.
+    pub total: Duration,
+}
+
+impl BatchReport {
+    /// Returns the total time spent waiting for the other threads.
+    pub fn total_wait(&self) -> Duration {
+        self.devices.iter().map(|d| d.wait).sum()
+    }
+
+    /// Returns the longest time spent reading one device.
+    pub fn max_device_duration(&self) -> Duration {
+        self.devices
+            .iter()
+            .map(|d| d.duration)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::BatchReader;
+    use crate::{
+        dynamic::{MetricKind, MetricValue},
+        error::{AmdError, AmdStatus},
+        handles::MockProcessorHandle,
+        serialize::FfiLock,
+    };
+
+    fn device(power: u64) -> MockProcessorHandle {
+        let mut device = MockProcessorHandle::new();
+        device
+            .expect_device_read_metric()
+            .returning(move |kind| match kind {
+                MetricKind::Power => Ok(MetricValue::Unsigned(power)),
+                _ => Err(AmdError::Status {
+                    status: AmdStatus::AMDSMI_STATUS_NOT_SUPPORTED,
+                    message: None,
+                }),
+            });
+        device
+    }
+
+    #[test]
+    fn read() {
+        // the identifiers are addresses of functions, this one cannot collide with a real library
+        let reader = BatchReader::with_ffi_lock(
+            FfiLock::for_library(5),
+            [MetricKind::Power, MetricKind::GfxActivity],
+        );
+        let report = reader.read(&[device(100), device(200)]);
+        assert_eq!(report.devices.len(), 2);
+        for (batch, power) in report.devices.iter().zip([100, 200]) {
+            assert_eq!(batch.values.len(), 2);
+            assert_eq!(batch.values[0].0, MetricKind::Power);
+            assert_eq!(
+                *batch.values[0].1.as_ref().unwrap(),
+                MetricValue::Unsigned(power)
+            );
+            // an error does not prevent the other metrics from being read
+            assert_eq!(batch.values[1].0, MetricKind::GfxActivity);
+            assert!(batch.values[1].1.is_err());
+        }
+        assert!(report.total >= report.total_wait() + report.max_device_duration());
+    }
+
+    #[cfg(feature = "serialize-ffi")]
+    #[test]
+    fn ffi_lock_per_device() {
+        let ffi_lock = FfiLock::for_library(6);
+        let mut device = MockProcessorHandle::new();
+        let lock = ffi_lock.clone();
+        device
+            .expect_device_read_metric()
+            .times(4)
+            .returning(move |_| {
+                // the calls of the library re-enter the lock held by the batch
+                drop(lock.lock());
+                Ok(MetricValue::Unsigned(0))
+            });
+        let reader =
+            BatchReader::with_ffi_lock(ffi_lock.clone(), [MetricKind::Power, MetricKind::Energy]);
+        reader.read(std::slice::from_ref(&device));
+        reader.read(std::slice::from_ref(&device));
+        assert_eq!(ffi_lock.stats().acquisitions, 2);
+    }
+}
@@ -0,0 +1,311 @@
+//! Clocks pinned for benchmarking, to get reproducible performance and energy measurements.
+//!
+//! By default, the driver changes the clocks of the GPU according to the load, the temperature and the power:
+//! two runs of the same workload can run at different frequencies, and consume different energies.
+//! [`BenchmarkMode`] pins the clocks with the performance level, the determinism mode and the clock ranges
+//! of a [`BenchmarkConfig`], and restores the original settings when it is dropped.
+//!
+//! This module requires the feature `control`.
+//!
+//! # Example
+//! ```no_run
+//! use amd_smi_wrapper::{AmdSmi, AmdInitFlags, benchmark::{BenchmarkConfig, BenchmarkMode}};
+//!
+//! let amdsmi = AmdSmi::init(AmdInitFlags::AMDSMI_INIT_AMD_GPUS).unwrap();
+//! let device = amdsmi.iter_processors().unwrap().next().unwrap().unwrap();
+//! let config = BenchmarkConfig {
+//!     determinism_clock: Some(1500),
+//!     ..Default::default()
+//! };
+//! {
+//!     let _pinned = BenchmarkMode::enter(&device, &config).expect("could not pin the clocks");
+//!     // ... run the benchmark at 1500 MHz ...
+//! } // the original settings are restored here
+//! ```
+
+use crate::{
+    control::ProcessorControl,
+    error::{AmdError, AmdStatus, ResultExt},
+    handles::ProcessorHandle,
+    metrics::{AmdClkType, AmdClockRange, AmdPerfLevel},
+};
+
+/// Settings applied by [`BenchmarkMode::enter`], in this order. The settings that are `None` are not changed.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BenchmarkConfig {
+    /// Performance level, for instance `STABLE_STD` to run at the stable frequencies of the GPU.
+    pub perf_level: Option<AmdPerfLevel>,
+    /// Cap of the graphics clock in determinism mode, in MHz. This switches the performance level to
+    /// `DETERMINISM`.
+    pub determinism_clock: Option<u64>,
+    /// Range of the graphics clock, in MHz.
+    pub gfx_clock_range: Option<AmdClockRange>,
+    /// Range of the memory clock, in MHz.
+    pub mem_clock_range: Option<AmdClockRange>,
+}
+
+impl BenchmarkConfig {
+    /// Returns a configuration that pins the graphics clock to a frequency, in MHz.
+    pub fn pinned_gfx_clock(frequency: u64) -> Self {
+        Self {
+            gfx_clock_range: Some(AmdClockRange {
+                min: frequency,
+                max: frequency,
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+/// Clocks of a GPU pinned for benchmarking, restored when dropped. See the [module documentation](self).
+///
+/// The errors that happen when the settings are restored on drop are ignored. To handle them,
+/// call [`exit`](BenchmarkMode::exit).
+pub struct BenchmarkMode<'a, P: ProcessorHandle + ProcessorControl> {
+    device: &'a P,
+    original_perf_level: AmdPerfLevel,
+    /// Clock ranges to restore, in the order in which they have been changed.
+    original_ranges: Vec<(AmdClkType, AmdClockRange)>,
+    restored: bool,
+}
+
+impl<'a, P: ProcessorHandle + ProcessorControl> BenchmarkMode<'a, P> {
+    /// Applies the settings of `config` to the device.
+    ///
+    /// If a setting cannot be applied, the settings already applied are restored and the error is returned.
+    /// The clock ranges can only be restored if the device reports them
+    /// (see [`device_clock_limits`](ProcessorHandle::device_clock_limits)): otherwise, changing them fails
+    /// with `NOT_SUPPORTED`.
+    pub fn enter(device: &'a P, config: &BenchmarkConfig) -> Result<Self, AmdError> {
+        for range in [config.gfx_clock_range, config.mem_clock_range]
+            .into_iter()
+            .flatten()
+        {
+            if range.min > range.max {
+                return Err(AmdError::InvalidArgument(format!(
+                    "invalid clock range {}-{} MHz",
+                    range.min, range.max
+                )));
+            }
+        }
+        let ranges = [
+            (AmdClkType::AMDSMI_CLK_TYPE_SYS, config.gfx_clock_range),
+            (AmdClkType::AMDSMI_CLK_TYPE_MEM, config.mem_clock_range),
+        ];
+        let limits = if ranges.iter().any(|(_, range)| range.is_some()) {
+            let limits = device.device_clock_limits().optional()?.ok_or_else(|| {
+                AmdError::Status {
+                    status: AmdStatus::AMDSMI_STATUS_NOT_SUPPORTED,
+                    message: Some(String::from(
+                        "the clock ranges of the device cannot be read, they could not be restored",
+                    )),
+                }
+            })?;
+            Some(limits)
+        } else {
+            None
+        };
+        let mut mode = Self {
+            device,
+            original_perf_level: device.device_perf_level()?,
+            original_ranges: Vec::new(),
+            restored: false,
+        };
+        // on error, `mode` is dropped and restores the settings
+        if let Some(level) = config.perf_level {
+            device.set_perf_level(level)?;
+        }
+        if let Some(clock) = config.determinism_clock {
+            device.set_determinism_mode(clock)?;
+        }
+        for (clk_type, range) in ranges {
+            if let (Some(range), Some(limits)) = (range, limits) {
+                let original = if clk_type == AmdClkType::AMDSMI_CLK_TYPE_SYS {
+                    restorable(limits.sclk, limits.sclk_limits)
+                } else {
+                    // the lower bound of the current memory range is not meaningful (typically 0)
+                    restorable(limits.mclk, limits.mclk_limits)
+                };
+                mode.original_ranges.push((clk_type, original));
+                device.set_clock_range(clk_type, range)?;
+            }
+        }
+        Ok(mode)
+    }
+
+    /// Returns the performance level that will be restored.
+    pub fn original_perf_level(&self) -> AmdPerfLevel {
+        self.original_perf_level
+    }
+
+    /// Restores the original settings now.
+    ///
+    /// All the settings are restored even if one fails: the first error is returned.
+    pub fn exit(mut self) -> Result<(), AmdError> {
+        self.restore()
+    }
+
+    fn restore(&mut self) -> Result<(), AmdError> {
+        self.restored = true;
+        let mut result = Ok(());
+        for (clk_type, range) in self.original_ranges.iter().rev() {
+            result = result.and(self.device.set_clock_range(*clk_type, *range));
+        }
+        // also leaves the determinism mode
+        result.and(self.device.set_perf_level(self.original_perf_level))
+    }
+}
+
+/// Clamps a range read from the device to the `limits` of the clock, so that
+/// [`set_clock_range`](ProcessorControl::set_clock_range) accepts it when it is restored.
+fn restorable(range: AmdClockRange, limits: AmdClockRange) -> AmdClockRange {
+    if limits.min > limits.max {
+        return range;
+    }
+    let max = range.max.clamp(limits.min, limits.max);
+    AmdClockRange {
+        min: range.min.clamp(limits.min, max),
+        max,
+    }
+}
+
+impl<P: ProcessorHandle + ProcessorControl> Drop for BenchmarkMode<'_, P> {
+    fn drop(&mut self) {
+        if !self.restored {
+            let _ = self.restore();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use mockall::{Sequence, predicate::eq};
+
+    use super::{BenchmarkConfig, BenchmarkMode};
+    use crate::{
+        control::MockControlledDevice,
+        error::{AmdError, AmdStatus},
+        metrics::{AmdClkType, AmdClockLimits, AmdClockRange, AmdPerfLevel},
+    };
+
+    const SYS: AmdClkType = AmdClkType::AMDSMI_CLK_TYPE_SYS;
+    const MEM: AmdClkType = AmdClkType::AMDSMI_CLK_TYPE_MEM;
+    const AUTO: AmdPerfLevel = AmdPerfLevel::AMDSMI_DEV_PERF_LEVEL_AUTO;
+    const MANUAL: AmdPerfLevel = AmdPerfLevel::AMDSMI_DEV_PERF_LEVEL_MANUAL;
+
+    fn range(min: u64, max: u64) -> AmdClockRange {
+        AmdClockRange { min, max }
+    }
+
+    fn config() -> BenchmarkConfig {
+        BenchmarkConfig {
+            perf_level: Some(MANUAL),
+            gfx_clock_range: Some(range(1500, 1500)),
+            mem_clock_range: Some(range(900, 900)),
+            ..Default::default()
+        }
+    }
+
+    /// Device whose memory clock reports a current lower bound of 0, below its limits.
+    fn device() -> MockControlledDevice {
+        let mut device = MockControlledDevice::new();
+        device.expect_device_clock_limits().returning(|| {
+            Ok(AmdClockLimits {
+                sclk: range(500, 2100),
+                mclk: range(0, 1200),
+                sclk_limits: range(500, 2100),
+                mclk_limits: range(100, 1200),
+            })
+        });
+        device.expect_device_perf_level().returning(|| Ok(AUTO));
+        device
+    }
+
+    fn expect_range(
+        device: &mut MockControlledDevice,
+        seq: &mut Sequence,
+        clk_type: AmdClkType,
+        range: AmdClockRange,
+        result: fn() -> Result<(), AmdError>,
+    ) {
+        device
+            .expect_set_clock_range()
+            .with(eq(clk_type), eq(range))
+            .times(1)
+            .in_sequence(seq)
+            .returning(move |_, _| result());
+    }
+
+    fn expect_level(device: &mut MockControlledDevice, seq: &mut Sequence, level: AmdPerfLevel) {
+        device
+            .expect_set_perf_level()
+            .with(eq(level))
+            .times(1)
+            .in_sequence(seq)
+            .returning(|_| Ok(()));
+    }
+
+    #[test]
+    fn restore_on_drop() {
+        let mut device = device();
+        let mut seq = Sequence::new();
+        expect_level(&mut device, &mut seq, MANUAL);
+        expect_range(&mut device, &mut seq, SYS, range(1500, 1500), || Ok(()));
+        expect_range(&mut device, &mut seq, MEM, range(900, 900), || Ok(()));
+        // restored in the reverse order, with the lower bound of the memory clock within its limits
+        expect_range(&mut device, &mut seq, MEM, range(100, 1200), || Ok(()));
+        expect_range(&mut device, &mut seq, SYS, range(500, 2100), || Ok(()));
+        expect_level(&mut device, &mut seq, AUTO);
+        {
+            let mode = BenchmarkMode::enter(&device, &config()).unwrap();
+            assert_eq!(mode.original_perf_level(), AUTO);
+        }
+        device.checkpoint();
+    }
+
+    #[test]
+    fn rollback_on_error() {
+        let mut device = device();
+        let mut seq = Sequence::new();
+        expect_level(&mut device, &mut seq, MANUAL);
+        expect_range(&mut device, &mut seq, SYS, range(1500, 1500), || Ok(()));
+        expect_range(&mut device, &mut seq, MEM, range(900, 900), || {
+            Err(AmdError::Status {
+                status: AmdStatus::AMDSMI_STATUS_NO_PERM,
+                message: None,
+            })
+        });
+        expect_range(&mut device, &mut seq, MEM, range(100, 1200), || Ok(()));
+        expect_range(&mut device, &mut seq, SYS, range(500, 2100), || Ok(()));
+        expect_level(&mut device, &mut seq, AUTO);
+        let Err(err) = BenchmarkMode::enter(&device, &config()) else {
+            panic!("the memory clock should not be applied");
+        };
+        assert_eq!(err.status(), Some(AmdStatus::AMDSMI_STATUS_NO_PERM));
+        device.checkpoint();
+    }
+
+    #[test]
+    fn exit_reports_errors() {
+        let mut device = device();
+        let mut seq = Sequence::new();
+        expect_level(&mut device, &mut seq, MANUAL);
+        expect_range(&mut device, &mut seq, SYS, range(1500, 1500), || Ok(()));
+        expect_range(&mut device, &mut seq, SYS, range(500, 2100), || {
+            Err(AmdError::Status {
+                status: AmdStatus::AMDSMI_STATUS_BUSY,
+                message: None,
+            })
+        });
+        // the other settings are still restored
+        expect_level(&mut device, &mut seq, AUTO);
+        let config = BenchmarkConfig {
+            mem_clock_range: None,
+            ..config()
+        };
+        let mode = BenchmarkMode::enter(&device, &config).unwrap();
+        let err = mode.exit().unwrap_err();
+        assert_eq!(err.status(), Some(AmdStatus::AMDSMI_STATUS_BUSY));
+        device.checkpoint();
+    }
+}
@@ -0,0 +1,464 @@
+//! Configuration of the initialization of the library.
+
+use std::{
+    ffi::OsString,
+    sync::{
+        Arc, Mutex, RwLock,
+        atomic::{AtomicBool, AtomicU64},
+    },
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    AmdInitFlags, AmdSmi, LibAmdSmi,
+    bindings::amdsmi_init_flags_t,
+    dynamic::RateLimits,
+    error::{AmdError, AmdInitError, AmdStatus},
+    instrument::LatencyRecorder,
+    loader::{self, AmdProbe, LibraryVariant},
+    serialize::FfiLock,
+    units::UnitSystem,
+};
+
+/// Class of processors that the library discovers.
+///
+/// See [`AmdSmiBuilder::processor_class`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ProcessorClass {
+    AmdCpus,
+    AmdGpus,
+    NonAmdCpus,
+    NonAmdGpus,
+}
+
+impl ProcessorClass {
+    /// All the classes of processors.
+    pub const ALL: [ProcessorClass; 4] = [
+        ProcessorClass::AmdCpus,
+        ProcessorClass::AmdGpus,
+        ProcessorClass::NonAmdCpus,
+        ProcessorClass::NonAmdGpus,
+    ];
+
+    /// Returns the classes of processors selected by `flags`, for instance to log them.
+    ///
+    /// The bits that are not a class are ignored.
+    pub fn from_flags(flags: AmdInitFlags) -> Vec<ProcessorClass> {
+        Self::ALL
+            .into_iter()
+            .filter(|class| flags.0 & class.flag().0 != 0)
+            .collect()
+    }
+
+    /// Returns the initialization flag that corresponds to this class.
+    pub fn flag(&self) -> AmdInitFlags {
+        match self {
+            ProcessorClass::AmdCpus => AmdInitFlags::AMDSMI_INIT_AMD_CPUS,
+            ProcessorClass::AmdGpus => AmdInitFlags::AMDSMI_INIT_AMD_GPUS,
+            ProcessorClass::NonAmdCpus => AmdInitFlags::AMDSMI_INIT_NON_AMD_CPUS,
+            ProcessorClass::NonAmdGpus => AmdInitFlags::AMDSMI_INIT_NON_AMD_GPUS,
+        }
+    }
+}
+
+/// Bits of the known processor classes.
+const KNOWN_FLAGS: u32 = AmdInitFlags::AMDSMI_INIT_AMD_CPUS.0
+    | AmdInitFlags::AMDSMI_INIT_AMD_GPUS.0
+    | AmdInitFlags::AMDSMI_INIT_NON_AMD_CPUS.0
+    | AmdInitFlags::AMDSMI_INIT_NON_AMD_GPUS.0;
+
+/// Checks that the library can be initialized with `flags`, before loading it.
+///
+/// The library returns `INVAL` or `NOT_SUPPORTED` for the invalid combinations, without details.
+/// This function rejects them with a description:
+/// - no processor class,
+/// - bits that are not a processor class (except [`AMDSMI_INIT_ALL_PROCESSORS`](AmdInitFlags::AMDSMI_INIT_ALL_PROCESSORS)),
+/// - the non-AMD CPUs, that the library does not support,
+/// - the CPUs with the library of the host driver, that only manages GPUs.
+pub fn validate_flags(flags: AmdInitFlags, variant: LibraryVariant) -> Result<(), AmdInitError> {
+    let invalid = |reason| {
+        Err(AmdInitError::InvalidFlags {
+            flags: flags.0,
+            reason,
+        })
+    };
+    if flags == AmdInitFlags::AMDSMI_INIT_ALL_PROCESSORS {
+        return Ok(());
+    }
+    if flags.0 == 0 {
+        return invalid("no processor class is selected");
+    }
+    if flags.0 & !KNOWN_FLAGS != 0 {
+        return invalid("unknown bits are set");
+    }
+    if flags.0 & AmdInitFlags::AMDSMI_INIT_NON_AMD_CPUS.0 != 0 {
+        return invalid("the library does not support the non-AMD CPUs");
+    }
+    if variant == LibraryVariant::Host && flags.0 & AmdInitFlags::AMDSMI_INIT_AMD_CPUS.0 != 0 {
+        return invalid("the library of the host driver only supports the GPUs");
+    }
+    Ok(())
+}
+
+/// How to retry the initialization when it fails with a transient error.
+///
+/// The initialization is retried if the error is retryable (see [`AmdError::is_retryable`]),
+/// or if the GPU driver is not loaded yet, which happens when the program starts early at boot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximal number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// Delay between two attempts.
+    pub delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Makes `max_attempts` attempts, waiting `delay` between them.
+    pub fn new(max_attempts: u32, delay: Duration) -> Self {
+        Self {
+            max_attempts,
+            delay,
+        }
+    }
+
+    /// Makes only one attempt.
+    pub fn none() -> Self {
+        Self::new(1, Duration::ZERO)
+    }
+
+    fn should_retry(&self, attempt: u32, error: &AmdError) -> bool {
+        attempt < self.max_attempts
+            && (error.is_retryable()
+                || error.status() == Some(AmdStatus::AMDSMI_STATUS_DRIVER_NOT_LOADED))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+/// Builder of [`AmdSmi`], to configure the initialization of the library.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use amd_smi_wrapper::{AmdSmi, builder::{ProcessorClass, RetryPolicy}};
+///
+/// let amdsmi = AmdSmi::builder()
+///     .processor_class(ProcessorClass::AmdGpus)
+///     .library_path("/opt/rocm/lib/libamd_smi.so")
+///     .retry_policy(RetryPolicy::new(5, Duration::from_secs(2)))
+///     .auto_recovery(true)
+///     .build()
+///     .expect("init failed");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct AmdSmiBuilder {
+    flags: Option<AmdInitFlags>,
+    library_path: Option<OsString>,
+    variant: LibraryVariant,
+    retry_policy: RetryPolicy,
+    auto_recovery: bool,
+    skip_suspended: bool,
+    unsupported_cache_ttl: Option<Duration>,
+    units: UnitSystem,
+    rate_limits: RateLimits,
+    /// Whether the unknown bits of the flags are passed to the library.
+    unknown_flags: bool,
+    #[cfg(feature = "control")]
+    shared_control: bool,
+}
+
+impl AmdSmiBuilder {
+    /// Creates a builder with the default configuration: AMD GPUs only, library loaded from the default search path.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a class of processors to discover.
+    ///
+    /// If no class is added, only the AMD GPUs are discovered.
+    pub fn processor_class(mut self, class: ProcessorClass) -> Self {
+        let flags = self.flags.map_or(0, |f| f.0) | class.flag().0;
+        self.flags = Some(amdsmi_init_flags_t(flags));
+        self
+    }
+
+    /// Adds several classes of processors to discover.
+    pub fn processor_classes(self, classes: impl IntoIterator<Item = ProcessorClass>) -> Self {
+        classes
+            .into_iter()
+            .fold(self, |builder, class| builder.processor_class(class))
+    }
+
+    /// Sets the raw initialization flags, replacing the selected processor classes.
+    pub fn flags(mut self, flags: AmdInitFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Sets the bits of the initialization flags, replacing the selected processor classes.
+    ///
+    /// Unlike [`flags`](Self::flags), the bits that this crate does not know are passed to the library,
+    /// for the flags added by the newer versions of the library. The other checks of [`validate_flags`] still apply.
+    pub fn flag_bits(mut self, bits: u32) -> Self {
+        self.flags = Some(amdsmi_init_flags_t(bits));
+        self.unknown_flags = true;
+        self
+    }
+
+    /// Loads the library from `path` instead of the file name of the variant (see
+    /// [`LibraryVariant::file_name`]), which is resolved by the dynamic loader.
+    pub fn library_path(mut self, path: impl Into<OsString>) -> Self {
+        self.library_path = Some(path.into());
+        self
+    }
+
+    /// Allows the control of the devices (see [`control`](crate::control)) while another copy of the library
+    /// is loaded in the process.
+    ///
+    /// Several instances can load different files of the library, for instance to compare a new ROCm build with
+    /// the installed one: each file is a separate copy, with its own state, but all the copies talk to the same
+    /// driver (see [`LoadedLibrary`](crate::loader::LoadedLibrary) for the limits of this separation).
+    /// The copies do not coordinate their changes of the settings (power cap, clocks...): by default, the control
+    /// fails with [`AmdError::ControlRefused`] while several copies are loaded.
+    /// Instances that load the same file, even through different paths, share one copy, and are not restricted.
+    #[cfg(feature = "control")]
+    pub fn shared_control(mut self, allowed: bool) -> Self {
+        self.shared_control = allowed;
+        self
+    }
+
+    /// Selects the variant of the library to load: the ROCm library (the default), or the library
+    /// of the host virtualization driver.
+    ///
+    /// The variant changes the name of the library that is searched when no path is given with
+    /// [`library_path`](Self::library_path), and the symbols that the library must export.
+    /// The support of the host library is untested, see the limitations of [`LibraryVariant::Host`].
+    pub fn library_variant(mut self, variant: LibraryVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Sets how to retry the initialization. By default, it is not retried.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Enables the automatic recovery after a driver restart, see [`AmdSmi::set_auto_recovery`].
+    pub fn auto_recovery(mut self, enabled: bool) -> Self {
+        self.auto_recovery = enabled;
+        self
+    }
+
+    /// Enables the protection of the suspended devices, see [`AmdSmi::set_skip_suspended`].
+    pub fn skip_suspended(mut self, enabled: bool) -> Self {
+        self.skip_suspended = enabled;
+        self
+    }
+
+    /// Enables the cache of the unavailable metrics, see [`AmdSmi::set_unsupported_cache_ttl`].
+    pub fn unsupported_cache_ttl(mut self, ttl: Option<Duration>) -> Self {
+        self.unsupported_cache_ttl = ttl;
+        self
+    }
+
+    /// Limits the rate of the reads of the metrics, see [`AmdSmi::set_rate_limits`].
+    pub fn rate_limits(mut self, limits: RateLimits) -> Self {
+        self.rate_limits = limits;
+        self
+    }
+
+    /// Selects the units of the values returned by the dynamic reads and by the getters of
+    /// [`Quantity`](crate::units::Quantity): the units of the library (the default), or the SI units.
+    /// See [`units`](crate::units).
+    pub fn unit_system(mut self, units: UnitSystem) -> Self {
+        self.units = units;
+        self
+    }
+
+    /// Checks whether the library can be loaded from the configured path, and exports the required symbols.
+    ///
+    /// The library is not initialized. See [`AmdSmi::probe`].
+    pub fn probe(&self) -> AmdProbe {
+        AmdProbe::run(&self.candidates(), self.variant)
+    }
+
+    fn candidates(&self) -> Vec<OsString> {
+        match &self.library_path {
+            Some(path) => vec![path.clone()],
+            None => vec![OsString::from(self.variant.file_name())],
+        }
+    }
+
+    /// Returns the initialization flags, after checking them with [`validate_flags`].
+    ///
+    /// With [`flag_bits`](Self::flag_bits), only the known bits are checked: the unknown bits are left to the
+    /// library, even if they are the only ones.
+    fn checked_flags(&self) -> Result<AmdInitFlags, AmdInitError> {
+        let flags = self.flags.unwrap_or(AmdInitFlags::AMDSMI_INIT_AMD_GPUS);
+        let checked = if self.unknown_flags && flags != AmdInitFlags::AMDSMI_INIT_ALL_PROCESSORS {
+            amdsmi_init_flags_t(flags.0 & KNOWN_FLAGS)
+        } else {
+            flags
+        };
+        if checked.0 != 0 || flags.0 == 0 {
+            validate_flags(checked, self.variant)?;
+        }
+        Ok(flags)
+    }
+
+    /// Loads and initializes the library.
+    pub fn build(self) -> Result<AmdSmi, AmdInitError> {
+        let flags = self.checked_flags()?;
+
+        let (amdsmi, path) = match loader::load(&self.candidates()) {
+            (Some(loaded), _) => loaded,
+            (None, attempts) => return Err(AmdInitError::Load { attempts }),
+        };
+        let missing = loader::missing_symbols(&amdsmi, self.variant);
+        if !missing.is_empty() {
+            return Err(AmdInitError::MissingSymbols {
+                path,
+                symbols: missing,
+            });
+        }
+        let library_id = loader::library_id(&amdsmi);
+        // unregistered when `LibAmdSmi` is dropped
+        loader::register(library_id, &path);
+        let instance = AmdSmi {
+            amdsmi: Arc::new(LibAmdSmi {
+                amdsmi,
+                flags,
+                variant: self.variant,
+                library_id,
+                path,
+                #[cfg(feature = "control")]
+                shared_control: self.shared_control,
+                units: self.units,
+                auto_recovery: AtomicBool::new(self.auto_recovery),
+                skip_suspended: AtomicBool::new(self.skip_suspended),
+                generation: AtomicU64::new(0),
+                reinit_lock: Mutex::new(()),
+                unsupported_ttl_ms: AtomicU64::new(0),
+                unsupported_epoch: AtomicU64::new(0),
+                rate_limits: RwLock::new(self.rate_limits),
+                ffi_lock: FfiLock::for_library(library_id),
+                latency: LatencyRecorder::default(),
+                #[cfg(feature = "trace-capture")]
+                capture: Default::default(),
+            }),
+        };
+
+        instance.set_unsupported_cache_ttl(self.unsupported_cache_ttl);
+
+        let mut attempt = 1;
+        loop {
+            let status = {
+                let _ffi = instance.amdsmi.ffi_lock.lock();
+                // SAFETY: The function expects a valid library instance and valid flags.
+                // According to the AMD-SMI documentation, the function fully initializes internal structures for GPU discovery.
+                // The return code `amdsmi_status_t` is checked to ensure initialization succeeded before using the library.
+                unsafe { instance.amdsmi.amdsmi.amdsmi_init(flags.0.into()) }
+            };
+            match instance.check_status(status) {
+                Ok(()) => return Ok(instance),
+                Err(e) if self.retry_policy.should_retry(attempt, &e) => {
+                    attempt += 1;
+                    thread::sleep(self.retry_policy.delay);
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AmdSmiBuilder, ProcessorClass, validate_flags};
+    use crate::{AmdInitFlags, bindings::amdsmi_init_flags_t, loader::LibraryVariant};
+
+    #[test]
+    fn processor_classes() {
+        let builder = AmdSmiBuilder::new();
+        assert_eq!(builder.flags, None);
+
+        let builder = builder.processor_classes([ProcessorClass::AmdGpus, ProcessorClass::AmdCpus]);
+        assert_eq!(builder.flags, Some(AmdInitFlags::AMDSMI_INIT_AMD_APUS));
+    }
+
+    #[test]
+    fn classes_from_flags() {
+        assert_eq!(
+            ProcessorClass::from_flags(AmdInitFlags::AMDSMI_INIT_AMD_APUS),
+            vec![ProcessorClass::AmdCpus, ProcessorClass::AmdGpus]
+        );
+        assert_eq!(
+            ProcessorClass::from_flags(amdsmi_init_flags_t(1 << 1 | 1 << 8)),
+            vec![ProcessorClass::AmdGpus]
+        );
+    }
+
+    #[test]
+    fn checked_flags() {
+        let checked = |builder: AmdSmiBuilder| builder.checked_flags().map(|flags| flags.0);
+        let unknown = 1 << 8;
+        let gpus = AmdInitFlags::AMDSMI_INIT_AMD_GPUS.0;
+        let non_amd_cpus = AmdInitFlags::AMDSMI_INIT_NON_AMD_CPUS.0;
+
+        assert_eq!(checked(AmdSmiBuilder::new()).unwrap(), gpus);
+        // with `flags`, the unknown bits are refused
+        assert!(checked(AmdSmiBuilder::new().flags(amdsmi_init_flags_t(gpus | unknown))).is_err());
+        // with `flag_bits`, they are passed to the library, alone or with known bits
+        assert_eq!(
+            checked(AmdSmiBuilder::new().flag_bits(unknown)).unwrap(),
+            unknown
+        );
+        assert_eq!(
+            checked(AmdSmiBuilder::new().flag_bits(gpus | unknown)).unwrap(),
+            gpus | unknown
+        );
+        // but the known bits are still checked
+        assert!(checked(AmdSmiBuilder::new().flag_bits(non_amd_cpus | unknown)).is_err());
+        assert!(checked(AmdSmiBuilder::new().flag_bits(0)).is_err());
+        assert!(
+            checked(
+                AmdSmiBuilder::new()
+                    .library_variant(LibraryVariant::Host)
+                    .flag_bits(AmdInitFlags::AMDSMI_INIT_AMD_CPUS.0 | unknown)
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn flags_validation() {
+        let valid = |flags, variant| validate_flags(flags, variant).is_ok();
+        assert!(valid(
+            AmdInitFlags::AMDSMI_INIT_AMD_GPUS,
+            LibraryVariant::Rocm
+        ));
+        assert!(valid(
+            AmdInitFlags::AMDSMI_INIT_AMD_APUS,
+            LibraryVariant::Rocm
+        ));
+        assert!(valid(
+            AmdInitFlags::AMDSMI_INIT_ALL_PROCESSORS,
+            LibraryVariant::Rocm
+        ));
+        assert!(!valid(amdsmi_init_flags_t(0), LibraryVariant::Rocm));
+        assert!(!valid(amdsmi_init_flags_t(1 << 8), LibraryVariant::Rocm));
+        assert!(!valid(
+            AmdInitFlags::AMDSMI_INIT_NON_AMD_CPUS,
+            LibraryVariant::Rocm
+        ));
+        assert!(!valid(
+            AmdInitFlags::AMDSMI_INIT_AMD_APUS,
+            LibraryVariant::Host
+        ));
+    }
+}
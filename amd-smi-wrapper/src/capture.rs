@@ -0,0 +1,818 @@
+//! Capture of the calls to the library, to reproduce the behavior of a machine elsewhere.
+//!
+//! With [`AmdSmi::start_trace_capture`], each call to the library is written to a file: the name of the
+//! wrapper function, the PCI address of the device, the arguments of the call, the status returned by the
+//! library, the kind of the error, the duration of the call and the returned value. The arguments and the value
+//! are formatted with [`Debug`]. The file can be attached to a bug report, and loaded by the maintainers with
+//! [`TraceReplay::load`].
+//!
+//! With the feature `mock`, [`TraceReplay::mock_device`] returns a [`MockProcessorHandle`] that replays the
+//! results of the calls to one device: each call returns the next result captured for the same function
+//! with the same arguments (sensor, memory type...). The wrappers that return a scalar or a string are replayed,
+//! and so are the structured getters read by the collectors: power, energy, activity, clocks, PCIe traffic
+//! and GPU metrics table. The other values are kept as text in the [`records`](TraceReplay::records).
+//!
+//! This module requires the feature `trace-capture`.
+//!
+//! # Format
+//! The capture is a text file with one call per line, made of tab-separated fields:
+//! `function`, `device` (empty for the calls that do not target a device), `arguments` (empty for the
+//! functions without arguments), `status` (empty for the errors that do not come from the library), `error`
+//! (the [kind](crate::error::AmdError::kind) of the error, empty if the call succeeded), `duration` (in µs) and
+//! `value` (empty if the call failed). The fields are escaped like the JSON strings (without the quotes),
+//! so that they contain no tab nor line feed. The lines that start with `#` are comments.
+//!
+//! The captures written by the previous versions of this crate have no `arguments` field: their calls are
+//! replayed in order, whatever their arguments.
+//!
+//! # Example
+//! ```no_run
+//! use amd_smi_wrapper::{AmdSmi, AmdInitFlags, capture::TraceReplay};
+//!
+//! // on the machine that shows the problem
+//! let amdsmi = AmdSmi::init(AmdInitFlags::AMDSMI_INIT_AMD_GPUS).unwrap();
+//! amdsmi.start_trace_capture("amd-smi.trace").unwrap();
+//! // ... run the program ...
+//! amdsmi.stop_trace_capture().unwrap();
+//!
+//! // elsewhere
+//! let replay = TraceReplay::load("amd-smi.trace").unwrap();
+//! for record in replay.records() {
+//!     println!("{} {:?} -> {:?}", record.function, record.device, record.value);
+//! }
+//! ```
+//!
+//! [`AmdSmi::start_trace_capture`]: crate::AmdSmi::start_trace_capture
+//! [`MockProcessorHandle`]: crate::handles::MockProcessorHandle
+
+use std::{
+    fs::File,
+    io::{self, LineWriter, Write},
+    path::Path,
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+#[cfg(feature = "mock")]
+use std::collections::{HashMap, VecDeque};
+
+use crate::json;
+
+#[cfg(feature = "mock")]
+use crate::{
+    bindings::{
+        amd_metrics_table_header_t, amdsmi_gpu_metrics_t, amdsmi_gpu_xcp_metrics_t, amdsmi_status_t,
+    },
+    error::{AmdError, AmdStatus},
+    gpu_metrics::AmdGpuMetrics,
+    handles::MockProcessorHandle,
+    metrics::{
+        AmdClkInfo, AmdEnergyConsumption, AmdEngineUsage, AmdPciTraffic, AmdPowerCapInfo,
+        AmdPowerConsumption,
+    },
+};
+
+/// First line of the captures.
+const HEADER: &str = "# amd-smi-wrapper trace capture";
+
+/// Call to the library, as written to a capture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceRecord {
+    /// Name of the wrapper function.
+    pub function: String,
+    /// PCI address of the device, if the call targets a device.
+    pub device: Option<String>,
+    /// Arguments of the call, formatted with [`Debug`] (a tuple if there are several), or `None` if the
+    /// function has no arguments.
+    pub arguments: Option<String>,
+    /// Raw status returned by the library, or `None` if the error does not come from the library.
+    pub status: Option<u32>,
+    /// Kind of the error (see [`AmdError::kind`](crate::error::AmdError::kind)), or `None` if the call succeeded.
+    pub error: Option<String>,
+    /// Duration of the call.
+    pub duration: Duration,
+    /// Returned value, formatted with [`Debug`], or `None` if the call failed.
+    pub value: Option<String>,
+}
+
+impl TraceRecord {
+    /// Formats the record as a line of a capture, without the line feed.
+    pub fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            escape(&self.function),
+            escape(self.device.as_deref().unwrap_or_default()),
+            escape(self.arguments.as_deref().unwrap_or_default()),
+            self.status.map(|s| s.to_string()).unwrap_or_default(),
+            escape(self.error.as_deref().unwrap_or_default()),
+            self.duration.as_micros(),
+            escape(self.value.as_deref().unwrap_or_default()),
+        )
+    }
+
+    /// Parses a line of a capture. Returns `None` if the line is not a valid record.
+    ///
+    /// The lines without the `arguments` field, written by the previous versions of this crate, are accepted.
+    pub fn parse_line(line: &str) -> Option<Self> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let (function, device, arguments, status, error, duration, value) = match fields[..] {
+            [function, device, arguments, status, error, duration, value] => {
+                (function, device, arguments, status, error, duration, value)
+            }
+            [function, device, status, error, duration, value] => {
+                (function, device, "", status, error, duration, value)
+            }
+            _ => return None,
+        };
+        let optional = |field: &str| (!field.is_empty()).then(|| unescape(field));
+        Some(Self {
+            function: unescape(function),
+            device: optional(device),
+            arguments: optional(arguments),
+            status: match status {
+                "" => None,
+                status => Some(status.parse().ok()?),
+            },
+            error: optional(error),
+            duration: Duration::from_micros(duration.parse().ok()?),
+            value: optional(value),
+        })
+    }
+}
+
+fn escape(field: &str) -> String {
+    let mut result = String::with_capacity(field.len());
+    json::escape_into(&mut result, field);
+    result
+}
+
+fn unescape(field: &str) -> String {
+    let mut result = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => result.push('\t'),
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            Some('u') => {
+                let code: String = chars.by_ref().take(4).collect();
+                match u32::from_str_radix(&code, 16).ok().and_then(char::from_u32) {
+                    Some(c) => result.push(c),
+                    None => {
+                        result.push_str("\\u");
+                        result.push_str(&code);
+                    }
+                }
+            }
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+/// Capture in progress, shared by the clones of an `AmdSmi`.
+#[derive(Default)]
+pub(crate) struct TraceCapture {
+    active: AtomicBool,
+    file: Mutex<Option<LineWriter<File>>>,
+}
+
+impl TraceCapture {
+    pub(crate) fn is_active(&self) -> bool {
+        self.active.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn start(&self, path: &Path) -> io::Result<()> {
+        let mut file = LineWriter::new(File::create(path)?);
+        writeln!(file, "{HEADER}")?;
+        let mut current = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(mut previous) = current.replace(file) {
+            previous.flush()?;
+        }
+        self.active.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    pub(crate) fn stop(&self) -> io::Result<()> {
+        self.active.store(false, Ordering::Relaxed);
+        let mut current = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        match current.take() {
+            Some(mut file) => file.flush(),
+            None => Ok(()),
+        }
+    }
+
+    /// Writes a record. The write errors are ignored: the capture must not change the result of the calls.
+    pub(crate) fn record(&self, record: &TraceRecord) {
+        let mut current = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(file) = current.as_mut() {
+            let _ = writeln!(file, "{}", record.to_line());
+        }
+    }
+}
+
+/// Capture loaded from a file, see the [module documentation](self).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TraceReplay {
+    records: Vec<TraceRecord>,
+}
+
+impl TraceReplay {
+    /// Loads the capture written to the file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Parses the content of a capture.
+    ///
+    /// Returns an error of kind [`InvalidData`](io::ErrorKind::InvalidData) if a line is not a valid record.
+    pub fn parse(capture: &str) -> io::Result<Self> {
+        let mut records = Vec::new();
+        for (i, line) in capture.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let record = TraceRecord::parse_line(line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid record at line {}", i + 1),
+                )
+            })?;
+            records.push(record);
+        }
+        Ok(Self { records })
+    }
+
+    /// Returns the calls of the capture, in order.
+    pub fn records(&self) -> &[TraceRecord] {
+        &self.records
+    }
+
+    /// Returns the PCI addresses of the devices that appear in the capture, in order of appearance.
+    pub fn devices(&self) -> Vec<&str> {
+        let mut devices: Vec<&str> = Vec::new();
+        for device in self.records.iter().filter_map(|r| r.device.as_deref()) {
+            if !devices.contains(&device) {
+                devices.push(device);
+            }
+        }
+        devices
+    }
+
+    /// Returns a mock handle that replays the calls made to the device at the PCI address `device`.
+    ///
+    /// Each replayed method returns the results captured for the same arguments in order (or the results
+    /// captured without arguments, by the previous versions of this crate), then fails with `NO_DATA` when
+    /// they are exhausted. The methods that do not appear in the capture are not configured: calling them
+    /// panics, like any unexpected call to a mock.
+    #[cfg(feature = "mock")]
+    pub fn mock_device(&self, device: &str) -> MockProcessorHandle {
+        let mut calls: HashMap<&str, ReplayQueues> = HashMap::new();
+        for record in self
+            .records
+            .iter()
+            .filter(|r| r.device.as_deref() == Some(device))
+        {
+            calls
+                .entry(record.function.as_str())
+                .or_default()
+                .entry(record.arguments.clone())
+                .or_default()
+                .push_back(record.clone());
+        }
+
+        let mut mock = MockProcessorHandle::new();
+        // formats the arguments like the wrappers: the value of a single argument, a tuple of several ones
+        macro_rules! arguments {
+            () => {
+                None
+            };
+            ($($arg:ident),+) => {
+                Some(format!("{:?}", ($($arg),+)))
+            };
+        }
+        macro_rules! replay {
+            ($($function:ident($($arg:ident),*) => $expect:ident),* $(,)?) => {
+                $(
+                    if let Some(mut queues) = calls.remove(stringify!($function)) {
+                        mock.$expect()
+                            .returning(move |$($arg),*| replay_next(&mut queues, arguments!($($arg),*)));
+                    }
+                )*
+            };
+        }
+        replay!(
+            device_temperature(sensor_type, metric) => expect_device_temperature,
+            device_voltage(sensor_type, metric) => expect_device_voltage,
+            device_fan_speed(sensor_index) => expect_device_fan_speed,
+            device_fan_rpms(sensor_index) => expect_device_fan_rpms,
+            device_fan_speed_max(sensor_index) => expect_device_fan_speed_max,
+            device_memory_usage(mem_type) => expect_device_memory_usage,
+            device_memory_total(mem_type) => expect_device_memory_total,
+            device_numa_node() => expect_device_numa_node,
+            device_power_management_enabled() => expect_device_power_management_enabled,
+            device_validate_ras_eeprom() => expect_device_validate_ras_eeprom,
+            device_vendor_name() => expect_device_vendor_name,
+            device_vram_vendor() => expect_device_vram_vendor,
+            device_subsystem_name() => expect_device_subsystem_name,
+            device_compute_partition_name() => expect_device_compute_partition_name,
+            device_memory_partition_name() => expect_device_memory_partition_name,
+            device_activity() => expect_device_activity,
+            device_power_consumption() => expect_device_power_consumption,
+            device_energy_consumption() => expect_device_energy_consumption,
+            device_power_cap_info(sensor_index) => expect_device_power_cap_info,
+            device_clock_info(clk_type) => expect_device_clock_info,
+            device_pci_usage() => expect_device_pci_usage,
+            device_gpu_metrics() => expect_device_gpu_metrics,
+        );
+        mock
+    }
+}
+
+/// Captured results of a function, by arguments.
+#[cfg(feature = "mock")]
+type ReplayQueues = HashMap<Option<String>, VecDeque<TraceRecord>>;
+
+/// Returns the next result of a replayed method called with `arguments`.
+#[cfg(feature = "mock")]
+fn replay_next<T: Replay>(
+    queues: &mut ReplayQueues,
+    arguments: Option<String>,
+) -> Result<T, AmdError> {
+    // the captures of the previous versions have no arguments
+    let key = if queues.contains_key(&arguments) {
+        arguments
+    } else {
+        None
+    };
+    let Some(record) = queues.get_mut(&key).and_then(VecDeque::pop_front) else {
+        return Err(AmdError::Status {
+            status: AmdStatus::AMDSMI_STATUS_NO_DATA,
+            message: Some(String::from("no more calls in the capture")),
+        });
+    };
+    match &record.value {
+        Some(value) => DebugValue::parse(value)
+            .as_ref()
+            .and_then(T::replay)
+            .ok_or_else(|| AmdError::Status {
+                status: AmdStatus::AMDSMI_STATUS_UNEXPECTED_DATA,
+                message: Some(format!(
+                    "cannot replay the value {value} of {}",
+                    record.function
+                )),
+            }),
+        None => match record.status {
+            Some(status) => Err(AmdError::Status {
+                status: amdsmi_status_t(status),
+                message: None,
+            }),
+            None if record.error.as_deref() == Some("device_suspended") => {
+                Err(AmdError::DeviceSuspended)
+            }
+            None => Err(AmdError::Status {
+                status: AmdStatus::AMDSMI_STATUS_UNKNOWN_ERROR,
+                message: Some(format!(
+                    "replayed error {} that does not come from the library",
+                    record.error.as_deref().unwrap_or("unknown")
+                )),
+            }),
+        },
+    }
+}
+
+/// Value formatted with [`Debug`], parsed to be replayed.
+#[cfg(feature = "mock")]
+#[derive(Debug, Clone, PartialEq)]
+enum DebugValue {
+    /// Number, boolean or identifier (`None`, variant of an enum...).
+    Scalar(String),
+    /// String, unescaped.
+    Str(String),
+    /// Fields of a struct `Name { field: value, .. }`, without the name of the struct.
+    Struct(Vec<(String, DebugValue)>),
+    /// Items of a list `[a, b]`, of a tuple `(a, b)` or of a tuple struct `Name(a, b)`.
+    List(Vec<DebugValue>),
+}
+
+#[cfg(feature = "mock")]
+impl DebugValue {
+    /// Parses a value formatted with `{:?}`. Returns `None` if the text is not a complete value.
+    fn parse(text: &str) -> Option<Self> {
+        let mut parser = DebugParser {
+            chars: text.chars().peekable(),
+        };
+        let value = parser.value()?;
+        parser.skip_spaces();
+        parser.chars.next().is_none().then_some(value)
+    }
+
+    /// Returns the value of a field of a struct.
+    fn field(&self, name: &str) -> Option<&DebugValue> {
+        match self {
+            DebugValue::Struct(fields) => fields.iter().find(|(n, _)| n == name).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "mock")]
+struct DebugParser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+#[cfg(feature = "mock")]
+impl DebugParser<'_> {
+    fn skip_spaces(&mut self) {
+        while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+
+    fn value(&mut self) -> Option<DebugValue> {
+        self.skip_spaces();
+        match *self.chars.peek()? {
+            '"' => {
+                self.chars.next();
+                self.string().map(DebugValue::Str)
+            }
+            '[' => {
+                self.chars.next();
+                self.items(']').map(DebugValue::List)
+            }
+            '(' => {
+                self.chars.next();
+                self.items(')').map(DebugValue::List)
+            }
+            _ => {
+                let mut token = String::new();
+                while let Some(c) = self
+                    .chars
+                    .next_if(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | '-' | '+'))
+                {
+                    token.push(c);
+                }
+                if token.is_empty() {
+                    return None;
+                }
+                self.skip_spaces();
+                if self.chars.next_if_eq(&'{').is_some() {
+                    self.fields().map(DebugValue::Struct)
+                } else if self.chars.next_if_eq(&'(').is_some() {
+                    self.items(')').map(DebugValue::List)
+                } else {
+                    Some(DebugValue::Scalar(token))
+                }
+            }
+        }
+    }
+
+    /// Parses the items of a list, after its opening delimiter.
+    fn items(&mut self, end: char) -> Option<Vec<DebugValue>> {
+        let mut items = Vec::new();
+        loop {
+            self.skip_spaces();
+            if self.chars.next_if_eq(&end).is_some() {
+                return Some(items);
+            }
+            items.push(self.value()?);
+            self.skip_spaces();
+            if self.chars.next_if_eq(&',').is_none() {
+                self.skip_spaces();
+                return self.chars.next_if_eq(&end).map(|_| items);
+            }
+        }
+    }
+
+    /// Parses the fields of a struct, after its opening brace.
+    fn fields(&mut self) -> Option<Vec<(String, DebugValue)>> {
+        let mut fields = Vec::new();
+        loop {
+            self.skip_spaces();
+            if self.chars.next_if_eq(&'}').is_some() {
+                return Some(fields);
+            }
+            // non-exhaustive struct
+            if self.chars.next_if_eq(&'.').is_some() {
+                self.chars.next_if_eq(&'.')?;
+                continue;
+            }
+            let mut name = String::new();
+            while let Some(c) = self.chars.next_if(|c| c.is_alphanumeric() || *c == '_') {
+                name.push(c);
+            }
+            self.skip_spaces();
+            self.chars.next_if_eq(&':')?;
+            fields.push((name, self.value()?));
+            self.skip_spaces();
+            if self.chars.next_if_eq(&',').is_none() {
+                self.skip_spaces();
+                return self.chars.next_if_eq(&'}').map(|_| fields);
+            }
+        }
+    }
+
+    /// Parses a string after its opening quote, with the escapes of `Debug`.
+    fn string(&mut self) -> Option<String> {
+        let mut result = String::new();
+        loop {
+            match self.chars.next()? {
+                '"' => return Some(result),
+                '\\' => match self.chars.next()? {
+                    'n' => result.push('\n'),
+                    't' => result.push('\t'),
+                    'r' => result.push('\r'),
+                    '0' => result.push('\0'),
+                    'u' => {
+                        self.chars.next_if_eq(&'{')?;
+                        let mut code = String::new();
+                        while let Some(c) = self.chars.next_if(|c| *c != '}') {
+                            code.push(c);
+                        }
+                        self.chars.next_if_eq(&'}')?;
+                        result.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+                    }
+                    other => result.push(other),
+                },
+                c => result.push(c),
+            }
+        }
+    }
+}
+
+/// Type whose values can be rebuilt from their [`Debug`] representation, to be replayed.
+#[cfg(feature = "mock")]
+trait Replay: Sized {
+    fn replay(value: &DebugValue) -> Option<Self>;
+}
+
+#[cfg(feature = "mock")]
+macro_rules! replay_scalar {
+    ($($ty:ty),*) => {
+        $(
+            impl Replay for $ty {
+                fn replay(value: &DebugValue) -> Option<Self> {
+                    match value {
+                        DebugValue::Scalar(scalar) => scalar.parse().ok(),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "mock")]
+replay_scalar!(u8, u16, u32, u64, i32, i64, f32, f64, bool);
+
+#[cfg(feature = "mock")]
+impl Replay for String {
+    fn replay(value: &DebugValue) -> Option<Self> {
+        match value {
+            DebugValue::Str(string) => Some(string.clone()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "mock")]
+impl<T: Replay, const N: usize> Replay for [T; N] {
+    fn replay(value: &DebugValue) -> Option<Self> {
+        match value {
+            DebugValue::List(items) => items
+                .iter()
+                .map(T::replay)
+                .collect::<Option<Vec<T>>>()?
+                .try_into()
+                .ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Implements [`Replay`] for structs, by replaying each of their fields. All the fields must be listed.
+#[cfg(feature = "mock")]
+macro_rules! replay_struct {
+    ($($ty:ty => { $($field:ident),* $(,)? })*) => {
+        $(
+            impl Replay for $ty {
+                fn replay(value: &DebugValue) -> Option<Self> {
+                    Some(Self {
+                        $($field: Replay::replay(value.field(stringify!($field))?)?,)*
+                    })
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "mock")]
+replay_struct! {
+    AmdEngineUsage => { gfx_activity, mm_activity, umc_activity }
+    AmdPowerConsumption => {
+        socket_power, current_socket_power, average_socket_power, gfx_voltage, soc_voltage, mem_voltage,
+        power_limit,
+    }
+    AmdEnergyConsumption => { energy, resolution, timestamp }
+    AmdPowerCapInfo => {
+        power_cap, default_power_cap, dpm_cap, min_power_cap, max_power_cap,
+    }
+    AmdClkInfo => { clk, min_clk, max_clk, clk_locked, clk_deep_sleep }
+    AmdPciTraffic => { sent, received, max_pkt_sz }
+    amd_metrics_table_header_t => {
+        structure_size, format_revision, content_revision,
+    }
+    amdsmi_gpu_xcp_metrics_t => {
+        gfx_busy_inst, jpeg_busy, vcn_busy, gfx_busy_acc, gfx_below_host_limit_acc,
+        gfx_below_host_limit_ppt_acc, gfx_below_host_limit_thm_acc, gfx_low_utilization_acc,
+        gfx_below_host_limit_total_acc,
+    }
+    amdsmi_gpu_metrics_t => {
+        common_header, temperature_edge, temperature_hotspot, temperature_mem, temperature_vrgfx,
+        temperature_vrsoc, temperature_vrmem, average_gfx_activity, average_umc_activity, average_mm_activity,
+        average_socket_power, energy_accumulator, system_clock_counter, average_gfxclk_frequency,
+        average_socclk_frequency, average_uclk_frequency, average_vclk0_frequency, average_dclk0_frequency,
+        average_vclk1_frequency, average_dclk1_frequency, current_gfxclk, current_socclk, current_uclk,
+        current_vclk0, current_dclk0, current_vclk1, current_dclk1, throttle_status, current_fan_speed,
+        pcie_link_width, pcie_link_speed, gfx_activity_acc, mem_activity_acc, temperature_hbm,
+        firmware_timestamp, voltage_soc, voltage_gfx, voltage_mem, indep_throttle_status, current_socket_power,
+        vcn_activity, gfxclk_lock_status, xgmi_link_width, xgmi_link_speed, pcie_bandwidth_acc,
+        pcie_bandwidth_inst, pcie_l0_to_recov_count_acc, pcie_replay_count_acc, pcie_replay_rover_count_acc,
+        xgmi_read_data_acc, xgmi_write_data_acc, current_gfxclks, current_socclks, current_vclk0s,
+        current_dclk0s, jpeg_activity, pcie_nak_sent_count_acc, pcie_nak_rcvd_count_acc, accumulation_counter,
+        prochot_residency_acc, ppt_residency_acc, socket_thm_residency_acc, vr_thm_residency_acc,
+        hbm_thm_residency_acc, num_partition, xcp_stats, pcie_lc_perf_other_end_recovery, vram_max_bandwidth,
+        xgmi_link_status,
+    }
+}
+
+#[cfg(feature = "mock")]
+impl Replay for AmdGpuMetrics {
+    fn replay(value: &DebugValue) -> Option<Self> {
+        let raw = amdsmi_gpu_metrics_t::replay(value.field("raw")?)?;
+        Some(AmdGpuMetrics::new(Box::new(raw)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{TraceRecord, TraceReplay};
+
+    #[test]
+    fn records() {
+        let record = TraceRecord {
+            function: String::from("device_vendor_name"),
+            device: Some(String::from("0000:03:00.0")),
+            arguments: None,
+            status: Some(0),
+            error: None,
+            duration: Duration::from_micros(42),
+            value: Some(String::from("\"Advanced\\tMicro\"\r\u{1}")),
+        };
+        let failed = TraceRecord {
+            function: String::from("socket_count"),
+            device: None,
+            arguments: None,
+            status: Some(2),
+            error: Some(String::from("status")),
+            duration: Duration::from_micros(3),
+            value: None,
+        };
+        let suspended = TraceRecord {
+            function: String::from("device_activity"),
+            device: Some(String::from("0000:03:00.0")),
+            arguments: None,
+            status: None,
+            error: Some(String::from("device_suspended")),
+            duration: Duration::from_micros(1),
+            value: None,
+        };
+        let fan = TraceRecord {
+            function: String::from("device_fan_speed"),
+            device: Some(String::from("0000:03:00.0")),
+            arguments: Some(String::from("1")),
+            status: Some(0),
+            error: None,
+            duration: Duration::from_micros(7),
+            value: Some(String::from("30")),
+        };
+        let capture = format!(
+            "# amd-smi-wrapper trace capture\n{}\n{}\n{}\n{}\n",
+            record.to_line(),
+            failed.to_line(),
+            suspended.to_line(),
+            fan.to_line()
+        );
+        let replay = TraceReplay::parse(&capture).unwrap();
+        assert_eq!(replay.records(), [record, failed, suspended, fan]);
+        assert_eq!(replay.devices(), ["0000:03:00.0"]);
+        assert!(TraceReplay::parse("device_uuid\t\t0").is_err());
+
+        // line of a previous version, without the arguments
+        let old = TraceRecord::parse_line("device_fan_speed\t0000:03:00.0\t0\t\t7\t30").unwrap();
+        assert_eq!(old.arguments, None);
+        assert_eq!(old.value.as_deref(), Some("30"));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn mock_device() {
+        use crate::{
+            bindings::amdsmi_gpu_metrics_t,
+            gpu_metrics::AmdGpuMetrics,
+            handles::ProcessorHandle,
+            metrics::{AmdPowerConsumption, AmdTemperatureMetric, AmdTemperatureType},
+        };
+
+        let edge = AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_EDGE;
+        let hotspot = AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_HOTSPOT;
+        let current = AmdTemperatureMetric::AMDSMI_TEMP_CURRENT;
+        let record =
+            |function: &str, arguments: Option<String>, status: u32, value: Option<String>| {
+                TraceRecord {
+                    function: function.to_owned(),
+                    device: Some(String::from("0000:03:00.0")),
+                    arguments,
+                    status: Some(status),
+                    error: (status != 0).then(|| String::from("status")),
+                    duration: Duration::from_micros(10),
+                    value,
+                }
+                .to_line()
+            };
+        let power = AmdPowerConsumption {
+            socket_power: 250,
+            current_socket_power: 248,
+            power_limit: 300,
+            ..Default::default()
+        };
+        // SAFETY: the table only contains integers, for which any bit pattern is valid.
+        let mut table: Box<amdsmi_gpu_metrics_t> = Box::new(unsafe { std::mem::zeroed() });
+        table.temperature_hotspot = 71;
+        table.current_gfxclks[1] = 2100;
+        table.xcp_stats[2].vcn_busy[3] = 17;
+        let metrics = AmdGpuMetrics::new(table);
+        let capture = [
+            record(
+                "device_temperature",
+                Some(format!("{:?}", (edge, current))),
+                0,
+                Some(String::from("45")),
+            ),
+            record(
+                "device_temperature",
+                Some(format!("{:?}", (hotspot, current))),
+                0,
+                Some(String::from("70")),
+            ),
+            record(
+                "device_temperature",
+                Some(format!("{:?}", (edge, current))),
+                2,
+                None,
+            ),
+            record(
+                "device_vendor_name",
+                None,
+                0,
+                Some(String::from("\"AMD \\\"x\\\"\"")),
+            ),
+            record(
+                "device_power_consumption",
+                None,
+                0,
+                Some(format!("{power:?}")),
+            ),
+            record("device_gpu_metrics", None, 0, Some(format!("{metrics:?}"))),
+        ]
+        .join("\n");
+        let mock = TraceReplay::parse(&capture)
+            .unwrap()
+            .mock_device("0000:03:00.0");
+        // the results are replayed by arguments
+        assert_eq!(mock.device_temperature(hotspot, current).unwrap(), 70);
+        assert_eq!(mock.device_temperature(edge, current).unwrap(), 45);
+        assert!(mock.device_temperature(edge, current).is_err());
+        assert!(mock.device_temperature(edge, current).is_err());
+        assert_eq!(mock.device_vendor_name().unwrap(), "AMD \"x\"");
+
+        let replayed = mock.device_power_consumption().unwrap();
+        assert_eq!(replayed.current_socket_power, 248);
+        assert_eq!(replayed.power_limit, 300);
+        let replayed = mock.device_gpu_metrics().unwrap();
+        assert_eq!(replayed.raw().temperature_hotspot, 71);
+        assert_eq!(
+            replayed.raw().current_gfxclks,
+            metrics.raw().current_gfxclks
+        );
+        assert_eq!(replayed.raw().xcp_stats[2].vcn_busy[3], 17);
+    }
+}
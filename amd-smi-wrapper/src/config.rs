@@ -0,0 +1,199 @@
+//! Comparison of the configurations of the devices, for fleet-consistency audits.
+//!
+//! A [`DeviceConfig`] gathers the settings that should be identical on the devices of a fleet: versions of
+//! the driver, VBIOS and firmwares, power cap, partition modes and clock limits. Two configurations are
+//! compared with [`DeviceConfig::diff`], for instance each device against the first one, or against a
+//! golden configuration.
+//!
+//! # Example
+//! ```no_run
+//! use amd_smi_wrapper::{AmdSmi, AmdInitFlags, config::DeviceConfig};
+//!
+//! let amdsmi = AmdSmi::init(AmdInitFlags::AMDSMI_INIT_AMD_GPUS).unwrap();
+//! let configs: Vec<_> = amdsmi
+//!     .iter_processors()
+//!     .unwrap()
+//!     .map(|device| DeviceConfig::collect(&device.unwrap()).unwrap())
+//!     .collect();
+//! for (i, config) in configs.iter().enumerate().skip(1) {
+//!     for difference in configs[0].diff(config) {
+//!         println!("device {i}: {difference}");
+//!     }
+//! }
+//! ```
+
+use std::{collections::BTreeMap, fmt};
+
+use crate::{
+    error::{AmdError, ResultExt},
+    handles::ProcessorHandle,
+    metrics::{ALL_CLK_TYPES, AmdFirmwareInfo, AmdPerfLevel},
+    naming::clock_name,
+};
+
+/// Settings of a device that are compared between devices.
+///
+/// The settings that are not available on the device are `None` (or empty).
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DeviceConfig {
+    /// Model name of the GPU.
+    pub market_name: Option<String>,
+    /// Version of the kernel driver.
+    pub driver_version: Option<String>,
+    /// Version of the VBIOS.
+    pub vbios_version: Option<String>,
+    /// Versions of the firmware blocks.
+    pub firmwares: Vec<AmdFirmwareInfo>,
+    /// Power cap of the first power sensor, in µW.
+    pub power_cap: Option<u64>,
+    /// Performance level.
+    pub perf_level: Option<AmdPerfLevel>,
+    /// Compute partition mode.
+    pub compute_partition: Option<String>,
+    /// Memory partition mode.
+    pub memory_partition: Option<String>,
+    /// Minimal and maximal frequencies of each clock domain, in MHz, by name (see [`clock_name`]).
+    pub clock_limits: BTreeMap<String, (u32, u32)>,
+}
+
+impl DeviceConfig {
+    /// Reads the configuration of a device.
+    pub fn collect<P: ProcessorHandle + ?Sized>(device: &P) -> Result<Self, AmdError> {
+        let mut clock_limits = BTreeMap::new();
+        for &clk_type in ALL_CLK_TYPES {
+            if let Some(info) = device.device_clock_info(clk_type).optional()? {
+                clock_limits.insert(clock_name(clk_type), (info.min_clk, info.max_clk));
+            }
+        }
+        Ok(Self {
+            market_name: device.device_asic_info().optional()?.map(|a| a.market_name),
+            driver_version: device.device_driver_info().optional()?.map(|d| d.version),
+            vbios_version: device.device_vbios_info().optional()?.map(|v| v.version),
+            firmwares: device.device_firmwares().optional()?.unwrap_or_default(),
+            power_cap: device
+                .device_power_cap_info(0)
+                .optional()?
+                .map(|c| c.power_cap),
+            perf_level: device.device_perf_level().optional()?,
+            compute_partition: device.device_compute_partition_name().optional()?,
+            memory_partition: device.device_memory_partition_name().optional()?,
+            clock_limits,
+        })
+    }
+
+    /// Returns the settings as `(key, value)` pairs, ordered by key.
+    ///
+    /// The keys are stable: `driver_version`, `firmware[<block>]`, `clock_limits[<clock>]`...
+    /// The settings that are not available are omitted.
+    pub fn entries(&self) -> BTreeMap<String, String> {
+        let mut entries = BTreeMap::new();
+        for (key, value) in [
+            ("market_name", &self.market_name),
+            ("driver_version", &self.driver_version),
+            ("vbios_version", &self.vbios_version),
+            ("compute_partition", &self.compute_partition),
+            ("memory_partition", &self.memory_partition),
+        ] {
+            if let Some(value) = value {
+                entries.insert(key.to_owned(), value.clone());
+            }
+        }
+        for fw in &self.firmwares {
+            entries.insert(format!("firmware[{}]", fw.block.0), fw.version.to_string());
+        }
+        if let Some(cap) = self.power_cap {
+            entries.insert(String::from("power_cap"), cap.to_string());
+        }
+        if let Some(level) = self.perf_level {
+            entries.insert(String::from("perf_level"), level.0.to_string());
+        }
+        for (clock, (min, max)) in &self.clock_limits {
+            entries.insert(format!("clock_limits[{clock}]"), format!("{min}-{max}"));
+        }
+        entries
+    }
+
+    /// Compares this configuration (the reference) with another one.
+    ///
+    /// Returns the settings that differ, ordered by key, including the settings that are available
+    /// on only one of the devices.
+    pub fn diff(&self, other: &DeviceConfig) -> Vec<ConfigDifference> {
+        let (left, mut right) = (self.entries(), other.entries());
+        let mut differences = Vec::new();
+        for (key, value) in left {
+            match right.remove(&key) {
+                Some(other) if other == value => (),
+                other => differences.push(ConfigDifference {
+                    key,
+                    reference: Some(value),
+                    other,
+                }),
+            }
+        }
+        differences.extend(right.into_iter().map(|(key, value)| ConfigDifference {
+            key,
+            reference: None,
+            other: Some(value),
+        }));
+        differences.sort_by(|a, b| a.key.cmp(&b.key));
+        differences
+    }
+}
+
+/// Setting that differs between two [`DeviceConfig`]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigDifference {
+    /// Key of the setting, see [`DeviceConfig::entries`].
+    pub key: String,
+    /// Value in the reference configuration, or `None` if it is not available.
+    pub reference: Option<String>,
+    /// Value in the other configuration, or `None` if it is not available.
+    pub other: Option<String>,
+}
+
+impl fmt::Display for ConfigDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = |v: &Option<String>| v.clone().unwrap_or_else(|| String::from("n/a"));
+        write!(
+            f,
+            "{}: {} != {}",
+            self.key,
+            value(&self.reference),
+            value(&self.other)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DeviceConfig;
+
+    #[test]
+    fn diff() {
+        let reference = DeviceConfig {
+            driver_version: Some(String::from("6.10.5")),
+            power_cap: Some(750_000_000),
+            clock_limits: [(String::from("gfx"), (500, 2100))].into(),
+            ..Default::default()
+        };
+        let mut other = reference.clone();
+        assert!(reference.diff(&other).is_empty());
+
+        other.power_cap = Some(600_000_000);
+        other.driver_version = None;
+        other.compute_partition = Some(String::from("SPX"));
+        let differences: Vec<_> = reference
+            .diff(&other)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            differences,
+            [
+                "compute_partition: n/a != SPX",
+                "driver_version: 6.10.5 != n/a",
+                "power_cap: 750000000 != 600000000",
+            ]
+        );
+    }
+}
@@ -0,0 +1,48 @@
+//! Limits of the library, with Rust types.
+//!
+//! The header of the library defines these constants as `u32`. Here, the sizes of the buffers and arrays are
+//! `usize`, so that they can size a Rust buffer or bound an index without a cast.
+
+use crate::bindings;
+
+/// Size of the buffers of strings (names, versions, serial numbers...), including the terminating NUL.
+pub const MAX_STRING_LENGTH: usize = bindings::AMDSMI_MAX_STRING_LENGTH as usize;
+
+/// Size of the buffer of a GPU UUID, including the terminating NUL.
+pub const GPU_UUID_SIZE: usize = bindings::AMDSMI_GPU_UUID_SIZE as usize;
+
+/// Value of the raw fan speed when the fan runs at its maximal speed.
+pub const MAX_FAN_SPEED: u32 = bindings::AMDSMI_MAX_FAN_SPEED;
+
+/// Maximal number of devices handled by the library.
+pub const MAX_DEVICES: usize = bindings::AMDSMI_MAX_DEVICES as usize;
+
+/// Maximal number of frequency levels of a clock domain.
+pub const MAX_NUM_FREQUENCIES: usize = bindings::AMDSMI_MAX_NUM_FREQUENCIES as usize;
+
+/// Maximal number of power management policies of a device.
+pub const MAX_NUM_PM_POLICIES: usize = bindings::AMDSMI_MAX_NUM_PM_POLICIES as usize;
+
+/// Maximal number of video encode/decode engines (VCN) in the GPU metrics.
+pub const MAX_NUM_VCN: usize = bindings::AMDSMI_MAX_NUM_VCN as usize;
+
+/// Maximal number of JPEG engines in the GPU metrics.
+pub const MAX_NUM_JPEG: usize = bindings::AMDSMI_MAX_NUM_JPEG as usize;
+
+/// Maximal number of compute dies (XCC) in the GPU metrics.
+pub const MAX_NUM_XCC: usize = bindings::AMDSMI_MAX_NUM_XCC as usize;
+
+/// Maximal number of partitions (XCP) in the GPU metrics.
+pub const MAX_NUM_XCP: usize = bindings::AMDSMI_MAX_NUM_XCP as usize;
+
+/// Maximal number of XGMI links in the GPU metrics.
+pub const MAX_NUM_XGMI_LINKS: usize = bindings::AMDSMI_MAX_NUM_XGMI_LINKS as usize;
+
+/// Version `(major, minor, release)` of the header that the bindings have been generated from.
+///
+/// The loaded library may be more recent, see [`AmdSmi::lib_version`](crate::AmdSmi::lib_version).
+pub const BINDINGS_VERSION: (u32, u32, u32) = (
+    bindings::AMDSMI_LIB_VERSION_MAJOR,
+    bindings::AMDSMI_LIB_VERSION_MINOR,
+    bindings::AMDSMI_LIB_VERSION_RELEASE,
+);
@@ -0,0 +1,595 @@
+//! Control of the devices: power cap, performance level...
+//!
+//! This module requires the feature `control`. Without it, the crate does not contain any function that
+//! changes the settings of the devices.
+//!
+//! The settings apply to the device for all its users, and changing them usually requires root access.
+//! To restore the original settings at the end of an experiment, even if it fails or panics,
+//! use the guards [`PowerCapGuard`], [`PerfLevelGuard`] and [`ClockLevelsGuard`], or [`BenchmarkMode`](crate::benchmark::BenchmarkMode)
+//! to pin the clocks.
+//!
+//! While several copies of the library are loaded in the process, the control is refused unless it has
+//! been allowed with [`AmdSmiBuilder::shared_control`](crate::builder::AmdSmiBuilder::shared_control).
+//!
+//! With the feature `mock`, `MockControlledDevice` implements both [`ProcessorHandle`] and [`ProcessorControl`],
+//! to test the code that uses the guards.
+
+use crate::{
+    consts::MAX_FAN_SPEED,
+    error::{AmdError, ResultExt},
+    handles::{AmdProcessorHandle, ProcessorHandle},
+    metrics::{AmdClkType, AmdClockRange, AmdPerfLevel, AmdPowerCapInfo},
+    smi_call,
+};
+
+#[cfg(feature = "mock")]
+use mockall::automock;
+
+#[cfg(feature = "mock")]
+pub use mock::MockControlledDevice;
+
+/// Changes the settings of a processor.
+#[cfg_attr(feature = "mock", automock)]
+pub trait ProcessorControl {
+    /// Sets the power cap of a power sensor of the GPU device, in µW.
+    ///
+    /// The cap must be between the minimal and maximal caps given by
+    /// [`device_power_cap_info`](ProcessorHandle::device_power_cap_info), otherwise [`AmdError::OutOfRange`]
+    /// is returned without calling the library.
+    ///
+    /// # Arguments
+    ///
+    /// - `sensor_index`: 0-based index of the power sensor, usually 0.
+    /// - `cap`: new power cap, in µW.
+    fn set_power_cap(&self, sensor_index: u32, cap: u64) -> Result<(), AmdError>;
+
+    /// Sets the performance level of the GPU device, which controls how the clocks are chosen.
+    ///
+    /// The level `UNKNOWN` is refused with [`AmdError::InvalidArgument`].
+    fn set_perf_level(&self, level: AmdPerfLevel) -> Result<(), AmdError>;
+
+    /// Restricts the frequency levels that a clock domain of the GPU device can use.
+    ///
+    /// Bit `i` of `mask` allows the level `i` of [`device_clock_frequencies`](ProcessorHandle::device_clock_frequencies).
+    /// The performance level is switched to `MANUAL`: set it back to `AUTO` to let the driver choose the levels again,
+    /// for instance with a [`PerfLevelGuard`].
+    ///
+    /// The mask must allow at least one existing level, otherwise [`AmdError::InvalidArgument`] is returned.
+    fn set_clock_levels(&self, clk_type: AmdClkType, mask: u64) -> Result<(), AmdError>;
+
+    /// Restricts the frequency of the graphics (`SYS`) or memory (`MEM`) clock of the GPU device to a range,
+    /// in MHz, through the overdrive settings of the driver.
+    ///
+    /// The applied ranges are returned by [`device_clock_limits`](ProcessorHandle::device_clock_limits).
+    /// The range must be within the limits given by this function, otherwise [`AmdError::OutOfRange`] is returned.
+    fn set_clock_range(&self, clk_type: AmdClkType, range: AmdClockRange) -> Result<(), AmdError>;
+
+    /// Enables the performance determinism mode of the GPU device: the graphics clock is capped to `clock`,
+    /// in MHz, so that the same workload runs at the same speed on all the GPUs.
+    ///
+    /// The performance level is switched to `DETERMINISM`: set it back to `AUTO` to disable the mode.
+    fn set_determinism_mode(&self, clock: u64) -> Result<(), AmdError>;
+
+    /// Sets the speed of a fan of the GPU device, relative to [`MAX_FAN_SPEED`]. The fan is switched to the
+    /// manual mode (see [`FanControlMode`](crate::fan_mode::FanControlMode)): call
+    /// [`reset_fan`](ProcessorControl::reset_fan) to give the control back to the firmware.
+    ///
+    /// The speed must not exceed [`device_fan_speed_max`](ProcessorHandle::device_fan_speed_max),
+    /// otherwise [`AmdError::OutOfRange`] is returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `sensor_index`: 0-based index of the fan sensor, usually 0.
+    /// - `speed`: new fan speed.
+    fn set_fan_speed(&self, sensor_index: u32, speed: u64) -> Result<(), AmdError>;
+
+    /// Gives the control of a fan of the GPU device back to the firmware (automatic mode).
+    fn reset_fan(&self, sensor_index: u32) -> Result<(), AmdError>;
+}
+
+impl ProcessorControl for AmdProcessorHandle {
+    fn set_power_cap(&self, sensor_index: u32, cap: u64) -> Result<(), AmdError> {
+        self.amdsmi.check_control()?;
+        if let Some(info) = self.device_power_cap_info(sensor_index).optional()? {
+            check_power_cap(&info, cap)?;
+        }
+        self.call_raw_with_args(
+            "set_power_cap",
+            Some(&(sensor_index, cap)),
+            |raw, handle| {
+                // SAFETY: The function only reads its arguments.
+                unsafe { smi_call!(raw, amdsmi_set_power_cap(handle, sensor_index, cap)) }
+            },
+        )
+    }
+
+    fn set_perf_level(&self, level: AmdPerfLevel) -> Result<(), AmdError> {
+        self.amdsmi.check_control()?;
+        check_perf_level(level)?;
+        self.call_raw_with_args("set_perf_level", Some(&level), |raw, handle| {
+            // SAFETY: The function only reads its arguments.
+            unsafe { smi_call!(raw, amdsmi_set_gpu_perf_level(handle, level)) }
+        })
+    }
+
+    fn set_clock_levels(&self, clk_type: AmdClkType, mask: u64) -> Result<(), AmdError> {
+        self.amdsmi.check_control()?;
+        if let Some(frequencies) = self.device_clock_frequencies(clk_type).optional()? {
+            let levels = frequencies.frequencies.len() as u32;
+            let existing = 1u64.checked_shl(levels).map_or(u64::MAX, |bit| bit - 1);
+            if mask & existing == 0 {
+                return Err(AmdError::InvalidArgument(format!(
+                    "the mask {mask:#x} allows none of the {levels} frequency levels"
+                )));
+            }
+        }
+        self.call_raw_with_args(
+            "set_clock_levels",
+            Some(&(clk_type, mask)),
+            |raw, handle| {
+                // SAFETY: The function only reads its arguments. The bits above the number of levels are ignored.
+                unsafe { smi_call!(raw, amdsmi_set_clk_freq(handle, clk_type, mask)) }
+            },
+        )
+    }
+
+    fn set_clock_range(&self, clk_type: AmdClkType, range: AmdClockRange) -> Result<(), AmdError> {
+        self.amdsmi.check_control()?;
+        if range.min > range.max {
+            return Err(AmdError::InvalidArgument(format!(
+                "invalid clock range {}-{} MHz",
+                range.min, range.max
+            )));
+        }
+        let limits = self.device_clock_limits().optional()?.and_then(|limits| {
+            if clk_type == AmdClkType::AMDSMI_CLK_TYPE_SYS {
+                Some(limits.sclk_limits)
+            } else if clk_type == AmdClkType::AMDSMI_CLK_TYPE_MEM {
+                Some(limits.mclk_limits)
+            } else {
+                None
+            }
+        });
+        if let Some(limits) = limits {
+            check_range("minimal clock", "MHz", range.min, limits.min, limits.max)?;
+            check_range("maximal clock", "MHz", range.max, limits.min, limits.max)?;
+        }
+        self.call_raw_with_args(
+            "set_clock_range",
+            Some(&(clk_type, range)),
+            |raw, handle| {
+                // SAFETY: The function only reads its arguments.
+                unsafe {
+                    smi_call!(
+                        raw,
+                        amdsmi_set_gpu_clk_range(handle, range.min, range.max, clk_type)
+                    )
+                }
+            },
+        )
+    }
+
+    fn set_determinism_mode(&self, clock: u64) -> Result<(), AmdError> {
+        self.amdsmi.check_control()?;
+        self.call_raw_with_args("set_determinism_mode", Some(&clock), |raw, handle| {
+            // SAFETY: The function only reads its arguments.
+            unsafe { smi_call!(raw, amdsmi_set_gpu_perf_determinism_mode(handle, clock)) }
+        })
+    }
+
+    fn set_fan_speed(&self, sensor_index: u32, speed: u64) -> Result<(), AmdError> {
+        self.amdsmi.check_control()?;
+        let max = self
+            .device_fan_speed_max(sensor_index)
+            .optional()?
+            .unwrap_or(MAX_FAN_SPEED as u64);
+        check_range("fan speed", "", speed, 0, max)?;
+        self.call_raw_with_args(
+            "set_fan_speed",
+            Some(&(sensor_index, speed)),
+            |raw, handle| {
+                // SAFETY: The function only reads its arguments.
+                unsafe { smi_call!(raw, amdsmi_set_gpu_fan_speed(handle, sensor_index, speed)) }
+            },
+        )
+    }
+
+    fn reset_fan(&self, sensor_index: u32) -> Result<(), AmdError> {
+        self.amdsmi.check_control()?;
+        self.call_raw_with_args("reset_fan", Some(&sensor_index), |raw, handle| {
+            // SAFETY: The function only reads its arguments.
+            unsafe { smi_call!(raw, amdsmi_reset_gpu_fan(handle, sensor_index)) }
+        })
+    }
+}
+
+/// Checks that a power cap is within the range of the power sensor.
+fn check_power_cap(info: &AmdPowerCapInfo, cap: u64) -> Result<(), AmdError> {
+    check_range(
+        "power cap",
+        "µW",
+        cap,
+        info.min_power_cap,
+        info.max_power_cap,
+    )
+}
+
+/// Checks that a performance level can be set.
+fn check_perf_level(level: AmdPerfLevel) -> Result<(), AmdError> {
+    if level == AmdPerfLevel::AMDSMI_DEV_PERF_LEVEL_UNKNOWN {
+        return Err(AmdError::InvalidArgument(String::from(
+            "the performance level UNKNOWN cannot be set",
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that a setting is within the range allowed by the device. A range whose maximum is zero
+/// is unknown, and is not checked.
+fn check_range(
+    what: &'static str,
+    unit: &'static str,
+    value: u64,
+    min: u64,
+    max: u64,
+) -> Result<(), AmdError> {
+    if max == 0 || (min..=max).contains(&value) {
+        Ok(())
+    } else {
+        Err(AmdError::OutOfRange {
+            what,
+            unit,
+            value,
+            min,
+            max,
+        })
+    }
+}
+
+/// Sets the power cap of a GPU, and restores the original cap when dropped.
+///
+/// The errors that happen when the guard is dropped are ignored. To handle them, call [`restore`](PowerCapGuard::restore).
+///
+/// # Example
+/// ```no_run
+/// use amd_smi_wrapper::{AmdSmi, AmdInitFlags, control::PowerCapGuard};
+///
+/// let amdsmi = AmdSmi::init(AmdInitFlags::AMDSMI_INIT_AMD_GPUS).unwrap();
+/// let device = amdsmi.iter_processors().unwrap().next().unwrap().unwrap();
+/// {
+///     let _cap = PowerCapGuard::set(&device, 0, 150_000_000).expect("could not cap the power");
+///     // ... run the experiment at 150 W ...
+/// } // the original cap is restored here
+/// ```
+pub struct PowerCapGuard<'a, P: ProcessorHandle + ProcessorControl> {
+    device: &'a P,
+    sensor_index: u32,
+    original: u64,
+    restored: bool,
+}
+
+impl<'a, P: ProcessorHandle + ProcessorControl> PowerCapGuard<'a, P> {
+    /// Sets the power cap of the power sensor `sensor_index` to `cap`, in µW.
+    ///
+    /// The cap is checked against the range of the sensor before it is set.
+    pub fn set(device: &'a P, sensor_index: u32, cap: u64) -> Result<Self, AmdError> {
+        let info = device.device_power_cap_info(sensor_index)?;
+        check_power_cap(&info, cap)?;
+        let original = info.power_cap;
+        device.set_power_cap(sensor_index, cap)?;
+        Ok(Self {
+            device,
+            sensor_index,
+            original,
+            restored: false,
+        })
+    }
+
+    /// Returns the power cap that will be restored, in µW.
+    pub fn original(&self) -> u64 {
+        self.original
+    }
+
+    /// Restores the original power cap now.
+    pub fn restore(mut self) -> Result<(), AmdError> {
+        self.restored = true;
+        self.device.set_power_cap(self.sensor_index, self.original)
+    }
+}
+
+impl<P: ProcessorHandle + ProcessorControl> Drop for PowerCapGuard<'_, P> {
+    fn drop(&mut self) {
+        if !self.restored {
+            let _ = self.device.set_power_cap(self.sensor_index, self.original);
+        }
+    }
+}
+
+/// Sets the performance level of a GPU, and restores the original level when dropped.
+///
+/// The errors that happen when the guard is dropped are ignored. To handle them, call [`restore`](PerfLevelGuard::restore).
+pub struct PerfLevelGuard<'a, P: ProcessorHandle + ProcessorControl> {
+    device: &'a P,
+    original: AmdPerfLevel,
+    restored: bool,
+}
+
+impl<'a, P: ProcessorHandle + ProcessorControl> PerfLevelGuard<'a, P> {
+    /// Sets the performance level to `level`.
+    pub fn set(device: &'a P, level: AmdPerfLevel) -> Result<Self, AmdError> {
+        check_perf_level(level)?;
+        let original = device.device_perf_level()?;
+        device.set_perf_level(level)?;
+        Ok(Self {
+            device,
+            original,
+            restored: false,
+        })
+    }
+
+    /// Returns the performance level that will be restored.
+    pub fn original(&self) -> AmdPerfLevel {
+        self.original
+    }
+
+    /// Restores the original performance level now.
+    pub fn restore(mut self) -> Result<(), AmdError> {
+        self.restored = true;
+        self.device.set_perf_level(self.original)
+    }
+}
+
+impl<P: ProcessorHandle + ProcessorControl> Drop for PerfLevelGuard<'_, P> {
+    fn drop(&mut self) {
+        if !self.restored {
+            let _ = self.device.set_perf_level(self.original);
+        }
+    }
+}
+
+/// Restricts the frequency levels of a clock domain of a GPU, and allows all the levels again when dropped.
+///
+/// The library does not report which levels are allowed, hence the original restriction cannot be restored:
+/// all the levels of [`device_clock_frequencies`](ProcessorHandle::device_clock_frequencies) are allowed again.
+/// Restricting the levels switches the performance level to `MANUAL`: create a [`PerfLevelGuard`] before this
+/// guard, so that it is dropped after it and restores the performance level too.
+///
+/// The errors that happen when the guard is dropped are ignored. To handle them, call [`restore`](ClockLevelsGuard::restore).
+pub struct ClockLevelsGuard<'a, P: ProcessorHandle + ProcessorControl> {
+    device: &'a P,
+    clk_type: AmdClkType,
+    /// Mask of all the levels of the clock domain.
+    all_levels: u64,
+    restored: bool,
+}
+
+impl<'a, P: ProcessorHandle + ProcessorControl> ClockLevelsGuard<'a, P> {
+    /// Restricts the levels of the clock domain `clk_type` to `mask`, see [`ProcessorControl::set_clock_levels`].
+    pub fn set(device: &'a P, clk_type: AmdClkType, mask: u64) -> Result<Self, AmdError> {
+        let levels = device.device_clock_frequencies(clk_type)?.frequencies.len() as u32;
+        let all_levels = 1u64.checked_shl(levels).map_or(u64::MAX, |bit| bit - 1);
+        device.set_clock_levels(clk_type, mask)?;
+        Ok(Self {
+            device,
+            clk_type,
+            all_levels,
+            restored: false,
+        })
+    }
+
+    /// Restricts the levels to another `mask`, keeping the guard.
+    pub fn change(&self, mask: u64) -> Result<(), AmdError> {
+        self.device.set_clock_levels(self.clk_type, mask)
+    }
+
+    /// Allows all the levels again now.
+    pub fn restore(mut self) -> Result<(), AmdError> {
+        self.restored = true;
+        self.device.set_clock_levels(self.clk_type, self.all_levels)
+    }
+}
+
+impl<P: ProcessorHandle + ProcessorControl> Drop for ClockLevelsGuard<'_, P> {
+    fn drop(&mut self) {
+        if !self.restored {
+            let _ = self.device.set_clock_levels(self.clk_type, self.all_levels);
+        }
+    }
+}
+
+/// Mock of a device that implements both [`ProcessorHandle`] and [`ProcessorControl`], to test the code
+/// that reads and changes the settings, such as the guards of this module.
+#[cfg(feature = "mock")]
+mod mock {
+    use mockall::mock;
+
+    use super::ProcessorControl;
+    use crate::{
+        counters::PerfCounter, error::AmdError, gpu_metrics::AmdGpuMetrics,
+        handles::ProcessorHandle, interconnect::AmdP2pCapability, metrics::*,
+    };
+
+    mock! {
+        /// Device that implements both [`ProcessorHandle`] and [`ProcessorControl`].
+        pub ControlledDevice {}
+
+        impl ProcessorHandle for ControlledDevice {
+            fn device_activity(&self) -> Result<AmdEngineUsage, AmdError>;
+            fn processor_type(&self) -> Result<AmdProcessorType, AmdError>;
+            fn device_asic_info(&self) -> Result<AmdAsicInfo, AmdError>;
+            fn device_clock_info(&self, clk_type: AmdClkType) -> Result<AmdClkInfo, AmdError>;
+            fn device_clock_frequencies(&self, clk_type: AmdClkType) -> Result<AmdFrequencies, AmdError>;
+            fn device_cper_entries(&self, severity_mask: u32, cursor: u64) -> Result<AmdCperEntries, AmdError>;
+            fn device_energy_consumption(&self) -> Result<AmdEnergyConsumption, AmdError>;
+            fn device_fan_speed(&self, sensor_index: u32) -> Result<u32, AmdError>;
+            fn device_fan_rpms(&self, sensor_index: u32) -> Result<i64, AmdError>;
+            fn device_fan_speed_max(&self, sensor_index: u32) -> Result<u64, AmdError>;
+            fn device_memory_usage(&self, mem_type: AmdMemoryType) -> Result<u64, AmdError>;
+            fn device_memory_total(&self, mem_type: AmdMemoryType) -> Result<u64, AmdError>;
+            fn device_metrics_header(&self) -> Result<AmdMetricsHeader, AmdError>;
+            fn device_ecc_count(&self) -> Result<AmdEccCount, AmdError>;
+            fn device_block_ecc_count(&self, block: AmdGpuBlock) -> Result<AmdEccCount, AmdError>;
+            fn device_ras_features(&self) -> Result<AmdRasFeatures, AmdError>;
+            fn device_ras_block_state(&self, block: AmdGpuBlock) -> Result<AmdRasErrState, AmdError>;
+            fn device_retired_pages(&self) -> Result<Vec<AmdRetiredPage>, AmdError>;
+            fn device_pci_usage(&self) -> Result<AmdPciTraffic, AmdError>;
+            fn device_pcie_info(&self) -> Result<AmdPcieInfo, AmdError>;
+            fn device_power_profile_status(&self) -> Result<AmdPowerProfileStatus, AmdError>;
+            fn device_power_consumption(&self) -> Result<AmdPowerConsumption, AmdError>;
+            fn device_perf_level(&self) -> Result<AmdPerfLevel, AmdError>;
+            fn device_clock_limits(&self) -> Result<AmdClockLimits, AmdError>;
+            fn device_power_cap_info(&self, sensor_index: u32) -> Result<AmdPowerCapInfo, AmdError>;
+            fn device_power_management_enabled(&self) -> Result<bool, AmdError>;
+            fn device_soc_pstate(&self) -> Result<AmdDpmPolicy, AmdError>;
+            fn device_xgmi_plpd(&self) -> Result<AmdDpmPolicy, AmdError>;
+            fn device_process_list(&self) -> Result<Vec<AmdProcess>, AmdError>;
+            fn device_process_list_into(&self, processes: &mut Vec<AmdProcess>) -> Result<(), AmdError>;
+            fn device_validate_ras_eeprom(&self) -> Result<bool, AmdError>;
+            fn device_temperature(&self, sensor_type: AmdTemperatureType, metric: AmdTemperatureMetric) -> Result<i64, AmdError>;
+            fn device_is_alive(&self) -> bool;
+            fn device_supported_temperature_sensors(&self, metric: AmdTemperatureMetric) -> Result<Vec<AmdTemperatureType>, AmdError>;
+            fn device_uuid(&self) -> Result<String, AmdError>;
+            fn device_uuid_bytes(&self) -> Result<Vec<u8>, AmdError>;
+            fn device_vendor_name(&self) -> Result<String, AmdError>;
+            fn device_vram_vendor(&self) -> Result<String, AmdError>;
+            fn device_subsystem_name(&self) -> Result<String, AmdError>;
+            fn device_board_info(&self) -> Result<AmdBoardInfo, AmdError>;
+            fn device_driver_info(&self) -> Result<AmdDriverInfo, AmdError>;
+            fn device_firmwares(&self) -> Result<Vec<AmdFirmwareInfo>, AmdError>;
+            fn device_compute_partition_name(&self) -> Result<String, AmdError>;
+            fn device_memory_partition_name(&self) -> Result<String, AmdError>;
+            fn lib_version(&self) -> Result<AmdVersion, AmdError>;
+            fn device_gpu_metrics(&self) -> Result<AmdGpuMetrics, AmdError>;
+            fn device_busy_time(&self) -> Result<AmdBusyTime, AmdError>;
+            fn device_memory_busy_time(&self) -> Result<AmdBusyTime, AmdError>;
+            fn device_pci_address(&self) -> Result<AmdPciAddress, AmdError>;
+            fn device_identity(&self) -> Result<AmdDeviceIdentity, AmdError>;
+            fn device_enumeration_info(&self) -> Result<AmdEnumerationInfo, AmdError>;
+            fn device_kfd_info(&self) -> Result<AmdKfdInfo, AmdError>;
+            fn device_vbios_info(&self) -> Result<AmdVbiosInfo, AmdError>;
+            fn device_xgmi_info(&self) -> Result<AmdXgmiInfo, AmdError>;
+            fn device_link_metrics(&self) -> Result<Vec<AmdLinkMetrics>, AmdError>;
+            fn device_link_type(&self, dst: &Self) -> Result<(u64, AmdLinkType), AmdError>;
+            fn device_link_weight(&self, dst: &Self) -> Result<u64, AmdError>;
+            fn device_p2p_accessible(&self, dst: &Self) -> Result<bool, AmdError>;
+            fn device_p2p_status(&self, dst: &Self) -> Result<(AmdLinkType, AmdP2pCapability), AmdError>;
+            fn device_xgmi_error_status(&self) -> Result<AmdXgmiStatus, AmdError>;
+            fn device_xgmi_link_status(&self) -> Result<Vec<AmdXgmiLinkStatus>, AmdError>;
+            fn device_numa_node(&self) -> Result<u32, AmdError>;
+            fn device_counter_group_supported(&self, group: AmdEventGroup) -> Result<bool, AmdError>;
+            fn device_available_counters(&self, group: AmdEventGroup) -> Result<u32, AmdError>;
+            fn device_create_counter(&self, event: AmdEventType) -> Result<PerfCounter, AmdError>;
+            fn device_voltage(&self, sensor_type: AmdVoltageType, metric: AmdVoltageMetric) -> Result<i64, AmdError>;
+            fn device_supported_voltage_sensors(&self, metric: AmdVoltageMetric) -> Result<Vec<AmdVoltageType>, AmdError>;
+        }
+
+        impl ProcessorControl for ControlledDevice {
+            fn set_power_cap(&self, sensor_index: u32, cap: u64) -> Result<(), AmdError>;
+            fn set_perf_level(&self, level: AmdPerfLevel) -> Result<(), AmdError>;
+            fn set_clock_levels(&self, clk_type: AmdClkType, mask: u64) -> Result<(), AmdError>;
+            fn set_clock_range(&self, clk_type: AmdClkType, range: AmdClockRange) -> Result<(), AmdError>;
+            fn set_determinism_mode(&self, clock: u64) -> Result<(), AmdError>;
+            fn set_fan_speed(&self, sensor_index: u32, speed: u64) -> Result<(), AmdError>;
+            fn reset_fan(&self, sensor_index: u32) -> Result<(), AmdError>;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_range;
+    use crate::{error::AmdError, metrics::AmdPerfLevel};
+
+    #[test]
+    fn range() {
+        assert!(check_range("power cap", "µW", 150, 100, 300).is_ok());
+        assert!(check_range("power cap", "µW", 150, 0, 0).is_ok());
+        let err = check_range("power cap", "µW", 350, 100, 300).unwrap_err();
+        assert!(matches!(err, AmdError::OutOfRange { value: 350, .. }));
+        assert_eq!(err.status(), None);
+        assert!(
+            err.to_string()
+                .contains("the power cap 350µW is not between 100µW and 300µW")
+        );
+    }
+
+    #[cfg(feature = "mock")]
+    fn mock_device() -> super::MockControlledDevice {
+        let mut device = super::MockControlledDevice::new();
+        device.expect_device_power_cap_info().returning(|_| {
+            Ok(crate::metrics::AmdPowerCapInfo {
+                power_cap: 200,
+                min_power_cap: 100,
+                max_power_cap: 300,
+                ..Default::default()
+            })
+        });
+        device
+            .expect_device_perf_level()
+            .returning(|| Ok(AmdPerfLevel::AMDSMI_DEV_PERF_LEVEL_AUTO));
+        device
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn guards_restore() {
+        use super::{PerfLevelGuard, PowerCapGuard};
+        use mockall::{Sequence, predicate::eq};
+
+        let mut device = mock_device();
+        // the guards are dropped in the reverse order
+        let mut seq = Sequence::new();
+        device
+            .expect_set_power_cap()
+            .with(eq(0), eq(150))
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _| Ok(()));
+        for level in [
+            AmdPerfLevel::AMDSMI_DEV_PERF_LEVEL_STABLE_STD,
+            AmdPerfLevel::AMDSMI_DEV_PERF_LEVEL_AUTO,
+        ] {
+            device
+                .expect_set_perf_level()
+                .with(eq(level))
+                .times(1)
+                .in_sequence(&mut seq)
+                .returning(|_| Ok(()));
+        }
+        device
+            .expect_set_power_cap()
+            .with(eq(0), eq(200))
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _| Ok(()));
+        {
+            let cap = PowerCapGuard::set(&device, 0, 150).unwrap();
+            assert_eq!(cap.original(), 200);
+            let level =
+                PerfLevelGuard::set(&device, AmdPerfLevel::AMDSMI_DEV_PERF_LEVEL_STABLE_STD)
+                    .unwrap();
+            assert_eq!(level.original(), AmdPerfLevel::AMDSMI_DEV_PERF_LEVEL_AUTO);
+        }
+        device.checkpoint();
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn guards_validate() {
+        use super::{PerfLevelGuard, PowerCapGuard};
+
+        let mut device = mock_device();
+        device.expect_set_power_cap().never();
+        device.expect_set_perf_level().never();
+        let Err(err) = PowerCapGuard::set(&device, 0, 350) else {
+            panic!("the power cap should be refused");
+        };
+        assert!(matches!(err, AmdError::OutOfRange { value: 350, .. }));
+        let Err(err) = PerfLevelGuard::set(&device, AmdPerfLevel::AMDSMI_DEV_PERF_LEVEL_UNKNOWN)
+        else {
+            panic!("the performance level should be refused");
+        };
+        assert!(matches!(err, AmdError::InvalidArgument(_)));
+        device.checkpoint();
+    }
+}
@@ -0,0 +1,373 @@
+//! Performance counters of the GPUs, and the XGMI throughput measured with them.
+//!
+//! A [`PerfCounter`] counts the occurrences of a hardware event (see [`AmdEventType`]) since its start.
+//! The library only returns the cumulative value of the counter: [`PerfCounter::read`] keeps the previous value
+//! and returns the events counted since the previous read. The counters are a limited resource of the device,
+//! and creating them usually requires root access: they are destroyed when the [`PerfCounter`] is dropped.
+//!
+//! The XGMI links are measured by the `XGMI_DATA_OUT` group, which counts the beats of 32 bytes sent on each
+//! link. [`XgmiThroughputMonitor`] manages these counters and converts them to bytes/s, and
+//! [`ProcessorHandle::device_xgmi_throughput`] measures the throughput over a given interval.
+//!
+//! # Example
+//! ```no_run
+//! use std::time::Duration;
+//! use amd_smi_wrapper::{AmdSmi, AmdInitFlags, handles::ProcessorHandle};
+//!
+//! let amdsmi = AmdSmi::init(AmdInitFlags::AMDSMI_INIT_AMD_GPUS).unwrap();
+//! let device = amdsmi.iter_processors().unwrap().next().unwrap().unwrap();
+//! for link in device.device_xgmi_throughput(Duration::from_secs(1)).unwrap() {
+//!     println!("link {}: {:.0} B/s", link.link, link.bytes_per_second);
+//! }
+//! ```
+
+use std::{cell::Cell, ptr::null_mut};
+
+use crate::{
+    AmdSmi,
+    bindings::{self, amdsmi_counter_command_t, amdsmi_counter_value_t, amdsmi_event_handle_t},
+    error::{AmdError, AmdStatus, ResultExt},
+    handles::{AmdProcessorHandle, ProcessorHandle},
+    lib_call,
+    metrics::{AmdEventGroup, AmdEventType},
+    smi_call,
+};
+
+/// Number of bytes represented by a beat of the XGMI counters.
+pub const XGMI_BEAT_BYTES: u64 = 32;
+
+/// Maximal number of XGMI links of a GPU measured by the `XGMI_DATA_OUT` group.
+pub const XGMI_MAX_LINKS: u32 = 6;
+
+type AmdCounterCommand = amdsmi_counter_command_t;
+
+/// Value of a performance counter.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AmdCounterValue {
+    /// Number of events counted since the start of the counter, or since the previous read.
+    pub value: u64,
+    /// Time during which the counter was enabled, in ns.
+    pub time_enabled: u64,
+    /// Time during which the counter was running, in ns.
+    pub time_running: u64,
+}
+
+impl From<amdsmi_counter_value_t> for AmdCounterValue {
+    fn from(value: amdsmi_counter_value_t) -> Self {
+        Self {
+            value: value.value,
+            time_enabled: value.time_enabled,
+            time_running: value.time_running,
+        }
+    }
+}
+
+impl AmdCounterValue {
+    /// Returns the events and the times counted between a `previous` cumulative value and this one.
+    pub fn since(&self, previous: &AmdCounterValue) -> AmdCounterValue {
+        AmdCounterValue {
+            value: self.value.saturating_sub(previous.value),
+            time_enabled: self.time_enabled.saturating_sub(previous.time_enabled),
+            time_running: self.time_running.saturating_sub(previous.time_running),
+        }
+    }
+}
+
+/// Performance counter of a GPU, destroyed when dropped.
+pub struct PerfCounter {
+    amdsmi: AmdSmi,
+    handle: amdsmi_event_handle_t,
+    event: AmdEventType,
+    previous: Cell<AmdCounterValue>,
+}
+
+impl PerfCounter {
+    /// Returns the event counted by this counter.
+    pub fn event(&self) -> AmdEventType {
+        self.event
+    }
+
+    /// Starts counting.
+    pub fn start(&self) -> Result<(), AmdError> {
+        self.control("start_counter", AmdCounterCommand::AMDSMI_CNTR_CMD_START)
+    }
+
+    /// Stops counting. The counter should be read before being stopped.
+    pub fn stop(&self) -> Result<(), AmdError> {
+        self.control("stop_counter", AmdCounterCommand::AMDSMI_CNTR_CMD_STOP)
+    }
+
+    /// Reads the counter, and returns the events counted since the previous read, or since the start
+    /// for the first read.
+    pub fn read(&self) -> Result<AmdCounterValue, AmdError> {
+        let total = self.read_total()?;
+        Ok(total.since(&self.previous.replace(total)))
+    }
+
+    /// Reads the cumulative value of the counter, since its start. The counter is not reset.
+    pub fn read_total(&self) -> Result<AmdCounterValue, AmdError> {
+        self.amdsmi.call_raw("read_counter", |raw| {
+            // SAFETY: The handle is valid until the counter is dropped, and the function initializes the value on success.
+            let value = unsafe {
+                raw.read_struct::<amdsmi_counter_value_t>(|p| {
+                    lib_call!(raw.lib(), amdsmi_gpu_read_counter(self.handle, p))
+                })?
+            };
+            Ok(value.into())
+        })
+    }
+
+    fn control(&self, function: &'static str, command: AmdCounterCommand) -> Result<(), AmdError> {
+        self.amdsmi.call_raw(function, |raw| {
+            // SAFETY: The handle is valid until the counter is dropped, and the commands take no argument.
+            unsafe {
+                smi_call!(
+                    raw,
+                    amdsmi_gpu_control_counter(self.handle, command, null_mut())
+                )
+            }
+        })
+    }
+}
+
+impl Drop for PerfCounter {
+    fn drop(&mut self) {
+        let _ = self.amdsmi.call_raw("destroy_counter", |raw| {
+            // SAFETY: The handle has been created by the library, and is not used after this call.
+            unsafe { smi_call!(raw, amdsmi_gpu_destroy_counter(self.handle)) }
+        });
+    }
+}
+
+/// Implementation of [`ProcessorHandle::device_counter_group_supported`].
+pub(crate) fn counter_group_supported(
+    device: &AmdProcessorHandle,
+    group: AmdEventGroup,
+) -> Result<bool, AmdError> {
+    device
+        .call_raw_with_args(
+            "device_counter_group_supported",
+            Some(&group),
+            |raw, handle| {
+                // SAFETY: The function only reads its arguments.
+                unsafe { smi_call!(raw, amdsmi_gpu_counter_group_supported(handle, group)) }
+            },
+        )
+        .optional()
+        .map(|supported| supported.is_some())
+}
+
+/// Implementation of [`ProcessorHandle::device_available_counters`].
+pub(crate) fn available_counters(
+    device: &AmdProcessorHandle,
+    group: AmdEventGroup,
+) -> Result<u32, AmdError> {
+    device.call_raw_with_args("device_available_counters", Some(&group), |raw, handle| {
+        raw.read_scalar(|available| {
+            // SAFETY: The pointer is valid for the duration of the call.
+            unsafe {
+                lib_call!(
+                    raw.lib(),
+                    amdsmi_get_gpu_available_counters(handle, group, available)
+                )
+            }
+        })
+    })
+}
+
+/// Implementation of [`ProcessorHandle::device_create_counter`].
+pub(crate) fn create_counter(
+    device: &AmdProcessorHandle,
+    event: AmdEventType,
+) -> Result<PerfCounter, AmdError> {
+    let handle =
+        device.call_raw_with_args("device_create_counter", Some(&event), |raw, handle| {
+            raw.read_scalar(|counter| {
+                // SAFETY: The pointer is valid for the duration of the call.
+                unsafe { lib_call!(raw.lib(), amdsmi_gpu_create_counter(handle, event, counter)) }
+            })
+        })?;
+    Ok(PerfCounter {
+        amdsmi: device.amdsmi.clone(),
+        handle,
+        event,
+        previous: Cell::default(),
+    })
+}
+
+/// Outbound throughput of an XGMI link.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XgmiThroughput {
+    /// Index of the link.
+    pub link: u32,
+    /// Bytes sent on the link during the measurement.
+    pub bytes: u64,
+    /// Duration of the measurement, in ns.
+    pub time_running: u64,
+    /// Average throughput during the measurement, in bytes/s. Zero if the counter did not run.
+    pub bytes_per_second: f64,
+}
+
+impl XgmiThroughput {
+    /// Computes the throughput of a link from the value of its `XGMI_DATA_OUT` counter during the measurement.
+    ///
+    /// Returns `None` if the number of bytes does not fit in a `u64`.
+    pub fn from_counter(link: u32, value: AmdCounterValue) -> Option<Self> {
+        let bytes = value.value.checked_mul(XGMI_BEAT_BYTES)?;
+        let bytes_per_second = if value.time_running == 0 {
+            0.0
+        } else {
+            bytes as f64 / value.time_running as f64 * 1e9
+        };
+        Some(Self {
+            link,
+            bytes,
+            time_running: value.time_running,
+            bytes_per_second,
+        })
+    }
+}
+
+/// Counters of the outbound data of the XGMI links of a GPU, started at the creation of the monitor.
+///
+/// Each [`read`](XgmiThroughputMonitor::read) returns the throughput since the previous read.
+pub struct XgmiThroughputMonitor {
+    counters: Vec<(u32, PerfCounter)>,
+}
+
+impl XgmiThroughputMonitor {
+    /// Creates and starts a counter for each XGMI link of the device.
+    ///
+    /// The links whose counter cannot be created are skipped. Fails with `NOT_SUPPORTED` if the device
+    /// does not support the `XGMI_DATA_OUT` group, or if no counter can be created.
+    pub fn new<P: ProcessorHandle + ?Sized>(device: &P) -> Result<Self, AmdError> {
+        let not_supported = |message: &str| AmdError::Status {
+            status: AmdStatus::AMDSMI_STATUS_NOT_SUPPORTED,
+            message: Some(String::from(message)),
+        };
+        if !device.device_counter_group_supported(AmdEventGroup::AMDSMI_EVNT_GRP_XGMI_DATA_OUT)? {
+            return Err(not_supported("the device has no XGMI data counters"));
+        }
+        let mut counters = Vec::new();
+        for link in 0..XGMI_MAX_LINKS {
+            let event = bindings::amdsmi_event_type_t(
+                AmdEventType::AMDSMI_EVNT_XGMI_DATA_OUT_FIRST.0 + link,
+            );
+            if let Some(counter) = device.device_create_counter(event).optional()? {
+                counter.start()?;
+                counters.push((link, counter));
+            }
+        }
+        if counters.is_empty() {
+            return Err(not_supported("no XGMI data counter could be created"));
+        }
+        Ok(Self { counters })
+    }
+
+    /// Returns the indices of the measured links.
+    pub fn links(&self) -> impl Iterator<Item = u32> + '_ {
+        self.counters.iter().map(|(link, _)| *link)
+    }
+
+    /// Reads the counters, and returns the throughput of each link since the previous read, or since the
+    /// creation of the monitor for the first read.
+    pub fn read(&self) -> Result<Vec<XgmiThroughput>, AmdError> {
+        self.counters
+            .iter()
+            .map(|(link, counter)| {
+                let value = counter.read()?;
+                XgmiThroughput::from_counter(*link, value).ok_or_else(|| {
+                    AmdError::InvalidData(format!(
+                        "the XGMI counter of link {link} reported {} beats, which overflows the byte count",
+                        value.value
+                    ))
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AmdCounterValue, XGMI_BEAT_BYTES, XgmiThroughput};
+
+    #[test]
+    fn delta() {
+        let first = AmdCounterValue {
+            value: 100,
+            time_enabled: 1_000,
+            time_running: 900,
+        };
+        let second = AmdCounterValue {
+            value: 250,
+            time_enabled: 3_000,
+            time_running: 2_900,
+        };
+        assert_eq!(first.since(&AmdCounterValue::default()), first);
+        assert_eq!(
+            second.since(&first),
+            AmdCounterValue {
+                value: 150,
+                time_enabled: 2_000,
+                time_running: 2_000,
+            }
+        );
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn monitor_unsupported() {
+        use super::XgmiThroughputMonitor;
+        use crate::{
+            error::{AmdError, AmdStatus},
+            handles::MockProcessorHandle,
+        };
+
+        let mut device = MockProcessorHandle::new();
+        device
+            .expect_device_counter_group_supported()
+            .returning(|_| Ok(true));
+        device
+            .expect_device_create_counter()
+            .times(super::XGMI_MAX_LINKS as usize)
+            .returning(|_| {
+                Err(AmdError::Status {
+                    status: AmdStatus::AMDSMI_STATUS_NOT_SUPPORTED,
+                    message: None,
+                })
+            });
+        let err = XgmiThroughputMonitor::new(&device).err().unwrap();
+        assert_eq!(err.status(), Some(AmdStatus::AMDSMI_STATUS_NOT_SUPPORTED));
+
+        let mut device = MockProcessorHandle::new();
+        device
+            .expect_device_counter_group_supported()
+            .returning(|_| Ok(false));
+        device.expect_device_create_counter().never();
+        assert!(XgmiThroughputMonitor::new(&device).is_err());
+    }
+
+    #[test]
+    fn throughput() {
+        let link = XgmiThroughput::from_counter(
+            2,
+            AmdCounterValue {
+                value: 1_000_000,
+                time_enabled: 500_000_000,
+                time_running: 500_000_000,
+            },
+        )
+        .unwrap();
+        assert_eq!(link.bytes, 32_000_000);
+        assert_eq!(link.bytes_per_second, 64_000_000.0);
+
+        let idle = XgmiThroughput::from_counter(0, AmdCounterValue::default()).unwrap();
+        assert_eq!(idle.bytes_per_second, 0.0);
+
+        let overflow = AmdCounterValue {
+            value: u64::MAX / XGMI_BEAT_BYTES + 1,
+            ..Default::default()
+        };
+        assert_eq!(XgmiThroughput::from_counter(0, overflow), None);
+    }
+}
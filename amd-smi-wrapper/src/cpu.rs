@@ -0,0 +1,468 @@
+//! Metrics of the AMD CPUs (EPYC), read through the HSMP interface of the processors.
+//!
+//! The CPUs are only discovered when the library is initialized with [`AmdInitFlags::AMDSMI_INIT_AMD_CPUS`],
+//! and if it has been built with the support of the CPUs (E-SMI). Each CPU socket is then a processor of type
+//! `AMD_CPU`, whose cores are listed by [`AmdSocketHandle::cpu_core_handles`]. The functions of
+//! [`CpuProcessorHandle`] apply either to a CPU socket or to a core, as indicated by their documentation:
+//! the library returns an error if they are called on the other kind of processor.
+//!
+//! The HSMP driver of the kernel (`amd_hsmp`) must be loaded.
+//!
+//! # Example
+//! ```no_run
+//! use amd_smi_wrapper::{AmdSmi, AmdInitFlags, cpu::CpuProcessorHandle, handles::ProcessorHandle};
+//! use amd_smi_wrapper::metrics::AmdProcessorType;
+//!
+//! let amdsmi = AmdSmi::init(AmdInitFlags::AMDSMI_INIT_AMD_CPUS).unwrap();
+//! for processor in amdsmi.iter_processors().unwrap() {
+//!     let processor = processor.unwrap();
+//!     if processor.processor_type().unwrap() == AmdProcessorType::AMDSMI_PROCESSOR_TYPE_AMD_CPU {
+//!         println!("socket power: {} mW", processor.socket_power().unwrap());
+//!     }
+//! }
+//! ```
+//!
+//! [`AmdInitFlags::AMDSMI_INIT_AMD_CPUS`]: crate::AmdInitFlags::AMDSMI_INIT_AMD_CPUS
+
+use std::ptr::null_mut;
+
+#[cfg(feature = "mock")]
+use mockall::automock;
+
+use crate::{
+    bindings::{
+        amdsmi_hsmp_driver_version_t, amdsmi_hsmp_metrics_table_t, amdsmi_smu_fw_version_t,
+    },
+    dynamic::MetricUnit,
+    error::AmdError,
+    ffi,
+    handles::{AmdProcessorHandle, AmdSocketHandle},
+    lib_call,
+    metrics::{AmdProcessorType, AmdVersion},
+    units::Quantity,
+};
+
+/// Processor that is an AMD CPU socket or core.
+#[cfg_attr(feature = "mock", automock)]
+pub trait CpuProcessorHandle {
+    /// Retrieves the power consumption of the CPU socket, in mW.
+    fn socket_power(&self) -> Result<u32, AmdError>;
+
+    /// Retrieves the power cap of the CPU socket, in mW.
+    fn socket_power_cap(&self) -> Result<u32, AmdError>;
+
+    /// Retrieves the maximal power cap that can be set on the CPU socket, in mW.
+    fn socket_power_cap_max(&self) -> Result<u32, AmdError>;
+
+    /// Retrieves the energy consumed by the CPU socket since the boot, in µJ.
+    fn socket_energy(&self) -> Result<u64, AmdError>;
+
+    /// Retrieves the temperature of the CPU socket, in m°C.
+    fn socket_temperature(&self) -> Result<u32, AmdError>;
+
+    /// Returns `true` if the CPU socket is currently throttled by the `PROCHOT` signal.
+    fn socket_prochot(&self) -> Result<bool, AmdError>;
+
+    /// Retrieves the maximal frequency of the cores of the CPU socket, in MHz.
+    fn cclk_limit(&self) -> Result<u32, AmdError>;
+
+    /// Retrieves the energy consumed by the CPU core since the boot, in µJ.
+    fn core_energy(&self) -> Result<u64, AmdError>;
+
+    /// Retrieves the boost limit of the CPU core, in MHz.
+    fn core_boost_limit(&self) -> Result<u32, AmdError>;
+
+    /// Retrieves the current frequency limit of the CPU core, in MHz.
+    fn core_frequency_limit(&self) -> Result<u32, AmdError>;
+
+    /// Retrieves the version of the HSMP driver of the kernel, through the CPU socket.
+    fn hsmp_driver_version(&self) -> Result<AmdVersion, AmdError>;
+
+    /// Retrieves the version of the firmware of the SMU (system management unit) of the CPU socket.
+    fn smu_firmware_version(&self) -> Result<AmdVersion, AmdError>;
+
+    /// Retrieves the version of the HSMP metrics table of the CPU socket.
+    fn hsmp_metrics_table_version(&self) -> Result<u32, AmdError>;
+
+    /// Retrieves the HSMP metrics table of the CPU socket.
+    ///
+    /// The table is only supported by the version 6 of the HSMP protocol (MI300A APUs).
+    fn hsmp_metrics(&self) -> Result<AmdHsmpMetrics, AmdError>;
+}
+
+/// Main values of the HSMP metrics table of a CPU socket: [`amdsmi_hsmp_metrics_table_t`].
+///
+/// The temperatures and the powers are reported by the firmware as fixed-point numbers with 10 fractional bits,
+/// use the methods to get them in °C and W. The accumulators are summed by the firmware at each update
+/// of the table (see `accumulation_counter`): divide the difference of two readings by the difference of
+/// their `accumulation_counter` to get the mean value in between.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AmdHsmpMetrics {
+    /// Incremented every time the accumulators of the table are updated.
+    pub accumulation_counter: u32,
+    /// Maximal temperature of the on-die sensors of the socket, in °C (Q10 fixed point).
+    pub max_socket_temperature: u32,
+    /// Maximal temperature of the voltage regulators, in °C (Q10 fixed point).
+    pub max_vr_temperature: u32,
+    /// Maximal temperature of the HBM stacks, in °C (Q10 fixed point).
+    pub max_hbm_temperature: u32,
+    /// Power limit enforced by the power throttling controller, in W (Q10 fixed point).
+    pub socket_power_limit: u32,
+    /// Maximal power limit that can be configured, in W (Q10 fixed point).
+    pub max_socket_power_limit: u32,
+    /// Power consumed by all the dies of the socket, in W (Q10 fixed point).
+    pub socket_power: u32,
+    /// Time of the energy accumulators, in units of 10 ns.
+    pub timestamp: u64,
+    /// Energy accumulator of all the dies of the socket, in the fixed-point format of the firmware.
+    pub socket_energy_acc: u64,
+    /// Frequency limit of the cores enforced by the controllers, in MHz.
+    pub cclk_frequency_limit: u32,
+    /// Frequency limit of the graphics engines enforced by the controllers, in MHz.
+    pub gfxclk_frequency_limit: u32,
+    /// Effective frequency of the data fabric, in MHz.
+    pub fclk_frequency: u32,
+    /// Effective frequency of the memory controller, in MHz.
+    pub uclk_frequency: u32,
+    /// Number of updates during which the `PROCHOT` signal was active.
+    pub prochot_residency_acc: u32,
+    /// Number of updates during which the power throttling controller was active.
+    pub ppt_residency_acc: u32,
+    /// Number of updates during which the thermal throttling controller of the socket was active.
+    pub socket_thm_residency_acc: u32,
+}
+
+/// Number of fractional bits of the Q10 fixed-point values of the HSMP metrics table.
+const Q10_FRACTION_BITS: i32 = 10;
+
+/// Decodes a Q10 fixed-point value of the HSMP metrics table.
+fn from_q10(value: u32) -> f64 {
+    value as f64 * 2f64.powi(-Q10_FRACTION_BITS)
+}
+
+impl AmdHsmpMetrics {
+    /// Returns the maximal temperature of the on-die sensors of the socket, in °C.
+    pub fn max_socket_temperature_celsius(&self) -> f64 {
+        from_q10(self.max_socket_temperature)
+    }
+
+    /// Returns the maximal temperature of the HBM stacks, in °C.
+    pub fn max_hbm_temperature_celsius(&self) -> f64 {
+        from_q10(self.max_hbm_temperature)
+    }
+
+    /// Returns the power consumed by the socket, in W.
+    pub fn socket_power_watts(&self) -> f64 {
+        from_q10(self.socket_power)
+    }
+
+    /// Returns the power limit enforced on the socket, in W.
+    pub fn socket_power_limit_watts(&self) -> f64 {
+        from_q10(self.socket_power_limit)
+    }
+}
+
+impl From<&amdsmi_hsmp_metrics_table_t> for AmdHsmpMetrics {
+    fn from(table: &amdsmi_hsmp_metrics_table_t) -> Self {
+        Self {
+            accumulation_counter: table.accumulation_counter,
+            max_socket_temperature: table.max_socket_temperature,
+            max_vr_temperature: table.max_vr_temperature,
+            max_hbm_temperature: table.max_hbm_temperature,
+            socket_power_limit: table.socket_power_limit,
+            max_socket_power_limit: table.max_socket_power_limit,
+            socket_power: table.socket_power,
+            timestamp: table.timestamp,
+            socket_energy_acc: table.socket_energy_acc,
+            cclk_frequency_limit: table.cclk_frequency_limit,
+            gfxclk_frequency_limit: table.gfxclk_frequency_limit,
+            fclk_frequency: table.fclk_frequency,
+            uclk_frequency: table.uclk_frequency,
+            prochot_residency_acc: table.prochot_residency_acc,
+            ppt_residency_acc: table.ppt_residency_acc,
+            socket_thm_residency_acc: table.socket_thm_residency_acc,
+        }
+    }
+}
+
+impl CpuProcessorHandle for AmdProcessorHandle {
+    fn socket_power(&self) -> Result<u32, AmdError> {
+        self.call_raw("socket_power", |raw, handle| {
+            raw.read_scalar(|power| {
+                // SAFETY: The pointer is valid for the duration of the call.
+                unsafe { lib_call!(raw.lib(), amdsmi_get_cpu_socket_power(handle, power)) }
+            })
+        })
+    }
+
+    fn socket_power_cap(&self) -> Result<u32, AmdError> {
+        self.call_raw("socket_power_cap", |raw, handle| {
+            raw.read_scalar(|cap| {
+                // SAFETY: The pointer is valid for the duration of the call.
+                unsafe { lib_call!(raw.lib(), amdsmi_get_cpu_socket_power_cap(handle, cap)) }
+            })
+        })
+    }
+
+    fn socket_power_cap_max(&self) -> Result<u32, AmdError> {
+        self.call_raw("socket_power_cap_max", |raw, handle| {
+            raw.read_scalar(|max| {
+                // SAFETY: The pointer is valid for the duration of the call.
+                unsafe { lib_call!(raw.lib(), amdsmi_get_cpu_socket_power_cap_max(handle, max)) }
+            })
+        })
+    }
+
+    fn socket_energy(&self) -> Result<u64, AmdError> {
+        self.call_raw("socket_energy", |raw, handle| {
+            raw.read_scalar(|energy| {
+                // SAFETY: The pointer is valid for the duration of the call.
+                unsafe { lib_call!(raw.lib(), amdsmi_get_cpu_socket_energy(handle, energy)) }
+            })
+        })
+    }
+
+    fn socket_temperature(&self) -> Result<u32, AmdError> {
+        self.call_raw("socket_temperature", |raw, handle| {
+            raw.read_scalar(|temperature| {
+                // SAFETY: The pointer is valid for the duration of the call.
+                unsafe {
+                    lib_call!(
+                        raw.lib(),
+                        amdsmi_get_cpu_socket_temperature(handle, temperature)
+                    )
+                }
+            })
+        })
+    }
+
+    fn socket_prochot(&self) -> Result<bool, AmdError> {
+        let status: u32 = self.call_raw("socket_prochot", |raw, handle| {
+            raw.read_scalar(|status| {
+                // SAFETY: The pointer is valid for the duration of the call.
+                unsafe { lib_call!(raw.lib(), amdsmi_get_cpu_prochot_status(handle, status)) }
+            })
+        })?;
+        Ok(status != 0)
+    }
+
+    fn cclk_limit(&self) -> Result<u32, AmdError> {
+        self.call_raw("cclk_limit", |raw, handle| {
+            raw.read_scalar(|limit| {
+                // SAFETY: The pointer is valid for the duration of the call.
+                unsafe { lib_call!(raw.lib(), amdsmi_get_cpu_cclk_limit(handle, limit)) }
+            })
+        })
+    }
+
+    fn core_energy(&self) -> Result<u64, AmdError> {
+        self.call_raw("core_energy", |raw, handle| {
+            raw.read_scalar(|energy| {
+                // SAFETY: The pointer is valid for the duration of the call.
+                unsafe { lib_call!(raw.lib(), amdsmi_get_cpu_core_energy(handle, energy)) }
+            })
+        })
+    }
+
+    fn core_boost_limit(&self) -> Result<u32, AmdError> {
+        self.call_raw("core_boost_limit", |raw, handle| {
+            raw.read_scalar(|limit| {
+                // SAFETY: The pointer is valid for the duration of the call.
+                unsafe { lib_call!(raw.lib(), amdsmi_get_cpu_core_boostlimit(handle, limit)) }
+            })
+        })
+    }
+
+    fn core_frequency_limit(&self) -> Result<u32, AmdError> {
+        self.call_raw("core_frequency_limit", |raw, handle| {
+            raw.read_scalar(|limit| {
+                // SAFETY: The pointer is valid for the duration of the call.
+                unsafe {
+                    lib_call!(
+                        raw.lib(),
+                        amdsmi_get_cpu_core_current_freq_limit(handle, limit)
+                    )
+                }
+            })
+        })
+    }
+
+    fn hsmp_driver_version(&self) -> Result<AmdVersion, AmdError> {
+        self.call_raw("hsmp_driver_version", |raw, handle| {
+            // SAFETY: The function initializes the structure on success.
+            let version = unsafe {
+                raw.read_struct::<amdsmi_hsmp_driver_version_t>(|p| {
+                    lib_call!(raw.lib(), amdsmi_get_cpu_hsmp_driver_version(handle, p))
+                })?
+            };
+            Ok(version.into())
+        })
+    }
+
+    fn smu_firmware_version(&self) -> Result<AmdVersion, AmdError> {
+        self.call_raw("smu_firmware_version", |raw, handle| {
+            // SAFETY: The function initializes the structure on success.
+            let version = unsafe {
+                raw.read_struct::<amdsmi_smu_fw_version_t>(|p| {
+                    lib_call!(raw.lib(), amdsmi_get_cpu_smu_fw_version(handle, p))
+                })?
+            };
+            Ok(version.into())
+        })
+    }
+
+    fn hsmp_metrics_table_version(&self) -> Result<u32, AmdError> {
+        self.call_raw("hsmp_metrics_table_version", |raw, handle| {
+            raw.read_scalar(|version| {
+                // SAFETY: The pointer is valid for the duration of the call.
+                unsafe {
+                    lib_call!(
+                        raw.lib(),
+                        amdsmi_get_hsmp_metrics_table_version(handle, version)
+                    )
+                }
+            })
+        })
+    }
+
+    fn hsmp_metrics(&self) -> Result<AmdHsmpMetrics, AmdError> {
+        self.call_raw("hsmp_metrics", |raw, handle| {
+            // SAFETY: The function initializes the table on success.
+            let table = unsafe {
+                raw.read_struct::<amdsmi_hsmp_metrics_table_t>(|p| {
+                    lib_call!(raw.lib(), amdsmi_get_hsmp_metrics_table(handle, p))
+                })?
+            };
+            Ok(AmdHsmpMetrics::from(&table))
+        })
+    }
+}
+
+impl From<amdsmi_hsmp_driver_version_t> for AmdVersion {
+    fn from(version: amdsmi_hsmp_driver_version_t) -> Self {
+        Self {
+            major: version.major,
+            minor: version.minor,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<amdsmi_smu_fw_version_t> for AmdVersion {
+    fn from(version: amdsmi_smu_fw_version_t) -> Self {
+        Self {
+            major: version.major as u32,
+            minor: version.minor as u32,
+            release: version.debug as u32,
+            build: String::new(),
+        }
+    }
+}
+
+impl AmdProcessorHandle {
+    /// Retrieves the temperature of the CPU socket, in the [`UnitSystem`](crate::units::UnitSystem)
+    /// of the library (m°C or °C).
+    pub fn socket_temperature_quantity(&self) -> Result<Quantity, AmdError> {
+        let temperature = self.socket_temperature()?;
+        Ok(self
+            .amdsmi
+            .unit_system()
+            .quantity(temperature as f64, MetricUnit::MilliCelsius))
+    }
+
+    /// Retrieves the energy consumed by the CPU socket since the boot, in the
+    /// [`UnitSystem`](crate::units::UnitSystem) of the library (µJ or J).
+    pub fn socket_energy_quantity(&self) -> Result<Quantity, AmdError> {
+        let energy = self.socket_energy()?;
+        Ok(self
+            .amdsmi
+            .unit_system()
+            .quantity(energy as f64, MetricUnit::MicroJoule))
+    }
+}
+
+impl AmdSocketHandle {
+    /// Lists the cores of this socket, if it is a CPU socket.
+    ///
+    /// The cores are not returned by [`processor_handles`](crate::handles::SocketHandle::processor_handles),
+    /// which only lists the CPU socket itself.
+    pub fn cpu_core_handles(&self) -> Result<Vec<AmdProcessorHandle>, AmdError> {
+        let core_type = AmdProcessorType::AMDSMI_PROCESSOR_TYPE_AMD_CPU_CORE;
+        let socket = self.current_handle()?;
+        let handles = self.amdsmi.call_raw("cpu_core_handles", |raw| {
+            let mut count = 0;
+            // SAFETY: With a null buffer, the function only writes the number of cores.
+            raw.check(unsafe {
+                lib_call!(
+                    raw.lib(),
+                    amdsmi_get_processor_handles_by_type(socket, core_type, null_mut(), &mut count,)
+                )
+            })?;
+            let mut handles = vec![null_mut(); count as usize];
+            // SAFETY: The buffer can hold `count` handles, and the function writes at most `count` handles.
+            raw.check(unsafe {
+                lib_call!(
+                    raw.lib(),
+                    amdsmi_get_processor_handles_by_type(
+                        socket,
+                        core_type,
+                        handles.as_mut_ptr(),
+                        &mut count,
+                    )
+                )
+            })?;
+            ffi::check_buffer(count as usize, handles.len())?;
+            handles.truncate(count as usize);
+            Ok(handles)
+        })?;
+        Ok(handles
+            .into_iter()
+            .map(|p| AmdProcessorHandle::new(self.amdsmi.clone(), p))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::MaybeUninit;
+
+    use super::AmdHsmpMetrics;
+    use crate::{
+        bindings::{
+            amdsmi_hsmp_driver_version_t, amdsmi_hsmp_metrics_table_t, amdsmi_smu_fw_version_t,
+        },
+        metrics::AmdVersion,
+    };
+
+    #[test]
+    fn versions() {
+        let smu = AmdVersion::from(amdsmi_smu_fw_version_t {
+            debug: 3,
+            minor: 85,
+            major: 4,
+            unused: 0,
+        });
+        assert_eq!(smu.to_string(), "4.85.3");
+        let hsmp = AmdVersion::from(amdsmi_hsmp_driver_version_t { major: 2, minor: 1 });
+        assert_eq!(hsmp.to_string(), "2.1.0");
+    }
+
+    #[test]
+    fn hsmp_metrics() {
+        // SAFETY: The table only contains integers, for which zero is a valid value.
+        let mut table: amdsmi_hsmp_metrics_table_t = unsafe { MaybeUninit::zeroed().assume_init() };
+        table.accumulation_counter = 12;
+        table.max_socket_temperature = 45 * 1024 + 512;
+        table.socket_power = 550 * 1024 + 256;
+        table.socket_power_limit = 550 * 1024;
+        table.cclk_frequency_limit = 3700;
+        table.socket_thm_residency_acc = 3;
+        let metrics = AmdHsmpMetrics::from(&table);
+        assert_eq!(metrics.accumulation_counter, 12);
+        assert_eq!(metrics.max_socket_temperature_celsius(), 45.5);
+        assert_eq!(metrics.socket_power_watts(), 550.25);
+        assert_eq!(metrics.socket_power_limit_watts(), 550.0);
+        assert_eq!(metrics.cclk_frequency_limit, 3700);
+        assert_eq!(metrics.socket_thm_residency_acc, 3);
+    }
+}
@@ -0,0 +1,483 @@
+//! Reflection-style access to the metrics, for generic collectors.
+//!
+//! Instead of calling a specific method for each metric, a collector can list the metrics that
+//! a device supports with [`ProcessorHandle::device_supported_metrics`], and read them
+//! with [`ProcessorHandle::device_read_metric`].
+
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::{AmdError, ResultExt},
+    handles::ProcessorHandle,
+    metrics::*,
+};
+
+/// Metric that can be read dynamically from a GPU device.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum MetricKind {
+    /// Socket power, in W.
+    Power,
+    /// Energy consumed since the last boot, in µJ.
+    Energy,
+    /// Current temperature of a sensor, in °C.
+    Temperature(AmdTemperatureType),
+    /// Current voltage of a sensor, in mV.
+    Voltage(AmdVoltageType),
+    /// Current frequency of a clock domain, in MHz.
+    Clock(AmdClkType),
+    /// Used memory, in bytes.
+    MemoryUsed(AmdMemoryType),
+    /// Activity of the graphic core, in %.
+    GfxActivity,
+    /// Activity of the memory management, in %.
+    MmActivity,
+    /// Activity of the memory controller, in %.
+    UmcActivity,
+    /// Speed of a fan, in % of the maximal speed.
+    FanSpeed(u32),
+    /// Number of bytes sent on the PCI bus during the last second.
+    PciSent,
+    /// Number of bytes received on the PCI bus during the last second.
+    PciReceived,
+}
+
+impl MetricKind {
+    /// Returns the scope of the metric: the whole package, or a partition.
+    ///
+    /// When a GPU is partitioned (CPX, DPX... modes), package-level metrics have the same value
+    /// on all the partitions, and must be counted only once.
+    pub fn scope(&self) -> MetricScope {
+        match self {
+            MetricKind::Power
+            | MetricKind::Energy
+            | MetricKind::Temperature(_)
+            | MetricKind::Voltage(_)
+            | MetricKind::FanSpeed(_)
+            | MetricKind::PciSent
+            | MetricKind::PciReceived => MetricScope::Package,
+            MetricKind::Clock(_)
+            | MetricKind::MemoryUsed(_)
+            | MetricKind::GfxActivity
+            | MetricKind::MmActivity
+            | MetricKind::UmcActivity => MetricScope::Partition,
+        }
+    }
+
+    /// Returns the metadata of the metric: name, unit, semantics and expected range.
+    pub fn metadata(&self) -> MetricMetadata {
+        use MetricSemantics::{Counter, Gauge};
+        use MetricUnit::*;
+
+        let (name, unit, semantics, range) = match self {
+            MetricKind::Power => ("power", Watt, Gauge, None),
+            MetricKind::Energy => ("energy", MicroJoule, Counter, None),
+            MetricKind::Temperature(_) => ("temperature", Celsius, Gauge, None),
+            MetricKind::Voltage(_) => ("voltage", MilliVolt, Gauge, None),
+            MetricKind::Clock(_) => ("clock", MegaHertz, Gauge, None),
+            MetricKind::MemoryUsed(_) => ("memory_used", Byte, Gauge, None),
+            MetricKind::GfxActivity => ("gfx_activity", Percent, Gauge, Some((0.0, 100.0))),
+            MetricKind::MmActivity => ("mm_activity", Percent, Gauge, Some((0.0, 100.0))),
+            MetricKind::UmcActivity => ("umc_activity", Percent, Gauge, Some((0.0, 100.0))),
+            MetricKind::FanSpeed(_) => ("fan_speed", Percent, Gauge, Some((0.0, 100.0))),
+            MetricKind::PciSent => ("pci_sent", BytePerSecond, Gauge, None),
+            MetricKind::PciReceived => ("pci_received", BytePerSecond, Gauge, None),
+        };
+        MetricMetadata {
+            name,
+            unit,
+            semantics,
+            range,
+        }
+    }
+}
+
+/// Description of a [`MetricKind`], to generate metric descriptors automatically.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricMetadata {
+    /// Name of the metric, in snake case.
+    pub name: &'static str,
+    /// Unit of the values.
+    pub unit: MetricUnit,
+    /// How the values evolve over time.
+    pub semantics: MetricSemantics,
+    /// Expected range of the values (min, max), if it is bounded.
+    pub range: Option<(f64, f64)>,
+}
+
+/// Unit of a [`MetricValue`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum MetricUnit {
+    /// Watt (W).
+    Watt,
+    /// Microwatt (µW).
+    MicroWatt,
+    /// Joule (J).
+    Joule,
+    /// Microjoule (µJ).
+    MicroJoule,
+    /// Degree Celsius (°C).
+    Celsius,
+    /// Millidegree Celsius (m°C).
+    MilliCelsius,
+    /// Volt (V).
+    Volt,
+    /// Millivolt (mV).
+    MilliVolt,
+    /// Hertz (Hz).
+    Hertz,
+    /// Megahertz (MHz).
+    MegaHertz,
+    /// Byte (B).
+    Byte,
+    /// Byte per second (B/s), for a throughput.
+    BytePerSecond,
+    /// Percentage (%), between 0 and 100.
+    Percent,
+}
+
+impl MetricUnit {
+    /// Returns the symbol of the unit, for instance `µJ`.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            MetricUnit::Watt => "W",
+            MetricUnit::MicroWatt => "µW",
+            MetricUnit::Joule => "J",
+            MetricUnit::MicroJoule => "µJ",
+            MetricUnit::Celsius => "°C",
+            MetricUnit::MilliCelsius => "m°C",
+            MetricUnit::Volt => "V",
+            MetricUnit::MilliVolt => "mV",
+            MetricUnit::Hertz => "Hz",
+            MetricUnit::MegaHertz => "MHz",
+            MetricUnit::Byte => "B",
+            MetricUnit::BytePerSecond => "B/s",
+            MetricUnit::Percent => "%",
+        }
+    }
+}
+
+/// How the values of a metric evolve over time.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum MetricSemantics {
+    /// Monotonic counter, that only increases (except when it wraps around or is reset).
+    Counter,
+    /// Value that can go up and down.
+    Gauge,
+}
+
+/// Scope of a [`MetricKind`] on a partitioned GPU.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum MetricScope {
+    /// The metric describes the whole physical GPU, it is shared by all the partitions.
+    Package,
+    /// The metric describes a single partition.
+    Partition,
+}
+
+/// Value of a [`MetricKind`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MetricValue {
+    /// Unsigned integer value.
+    Unsigned(u64),
+    /// Signed integer value.
+    Signed(i64),
+    /// Floating-point value.
+    Float(f64),
+}
+
+impl MetricValue {
+    /// Converts the value to a float, possibly with a loss of precision.
+    pub fn as_f64(&self) -> f64 {
+        match *self {
+            MetricValue::Unsigned(v) => v as f64,
+            MetricValue::Signed(v) => v as f64,
+            MetricValue::Float(v) => v,
+        }
+    }
+}
+
+/// Reads a metric by calling the corresponding method of the handle.
+pub(crate) fn read_metric<H: ProcessorHandle + ?Sized>(
+    handle: &H,
+    kind: MetricKind,
+) -> Result<MetricValue, AmdError> {
+    let value = match kind {
+        MetricKind::Power => MetricValue::Unsigned(handle.device_power_consumption()?.socket_power),
+        MetricKind::Energy => {
+            let consumption = handle.device_energy_consumption()?;
+            MetricValue::Unsigned(consumption.microjoules() as u64)
+        }
+        MetricKind::Temperature(sensor_type) => MetricValue::Signed(
+            handle.device_temperature(sensor_type, AmdTemperatureMetric::AMDSMI_TEMP_CURRENT)?,
+        ),
+        MetricKind::Voltage(sensor_type) => MetricValue::Signed(
+            handle.device_voltage(sensor_type, AmdVoltageMetric::AMDSMI_VOLT_CURRENT)?,
+        ),
+        MetricKind::Clock(clk_type) => {
+            MetricValue::Unsigned(handle.device_clock_info(clk_type)?.clk as u64)
+        }
+        MetricKind::MemoryUsed(mem_type) => {
+            MetricValue::Unsigned(handle.device_memory_usage(mem_type)?)
+        }
+        MetricKind::GfxActivity => {
+            MetricValue::Unsigned(handle.device_activity()?.gfx_activity as u64)
+        }
+        MetricKind::MmActivity => {
+            MetricValue::Unsigned(handle.device_activity()?.mm_activity as u64)
+        }
+        MetricKind::UmcActivity => {
+            MetricValue::Unsigned(handle.device_activity()?.umc_activity as u64)
+        }
+        MetricKind::FanSpeed(sensor_index) => {
+            MetricValue::Unsigned(handle.device_fan_speed(sensor_index)? as u64)
+        }
+        MetricKind::PciSent => MetricValue::Unsigned(handle.device_pci_usage()?.sent),
+        MetricKind::PciReceived => MetricValue::Unsigned(handle.device_pci_usage()?.received),
+    };
+    Ok(value)
+}
+
+/// Lists the metrics that can be read from the handle, by trying to read all the known metrics.
+pub(crate) fn supported_metrics<H: ProcessorHandle + ?Sized>(
+    handle: &H,
+) -> Result<Vec<MetricKind>, AmdError> {
+    let mut candidates = vec![MetricKind::Power, MetricKind::Energy];
+    // the sensors are probed once per handle, see `device_supported_temperature_sensors`
+    candidates.extend(
+        handle
+            .device_supported_temperature_sensors(AmdTemperatureMetric::AMDSMI_TEMP_CURRENT)?
+            .into_iter()
+            .map(MetricKind::Temperature),
+    );
+    candidates.extend(
+        handle
+            .device_supported_voltage_sensors(AmdVoltageMetric::AMDSMI_VOLT_CURRENT)?
+            .into_iter()
+            .map(MetricKind::Voltage),
+    );
+    candidates.extend(ALL_CLK_TYPES.iter().map(|&t| MetricKind::Clock(t)));
+    candidates.extend(ALL_MEMORY_TYPES.iter().map(|&t| MetricKind::MemoryUsed(t)));
+    candidates.extend([
+        MetricKind::GfxActivity,
+        MetricKind::MmActivity,
+        MetricKind::UmcActivity,
+    ]);
+    candidates.extend(
+        handle
+            .device_fans()?
+            .iter()
+            .map(|fan| MetricKind::FanSpeed(fan.sensor_index)),
+    );
+    candidates.extend([MetricKind::PciSent, MetricKind::PciReceived]);
+
+    let mut supported = Vec::with_capacity(candidates.len());
+    for kind in candidates {
+        if read_metric(handle, kind).optional()?.is_some() {
+            supported.push(kind);
+        }
+    }
+    Ok(supported)
+}
+
+/// Metrics of a device that recently returned an "unavailable" error (see [`AmdError::is_unavailable`]),
+/// to skip them until the entry expires.
+///
+/// See [`AmdSmi::set_unsupported_cache_ttl`](crate::AmdSmi::set_unsupported_cache_ttl).
+#[derive(Debug, Default)]
+pub(crate) struct UnsupportedCache {
+    /// Time at which each metric failed.
+    entries: HashMap<MetricKind, Instant>,
+    /// Epoch of the cache, compared to the epoch of the library to clear the cache on reset.
+    epoch: u64,
+}
+
+impl UnsupportedCache {
+    /// Returns `true` if `kind` failed less than `ttl` before `now`, in the epoch `epoch`.
+    ///
+    /// The entries of a previous epoch are dropped.
+    pub(crate) fn contains(
+        &mut self,
+        kind: MetricKind,
+        now: Instant,
+        ttl: Duration,
+        epoch: u64,
+    ) -> bool {
+        if self.epoch != epoch {
+            self.entries.clear();
+            self.epoch = epoch;
+        }
+        match self.entries.get(&kind) {
+            Some(&time) if now.saturating_duration_since(time) < ttl => true,
+            Some(_) => {
+                self.entries.remove(&kind);
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// Remembers that `kind` failed at `now`.
+    pub(crate) fn insert(&mut self, kind: MetricKind, now: Instant) {
+        self.entries.insert(kind, now);
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// Minimal intervals between two reads of the same metric of a device, see [`AmdSmi::set_rate_limits`](crate::AmdSmi::set_rate_limits).
+///
+/// Many counters of the GPUs are updated by the firmware about once per second: reading them faster wastes
+/// calls to the library, and returns identical samples that look like fresh measurements.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use amd_smi_wrapper::dynamic::{MetricKind, RateLimits};
+///
+/// let limits = RateLimits::new()
+///     .with_default(Duration::from_millis(100))
+///     .with_metric(MetricKind::Energy, Duration::from_secs(1));
+/// assert_eq!(limits.interval(MetricKind::Energy), Some(Duration::from_secs(1)));
+/// assert_eq!(limits.interval(MetricKind::Power), Some(Duration::from_millis(100)));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RateLimits {
+    default: Option<Duration>,
+    per_metric: HashMap<MetricKind, Duration>,
+}
+
+impl RateLimits {
+    /// Creates a configuration without any limit: each read calls the library.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the interval of the metrics that have no specific interval.
+    pub fn with_default(mut self, interval: Duration) -> Self {
+        self.default = Some(interval);
+        self
+    }
+
+    /// Sets the interval of a metric.
+    pub fn with_metric(mut self, kind: MetricKind, interval: Duration) -> Self {
+        self.per_metric.insert(kind, interval);
+        self
+    }
+
+    /// Returns the minimal interval between two reads of `kind`, or `None` if the reads are not limited.
+    pub fn interval(&self, kind: MetricKind) -> Option<Duration> {
+        self.per_metric
+            .get(&kind)
+            .copied()
+            .or(self.default)
+            .filter(|interval| !interval.is_zero())
+    }
+
+    /// Returns `true` if no read is limited.
+    pub fn is_empty(&self) -> bool {
+        self.default.is_none_or(|interval| interval.is_zero())
+            && self.per_metric.values().all(|interval| interval.is_zero())
+    }
+}
+
+/// Last values read from a device, returned when the metrics are read faster than their [`RateLimits`].
+#[derive(Debug, Default)]
+pub(crate) struct RecentValues {
+    /// Time and value of the last read of each metric.
+    entries: HashMap<MetricKind, (Instant, MetricValue)>,
+    /// Generation of the library when the values were read: the values of a previous generation are dropped.
+    generation: u64,
+}
+
+impl RecentValues {
+    /// Returns the value of `kind` if it was read less than `interval` before `now`, in the generation `generation`.
+    pub(crate) fn get(
+        &mut self,
+        kind: MetricKind,
+        now: Instant,
+        interval: Duration,
+        generation: u64,
+    ) -> Option<MetricValue> {
+        if self.generation != generation {
+            self.entries.clear();
+            self.generation = generation;
+        }
+        match self.entries.get(&kind) {
+            Some(&(time, value)) if now.saturating_duration_since(time) < interval => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Remembers that `kind` had the value `value` at `now`.
+    pub(crate) fn insert(&mut self, kind: MetricKind, now: Instant, value: MetricValue) {
+        self.entries.insert(kind, (now, value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{
+        MetricKind, MetricSemantics, MetricUnit, MetricValue, RateLimits, RecentValues,
+        UnsupportedCache,
+    };
+
+    #[test]
+    fn pci_metadata() {
+        // the driver measures the traffic of the last second, the values are not cumulative
+        for kind in [MetricKind::PciSent, MetricKind::PciReceived] {
+            let metadata = kind.metadata();
+            assert_eq!(metadata.unit, MetricUnit::BytePerSecond);
+            assert_eq!(metadata.semantics, MetricSemantics::Gauge);
+        }
+    }
+
+    #[test]
+    fn unsupported_cache() {
+        let ttl = Duration::from_secs(60);
+        let t0 = Instant::now();
+        let mut cache = UnsupportedCache::default();
+        assert!(!cache.contains(MetricKind::Power, t0, ttl, 0));
+
+        cache.insert(MetricKind::Power, t0);
+        assert!(cache.contains(MetricKind::Power, t0 + Duration::from_secs(30), ttl, 0));
+        assert!(!cache.contains(MetricKind::Energy, t0, ttl, 0));
+        // expired
+        assert!(!cache.contains(MetricKind::Power, t0 + ttl, ttl, 0));
+        assert!(!cache.contains(MetricKind::Power, t0, ttl, 0));
+
+        // reset of the library
+        cache.insert(MetricKind::Power, t0);
+        assert!(!cache.contains(MetricKind::Power, t0, ttl, 1));
+    }
+
+    #[test]
+    fn recent_values() {
+        let limits = RateLimits::new().with_metric(MetricKind::Energy, Duration::from_secs(1));
+        assert_eq!(limits.interval(MetricKind::Power), None);
+        assert!(RateLimits::new().with_default(Duration::ZERO).is_empty());
+
+        let interval = limits.interval(MetricKind::Energy).unwrap();
+        let t0 = Instant::now();
+        let mut recent = RecentValues::default();
+        assert_eq!(recent.get(MetricKind::Energy, t0, interval, 0), None);
+        recent.insert(MetricKind::Energy, t0, MetricValue::Unsigned(42));
+        let half = t0 + Duration::from_millis(500);
+        assert_eq!(
+            recent.get(MetricKind::Energy, half, interval, 0),
+            Some(MetricValue::Unsigned(42))
+        );
+        assert_eq!(
+            recent.get(MetricKind::Energy, t0 + interval, interval, 0),
+            None
+        );
+        // re-initialization of the library
+        assert_eq!(recent.get(MetricKind::Energy, half, interval, 1), None);
+    }
+}
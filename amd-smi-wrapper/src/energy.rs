@@ -0,0 +1,122 @@
+//! Common interface to the energy counters of the AMD devices.
+
+use crate::{
+    cpu::CpuProcessorHandle,
+    error::AmdError,
+    handles::{AmdProcessorHandle, ProcessorHandle},
+    metrics::{AmdEnergyReading, AmdProcessorType, energy_microjoules},
+};
+
+#[cfg(feature = "mock")]
+use mockall::automock;
+
+/// Device that provides an energy counter, such as a GPU or a CPU socket.
+///
+/// # Wrap around
+///
+/// The raw counter is monotonic, but it wraps around to zero when it exceeds
+/// [`energy_counter_wrap`](EnergyReader::energy_counter_wrap), which is expressed in units of the counter,
+/// not in µJ. To compute the energy consumed between two readings, use [`energy_delta_uj`](EnergyReader::energy_delta_uj),
+/// which handles one wrap around. Read the counter often enough to never miss two of them.
+#[cfg_attr(feature = "mock", automock)]
+pub trait EnergyReader {
+    /// Reads the energy counter, with its resolution.
+    fn energy_reading(&self) -> Result<AmdEnergyReading, AmdError>;
+
+    /// Returns the value at which the raw counter wraps around to zero, in units of the counter.
+    fn energy_counter_wrap(&self) -> u64 {
+        u64::MAX
+    }
+
+    /// Reads the energy counter, in µJ.
+    fn energy_uj(&self) -> Result<u64, AmdError> {
+        Ok(self.energy_reading()?.microjoules() as u64)
+    }
+
+    /// Returns the energy consumed between two readings of the counter, in µJ.
+    fn energy_delta_uj(&self, previous: &AmdEnergyReading, current: &AmdEnergyReading) -> u64 {
+        let delta = energy_delta(
+            previous.counter,
+            current.counter,
+            self.energy_counter_wrap(),
+        );
+        energy_microjoules(delta, current.resolution) as u64
+    }
+}
+
+/// Computes the difference between two readings of a counter that wraps around at `wrap`.
+pub(crate) fn energy_delta(previous: u64, current: u64, wrap: u64) -> u64 {
+    if current >= previous {
+        current - previous
+    } else {
+        // the counter has wrapped around: previous -> wrap -> 0 -> current
+        (wrap - previous.min(wrap)).saturating_add(current)
+    }
+}
+
+/// Reads the counter of a GPU, or of a CPU socket or core (in µJ, read through HSMP).
+impl EnergyReader for AmdProcessorHandle {
+    fn energy_reading(&self) -> Result<AmdEnergyReading, AmdError> {
+        match self.processor_type()? {
+            AmdProcessorType::AMDSMI_PROCESSOR_TYPE_AMD_CPU => {
+                Ok(AmdEnergyReading::from_microjoules(self.socket_energy()?))
+            }
+            AmdProcessorType::AMDSMI_PROCESSOR_TYPE_AMD_CPU_CORE => {
+                Ok(AmdEnergyReading::from_microjoules(self.core_energy()?))
+            }
+            _ => self.device_energy_joules(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EnergyReader, energy_delta};
+    use crate::{error::AmdError, metrics::AmdEnergyReading};
+
+    struct Counter {
+        wrap: u64,
+    }
+
+    impl EnergyReader for Counter {
+        fn energy_reading(&self) -> Result<AmdEnergyReading, AmdError> {
+            unimplemented!()
+        }
+
+        fn energy_counter_wrap(&self) -> u64 {
+            self.wrap
+        }
+    }
+
+    fn reading(counter: u64) -> AmdEnergyReading {
+        AmdEnergyReading {
+            counter,
+            resolution: 15.3,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn delta() {
+        assert_eq!(energy_delta(10, 25, u64::MAX), 15);
+        assert_eq!(energy_delta(10, 10, u64::MAX), 0);
+        assert_eq!(energy_delta(u64::MAX - 5, 4, u64::MAX), 9);
+        assert_eq!(energy_delta(1000, 200, 1024), 224);
+    }
+
+    #[test]
+    fn delta_uj() {
+        // the wrap is applied to the raw counter, then the resolution
+        let counter = Counter { wrap: 1 << 32 };
+        assert_eq!(
+            counter.energy_delta_uj(&reading((1 << 32) - 10), &reading(10)),
+            306
+        );
+        assert_eq!(counter.energy_delta_uj(&reading(100), &reading(110)), 153);
+        let counter = Counter { wrap: u64::MAX };
+        assert_eq!(
+            counter.energy_delta_uj(&reading(u64::MAX - 10), &reading(10)),
+            306
+        );
+    }
+}
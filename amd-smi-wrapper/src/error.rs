@@ -1,45 +1,269 @@
 //! Error handling.
 
 use std::{
-    ffi::{CStr, c_char},
+    ffi::{CStr, OsString, c_char},
     fmt::{Display, Formatter},
+    path::PathBuf,
     ptr,
+    str::Utf8Error,
 };
 use thiserror::Error;
 
-use super::LIB_PATH;
-use crate::bindings::{amdsmi_status_t, libamd_smi};
+use crate::{
+    bindings::{amdsmi_status_t, libamd_smi},
+    loader::LoadAttempt,
+};
 
 pub type AmdStatus = amdsmi_status_t;
 
 /// Error while using the AMD SMI library.
 #[derive(Error, Debug)]
-pub struct AmdError {
-    /// The underlying status provided by amdsmi library.
-    pub status: AmdStatus,
-    /// Detailed description of the error.
-    pub message: Option<String>,
+pub enum AmdError {
+    /// The library returned a status other than `SUCCESS`.
+    Status {
+        /// The underlying status provided by amdsmi library.
+        status: AmdStatus,
+        /// Detailed description of the error.
+        message: Option<String>,
+    },
+    /// The library returned a string that is not valid UTF-8.
+    InvalidString(#[source] Utf8Error),
+    /// The device is suspended, and has not been queried to avoid waking it up.
+    ///
+    /// See [`AmdSmi::set_skip_suspended`](crate::AmdSmi::set_skip_suspended).
+    DeviceSuspended,
+    /// A call to the library panicked, and the panic has been caught at the boundary of the library.
+    Panicked {
+        /// Name of the wrapper function.
+        function: &'static str,
+        /// Message of the panic.
+        message: String,
+    },
+    /// The control of the devices has been refused by the wrapper, because other copies of the library
+    /// are loaded and the shared control has not been allowed.
+    ///
+    /// See [`AmdSmiBuilder::shared_control`](crate::builder::AmdSmiBuilder::shared_control).
+    ControlRefused {
+        /// Paths of the other copies of the library.
+        others: Vec<PathBuf>,
+    },
+    /// A setting is outside of the range allowed by the device, and has not been passed to the library.
+    OutOfRange {
+        /// Name of the setting.
+        what: &'static str,
+        /// Unit of the values, possibly empty.
+        unit: &'static str,
+        /// Refused value.
+        value: u64,
+        /// Minimal value allowed by the device.
+        min: u64,
+        /// Maximal value allowed by the device.
+        max: u64,
+    },
+    /// An argument has been refused by the wrapper before calling the library.
+    InvalidArgument(String),
+    /// The library returned a value that the wrapper cannot represent, or that violates the invariants
+    /// of the library.
+    InvalidData(String),
 }
 
 impl Display for AmdError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match &self.message {
-            Some(msg) => write!(f, "amd-smi error {:?}: {msg}", self.status),
-            None => write!(f, "amd-smi error {:?}", self.status),
+        match self {
+            AmdError::Status {
+                status,
+                message: Some(msg),
+            } => write!(f, "amd-smi error {status:?}: {msg}"),
+            AmdError::Status {
+                status,
+                message: None,
+            } => write!(f, "amd-smi error {status:?}"),
+            AmdError::InvalidString(e) => write!(f, "amd-smi returned an invalid string: {e}"),
+            AmdError::DeviceSuspended => write!(f, "the device is suspended"),
+            AmdError::Panicked { function, message } => {
+                write!(f, "the call to {function} panicked: {message}")
+            }
+            AmdError::ControlRefused { others } => write!(
+                f,
+                "another copy of the library is loaded ({}), and the shared control has not been allowed",
+                others
+                    .iter()
+                    .map(|p| p.to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            AmdError::OutOfRange {
+                what,
+                unit,
+                value,
+                min,
+                max,
+            } => write!(
+                f,
+                "the {what} {value}{unit} is not between {min}{unit} and {max}{unit}"
+            ),
+            AmdError::InvalidArgument(msg) => write!(f, "invalid argument: {msg}"),
+            AmdError::InvalidData(msg) => write!(f, "amd-smi returned invalid data: {msg}"),
+        }
+    }
+}
+
+impl AmdError {
+    /// Returns the status provided by the library, if the error comes from a failed call.
+    pub fn status(&self) -> Option<AmdStatus> {
+        match self {
+            AmdError::Status { status, .. } => Some(*status),
+            AmdError::InvalidString(_)
+            | AmdError::DeviceSuspended
+            | AmdError::Panicked { .. }
+            | AmdError::ControlRefused { .. }
+            | AmdError::OutOfRange { .. }
+            | AmdError::InvalidArgument(_)
+            | AmdError::InvalidData(_) => None,
+        }
+    }
+
+    /// Returns the kind of the error, in snake case: `status` for the errors returned by the library,
+    /// `invalid_string`, `device_suspended`... for the errors detected by the wrapper.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            AmdError::Status { .. } => "status",
+            AmdError::InvalidString(_) => "invalid_string",
+            AmdError::DeviceSuspended => "device_suspended",
+            AmdError::Panicked { .. } => "panicked",
+            AmdError::ControlRefused { .. } => "control_refused",
+            AmdError::OutOfRange { .. } => "out_of_range",
+            AmdError::InvalidArgument(_) => "invalid_argument",
+            AmdError::InvalidData(_) => "invalid_data",
+        }
+    }
+
+    /// Returns `true` if the error means that the query is not available on this device, driver or library.
+    ///
+    /// This is the case of the statuses `NOT_SUPPORTED`, `NO_DATA` and `NOT_YET_IMPLEMENTED`.
+    pub fn is_unavailable(&self) -> bool {
+        matches!(
+            self.status(),
+            Some(
+                AmdStatus::AMDSMI_STATUS_NOT_SUPPORTED
+                    | AmdStatus::AMDSMI_STATUS_NO_DATA
+                    | AmdStatus::AMDSMI_STATUS_NOT_YET_IMPLEMENTED
+            )
+        )
+    }
+
+    /// Returns `true` if the error means that the library must be initialized again,
+    /// which happens after a restart of the GPU driver.
+    pub fn needs_reinit(&self) -> bool {
+        matches!(
+            self.status(),
+            Some(AmdStatus::AMDSMI_STATUS_NOT_INIT | AmdStatus::AMDSMI_STATUS_AMDGPU_RESTART_ERR)
+        )
+    }
+
+    /// Returns `true` if the error is transient, i.e. the same call may succeed if it is retried later.
+    ///
+    /// This includes [`AmdError::DeviceSuspended`]: the device may be resumed later.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, AmdError::DeviceSuspended)
+            || matches!(
+                self.status(),
+                Some(
+                    AmdStatus::AMDSMI_STATUS_RETRY
+                        | AmdStatus::AMDSMI_STATUS_BUSY
+                        | AmdStatus::AMDSMI_STATUS_TIMEOUT
+                        | AmdStatus::AMDSMI_STATUS_INTERRUPT
+                        | AmdStatus::AMDSMI_STATUS_HSMP_TIMEOUT
+                )
+            )
+    }
+}
+
+/// Extension methods for the results of the AMD SMI wrappers.
+///
+/// Not every query is available on every device, and some failures are only temporary.
+/// These methods standardize how consumers degrade in such cases.
+///
+/// # Example
+/// ```no_run
+/// use amd_smi_wrapper::{error::ResultExt, handles::ProcessorHandle};
+///
+/// fn print_power(device: &impl ProcessorHandle) -> Result<(), amd_smi_wrapper::error::AmdError> {
+///     match device.device_power_consumption().optional()? {
+///         Some(power) => println!("power: {} W", power.socket_power),
+///         None => println!("power: not available"),
+///     }
+///     Ok(())
+/// }
+/// ```
+pub trait ResultExt<T> {
+    /// Maps the errors that mean "not available" to `Ok(None)`.
+    ///
+    /// See [`AmdError::is_unavailable`]. Other errors are returned unchanged.
+    fn optional(self) -> Result<Option<T>, AmdError>;
+
+    /// Returns `true` if the result is an error that may disappear if the call is retried.
+    ///
+    /// See [`AmdError::is_retryable`].
+    fn retryable(&self) -> bool;
+}
+
+impl<T> ResultExt<T> for Result<T, AmdError> {
+    fn optional(self) -> Result<Option<T>, AmdError> {
+        match self {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if e.is_unavailable() => Ok(None),
+            Err(e) => Err(e),
         }
     }
+
+    fn retryable(&self) -> bool {
+        matches!(self, Err(e) if e.is_retryable())
+    }
 }
 
 #[derive(Debug, Error)]
 pub enum AmdInitError {
     #[error("amd-smi init error")]
     Init(#[from] AmdError),
-    #[error("Failed to load {LIB_PATH}")]
-    Load(#[from] libloading::Error),
+    /// The library could not be loaded from any of the candidate paths.
+    #[error("Failed to load the AMD SMI library: {}", join(attempts, "; "))]
+    Load {
+        /// Paths that have been tried, with the error reported by the dynamic loader.
+        attempts: Vec<LoadAttempt>,
+    },
+    /// The library has been loaded, but does not export the required symbols (too old or wrong library).
+    #[error("{} does not export the required symbols: {}", path.to_string_lossy(), symbols.join(", "))]
+    MissingSymbols {
+        /// Path from which the library has been loaded.
+        path: OsString,
+        /// Names of the required symbols that the library does not export.
+        symbols: Vec<&'static str>,
+    },
+    /// The initialization flags are not accepted by the library, see [`validate_flags`](crate::builder::validate_flags).
+    #[error("Invalid initialization flags {flags:#x}: {reason}")]
+    InvalidFlags {
+        /// Bits of the flags.
+        flags: u32,
+        /// Why the flags are invalid.
+        reason: &'static str,
+    },
+}
+
+fn join(attempts: &[LoadAttempt], separator: &str) -> String {
+    attempts
+        .iter()
+        .map(LoadAttempt::to_string)
+        .collect::<Vec<_>>()
+        .join(separator)
 }
 
-/// Returns a detailed description of a status code.
+/// Returns a detailed description of a status code, or `None` if the library does not provide it.
 pub fn status_message(amdsmi: &libamd_smi, status: amdsmi_status_t) -> Option<String> {
+    // the host library may not export the function (see `LibraryVariant::Host`)
+    if amdsmi.amdsmi_status_code_to_string.is_err() {
+        return None;
+    }
     let mut status_string: *const c_char = ptr::null();
     let result = unsafe { amdsmi.amdsmi_status_code_to_string(status, &mut status_string) };
     if result == amdsmi_status_t::AMDSMI_STATUS_SUCCESS && !status_string.is_null() {
@@ -50,3 +274,41 @@ pub fn status_message(amdsmi: &libamd_smi, status: amdsmi_status_t) -> Option<St
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{AmdError, AmdStatus, ResultExt};
+
+    fn err(status: AmdStatus) -> Result<u32, AmdError> {
+        Err(AmdError::Status {
+            status,
+            message: None,
+        })
+    }
+
+    #[test]
+    fn optional() {
+        assert_eq!(Ok::<u32, AmdError>(1).optional().unwrap(), Some(1));
+        assert_eq!(
+            err(AmdStatus::AMDSMI_STATUS_NOT_SUPPORTED)
+                .optional()
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            err(AmdStatus::AMDSMI_STATUS_NO_DATA).optional().unwrap(),
+            None
+        );
+        assert!(err(AmdStatus::AMDSMI_STATUS_NO_PERM).optional().is_err());
+    }
+
+    #[test]
+    fn retryable() {
+        assert!(err(AmdStatus::AMDSMI_STATUS_BUSY).retryable());
+        assert!(!err(AmdStatus::AMDSMI_STATUS_NOT_SUPPORTED).retryable());
+        assert!(!Ok::<u32, AmdError>(1).retryable());
+        assert!(Err::<u32, AmdError>(AmdError::DeviceSuspended).retryable());
+        let refused = AmdError::ControlRefused { others: Vec::new() };
+        assert!(!Err::<u32, AmdError>(refused).retryable());
+    }
+}
@@ -0,0 +1,270 @@
+//! Notifications of the GPU events: thermal throttling, resets, page faults...
+//!
+//! Instead of polling the metrics, an [`EventListener`] waits for the events reported by the driver.
+//! It is an iterator that blocks until the next event, and stops when no event arrives within its timeout.
+//! The notifications of its devices are stopped when the listener is dropped.
+//!
+//! The library collects the events of all the devices at once: only one listener should be active at a time,
+//! otherwise a listener can receive the events of the devices of another one (they are reported
+//! with no [`device`](GpuEvent::device)). With the feature `serialize-ffi`, the other calls to the library
+//! wait while a listener polls: keep the timeout short.
+//!
+//! # Example
+//! ```no_run
+//! use std::time::Duration;
+//! use amd_smi_wrapper::{AmdSmi, AmdInitFlags, metrics::AmdNotificationType};
+//!
+//! let amdsmi = AmdSmi::init(AmdInitFlags::AMDSMI_INIT_AMD_GPUS).unwrap();
+//! let device = amdsmi.iter_processors().unwrap().next().unwrap().unwrap();
+//! let mut listener = device
+//!     .event_listener(
+//!         &[AmdNotificationType::AMDSMI_EVT_NOTIF_THERMAL_THROTTLE],
+//!         Duration::from_secs(1),
+//!     )
+//!     .unwrap();
+//! loop {
+//!     for event in listener.by_ref() {
+//!         let event = event.unwrap();
+//!         println!("{:?}: {}", event.kind, event.message);
+//!     }
+//! }
+//! ```
+
+use std::{collections::VecDeque, mem::MaybeUninit, time::Duration};
+
+use crate::{
+    AmdSmi,
+    bindings::{amdsmi_evt_notification_data_t, amdsmi_processor_handle},
+    error::{AmdError, AmdStatus},
+    ffi,
+    handles::AmdProcessorHandle,
+    metrics::AmdNotificationType,
+    smi_call,
+    utils::c_buffer_to_string,
+};
+
+/// Maximal number of events retrieved by one call to the library.
+const MAX_EVENTS: usize = 16;
+
+/// Event reported by the driver, see [`EventListener`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GpuEvent {
+    /// Index of the device in the devices of the listener, or `None` if the event comes from another device.
+    pub device: Option<usize>,
+    /// Type of the event.
+    pub kind: AmdNotificationType,
+    /// Description of the event by the driver.
+    pub message: String,
+}
+
+impl GpuEvent {
+    /// Returns `true` if the event announces or ends a reset of the GPU: the handles and the metrics
+    /// of the device may be invalid across the reset.
+    pub fn is_reset(&self) -> bool {
+        self.kind == AmdNotificationType::AMDSMI_EVT_NOTIF_GPU_PRE_RESET
+            || self.kind == AmdNotificationType::AMDSMI_EVT_NOTIF_GPU_POST_RESET
+    }
+}
+
+/// Returns the mask of the library that selects the given events.
+///
+/// Returns [`AmdError::InvalidArgument`] if one of the events is not known by the library.
+pub fn event_mask(events: &[AmdNotificationType]) -> Result<u64, AmdError> {
+    events.iter().filter(|e| e.0 != 0).try_fold(0, |mask, e| {
+        if e.0 > AmdNotificationType::AMDSMI_EVT_NOTIF_LAST.0 {
+            return Err(AmdError::InvalidArgument(format!(
+                "unknown event type {}",
+                e.0
+            )));
+        }
+        Ok(mask | (1u64 << (e.0 - 1)))
+    })
+}
+
+/// Listener of the events of some GPU devices, see the [module documentation](self).
+///
+/// Each call to [`next`](Iterator::next) waits for an event during the timeout of the listener,
+/// and returns `None` if no event arrives. The iteration can be resumed after that.
+pub struct EventListener {
+    amdsmi: AmdSmi,
+    devices: Vec<amdsmi_processor_handle>,
+    timeout: Duration,
+    pending: VecDeque<GpuEvent>,
+}
+
+impl EventListener {
+    /// Starts the notification of the given events on each device.
+    ///
+    /// If the notification cannot be started on one of the devices, it is stopped on the others.
+    pub fn new(
+        devices: &[&AmdProcessorHandle],
+        events: &[AmdNotificationType],
+        timeout: Duration,
+    ) -> Result<Self, AmdError> {
+        let Some(first) = devices.first() else {
+            return Err(AmdError::InvalidArgument(String::from(
+                "no device to listen to",
+            )));
+        };
+        let mut listener = Self {
+            amdsmi: first.amdsmi.clone(),
+            devices: Vec::with_capacity(devices.len()),
+            timeout,
+            pending: VecDeque::new(),
+        };
+        let mask = event_mask(events)?;
+        for device in devices {
+            device.call_raw("init_event_notification", |raw, handle| {
+                // SAFETY: The function only reads the handle.
+                unsafe { smi_call!(raw, amdsmi_init_gpu_event_notification(handle))? };
+                Ok(())
+            })?;
+            // registered before the mask is set, so that the notification is stopped if it fails
+            match device.current_handle() {
+                Ok(handle) => listener.devices.push(handle),
+                Err(e) => {
+                    let _ = device.call_raw("stop_event_notification", |raw, handle| {
+                        // SAFETY: The notification has just been initialized on the device.
+                        unsafe { smi_call!(raw, amdsmi_stop_gpu_event_notification(handle)) }
+                    });
+                    return Err(e);
+                }
+            }
+            device.call_raw("set_event_notification_mask", |raw, handle| {
+                // SAFETY: The notification has been initialized on the device.
+                unsafe { smi_call!(raw, amdsmi_set_gpu_event_notification_mask(handle, mask)) }
+            })?;
+        }
+        Ok(listener)
+    }
+
+    /// Returns the timeout of each wait for an event.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Changes the timeout of each wait for an event.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Waits for events during the timeout, and returns all the events received, possibly none.
+    pub fn poll(&mut self) -> Result<Vec<GpuEvent>, AmdError> {
+        let mut events: Vec<_> = self.pending.drain(..).collect();
+        if events.is_empty() {
+            events = self.wait()?;
+        }
+        Ok(events)
+    }
+
+    fn wait(&self) -> Result<Vec<GpuEvent>, AmdError> {
+        let timeout_ms = self.timeout.as_millis().min(i32::MAX as u128) as i32;
+        let mut buffer = [MaybeUninit::<amdsmi_evt_notification_data_t>::uninit(); MAX_EVENTS];
+        let mut count = MAX_EVENTS as u32;
+        let result = self.amdsmi.call_raw("event_notification", |raw| {
+            // SAFETY: The buffer can hold `count` events, and the function writes at most `count` events.
+            unsafe {
+                smi_call!(
+                    raw,
+                    amdsmi_get_gpu_event_notification(
+                        timeout_ms,
+                        &mut count,
+                        buffer.as_mut_ptr().cast()
+                    )
+                )
+            }
+        });
+        match result {
+            Ok(()) => {}
+            // no event during the timeout
+            Err(e) if e.status() == Some(AmdStatus::AMDSMI_STATUS_NO_DATA) => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        }
+        ffi::check_buffer(count as usize, MAX_EVENTS)?;
+        let count = (count as usize).min(MAX_EVENTS);
+        Ok(buffer[..count]
+            .iter()
+            .map(|data| {
+                // SAFETY: The call succeeded and initialized the first `count` events.
+                let data = unsafe { data.assume_init_ref() };
+                GpuEvent {
+                    device: self
+                        .devices
+                        .iter()
+                        .position(|d| *d == data.processor_handle),
+                    kind: data.event,
+                    message: c_buffer_to_string(&data.message),
+                }
+            })
+            .collect())
+    }
+}
+
+impl Iterator for EventListener {
+    type Item = Result<GpuEvent, AmdError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pending.is_empty() {
+            match self.wait() {
+                Ok(events) => self.pending.extend(events),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        self.pending.pop_front().map(Ok)
+    }
+}
+
+impl Drop for EventListener {
+    fn drop(&mut self) {
+        for &device in &self.devices {
+            let _ = self.amdsmi.call_raw("stop_event_notification", |raw| {
+                // SAFETY: The notification has been initialized on the device, and is not used after this call.
+                unsafe { smi_call!(raw, amdsmi_stop_gpu_event_notification(device)) }
+            });
+        }
+    }
+}
+
+impl AmdProcessorHandle {
+    /// Starts listening to some events of the GPU device, see [`EventListener`].
+    pub fn event_listener(
+        &self,
+        events: &[AmdNotificationType],
+        timeout: Duration,
+    ) -> Result<EventListener, AmdError> {
+        EventListener::new(&[self], events, timeout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::event_mask;
+    use crate::{error::AmdError, metrics::AmdNotificationType};
+
+    #[test]
+    fn mask() {
+        assert_eq!(event_mask(&[]).unwrap(), 0);
+        assert_eq!(
+            event_mask(&[
+                AmdNotificationType::AMDSMI_EVT_NOTIF_VMFAULT,
+                AmdNotificationType::AMDSMI_EVT_NOTIF_THERMAL_THROTTLE,
+                AmdNotificationType::AMDSMI_EVT_NOTIF_GPU_POST_RESET,
+                AmdNotificationType::AMDSMI_EVT_NOTIF_NONE,
+            ])
+            .unwrap(),
+            0b1011
+        );
+        assert_eq!(
+            event_mask(&[AmdNotificationType::AMDSMI_EVT_NOTIF_LAST]).unwrap(),
+            1 << 12
+        );
+        assert!(matches!(
+            event_mask(&[crate::bindings::amdsmi_evt_notification_type_t(14)]),
+            Err(AmdError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            event_mask(&[crate::bindings::amdsmi_evt_notification_type_t(65)]),
+            Err(AmdError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,70 @@
+//! Control mode of the fans of the GPUs.
+//!
+//! The library sets the fan speed (which switches the fan to the manual mode) and resets the fan to the
+//! automatic mode, but cannot tell in which mode the fan is. The mode is read from the `pwm1_enable` file
+//! of the hwmon directory of the device, so that a GPU left in manual mode after an experiment can be detected.
+
+use std::{fmt, fs, path::Path};
+
+/// Control mode of the fan of a device, as reported by the kernel in `hwmon/hwmon*/pwm1_enable`.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum FanControlMode {
+    /// The fan is not controlled: it runs at full speed.
+    Disabled,
+    /// The fan speed has been set manually, and is not adjusted to the temperature.
+    Manual,
+    /// The fan speed is controlled by the firmware.
+    Automatic,
+}
+
+impl FanControlMode {
+    /// Parses the content of the `pwm1_enable` file.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "0" => Some(Self::Disabled),
+            "1" => Some(Self::Manual),
+            "2" => Some(Self::Automatic),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the fan is not controlled by the firmware.
+    pub fn is_overridden(&self) -> bool {
+        !matches!(self, Self::Automatic)
+    }
+}
+
+impl fmt::Display for FanControlMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Disabled => "disabled",
+            Self::Manual => "manual",
+            Self::Automatic => "auto",
+        })
+    }
+}
+
+/// Reads the fan control mode of the device whose sysfs directory is `sysfs_path`.
+///
+/// Returns `None` if the mode cannot be read (no hwmon directory, no fan, or unknown value).
+pub(crate) fn read_fan_control_mode(sysfs_path: &Path) -> Option<FanControlMode> {
+    fs::read_dir(sysfs_path.join("hwmon"))
+        .ok()?
+        .filter_map(Result::ok)
+        .find_map(|entry| fs::read_to_string(entry.path().join("pwm1_enable")).ok())
+        .and_then(|value| FanControlMode::parse(&value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FanControlMode;
+
+    #[test]
+    fn parse() {
+        assert_eq!(FanControlMode::parse("1\n"), Some(FanControlMode::Manual));
+        assert_eq!(FanControlMode::parse("2"), Some(FanControlMode::Automatic));
+        assert_eq!(FanControlMode::parse("auto"), None);
+        assert!(FanControlMode::Disabled.is_overridden());
+        assert!(!FanControlMode::Automatic.is_overridden());
+    }
+}
@@ -0,0 +1,306 @@
+//! Helpers to wrap the functions of the library that this crate does not wrap (yet).
+//!
+//! The functions of the library follow a few patterns: writing a scalar or a struct to an output pointer,
+//! filling a list after returning the number of elements, or writing a string into a buffer.
+//! [`RawCall`] implements these patterns once, so that a new function can be wrapped in a few lines,
+//! inside this crate or downstream through [`AmdProcessorHandle::call_raw`] and [`AmdSmi::call_raw`].
+//!
+//! This module exposes the low-level bindings: it follows the version of the bindings,
+//! and is not covered by the stability of the rest of the API.
+//!
+//! # Example
+//! ```no_run
+//! use amd_smi_wrapper::{AmdSmi, AmdInitFlags, lib_call, smi_call};
+//!
+//! let amdsmi = AmdSmi::init(AmdInitFlags::AMDSMI_INIT_AMD_GPUS).unwrap();
+//! let device = amdsmi.iter_processors().unwrap().next().unwrap().unwrap();
+//! let rpm = device
+//!     .call_raw("fan_rpms", |raw, handle| {
+//!         // SAFETY: the pointer is valid, and the function only writes an `i64` to it.
+//!         raw.read_scalar(|rpm| unsafe { lib_call!(raw.lib(), amdsmi_get_gpu_fan_rpms(handle, 0, rpm)) })
+//!     })
+//!     .unwrap();
+//! let level = device
+//!     .call_raw("perf_level", |raw, handle| {
+//!         let mut level = std::mem::MaybeUninit::uninit();
+//!         // SAFETY: the pointer is valid, and the function writes a `amdsmi_dev_perf_level_t` to it.
+//!         unsafe { smi_call!(raw, amdsmi_get_gpu_perf_level(handle, level.as_mut_ptr()))? };
+//!         // SAFETY: the call succeeded and initialized `level`.
+//!         Ok(unsafe { level.assume_init() })
+//!     })
+//!     .unwrap();
+//! ```
+//!
+//! # Panic safety
+//! The calls made through [`AmdProcessorHandle::call_raw`], [`AmdSmi::call_raw`] and the wrappers of this crate
+//! never unwind into the caller: a panic during the call is converted to [`AmdError::Panicked`].
+//! The panic hook still runs, and nothing is caught if the program is built with `panic = "abort"`.
+//!
+//! The functions that the loaded library does not export (older versions of the library) are not called:
+//! [`lib_call!`](crate::lib_call) and [`smi_call!`](crate::smi_call) check that the function has been loaded, and return the status
+//! `NOT_YET_IMPLEMENTED`, which is [unavailable](AmdError::is_unavailable), instead. Calling the methods of
+//! [`libamd_smi`] directly panics on such functions.
+//!
+//! # Invariant checks
+//! With the feature `debug-ffi`, the arguments and the results of the calls are checked against the invariants
+//! that the library is supposed to respect: the enum arguments must be known values, and the library must not
+//! report more elements than the buffers can hold. A violation returns an error instead of being silently
+//! clamped. The checks cost a few comparisons per call, and are meant for debugging.
+//!
+//! [`AmdProcessorHandle::call_raw`]: crate::handles::AmdProcessorHandle::call_raw
+
+use std::{
+    any::Any,
+    ffi::c_char,
+    fmt::Debug,
+    mem::MaybeUninit,
+    panic::{self, AssertUnwindSafe},
+    ptr::null_mut,
+};
+
+use crate::{
+    AmdSmi,
+    bindings::{amdsmi_status_t, libamd_smi},
+    error::AmdError,
+    utils::StringBuffer,
+};
+
+/// Runs the call `f` to the library, and converts a panic to an error instead of unwinding into the caller.
+///
+/// `f` must not leave shared state inconsistent if it panics: it only fills its own buffers.
+pub(crate) fn guarded<T>(
+    function: &'static str,
+    f: impl FnOnce() -> Result<T, AmdError>,
+) -> Result<T, AmdError> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(panic_error(function, payload)))
+}
+
+fn panic_error(function: &'static str, payload: Box<dyn Any + Send>) -> AmdError {
+    let message = match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .unwrap_or_default(),
+    };
+    AmdError::Panicked { function, message }
+}
+
+/// With the feature `debug-ffi`, checks that `value` is one of the `known` values of its enum,
+/// before it is passed to the library, and returns [`AmdError::InvalidArgument`] otherwise. Does nothing
+/// without the feature.
+#[inline]
+pub(crate) fn check_enum<E: Copy + PartialEq + Debug>(
+    value: E,
+    known: &[E],
+) -> Result<(), AmdError> {
+    if cfg!(feature = "debug-ffi") && !known.contains(&value) {
+        return Err(AmdError::InvalidArgument(format!(
+            "unknown value {value:?}"
+        )));
+    }
+    Ok(())
+}
+
+/// With the feature `debug-ffi`, checks that the library has not reported more elements (`written`)
+/// than the buffer can hold (`capacity`), and returns [`AmdError::InvalidData`] otherwise. Does nothing
+/// without the feature: the callers clamp the number of elements.
+#[inline]
+pub(crate) fn check_buffer(written: usize, capacity: usize) -> Result<(), AmdError> {
+    if cfg!(feature = "debug-ffi") && written > capacity {
+        return Err(AmdError::InvalidData(format!(
+            "the library reported {written} elements in a buffer of {capacity}"
+        )));
+    }
+    Ok(())
+}
+
+/// Calls a function of the low-level bindings [`libamd_smi`], if the loaded library exports it.
+///
+/// `lib_call!(lib, function(args...))` calls `lib.function(args...)` and returns its status, or returns the status
+/// `NOT_YET_IMPLEMENTED` without evaluating the arguments if the function has not been loaded. Like the function,
+/// it must be used in an `unsafe` block.
+#[macro_export]
+macro_rules! lib_call {
+    ($lib:expr, $function:ident($($arg:expr),* $(,)?)) => {
+        match &$lib.$function {
+            Ok(function) => function($($arg),*),
+            Err(_) => $crate::error::AmdStatus::AMDSMI_STATUS_NOT_YET_IMPLEMENTED,
+        }
+    };
+}
+
+/// Calls a function of the library through a [`RawCall`] and checks the returned status.
+///
+/// `smi_call!(raw, function(args...))` calls `raw.lib().function(args...)` with [`lib_call!`](crate::lib_call) and converts the
+/// status with [`RawCall::check`]. Like the function, it must be used in an `unsafe` block.
+#[macro_export]
+macro_rules! smi_call {
+    ($raw:expr, $function:ident($($arg:expr),* $(,)?)) => {
+        $raw.check($crate::lib_call!($raw.lib(), $function($($arg),*)))
+    };
+}
+
+/// Access to the library during a call, given by [`AmdProcessorHandle::call_raw`](crate::handles::AmdProcessorHandle::call_raw)
+/// and [`AmdSmi::call_raw`].
+///
+/// The helpers check the status returned by the library, and convert it to an [`AmdError`].
+#[derive(Clone, Copy)]
+pub struct RawCall<'a> {
+    amdsmi: &'a AmdSmi,
+}
+
+impl<'a> RawCall<'a> {
+    pub(crate) fn new(amdsmi: &'a AmdSmi) -> Self {
+        Self { amdsmi }
+    }
+
+    /// Returns the low-level bindings of the library.
+    pub fn lib(&self) -> &'a libamd_smi {
+        &self.amdsmi.amdsmi.amdsmi
+    }
+
+    /// Converts the status returned by the library to a result.
+    pub fn check(&self, status: amdsmi_status_t) -> Result<(), AmdError> {
+        self.amdsmi.check_status(status)
+    }
+
+    /// Calls `f` with a pointer to a value initialized with `T::default()`, and returns the value
+    /// that the library has written to it.
+    pub fn read_scalar<T: Default>(
+        &self,
+        f: impl FnOnce(*mut T) -> amdsmi_status_t,
+    ) -> Result<T, AmdError> {
+        let mut value = T::default();
+        self.check(f(&mut value))?;
+        Ok(value)
+    }
+
+    /// Calls `f` with a pointer to an uninitialized struct, and returns the struct that the library
+    /// has written to it.
+    ///
+    /// # Safety
+    /// When it returns `SUCCESS`, `f` must have fully initialized the struct.
+    pub unsafe fn read_struct<T>(
+        &self,
+        f: impl FnOnce(*mut T) -> amdsmi_status_t,
+    ) -> Result<T, AmdError> {
+        let mut value = MaybeUninit::<T>::uninit();
+        self.check(f(value.as_mut_ptr()))?;
+        // SAFETY: The call succeeded, and the caller guarantees that it initialized `value`.
+        Ok(unsafe { value.assume_init() })
+    }
+
+    /// Reads a list with the count-then-fill pattern of the library.
+    ///
+    /// `f` is called with a pointer to the number of elements and a null pointer, and must write the
+    /// number of elements. It is then called again with a buffer of that many copies of `init`,
+    /// and must write the elements and their number. The list is truncated to the number written by `f`.
+    pub fn read_list<T: Clone>(
+        &self,
+        init: T,
+        mut f: impl FnMut(*mut u32, *mut T) -> amdsmi_status_t,
+    ) -> Result<Vec<T>, AmdError> {
+        let mut count = 0u32;
+        self.check(f(&mut count, null_mut()))?;
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+        let mut list = vec![init; count as usize];
+        self.check(f(&mut count, list.as_mut_ptr()))?;
+        check_buffer(count as usize, list.len())?;
+        list.truncate(count as usize);
+        Ok(list)
+    }
+
+    /// Calls `f` with a buffer of `N` characters and its size, and returns the string that the library
+    /// has written to it.
+    ///
+    /// Invalid UTF-8 sequences are reported with [`AmdError::InvalidString`], like [`StringBuffer::to_string`]
+    /// does. Use [`read_string_lossy`](Self::read_string_lossy) to replace them instead.
+    pub fn read_string<const N: usize>(
+        &self,
+        f: impl FnOnce(*mut c_char, usize) -> amdsmi_status_t,
+    ) -> Result<String, AmdError> {
+        self.read_buffer::<N>(f)?.to_string()
+    }
+
+    /// Like [`read_string`](Self::read_string), but replaces the invalid UTF-8 sequences.
+    pub fn read_string_lossy<const N: usize>(
+        &self,
+        f: impl FnOnce(*mut c_char, usize) -> amdsmi_status_t,
+    ) -> Result<String, AmdError> {
+        Ok(self.read_buffer::<N>(f)?.to_string_lossy())
+    }
+
+    fn read_buffer<const N: usize>(
+        &self,
+        f: impl FnOnce(*mut c_char, usize) -> amdsmi_status_t,
+    ) -> Result<StringBuffer<N>, AmdError> {
+        let mut buffer = StringBuffer::<N>::new();
+        let capacity = buffer.capacity();
+        self.check(f(buffer.as_mut_ptr(), capacity))?;
+        Ok(buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::guarded;
+    use crate::error::{AmdError, AmdStatus};
+
+    #[test]
+    fn missing_functions() {
+        // same shape as the fields of the bindings
+        struct Lib {
+            amdsmi_get_gpu_fan_rpms: Result<unsafe extern "C" fn(u32) -> AmdStatus, ()>,
+            amdsmi_get_gpu_fan_speed: Result<unsafe extern "C" fn(u32) -> AmdStatus, ()>,
+        }
+        unsafe extern "C" fn fan_rpms(sensor: u32) -> AmdStatus {
+            if sensor == 0 {
+                AmdStatus::AMDSMI_STATUS_SUCCESS
+            } else {
+                AmdStatus::AMDSMI_STATUS_INVAL
+            }
+        }
+        let lib = Lib {
+            amdsmi_get_gpu_fan_rpms: Ok(fan_rpms),
+            amdsmi_get_gpu_fan_speed: Err(()),
+        };
+        // SAFETY: the functions have no preconditions.
+        unsafe {
+            assert_eq!(
+                crate::lib_call!(lib, amdsmi_get_gpu_fan_rpms(0)),
+                AmdStatus::AMDSMI_STATUS_SUCCESS
+            );
+            assert_eq!(
+                crate::lib_call!(lib, amdsmi_get_gpu_fan_rpms(1)),
+                AmdStatus::AMDSMI_STATUS_INVAL
+            );
+            // the arguments are not evaluated
+            let mut evaluated = false;
+            assert_eq!(
+                crate::lib_call!(
+                    lib,
+                    amdsmi_get_gpu_fan_speed({
+                        evaluated = true;
+                        0
+                    })
+                ),
+                AmdStatus::AMDSMI_STATUS_NOT_YET_IMPLEMENTED
+            );
+            assert!(!evaluated);
+        }
+    }
+
+    #[test]
+    fn panics() {
+        let panicked = guarded::<()>("device_uuid", || panic!("index out of bounds")).unwrap_err();
+        assert!(matches!(
+            panicked,
+            AmdError::Panicked { function: "device_uuid", ref message } if message == "index out of bounds"
+        ));
+        assert_eq!(guarded("device_uuid", || Ok(3)).unwrap(), 3);
+    }
+}
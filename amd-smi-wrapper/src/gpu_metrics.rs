@@ -0,0 +1,531 @@
+//! GPU metrics table: the metrics reported by the firmware of the GPU, read in one query.
+//!
+//! The content of the table depends on its version (see [`AmdGpuMetrics::header`]) and on the GPU family.
+//! The firmware fills the fields that it does not report with the maximal value of their type:
+//! the accessors of [`AmdGpuMetrics`] skip them, or return `None`.
+
+use std::time::Duration;
+
+use crate::{
+    bindings::{amdsmi_gpu_metrics_t, amdsmi_gpu_xcp_metrics_t},
+    metrics::{AmdMetricsHeader, AmdRailVoltages},
+    throttle::ThrottleStatus,
+    timebase::DriverTimestamp,
+};
+
+/// GPU metrics table of a device: [`amdsmi_gpu_metrics_t`].
+#[derive(Debug, Clone)]
+pub struct AmdGpuMetrics {
+    raw: Box<amdsmi_gpu_metrics_t>,
+}
+
+impl AmdGpuMetrics {
+    pub(crate) fn new(raw: Box<amdsmi_gpu_metrics_t>) -> Self {
+        Self { raw }
+    }
+
+    /// Returns the raw table, for the fields that have no accessor.
+    pub fn raw(&self) -> &amdsmi_gpu_metrics_t {
+        &self.raw
+    }
+
+    /// Returns the header of the table, which contains its version.
+    pub fn header(&self) -> AmdMetricsHeader {
+        self.raw.common_header.into()
+    }
+
+    /// Returns the time at which the driver has read the table, on the boot clock (see [`timebase`](crate::timebase)).
+    pub fn timestamp(&self) -> Option<DriverTimestamp> {
+        let ns = self.raw.system_clock_counter;
+        (ns != 0 && ns != u64::MAX).then_some(DriverTimestamp::from_nanos(ns))
+    }
+
+    /// Returns the time at which the firmware has filled the table, on the clock of the firmware.
+    ///
+    /// This clock is not related to the clocks of the host: only the differences between two tables of the same GPU are meaningful.
+    pub fn firmware_timestamp(&self) -> Option<Duration> {
+        let ticks = self.raw.firmware_timestamp;
+        // the resolution of the firmware clock is 10 ns
+        (ticks != 0 && ticks != u64::MAX).then(|| Duration::from_nanos(ticks.saturating_mul(10)))
+    }
+
+    /// Returns the number of partitions of the GPU, at least 1.
+    pub fn partition_count(&self) -> usize {
+        match self.raw.num_partition {
+            0 | u16::MAX => 1,
+            n => (n as usize).min(self.raw.xcp_stats.len()),
+        }
+    }
+
+    /// Returns the utilization of the video engines (VCN) and of the JPEG engines, in %.
+    ///
+    /// On partitioned GPUs (MI300 and later), the engines are reported per partition: the engines of all
+    /// the partitions are listed, in the order of the partitions.
+    pub fn multimedia_activity(&self) -> AmdMultimediaActivity {
+        let mut activity = AmdMultimediaActivity {
+            vcn: available(&self.raw.vcn_activity),
+            jpeg: available(&self.raw.jpeg_activity),
+        };
+        if activity.vcn.is_empty() && activity.jpeg.is_empty() {
+            for xcp in &self.raw.xcp_stats[..self.partition_count()] {
+                activity.vcn.extend(available(&xcp.vcn_busy));
+                activity.jpeg.extend(available(&xcp.jpeg_busy));
+            }
+        }
+        activity
+    }
+
+    /// Returns the utilization of each instance of the video engines (VCN), in %, with its index in the table.
+    ///
+    /// Unlike [`multimedia_activity`](Self::multimedia_activity), the unavailable entries are kept, so that an
+    /// instance keeps the same index from one table to the other. On partitioned GPUs, there is one
+    /// [`AmdEngineInstances`] per partition.
+    pub fn vcn_instances(&self) -> Vec<AmdEngineInstances<'_>> {
+        self.engine_instances(&self.raw.vcn_activity, |xcp| &xcp.vcn_busy)
+    }
+
+    /// Returns the utilization of each instance of the JPEG engines, in %, with its index in the table.
+    ///
+    /// See [`vcn_instances`](Self::vcn_instances).
+    pub fn jpeg_instances(&self) -> Vec<AmdEngineInstances<'_>> {
+        self.engine_instances(&self.raw.jpeg_activity, |xcp| &xcp.jpeg_busy)
+    }
+
+    fn engine_instances<'a>(
+        &'a self,
+        device: &'a [u16],
+        partition: impl Fn(&'a amdsmi_gpu_xcp_metrics_t) -> &'a [u16],
+    ) -> Vec<AmdEngineInstances<'a>> {
+        let device = AmdEngineInstances {
+            partition: None,
+            activity: device,
+        };
+        if device.valid_count() > 0 {
+            return vec![device];
+        }
+        let partitions: Vec<_> = self.raw.xcp_stats[..self.partition_count()]
+            .iter()
+            .enumerate()
+            .map(|(i, xcp)| AmdEngineInstances {
+                partition: Some(i),
+                activity: partition(xcp),
+            })
+            .collect();
+        if partitions.iter().any(|p| p.valid_count() > 0) {
+            partitions
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Decodes the main metrics of the table: temperatures, activity, power, clocks, throttle status
+    /// and XGMI counters. See [`AmdDeviceMetrics`].
+    pub fn device_metrics(&self) -> AmdDeviceMetrics {
+        let raw = &self.raw;
+        AmdDeviceMetrics {
+            timestamp: self.timestamp(),
+            temperature: AmdMetricsTemperatures {
+                edge: valid_u16(raw.temperature_edge),
+                hotspot: valid_u16(raw.temperature_hotspot),
+                mem: valid_u16(raw.temperature_mem),
+                vrgfx: valid_u16(raw.temperature_vrgfx),
+                vrsoc: valid_u16(raw.temperature_vrsoc),
+                vrmem: valid_u16(raw.temperature_vrmem),
+                hbm: available(&raw.temperature_hbm),
+            },
+            gfx_activity: valid_u16(raw.average_gfx_activity),
+            umc_activity: valid_u16(raw.average_umc_activity),
+            mm_activity: valid_u16(raw.average_mm_activity),
+            average_socket_power: valid_u16(raw.average_socket_power),
+            current_socket_power: valid_u16(raw.current_socket_power),
+            energy_accumulator: valid_u64(raw.energy_accumulator),
+            // from version 1.4, the clocks are reported per instance
+            clocks: AmdMetricsClocks {
+                gfx: valid_u16(raw.current_gfxclk)
+                    .or_else(|| first_available(&raw.current_gfxclks)),
+                soc: valid_u16(raw.current_socclk)
+                    .or_else(|| first_available(&raw.current_socclks)),
+                mem: valid_u16(raw.current_uclk),
+                vclk0: valid_u16(raw.current_vclk0)
+                    .or_else(|| first_available(&raw.current_vclk0s)),
+                dclk0: valid_u16(raw.current_dclk0)
+                    .or_else(|| first_available(&raw.current_dclk0s)),
+                average_gfx: valid_u16(raw.average_gfxclk_frequency),
+            },
+            throttle_status: valid_u64(raw.indep_throttle_status).map(ThrottleStatus),
+            fan_speed: valid_u16(raw.current_fan_speed),
+            xgmi: raw
+                .xgmi_read_data_acc
+                .iter()
+                .zip(&raw.xgmi_write_data_acc)
+                .enumerate()
+                .filter(|(_, (read, write))| **read != u64::MAX && **write != u64::MAX)
+                .map(|(link, (read, write))| AmdXgmiCounters {
+                    link,
+                    read_kb: *read,
+                    write_kb: *write,
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the utilization of the memory bandwidth.
+    pub fn memory_bandwidth(&self) -> AmdMemoryBandwidth {
+        AmdMemoryBandwidth {
+            utilization: Some(self.raw.average_umc_activity).filter(|v| *v != u16::MAX),
+            max_bandwidth: Some(self.raw.vram_max_bandwidth).filter(|v| *v != u64::MAX && *v != 0),
+        }
+    }
+
+    /// Returns the voltages of the power rails (table version 1.3 or later).
+    pub fn rail_voltages(&self) -> AmdRailVoltages {
+        let valid = |v: u16| Some(v as u64).filter(|v| *v != 0 && *v != u16::MAX as u64);
+        AmdRailVoltages {
+            gfx: valid(self.raw.voltage_gfx),
+            soc: valid(self.raw.voltage_soc),
+            mem: valid(self.raw.voltage_mem),
+        }
+    }
+
+    /// Returns the instantaneous activity of the graphic core of each XCD (accelerator complex die), in %.
+    ///
+    /// Only the GPUs made of several dies (MI300 and later) report it, with a table of version 1.6 or later.
+    pub fn xcd_activity(&self) -> Vec<AmdXcdActivity> {
+        let mut res = Vec::new();
+        for (partition, xcp) in self.raw.xcp_stats[..self.partition_count()]
+            .iter()
+            .enumerate()
+        {
+            for (xcd, &busy) in xcp.gfx_busy_inst.iter().enumerate() {
+                if busy != u32::MAX {
+                    res.push(AmdXcdActivity {
+                        partition,
+                        xcd,
+                        gfx_activity: busy,
+                    });
+                }
+            }
+        }
+        res
+    }
+}
+
+/// Main metrics of a GPU, decoded from one [`AmdGpuMetrics`] table, see [`AmdGpuMetrics::device_metrics`].
+///
+/// All the values come from the same table: they are consistent with each other, and reading them costs
+/// one call to the library. The values that the GPU does not report are `None` or empty.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AmdDeviceMetrics {
+    /// Time at which the driver has read the table.
+    pub timestamp: Option<DriverTimestamp>,
+    /// Temperatures, in °C.
+    pub temperature: AmdMetricsTemperatures,
+    /// Average activity of the graphics engine, in %.
+    pub gfx_activity: Option<u16>,
+    /// Average activity of the memory controller, in %.
+    pub umc_activity: Option<u16>,
+    /// Average activity of the multimedia engines, in %.
+    pub mm_activity: Option<u16>,
+    /// Socket power averaged by the firmware, in W.
+    pub average_socket_power: Option<u16>,
+    /// Instantaneous socket power, in W (MI300 and later).
+    pub current_socket_power: Option<u16>,
+    /// Energy counter, in units of the energy resolution of the GPU
+    /// (see [`AmdEnergyConsumption`](crate::metrics::AmdEnergyConsumption)).
+    pub energy_accumulator: Option<u64>,
+    /// Current frequencies of the clocks, in MHz.
+    pub clocks: AmdMetricsClocks,
+    /// Reasons why the GPU is throttled.
+    pub throttle_status: Option<ThrottleStatus>,
+    /// Fan speed, in RPM.
+    pub fan_speed: Option<u16>,
+    /// Data transferred on each XGMI link since the boot.
+    pub xgmi: Vec<AmdXgmiCounters>,
+}
+
+/// Temperatures of a GPU in the metrics table, in °C.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AmdMetricsTemperatures {
+    /// Temperature at the edge of the die.
+    pub edge: Option<u16>,
+    /// Temperature of the hottest point of the die (junction).
+    pub hotspot: Option<u16>,
+    /// Temperature of the VRAM.
+    pub mem: Option<u16>,
+    /// Temperature of the voltage regulator of the graphics core.
+    pub vrgfx: Option<u16>,
+    /// Temperature of the voltage regulator of the SoC.
+    pub vrsoc: Option<u16>,
+    /// Temperature of the voltage regulator of the memory.
+    pub vrmem: Option<u16>,
+    /// Temperature of each HBM stack.
+    pub hbm: Vec<u16>,
+}
+
+/// Frequencies of the clocks of a GPU in the metrics table, in MHz.
+///
+/// From version 1.4 of the table, the GPUs made of several dies report a frequency per instance of the clocks:
+/// the first reported instance is used, the others are in [`AmdGpuMetrics::raw`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AmdMetricsClocks {
+    /// Frequency of the graphics clock (GFXCLK).
+    pub gfx: Option<u16>,
+    /// Frequency of the SoC clock (SOCCLK).
+    pub soc: Option<u16>,
+    /// Frequency of the memory clock (UCLK).
+    pub mem: Option<u16>,
+    /// Frequency of the first video clock (VCLK0).
+    pub vclk0: Option<u16>,
+    /// Frequency of the first decoder clock (DCLK0).
+    pub dclk0: Option<u16>,
+    /// Frequency of the graphics clock, averaged by the firmware.
+    pub average_gfx: Option<u16>,
+}
+
+/// Data transferred on an XGMI link, accumulated since the boot.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AmdXgmiCounters {
+    /// Index of the link.
+    pub link: usize,
+    /// Data read through the link, in kB.
+    pub read_kb: u64,
+    /// Data written through the link, in kB.
+    pub write_kb: u64,
+}
+
+/// Utilization of the memory bandwidth of a GPU, see [`AmdGpuMetrics::memory_bandwidth`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct AmdMemoryBandwidth {
+    /// Average activity of the memory controller (UMC), in %.
+    ///
+    /// This is the fraction of time during which the memory controller is busy transferring data:
+    /// a value close to 100% means that the workload is memory-bound.
+    pub utilization: Option<u16>,
+    /// Maximal bandwidth of the VRAM at the maximal memory clock, in GB/s (table version 1.7 or later).
+    pub max_bandwidth: Option<u64>,
+}
+
+impl AmdMemoryBandwidth {
+    /// Estimates the bandwidth currently used, in GB/s, from the utilization and the maximal bandwidth.
+    ///
+    /// This is an upper bound: the memory controller can be busy without transferring at the maximal rate.
+    pub fn estimated_bandwidth(&self) -> Option<f64> {
+        Some(self.utilization? as f64 / 100.0 * self.max_bandwidth? as f64)
+    }
+}
+
+/// Activity of one XCD of a GPU, see [`AmdGpuMetrics::xcd_activity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmdXcdActivity {
+    /// Index of the partition (XCP) that contains the XCD.
+    pub partition: usize,
+    /// Index of the XCD in its partition.
+    pub xcd: usize,
+    /// Instantaneous activity of the graphic core, in %.
+    pub gfx_activity: u32,
+}
+
+/// Utilization of the instances of a multimedia engine, in %, see [`AmdGpuMetrics::vcn_instances`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmdEngineInstances<'a> {
+    /// Index of the partition (XCP) that contains the instances, or `None` if the table reports the
+    /// instances of the whole GPU.
+    pub partition: Option<usize>,
+    /// Entries of the table, indexed by instance. The unavailable entries are `u16::MAX`.
+    pub activity: &'a [u16],
+}
+
+impl AmdEngineInstances<'_> {
+    /// Returns the number of entries that contain a value.
+    pub fn valid_count(&self) -> usize {
+        self.activity.iter().filter(|v| **v != u16::MAX).count()
+    }
+
+    /// Returns the utilization of an instance, or `None` if it is not reported.
+    pub fn get(&self, instance: usize) -> Option<u16> {
+        self.activity
+            .get(instance)
+            .copied()
+            .filter(|v| *v != u16::MAX)
+    }
+
+    /// Iterates on the reported instances, as `(index, utilization)`.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, u16)> + '_ {
+        self.activity
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(_, v)| *v != u16::MAX)
+    }
+}
+
+/// Utilization of the multimedia engines of a GPU, in %, see [`AmdGpuMetrics::multimedia_activity`].
+///
+/// The engines that the GPU does not have, or does not report, are not listed.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AmdMultimediaActivity {
+    /// Utilization of each video encoding/decoding engine (VCN).
+    pub vcn: Vec<u16>,
+    /// Utilization of each JPEG decoding engine.
+    pub jpeg: Vec<u16>,
+}
+
+impl AmdMultimediaActivity {
+    /// Returns the mean utilization of the video engines, or `None` if there is none.
+    pub fn vcn_mean(&self) -> Option<f64> {
+        mean(&self.vcn)
+    }
+
+    /// Returns the mean utilization of the JPEG engines, or `None` if there is none.
+    pub fn jpeg_mean(&self) -> Option<f64> {
+        mean(&self.jpeg)
+    }
+}
+
+fn valid_u16(value: u16) -> Option<u16> {
+    (value != u16::MAX).then_some(value)
+}
+
+fn valid_u64(value: u64) -> Option<u64> {
+    (value != u64::MAX).then_some(value)
+}
+
+/// Returns the first value that is not marked as unavailable by the firmware.
+fn first_available(values: &[u16]) -> Option<u16> {
+    values.iter().copied().find(|v| *v != u16::MAX)
+}
+
+/// Returns the values that are not marked as unavailable by the firmware.
+fn available(values: &[u16]) -> Vec<u16> {
+    values.iter().copied().filter(|v| *v != u16::MAX).collect()
+}
+
+fn mean(values: &[u16]) -> Option<f64> {
+    (!values.is_empty())
+        .then(|| values.iter().map(|v| *v as f64).sum::<f64>() / values.len() as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AmdGpuMetrics, AmdXcdActivity};
+    use crate::bindings::amdsmi_gpu_metrics_t;
+
+    /// Returns a table in which every metric is unavailable.
+    pub(crate) fn unavailable_table() -> Box<amdsmi_gpu_metrics_t> {
+        let mut raw = Box::<amdsmi_gpu_metrics_t>::new_uninit();
+        // SAFETY: the table only contains integers, for which any bit pattern is valid.
+        unsafe {
+            raw.as_mut_ptr().write_bytes(0xff, 1);
+            raw.assume_init()
+        }
+    }
+
+    #[test]
+    fn multimedia_activity() {
+        let mut raw = unavailable_table();
+        raw.vcn_activity[0] = 40;
+        raw.vcn_activity[1] = 60;
+        let activity = AmdGpuMetrics::new(raw).multimedia_activity();
+        assert_eq!(activity.vcn, vec![40, 60]);
+        assert_eq!(activity.vcn_mean(), Some(50.0));
+        assert_eq!(activity.jpeg_mean(), None);
+
+        // partitioned GPU: the engines are reported per partition
+        let mut raw = unavailable_table();
+        raw.num_partition = 2;
+        raw.xcp_stats[0].vcn_busy[0] = 10;
+        raw.xcp_stats[1].vcn_busy[0] = 20;
+        raw.xcp_stats[2].vcn_busy[0] = 30;
+        raw.xcp_stats[1].jpeg_busy[3] = 5;
+        let activity = AmdGpuMetrics::new(raw.clone()).multimedia_activity();
+        assert_eq!(activity.vcn, vec![10, 20]);
+        assert_eq!(activity.jpeg, vec![5]);
+
+        let metrics = AmdGpuMetrics::new(raw);
+        let jpeg = metrics.jpeg_instances();
+        assert_eq!(jpeg.len(), 2);
+        assert_eq!(jpeg[0].valid_count(), 0);
+        assert_eq!(jpeg[1].partition, Some(1));
+        assert_eq!(jpeg[1].get(3), Some(5));
+        assert_eq!(jpeg[1].iter().collect::<Vec<_>>(), vec![(3, 5)]);
+        assert!(
+            AmdGpuMetrics::new(unavailable_table())
+                .vcn_instances()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn device_metrics() {
+        let mut raw = unavailable_table();
+        raw.temperature_hotspot = 71;
+        raw.temperature_hbm[1] = 60;
+        raw.current_socket_power = 450;
+        raw.indep_throttle_status = 0;
+        raw.xgmi_read_data_acc[2] = 1000;
+        raw.xgmi_write_data_acc[2] = 2000;
+        let metrics = AmdGpuMetrics::new(raw).device_metrics();
+        assert_eq!(metrics.temperature.hotspot, Some(71));
+        assert_eq!(metrics.temperature.edge, None);
+        assert_eq!(metrics.temperature.hbm, vec![60]);
+        assert_eq!(metrics.current_socket_power, Some(450));
+        assert_eq!(metrics.average_socket_power, None);
+        assert!(metrics.throttle_status.unwrap().is_empty());
+        assert_eq!(metrics.xgmi.len(), 1);
+        assert_eq!(metrics.xgmi[0].link, 2);
+        assert_eq!(metrics.xgmi[0].write_kb, 2000);
+        assert_eq!(metrics.clocks.gfx, None);
+    }
+
+    #[test]
+    fn device_metrics_v1_4() {
+        // from version 1.4, the clocks are only reported per instance
+        let mut raw = unavailable_table();
+        raw.common_header.format_revision = 1;
+        raw.common_header.content_revision = 4;
+        raw.current_uclk = 1300;
+        raw.current_gfxclks[0] = u16::MAX;
+        raw.current_gfxclks[1] = 2100;
+        raw.current_gfxclks[2] = 2000;
+        raw.current_socclks[0] = 1200;
+        raw.current_vclk0s[0] = 800;
+        raw.current_dclk0s[3] = 700;
+        let clocks = AmdGpuMetrics::new(raw.clone()).device_metrics().clocks;
+        assert_eq!(clocks.gfx, Some(2100));
+        assert_eq!(clocks.soc, Some(1200));
+        assert_eq!(clocks.mem, Some(1300));
+        assert_eq!(clocks.vclk0, Some(800));
+        assert_eq!(clocks.dclk0, Some(700));
+
+        // the single values of the older versions are preferred
+        raw.current_gfxclk = 1900;
+        let clocks = AmdGpuMetrics::new(raw).device_metrics().clocks;
+        assert_eq!(clocks.gfx, Some(1900));
+    }
+
+    #[test]
+    fn xcd_activity() {
+        let mut raw = unavailable_table();
+        assert!(AmdGpuMetrics::new(raw.clone()).xcd_activity().is_empty());
+
+        raw.num_partition = 1;
+        raw.xcp_stats[0].gfx_busy_inst[0] = 12;
+        raw.xcp_stats[0].gfx_busy_inst[1] = 34;
+        let activity = AmdGpuMetrics::new(raw).xcd_activity();
+        assert_eq!(
+            activity,
+            vec![
+                AmdXcdActivity {
+                    partition: 0,
+                    xcd: 0,
+                    gfx_activity: 12
+                },
+                AmdXcdActivity {
+                    partition: 0,
+                    xcd: 1,
+                    gfx_activity: 34
+                },
+            ]
+        );
+    }
+}
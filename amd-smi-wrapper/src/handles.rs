@@ -1,34 +1,435 @@
 //! Handles used to manipulate the devices.
+//!
+//! # Deprecated methods
+//! The following methods of [`ProcessorHandle`] are deprecated, and will be removed in the next release:
+//!
+//! | Deprecated                 | Replacement                                                               |
+//! |----------------------------|---------------------------------------------------------------------------|
+//! | `device_power_managment`   | `device_power_management_enabled`, or `device_power_management` (details) |
+//! | `device_compute_partition` | `device_compute_partition_name`, or `device_compute_partition_mode` (typed) |
+//! | `device_memory_partition`  | `device_memory_partition_name`, or `device_memory_partition_mode` (typed) |
+//!
+//! The implementations of `ProcessorHandle` outside of this crate must implement the replacements,
+//! the deprecated methods are provided. See the changelog of the repository for the migration.
 
 use std::{
-    ffi::c_char,
-    mem::MaybeUninit,
-    ptr::{self},
+    cell::{Cell, OnceCell, RefCell},
+    collections::HashMap,
+    ffi::{c_char, c_uint},
+    fmt::Debug,
+    hash::Hash,
+    mem::{MaybeUninit, size_of},
+    path::PathBuf,
+    ptr, thread,
+    time::{Duration, Instant},
 };
 
 use crate::{
     AmdSmi,
     bindings::{
-        AMDSMI_GPU_UUID_SIZE, AMDSMI_MAX_FAN_SPEED, amdsmi_asic_info_t, amdsmi_clk_info_t,
-        amdsmi_engine_usage_t, amdsmi_power_info_t, amdsmi_proc_info_t, amdsmi_processor_handle,
-        amdsmi_socket_handle, amdsmi_status_t,
+        amd_metrics_table_header_t, amdsmi_asic_info_t, amdsmi_board_info_t, amdsmi_clk_info_t,
+        amdsmi_cper_hdr_t, amdsmi_dpm_policy_t, amdsmi_driver_info_t, amdsmi_engine_usage_t,
+        amdsmi_enumeration_info_t, amdsmi_error_count_t, amdsmi_frequencies_t, amdsmi_fw_info_t,
+        amdsmi_gpu_metrics_t, amdsmi_kfd_info_t, amdsmi_link_metrics_t, amdsmi_od_volt_freq_data_t,
+        amdsmi_pcie_info_t, amdsmi_power_cap_info_t, amdsmi_power_info_t,
+        amdsmi_power_profile_status_t, amdsmi_proc_info_t, amdsmi_processor_handle,
+        amdsmi_ras_feature_t, amdsmi_retired_page_record_t, amdsmi_socket_handle, amdsmi_status_t,
+        amdsmi_utilization_counter_t, amdsmi_utilization_counter_type_t, amdsmi_vbios_info_t,
+        amdsmi_xgmi_info_t, amdsmi_xgmi_link_status_t,
     },
-    error::AmdError,
+    consts::{GPU_UUID_SIZE, MAX_FAN_SPEED, MAX_STRING_LENGTH},
+    counters::{self, PerfCounter, XgmiThroughput, XgmiThroughputMonitor},
+    dynamic::{self, MetricKind, MetricUnit, MetricValue, RecentValues, UnsupportedCache},
+    error::{AmdError, AmdStatus, ResultExt},
+    fan_mode::{self, FanControlMode},
+    ffi::{self, RawCall},
+    gpu_metrics::{
+        AmdDeviceMetrics, AmdGpuMetrics, AmdMemoryBandwidth, AmdMultimediaActivity, AmdXcdActivity,
+    },
+    instrument::CallSpan,
+    interconnect::{self, AmdLinkInfo, AmdP2pCapability},
+    kfd::{self, KfdNodeProperties, KfdProcessFaults},
+    lib_call,
     metrics::*,
-    utils::c_buffer_to_string,
+    power_state::{self, RuntimePowerState},
+    report::{self, AmdCapabilityReport},
+    snapshot::DeviceSnapshot,
+    timebase::DriverTimestamp,
+    units::{Quantity, UnitSystem, power_cap_unit},
+    utils::StringBuffer,
 };
 
 #[cfg(feature = "mock")]
 use mockall::automock;
 
+/// Maximal number of power sensors of a GPU, one per Package Power Tracking (PPT) type.
+///
+/// On some platforms, the sensor index is ignored by the library: probing must stop somewhere.
+const MAX_POWER_SENSORS: u32 = 2;
+
+/// Maximal number of fan sensors of a GPU probed by [`ProcessorHandle::device_fans`].
+///
+/// The probing must stop somewhere if the library accepts any sensor index.
+const MAX_FAN_SENSORS: u32 = 8;
+
+/// Initial size of the buffer that receives the CPER entries, doubled when it cannot hold one entry.
+const CPER_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Maximal size of the buffer that receives the CPER entries.
+const CPER_MAX_BUFFER_SIZE: usize = 16 * 1024 * 1024;
+
+/// Maximal number of CPER entries retrieved by one call to the library.
+const CPER_MAX_ENTRIES: usize = 64;
+
+/// Minimal number of elements of the buffer that receives the process list.
+const MIN_PROCESS_BUFFER_SIZE: usize = 16;
+
+/// Returns the new size of a buffer of `current` elements that must hold at least `needed` elements.
+///
+/// The size at least doubles, with some headroom, to absorb the variations of the number of elements.
+fn grown_buffer_size(current: usize, needed: usize) -> usize {
+    needed
+        .saturating_add(needed / 2)
+        .max(current.saturating_mul(2))
+        .max(MIN_PROCESS_BUFFER_SIZE)
+}
+
 pub struct AmdSocketHandle {
     pub(crate) amdsmi: AmdSmi,
-    pub(crate) inner: amdsmi_socket_handle,
+    inner: Cell<amdsmi_socket_handle>,
+    /// Generation of the library when `inner` was obtained.
+    generation: Cell<u64>,
+    /// Identifier of the socket, used to resolve the handle again after a re-initialization.
+    socket_id: Option<String>,
 }
 
 pub struct AmdProcessorHandle {
     pub(crate) amdsmi: AmdSmi,
-    pub(crate) inner: amdsmi_processor_handle,
+    pub(crate) inner: Cell<amdsmi_processor_handle>,
+    /// Generation of the library when `inner` was obtained.
+    generation: Cell<u64>,
+    /// PCI identifier used to resolve the handle again after a re-initialization.
+    bdf_id: Option<u64>,
+    /// Identification of the device, read on first use.
+    identity: OnceCell<AmdDeviceIdentity>,
+    /// Temperature sensors that report each metric, probed on first use.
+    temperature_sensors: RefCell<SensorCache<AmdTemperatureMetric, AmdTemperatureType>>,
+    /// Voltage sensors that report each metric, probed on first use.
+    voltage_sensors: RefCell<SensorCache<AmdVoltageMetric, AmdVoltageType>>,
+    /// Metrics that are not available on the device, see [`AmdSmi::set_unsupported_cache_ttl`].
+    unsupported: RefCell<UnsupportedCache>,
+    /// Last values of the metrics, returned when they are read faster than the rate limits.
+    recent: RefCell<RecentValues>,
+    /// Buffer that receives the process list, kept across queries.
+    process_buffer: RefCell<Vec<MaybeUninit<amdsmi_proc_info_t>>>,
+}
+
+/// Sensors that report each metric, see [`AmdProcessorHandle::probe_sensors`].
+struct SensorCache<M, S> {
+    sensors: HashMap<M, Vec<S>>,
+    /// Epoch of the cache, compared to the epoch of the cache of unavailable metrics to clear it on reset.
+    epoch: u64,
+}
+
+impl<M: Eq + Hash, S: Clone> SensorCache<M, S> {
+    fn new() -> Self {
+        Self {
+            sensors: HashMap::new(),
+            epoch: 0,
+        }
+    }
+
+    /// Returns the sensors that report `metric`, if they have been probed in the epoch `epoch`.
+    fn get(&mut self, metric: M, epoch: u64) -> Option<Vec<S>> {
+        if self.epoch != epoch {
+            self.sensors.clear();
+            self.epoch = epoch;
+        }
+        self.sensors.get(&metric).cloned()
+    }
+
+    fn insert(&mut self, metric: M, sensors: Vec<S>) {
+        self.sensors.insert(metric, sensors);
+    }
+
+    fn clear(&mut self) {
+        self.sensors.clear();
+    }
+}
+
+impl AmdProcessorHandle {
+    pub(crate) fn new(amdsmi: AmdSmi, inner: amdsmi_processor_handle) -> Self {
+        let bdf_id = if amdsmi.auto_recovery() {
+            amdsmi.raw_bdf_id(inner).ok()
+        } else {
+            None
+        };
+        Self {
+            generation: Cell::new(amdsmi.generation()),
+            amdsmi,
+            inner: Cell::new(inner),
+            bdf_id,
+            identity: OnceCell::new(),
+            temperature_sensors: RefCell::new(SensorCache::new()),
+            voltage_sensors: RefCell::new(SensorCache::new()),
+            unsupported: RefCell::new(UnsupportedCache::default()),
+            recent: RefCell::new(RecentValues::default()),
+            process_buffer: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns the generation of the library in which this handle has been obtained (or resolved again).
+    ///
+    /// See [`AmdSmi::generation`].
+    pub fn generation(&self) -> u64 {
+        self.generation.get()
+    }
+
+    /// Calls `f` with the raw processor handle.
+    ///
+    /// If the automatic recovery is enabled (see [`AmdSmi::set_auto_recovery`]) and the library has been
+    /// re-initialized, or needs to be, the handle is resolved again before calling `f`.
+    /// If the suspended devices are protected (see [`AmdSmi::set_skip_suspended`]), `f` is not called on
+    /// a suspended device.
+    pub(crate) fn call<T: Debug>(
+        &self,
+        function: &'static str,
+        f: impl Fn(amdsmi_processor_handle) -> Result<T, AmdError>,
+    ) -> Result<T, AmdError> {
+        self.call_with_args(function, None, f)
+    }
+
+    /// Like [`call`](Self::call), for a wrapper function that takes `arguments`: they are written to the
+    /// captures of the calls (see the [`instrument`](crate::instrument) module).
+    pub(crate) fn call_with_args<T: Debug>(
+        &self,
+        function: &'static str,
+        arguments: Option<&dyn Debug>,
+        f: impl Fn(amdsmi_processor_handle) -> Result<T, AmdError>,
+    ) -> Result<T, AmdError> {
+        if self.amdsmi.skip_suspended() && self.is_suspended() {
+            return Err(AmdError::DeviceSuspended);
+        }
+        if !self.amdsmi.auto_recovery() {
+            return self.invoke(function, arguments, &f);
+        }
+        if self.generation.get() != self.amdsmi.generation() {
+            self.resolve()?;
+        }
+        match self.invoke(function, arguments, &f) {
+            Err(e) if e.needs_reinit() && self.bdf_id.is_some() => {
+                self.amdsmi.reinit(self.generation.get())?;
+                self.resolve()?;
+                self.invoke(function, arguments, &f)
+            }
+            res => res,
+        }
+    }
+
+    /// Calls `f` with the low-level bindings and the raw processor handle, to call a function of the library
+    /// that this crate does not wrap.
+    ///
+    /// The call goes through the same path as the wrappers of this crate: recovery, serialization and
+    /// instrumentation, under the name `function`. See the [`ffi`](crate::ffi) module.
+    pub fn call_raw<T: Debug>(
+        &self,
+        function: &'static str,
+        f: impl Fn(RawCall, amdsmi_processor_handle) -> Result<T, AmdError>,
+    ) -> Result<T, AmdError> {
+        self.call_raw_with_args(function, None, f)
+    }
+
+    /// Like [`call_raw`](Self::call_raw), for a wrapper function that takes `arguments`.
+    pub(crate) fn call_raw_with_args<T: Debug>(
+        &self,
+        function: &'static str,
+        arguments: Option<&dyn Debug>,
+        f: impl Fn(RawCall, amdsmi_processor_handle) -> Result<T, AmdError>,
+    ) -> Result<T, AmdError> {
+        self.call_with_args(function, arguments, |handle| {
+            f(RawCall::new(&self.amdsmi), handle)
+        })
+    }
+
+    /// Returns the raw handle, resolved again if the automatic recovery is enabled and the library has been
+    /// re-initialized, to pass it as an argument of a call made on another handle.
+    pub(crate) fn current_handle(&self) -> Result<amdsmi_processor_handle, AmdError> {
+        if self.amdsmi.auto_recovery() && self.generation.get() != self.amdsmi.generation() {
+            self.resolve()?;
+        }
+        Ok(self.inner.get())
+    }
+
+    /// Calls `f` on the raw handle, while holding the lock that serializes the calls to the library
+    /// (see the [`serialize`](crate::serialize) module).
+    /// The call is recorded by the [`instrument`](crate::instrument) module, and a panic is converted
+    /// to an error (see the [`ffi`](crate::ffi) module).
+    fn invoke<T: Debug>(
+        &self,
+        function: &'static str,
+        arguments: Option<&dyn Debug>,
+        f: &impl Fn(amdsmi_processor_handle) -> Result<T, AmdError>,
+    ) -> Result<T, AmdError> {
+        let _ffi = self.amdsmi.amdsmi.ffi_lock.lock();
+        let span = CallSpan::start(function, &self.amdsmi.amdsmi).with_arguments(arguments);
+        let result = ffi::guarded(function, || f(self.inner.get()));
+        span.finish(|| self.pci_address_for_log(), &result);
+        result
+    }
+
+    /// Returns the PCI address of the device, without going through [`call`](Self::call).
+    fn pci_address_for_log(&self) -> Option<AmdPciAddress> {
+        let bdf_id = match self.bdf_id {
+            Some(bdf_id) => bdf_id,
+            None => self.amdsmi.raw_bdf_id(self.inner.get()).ok()?,
+        };
+        Some(AmdPciAddress::from_bdf_id(bdf_id))
+    }
+
+    /// Reads a coarse-grain utilization counter, accumulated by the firmware.
+    fn read_busy_time(
+        &self,
+        function: &'static str,
+        counter_type: amdsmi_utilization_counter_type_t,
+    ) -> Result<AmdBusyTime, AmdError> {
+        self.call_raw(function, |raw, handle| {
+            let mut counter = amdsmi_utilization_counter_t {
+                type_: counter_type,
+                value: 0,
+                fine_value: [0; 4],
+                fine_value_count: 0,
+            };
+            let mut timestamp = 0;
+            // SAFETY: The array contains one counter, whose type is set, and the pointers are valid for the duration of the call.
+            raw.check(unsafe {
+                lib_call!(
+                    raw.lib(),
+                    amdsmi_get_utilization_count(handle, &mut counter, 1, &mut timestamp)
+                )
+            })?;
+            Ok(AmdBusyTime {
+                counter: counter.value,
+                timestamp: DriverTimestamp::from_nanos(timestamp),
+            })
+        })
+    }
+
+    /// Forgets the metrics that have been found unavailable on this device, and the sensors that have been
+    /// probed, to query them again.
+    ///
+    /// See [`AmdSmi::set_unsupported_cache_ttl`], and [`AmdSmi::reset_unsupported_cache`] to reset all the handles.
+    pub fn reset_unsupported_cache(&self) {
+        self.unsupported.borrow_mut().clear();
+        self.temperature_sensors.borrow_mut().clear();
+        self.voltage_sensors.borrow_mut().clear();
+    }
+
+    /// Returns the sensors of `all` that report `metric`, probed with `read` on first use.
+    ///
+    /// The cache is cleared with the cache of the unavailable metrics, and when the library is re-initialized.
+    fn probe_sensors<M: Copy + Eq + Hash, S: Copy>(
+        &self,
+        cache: &RefCell<SensorCache<M, S>>,
+        metric: M,
+        all: &[S],
+        read: impl Fn(S, M) -> Result<i64, AmdError>,
+    ) -> Result<Vec<S>, AmdError> {
+        let epoch = self.amdsmi.unsupported_cache_epoch();
+        if let Some(sensors) = cache.borrow_mut().get(metric, epoch) {
+            return Ok(sensors);
+        }
+        let mut sensors = Vec::new();
+        for &sensor in all {
+            if read(sensor, metric).optional()?.is_some() {
+                sensors.push(sensor);
+            }
+        }
+        cache.borrow_mut().insert(metric, sensors.clone());
+        Ok(sensors)
+    }
+
+    /// Reads a metric in the unit of the library, or returns its last value if it has been read too recently.
+    fn read_metric_limited(&self, kind: MetricKind) -> Result<MetricValue, AmdError> {
+        let Some(interval) = self.amdsmi.rate_limit(kind) else {
+            return self.read_metric_cached(kind);
+        };
+        let now = Instant::now();
+        let generation = self.amdsmi.generation();
+        if let Some(value) = self
+            .recent
+            .borrow_mut()
+            .get(kind, now, interval, generation)
+        {
+            return Ok(value);
+        }
+        let value = self.read_metric_cached(kind)?;
+        self.recent.borrow_mut().insert(kind, now, value);
+        Ok(value)
+    }
+
+    /// Reads a metric in the unit of the library, skipping the metrics that were recently unavailable.
+    fn read_metric_cached(&self, kind: MetricKind) -> Result<MetricValue, AmdError> {
+        let Some(ttl) = self.amdsmi.unsupported_cache_ttl() else {
+            return dynamic::read_metric(self, kind);
+        };
+        let epoch = self.amdsmi.unsupported_cache_epoch();
+        if self
+            .unsupported
+            .borrow_mut()
+            .contains(kind, Instant::now(), ttl, epoch)
+        {
+            return Err(AmdError::Status {
+                status: AmdStatus::AMDSMI_STATUS_NOT_SUPPORTED,
+                message: Some(String::from(
+                    "the metric was not available at the last read",
+                )),
+            });
+        }
+        let result = dynamic::read_metric(self, kind);
+        if let Err(e) = &result
+            && e.is_unavailable()
+        {
+            self.unsupported.borrow_mut().insert(kind, Instant::now());
+        }
+        result
+    }
+
+    /// Returns `true` if the kernel reports that the device is suspended.
+    ///
+    /// The PCI identifier is known by the library without querying the device, which stays suspended.
+    fn is_suspended(&self) -> bool {
+        let Ok(bdf_id) = self.amdsmi.raw_bdf_id(self.inner.get()) else {
+            return false;
+        };
+        let path = AmdPciAddress::from_bdf_id(bdf_id).sysfs_path();
+        power_state::read_runtime_power_state(&path).is_some_and(|state| state.is_suspended())
+    }
+
+    /// Finds the processor that has the same PCI identifier, in the current generation of the library.
+    fn resolve(&self) -> Result<(), AmdError> {
+        let generation = self.amdsmi.generation();
+        let bdf_id = self.bdf_id.ok_or(AmdError::Status {
+            status: AmdStatus::AMDSMI_STATUS_NOT_FOUND,
+            message: Some(String::from(
+                "the processor handle cannot be resolved again",
+            )),
+        })?;
+        for socket in self.amdsmi.raw_socket_handles()? {
+            for processor in self.amdsmi.raw_processor_handles(socket)? {
+                if self.amdsmi.raw_bdf_id(processor).ok() == Some(bdf_id) {
+                    self.inner.set(processor);
+                    self.generation.set(generation);
+                    return Ok(());
+                }
+            }
+        }
+        Err(AmdError::Status {
+            status: AmdStatus::AMDSMI_STATUS_NOT_FOUND,
+            message: Some(format!(
+                "no processor with BDF id {bdf_id:#x} after re-initialization"
+            )),
+        })
+    }
 }
 
 /// Handle to a socket in the system.
@@ -39,58 +440,203 @@ pub trait SocketHandle {
 
     /// Lists the processors associated to this socket.
     fn processor_handles(&self) -> Result<Vec<Self::ProcessorHandle>, AmdError>;
-}
 
-impl SocketHandle for AmdSocketHandle {
-    type ProcessorHandle = AmdProcessorHandle;
+    /// Lists the processors like [`processor_handles`](SocketHandle::processor_handles), but checks each
+    /// processor by reading its type: the processors that cannot be read are returned as errors, at their
+    /// position, instead of being returned as unusable handles. The query being not supported is not a failure.
+    ///
+    /// An error is returned only if the processors themselves cannot be listed.
+    fn processor_handles_partial(
+        &self,
+    ) -> Result<Vec<Result<Self::ProcessorHandle, AmdError>>, AmdError> {
+        Ok(self
+            .processor_handles()?
+            .into_iter()
+            .map(|processor| processor.processor_type().optional().map(|_| processor))
+            .collect())
+    }
 
-    fn processor_handles(&self) -> Result<Vec<Self::ProcessorHandle>, AmdError> {
-        let mut processor_count = 0;
-
-        // Query the number of processor handles for the given socket.
-        // SAFETY: According the AMD-SMI library documentation, passing `null_mut()` is safe which sets `processor_count` to the number of processors available for this socket.
-        let result = unsafe {
-            self.amdsmi.amdsmi.amdsmi.amdsmi_get_processor_handles(
-                self.inner,
-                &mut processor_count,
-                ptr::null_mut(),
-            )
+    /// Counts the processors associated to this socket, without retrieving their handles.
+    fn processor_count(&self) -> Result<usize, AmdError>;
+
+    /// Retrieves the identifier of this socket.
+    fn socket_id(&self) -> Result<String, AmdError>;
+
+    /// Retrieves a [`AmdSocketMetadata`] structure describing this socket: identifier, processors and OAM slot.
+    fn socket_metadata(&self) -> Result<AmdSocketMetadata, AmdError> {
+        let mut metadata = AmdSocketMetadata {
+            id: self.socket_id()?,
+            ..Default::default()
         };
-        self.amdsmi.check_status(result)?;
-
-        // Allocate a vector of nulls.
-        let mut processor_handles = vec![ptr::null_mut(); processor_count as usize];
-
-        // Fill the buffer with processor handles.
-        // SAFETY: `processor_handles.as_mut_ptr()` points to a memory block of sufficient size.
-        //  According the AMD-SMI library documentation, the function writes at most `processor_count` handles ensuring no out-of-bounds access occurs.
-        let result = unsafe {
-            self.amdsmi.amdsmi.amdsmi.amdsmi_get_processor_handles(
-                self.inner,
-                &mut processor_count,
-                processor_handles.as_mut_ptr(),
-            )
+        for processor in self.processor_handles()? {
+            let processor_type = processor.processor_type()?;
+            *metadata.processor_counts.entry(processor_type).or_default() += 1;
+            if metadata.oam_id.is_none()
+                && processor_type == AmdProcessorType::AMDSMI_PROCESSOR_TYPE_AMD_GPU
+            {
+                // the unsupported OAM id is reported as the maximal value of its type, depending on the version
+                metadata.oam_id = processor
+                    .device_asic_info()
+                    .optional()?
+                    .map(|info| info.oam_id)
+                    .filter(|&id| id != u16::MAX as u32 && id != u32::MAX);
+            }
+        }
+        Ok(metadata)
+    }
+}
+
+impl AmdSocketHandle {
+    pub(crate) fn new(amdsmi: AmdSmi, inner: amdsmi_socket_handle) -> Self {
+        let socket_id = if amdsmi.auto_recovery() {
+            amdsmi.raw_socket_id(inner).ok()
+        } else {
+            None
         };
+        Self {
+            generation: Cell::new(amdsmi.generation()),
+            amdsmi,
+            inner: Cell::new(inner),
+            socket_id,
+        }
+    }
+
+    /// Returns the raw handle, resolved again if the automatic recovery is enabled and the library has been
+    /// re-initialized.
+    pub(crate) fn current_handle(&self) -> Result<amdsmi_socket_handle, AmdError> {
+        if self.amdsmi.auto_recovery() && self.generation.get() != self.amdsmi.generation() {
+            self.resolve()?;
+        }
+        Ok(self.inner.get())
+    }
+
+    /// Finds the socket that has the same identifier, in the current generation of the library.
+    fn resolve(&self) -> Result<(), AmdError> {
+        let generation = self.amdsmi.generation();
+        let socket_id = self.socket_id.as_deref().ok_or(AmdError::Status {
+            status: AmdStatus::AMDSMI_STATUS_NOT_FOUND,
+            message: Some(String::from("the socket handle cannot be resolved again")),
+        })?;
+        for socket in self.amdsmi.raw_socket_handles()? {
+            if self.amdsmi.raw_socket_id(socket).ok().as_deref() == Some(socket_id) {
+                self.inner.set(socket);
+                self.generation.set(generation);
+                return Ok(());
+            }
+        }
+        Err(AmdError::Status {
+            status: AmdStatus::AMDSMI_STATUS_NOT_FOUND,
+            message: Some(format!(
+                "no socket with identifier {socket_id:?} after re-initialization"
+            )),
+        })
+    }
+}
 
-        self.amdsmi.check_status(result)?;
+impl SocketHandle for AmdSocketHandle {
+    type ProcessorHandle = AmdProcessorHandle;
 
-        processor_handles.truncate(processor_count as usize);
-        Ok(processor_handles
+    fn processor_handles(&self) -> Result<Vec<Self::ProcessorHandle>, AmdError> {
+        Ok(self
+            .amdsmi
+            .raw_processor_handles(self.current_handle()?)?
             .into_iter()
-            .map(|s| AmdProcessorHandle {
-                amdsmi: self.amdsmi.clone(),
-                inner: s,
-            })
+            .map(|p| AmdProcessorHandle::new(self.amdsmi.clone(), p))
             .collect())
     }
+
+    fn processor_count(&self) -> Result<usize, AmdError> {
+        Ok(self.amdsmi.raw_processor_count(self.current_handle()?)? as usize)
+    }
+
+    fn socket_id(&self) -> Result<String, AmdError> {
+        self.amdsmi.raw_socket_id(self.current_handle()?)
+    }
+}
+
+/// Lazy iterator over the sockets, see [`AmdSmi::iter_sockets`].
+pub struct SocketIter {
+    amdsmi: AmdSmi,
+    raw: std::vec::IntoIter<amdsmi_socket_handle>,
+}
+
+impl SocketIter {
+    pub(crate) fn new(amdsmi: AmdSmi) -> Result<Self, AmdError> {
+        let raw = amdsmi.raw_socket_handles()?.into_iter();
+        Ok(Self { amdsmi, raw })
+    }
+}
+
+impl Iterator for SocketIter {
+    type Item = AmdSocketHandle;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let inner = self.raw.next()?;
+        Some(AmdSocketHandle::new(self.amdsmi.clone(), inner))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.raw.size_hint()
+    }
+}
+
+/// Lazy iterator over the processors of all the sockets, see [`AmdSmi::iter_processors`].
+///
+/// The processors of a socket are listed when the iterator reaches it.
+/// If they cannot be listed, the error is yielded and the iteration continues with the next socket.
+pub struct ProcessorIter {
+    sockets: SocketIter,
+    current: Option<(AmdSmi, std::vec::IntoIter<amdsmi_processor_handle>)>,
+}
+
+impl ProcessorIter {
+    pub(crate) fn new(amdsmi: AmdSmi) -> Result<Self, AmdError> {
+        Ok(Self {
+            sockets: SocketIter::new(amdsmi)?,
+            current: None,
+        })
+    }
+}
+
+impl Iterator for ProcessorIter {
+    type Item = Result<AmdProcessorHandle, AmdError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((amdsmi, processors)) = &mut self.current
+                && let Some(inner) = processors.next()
+            {
+                return Some(Ok(AmdProcessorHandle::new(amdsmi.clone(), inner)));
+            }
+            let socket = self.sockets.next()?;
+            match socket
+                .current_handle()
+                .and_then(|inner| socket.amdsmi.raw_processor_handles(inner))
+            {
+                Ok(processors) => self.current = Some((socket.amdsmi, processors.into_iter())),
+                Err(e) => {
+                    self.current = None;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
 }
 
 /// Handle to a processor in a [socket](SocketHandle).
 #[cfg_attr(feature = "mock", automock)]
+// the mock copies the deprecations of the methods to its own items
+#[cfg_attr(
+    feature = "mock",
+    allow(deprecated, useless_deprecated, unused_attributes)
+)]
 pub trait ProcessorHandle {
     /// Retrieves a [`AmdEngineUsage`] structure containing all data about GPU device activities.
     fn device_activity(&self) -> Result<AmdEngineUsage, AmdError>;
 
+    /// Retrieves the type of the processor (AMD GPU, AMD CPU...).
+    fn processor_type(&self) -> Result<AmdProcessorType, AmdError>;
+
     /// Retrieves globals [`AmdAsicInfo`] information about a GPU device.
     fn device_asic_info(&self) -> Result<AmdAsicInfo, AmdError>;
 
@@ -101,262 +647,1678 @@ pub trait ProcessorHandle {
     /// - `clk_type`: Clock devices existing among [`AmdClkType`] on hardware.
     fn device_clock_info(&self, clk_type: AmdClkType) -> Result<AmdClkInfo, AmdError>;
 
-    /// Retrieves a [`AmdEnergyConsumption`] structure containing data about energy consumption of the GPU device.
-    fn device_energy_consumption(&self) -> Result<AmdEnergyConsumption, AmdError>;
-
-    /// Retrieves the fan speed ratio.
-    fn device_fan_speed(&self, sensor_index: u32) -> Result<u32, AmdError>;
-
-    /// Retrieves the memory consumption of the GPU device.
+    /// Retrieves the frequency levels of a clock domain of the GPU device, and the current level.
     ///
     /// # Arguments
     ///
-    /// - `mem_type`: Memory devices existing among [`AmdMemoryType`] on hardware.
-    fn device_memory_usage(&self, mem_type: AmdMemoryType) -> Result<u64, AmdError>;
-
-    /// Retrieves the PCI bus traffic used by the GPU device.
-    fn device_pci_usage(&self) -> Result<AmdPciTraffic, AmdError>;
+    /// - `clk_type`: Clock devices existing among [`AmdClkType`] on hardware.
+    fn device_clock_frequencies(&self, clk_type: AmdClkType) -> Result<AmdFrequencies, AmdError>;
 
-    /// Retrieves a [`AmdPowerConsumption`] structure containing all data about GPU device power consumption.
-    fn device_power_consumption(&self) -> Result<AmdPowerConsumption, AmdError>;
-    /// Retrieves the power management status accessability of the GPU device.
-    fn device_power_managment(&self) -> Result<bool, AmdError>;
+    /// Retrieves a [`AmdClkInfo`] structure for every clock domain of the GPU device.
+    ///
+    /// All the domains listed in [`ALL_CLK_TYPES`] are queried, those that are not supported by the device are skipped.
+    fn device_clocks(&self) -> Result<HashMap<AmdClkType, AmdClkInfo>, AmdError> {
+        let mut clocks = HashMap::new();
+        for &clk_type in ALL_CLK_TYPES {
+            if let Some(info) = self.device_clock_info(clk_type).optional()? {
+                clocks.insert(clk_type, info);
+            }
+        }
+        Ok(clocks)
+    }
 
-    /// Retrieves a set of [`AmdProcess`] structure containing data about running processes on the GPU device.
-    fn device_process_list(&self) -> Result<Vec<AmdProcess>, AmdError>;
+    /// Retrieves the [`AmdClockState`] of every clock domain of the GPU device: active, in deep sleep or gated.
+    ///
+    /// The domains that are not supported by the device are skipped.
+    fn device_clock_states(&self) -> Result<HashMap<AmdClkType, AmdClockState>, AmdError> {
+        Ok(self
+            .device_clocks()?
+            .into_iter()
+            .map(|(clk_type, info)| (clk_type, info.state()))
+            .collect())
+    }
 
-    /// Retrieves the temperature of a given area of the GPU device.
+    /// Retrieves the CPER entries (RAS events) cached by the driver, starting at `cursor`.
     ///
     /// # Arguments
     ///
-    /// - `sensor_type`: Thermal sensor [`AmdTemperatureType`] on AMD GPU hardware.
-    /// - `metric`: Temperature type [`AmdTemperatureMetric`] analyzed (current, average...).
-    fn device_temperature(
+    /// - `severity_mask`: Severities of the entries to retrieve, see [`cper_severity_mask`].
+    /// - `cursor`: Position of the first entry to retrieve, `0` to start at the oldest entry.
+    ///   Pass the cursor returned in [`AmdCperEntries`] to only retrieve the new entries.
+    fn device_cper_entries(
         &self,
-        sensor_type: AmdTemperatureType,
-        metric: AmdTemperatureMetric,
-    ) -> Result<i64, AmdError>;
+        severity_mask: u32,
+        cursor: u64,
+    ) -> Result<AmdCperEntries, AmdError>;
 
-    /// Retrieves the UUID of the GPU device.
-    fn device_uuid(&self) -> Result<String, AmdError>;
+    /// Retrieves a [`AmdEnergyConsumption`] structure containing data about energy consumption of the GPU device.
+    fn device_energy_consumption(&self) -> Result<AmdEnergyConsumption, AmdError>;
 
-    /// Retrieves the voltage of a given area of the GPU device.
-    ///
-    /// # Arguments
+    /// Retrieves the energy consumed by the GPU device since the last boot, in joules, with the raw counter.
     ///
-    /// - `sensor_type`: Voltage retrieved by a [`AmdVoltageType`] sensor on AMD GPU hardware.
-    /// - `metric`: Voltage type [`AmdVoltageMetric`] analyzed (current, average...).
-    fn device_voltage(
-        &self,
-        sensor_type: AmdVoltageType,
-        metric: AmdVoltageMetric,
-    ) -> Result<i64, AmdError>;
-}
-
-impl ProcessorHandle for AmdProcessorHandle {
-    fn device_activity(&self) -> Result<AmdEngineUsage, AmdError> {
-        // Allocate uninitialized memory for the structure and avoid reading uninitialized memory before the FFI call.
-        let mut info = MaybeUninit::<amdsmi_engine_usage_t>::uninit();
-
-        // SAFETY: Pass a raw pointer to uninitialized memory to the FFI function.
-        // According to AMD-SMI documentation, the function fully initializes the structure on success.
-        // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
-        let result = unsafe {
-            self.amdsmi
-                .amdsmi
-                .amdsmi
-                .amdsmi_get_gpu_activity(self.inner, info.as_mut_ptr())
-        };
+    /// The resolution of the counter is applied. See [`AmdEnergyReading`] for the overflow and precision characteristics.
+    fn device_energy_joules(&self) -> Result<AmdEnergyReading, AmdError> {
+        Ok(self.device_energy_consumption()?.into())
+    }
 
-        self.amdsmi.check_status(result)?;
+    /// Retrieves the fan speed ratio.
+    fn device_fan_speed(&self, sensor_index: u32) -> Result<u32, AmdError>;
 
-        // SAFETY: `assume_init()` is safe because the FFI call succeeded and fully initialized `info`.
-        let info = unsafe { info.assume_init() };
-        Ok(info.into())
-    }
+    /// Retrieves the fan speed in RPM.
+    fn device_fan_rpms(&self, sensor_index: u32) -> Result<i64, AmdError>;
 
-    fn device_asic_info(&self) -> Result<AmdAsicInfo, AmdError> {
-        // Allocate uninitialized memory for the structure and avoid reading uninitialized memory before the FFI call.
-        let mut info = MaybeUninit::<amdsmi_asic_info_t>::uninit();
-
-        // SAFETY: Pass a raw pointer to uninitialized memory to the FFI function.
-        // According to AMD-SMI documentation, the function fully initializes the structure on success.
-        // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
-        let result = unsafe {
-            self.amdsmi
-                .amdsmi
-                .amdsmi
-                .amdsmi_get_gpu_asic_info(self.inner, info.as_mut_ptr())
-        };
+    /// Retrieves the maximal fan speed, relative to [`MAX_FAN_SPEED`].
+    fn device_fan_speed_max(&self, sensor_index: u32) -> Result<u64, AmdError>;
 
-        self.amdsmi.check_status(result)?;
+    /// Returns the control mode of the fan of the GPU device (automatic or manual), read from sysfs.
+    ///
+    /// Returns `None` if the mode is not available, for instance on passively cooled GPUs.
+    fn device_fan_control_mode(&self) -> Result<Option<FanControlMode>, AmdError> {
+        let path = self.device_sysfs_path()?;
+        Ok(fan_mode::read_fan_control_mode(&path))
+    }
 
-        // SAFETY: `assume_init()` is safe because the FFI call succeeded and fully initialized `info`.
-        let info = unsafe { info.assume_init() };
-        Ok(info.into())
+    /// Retrieves a [`AmdFanInfo`] structure for every fan of the GPU device.
+    ///
+    /// The sensor indexes are probed from 0 until the library reports that the sensor is not supported,
+    /// up to 8 sensors.
+    fn device_fans(&self) -> Result<Vec<AmdFanInfo>, AmdError> {
+        let mut fans = Vec::new();
+        let mut control_mode = None;
+        for sensor_index in 0..MAX_FAN_SENSORS {
+            let Some(speed) = self.device_fan_speed(sensor_index).optional()? else {
+                break;
+            };
+            if sensor_index == 0 {
+                control_mode = self.device_fan_control_mode().optional()?.flatten();
+            }
+            fans.push(AmdFanInfo {
+                sensor_index,
+                speed,
+                rpm: self.device_fan_rpms(sensor_index).optional()?,
+                max_speed: self.device_fan_speed_max(sensor_index).optional()?,
+                control_mode,
+            });
+        }
+        Ok(fans)
     }
 
-    fn device_clock_info(&self, clk_type: AmdClkType) -> Result<AmdClkInfo, AmdError> {
-        let mut info = MaybeUninit::<amdsmi_clk_info_t>::uninit();
-
-        // SAFETY: Pass a pointer to uninitialized memory to the FFI function.
-        // According to AMD-SMI documentation, the function fully initializes the `amdsmi_clk_info_t` on success.
-        // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
-        let result = unsafe {
-            self.amdsmi
-                .amdsmi
-                .amdsmi
-                .amdsmi_get_clock_info(self.inner, clk_type, info.as_mut_ptr())
-        };
+    /// Retrieves the memory consumption of the GPU device, in bytes.
+    ///
+    /// # Arguments
+    ///
+    /// - `mem_type`: Memory devices existing among [`AmdMemoryType`] on hardware.
+    fn device_memory_usage(&self, mem_type: AmdMemoryType) -> Result<u64, AmdError>;
 
-        self.amdsmi.check_status(result)?;
+    /// Retrieves the total amount of memory of the GPU device, in bytes.
+    ///
+    /// # Arguments
+    ///
+    /// - `mem_type`: Memory devices existing among [`AmdMemoryType`] on hardware.
+    fn device_memory_total(&self, mem_type: AmdMemoryType) -> Result<u64, AmdError>;
 
-        // SAFETY: `assume_init()` is safe because the FFI call succeeded and the structure was fully initialized by the library.
-        let info = unsafe { info.assume_init() };
-        Ok(info.into())
+    /// Retrieves a [`AmdMemoryUsage`] structure (used and total memory) for every memory type of the GPU device.
+    ///
+    /// All the types listed in [`ALL_MEMORY_TYPES`] are queried, those that are not supported by the device are skipped.
+    fn device_memory_usages(&self) -> Result<HashMap<AmdMemoryType, AmdMemoryUsage>, AmdError> {
+        let mut usages = HashMap::new();
+        for &mem_type in ALL_MEMORY_TYPES {
+            let Some(used) = self.device_memory_usage(mem_type).optional()? else {
+                continue;
+            };
+            let Some(total) = self.device_memory_total(mem_type).optional()? else {
+                continue;
+            };
+            usages.insert(mem_type, AmdMemoryUsage { used, total });
+        }
+        Ok(usages)
     }
 
-    fn device_energy_consumption(&self) -> Result<AmdEnergyConsumption, AmdError> {
-        let mut consumption = AmdEnergyConsumption {
-            energy: 0,
-            resolution: 0.0,
-            timestamp: 0,
-        };
+    /// Retrieves the header of the GPU metrics table, which gives its format version and content revision.
+    fn device_metrics_header(&self) -> Result<AmdMetricsHeader, AmdError>;
 
-        // SAFETY: Pass mutable pointers to the fields of `consumption` to the FFI function.
-        // According to AMD-SMI documentation, the function writes all values on success and will not write beyond the memory locations provided.
-        // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
-        let result = unsafe {
-            self.amdsmi.amdsmi.amdsmi.amdsmi_get_energy_count(
-                self.inner,
-                &mut consumption.energy,
-                &mut consumption.resolution,
-                &mut consumption.timestamp,
-            )
-        };
+    /// Retrieves the number of ECC errors of the GPU device since boot, for all its blocks.
+    fn device_ecc_count(&self) -> Result<AmdEccCount, AmdError>;
 
-        self.amdsmi.check_status(result)?;
-        Ok(consumption)
+    /// Retrieves the number of ECC errors of the GPU device, with the time of the query,
+    /// to compute error rates with [`AmdEccSnapshot::rate_since`].
+    fn device_ecc_snapshot(&self) -> Result<AmdEccSnapshot, AmdError> {
+        let count = self.device_ecc_count()?;
+        Ok(AmdEccSnapshot {
+            count,
+            time: Instant::now(),
+        })
     }
 
-    fn device_fan_speed(&self, sensor_index: u32) -> Result<u32, AmdError> {
-        let mut speed = 0;
-
-        // SAFETY: Pass a mutable pointer to `speed` for the FFI function to write the current fan speed.
-        // According to AMD-SMI documentation, the function writes a value between 0 and `AMDSMI_MAX_FAN_SPEED` to this pointer.
-        // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
-        let result = unsafe {
-            self.amdsmi
-                .amdsmi
-                .amdsmi
-                .amdsmi_get_gpu_fan_speed(self.inner, sensor_index, &mut speed)
-        };
+    /// Retrieves the number of ECC errors of one block of the GPU device since boot.
+    fn device_block_ecc_count(&self, block: AmdGpuBlock) -> Result<AmdEccCount, AmdError>;
 
-        self.amdsmi.check_status(result)?;
-        Ok((speed as u32 * 100) / AMDSMI_MAX_FAN_SPEED)
+    /// Retrieves the number of ECC errors of each block of [`ALL_GPU_BLOCKS`] since boot.
+    ///
+    /// The blocks that do not report ECC errors are skipped.
+    fn device_block_ecc_counts(&self) -> Result<Vec<AmdBlockEccCount>, AmdError> {
+        let mut counts = Vec::new();
+        for &block in ALL_GPU_BLOCKS {
+            if let Some(count) = self.device_block_ecc_count(block).optional()? {
+                counts.push(AmdBlockEccCount { block, count });
+            }
+        }
+        Ok(counts)
     }
 
-    fn device_memory_usage(&self, mem_type: AmdMemoryType) -> Result<u64, AmdError> {
-        let mut used = 0;
-
-        // SAFETY: Pass a mutable pointer to `used` for the FFI function to write the memory usage.
-        // According to AMD-SMI documentation, the function will write a valid value on success and will not write outside the provided memory location.
-        // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
-        let result = unsafe {
-            self.amdsmi
-                .amdsmi
-                .amdsmi
-                .amdsmi_get_gpu_memory_usage(self.inner, mem_type, &mut used)
-        };
+    /// Retrieves the RAS (Reliability, Availability, Serviceability) features supported by the GPU device.
+    fn device_ras_features(&self) -> Result<AmdRasFeatures, AmdError>;
 
-        self.amdsmi.check_status(result)?;
-        Ok(used)
-    }
+    /// Retrieves the state of the RAS features (ECC) of one block of the GPU device.
+    fn device_ras_block_state(&self, block: AmdGpuBlock) -> Result<AmdRasErrState, AmdError>;
 
-    fn device_pci_usage(&self) -> Result<AmdPciTraffic, AmdError> {
-        let mut usage = AmdPciTraffic {
-            sent: 0,
-            received: 0,
-            max_pkt_sz: 0,
-        };
+    /// Retrieves the memory pages of the GPU device that have been retired because of ECC errors.
+    fn device_retired_pages(&self) -> Result<Vec<AmdRetiredPage>, AmdError>;
 
-        // SAFETY: Pass mutable pointers to the fields of `usage` to the FFI function.
-        // According to AMD-SMI documentation, the function writes all values on success or ignored them.
-        // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
-        let result = unsafe {
-            self.amdsmi.amdsmi.amdsmi.amdsmi_get_gpu_pci_throughput(
-                self.inner,
-                &mut usage.sent,
-                &mut usage.received,
-                &mut usage.max_pkt_sz,
-            )
-        };
+    /// Retrieves the PCI bus traffic used by the GPU device.
+    fn device_pci_usage(&self) -> Result<AmdPciTraffic, AmdError>;
 
-        self.amdsmi.check_status(result)?;
-        Ok(usage)
-    }
+    /// Retrieves the [`AmdPcieInfo`] of the GPU device: capabilities, current state and error counters of its PCIe link.
+    fn device_pcie_info(&self) -> Result<AmdPcieInfo, AmdError>;
 
-    /// Retrieves a [`amdsmi_power_info_t`] structure containing all data about GPU device power consumption.
-    fn device_power_consumption(&self) -> Result<AmdPowerConsumption, AmdError> {
-        // Reserve uninitialized memory space for the C function to fill.
-        let mut info = MaybeUninit::<amdsmi_power_info_t>::uninit();
-
-        // SAFETY: Pass a raw pointer to uninitialized memory for the FFI function to write into.
-        // `info` has exactly the size of `amdsmi_power_info_t`.
-        // According to AMD-SMI documentation, the function fully initializes the structure on success.
-        // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
-        let result = unsafe {
-            self.amdsmi
-                .amdsmi
-                .amdsmi
-                .amdsmi_get_power_info(self.inner, info.as_mut_ptr())
-        };
+    /// Retrieves the current speed of the PCIe link of the GPU device, in GT/s.
+    ///
+    /// Returns `None` if the device does not report it.
+    fn device_pcie_speed(&self) -> Result<Option<f64>, AmdError> {
+        Ok(self.device_pcie_info()?.speed_gts())
+    }
 
-        self.amdsmi.check_status(result)?;
+    /// Retrieves the current number of lanes of the PCIe link of the GPU device.
+    ///
+    /// Returns `None` if the device does not report it.
+    fn device_pcie_width(&self) -> Result<Option<u16>, AmdError> {
+        Ok(self.device_pcie_info()?.width)
+    }
 
-        // SAFETY: `assume_init()` is safe because the FFI call returned SUCCESS, meaning `info` is fully initialized.
-        let info = unsafe { info.assume_init() };
-        Ok(info.into())
+    /// Retrieves the number of times the PCIe link of the GPU device has left the L0 (active) state
+    /// to recover from errors, since boot.
+    ///
+    /// A growing count indicates an unstable link. Returns `None` if the device does not report it.
+    fn device_pcie_link_recoveries(&self) -> Result<Option<u64>, AmdError> {
+        Ok(self.device_pcie_info()?.l0_to_recovery_count)
     }
 
-    fn device_power_managment(&self) -> Result<bool, AmdError> {
-        let mut enabled = false;
-
-        // SAFETY: Pass a mutable pointer to `enabled` for the FFI function to write the power management status.
-        // According to AMD-SMI documentation, the function will write a valid boolean value on success.
-        // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
-        let result = unsafe {
-            self.amdsmi
-                .amdsmi
-                .amdsmi
-                .amdsmi_is_gpu_power_management_enabled(self.inner, &mut enabled)
-        };
+    /// Retrieves the [`AmdPowerProfileStatus`] of the GPU device: supported power profiles and active profile.
+    fn device_power_profile_status(&self) -> Result<AmdPowerProfileStatus, AmdError>;
 
-        self.amdsmi.check_status(result)?;
-        Ok(enabled)
+    /// Retrieves the power profile that is currently active on the GPU device.
+    ///
+    /// This is a shortcut for frequent polling: the library has no cheaper query than
+    /// [`device_power_profile_status`](ProcessorHandle::device_power_profile_status), but the list of
+    /// the supported profiles is not built.
+    fn device_power_profile(&self) -> Result<AmdPowerProfile, AmdError> {
+        Ok(self.device_power_profile_status()?.current)
     }
 
-    fn device_temperature(
+    /// Retrieves a [`AmdPowerConsumption`] structure containing all data about GPU device power consumption.
+    fn device_power_consumption(&self) -> Result<AmdPowerConsumption, AmdError>;
+
+    /// Retrieves the current performance level of the GPU device, which controls how the clocks are chosen.
+    fn device_perf_level(&self) -> Result<AmdPerfLevel, AmdError>;
+
+    /// Retrieves the frequency ranges currently applied to the graphics and memory clocks of the GPU device,
+    /// and the ranges that can be applied.
+    ///
+    /// Unlike [`device_clock_info`](ProcessorHandle::device_clock_info), which gives the range of the
+    /// frequency levels, this is the range set by the overdrive settings of the driver: it can be used to
+    /// verify that a frequency cap has been applied.
+    fn device_clock_limits(&self) -> Result<AmdClockLimits, AmdError>;
+
+    /// Retrieves a [`AmdPowerCapInfo`] structure containing the power cap configuration of a power sensor.
+    ///
+    /// # Arguments
+    ///
+    /// - `sensor_index`: 0-based index of the power sensor, usually 0.
+    fn device_power_cap_info(&self, sensor_index: u32) -> Result<AmdPowerCapInfo, AmdError>;
+
+    /// Retrieves a [`AmdPowerSensor`] structure for every power sensor of the GPU device.
+    ///
+    /// The sensor indexes are probed from 0 until the library reports that the sensor is not supported.
+    /// The library only provides per-sensor power caps: the power consumption is given for the whole device
+    /// by [`device_power_consumption`](ProcessorHandle::device_power_consumption).
+    fn device_power_sensors(&self) -> Result<Vec<AmdPowerSensor>, AmdError> {
+        let mut sensors = Vec::new();
+        for sensor_index in 0..MAX_POWER_SENSORS {
+            let Some(cap) = self.device_power_cap_info(sensor_index).optional()? else {
+                break;
+            };
+            sensors.push(AmdPowerSensor { sensor_index, cap });
+        }
+        Ok(sensors)
+    }
+
+    /// Returns `true` if the dynamic power management (DPM) of the GPU device is enabled.
+    fn device_power_management_enabled(&self) -> Result<bool, AmdError>;
+
+    /// Returns `true` if the dynamic power management of the GPU device is enabled.
+    #[deprecated(
+        note = "use `device_power_management_enabled`, or `device_power_management` for the details"
+    )]
+    fn device_power_managment(&self) -> Result<bool, AmdError> {
+        self.device_power_management_enabled()
+    }
+
+    /// Retrieves the SoC P-state policies of the GPU device.
+    fn device_soc_pstate(&self) -> Result<AmdDpmPolicy, AmdError>;
+
+    /// Retrieves the XGMI per-link power down policies of the GPU device.
+    fn device_xgmi_plpd(&self) -> Result<AmdDpmPolicy, AmdError>;
+
+    /// Returns the power management features of the GPU device, and whether they are active.
+    ///
+    /// The power capping is active if the power cap of the first sensor is not zero,
+    /// and the profile switching is possible if the device supports more than one power profile.
+    fn device_power_management(&self) -> Result<AmdPowerManagement, AmdError> {
+        Ok(AmdPowerManagement {
+            dpm: self.device_power_management_enabled()?,
+            power_capping: self
+                .device_power_cap_info(0)
+                .optional()?
+                .map(|cap| cap.power_cap > 0),
+            profile_switching: self
+                .device_power_profile_status()
+                .optional()?
+                .map(|status| status.available().len() > 1),
+            soc_pstate: self.device_soc_pstate().optional()?,
+            xgmi_plpd: self.device_xgmi_plpd().optional()?,
+        })
+    }
+
+    /// Retrieves a set of [`AmdProcess`] structure containing data about running processes on the GPU device.
+    fn device_process_list(&self) -> Result<Vec<AmdProcess>, AmdError>;
+
+    /// Retrieves the processes that use the GPU device, like [`device_process_list`](ProcessorHandle::device_process_list),
+    /// but reuses the vector `processes` and the strings of its elements, to avoid allocating at each query.
+    fn device_process_list_into(&self, processes: &mut Vec<AmdProcess>) -> Result<(), AmdError>;
+
+    /// Retrieves the processes that use the GPU device and match the given [`ProcessFilter`].
+    fn device_process_list_filtered(
+        &self,
+        filter: &ProcessFilter,
+    ) -> Result<Vec<AmdProcess>, AmdError> {
+        let mut processes = self.device_process_list()?;
+        processes.retain(|p| filter.matches(p));
+        Ok(processes)
+    }
+
+    /// Retrieves the [`AmdMemoryPressure`] of the GPU device: usage of the VRAM and of the GTT,
+    /// and eviction time of the processes.
+    ///
+    /// The memory types that the device does not report are `None`.
+    fn device_memory_pressure(&self) -> Result<AmdMemoryPressure, AmdError> {
+        let usage = |mem_type| -> Result<Option<AmdMemoryUsage>, AmdError> {
+            let Some(used) = self.device_memory_usage(mem_type).optional()? else {
+                return Ok(None);
+            };
+            let Some(total) = self.device_memory_total(mem_type).optional()? else {
+                return Ok(None);
+            };
+            Ok(Some(AmdMemoryUsage { used, total }))
+        };
+        let vram = usage(AmdMemoryType::AMDSMI_MEM_TYPE_VRAM)?;
+        let gtt = usage(AmdMemoryType::AMDSMI_MEM_TYPE_GTT)?;
+        let processes = self.device_process_list()?;
+        Ok(AmdMemoryPressure::new(vram, gtt, &processes))
+    }
+
+    /// Verifies the checksum of the RAS EEPROM of the GPU device.
+    ///
+    /// Returns `false` if the EEPROM is corrupted. This query requires root access,
+    /// and is not supported on virtual machine guests.
+    fn device_validate_ras_eeprom(&self) -> Result<bool, AmdError>;
+
+    /// Takes a [`DeviceSnapshot`] of the main metrics of the GPU device.
+    fn device_snapshot(&self) -> Result<DeviceSnapshot, AmdError> {
+        DeviceSnapshot::capture(self)
+    }
+
+    /// Takes a new snapshot of the GPU device into an existing [`DeviceSnapshot`], reusing its allocations.
+    ///
+    /// In sampling loops, this avoids allocating at each sample.
+    fn device_snapshot_into(&self, snapshot: &mut DeviceSnapshot) -> Result<(), AmdError> {
+        snapshot.update(self)
+    }
+
+    /// Retrieves the temperature of a given area of the GPU device, in Celsius degrees.
+    ///
+    /// # Arguments
+    ///
+    /// - `sensor_type`: Thermal sensor [`AmdTemperatureType`] on AMD GPU hardware.
+    /// - `metric`: Temperature type [`AmdTemperatureMetric`] analyzed (current, average...).
+    fn device_temperature(
+        &self,
+        sensor_type: AmdTemperatureType,
+        metric: AmdTemperatureMetric,
+    ) -> Result<i64, AmdError>;
+
+    /// Checks whether this handle still refers to a device that the library knows.
+    ///
+    /// A handle becomes stale after a GPU reset or a reload of the driver: it must then be acquired again,
+    /// by listing the sockets and processors, instead of being used to query garbage or errors.
+    /// With the automatic recovery (see [`AmdSmi::set_auto_recovery`]), the handle is resolved again if possible.
+    fn device_is_alive(&self) -> bool;
+
+    /// Returns the temperature sensors of [`ALL_TEMPERATURE_TYPES`] that report `metric` on the GPU device.
+    ///
+    /// The sensors are probed once per metric, then cached by the handle until
+    /// [`reset_unsupported_cache`](AmdProcessorHandle::reset_unsupported_cache) or a re-initialization of the library.
+    fn device_supported_temperature_sensors(
+        &self,
+        metric: AmdTemperatureMetric,
+    ) -> Result<Vec<AmdTemperatureType>, AmdError>;
+
+    /// Retrieves the temperature of every sensor of the GPU device, in Celsius degrees.
+    ///
+    /// The sensors given by [`device_supported_temperature_sensors`](Self::device_supported_temperature_sensors)
+    /// for `metric` are queried.
+    ///
+    /// # Arguments
+    ///
+    /// - `metric`: Temperature type [`AmdTemperatureMetric`] analyzed (current, average...).
+    fn device_temperatures(
+        &self,
+        metric: AmdTemperatureMetric,
+    ) -> Result<HashMap<AmdTemperatureType, i64>, AmdError> {
+        let mut temperatures = HashMap::new();
+        for sensor_type in self.device_supported_temperature_sensors(metric)? {
+            if let Some(temperature) = self.device_temperature(sensor_type, metric).optional()? {
+                temperatures.insert(sensor_type, temperature);
+            }
+        }
+        Ok(temperatures)
+    }
+
+    /// Compares the current temperature of a sensor of the GPU device to its critical, emergency and
+    /// shutdown limits, to compute the thermal headroom, in Celsius degrees.
+    ///
+    /// The limits that the sensor does not report are `None`.
+    fn device_thermal_margin(
+        &self,
+        sensor_type: AmdTemperatureType,
+    ) -> Result<AmdThermalMargin, AmdError> {
+        // some sensors report 0 for the limits that are not set
+        let limit = |metric| {
+            self.device_temperature(sensor_type, metric)
+                .optional()
+                .map(|t| t.filter(|&t| t > 0))
+        };
+        Ok(AmdThermalMargin {
+            sensor: sensor_type,
+            current: self
+                .device_temperature(sensor_type, AmdTemperatureMetric::AMDSMI_TEMP_CURRENT)?,
+            critical: limit(AmdTemperatureMetric::AMDSMI_TEMP_CRITICAL)?,
+            critical_hyst: limit(AmdTemperatureMetric::AMDSMI_TEMP_CRITICAL_HYST)?,
+            emergency: limit(AmdTemperatureMetric::AMDSMI_TEMP_EMERGENCY)?,
+            emergency_hyst: limit(AmdTemperatureMetric::AMDSMI_TEMP_EMERGENCY_HYST)?,
+            shutdown: limit(AmdTemperatureMetric::AMDSMI_TEMP_SHUTDOWN)?,
+        })
+    }
+
+    /// Lists the metrics of the GPU device that can be read with [`device_read_metric`](ProcessorHandle::device_read_metric).
+    ///
+    /// All the known metrics are read once, those that are not supported by the device are skipped.
+    fn device_supported_metrics(&self) -> Result<Vec<MetricKind>, AmdError> {
+        dynamic::supported_metrics(self)
+    }
+
+    /// Reads a metric of the GPU device, chosen at runtime.
+    ///
+    /// The value is in the unit given by [`device_metric_unit`](ProcessorHandle::device_metric_unit).
+    ///
+    /// # Arguments
+    ///
+    /// - `kind`: The [`MetricKind`] to read.
+    fn device_read_metric(&self, kind: MetricKind) -> Result<MetricValue, AmdError> {
+        dynamic::read_metric(self, kind)
+    }
+
+    /// Returns the unit of the values of [`device_read_metric`](ProcessorHandle::device_read_metric),
+    /// which depends on the [`UnitSystem`](crate::units::UnitSystem) of the library.
+    fn device_metric_unit(&self, kind: MetricKind) -> MetricUnit {
+        kind.metadata().unit
+    }
+
+    /// Returns the [`UnitSystem`] of the values returned by the getters of [`Quantity`].
+    fn unit_system(&self) -> UnitSystem {
+        UnitSystem::Native
+    }
+
+    /// Retrieves the socket power of the GPU device, in the [`UnitSystem`] of the library (W).
+    fn device_power_quantity(&self) -> Result<Quantity, AmdError> {
+        let power = self.device_power_consumption()?.socket_power;
+        Ok(self.unit_system().quantity(power as f64, MetricUnit::Watt))
+    }
+
+    /// Retrieves the energy consumed by the GPU device since the last boot, in the [`UnitSystem`] of the library
+    /// (µJ or J).
+    fn device_energy_quantity(&self) -> Result<Quantity, AmdError> {
+        let consumption = self.device_energy_consumption()?;
+        Ok(self
+            .unit_system()
+            .quantity(consumption.microjoules(), MetricUnit::MicroJoule))
+    }
+
+    /// Retrieves a temperature of the GPU device, in the [`UnitSystem`] of the library (°C).
+    fn device_temperature_quantity(
+        &self,
+        sensor_type: AmdTemperatureType,
+        metric: AmdTemperatureMetric,
+    ) -> Result<Quantity, AmdError> {
+        let temperature = self.device_temperature(sensor_type, metric)?;
+        Ok(self
+            .unit_system()
+            .quantity(temperature as f64, MetricUnit::Celsius))
+    }
+
+    /// Retrieves a voltage of the GPU device, in the [`UnitSystem`] of the library (mV or V).
+    fn device_voltage_quantity(
+        &self,
+        sensor_type: AmdVoltageType,
+        metric: AmdVoltageMetric,
+    ) -> Result<Quantity, AmdError> {
+        let voltage = self.device_voltage(sensor_type, metric)?;
+        Ok(self
+            .unit_system()
+            .quantity(voltage as f64, MetricUnit::MilliVolt))
+    }
+
+    /// Retrieves the current frequency of a clock domain of the GPU device, in the [`UnitSystem`] of the library
+    /// (MHz or Hz).
+    fn device_clock_quantity(&self, clk_type: AmdClkType) -> Result<Quantity, AmdError> {
+        let clock = self.device_clock_info(clk_type)?.clk;
+        Ok(self
+            .unit_system()
+            .quantity(clock as f64, MetricUnit::MegaHertz))
+    }
+
+    /// Retrieves the current power cap of the GPU device, in the [`UnitSystem`] of the library (µW or W).
+    ///
+    /// The native unit depends on the library, see [`power_cap_unit`](crate::units::power_cap_unit).
+    fn device_power_cap_quantity(&self, sensor_index: u32) -> Result<Quantity, AmdError> {
+        let power_cap = self.device_power_cap_info(sensor_index)?.power_cap;
+        Ok(self
+            .unit_system()
+            .quantity(power_cap as f64, MetricUnit::MicroWatt))
+    }
+
+    /// Retrieves the UUID of the GPU device.
+    ///
+    /// Returns [`AmdError::InvalidString`] if the UUID is not valid UTF-8,
+    /// use [`device_uuid_bytes`](ProcessorHandle::device_uuid_bytes) to get the raw value.
+    fn device_uuid(&self) -> Result<String, AmdError>;
+
+    /// Retrieves the UUID of the GPU device as raw bytes, without the null terminator.
+    fn device_uuid_bytes(&self) -> Result<Vec<u8>, AmdError>;
+
+    /// Retrieves the name of the GPU vendor.
+    fn device_vendor_name(&self) -> Result<String, AmdError>;
+
+    /// Retrieves the name of the VRAM vendor.
+    fn device_vram_vendor(&self) -> Result<String, AmdError>;
+
+    /// Retrieves the name of the GPU subsystem.
+    fn device_subsystem_name(&self) -> Result<String, AmdError>;
+
+    /// Retrieves a [`AmdBoardInfo`] structure containing the board identification (serial number, FRU...).
+    fn device_board_info(&self) -> Result<AmdBoardInfo, AmdError>;
+
+    /// Retrieves a [`AmdDriverInfo`] structure containing the name and version of the kernel driver.
+    fn device_driver_info(&self) -> Result<AmdDriverInfo, AmdError>;
+
+    /// Retrieves the version of each firmware block of the GPU device.
+    fn device_firmwares(&self) -> Result<Vec<AmdFirmwareInfo>, AmdError>;
+
+    /// Retrieves the name of the current compute partition mode of the GPU device, for instance `SPX` or `CPX`.
+    fn device_compute_partition_name(&self) -> Result<String, AmdError>;
+
+    /// Retrieves the current compute partition mode of the GPU device.
+    ///
+    /// The modes that this version of the crate does not know are `AMDSMI_COMPUTE_PARTITION_INVALID`:
+    /// use [`device_compute_partition_name`](Self::device_compute_partition_name) to get them.
+    fn device_compute_partition_mode(&self) -> Result<AmdComputePartition, AmdError> {
+        Ok(parse_compute_partition(
+            &self.device_compute_partition_name()?,
+        ))
+    }
+
+    /// Retrieves the current compute partition mode of the GPU device, for instance `SPX` or `CPX`.
+    #[deprecated(
+        note = "use `device_compute_partition_name`, or `device_compute_partition_mode` for a typed value"
+    )]
+    fn device_compute_partition(&self) -> Result<String, AmdError> {
+        self.device_compute_partition_name()
+    }
+
+    /// Retrieves the name of the current memory partition mode of the GPU device, for instance `NPS1` or `NPS4`.
+    fn device_memory_partition_name(&self) -> Result<String, AmdError>;
+
+    /// Retrieves the current memory partition mode of the GPU device.
+    ///
+    /// The modes that this version of the crate does not know are `AMDSMI_MEMORY_PARTITION_UNKNOWN`:
+    /// use [`device_memory_partition_name`](Self::device_memory_partition_name) to get them.
+    fn device_memory_partition_mode(&self) -> Result<AmdMemoryPartition, AmdError> {
+        Ok(parse_memory_partition(
+            &self.device_memory_partition_name()?,
+        ))
+    }
+
+    /// Retrieves the current memory partition mode of the GPU device, for instance `NPS1` or `NPS4`.
+    #[deprecated(
+        note = "use `device_memory_partition_name`, or `device_memory_partition_mode` for a typed value"
+    )]
+    fn device_memory_partition(&self) -> Result<String, AmdError> {
+        self.device_memory_partition_name()
+    }
+
+    /// Retrieves the version of the AMD SMI library that manages the GPU device.
+    fn lib_version(&self) -> Result<AmdVersion, AmdError>;
+
+    /// Produces a [`AmdCapabilityReport`] of the GPU device: supported metrics, versions and partition modes.
+    fn device_capability_report(&self) -> Result<AmdCapabilityReport, AmdError> {
+        report::capability_report(self)
+    }
+
+    /// Retrieves the [`AmdGpuMetrics`] table of the GPU device.
+    fn device_gpu_metrics(&self) -> Result<AmdGpuMetrics, AmdError>;
+
+    /// Retrieves the main metrics of the GPU device in one call: temperatures, activity, power, clocks,
+    /// throttle status and XGMI counters, decoded from its [`AmdGpuMetrics`].
+    ///
+    /// This is cheaper than the separate queries when many devices are polled frequently, and the values
+    /// are consistent with each other.
+    fn device_metrics(&self) -> Result<AmdDeviceMetrics, AmdError> {
+        Ok(self.device_gpu_metrics()?.device_metrics())
+    }
+
+    /// Retrieves the utilization of the video (VCN) and JPEG engines of the GPU device, from its [`AmdGpuMetrics`].
+    fn device_multimedia_activity(&self) -> Result<AmdMultimediaActivity, AmdError> {
+        Ok(self.device_gpu_metrics()?.multimedia_activity())
+    }
+
+    /// Retrieves the utilization of the memory bandwidth of the GPU device, from its [`AmdGpuMetrics`].
+    ///
+    /// Compared with the activity of the graphic core, it tells whether a workload is memory-bound or compute-bound.
+    fn device_memory_bandwidth(&self) -> Result<AmdMemoryBandwidth, AmdError> {
+        Ok(self.device_gpu_metrics()?.memory_bandwidth())
+    }
+
+    /// Retrieves the activity of the graphic core of each XCD of the GPU device, from its [`AmdGpuMetrics`].
+    ///
+    /// Unlike [`device_activity`](ProcessorHandle::device_activity), which aggregates all the dies,
+    /// this shows the imbalance between the dies of a multi-die or partitioned GPU.
+    /// The list is empty if the GPU does not report it.
+    fn device_activity_per_xcd(&self) -> Result<Vec<AmdXcdActivity>, AmdError> {
+        Ok(self.device_gpu_metrics()?.xcd_activity())
+    }
+
+    /// Retrieves the time during which the graphic engine of the GPU device has been busy, accumulated by the firmware.
+    ///
+    /// Unlike [`device_activity`](ProcessorHandle::device_activity), which is sampled, the difference between
+    /// two readings gives the exact duty cycle over the interval, see [`AmdBusyTime::duty_cycle_since`].
+    fn device_busy_time(&self) -> Result<AmdBusyTime, AmdError>;
+
+    /// Retrieves the time during which the memory controller (UMC) of the GPU device has been busy, accumulated
+    /// by the firmware.
+    ///
+    /// This is the accumulated counterpart of [`AmdEngineUsage::umc_activity`]: the difference between two
+    /// readings gives the duty cycle of the memory bandwidth over the interval, see [`AmdBusyTime::duty_cycle_since`].
+    fn device_memory_busy_time(&self) -> Result<AmdBusyTime, AmdError>;
+
+    /// Retrieves the [`AmdPciAddress`] of the GPU device.
+    fn device_pci_address(&self) -> Result<AmdPciAddress, AmdError>;
+
+    /// Retrieves a [`AmdDeviceIdentity`] structure containing the UUID, PCI address, serial numbers and model name of the GPU device.
+    ///
+    /// The identity does not change: it is read once, then cached by the handle.
+    fn device_identity(&self) -> Result<AmdDeviceIdentity, AmdError>;
+
+    /// Retrieves the serial number of the GPU device: the ASIC serial number if the device reports it,
+    /// else the serial number of the board, else its FRU (Field Replaceable Unit) identifier.
+    ///
+    /// The surrounding whitespace is removed. Empty, `N/A` and all-zero values are considered unavailable.
+    /// If no identifier is available, the error status is `NOT_SUPPORTED`.
+    fn device_serial_number(&self) -> Result<String, AmdError> {
+        let asic = self.device_asic_info().optional()?;
+        if let Some(asic) = asic
+            && let Some(serial) = valid_serial(&asic.asic_serial)
+        {
+            return Ok(serial.to_owned());
+        }
+        if let Some(board) = self.device_board_info().optional()?
+            && let Some(serial) =
+                valid_serial(&board.product_serial).or_else(|| valid_serial(&board.fru_id))
+        {
+            return Ok(serial.to_owned());
+        }
+        Err(AmdError::Status {
+            status: AmdStatus::AMDSMI_STATUS_NOT_SUPPORTED,
+            message: Some(String::from("no serial number available")),
+        })
+    }
+
+    /// Retrieves a [`AmdEnumerationInfo`] structure containing the indexes of the GPU device (DRM, HSA, HIP).
+    fn device_enumeration_info(&self) -> Result<AmdEnumerationInfo, AmdError>;
+
+    /// Returns the path of the DRM render node of the GPU device, for instance `/dev/dri/renderD128`.
+    fn device_drm_render_path(&self) -> Result<PathBuf, AmdError> {
+        let info = self.device_enumeration_info()?;
+        Ok(PathBuf::from(format!(
+            "/dev/dri/renderD{}",
+            info.drm_render
+        )))
+    }
+
+    /// Returns the sysfs directory of the GPU device, for instance `/sys/bus/pci/devices/0000:03:00.0`.
+    fn device_sysfs_path(&self) -> Result<PathBuf, AmdError> {
+        Ok(self.device_pci_address()?.sysfs_path())
+    }
+
+    /// Retrieves a [`AmdKfdInfo`] structure containing the identification of the GPU device by the compute driver (KFD).
+    fn device_kfd_info(&self) -> Result<AmdKfdInfo, AmdError>;
+
+    /// Returns the index of the compute partition of the GPU device, and the address of the physical GPU
+    /// that contains it, to distinguish the partitions of a GPU from distinct GPUs.
+    ///
+    /// The index is given by the compute driver (KFD) or, if it is not available, by the BDF identifier.
+    fn device_partition(&self) -> Result<AmdDevicePartition, AmdError> {
+        let address = self.device_pci_address()?;
+        let kfd_partition = self
+            .device_kfd_info()
+            .optional()?
+            .and_then(|info| info.current_partition_id);
+        Ok(AmdDevicePartition {
+            physical: address.physical(),
+            partition_id: kfd_partition.unwrap_or(address.partition as u32),
+        })
+    }
+
+    /// Returns the properties of the node of the GPU device in the KFD topology, read from sysfs.
+    ///
+    /// Returns `None` if the node is not known or its properties cannot be read.
+    /// See the [`kfd`] module.
+    fn device_kfd_properties(&self) -> Result<Option<KfdNodeProperties>, AmdError> {
+        Ok(self
+            .device_kfd_info()?
+            .node_id
+            .and_then(kfd::read_node_properties))
+    }
+
+    /// Returns the page fault counters of the processes that use the GPU device, read from sysfs.
+    ///
+    /// Returns an empty list if the device is not known by the KFD. See [`kfd::read_process_fault_counters`],
+    /// and [`KfdFaultCounters::total`](kfd::KfdFaultCounters::total) to get the counters of the device.
+    fn device_page_faults(&self) -> Result<Vec<KfdProcessFaults>, AmdError> {
+        Ok(self
+            .device_kfd_info()?
+            .kfd_id
+            .map(kfd::read_process_fault_counters)
+            .unwrap_or_default())
+    }
+
+    /// Returns the runtime power state of the GPU device, read from sysfs without waking it up.
+    ///
+    /// Returns `None` if the state is not available.
+    fn device_runtime_power_state(&self) -> Result<Option<RuntimePowerState>, AmdError> {
+        let path = self.device_sysfs_path()?;
+        Ok(power_state::read_runtime_power_state(&path))
+    }
+
+    /// Retrieves a [`AmdVbiosInfo`] structure containing data about the video BIOS.
+    fn device_vbios_info(&self) -> Result<AmdVbiosInfo, AmdError>;
+
+    /// Retrieves a [`AmdXgmiInfo`] structure containing data about the XGMI hive of the GPU device.
+    fn device_xgmi_info(&self) -> Result<AmdXgmiInfo, AmdError>;
+
+    /// Retrieves the metrics of the links (XGMI, PCIe) from the GPU device to the other GPUs.
+    fn device_link_metrics(&self) -> Result<Vec<AmdLinkMetrics>, AmdError>;
+
+    /// Retrieves the number of hops and the type of the link from the GPU device to `dst`.
+    fn device_link_type(&self, dst: &Self) -> Result<(u64, AmdLinkType), AmdError>;
+
+    /// Retrieves the weight of the link from the GPU device to `dst`.
+    fn device_link_weight(&self, dst: &Self) -> Result<u64, AmdError>;
+
+    /// Returns `true` if the memory of `dst` can be accessed directly (peer-to-peer) by the GPU device.
+    fn device_p2p_accessible(&self, dst: &Self) -> Result<bool, AmdError>;
+
+    /// Retrieves the type and the peer-to-peer capabilities of the link from the GPU device to `dst`.
+    fn device_p2p_status(&self, dst: &Self) -> Result<(AmdLinkType, AmdP2pCapability), AmdError>;
+
+    /// Retrieves all the properties of the link from the GPU device to `dst`.
+    ///
+    /// Fails only if the type of the link is not available.
+    fn device_link_info(&self, dst: &Self) -> Result<AmdLinkInfo, AmdError> {
+        let links = self.device_link_metrics().optional()?;
+        interconnect::link_info(self, dst, links.as_deref())
+    }
+
+    /// Retrieves the error status of the XGMI links of the GPU device.
+    fn device_xgmi_error_status(&self) -> Result<AmdXgmiStatus, AmdError>;
+
+    /// Retrieves the status (up, down or disabled) of each XGMI link of the GPU device, ordered by link index.
+    fn device_xgmi_link_status(&self) -> Result<Vec<AmdXgmiLinkStatus>, AmdError>;
+
+    /// Retrieves the NUMA node of the host that is the closest to the GPU device.
+    fn device_numa_node(&self) -> Result<u32, AmdError>;
+
+    /// Returns `true` if the GPU device supports the performance counters of an event group.
+    fn device_counter_group_supported(&self, group: AmdEventGroup) -> Result<bool, AmdError>;
+
+    /// Retrieves the number of performance counters of an event group that can still be created on the GPU device.
+    fn device_available_counters(&self, group: AmdEventGroup) -> Result<u32, AmdError>;
+
+    /// Creates a performance counter of an event on the GPU device. The counter is not started.
+    ///
+    /// See the [`counters`](crate::counters) module.
+    fn device_create_counter(&self, event: AmdEventType) -> Result<PerfCounter, AmdError>;
+
+    /// Measures the outbound throughput of each XGMI link of the GPU device during `interval`.
+    ///
+    /// This call blocks for `interval`. To measure the throughput periodically, keep an [`XgmiThroughputMonitor`].
+    fn device_xgmi_throughput(&self, interval: Duration) -> Result<Vec<XgmiThroughput>, AmdError> {
+        let monitor = XgmiThroughputMonitor::new(self)?;
+        thread::sleep(interval);
+        monitor.read()
+    }
+
+    /// Retrieves the voltage of a given area of the GPU device, in millivolts.
+    ///
+    /// # Arguments
+    ///
+    /// - `sensor_type`: Voltage retrieved by a [`AmdVoltageType`] sensor on AMD GPU hardware.
+    /// - `metric`: Voltage type [`AmdVoltageMetric`] analyzed (current, average...).
+    fn device_voltage(
+        &self,
+        sensor_type: AmdVoltageType,
+        metric: AmdVoltageMetric,
+    ) -> Result<i64, AmdError>;
+
+    /// Retrieves the current voltages of the GFX, SoC and memory rails of the GPU device, in millivolts.
+    ///
+    /// The voltages are taken from the power information, completed by the [`AmdGpuMetrics`] table,
+    /// then by the `VDDGFX` sensor. What is reported depends on the hardware family:
+    /// - consumer GPUs (Navi) usually report the GFX voltage only, through the power information and the sensor;
+    /// - Instinct MI200 series report the three rails in the GPU metrics table (version 1.3 and later);
+    /// - Instinct MI300 series and later do not report voltages.
+    fn device_rail_voltages(&self) -> Result<AmdRailVoltages, AmdError> {
+        let mut voltages = self
+            .device_power_consumption()
+            .optional()?
+            .map(|power| AmdRailVoltages::from_power(&power))
+            .unwrap_or_default();
+        if (voltages.gfx.is_none() || voltages.soc.is_none() || voltages.mem.is_none())
+            && let Some(metrics) = self.device_gpu_metrics().optional()?
+        {
+            voltages = voltages.or(metrics.rail_voltages());
+        }
+        if voltages.gfx.is_none() {
+            voltages.gfx = self
+                .device_voltage(
+                    AmdVoltageType::AMDSMI_VOLT_TYPE_VDDGFX,
+                    AmdVoltageMetric::AMDSMI_VOLT_CURRENT,
+                )
+                .optional()?
+                .filter(|v| *v > 0)
+                .map(|v| v as u64);
+        }
+        Ok(voltages)
+    }
+
+    /// Returns the voltage sensors of [`ALL_VOLTAGE_TYPES`] that report `metric` on the GPU device.
+    ///
+    /// The sensors are probed once per metric, then cached by the handle until
+    /// [`reset_unsupported_cache`](AmdProcessorHandle::reset_unsupported_cache) or a re-initialization of the library.
+    fn device_supported_voltage_sensors(
+        &self,
+        metric: AmdVoltageMetric,
+    ) -> Result<Vec<AmdVoltageType>, AmdError>;
+
+    /// Retrieves the voltage of every sensor of the GPU device, for every metric, in millivolts.
+    ///
+    /// For each of the [`ALL_VOLTAGE_METRICS`], the sensors given by
+    /// [`device_supported_voltage_sensors`](Self::device_supported_voltage_sensors) are queried.
+    fn device_voltages(
+        &self,
+    ) -> Result<HashMap<(AmdVoltageType, AmdVoltageMetric), i64>, AmdError> {
+        let mut voltages = HashMap::new();
+        for &metric in ALL_VOLTAGE_METRICS {
+            for sensor_type in self.device_supported_voltage_sensors(metric)? {
+                if let Some(voltage) = self.device_voltage(sensor_type, metric).optional()? {
+                    voltages.insert((sensor_type, metric), voltage);
+                }
+            }
+        }
+        Ok(voltages)
+    }
+}
+
+impl ProcessorHandle for AmdProcessorHandle {
+    fn device_activity(&self) -> Result<AmdEngineUsage, AmdError> {
+        self.call("device_activity", |handle| {
+            // Allocate uninitialized memory for the structure and avoid reading uninitialized memory before the FFI call.
+            let mut info = MaybeUninit::<amdsmi_engine_usage_t>::uninit();
+
+            // SAFETY: Pass a raw pointer to uninitialized memory to the FFI function.
+            // According to AMD-SMI documentation, the function fully initializes the structure on success.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_activity(handle, info.as_mut_ptr())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+
+            // SAFETY: `assume_init()` is safe because the FFI call succeeded and fully initialized `info`.
+            let info = unsafe { info.assume_init() };
+            Ok(info.into())
+        })
+    }
+
+    fn processor_type(&self) -> Result<AmdProcessorType, AmdError> {
+        self.call("processor_type", |handle| {
+            let mut processor_type = AmdProcessorType::AMDSMI_PROCESSOR_TYPE_UNKNOWN;
+
+            // SAFETY: Pass a non-null mutable pointer to `processor_type` for the FFI function to write the type.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_processor_type(handle, &mut processor_type)
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+            Ok(processor_type)
+        })
+    }
+
+    fn device_asic_info(&self) -> Result<AmdAsicInfo, AmdError> {
+        self.call("device_asic_info", |handle| {
+            // Allocate uninitialized memory for the structure and avoid reading uninitialized memory before the FFI call.
+            let mut info = MaybeUninit::<amdsmi_asic_info_t>::uninit();
+
+            // SAFETY: Pass a raw pointer to uninitialized memory to the FFI function.
+            // According to AMD-SMI documentation, the function fully initializes the structure on success.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_asic_info(handle, info.as_mut_ptr())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+
+            // SAFETY: `assume_init()` is safe because the FFI call succeeded and fully initialized `info`.
+            let info = unsafe { info.assume_init() };
+            Ok(info.into())
+        })
+    }
+
+    fn device_clock_frequencies(&self, clk_type: AmdClkType) -> Result<AmdFrequencies, AmdError> {
+        self.call_with_args("device_clock_frequencies", Some(&clk_type), |handle| {
+            let mut frequencies = MaybeUninit::<amdsmi_frequencies_t>::uninit();
+
+            // SAFETY: Pass a raw pointer to uninitialized memory for the FFI function to write into.
+            // According to AMD-SMI documentation, the function fully initializes the structure on success.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_clk_freq(handle, clk_type, frequencies.as_mut_ptr())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+
+            // SAFETY: The FFI call succeeded and fully initialized `frequencies`.
+            let frequencies = unsafe { frequencies.assume_init() };
+            Ok(frequencies.into())
+        })
+    }
+
+    fn device_clock_info(&self, clk_type: AmdClkType) -> Result<AmdClkInfo, AmdError> {
+        ffi::check_enum(clk_type, ALL_CLK_TYPES)?;
+        self.call_with_args("device_clock_info", Some(&clk_type), |handle| {
+            let mut info = MaybeUninit::<amdsmi_clk_info_t>::uninit();
+
+            // SAFETY: Pass a pointer to uninitialized memory to the FFI function.
+            // According to AMD-SMI documentation, the function fully initializes the `amdsmi_clk_info_t` on success.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_clock_info(handle, clk_type, info.as_mut_ptr())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+
+            // SAFETY: `assume_init()` is safe because the FFI call succeeded and the structure was fully initialized by the library.
+            let info = unsafe { info.assume_init() };
+            Ok(info.into())
+        })
+    }
+
+    fn device_cper_entries(
+        &self,
+        severity_mask: u32,
+        cursor: u64,
+    ) -> Result<AmdCperEntries, AmdError> {
+        self.call_with_args(
+            "device_cper_entries",
+            Some(&(severity_mask, cursor)),
+            |handle| {
+                let mut res = AmdCperEntries {
+                    entries: Vec::new(),
+                    cursor,
+                };
+                let mut data_size = CPER_BUFFER_SIZE;
+                loop {
+                    let mut data = vec![0u8; data_size];
+                    let mut headers = vec![ptr::null_mut::<amdsmi_cper_hdr_t>(); CPER_MAX_ENTRIES];
+                    let mut buf_size = data.len() as u64;
+                    let mut entry_count = headers.len() as u64;
+
+                    // SAFETY: Both buffers are allocated with the sizes given in `buf_size` and `entry_count`.
+                    // According to AMD-SMI documentation, the function does not write beyond these sizes, and
+                    // updates them with the size of the data and the number of headers that have been written.
+                    let result = unsafe {
+                        lib_call!(
+                            self.amdsmi.amdsmi.amdsmi,
+                            amdsmi_get_gpu_cper_entries(
+                                handle,
+                                severity_mask,
+                                data.as_mut_ptr() as *mut c_char,
+                                &mut buf_size,
+                                headers.as_mut_ptr(),
+                                &mut entry_count,
+                                &mut res.cursor,
+                            )
+                        )
+                    };
+
+                    match result {
+                        amdsmi_status_t::AMDSMI_STATUS_SUCCESS
+                        | amdsmi_status_t::AMDSMI_STATUS_MORE_DATA => {
+                            data.truncate(buf_size as usize);
+                            let count = (entry_count as usize).min(headers.len());
+                            for &header in &headers[..count] {
+                                res.entries.push(cper_entry(&data, header)?);
+                            }
+                            if result == amdsmi_status_t::AMDSMI_STATUS_SUCCESS {
+                                return Ok(res);
+                            }
+                            // According to AMD-SMI documentation: there are more entries, to retrieve with the new cursor.
+                        }
+                        // According to AMD-SMI documentation: the buffer is too small to hold one entry.
+                        amdsmi_status_t::AMDSMI_STATUS_OUT_OF_RESOURCES
+                            if data_size < CPER_MAX_BUFFER_SIZE =>
+                        {
+                            data_size *= 2;
+                        }
+                        err => {
+                            self.amdsmi.check_status(err)?;
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    fn device_energy_consumption(&self) -> Result<AmdEnergyConsumption, AmdError> {
+        self.call("device_energy_consumption", |handle| {
+            let mut consumption = AmdEnergyConsumption {
+                energy: 0,
+                resolution: 0.0,
+                timestamp: 0,
+            };
+
+            // SAFETY: Pass mutable pointers to the fields of `consumption` to the FFI function.
+            // According to AMD-SMI documentation, the function writes all values on success and will not write beyond the memory locations provided.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_energy_count(
+                        handle,
+                        &mut consumption.energy,
+                        &mut consumption.resolution,
+                        &mut consumption.timestamp,
+                    )
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+            Ok(consumption)
+        })
+    }
+
+    fn device_fan_speed(&self, sensor_index: u32) -> Result<u32, AmdError> {
+        self.call_with_args("device_fan_speed", Some(&sensor_index), |handle| {
+            let mut speed = 0;
+
+            // SAFETY: Pass a mutable pointer to `speed` for the FFI function to write the current fan speed.
+            // According to AMD-SMI documentation, the function writes a value between 0 and `AMDSMI_MAX_FAN_SPEED` to this pointer.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_fan_speed(handle, sensor_index, &mut speed)
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+            Ok((speed as u32 * 100) / MAX_FAN_SPEED)
+        })
+    }
+
+    fn device_fan_rpms(&self, sensor_index: u32) -> Result<i64, AmdError> {
+        self.call_with_args("device_fan_rpms", Some(&sensor_index), |handle| {
+            let mut rpm = 0;
+
+            // SAFETY: Pass a mutable pointer to `rpm` for the FFI function to write the current fan speed.
+            // According to AMD-SMI documentation, the function writes the speed in RPM to this pointer.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_fan_rpms(handle, sensor_index, &mut rpm)
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+            Ok(rpm)
+        })
+    }
+
+    fn device_fan_speed_max(&self, sensor_index: u32) -> Result<u64, AmdError> {
+        self.call_with_args("device_fan_speed_max", Some(&sensor_index), |handle| {
+            let mut max_speed = 0;
+
+            // SAFETY: Pass a mutable pointer to `max_speed` for the FFI function to write the maximal fan speed.
+            // According to AMD-SMI documentation, the function writes the value to this pointer.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_fan_speed_max(handle, sensor_index, &mut max_speed)
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+            Ok(max_speed)
+        })
+    }
+
+    fn device_memory_usage(&self, mem_type: AmdMemoryType) -> Result<u64, AmdError> {
+        ffi::check_enum(mem_type, ALL_MEMORY_TYPES)?;
+        self.call_with_args("device_memory_usage", Some(&mem_type), |handle| {
+            let mut used = 0;
+
+            // SAFETY: Pass a mutable pointer to `used` for the FFI function to write the memory usage.
+            // According to AMD-SMI documentation, the function will write a valid value on success and will not write outside the provided memory location.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_memory_usage(handle, mem_type, &mut used)
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+            Ok(used)
+        })
+    }
+
+    fn device_memory_total(&self, mem_type: AmdMemoryType) -> Result<u64, AmdError> {
+        ffi::check_enum(mem_type, ALL_MEMORY_TYPES)?;
+        self.call_with_args("device_memory_total", Some(&mem_type), |handle| {
+            let mut total = 0;
+
+            // SAFETY: Pass a mutable pointer to `total` for the FFI function to write the total amount of memory.
+            // According to AMD-SMI documentation, the function will write a valid value on success and will not write outside the provided memory location.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_memory_total(handle, mem_type, &mut total)
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+            Ok(total)
+        })
+    }
+
+    fn device_metrics_header(&self) -> Result<AmdMetricsHeader, AmdError> {
+        self.call("device_metrics_header", |handle| {
+            let mut header = MaybeUninit::<amd_metrics_table_header_t>::uninit();
+
+            // SAFETY: Pass a pointer to uninitialized memory to the FFI function.
+            // According to AMD-SMI documentation, the function fully initializes the `amd_metrics_table_header_t` on success.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_metrics_header_info(handle, header.as_mut_ptr())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+
+            // SAFETY: `assume_init()` is safe because the FFI call succeeded and the structure was fully initialized by the library.
+            let header = unsafe { header.assume_init() };
+            Ok(header.into())
+        })
+    }
+
+    fn device_gpu_metrics(&self) -> Result<AmdGpuMetrics, AmdError> {
+        self.call("device_gpu_metrics", |handle| {
+            // The table is large: allocate it on the heap.
+            let mut metrics = Box::<amdsmi_gpu_metrics_t>::new_uninit();
+
+            // SAFETY: Pass a pointer to uninitialized memory to the FFI function.
+            // According to AMD-SMI documentation, the function fully initializes the `amdsmi_gpu_metrics_t` on success.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_metrics_info(handle, metrics.as_mut_ptr())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+
+            // SAFETY: `assume_init()` is safe because the FFI call succeeded and the structure was fully initialized by the library.
+            let metrics = unsafe { metrics.assume_init() };
+            Ok(AmdGpuMetrics::new(metrics))
+        })
+    }
+
+    fn device_pci_usage(&self) -> Result<AmdPciTraffic, AmdError> {
+        self.call("device_pci_usage", |handle| {
+            let mut usage = AmdPciTraffic {
+                sent: 0,
+                received: 0,
+                max_pkt_sz: 0,
+            };
+
+            // SAFETY: Pass mutable pointers to the fields of `usage` to the FFI function.
+            // According to AMD-SMI documentation, the function writes all values on success or ignored them.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_pci_throughput(
+                        handle,
+                        &mut usage.sent,
+                        &mut usage.received,
+                        &mut usage.max_pkt_sz,
+                    )
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+            Ok(usage)
+        })
+    }
+
+    fn device_ecc_count(&self) -> Result<AmdEccCount, AmdError> {
+        self.call("device_ecc_count", |handle| {
+            let mut count = MaybeUninit::<amdsmi_error_count_t>::uninit();
+
+            // SAFETY: Pass a raw pointer to uninitialized memory for the FFI function to write into.
+            // According to AMD-SMI documentation, the function fully initializes the structure on success.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_total_ecc_count(handle, count.as_mut_ptr())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+
+            // SAFETY: The FFI call succeeded and fully initialized `count`.
+            let count = unsafe { count.assume_init() };
+            Ok(count.into())
+        })
+    }
+
+    fn device_block_ecc_count(&self, block: AmdGpuBlock) -> Result<AmdEccCount, AmdError> {
+        ffi::check_enum(block, ALL_GPU_BLOCKS)?;
+        self.call_raw_with_args("device_block_ecc_count", Some(&block), |raw, handle| {
+            // SAFETY: The function initializes the structure on success.
+            let count = unsafe {
+                raw.read_struct::<amdsmi_error_count_t>(|p| {
+                    lib_call!(raw.lib(), amdsmi_get_gpu_ecc_count(handle, block, p))
+                })?
+            };
+            Ok(count.into())
+        })
+    }
+
+    fn device_ras_features(&self) -> Result<AmdRasFeatures, AmdError> {
+        self.call_raw("device_ras_features", |raw, handle| {
+            // SAFETY: The function initializes the structure on success.
+            let features = unsafe {
+                raw.read_struct::<amdsmi_ras_feature_t>(|p| {
+                    lib_call!(raw.lib(), amdsmi_get_gpu_ras_feature_info(handle, p))
+                })?
+            };
+            Ok(features.into())
+        })
+    }
+
+    fn device_ras_block_state(&self, block: AmdGpuBlock) -> Result<AmdRasErrState, AmdError> {
+        ffi::check_enum(block, ALL_GPU_BLOCKS)?;
+        self.call_raw_with_args("device_ras_block_state", Some(&block), |raw, handle| {
+            // SAFETY: The function writes the state on success.
+            unsafe {
+                raw.read_struct::<AmdRasErrState>(|state| {
+                    lib_call!(
+                        raw.lib(),
+                        amdsmi_get_gpu_ras_block_features_enabled(handle, block, state)
+                    )
+                })
+            }
+        })
+    }
+
+    fn device_retired_pages(&self) -> Result<Vec<AmdRetiredPage>, AmdError> {
+        self.call_raw("device_retired_pages", |raw, handle| {
+            let mut count = 0;
+            // SAFETY: With a null buffer, the function only writes the number of pages.
+            raw.check(unsafe {
+                lib_call!(
+                    raw.lib(),
+                    amdsmi_get_gpu_bad_page_info(handle, &mut count, ptr::null_mut())
+                )
+            })?;
+            let mut buffer = Vec::new();
+            loop {
+                if count == 0 {
+                    return Ok(Vec::new());
+                }
+                // Pages can be retired between the two calls: the buffer has some room to spare.
+                let size = grown_buffer_size(buffer.len(), count as usize);
+                buffer.resize_with(size, MaybeUninit::<amdsmi_retired_page_record_t>::uninit);
+                count = size as u32;
+
+                // SAFETY: The buffer holds `count` elements. According to AMD-SMI documentation, the function
+                // writes at most `count` records, and updates `count` with the number of records written.
+                let result = unsafe {
+                    lib_call!(
+                        raw.lib(),
+                        amdsmi_get_gpu_bad_page_info(
+                            handle,
+                            &mut count,
+                            buffer.as_mut_ptr().cast(),
+                        )
+                    )
+                };
+                match result {
+                    amdsmi_status_t::AMDSMI_STATUS_SUCCESS => break,
+                    // the buffer was too small: retry with a larger buffer
+                    amdsmi_status_t::AMDSMI_STATUS_INSUFFICIENT_SIZE
+                    | amdsmi_status_t::AMDSMI_STATUS_OUT_OF_RESOURCES => continue,
+                    status => raw.check(status)?,
+                }
+            }
+            ffi::check_buffer(count as usize, buffer.len())?;
+            let count = (count as usize).min(buffer.len());
+            Ok(buffer[..count]
+                .iter()
+                .map(|record| {
+                    // SAFETY: The call succeeded and initialized the first `count` records.
+                    AmdRetiredPage::from(unsafe { record.assume_init() })
+                })
+                .collect())
+        })
+    }
+
+    fn device_pcie_info(&self) -> Result<AmdPcieInfo, AmdError> {
+        self.call("device_pcie_info", |handle| {
+            let mut info = MaybeUninit::<amdsmi_pcie_info_t>::uninit();
+
+            // SAFETY: Pass a raw pointer to uninitialized memory for the FFI function to write into.
+            // According to AMD-SMI documentation, the function fully initializes the structure on success.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_pcie_info(handle, info.as_mut_ptr())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+
+            // SAFETY: The FFI call succeeded and fully initialized `info`.
+            let info = unsafe { info.assume_init() };
+            Ok(info.into())
+        })
+    }
+
+    fn device_power_profile_status(&self) -> Result<AmdPowerProfileStatus, AmdError> {
+        self.call("device_power_profile_status", |handle| {
+            let mut status = MaybeUninit::<amdsmi_power_profile_status_t>::uninit();
+
+            // SAFETY: Pass a raw pointer to uninitialized memory for the FFI function to write into.
+            // The sensor index is reserved and must be 0.
+            // According to AMD-SMI documentation, the function fully initializes the structure on success.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_power_profile_presets(handle, 0, status.as_mut_ptr())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+
+            // SAFETY: The FFI call succeeded and fully initialized `status`.
+            let status = unsafe { status.assume_init() };
+            Ok(status.into())
+        })
+    }
+
+    /// Retrieves a [`amdsmi_power_info_t`] structure containing all data about GPU device power consumption.
+    fn device_power_consumption(&self) -> Result<AmdPowerConsumption, AmdError> {
+        self.call("device_power_consumption", |handle| {
+            // Reserve uninitialized memory space for the C function to fill.
+            let mut info = MaybeUninit::<amdsmi_power_info_t>::uninit();
+
+            // SAFETY: Pass a raw pointer to uninitialized memory for the FFI function to write into.
+            // `info` has exactly the size of `amdsmi_power_info_t`.
+            // According to AMD-SMI documentation, the function fully initializes the structure on success.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_power_info(handle, info.as_mut_ptr())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+
+            // SAFETY: `assume_init()` is safe because the FFI call returned SUCCESS, meaning `info` is fully initialized.
+            let info = unsafe { info.assume_init() };
+            Ok(info.into())
+        })
+    }
+
+    fn device_perf_level(&self) -> Result<AmdPerfLevel, AmdError> {
+        self.call("device_perf_level", |handle| {
+            let mut level = AmdPerfLevel::AMDSMI_DEV_PERF_LEVEL_UNKNOWN;
+
+            // SAFETY: Pass a mutable pointer to `level` for the FFI function to write the performance level.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_perf_level(handle, &mut level)
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+            Ok(level)
+        })
+    }
+
+    fn device_clock_limits(&self) -> Result<AmdClockLimits, AmdError> {
+        self.call_raw("device_clock_limits", |raw, handle| {
+            // SAFETY: The function initializes the structure on success.
+            let data = unsafe {
+                raw.read_struct::<amdsmi_od_volt_freq_data_t>(|p| {
+                    lib_call!(raw.lib(), amdsmi_get_gpu_od_volt_info(handle, p))
+                })?
+            };
+            Ok(data.into())
+        })
+    }
+
+    fn device_power_cap_info(&self, sensor_index: u32) -> Result<AmdPowerCapInfo, AmdError> {
+        self.call_with_args("device_power_cap_info", Some(&sensor_index), |handle| {
+            let mut info = MaybeUninit::<amdsmi_power_cap_info_t>::uninit();
+
+            // SAFETY: Pass a pointer to uninitialized memory to the FFI function.
+            // According to AMD-SMI documentation, the function fully initializes the `amdsmi_power_cap_info_t` on success.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_power_cap_info(handle, sensor_index, info.as_mut_ptr())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+
+            // SAFETY: `assume_init()` is safe because the FFI call succeeded and the structure was fully initialized by the library.
+            let info = unsafe { info.assume_init() };
+            Ok(info.into())
+        })
+    }
+
+    fn device_power_management_enabled(&self) -> Result<bool, AmdError> {
+        self.call("device_power_management_enabled", |handle| {
+            let mut enabled = false;
+
+            // SAFETY: Pass a mutable pointer to `enabled` for the FFI function to write the power management status.
+            // According to AMD-SMI documentation, the function will write a valid boolean value on success.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_is_gpu_power_management_enabled(handle, &mut enabled)
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+            Ok(enabled)
+        })
+    }
+
+    fn device_soc_pstate(&self) -> Result<AmdDpmPolicy, AmdError> {
+        self.call_raw("device_soc_pstate", |raw, handle| {
+            // SAFETY: According to AMD-SMI documentation, the function fully initializes the structure on success.
+            let policy = unsafe {
+                raw.read_struct::<amdsmi_dpm_policy_t>(|policy| {
+                    lib_call!(raw.lib(), amdsmi_get_soc_pstate(handle, policy))
+                })?
+            };
+            AmdDpmPolicy::try_from(&policy)
+        })
+    }
+
+    fn device_xgmi_plpd(&self) -> Result<AmdDpmPolicy, AmdError> {
+        self.call_raw("device_xgmi_plpd", |raw, handle| {
+            // SAFETY: According to AMD-SMI documentation, the function fully initializes the structure on success.
+            let policy = unsafe {
+                raw.read_struct::<amdsmi_dpm_policy_t>(|policy| {
+                    lib_call!(raw.lib(), amdsmi_get_xgmi_plpd(handle, policy))
+                })?
+            };
+            AmdDpmPolicy::try_from(&policy)
+        })
+    }
+
+    fn device_validate_ras_eeprom(&self) -> Result<bool, AmdError> {
+        self.call("device_validate_ras_eeprom", |handle| {
+            // SAFETY: The function only takes the processor handle, which is valid.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_gpu_validate_ras_eeprom(handle)
+                )
+            };
+
+            if result == amdsmi_status_t::AMDSMI_STATUS_CORRUPTED_EEPROM {
+                return Ok(false);
+            }
+            self.amdsmi.check_status(result)?;
+            Ok(true)
+        })
+    }
+
+    fn device_temperature(
         &self,
         sensor_type: AmdTemperatureType,
         metric: AmdTemperatureMetric,
     ) -> Result<i64, AmdError> {
-        let mut temperature = 0;
-
-        // SAFETY: Pass a mutable pointer to `temperature` for the FFI function to write the temperature value.
-        // According to AMD-SMI documentation, the function writes the value to this pointer.
-        // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
-        let result = unsafe {
-            self.amdsmi.amdsmi.amdsmi.amdsmi_get_temp_metric(
-                self.inner,
-                sensor_type,
-                metric,
-                &mut temperature,
-            )
-        };
+        ffi::check_enum(sensor_type, ALL_TEMPERATURE_TYPES)?;
+        ffi::check_enum(metric, ALL_TEMPERATURE_METRICS)?;
+        self.call_with_args(
+            "device_temperature",
+            Some(&(sensor_type, metric)),
+            |handle| {
+                let mut temperature = 0;
+
+                // SAFETY: Pass a mutable pointer to `temperature` for the FFI function to write the temperature value.
+                // According to AMD-SMI documentation, the function writes the value to this pointer.
+                // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+                let result = unsafe {
+                    lib_call!(
+                        self.amdsmi.amdsmi.amdsmi,
+                        amdsmi_get_temp_metric(handle, sensor_type, metric, &mut temperature)
+                    )
+                };
+
+                self.amdsmi.check_status(result)?;
+                Ok(temperature)
+            },
+        )
+    }
+
+    fn device_xgmi_info(&self) -> Result<AmdXgmiInfo, AmdError> {
+        self.call("device_xgmi_info", |handle| {
+            let mut info = MaybeUninit::<amdsmi_xgmi_info_t>::uninit();
+
+            // SAFETY: Pass a raw pointer to uninitialized memory to the FFI function.
+            // According to AMD-SMI documentation, the function fully initializes the structure on success.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_xgmi_info(handle, info.as_mut_ptr())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+
+            // SAFETY: `assume_init()` is safe because the FFI call succeeded and fully initialized `info`.
+            let info = unsafe { info.assume_init() };
+            Ok(info.into())
+        })
+    }
+
+    fn device_link_metrics(&self) -> Result<Vec<AmdLinkMetrics>, AmdError> {
+        self.call_raw("device_link_metrics", |raw, handle| {
+            // SAFETY: The function initializes the structure on success.
+            let metrics = unsafe {
+                raw.read_struct::<amdsmi_link_metrics_t>(|p| {
+                    lib_call!(raw.lib(), amdsmi_get_link_metrics(handle, p))
+                })?
+            };
+            let count = metrics.num_links as usize;
+            ffi::check_buffer(count, metrics.links.len())?;
+            Ok(metrics.links[..count.min(metrics.links.len())]
+                .iter()
+                .map(AmdLinkMetrics::from)
+                .collect())
+        })
+    }
+
+    fn device_link_type(&self, dst: &Self) -> Result<(u64, AmdLinkType), AmdError> {
+        interconnect::link_type(self, dst)
+    }
+
+    fn device_link_weight(&self, dst: &Self) -> Result<u64, AmdError> {
+        interconnect::link_weight(self, dst)
+    }
+
+    fn device_p2p_accessible(&self, dst: &Self) -> Result<bool, AmdError> {
+        interconnect::p2p_accessible(self, dst)
+    }
+
+    fn device_p2p_status(&self, dst: &Self) -> Result<(AmdLinkType, AmdP2pCapability), AmdError> {
+        interconnect::p2p_status(self, dst)
+    }
+
+    fn device_xgmi_error_status(&self) -> Result<AmdXgmiStatus, AmdError> {
+        self.call_raw("device_xgmi_error_status", |raw, handle| {
+            // SAFETY: The function writes the status on success.
+            unsafe {
+                raw.read_struct::<AmdXgmiStatus>(|status| {
+                    lib_call!(raw.lib(), amdsmi_gpu_xgmi_error_status(handle, status))
+                })
+            }
+        })
+    }
+
+    fn device_xgmi_link_status(&self) -> Result<Vec<AmdXgmiLinkStatus>, AmdError> {
+        self.call("device_xgmi_link_status", |handle| {
+            let mut link_status = MaybeUninit::<amdsmi_xgmi_link_status_t>::uninit();
+
+            // SAFETY: Pass a raw pointer to uninitialized memory to the FFI function.
+            // According to AMD-SMI documentation, the function fully initializes the structure on success.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_xgmi_link_status(handle, link_status.as_mut_ptr())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+
+            // SAFETY: `assume_init()` is safe because the FFI call succeeded and fully initialized `link_status`.
+            let link_status = unsafe { link_status.assume_init() };
+            let total_links = (link_status.total_links as usize).min(link_status.status.len());
+            Ok(link_status.status[..total_links].to_vec())
+        })
+    }
+
+    fn device_busy_time(&self) -> Result<AmdBusyTime, AmdError> {
+        self.read_busy_time(
+            "device_busy_time",
+            amdsmi_utilization_counter_type_t::AMDSMI_COARSE_GRAIN_GFX_ACTIVITY,
+        )
+    }
+
+    fn device_memory_busy_time(&self) -> Result<AmdBusyTime, AmdError> {
+        self.read_busy_time(
+            "device_memory_busy_time",
+            amdsmi_utilization_counter_type_t::AMDSMI_COARSE_GRAIN_MEM_ACTIVITY,
+        )
+    }
 
-        self.amdsmi.check_status(result)?;
-        Ok(temperature)
+    fn device_numa_node(&self) -> Result<u32, AmdError> {
+        self.call_raw("device_numa_node", |raw, handle| {
+            raw.read_scalar(|node| {
+                // SAFETY: The pointer is valid for the duration of the call.
+                unsafe { lib_call!(raw.lib(), amdsmi_topo_get_numa_node_number(handle, node)) }
+            })
+        })
+    }
+
+    fn device_counter_group_supported(&self, group: AmdEventGroup) -> Result<bool, AmdError> {
+        counters::counter_group_supported(self, group)
+    }
+
+    fn device_available_counters(&self, group: AmdEventGroup) -> Result<u32, AmdError> {
+        counters::available_counters(self, group)
+    }
+
+    fn device_create_counter(&self, event: AmdEventType) -> Result<PerfCounter, AmdError> {
+        counters::create_counter(self, event)
     }
 
     fn device_voltage(
@@ -364,117 +2326,508 @@ impl ProcessorHandle for AmdProcessorHandle {
         sensor_type: AmdVoltageType,
         metric: AmdVoltageMetric,
     ) -> Result<i64, AmdError> {
-        let mut voltage = 0;
-
-        // SAFETY: Pass a non-null mutable pointer to `voltage` for the FFI function to write the voltage value.
-        // According to AMD-SMI documentation, the function writes the value to this pointer.
-        // The value is only read after confirming that the return status is SUCCESS.
-        // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
-        let result = unsafe {
-            self.amdsmi.amdsmi.amdsmi.amdsmi_get_gpu_volt_metric(
-                self.inner,
-                sensor_type,
-                metric,
-                &mut voltage,
-            )
-        };
+        ffi::check_enum(sensor_type, ALL_VOLTAGE_TYPES)?;
+        ffi::check_enum(metric, ALL_VOLTAGE_METRICS)?;
+        self.call_with_args("device_voltage", Some(&(sensor_type, metric)), |handle| {
+            let mut voltage = 0;
+
+            // SAFETY: Pass a non-null mutable pointer to `voltage` for the FFI function to write the voltage value.
+            // According to AMD-SMI documentation, the function writes the value to this pointer.
+            // The value is only read after confirming that the return status is SUCCESS.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_volt_metric(handle, sensor_type, metric, &mut voltage)
+                )
+            };
 
-        self.amdsmi.check_status(result)?;
-        Ok(voltage)
+            self.amdsmi.check_status(result)?;
+            Ok(voltage)
+        })
     }
 
     fn device_process_list(&self) -> Result<Vec<AmdProcess>, AmdError> {
-        let mut max_processes = 0;
-
-        // SAFETY: Retrieves the total number of GPU processes.
-        // Passing `null_mut()` as the buffer tells the FFI to only write the count to `max_processes`.
-        // According to AMD-SMI documentation, `max_processes` will be updated with the actual number of processes.
-        let result = unsafe {
-            self.amdsmi.amdsmi.amdsmi.amdsmi_get_gpu_process_list(
-                self.inner,
-                &mut max_processes,
-                ptr::null_mut(),
-            )
-        };
-
-        match result {
-            amdsmi_status_t::AMDSMI_STATUS_SUCCESS => {}
-            amdsmi_status_t::AMDSMI_STATUS_OUT_OF_RESOURCES => {}
-            err => {
-                return Err(AmdError {
-                    status: err,
-                    message: None,
-                });
-            }
-        }
+        let mut processes = Vec::new();
+        self.device_process_list_into(&mut processes)?;
+        Ok(processes)
+    }
 
-        if max_processes == 0 {
-            return Ok(Vec::new());
-        }
+    fn device_process_list_into(&self, processes: &mut Vec<AmdProcess>) -> Result<(), AmdError> {
+        let count = self.call("device_process_list_into", |handle| {
+            // The buffer is kept by the handle, to avoid allocating it for every query.
+            let mut buffer = self.process_buffer.borrow_mut();
+
+            if buffer.is_empty() {
+                let mut max_processes = 0;
+
+                // SAFETY: Retrieves the total number of GPU processes.
+                // Passing `null_mut()` as the buffer tells the FFI to only write the count to `max_processes`.
+                // According to AMD-SMI documentation, `max_processes` will be updated with the actual number of processes.
+                let result = unsafe {
+                    lib_call!(
+                        self.amdsmi.amdsmi.amdsmi,
+                        amdsmi_get_gpu_process_list(handle, &mut max_processes, ptr::null_mut())
+                    )
+                };
+
+                match result {
+                    amdsmi_status_t::AMDSMI_STATUS_SUCCESS => {}
+                    amdsmi_status_t::AMDSMI_STATUS_OUT_OF_RESOURCES => {}
+                    err => {
+                        return Err(AmdError::Status {
+                            status: err,
+                            message: None,
+                        });
+                    }
+                }
 
-        loop {
-            let mut buffer: Vec<MaybeUninit<amdsmi_proc_info_t>> =
-                Vec::with_capacity(max_processes as usize);
-
-            let mut count = max_processes;
-
-            // SAFETY: Pass a pointer to the uninitialized buffer.
-            // According the AMD-SMI library documentation, all elements up to `count` are written in case of `SUCCESS` or `OUT_OF_RESOURCES`.
-            // There is no uninitialized memory read before the function writes to it.
-            let result = unsafe {
-                self.amdsmi.amdsmi.amdsmi.amdsmi_get_gpu_process_list(
-                    self.inner,
-                    &mut count,
-                    buffer.as_mut_ptr() as *mut amdsmi_proc_info_t,
-                )
-            };
-
-            match result {
-                // SAFETY: According to AMD-SMI documentation, all elements up to `count` are written to the provided buffer.
-                // Allocated `max_processes` elements in `SUCCESS` status implies all elements are initialized.
-                amdsmi_status_t::AMDSMI_STATUS_SUCCESS => unsafe {
-                    buffer.set_len(count as usize);
-                    let processes = buffer
-                        .into_iter()
-                        .map(|x| AmdProcess::from(x.assume_init()))
-                        .collect();
-
-                    return Ok(processes);
-                },
-                // According to AMD-SMI documentation: The buffer was filled up to its capacity.
-                // A counter is used to contain the actual total number of processes.
-                // If The buffer was too small, we retry with the new required size.
-                amdsmi_status_t::AMDSMI_STATUS_OUT_OF_RESOURCES => {
-                    max_processes = count;
-                    continue;
+                if max_processes == 0 {
+                    return Ok(0);
                 }
-                err => {
-                    return Err(AmdError {
-                        status: err,
-                        message: None,
-                    });
+                let size = grown_buffer_size(0, max_processes as usize);
+                buffer.resize_with(size, MaybeUninit::uninit);
+            }
+
+            loop {
+                let mut count = buffer.len() as u32;
+
+                // SAFETY: Pass a pointer to the uninitialized buffer, which holds `count` elements.
+                // According the AMD-SMI library documentation, all elements up to `count` are written in case of `SUCCESS` or `OUT_OF_RESOURCES`.
+                // There is no uninitialized memory read before the function writes to it.
+                let result = unsafe {
+                    lib_call!(
+                        self.amdsmi.amdsmi.amdsmi,
+                        amdsmi_get_gpu_process_list(
+                            handle,
+                            &mut count,
+                            buffer.as_mut_ptr() as *mut amdsmi_proc_info_t,
+                        )
+                    )
+                };
+
+                match result {
+                    amdsmi_status_t::AMDSMI_STATUS_SUCCESS => {
+                        ffi::check_buffer(count as usize, buffer.len())?;
+                        return Ok((count as usize).min(buffer.len()));
+                    }
+                    // According to AMD-SMI documentation: The buffer was filled up to its capacity.
+                    // A counter is used to contain the actual total number of processes.
+                    // If The buffer was too small, we retry with a larger buffer. It grows geometrically,
+                    // so that a fluctuating number of processes does not cause a retry at each query.
+                    amdsmi_status_t::AMDSMI_STATUS_OUT_OF_RESOURCES => {
+                        let size = grown_buffer_size(buffer.len(), count as usize);
+                        buffer.resize_with(size, MaybeUninit::uninit);
+                        continue;
+                    }
+                    err => {
+                        return Err(AmdError::Status {
+                            status: err,
+                            message: None,
+                        });
+                    }
                 }
             }
+        })?;
+
+        let buffer = self.process_buffer.borrow();
+        processes.truncate(count);
+        for (i, info) in buffer[..count].iter().enumerate() {
+            // SAFETY: According to AMD-SMI documentation, all elements up to `count` have been written by the successful call.
+            let info = unsafe { info.assume_init_ref() };
+            match processes.get_mut(i) {
+                Some(process) => process.update_from(info),
+                None => processes.push(AmdProcess::from(*info)),
+            }
+        }
+        Ok(())
+    }
+
+    fn device_is_alive(&self) -> bool {
+        if !self.amdsmi.auto_recovery() && self.generation.get() != self.amdsmi.generation() {
+            return false;
+        }
+        match self.call("device_is_alive", |handle| self.amdsmi.raw_bdf_id(handle)) {
+            Ok(_) => true,
+            // the query may be unavailable on some platforms, this says nothing about the handle
+            Err(e) => e.is_unavailable() || matches!(e, AmdError::DeviceSuspended),
         }
     }
 
     fn device_uuid(&self) -> Result<String, AmdError> {
-        let mut uuid_buffer = [0 as c_char; AMDSMI_GPU_UUID_SIZE as usize];
-        let mut uuid_length = AMDSMI_GPU_UUID_SIZE;
-
-        // SAFETY: According to AMD-SMI documentation, the function will not write beyond `uuid_length`.
-        // `uuid_length` must be initialized to the buffer size, and the function will update it with the actual length.
-        let result = unsafe {
-            self.amdsmi.amdsmi.amdsmi.amdsmi_get_gpu_device_uuid(
-                self.inner,
-                &mut uuid_length,
-                uuid_buffer.as_mut_ptr(),
-            )
+        let uuid = self.device_uuid_bytes()?;
+        let uuid = String::from_utf8(uuid).map_err(|e| AmdError::InvalidString(e.utf8_error()))?;
+        Ok(uuid)
+    }
+
+    fn device_uuid_bytes(&self) -> Result<Vec<u8>, AmdError> {
+        self.call("device_uuid_bytes", |handle| {
+            let mut uuid_buffer = StringBuffer::<GPU_UUID_SIZE>::new();
+            let mut uuid_length = uuid_buffer.capacity() as c_uint;
+
+            // SAFETY: According to AMD-SMI documentation, the function will not write beyond `uuid_length`.
+            // `uuid_length` must be initialized to the buffer size, and the function will update it with the actual length.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_device_uuid(handle, &mut uuid_length, uuid_buffer.as_mut_ptr())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+
+            uuid_buffer.set_reported_len(uuid_length as usize);
+            Ok(uuid_buffer.as_bytes().to_vec())
+        })
+    }
+
+    fn device_vendor_name(&self) -> Result<String, AmdError> {
+        self.call("device_vendor_name", |handle| {
+            let mut buffer = StringBuffer::<MAX_STRING_LENGTH>::new();
+
+            // SAFETY: According to AMD-SMI documentation, the function writes at most `len` characters to the buffer.
+            // If the name does not fit, `INSUFFICIENT_SIZE` is returned.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_vendor_name(handle, buffer.as_mut_ptr(), buffer.capacity())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+            buffer.to_string()
+        })
+    }
+
+    fn device_vram_vendor(&self) -> Result<String, AmdError> {
+        self.call("device_vram_vendor", |handle| {
+            let mut buffer = StringBuffer::<MAX_STRING_LENGTH>::new();
+
+            // SAFETY: According to AMD-SMI documentation, the function writes at most `len` characters to the buffer.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_vram_vendor(
+                        handle,
+                        buffer.as_mut_ptr(),
+                        buffer.capacity() as u32,
+                    )
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+            buffer.to_string()
+        })
+    }
+
+    fn device_subsystem_name(&self) -> Result<String, AmdError> {
+        self.call("device_subsystem_name", |handle| {
+            let mut buffer = StringBuffer::<MAX_STRING_LENGTH>::new();
+
+            // SAFETY: According to AMD-SMI documentation, the function writes at most `len` characters to the buffer.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_subsystem_name(handle, buffer.as_mut_ptr(), buffer.capacity())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+            buffer.to_string()
+        })
+    }
+
+    fn device_board_info(&self) -> Result<AmdBoardInfo, AmdError> {
+        self.call("device_board_info", |handle| {
+            let mut info = MaybeUninit::<amdsmi_board_info_t>::uninit();
+
+            // SAFETY: Pass a raw pointer to uninitialized memory to the FFI function.
+            // According to AMD-SMI documentation, the function fully initializes the structure on success.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_board_info(handle, info.as_mut_ptr())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+
+            // SAFETY: `assume_init()` is safe because the FFI call succeeded and fully initialized `info`.
+            let info = unsafe { info.assume_init() };
+            AmdBoardInfo::try_from(info)
+        })
+    }
+
+    fn device_driver_info(&self) -> Result<AmdDriverInfo, AmdError> {
+        self.call("device_driver_info", |handle| {
+            let mut info = MaybeUninit::<amdsmi_driver_info_t>::uninit();
+
+            // SAFETY: Pass a raw pointer to uninitialized memory to the FFI function.
+            // According to AMD-SMI documentation, the function fully initializes the structure on success.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_driver_info(handle, info.as_mut_ptr())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+
+            // SAFETY: `assume_init()` is safe because the FFI call succeeded and fully initialized `info`.
+            let info = unsafe { info.assume_init() };
+            AmdDriverInfo::try_from(info)
+        })
+    }
+
+    fn device_firmwares(&self) -> Result<Vec<AmdFirmwareInfo>, AmdError> {
+        self.call("device_firmwares", |handle| {
+            let mut info = MaybeUninit::<amdsmi_fw_info_t>::uninit();
+
+            // SAFETY: Pass a raw pointer to uninitialized memory to the FFI function.
+            // According to AMD-SMI documentation, the function fully initializes the structure on success.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_fw_info(handle, info.as_mut_ptr())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+
+            // SAFETY: `assume_init()` is safe because the FFI call succeeded and fully initialized `info`.
+            let info = unsafe { info.assume_init() };
+            Ok(firmware_list(&info))
+        })
+    }
+
+    fn device_compute_partition_name(&self) -> Result<String, AmdError> {
+        self.call("device_compute_partition_name", |handle| {
+            let mut buffer = StringBuffer::<MAX_STRING_LENGTH>::new();
+
+            // SAFETY: According to AMD-SMI documentation, the function writes at most `len` characters to the buffer.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_compute_partition(
+                        handle,
+                        buffer.as_mut_ptr(),
+                        buffer.capacity() as u32,
+                    )
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+            buffer.to_string()
+        })
+    }
+
+    fn device_memory_partition_name(&self) -> Result<String, AmdError> {
+        self.call("device_memory_partition_name", |handle| {
+            let mut buffer = StringBuffer::<MAX_STRING_LENGTH>::new();
+
+            // SAFETY: According to AMD-SMI documentation, the function writes at most `len` characters to the buffer.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_memory_partition(
+                        handle,
+                        buffer.as_mut_ptr(),
+                        buffer.capacity() as u32,
+                    )
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+            buffer.to_string()
+        })
+    }
+
+    fn lib_version(&self) -> Result<AmdVersion, AmdError> {
+        self.amdsmi.lib_version()
+    }
+
+    fn device_identity(&self) -> Result<AmdDeviceIdentity, AmdError> {
+        if let Some(identity) = self.identity.get() {
+            return Ok(identity.clone());
+        }
+        let asic = self.device_asic_info()?;
+        let identity = AmdDeviceIdentity {
+            uuid: self.device_uuid()?,
+            pci_address: self.device_pci_address()?,
+            asic_serial: asic.asic_serial,
+            board_serial: self
+                .device_board_info()
+                .optional()?
+                .map(|board| board.product_serial),
+            market_name: asic.market_name,
         };
+        Ok(self.identity.get_or_init(|| identity).clone())
+    }
 
-        self.amdsmi.check_status(result)?;
+    fn device_read_metric(&self, kind: MetricKind) -> Result<MetricValue, AmdError> {
+        let value = self.read_metric_limited(kind)?;
+        Ok(self
+            .amdsmi
+            .unit_system()
+            .convert(value, kind.metadata().unit))
+    }
 
-        let uuid = c_buffer_to_string(uuid_buffer.as_slice());
-        Ok(uuid)
+    fn device_metric_unit(&self, kind: MetricKind) -> MetricUnit {
+        self.amdsmi.unit_system().unit(kind.metadata().unit)
+    }
+
+    fn unit_system(&self) -> UnitSystem {
+        self.amdsmi.unit_system()
+    }
+
+    fn device_power_cap_quantity(&self, sensor_index: u32) -> Result<Quantity, AmdError> {
+        let power_cap = self.device_power_cap_info(sensor_index)?.power_cap;
+        let unit = power_cap_unit(self.amdsmi.library_variant());
+        Ok(self.unit_system().quantity(power_cap as f64, unit))
+    }
+
+    fn device_supported_temperature_sensors(
+        &self,
+        metric: AmdTemperatureMetric,
+    ) -> Result<Vec<AmdTemperatureType>, AmdError> {
+        self.probe_sensors(
+            &self.temperature_sensors,
+            metric,
+            ALL_TEMPERATURE_TYPES,
+            |sensor, metric| self.device_temperature(sensor, metric),
+        )
+    }
+
+    fn device_supported_voltage_sensors(
+        &self,
+        metric: AmdVoltageMetric,
+    ) -> Result<Vec<AmdVoltageType>, AmdError> {
+        self.probe_sensors(
+            &self.voltage_sensors,
+            metric,
+            ALL_VOLTAGE_TYPES,
+            |sensor, metric| self.device_voltage(sensor, metric),
+        )
+    }
+
+    fn device_pci_address(&self) -> Result<AmdPciAddress, AmdError> {
+        self.call("device_pci_address", |handle| {
+            let bdf_id = self.amdsmi.raw_bdf_id(handle)?;
+            Ok(AmdPciAddress::from_bdf_id(bdf_id))
+        })
+    }
+
+    fn device_enumeration_info(&self) -> Result<AmdEnumerationInfo, AmdError> {
+        self.call("device_enumeration_info", |handle| {
+            let mut info = MaybeUninit::<amdsmi_enumeration_info_t>::uninit();
+
+            // SAFETY: Pass a raw pointer to uninitialized memory to the FFI function.
+            // According to AMD-SMI documentation, the function fully initializes the structure on success.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_enumeration_info(handle, info.as_mut_ptr())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+
+            // SAFETY: `assume_init()` is safe because the FFI call succeeded and fully initialized `info`.
+            let info = unsafe { info.assume_init() };
+            AmdEnumerationInfo::try_from(info)
+        })
+    }
+
+    fn device_kfd_info(&self) -> Result<AmdKfdInfo, AmdError> {
+        self.call_raw("device_kfd_info", |raw, handle| {
+            // SAFETY: According to AMD-SMI documentation, the function fully initializes the structure on success.
+            let info = unsafe {
+                raw.read_struct::<amdsmi_kfd_info_t>(|info| {
+                    lib_call!(raw.lib(), amdsmi_get_gpu_kfd_info(handle, info))
+                })?
+            };
+            Ok(info.into())
+        })
+    }
+
+    fn device_vbios_info(&self) -> Result<AmdVbiosInfo, AmdError> {
+        self.call("device_vbios_info", |handle| {
+            let mut info = MaybeUninit::<amdsmi_vbios_info_t>::uninit();
+
+            // SAFETY: Pass a raw pointer to uninitialized memory to the FFI function.
+            // According to AMD-SMI documentation, the function fully initializes the structure on success.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            let result = unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi.amdsmi,
+                    amdsmi_get_gpu_vbios_info(handle, info.as_mut_ptr())
+                )
+            };
+
+            self.amdsmi.check_status(result)?;
+
+            // SAFETY: `assume_init()` is safe because the FFI call succeeded and fully initialized `info`.
+            let info = unsafe { info.assume_init() };
+            AmdVbiosInfo::try_from(info)
+        })
+    }
+}
+
+/// Copies the CPER entry whose header has been written by the library at `header`, inside `data`.
+fn cper_entry(data: &[u8], header: *const amdsmi_cper_hdr_t) -> Result<AmdCperEntry, AmdError> {
+    let header_size = size_of::<amdsmi_cper_hdr_t>();
+    let offset = (header as usize).wrapping_sub(data.as_ptr() as usize);
+    if header.is_null() || offset.saturating_add(header_size) > data.len() {
+        return Err(AmdError::Status {
+            status: AmdStatus::AMDSMI_STATUS_UNEXPECTED_DATA,
+            message: Some(String::from("CPER header outside of the data buffer")),
+        });
+    }
+
+    // SAFETY: The header is entirely inside `data` (checked above), and `amdsmi_cper_hdr_t` is packed,
+    // hence it can be read at any address.
+    let header =
+        unsafe { ptr::read_unaligned(data[offset..].as_ptr() as *const amdsmi_cper_hdr_t) };
+    let length = (header.record_length as usize).clamp(header_size, data.len() - offset);
+    AmdCperEntry::new(&header, data[offset..offset + length].to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SensorCache, grown_buffer_size};
+    use crate::metrics::{AmdTemperatureMetric, AmdTemperatureType};
+
+    #[test]
+    fn sensor_cache() {
+        let mut cache = SensorCache::new();
+        let current = AmdTemperatureMetric::AMDSMI_TEMP_CURRENT;
+        let critical = AmdTemperatureMetric::AMDSMI_TEMP_CRITICAL;
+        cache.insert(
+            current,
+            vec![AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_EDGE],
+        );
+        cache.insert(
+            critical,
+            vec![AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_HOTSPOT],
+        );
+        assert_eq!(
+            cache.get(critical, 0),
+            Some(vec![AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_HOTSPOT])
+        );
+        // reset of the caches of unavailable metrics
+        assert_eq!(cache.get(current, 1), None);
+        assert_eq!(cache.get(critical, 1), None);
+    }
+
+    #[test]
+    fn buffer_growth() {
+        assert_eq!(grown_buffer_size(0, 1), 16);
+        assert_eq!(grown_buffer_size(0, 40), 60);
+        assert_eq!(grown_buffer_size(60, 61), 120);
+        assert_eq!(grown_buffer_size(16, 100), 150);
     }
 }
@@ -0,0 +1,260 @@
+//! Instrumentation of the calls to the library.
+//!
+//! # Logs
+//! With the `log` feature, each call emits a debug record with the target `amd_smi_wrapper::ffi`,
+//! which contains the name of the function, the PCI address of the device (if any),
+//! the raw status returned by the library and the duration of the call. The errors that do not come from
+//! the library (invalid strings, suspended devices...) have no status, but an `error` field with the
+//! [kind](crate::error::AmdError::kind) of the error.
+//! The records can be enabled at runtime, for instance with `RUST_LOG=amd_smi_wrapper::ffi=debug`
+//! when the program uses `env_logger`.
+//!
+//! The same fields are attached to the record as key-values, for the loggers that support them.
+//!
+//! # Latency statistics
+//! With [`AmdSmi::set_latency_stats`](crate::AmdSmi::set_latency_stats), the latency of the calls is
+//! recorded per function, and returned by [`AmdSmi::latency_stats`](crate::AmdSmi::latency_stats).
+//!
+//! # Trace capture
+//! With the `trace-capture` feature, the calls, their arguments and their results can be written to a file,
+//! see the `capture` module. The arguments and the results are formatted with [`Debug`] only while a capture
+//! is in progress.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "trace-capture")]
+use crate::capture::{TraceCapture, TraceRecord};
+use crate::{LibAmdSmi, error::AmdError, metrics::AmdPciAddress};
+
+/// Target of the log records of the calls.
+#[cfg(feature = "log")]
+const TARGET: &str = "amd_smi_wrapper::ffi";
+
+/// Latency of the calls to a function, see [`AmdSmi::latency_stats`](crate::AmdSmi::latency_stats).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CallLatency {
+    /// Number of calls.
+    pub count: u64,
+    /// Shortest call.
+    pub min: Duration,
+    /// Longest call.
+    pub max: Duration,
+    /// Total duration of the calls.
+    pub total: Duration,
+}
+
+impl CallLatency {
+    fn new(duration: Duration) -> Self {
+        Self {
+            count: 1,
+            min: duration,
+            max: duration,
+            total: duration,
+        }
+    }
+
+    fn record(&mut self, duration: Duration) {
+        self.count += 1;
+        self.min = self.min.min(duration);
+        self.max = self.max.max(duration);
+        self.total += duration;
+    }
+
+    /// Returns the mean duration of the calls.
+    pub fn mean(&self) -> Duration {
+        Duration::from_nanos((self.total.as_nanos() / u128::from(self.count.max(1))) as u64)
+    }
+}
+
+/// Latency statistics of the calls, per function.
+#[derive(Debug, Default)]
+pub(crate) struct LatencyRecorder {
+    enabled: AtomicBool,
+    stats: Mutex<BTreeMap<&'static str, CallLatency>>,
+}
+
+impl LatencyRecorder {
+    pub(crate) fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    fn record(&self, function: &'static str, duration: Duration) {
+        let mut stats = self.stats.lock().unwrap_or_else(|e| e.into_inner());
+        stats
+            .entry(function)
+            .and_modify(|s| s.record(duration))
+            .or_insert_with(|| CallLatency::new(duration));
+    }
+
+    pub(crate) fn snapshot(&self) -> BTreeMap<&'static str, CallLatency> {
+        self.stats.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    pub(crate) fn reset(&self) {
+        self.stats.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+}
+
+/// Call to the library, started by [`CallSpan::start`] and recorded by [`CallSpan::finish`].
+pub(crate) struct CallSpan<'a> {
+    function: &'static str,
+    /// Arguments of the call, only formatted for the captures.
+    arguments: Option<&'a dyn Debug>,
+    recorder: &'a LatencyRecorder,
+    #[cfg(feature = "trace-capture")]
+    capture: &'a TraceCapture,
+    /// `None` if the call is neither logged, measured nor captured.
+    start: Option<Instant>,
+}
+
+impl<'a> CallSpan<'a> {
+    /// Starts a call to the wrapper function `function`.
+    #[inline]
+    pub(crate) fn start(function: &'static str, amdsmi: &'a LibAmdSmi) -> Self {
+        let recorder = &amdsmi.latency;
+        let measured = cfg!(feature = "log") || recorder.is_enabled();
+        #[cfg(feature = "trace-capture")]
+        let measured = measured || amdsmi.capture.is_active();
+        Self {
+            function,
+            arguments: None,
+            recorder,
+            #[cfg(feature = "trace-capture")]
+            capture: &amdsmi.capture,
+            start: measured.then(Instant::now),
+        }
+    }
+
+    /// Sets the arguments of the call, written to the captures.
+    #[inline]
+    pub(crate) fn with_arguments(self, arguments: Option<&'a dyn Debug>) -> Self {
+        Self { arguments, ..self }
+    }
+
+    /// Records the end of the call.
+    ///
+    /// `device` is only called if the call is logged or captured: it can query the library.
+    #[inline]
+    pub(crate) fn finish<T: Debug>(
+        self,
+        device: impl Fn() -> Option<AmdPciAddress>,
+        result: &Result<T, AmdError>,
+    ) {
+        let Some(start) = self.start else {
+            return;
+        };
+        let duration = start.elapsed();
+        if self.recorder.is_enabled() {
+            self.recorder.record(self.function, duration);
+        }
+
+        #[cfg(feature = "trace-capture")]
+        if self.capture.is_active() {
+            self.capture.record(&TraceRecord {
+                function: self.function.to_owned(),
+                device: device().map(|d| d.to_string()),
+                arguments: self.arguments.map(|arguments| format!("{arguments:?}")),
+                status: library_status(result),
+                error: result.as_ref().err().map(|e| e.kind().to_owned()),
+                duration,
+                value: result.as_ref().ok().map(|value| format!("{value:?}")),
+            });
+        }
+
+        #[cfg(feature = "log")]
+        {
+            if !log::log_enabled!(target: TARGET, log::Level::Debug) {
+                return;
+            }
+            let device = device().map(|d| d.to_string()).unwrap_or_default();
+            match library_status(result) {
+                Some(status) => log::debug!(
+                    target: TARGET,
+                    function = self.function,
+                    device = device.as_str(),
+                    status,
+                    duration_us = duration.as_micros() as u64;
+                    "{} device={device} status={status} duration={duration:?}",
+                    self.function
+                ),
+                // errors that do not come from the library (invalid strings...) have no status
+                None => {
+                    let error = result.as_ref().err().map_or("", AmdError::kind);
+                    log::debug!(
+                        target: TARGET,
+                        function = self.function,
+                        device = device.as_str(),
+                        error,
+                        duration_us = duration.as_micros() as u64;
+                        "{} device={device} error={error} duration={duration:?}",
+                        self.function
+                    )
+                }
+            }
+        }
+        #[cfg(not(feature = "log"))]
+        let _ = (device, result);
+        #[cfg(not(feature = "trace-capture"))]
+        let _ = self.arguments;
+    }
+}
+
+/// Returns the raw status returned by the library: `0` on success, `None` if the error does not come from
+/// the library.
+#[cfg(any(feature = "log", feature = "trace-capture"))]
+fn library_status<T>(result: &Result<T, AmdError>) -> Option<u32> {
+    match result {
+        Ok(_) => Some(0),
+        Err(e) => e.status().map(|s| s.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{CallLatency, LatencyRecorder};
+
+    #[test]
+    fn latency_stats() {
+        let recorder = LatencyRecorder::default();
+        recorder.record("device_power_info", Duration::from_micros(30));
+        recorder.record("device_power_info", Duration::from_micros(10));
+        recorder.record("device_power_info", Duration::from_micros(20));
+        recorder.record("device_uuid", Duration::from_micros(5));
+
+        let stats = recorder.snapshot();
+        let power = stats["device_power_info"];
+        assert_eq!(power.count, 3);
+        assert_eq!(power.min, Duration::from_micros(10));
+        assert_eq!(power.max, Duration::from_micros(30));
+        assert_eq!(power.mean(), Duration::from_micros(20));
+        assert_eq!(stats["device_uuid"].count, 1);
+
+        recorder.reset();
+        assert!(recorder.snapshot().is_empty());
+    }
+
+    #[test]
+    fn mean_many_calls() {
+        let stats = CallLatency {
+            count: 1 << 32,
+            min: Duration::from_nanos(1),
+            max: Duration::from_nanos(5),
+            total: Duration::from_nanos(3 << 32),
+        };
+        assert_eq!(stats.mean(), Duration::from_nanos(3));
+    }
+}
@@ -0,0 +1,437 @@
+//! Links between the GPUs of a node: type, distance and peer-to-peer capabilities of each pair.
+//!
+//! [`topology_matrix`] queries all the pairs of a list of GPUs, and gives a [`TopologyMatrix`] that can be
+//! exported to JSON ([`TopologyMatrix::to_json`]) or to the DOT language of Graphviz ([`TopologyMatrix::to_dot`]),
+//! to visualize the interconnect of the node. The maximal bandwidth of each link is taken from the metrics of the
+//! links of the source GPU ([`device_link_metrics`](crate::handles::ProcessorHandle::device_link_metrics)).
+//!
+//! # Example
+//! ```no_run
+//! use amd_smi_wrapper::{AmdSmi, AmdInitFlags, interconnect::topology_matrix};
+//!
+//! let amdsmi = AmdSmi::init(AmdInitFlags::AMDSMI_INIT_AMD_GPUS).unwrap();
+//! let devices: Vec<_> = amdsmi.iter_processors().unwrap().filter_map(Result::ok).collect();
+//! let matrix = topology_matrix(&devices).unwrap();
+//! std::fs::write("topology.dot", matrix.to_dot()).unwrap();
+//! ```
+
+use std::fmt::Write;
+
+use crate::{
+    bindings::{amdsmi_link_type_t, amdsmi_p2p_capability_t},
+    error::{AmdError, ResultExt},
+    handles::{AmdProcessorHandle, ProcessorHandle},
+    json::JsonWriter,
+    lib_call,
+    metrics::{AmdLinkMetrics, AmdLinkType, AmdPciAddress},
+};
+
+/// Peer-to-peer capabilities of the IO link between two GPUs.
+///
+/// Each capability is `None` if the driver does not define it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AmdP2pCapability {
+    /// The link is cache coherent.
+    pub coherent: Option<bool>,
+    /// The link supports 32-bit atomic operations.
+    pub atomics_32bit: Option<bool>,
+    /// The link supports 64-bit atomic operations.
+    pub atomics_64bit: Option<bool>,
+    /// The link supports DMA.
+    pub dma: Option<bool>,
+    /// The link is bidirectional.
+    pub bi_directional: Option<bool>,
+}
+
+impl From<amdsmi_p2p_capability_t> for AmdP2pCapability {
+    fn from(value: amdsmi_p2p_capability_t) -> Self {
+        let flag = |v: u8| match v {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        };
+        Self {
+            coherent: flag(value.is_iolink_coherent),
+            atomics_32bit: flag(value.is_iolink_atomics_32bit),
+            atomics_64bit: flag(value.is_iolink_atomics_64bit),
+            dma: flag(value.is_iolink_dma),
+            bi_directional: flag(value.is_iolink_bi_directional),
+        }
+    }
+}
+
+/// Link from a GPU to another one.
+///
+/// The properties that are not available are `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmdLinkInfo {
+    /// Type of the link.
+    pub link_type: AmdLinkType,
+    /// Number of hops between the GPUs.
+    pub hops: Option<u64>,
+    /// Weight of the link in the topology of the KFD: the lower, the closer.
+    pub weight: Option<u64>,
+    /// Whether the memory of the destination GPU can be accessed directly by the source GPU.
+    pub p2p_accessible: Option<bool>,
+    /// Capabilities of the link.
+    pub capability: Option<AmdP2pCapability>,
+    /// Maximal bandwidth of the link, in Gb/s, if the source GPU reports the metrics of its links.
+    pub max_bandwidth: Option<u32>,
+}
+
+/// Retrieves the number of hops and the type of the link from `src` to `dst`.
+pub(crate) fn link_type(
+    src: &AmdProcessorHandle,
+    dst: &AmdProcessorHandle,
+) -> Result<(u64, AmdLinkType), AmdError> {
+    let dst = dst.current_handle()?;
+    src.call_raw("device_link_type", |raw, src| {
+        let mut hops = 0u64;
+        let mut link_type = amdsmi_link_type_t::AMDSMI_LINK_TYPE_UNKNOWN;
+        // SAFETY: The pointers are valid for the duration of the call.
+        raw.check(unsafe {
+            lib_call!(
+                raw.lib(),
+                amdsmi_topo_get_link_type(src, dst, &mut hops, &mut link_type)
+            )
+        })?;
+        Ok((hops, link_type))
+    })
+}
+
+/// Retrieves the weight of the link from `src` to `dst`.
+pub(crate) fn link_weight(
+    src: &AmdProcessorHandle,
+    dst: &AmdProcessorHandle,
+) -> Result<u64, AmdError> {
+    let dst = dst.current_handle()?;
+    src.call_raw("device_link_weight", |raw, src| {
+        raw.read_scalar(|weight| {
+            // SAFETY: The pointer is valid for the duration of the call.
+            unsafe { lib_call!(raw.lib(), amdsmi_topo_get_link_weight(src, dst, weight)) }
+        })
+    })
+}
+
+/// Returns `true` if the memory of `dst` can be accessed directly (peer-to-peer) by `src`.
+pub(crate) fn p2p_accessible(
+    src: &AmdProcessorHandle,
+    dst: &AmdProcessorHandle,
+) -> Result<bool, AmdError> {
+    let dst = dst.current_handle()?;
+    src.call_raw("device_p2p_accessible", |raw, src| {
+        raw.read_scalar(|accessible| {
+            // SAFETY: The pointer is valid for the duration of the call.
+            unsafe { lib_call!(raw.lib(), amdsmi_is_P2P_accessible(src, dst, accessible)) }
+        })
+    })
+}
+
+/// Retrieves the type and the peer-to-peer capabilities of the link from `src` to `dst`.
+pub(crate) fn p2p_status(
+    src: &AmdProcessorHandle,
+    dst: &AmdProcessorHandle,
+) -> Result<(AmdLinkType, AmdP2pCapability), AmdError> {
+    let dst = dst.current_handle()?;
+    src.call_raw("device_p2p_status", |raw, src| {
+        let mut link_type = amdsmi_link_type_t::AMDSMI_LINK_TYPE_UNKNOWN;
+        // SAFETY: The pointers are valid for the duration of the call, and the function initializes
+        // the capabilities on success.
+        let cap = unsafe {
+            raw.read_struct::<amdsmi_p2p_capability_t>(|cap| {
+                lib_call!(
+                    raw.lib(),
+                    amdsmi_topo_get_p2p_status(src, dst, &mut link_type, cap)
+                )
+            })?
+        };
+        Ok((link_type, cap.into()))
+    })
+}
+
+/// Retrieves all the properties of the link from `src` to `dst`, given the metrics of the links of `src`
+/// (`None` if they are not available).
+pub(crate) fn link_info<P: ProcessorHandle + ?Sized>(
+    src: &P,
+    dst: &P,
+    links: Option<&[AmdLinkMetrics]>,
+) -> Result<AmdLinkInfo, AmdError> {
+    let (link_type, capability) = match src.device_p2p_status(dst).optional()? {
+        Some((link_type, capability)) => (Some(link_type), Some(capability)),
+        None => (None, None),
+    };
+    let (hops, link_type) = match src.device_link_type(dst) {
+        Ok((hops, other_type)) => (Some(hops), link_type.unwrap_or(other_type)),
+        Err(e) if e.is_unavailable() => match link_type {
+            Some(link_type) => (None, link_type),
+            None => return Err(e),
+        },
+        Err(e) => return Err(e),
+    };
+    let max_bandwidth = match links {
+        Some(links) => {
+            let peer = dst.device_pci_address()?.physical();
+            links
+                .iter()
+                .find(|l| l.peer.physical() == peer)
+                .map(|l| l.max_bandwidth)
+        }
+        None => None,
+    };
+    Ok(AmdLinkInfo {
+        link_type,
+        hops,
+        weight: src.device_link_weight(dst).optional()?,
+        p2p_accessible: src.device_p2p_accessible(dst).optional()?,
+        capability,
+        max_bandwidth,
+    })
+}
+
+/// Links between all the pairs of a list of GPUs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopologyMatrix {
+    /// PCI addresses of the GPUs, in the order of the rows and the columns.
+    pub devices: Vec<AmdPciAddress>,
+    /// Link from the GPU of each row to the GPU of each column.
+    ///
+    /// The diagonal, and the pairs whose link is not available, are `None`.
+    pub links: Vec<Vec<Option<AmdLinkInfo>>>,
+}
+
+/// Queries the links between all the pairs of `devices`.
+pub fn topology_matrix<P: ProcessorHandle>(devices: &[P]) -> Result<TopologyMatrix, AmdError> {
+    let mut links = Vec::with_capacity(devices.len());
+    for (i, src) in devices.iter().enumerate() {
+        // The metrics of the links are the same for the whole row.
+        let src_links = src.device_link_metrics().optional()?;
+        let mut row = Vec::with_capacity(devices.len());
+        for (j, dst) in devices.iter().enumerate() {
+            row.push(if i == j {
+                None
+            } else {
+                link_info(src, dst, src_links.as_deref()).optional()?
+            });
+        }
+        links.push(row);
+    }
+    Ok(TopologyMatrix {
+        devices: devices
+            .iter()
+            .map(|d| d.device_pci_address())
+            .collect::<Result<_, _>>()?,
+        links,
+    })
+}
+
+/// Returns the name of a link type, in snake case, for instance `xgmi`.
+pub fn link_type_name(link_type: AmdLinkType) -> String {
+    let name = match link_type {
+        AmdLinkType::AMDSMI_LINK_TYPE_INTERNAL => "internal",
+        AmdLinkType::AMDSMI_LINK_TYPE_PCIE => "pcie",
+        AmdLinkType::AMDSMI_LINK_TYPE_XGMI => "xgmi",
+        AmdLinkType::AMDSMI_LINK_TYPE_NOT_APPLICABLE => "not_applicable",
+        AmdLinkType::AMDSMI_LINK_TYPE_UNKNOWN => "unknown",
+        other => return format!("link_{}", other.0),
+    };
+    name.to_owned()
+}
+
+impl TopologyMatrix {
+    /// Serializes the matrix to a JSON object, with the PCI addresses of the GPUs and a matrix of links.
+    ///
+    /// The unavailable links and properties are `null`.
+    pub fn to_json(&self) -> String {
+        let mut json = JsonWriter::new();
+        json.begin_object();
+        json.key("devices").begin_array();
+        for address in &self.devices {
+            json.string(&address.to_string());
+        }
+        json.end_array();
+        json.key("links").begin_array();
+        for row in &self.links {
+            json.begin_array();
+            for link in row {
+                json.option(link.as_ref(), write_link);
+            }
+            json.end_array();
+        }
+        json.end_array();
+        json.end_object();
+        json.finish()
+    }
+
+    /// Exports the matrix as an undirected graph in the DOT language of Graphviz.
+    ///
+    /// Each GPU is a node, named after its PCI address. Each pair is linked once, with the properties of the
+    /// link from the first GPU to the second one: the XGMI links are drawn solid, the other links dashed.
+    pub fn to_dot(&self) -> String {
+        let mut res = String::from("graph topology {\n");
+        for address in &self.devices {
+            writeln!(res, "  \"{address}\";").unwrap();
+        }
+        for (i, row) in self.links.iter().enumerate() {
+            for (j, link) in row.iter().enumerate().skip(i + 1) {
+                let Some(link) = link else {
+                    continue;
+                };
+                let mut label = link_type_name(link.link_type);
+                if let Some(hops) = link.hops {
+                    write!(label, ", {hops} hop{}", if hops == 1 { "" } else { "s" }).unwrap();
+                }
+                if let Some(weight) = link.weight {
+                    write!(label, ", weight {weight}").unwrap();
+                }
+                if let Some(bandwidth) = link.max_bandwidth {
+                    write!(label, ", {bandwidth} Gb/s").unwrap();
+                }
+                let style = if link.link_type == AmdLinkType::AMDSMI_LINK_TYPE_XGMI {
+                    "solid"
+                } else {
+                    "dashed"
+                };
+                writeln!(
+                    res,
+                    "  \"{}\" -- \"{}\" [label=\"{label}\", style={style}];",
+                    self.devices[i], self.devices[j]
+                )
+                .unwrap();
+            }
+        }
+        res.push_str("}\n");
+        res
+    }
+}
+
+/// Writes a link as a JSON object.
+fn write_link(json: &mut JsonWriter, link: &AmdLinkInfo) {
+    let number = |json: &mut JsonWriter, v: Option<u64>| {
+        json.option(v, |json, v| {
+            json.number(v);
+        });
+    };
+    let flag = |json: &mut JsonWriter, v: Option<bool>| {
+        json.option(v, |json, v| {
+            json.bool(v);
+        });
+    };
+    json.begin_object();
+    json.key("type").string(&link_type_name(link.link_type));
+    number(json.key("hops"), link.hops);
+    number(json.key("weight"), link.weight);
+    number(json.key("max_bandwidth"), link.max_bandwidth.map(u64::from));
+    flag(json.key("p2p_accessible"), link.p2p_accessible);
+    json.key("capability")
+        .option(link.capability.as_ref(), |json, c| {
+            json.begin_object();
+            flag(json.key("coherent"), c.coherent);
+            flag(json.key("atomics_32bit"), c.atomics_32bit);
+            flag(json.key("atomics_64bit"), c.atomics_64bit);
+            flag(json.key("dma"), c.dma);
+            flag(json.key("bi_directional"), c.bi_directional);
+            json.end_object();
+        });
+    json.end_object();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AmdLinkInfo, TopologyMatrix};
+    use crate::metrics::{AmdLinkType, AmdPciAddress};
+
+    #[test]
+    fn export() {
+        let link = AmdLinkInfo {
+            link_type: AmdLinkType::AMDSMI_LINK_TYPE_XGMI,
+            hops: Some(1),
+            weight: Some(15),
+            p2p_accessible: Some(true),
+            capability: None,
+            max_bandwidth: Some(400),
+        };
+        let matrix = TopologyMatrix {
+            devices: vec![
+                AmdPciAddress::from_bdf_id(0x0c00),
+                AmdPciAddress::from_bdf_id(0x2200),
+            ],
+            links: vec![vec![None, Some(link)], vec![Some(link), None]],
+        };
+        assert_eq!(
+            matrix.to_json(),
+            "{\"devices\":[\"0000:0c:00.0\",\"0000:22:00.0\"],\"links\":[[null,{\"type\":\"xgmi\",\"hops\":1,\
+             \"weight\":15,\"max_bandwidth\":400,\"p2p_accessible\":true,\"capability\":null}],[{\"type\":\"xgmi\",\"hops\":1,\
+             \"weight\":15,\"max_bandwidth\":400,\"p2p_accessible\":true,\"capability\":null},null]]}"
+        );
+        assert_eq!(
+            matrix.to_dot(),
+            "graph topology {\n  \"0000:0c:00.0\";\n  \"0000:22:00.0\";\n  \
+             \"0000:0c:00.0\" -- \"0000:22:00.0\" [label=\"xgmi, 1 hop, weight 15, 400 Gb/s\", style=solid];\n}\n"
+        );
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn matrix() {
+        use super::topology_matrix;
+        use crate::{
+            error::{AmdError, AmdStatus},
+            handles::MockProcessorHandle,
+            metrics::AmdLinkMetrics,
+        };
+
+        let addresses = [0x0c00, 0x2200].map(AmdPciAddress::from_bdf_id);
+        let devices: Vec<_> = (0..2)
+            .map(|i| {
+                let (own, peer) = (addresses[i], addresses[1 - i]);
+                let mut device = MockProcessorHandle::new();
+                device
+                    .expect_device_pci_address()
+                    .returning(move || Ok(own));
+                // Read once for the whole row.
+                device
+                    .expect_device_link_metrics()
+                    .times(1)
+                    .returning(move || {
+                        Ok(vec![AmdLinkMetrics {
+                            peer,
+                            link_type: AmdLinkType::AMDSMI_LINK_TYPE_XGMI,
+                            bit_rate: 32,
+                            max_bandwidth: 400,
+                            read_kb: 0,
+                            write_kb: 0,
+                        }])
+                    });
+                device.expect_device_p2p_status().returning(|_| {
+                    Err(AmdError::Status {
+                        status: AmdStatus::AMDSMI_STATUS_NOT_SUPPORTED,
+                        message: None,
+                    })
+                });
+                device
+                    .expect_device_link_type()
+                    .returning(|_| Ok((1, AmdLinkType::AMDSMI_LINK_TYPE_XGMI)));
+                device.expect_device_link_weight().returning(|_| Ok(15));
+                device
+                    .expect_device_p2p_accessible()
+                    .returning(|_| Ok(true));
+                device
+            })
+            .collect();
+
+        let matrix = topology_matrix(&devices).unwrap();
+        let link = AmdLinkInfo {
+            link_type: AmdLinkType::AMDSMI_LINK_TYPE_XGMI,
+            hops: Some(1),
+            weight: Some(15),
+            p2p_accessible: Some(true),
+            capability: None,
+            max_bandwidth: Some(400),
+        };
+        assert_eq!(matrix.devices, addresses);
+        assert_eq!(
+            matrix.links,
+            vec![vec![None, Some(link)], vec![Some(link), None]]
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! Minimal JSON writer, shared by the serializations of this crate (reports, summaries, topology, captures).
+
+use std::fmt::{Display, Write};
+
+/// Writer of a JSON document into a string.
+///
+/// The separators are inserted automatically: each value is written after a [`key`](JsonWriter::key)
+/// in an object, or as an element of an array.
+#[derive(Debug, Default)]
+pub(crate) struct JsonWriter {
+    out: String,
+    /// Whether the current object or array already has an element, which must be followed by a comma.
+    has_element: bool,
+}
+
+impl JsonWriter {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the document.
+    pub(crate) fn finish(self) -> String {
+        self.out
+    }
+
+    fn separator(&mut self) {
+        if self.has_element {
+            self.out.push(',');
+        }
+        self.has_element = true;
+    }
+
+    pub(crate) fn begin_object(&mut self) -> &mut Self {
+        self.separator();
+        self.out.push('{');
+        self.has_element = false;
+        self
+    }
+
+    pub(crate) fn end_object(&mut self) -> &mut Self {
+        self.out.push('}');
+        self.has_element = true;
+        self
+    }
+
+    pub(crate) fn begin_array(&mut self) -> &mut Self {
+        self.separator();
+        self.out.push('[');
+        self.has_element = false;
+        self
+    }
+
+    pub(crate) fn end_array(&mut self) -> &mut Self {
+        self.out.push(']');
+        self.has_element = true;
+        self
+    }
+
+    /// Writes the key of the next value of an object.
+    pub(crate) fn key(&mut self, key: &str) -> &mut Self {
+        self.separator();
+        self.push_string(key);
+        self.out.push(':');
+        self.has_element = false;
+        self
+    }
+
+    pub(crate) fn string(&mut self, value: &str) -> &mut Self {
+        self.separator();
+        self.push_string(value);
+        self
+    }
+
+    /// Writes a number, formatted by its `Display` implementation.
+    pub(crate) fn number(&mut self, value: impl Display) -> &mut Self {
+        self.separator();
+        write!(self.out, "{value}").unwrap();
+        self
+    }
+
+    pub(crate) fn bool(&mut self, value: bool) -> &mut Self {
+        self.separator();
+        self.out.push_str(if value { "true" } else { "false" });
+        self
+    }
+
+    pub(crate) fn null(&mut self) -> &mut Self {
+        self.separator();
+        self.out.push_str("null");
+        self
+    }
+
+    /// Writes a value with `write`, or `null` if there is none.
+    pub(crate) fn option<T>(
+        &mut self,
+        value: Option<T>,
+        write: impl FnOnce(&mut Self, T),
+    ) -> &mut Self {
+        match value {
+            Some(value) => write(self, value),
+            None => {
+                self.null();
+            }
+        }
+        self
+    }
+
+    fn push_string(&mut self, value: &str) {
+        self.out.push('"');
+        escape_into(&mut self.out, value);
+        self.out.push('"');
+    }
+}
+
+/// Appends a string to `out` with the escapes of the JSON strings, without the quotes.
+pub(crate) fn escape_into(out: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JsonWriter;
+
+    #[test]
+    fn writer() {
+        let mut json = JsonWriter::new();
+        json.begin_object();
+        json.key("name").string("a \"b\"\n\u{1}");
+        json.key("list").begin_array();
+        json.number(1).null().bool(true);
+        json.begin_object().end_object();
+        json.end_array();
+        json.key("missing").option(None::<u32>, |json, v| {
+            json.number(v);
+        });
+        json.key("present").option(Some(2.5), |json, v| {
+            json.number(v);
+        });
+        json.end_object();
+        assert_eq!(
+            json.finish(),
+            r#"{"name":"a \"b\"\n\u0001","list":[1,null,true,{}],"missing":null,"present":2.5}"#
+        );
+    }
+}
@@ -0,0 +1,400 @@
+//! Information of the compute driver (KFD, Kernel Fusion Driver) used by the HSA runtime.
+//!
+//! The library gives the index of each GPU in the KFD topology
+//! (see [`device_kfd_info`](crate::handles::ProcessorHandle::device_kfd_info)). The properties of the
+//! topology nodes, and the version of the kernel module, are read from sysfs. The version of the interface of the
+//! KFD, which the HSA runtime checks at startup, is queried from `/dev/kfd` (see [`read_interface_version`]).
+//! Together, they allow to check the compatibility between the runtime and the kernel driver.
+//!
+//! The KFD also counts the page faults of the processes that use shared virtual memory (SVM), per GPU.
+//! The GPU VM faults are not counted by the KFD: the library reports them as events, which
+//! [`VmFaultCounter`] counts.
+
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    os::fd::AsRawFd,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    error::AmdError,
+    events::{EventListener, GpuEvent},
+    handles::AmdProcessorHandle,
+    metrics::AmdNotificationType,
+};
+
+/// Sysfs directory of the KFD topology.
+pub const KFD_TOPOLOGY_PATH: &str = "/sys/class/kfd/kfd/topology";
+
+/// Sysfs directory of the processes that use the KFD.
+pub const KFD_PROC_PATH: &str = "/sys/class/kfd/kfd/proc";
+
+/// Sysfs file that contains the version of the `amdgpu` kernel module, when it has been built out of tree (DKMS).
+const MODULE_VERSION_PATH: &str = "/sys/module/amdgpu/version";
+
+/// Device file of the KFD, opened by the HSA runtime.
+pub const KFD_DEVICE_PATH: &str = "/dev/kfd";
+
+/// Request `AMDKFD_IOC_GET_VERSION` of the KFD: `_IOR('K', 0x01, struct kfd_ioctl_get_version_args)`.
+const AMDKFD_IOC_GET_VERSION: u64 = (2 << 30) | (8 << 16) | ((b'K' as u64) << 8) | 0x01;
+
+/// Arguments of [`AMDKFD_IOC_GET_VERSION`], `struct kfd_ioctl_get_version_args` in `linux/kfd_ioctl.h`.
+#[repr(C)]
+#[derive(Default)]
+struct KfdGetVersionArgs {
+    major_version: u32,
+    minor_version: u32,
+}
+
+/// Version of the graphics IP of a GPU, for instance `gfx942`: the target of the compiled kernels.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GfxVersion {
+    /// Major version of the IP, for instance `9` for `gfx942`.
+    pub major: u32,
+    /// Minor version of the IP, for instance `4` for `gfx942`.
+    pub minor: u32,
+    /// Stepping of the IP, for instance `2` for `gfx942`.
+    pub stepping: u32,
+}
+
+impl GfxVersion {
+    /// Decodes the `gfx_target_version` property of the KFD, for instance `90402` for `gfx942`.
+    ///
+    /// Returns `None` for `0`, which the KFD reports for the nodes that are not GPUs.
+    pub fn from_kfd(value: u64) -> Option<Self> {
+        (value != 0).then_some(Self {
+            major: (value / 10000) as u32,
+            minor: (value / 100 % 100) as u32,
+            stepping: (value % 100) as u32,
+        })
+    }
+}
+
+impl fmt::Display for GfxVersion {
+    /// Formats the version like the compilers do: the minor version and the stepping are written in hexadecimal.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "gfx{}{:x}{:x}", self.major, self.minor, self.stepping)
+    }
+}
+
+/// Version of the interface (ioctl) of the KFD.
+///
+/// This is the version that the HSA runtime reports as `HsaVersionInfo` (`hsaKmtGetVersion`): the runtime requires
+/// the same major version, and a minor version at least equal to the one it has been built with.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct KfdVersion {
+    /// Major version of the interface, incremented on incompatible changes.
+    pub major: u32,
+    /// Minor version of the interface, incremented when features are added.
+    pub minor: u32,
+}
+
+impl KfdVersion {
+    /// Returns `true` if this version of the interface provides the features of the `required` version.
+    pub fn is_compatible_with(&self, required: KfdVersion) -> bool {
+        self.major == required.major && self.minor >= required.minor
+    }
+}
+
+impl fmt::Display for KfdVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// Queries the version of the interface of the KFD, with the `AMDKFD_IOC_GET_VERSION` request on `/dev/kfd`.
+///
+/// Returns `None` if `/dev/kfd` cannot be opened (no KFD, or the user is not in the `render` group),
+/// or if the request fails.
+pub fn read_interface_version() -> Option<KfdVersion> {
+    let file = fs::File::open(KFD_DEVICE_PATH).ok()?;
+    let mut args = KfdGetVersionArgs::default();
+    // SAFETY: The request only writes a `kfd_ioctl_get_version_args` to `args`, which has the same layout,
+    // and the file descriptor is open during the call.
+    let result = unsafe { libc::ioctl(file.as_raw_fd(), AMDKFD_IOC_GET_VERSION as _, &mut args) };
+    (result == 0).then_some(KfdVersion {
+        major: args.major_version,
+        minor: args.minor_version,
+    })
+}
+
+/// Properties of a node of the KFD topology, read from its `properties` file.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct KfdNodeProperties {
+    values: BTreeMap<String, u64>,
+}
+
+impl KfdNodeProperties {
+    /// Parses the content of the `properties` file: one `name value` pair per line.
+    ///
+    /// The lines that cannot be parsed are ignored.
+    pub fn parse(content: &str) -> Self {
+        let values = content
+            .lines()
+            .filter_map(|line| {
+                let (name, value) = line.trim().split_once(' ')?;
+                Some((name.to_owned(), value.trim().parse().ok()?))
+            })
+            .collect();
+        Self { values }
+    }
+
+    /// Returns the value of a property, for instance `simd_count`.
+    pub fn get(&self, name: &str) -> Option<u64> {
+        self.values.get(name).copied()
+    }
+
+    /// Returns all the properties, ordered by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), *value))
+    }
+
+    /// Returns the version of the graphics IP of the node.
+    pub fn gfx_target_version(&self) -> Option<GfxVersion> {
+        GfxVersion::from_kfd(self.get("gfx_target_version")?)
+    }
+
+    /// Returns the version of the microcode of the compute engine (MEC) of the node.
+    pub fn fw_version(&self) -> Option<u64> {
+        self.get("fw_version")
+    }
+
+    /// Returns the version of the microcode of the DMA engines (SDMA) of the node.
+    pub fn sdma_fw_version(&self) -> Option<u64> {
+        self.get("sdma_fw_version")
+    }
+}
+
+/// Returns the sysfs directory of a node of the KFD topology.
+pub fn node_path(node_id: u32) -> PathBuf {
+    PathBuf::from(format!("{KFD_TOPOLOGY_PATH}/nodes/{node_id}"))
+}
+
+/// Reads the properties of a node of the KFD topology.
+///
+/// Returns `None` if the properties cannot be read (no KFD, or no such node).
+pub fn read_node_properties(node_id: u32) -> Option<KfdNodeProperties> {
+    let content = fs::read_to_string(node_path(node_id).join("properties")).ok()?;
+    Some(KfdNodeProperties::parse(&content))
+}
+
+/// Reads the version of the `amdgpu` kernel module, which provides the KFD.
+///
+/// The version is only available when the module has been installed out of tree (`amdgpu-dkms`),
+/// as in the ROCm compatibility matrices. Returns `None` for the module of the kernel.
+pub fn read_module_version() -> Option<String> {
+    let version = fs::read_to_string(MODULE_VERSION_PATH).ok()?;
+    let version = version.trim();
+    (!version.is_empty()).then(|| version.to_owned())
+}
+
+/// Page fault counters of the shared virtual memory, read from `<KFD_PROC_PATH>/<pid>/counters_<gpu_id>`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct KfdFaultCounters {
+    /// Number of GPU page faults.
+    pub faults: u64,
+    /// Number of pages migrated to the GPU.
+    pub page_in: u64,
+    /// Number of pages migrated from the GPU.
+    pub page_out: u64,
+}
+
+impl KfdFaultCounters {
+    /// Reads the counters of the directory `counters_<gpu_id>` of a process.
+    ///
+    /// Returns `None` if the directory does not exist, or if a counter cannot be read.
+    fn read(dir: &Path) -> Option<Self> {
+        let read =
+            |name| -> Option<u64> { fs::read_to_string(dir.join(name)).ok()?.trim().parse().ok() };
+        Some(Self {
+            faults: read("faults")?,
+            page_in: read("page_in")?,
+            page_out: read("page_out")?,
+        })
+    }
+
+    /// Returns the sum of the counters of several processes.
+    pub fn total<'a>(counters: impl IntoIterator<Item = &'a KfdProcessFaults>) -> Self {
+        counters
+            .into_iter()
+            .fold(Self::default(), |total, process| Self {
+                faults: total.faults.saturating_add(process.counters.faults),
+                page_in: total.page_in.saturating_add(process.counters.page_in),
+                page_out: total.page_out.saturating_add(process.counters.page_out),
+            })
+    }
+}
+
+/// Page fault counters of a process on a GPU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KfdProcessFaults {
+    /// Identifier of the process.
+    pub pid: u32,
+    /// Counters of the process on the GPU, since the process has opened the KFD.
+    pub counters: KfdFaultCounters,
+}
+
+/// Reads the page fault counters of the processes that use the GPU `gpu_id`
+/// (see [`AmdKfdInfo::kfd_id`](crate::metrics::AmdKfdInfo::kfd_id)).
+///
+/// The counters only exist while the processes are running: their sum is not monotonic.
+/// Reading the counters of the other users requires root access, the processes that cannot be read are skipped.
+pub fn read_process_fault_counters(gpu_id: u64) -> Vec<KfdProcessFaults> {
+    let Ok(entries) = fs::read_dir(KFD_PROC_PATH) else {
+        return Vec::new();
+    };
+    let mut processes: Vec<_> = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let pid = entry.file_name().to_str()?.parse().ok()?;
+            let counters =
+                KfdFaultCounters::read(&entry.path().join(format!("counters_{gpu_id}")))?;
+            Some(KfdProcessFaults { pid, counters })
+        })
+        .collect();
+    processes.sort_by_key(|p| p.pid);
+    processes
+}
+
+/// Counter of the GPU VM faults of some devices, from the events of an [`EventListener`].
+///
+/// The faults are only counted while the counter polls: poll it in a loop, in a dedicated thread.
+/// Like any listener, only one counter should be active at a time, see the [`events`](crate::events) module.
+pub struct VmFaultCounter {
+    listener: EventListener,
+    faults: Vec<u64>,
+}
+
+impl VmFaultCounter {
+    /// Starts the notification of the VM faults on the given devices.
+    pub fn new(devices: &[&AmdProcessorHandle], timeout: Duration) -> Result<Self, AmdError> {
+        let listener = EventListener::new(
+            devices,
+            &[AmdNotificationType::AMDSMI_EVT_NOTIF_VMFAULT],
+            timeout,
+        )?;
+        Ok(Self {
+            listener,
+            faults: vec![0; devices.len()],
+        })
+    }
+
+    /// Waits for events during the timeout of the listener, and returns the updated counters.
+    pub fn poll(&mut self) -> Result<&[u64], AmdError> {
+        let events = self.listener.poll()?;
+        count_vm_faults(&mut self.faults, &events);
+        Ok(&self.faults)
+    }
+
+    /// Returns the number of VM faults of each device since the creation of the counter,
+    /// in the order of the devices.
+    pub fn faults(&self) -> &[u64] {
+        &self.faults
+    }
+}
+
+/// Adds the VM faults among `events` to the counters of their devices.
+fn count_vm_faults(faults: &mut [u64], events: &[GpuEvent]) {
+    for event in events {
+        if event.kind != AmdNotificationType::AMDSMI_EVT_NOTIF_VMFAULT {
+            continue;
+        }
+        if let Some(count) = event.device.and_then(|device| faults.get_mut(device)) {
+            *count = count.saturating_add(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AMDKFD_IOC_GET_VERSION, GfxVersion, KfdFaultCounters, KfdNodeProperties, KfdProcessFaults,
+        KfdVersion, count_vm_faults,
+    };
+    use crate::{events::GpuEvent, metrics::AmdNotificationType};
+
+    #[test]
+    fn gfx_version() {
+        let version = |v| GfxVersion::from_kfd(v).unwrap().to_string();
+        assert_eq!(version(90402), "gfx942");
+        assert_eq!(version(90010), "gfx90a");
+        assert_eq!(version(110000), "gfx1100");
+        assert_eq!(GfxVersion::from_kfd(0), None);
+    }
+
+    #[test]
+    fn interface_version() {
+        // value of the request in linux/kfd_ioctl.h
+        assert_eq!(AMDKFD_IOC_GET_VERSION, 0x8008_4b01);
+
+        let version = |major, minor| KfdVersion { major, minor };
+        assert_eq!(version(1, 17).to_string(), "1.17");
+        assert!(version(1, 17).is_compatible_with(version(1, 17)));
+        assert!(version(1, 17).is_compatible_with(version(1, 14)));
+        assert!(!version(1, 14).is_compatible_with(version(1, 17)));
+        assert!(!version(2, 0).is_compatible_with(version(1, 17)));
+    }
+
+    #[test]
+    fn node_properties() {
+        let properties = KfdNodeProperties::parse(
+            "cpu_cores_count 0\nsimd_count 1216\ngfx_target_version 90402\nfw_version 177\nbad\n",
+        );
+        assert_eq!(properties.get("simd_count"), Some(1216));
+        assert_eq!(properties.fw_version(), Some(177));
+        assert_eq!(properties.sdma_fw_version(), None);
+        assert_eq!(
+            properties.gfx_target_version().unwrap().to_string(),
+            "gfx942"
+        );
+        assert_eq!(properties.iter().count(), 4);
+    }
+
+    #[test]
+    fn fault_totals() {
+        let process = |pid, faults, page_in| KfdProcessFaults {
+            pid,
+            counters: KfdFaultCounters {
+                faults,
+                page_in,
+                page_out: 1,
+            },
+        };
+        let processes = [process(10, 5, 100), process(12, 2, 40)];
+        let total = KfdFaultCounters::total(&processes);
+        assert_eq!(total.faults, 7);
+        assert_eq!(total.page_in, 140);
+        assert_eq!(total.page_out, 2);
+        assert_eq!(KfdFaultCounters::total(&[]), KfdFaultCounters::default());
+        let saturated = [process(1, u64::MAX, 0), process(2, 1, 0)];
+        assert_eq!(KfdFaultCounters::total(&saturated).faults, u64::MAX);
+    }
+
+    #[test]
+    fn vm_faults() {
+        let event = |device, kind| GpuEvent {
+            device,
+            kind,
+            message: String::new(),
+        };
+        let mut faults = [0, 3];
+        count_vm_faults(
+            &mut faults,
+            &[
+                event(Some(0), AmdNotificationType::AMDSMI_EVT_NOTIF_VMFAULT),
+                event(Some(1), AmdNotificationType::AMDSMI_EVT_NOTIF_VMFAULT),
+                event(Some(1), AmdNotificationType::AMDSMI_EVT_NOTIF_VMFAULT),
+                event(None, AmdNotificationType::AMDSMI_EVT_NOTIF_VMFAULT),
+                event(
+                    Some(0),
+                    AmdNotificationType::AMDSMI_EVT_NOTIF_THERMAL_THROTTLE,
+                ),
+            ],
+        );
+        assert_eq!(faults, [1, 5]);
+    }
+}
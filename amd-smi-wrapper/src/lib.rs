@@ -1,29 +1,136 @@
-use std::{ptr::null_mut, sync::Arc};
+use std::{
+    collections::BTreeMap,
+    ffi::{CStr, OsStr, OsString},
+    fmt::Debug,
+    mem::MaybeUninit,
+    ptr::null_mut,
+    sync::{
+        Arc, Mutex, RwLock,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    time::Duration,
+};
 
 #[cfg(feature = "mock")]
 use mockall::automock;
 
+pub mod baseline;
+pub mod batch;
+#[cfg(feature = "control")]
+pub mod benchmark;
+pub mod builder;
+#[cfg(feature = "trace-capture")]
+pub mod capture;
+pub mod config;
+pub mod consts;
+#[cfg(feature = "control")]
+pub mod control;
+pub mod counters;
+pub mod cpu;
+pub mod dynamic;
+pub mod energy;
 pub mod error;
+pub mod events;
+pub mod fan_mode;
+pub mod ffi;
+pub mod gpu_metrics;
 pub mod handles;
+pub mod instrument;
+pub mod interconnect;
+mod json;
+pub mod kfd;
+pub mod loader;
 pub mod metrics;
-mod utils;
+pub mod naming;
+#[cfg(feature = "otel")]
+pub mod otel;
+pub mod power_state;
+pub mod privileges;
+pub mod quality;
+pub mod registry;
+pub mod report;
+pub mod runtime;
+pub mod sandbox;
+pub mod serialize;
+pub mod snapshot;
+pub mod stats;
+pub mod summary;
+#[cfg(feature = "control")]
+pub mod sweep;
+pub mod throttle;
+pub mod timebase;
+pub mod topology;
+pub mod units;
+pub mod utils;
 
 use amd_smi_wrapper_sys as bindings;
 
 use crate::{
-    bindings::{amdsmi_init_flags_t, amdsmi_status_t, libamd_smi},
-    error::{AmdError, AmdInitError, AmdStatus, status_message},
-    handles::{AmdSocketHandle, SocketHandle},
+    bindings::{
+        amdsmi_init_flags_t, amdsmi_processor_handle, amdsmi_socket_handle, amdsmi_status_t,
+        amdsmi_version_t, libamd_smi,
+    },
+    builder::AmdSmiBuilder,
+    consts::MAX_STRING_LENGTH,
+    dynamic::{MetricKind, RateLimits},
+    error::{AmdError, AmdInitError, AmdStatus, ResultExt, status_message},
+    ffi::RawCall,
+    handles::{AmdSocketHandle, ProcessorIter, SocketHandle, SocketIter},
+    instrument::{CallLatency, CallSpan, LatencyRecorder},
+    loader::{AmdProbe, LibraryVariant},
+    metrics::AmdVersion,
+    serialize::FfiLock,
+    units::UnitSystem,
+    utils::StringBuffer,
 };
 
 pub(crate) const LIB_PATH: &str = "libamd_smi.so";
+/// File name of the library of the host virtualization driver, see [`LibraryVariant::Host`](loader::LibraryVariant::Host).
+///
+/// Not verified against a release of the host driver.
+pub(crate) const HOST_LIB_PATH: &str = "libamdsmi.so";
 
 /// Initialization flags for the library.
-/// See [`AmdSmi::init`].
+/// See [`AmdSmi::init`] and [`AmdSmiBuilder::processor_class`].
 pub type AmdInitFlags = amdsmi_init_flags_t;
 
 struct LibAmdSmi {
     amdsmi: libamd_smi,
+    /// Flags used to initialize the library, kept to re-initialize it.
+    flags: AmdInitFlags,
+    /// Variant of the loaded library.
+    variant: LibraryVariant,
+    /// Path from which the library has been loaded.
+    path: OsString,
+    /// Identifier of the copy of the library, see [`loader::loaded_libraries`].
+    library_id: usize,
+    /// Whether the devices can be controlled while another copy of the library is loaded.
+    #[cfg(feature = "control")]
+    shared_control: bool,
+    /// Units of the values returned by the dynamic reads.
+    units: UnitSystem,
+    /// Whether to re-initialize the library when the driver is restarted.
+    auto_recovery: AtomicBool,
+    /// Whether to refuse the queries to suspended devices.
+    skip_suspended: AtomicBool,
+    /// Incremented each time the library is re-initialized.
+    generation: AtomicU64,
+    /// Prevents concurrent re-initializations.
+    reinit_lock: Mutex<()>,
+    /// Duration for which the unsupported metrics are skipped, in ms, or 0 to disable the cache.
+    unsupported_ttl_ms: AtomicU64,
+    /// Incremented to clear the caches of unsupported metrics of all the handles.
+    unsupported_epoch: AtomicU64,
+    /// Minimal intervals between two reads of the same metric of a device.
+    rate_limits: RwLock<RateLimits>,
+    /// Serializes the calls to the copy of the library, shared with the other instances that use it
+    /// (does nothing without the `serialize-ffi` feature).
+    ffi_lock: FfiLock,
+    /// Latency of the calls, recorded when enabled.
+    latency: LatencyRecorder,
+    /// File to which the calls are written, when a capture is started.
+    #[cfg(feature = "trace-capture")]
+    capture: capture::TraceCapture,
 }
 
 /// Main wrapper around the AMD SMI library.
@@ -32,6 +139,13 @@ struct LibAmdSmi {
 /// The library is automatically shut down when `AmdSmi` is dropped.
 /// The `Drop` implementation of `AmdSmi` ignores shutdown errors.
 /// To handle the error, call [`AmdInterface::stop`].
+///
+/// # Recovery
+/// By default, the errors `NOT_INIT` and `AMDGPU_RESTART_ERR`, which are returned after a restart of the
+/// GPU driver, are returned to the caller like any other error.
+/// With [`set_auto_recovery`](AmdSmi::set_auto_recovery), the library is instead re-initialized and the
+/// processor handles are resolved again (matched by their PCI address), as well as the socket handles (matched
+/// by their identifier), so that long-running programs survive driver restarts transparently.
 #[derive(Clone)]
 pub struct AmdSmi {
     amdsmi: Arc<LibAmdSmi>,
@@ -40,9 +154,12 @@ pub struct AmdSmi {
 impl Drop for LibAmdSmi {
     fn drop(&mut self) {
         // Shut down the AMD-SMI library and release all internal resources.
+        // The other instances that use the same copy of the library may be calling it.
+        let _ffi = self.ffi_lock.lock();
         // SAFETY: The function expects a valid, initialized library instance.
         // The shutdown is called only once when the last reference is dropped.
         unsafe { self.amdsmi.amdsmi_shut_down() };
+        loader::unregister(self.library_id);
     }
 }
 
@@ -51,15 +168,29 @@ impl AmdSmi {
     fn check_status(&self, status: amdsmi_status_t) -> Result<(), AmdError> {
         match status {
             AmdStatus::AMDSMI_STATUS_SUCCESS => Ok(()),
-            status => Err(AmdError {
+            status => Err(AmdError::Status {
                 status,
                 message: status_message(&self.amdsmi.amdsmi, status),
             }),
         }
     }
 
+    /// Checks the status of a call that is not specific to a device, made through [`ffi::guarded`],
+    /// and records the call (see the [`instrument`] module).
+    fn check_call(
+        &self,
+        span: CallSpan,
+        status: Result<amdsmi_status_t, AmdError>,
+    ) -> Result<(), AmdError> {
+        let result = status.and_then(|status| self.check_status(status));
+        span.finish(|| None, &result);
+        result
+    }
+
     /// Initializes the AMD smi library.
     ///
+    /// The flags are checked before loading the library, see [`builder::validate_flags`].
+    ///
     /// # Example
     /// ```no_run
     /// use amd_smi_wrapper::{AmdSmi, AmdInitFlags};
@@ -67,20 +198,514 @@ impl AmdSmi {
     /// let amdsmi = AmdSmi::init(AmdInitFlags::AMDSMI_INIT_AMD_GPUS).expect("init failed");
     /// ```
     pub fn init(flags: AmdInitFlags) -> Result<Self, AmdInitError> {
-        // SAFETY: The library must exist at the specified path, otherwise `libamd_smi::new` returns an error.
-        // This operation involves raw FFI interaction and assumes the dynamic loader succeeds.
-        let amdsmi = unsafe { libamd_smi::new(LIB_PATH)? };
-        let instance = AmdSmi {
-            amdsmi: Arc::new(LibAmdSmi { amdsmi }),
+        AmdSmiBuilder::new().flags(flags).build()
+    }
+
+    /// Initializes the AMD smi library to discover the AMD GPUs.
+    pub fn init_gpus() -> Result<Self, AmdInitError> {
+        Self::init(AmdInitFlags::AMDSMI_INIT_AMD_GPUS)
+    }
+
+    /// Initializes the AMD smi library to discover the AMD CPUs.
+    ///
+    /// The library must have been built with the support of the CPUs (E-SMI).
+    pub fn init_cpus() -> Result<Self, AmdInitError> {
+        Self::init(AmdInitFlags::AMDSMI_INIT_AMD_CPUS)
+    }
+
+    /// Initializes the AMD smi library to discover all the processors that it supports.
+    pub fn init_all() -> Result<Self, AmdInitError> {
+        Self::init(AmdInitFlags::AMDSMI_INIT_ALL_PROCESSORS)
+    }
+
+    /// Checks whether the library can be loaded and exports the required symbols, without initializing it.
+    ///
+    /// The default paths are tried, like [`init`](AmdSmi::init) does. To probe a specific path,
+    /// use [`AmdSmiBuilder::probe`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use amd_smi_wrapper::AmdSmi;
+    ///
+    /// let probe = AmdSmi::probe();
+    /// if !probe.is_usable() {
+    ///     for attempt in &probe.failed_attempts {
+    ///         eprintln!("{attempt}");
+    ///     }
+    /// }
+    /// ```
+    pub fn probe() -> AmdProbe {
+        AmdSmiBuilder::new().probe()
+    }
+
+    /// Returns a builder to configure the initialization of the library.
+    ///
+    /// See [`AmdSmiBuilder`].
+    pub fn builder() -> AmdSmiBuilder {
+        AmdSmiBuilder::new()
+    }
+
+    /// Returns the flags with which the library has been initialized, including the bits passed with
+    /// [`AmdSmiBuilder::flag_bits`]. See [`ProcessorClass::from_flags`](builder::ProcessorClass::from_flags)
+    /// to log them.
+    pub fn init_flags(&self) -> AmdInitFlags {
+        self.amdsmi.flags
+    }
+
+    /// Returns the path from which the library has been loaded, as given to the dynamic loader.
+    pub fn library_path(&self) -> &OsStr {
+        &self.amdsmi.path
+    }
+
+    /// Returns `true` if this instance and `other` use the same copy of the library, and share its state.
+    ///
+    /// See [`loader::loaded_libraries`].
+    pub fn shares_library_with(&self, other: &AmdSmi) -> bool {
+        self.amdsmi.library_id == other.amdsmi.library_id
+    }
+
+    /// Checks that the devices can be controlled by this instance.
+    ///
+    /// When several copies of the library are loaded (see [`AmdSmiBuilder::shared_control`]), the copies
+    /// do not coordinate their changes of the settings: the control is refused unless it has been allowed.
+    #[cfg(feature = "control")]
+    pub(crate) fn check_control(&self) -> Result<(), AmdError> {
+        if self.amdsmi.shared_control {
+            return Ok(());
+        }
+        let others = loader::other_libraries(self.amdsmi.library_id);
+        if others.is_empty() {
+            return Ok(());
+        }
+        Err(AmdError::ControlRefused {
+            others: others
+                .iter()
+                .map(|l| std::path::PathBuf::from(&l.path))
+                .collect(),
+        })
+    }
+
+    /// Returns the variant of the loaded library, see [`AmdSmiBuilder::library_variant`].
+    pub fn library_variant(&self) -> LibraryVariant {
+        self.amdsmi.variant
+    }
+
+    /// Returns the units of the values returned by the dynamic reads and by the getters of
+    /// [`Quantity`](units::Quantity), see [`AmdSmiBuilder::unit_system`].
+    pub fn unit_system(&self) -> UnitSystem {
+        self.amdsmi.units
+    }
+
+    /// Enables or disables the automatic recovery after a driver restart.
+    ///
+    /// The setting is shared by all the clones of this `AmdSmi` and by the handles obtained from it.
+    /// Only the processor and socket handles created while the recovery is enabled can be resolved again.
+    pub fn set_auto_recovery(&self, enabled: bool) {
+        self.amdsmi.auto_recovery.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the automatic recovery is enabled.
+    pub fn auto_recovery(&self) -> bool {
+        self.amdsmi.auto_recovery.load(Ordering::Relaxed)
+    }
+
+    /// Enables or disables the recording of the latency of the calls to the library.
+    ///
+    /// The latency is recorded per wrapper function, for instance to choose a polling interval,
+    /// or to detect a slowdown of the driver. It is disabled by default.
+    /// The setting is shared by all the clones of this `AmdSmi` and by the handles obtained from it.
+    pub fn set_latency_stats(&self, enabled: bool) {
+        self.amdsmi.latency.set_enabled(enabled);
+    }
+
+    /// Returns the latency of the calls recorded since the recording has been enabled, or reset,
+    /// per wrapper function.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use amd_smi_wrapper::{AmdSmi, AmdInitFlags};
+    ///
+    /// let amdsmi = AmdSmi::init(AmdInitFlags::AMDSMI_INIT_AMD_GPUS).unwrap();
+    /// amdsmi.set_latency_stats(true);
+    /// // ... query the devices ...
+    /// for (function, latency) in amdsmi.latency_stats() {
+    ///     println!("{function}: {} calls, mean {:?}, max {:?}", latency.count, latency.mean(), latency.max);
+    /// }
+    /// ```
+    pub fn latency_stats(&self) -> BTreeMap<&'static str, CallLatency> {
+        self.amdsmi.latency.snapshot()
+    }
+
+    /// Clears the recorded latency statistics.
+    pub fn reset_latency_stats(&self) {
+        self.amdsmi.latency.reset();
+    }
+
+    /// Starts writing the calls to the library, and their results, to the file at `path`,
+    /// see the [`capture`] module.
+    ///
+    /// The file is truncated. The capture is shared by all the clones of this `AmdSmi` and by the handles
+    /// obtained from it, and replaces the capture in progress, if any.
+    #[cfg(feature = "trace-capture")]
+    pub fn start_trace_capture(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        self.amdsmi.capture.start(path.as_ref())
+    }
+
+    /// Stops the capture in progress, if any, and flushes the file.
+    #[cfg(feature = "trace-capture")]
+    pub fn stop_trace_capture(&self) -> std::io::Result<()> {
+        self.amdsmi.capture.stop()
+    }
+
+    /// Enables or disables the protection of the suspended devices.
+    ///
+    /// When enabled, the queries to a device that is suspended by the kernel (see [`RuntimePowerState`](power_state::RuntimePowerState))
+    /// fail with [`AmdError::DeviceSuspended`] instead of waking it up, so that the collection can be skipped or deferred.
+    /// The setting is shared by all the clones of this `AmdSmi` and by the handles obtained from it.
+    pub fn set_skip_suspended(&self, enabled: bool) {
+        self.amdsmi.skip_suspended.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the queries to suspended devices are refused.
+    pub fn skip_suspended(&self) -> bool {
+        self.amdsmi.skip_suspended.load(Ordering::Relaxed)
+    }
+
+    /// Sets the duration for which a metric that is not available on a device is skipped, or `None` to
+    /// query it every time (the default).
+    ///
+    /// When [`ProcessorHandle::device_read_metric`](handles::ProcessorHandle::device_read_metric) fails
+    /// because the metric is not available (see [`AmdError::is_unavailable`]), the failure is remembered per
+    /// device and metric, and the next reads fail immediately with `NOT_SUPPORTED`, without calling the library,
+    /// until `ttl` has elapsed. On heterogeneous fleets, this avoids wasting calls at each collection.
+    /// The setting is shared by all the clones of this `AmdSmi` and by the handles obtained from it.
+    pub fn set_unsupported_cache_ttl(&self, ttl: Option<Duration>) {
+        let ttl_ms = ttl.map_or(0, |ttl| (ttl.as_millis() as u64).max(1));
+        self.amdsmi
+            .unsupported_ttl_ms
+            .store(ttl_ms, Ordering::Relaxed);
+    }
+
+    /// Returns the duration for which the unavailable metrics are skipped, if the cache is enabled.
+    pub fn unsupported_cache_ttl(&self) -> Option<Duration> {
+        match self.amdsmi.unsupported_ttl_ms.load(Ordering::Relaxed) {
+            0 => None,
+            ttl_ms => Some(Duration::from_millis(ttl_ms)),
+        }
+    }
+
+    /// Forgets the unavailable metrics and the probed sensors of all the handles, to query them again.
+    ///
+    /// The cache is also reset when the library is re-initialized.
+    pub fn reset_unsupported_cache(&self) {
+        self.amdsmi
+            .unsupported_epoch
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn unsupported_cache_epoch(&self) -> u64 {
+        self.amdsmi.unsupported_epoch.load(Ordering::Relaxed)
+    }
+
+    /// Limits the rate of the reads of the metrics, per device (none by default).
+    ///
+    /// When [`ProcessorHandle::device_read_metric`](handles::ProcessorHandle::device_read_metric) is called again
+    /// for the same metric before the interval has elapsed, the handle returns the last value instead of calling
+    /// the library. The values are cached per handle, and dropped when the library is re-initialized.
+    /// The setting is shared by all the clones of this `AmdSmi` and by the handles obtained from it.
+    pub fn set_rate_limits(&self, limits: RateLimits) {
+        *self
+            .amdsmi
+            .rate_limits
+            .write()
+            .unwrap_or_else(|e| e.into_inner()) = limits;
+    }
+
+    /// Returns the rate limits of the reads of the metrics.
+    pub fn rate_limits(&self) -> RateLimits {
+        self.amdsmi
+            .rate_limits
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    pub(crate) fn rate_limit(&self, kind: MetricKind) -> Option<Duration> {
+        self.amdsmi
+            .rate_limits
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .interval(kind)
+    }
+
+    /// Returns the generation of the library, i.e. the number of times it has been re-initialized.
+    ///
+    /// The raw handles obtained in a previous generation are no longer valid.
+    /// See [`ProcessorHandle::device_is_alive`](handles::ProcessorHandle::device_is_alive).
+    pub fn generation(&self) -> u64 {
+        self.amdsmi.generation.load(Ordering::Acquire)
+    }
+
+    /// Returns the statistics of the lock that serializes the calls to the library.
+    ///
+    /// The lock is shared by the instances that use the same copy of the library, and so are the statistics.
+    /// See the [`serialize`] module.
+    #[cfg(feature = "serialize-ffi")]
+    pub fn ffi_lock_stats(&self) -> serialize::FfiLockStats {
+        self.amdsmi.ffi_lock.stats()
+    }
+
+    /// Calls `f` with the low-level bindings, to call a function of the library that is not specific to a
+    /// device and that this crate does not wrap.
+    ///
+    /// The call is serialized and instrumented under the name `function`, like the wrappers of this crate.
+    /// See the [`ffi`] module.
+    pub fn call_raw<T: Debug>(
+        &self,
+        function: &'static str,
+        f: impl FnOnce(RawCall) -> Result<T, AmdError>,
+    ) -> Result<T, AmdError> {
+        let _ffi = self.amdsmi.ffi_lock.lock();
+        let span = CallSpan::start(function, &self.amdsmi);
+        let result = ffi::guarded(function, || f(RawCall::new(self)));
+        span.finish(|| None, &result);
+        result
+    }
+
+    /// Shuts down and initializes the library again, unless it has already been re-initialized
+    /// since `seen_generation`.
+    pub(crate) fn reinit(&self, seen_generation: u64) -> Result<(), AmdError> {
+        // the lock protects no data: a panic of another re-initialization does not prevent this one
+        let _guard = self
+            .amdsmi
+            .reinit_lock
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if self.generation() != seen_generation {
+            // another handle has already done it
+            return Ok(());
+        }
+
+        // SAFETY: The library has been initialized by `init`, and the lock prevents concurrent re-initializations.
+        // Each shutdown is followed by an init, so the number of inits and shutdowns stays balanced.
+        // The status of the shutdown is ignored: after a driver restart, the library may be partially shut down.
+        let _ffi = self.amdsmi.ffi_lock.lock();
+        let status = unsafe {
+            self.amdsmi.amdsmi.amdsmi_shut_down();
+            self.amdsmi.amdsmi.amdsmi_init(self.amdsmi.flags.0.into())
+        };
+        self.check_status(status)?;
+
+        self.amdsmi.generation.fetch_add(1, Ordering::AcqRel);
+        self.reset_unsupported_cache();
+        Ok(())
+    }
+
+    /// Counts the available sockets, without retrieving their handles.
+    pub(crate) fn raw_socket_count(&self) -> Result<u32, AmdError> {
+        let mut socket_count = 0;
+
+        let _ffi = self.amdsmi.ffi_lock.lock();
+        let span = CallSpan::start("socket_count", &self.amdsmi);
+        let result = ffi::guarded("socket_count", || {
+            // Query the number of available GPU socket handles.
+            // SAFETY: According to the AMD-SMI documentation, passing `null_mut()` is safe which sets `socket_count` to the number of sockets in the system.
+            Ok(unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi,
+                    amdsmi_get_socket_handles(&mut socket_count, null_mut())
+                )
+            })
+        });
+        self.check_call(span, result)?;
+        Ok(socket_count)
+    }
+
+    /// Lists the raw handles of the available sockets.
+    pub(crate) fn raw_socket_handles(&self) -> Result<Vec<amdsmi_socket_handle>, AmdError> {
+        let mut socket_count = self.raw_socket_count()?;
+
+        // Allocate a vector of null pointers.
+        let mut socket_handles = vec![null_mut(); socket_count as usize];
+
+        let _ffi = self.amdsmi.ffi_lock.lock();
+        let span = CallSpan::start("socket_handles", &self.amdsmi);
+        let result = ffi::guarded("socket_handles", || {
+            // Fill the buffer with socket handles.
+            // SAFETY: `socket_handles.as_mut_ptr()` points to memory of sufficient size.
+            // According the AMD-SMI library documentation, the function writes at most `socket_count` handles, so no out-of-bounds write occurs.
+            Ok(unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi,
+                    amdsmi_get_socket_handles(&mut socket_count, socket_handles.as_mut_ptr())
+                )
+            })
+        });
+        self.check_call(span, result)?;
+
+        ffi::check_buffer(socket_count as usize, socket_handles.len())?;
+        socket_handles.truncate(socket_count as usize);
+        Ok(socket_handles)
+    }
+
+    /// Counts the processors of a socket, without retrieving their handles.
+    pub(crate) fn raw_processor_count(
+        &self,
+        socket: amdsmi_socket_handle,
+    ) -> Result<u32, AmdError> {
+        let mut processor_count = 0;
+
+        let _ffi = self.amdsmi.ffi_lock.lock();
+        let span = CallSpan::start("processor_count", &self.amdsmi);
+        let result = ffi::guarded("processor_count", || {
+            // Query the number of processor handles for the given socket.
+            // SAFETY: According the AMD-SMI library documentation, passing `null_mut()` is safe which sets `processor_count` to the number of processors available for this socket.
+            Ok(unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi,
+                    amdsmi_get_processor_handles(socket, &mut processor_count, null_mut())
+                )
+            })
+        });
+        self.check_call(span, result)?;
+        Ok(processor_count)
+    }
+
+    /// Lists the raw handles of the processors of a socket.
+    pub(crate) fn raw_processor_handles(
+        &self,
+        socket: amdsmi_socket_handle,
+    ) -> Result<Vec<amdsmi_processor_handle>, AmdError> {
+        let mut processor_count = self.raw_processor_count(socket)?;
+
+        // Allocate a vector of nulls.
+        let mut processor_handles = vec![null_mut(); processor_count as usize];
+
+        let _ffi = self.amdsmi.ffi_lock.lock();
+        let span = CallSpan::start("processor_handles", &self.amdsmi);
+        let result = ffi::guarded("processor_handles", || {
+            // Fill the buffer with processor handles.
+            // SAFETY: `processor_handles.as_mut_ptr()` points to a memory block of sufficient size.
+            //  According the AMD-SMI library documentation, the function writes at most `processor_count` handles ensuring no out-of-bounds access occurs.
+            Ok(unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi,
+                    amdsmi_get_processor_handles(
+                        socket,
+                        &mut processor_count,
+                        processor_handles.as_mut_ptr(),
+                    )
+                )
+            })
+        });
+        self.check_call(span, result)?;
+
+        ffi::check_buffer(processor_count as usize, processor_handles.len())?;
+        processor_handles.truncate(processor_count as usize);
+        Ok(processor_handles)
+    }
+
+    /// Returns a lazy iterator over the available sockets.
+    ///
+    /// Unlike [`socket_handles`](AmdInterface::socket_handles), the handles are built on demand.
+    pub fn iter_sockets(&self) -> Result<SocketIter, AmdError> {
+        SocketIter::new(self.clone())
+    }
+
+    /// Returns a lazy iterator over the processors of all the sockets.
+    ///
+    /// The processors of each socket are listed only when the iterator reaches it, which allows to stop
+    /// early, for instance when searching for a specific device.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use amd_smi_wrapper::{AmdSmi, AmdInitFlags, handles::ProcessorHandle};
+    ///
+    /// let amdsmi = AmdSmi::init(AmdInitFlags::AMDSMI_INIT_AMD_GPUS).unwrap();
+    /// let device = amdsmi
+    ///     .iter_processors()
+    ///     .unwrap()
+    ///     .filter_map(Result::ok)
+    ///     .find(|p| p.device_uuid().is_ok_and(|uuid| uuid.starts_with("5bff")));
+    /// ```
+    pub fn iter_processors(&self) -> Result<ProcessorIter, AmdError> {
+        ProcessorIter::new(self.clone())
+    }
+
+    /// Retrieves the version of the loaded AMD SMI library.
+    pub fn lib_version(&self) -> Result<AmdVersion, AmdError> {
+        let mut version = MaybeUninit::<amdsmi_version_t>::uninit();
+
+        let _ffi = self.amdsmi.ffi_lock.lock();
+        let span = CallSpan::start("lib_version", &self.amdsmi);
+        let result = ffi::guarded("lib_version", || {
+            // SAFETY: Pass a raw pointer to uninitialized memory to the FFI function.
+            // According to AMD-SMI documentation, the function fully initializes the structure on success.
+            Ok(unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi,
+                    amdsmi_get_lib_version(version.as_mut_ptr())
+                )
+            })
+        });
+        self.check_call(span, result)?;
+
+        // SAFETY: The FFI call succeeded and fully initialized `version`.
+        let version = unsafe { version.assume_init() };
+        let build = if version.build.is_null() {
+            String::new()
+        } else {
+            // SAFETY: The build string is a null-terminated static string owned by the library.
+            unsafe { CStr::from_ptr(version.build) }
+                .to_str()
+                .map_err(AmdError::InvalidString)?
+                .to_owned()
         };
+        Ok(AmdVersion {
+            major: version.major,
+            minor: version.minor,
+            release: version.release,
+            build,
+        })
+    }
+
+    /// Retrieves the identifier of a socket.
+    pub(crate) fn raw_socket_id(&self, socket: amdsmi_socket_handle) -> Result<String, AmdError> {
+        let mut buffer = StringBuffer::<MAX_STRING_LENGTH>::new();
 
-        // SAFETY: The function expects a valid library instance and valid flags.
-        // According to the AMD-SMI documentation, the function fully initializes internal structures for GPU discovery.
-        // The return code `amdsmi_status_t` is checked to ensure initialization succeeded before using the library.
-        let status = unsafe { instance.amdsmi.amdsmi.amdsmi_init(flags.0.into()) };
-        instance.check_status(status)?;
+        let _ffi = self.amdsmi.ffi_lock.lock();
+        let span = CallSpan::start("socket_id", &self.amdsmi);
+        let result = ffi::guarded("socket_id", || {
+            // SAFETY: According to AMD-SMI documentation, the function writes at most `len` characters to the buffer.
+            Ok(unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi,
+                    amdsmi_get_socket_info(socket, buffer.capacity(), buffer.as_mut_ptr())
+                )
+            })
+        });
 
-        Ok(instance)
+        self.check_call(span, result)?;
+        buffer.to_string()
+    }
+
+    /// Retrieves the PCI identifier (BDF) of a processor.
+    pub(crate) fn raw_bdf_id(&self, processor: amdsmi_processor_handle) -> Result<u64, AmdError> {
+        let mut bdf_id = 0;
+
+        let _ffi = self.amdsmi.ffi_lock.lock();
+        let result = ffi::guarded("bdf_id", || {
+            // SAFETY: Pass a mutable pointer to `bdf_id` for the FFI function to write the identifier.
+            // The `SUCCESS` return code `amdsmi_status_t` is checked before using the data.
+            Ok(unsafe {
+                lib_call!(
+                    self.amdsmi.amdsmi,
+                    amdsmi_get_gpu_bdf_id(processor, &mut bdf_id)
+                )
+            })
+        })?;
+        self.check_status(result)?;
+        Ok(bdf_id)
     }
 }
 
@@ -99,44 +724,37 @@ pub trait AmdInterface {
     /// For instance, if the library has been initialized with [`AMDSMI_INIT_AMD_GPUS`](AmdInitFlags::AMDSMI_INIT_AMD_GPUS),
     /// only sockets with GPUs are returned.
     fn socket_handles(&self) -> Result<Vec<Self::SocketHandle>, AmdError>;
+
+    /// Lists the available sockets like [`socket_handles`](AmdInterface::socket_handles), but checks each
+    /// socket by reading its identifier: the sockets that cannot be read are returned as errors, at their
+    /// position, instead of being returned as unusable handles. The query being not supported is not a failure.
+    ///
+    /// An error is returned only if the sockets themselves cannot be listed.
+    fn socket_handles_partial(
+        &self,
+    ) -> Result<Vec<Result<Self::SocketHandle, AmdError>>, AmdError> {
+        Ok(self
+            .socket_handles()?
+            .into_iter()
+            .map(|socket| socket.socket_id().optional().map(|_| socket))
+            .collect())
+    }
+
+    /// Counts the available sockets, without retrieving their handles.
+    ///
+    /// This is cheaper than [`socket_handles`](AmdInterface::socket_handles), for instance to check
+    /// that the expected number of devices is present.
+    fn socket_count(&self) -> Result<usize, AmdError>;
 }
 
 impl AmdInterface for AmdSmi {
     type SocketHandle = AmdSocketHandle;
 
     fn socket_handles(&self) -> Result<Vec<Self::SocketHandle>, AmdError> {
-        let mut socket_count = 0;
-
-        // Query the number of available GPU socket handles.
-        // SAFETY: According to the AMD-SMI documentation, passing `null_mut()` is safe which sets `socket_count` to the number of sockets in the system.
-        let result = unsafe {
-            self.amdsmi
-                .amdsmi
-                .amdsmi_get_socket_handles(&mut socket_count, null_mut())
-        };
-        self.check_status(result)?;
-
-        // Allocate a vector of null pointers.
-        let mut socket_handles = vec![null_mut(); socket_count as usize];
-
-        // Fill the buffer with socket handles.
-        // SAFETY: `socket_handles.as_mut_ptr()` points to memory of sufficient size.
-        // According the AMD-SMI library documentation, the function writes at most `socket_count` handles, so no out-of-bounds write occurs.
-        let result = unsafe {
-            self.amdsmi
-                .amdsmi
-                .amdsmi_get_socket_handles(&mut socket_count, socket_handles.as_mut_ptr())
-        };
-        self.check_status(result)?;
-
-        socket_handles.truncate(socket_count as usize);
+        Ok(self.iter_sockets()?.collect())
+    }
 
-        Ok(socket_handles
-            .into_iter()
-            .map(|s| AmdSocketHandle {
-                amdsmi: self.clone(),
-                inner: s,
-            })
-            .collect())
+    fn socket_count(&self) -> Result<usize, AmdError> {
+        Ok(self.raw_socket_count()? as usize)
     }
 }
@@ -0,0 +1,306 @@
+//! Loading of the AMD SMI library, and diagnostics when it fails.
+
+use std::{
+    ffi::OsString,
+    fmt::{Display, Formatter},
+    sync::Mutex,
+};
+
+use crate::{HOST_LIB_PATH, LIB_PATH, bindings::libamd_smi};
+
+/// Declares the names of the symbols, [`REQUIRED_SYMBOLS`], and [`symbols_present`], which checks them in a
+/// loaded library, from a single list.
+macro_rules! required_symbols {
+    ($($symbol:ident),+ $(,)?) => {
+        /// Symbols that must be present in the library to initialize it and discover the processors.
+        const REQUIRED_SYMBOLS: &[&str] = &[$(stringify!($symbol)),+];
+
+        /// Returns whether each of the [`REQUIRED_SYMBOLS`] is exported by the library, in the same order.
+        fn symbols_present(lib: &libamd_smi) -> impl Iterator<Item = bool> {
+            [$(lib.$symbol.is_ok()),+].into_iter()
+        }
+    };
+}
+
+required_symbols!(
+    amdsmi_init,
+    amdsmi_shut_down,
+    amdsmi_get_socket_handles,
+    amdsmi_get_processor_handles,
+    // must stay the last one, see `LibraryVariant::required_symbols`
+    amdsmi_status_code_to_string,
+);
+
+/// Variant of the AMD SMI library to load.
+///
+/// See [`AmdSmiBuilder::library_variant`](crate::builder::AmdSmiBuilder::library_variant).
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum LibraryVariant {
+    /// The library of ROCm, `libamd_smi.so`, used on bare-metal hosts and in virtual machines.
+    #[default]
+    Rocm,
+    /// The library of the host virtualization driver (amdgv), used on the hypervisor to monitor the physical GPUs
+    /// that are shared between virtual machines.
+    ///
+    /// The host library exports a subset of the functions: the wrappers of the functions that it does not export
+    /// fail with `NOT_YET_IMPLEMENTED`, without calling the library. `amdsmi_status_code_to_string` is not
+    /// required, the errors then have no message.
+    ///
+    /// # Limitations
+    ///
+    /// This variant has not been tested with the host library. Its file name ([`file_name`](Self::file_name),
+    /// `libamdsmi.so`) is an assumption, give the actual path with
+    /// [`AmdSmiBuilder::library_path`](crate::builder::AmdSmiBuilder::library_path) if it differs. The host
+    /// library is assumed to have the same ABI (structures and signatures) as the ROCm library whose headers
+    /// generated the bindings: this has not been verified, and a mismatch leads to undefined behavior.
+    Host,
+}
+
+impl LibraryVariant {
+    /// Returns the file name of the library, given to the dynamic loader.
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            LibraryVariant::Rocm => LIB_PATH,
+            LibraryVariant::Host => HOST_LIB_PATH,
+        }
+    }
+
+    /// Returns the symbols that must be present in the library to initialize it and discover the processors.
+    pub fn required_symbols(&self) -> &'static [&'static str] {
+        match self {
+            LibraryVariant::Rocm => REQUIRED_SYMBOLS,
+            // the status messages are optional, see `error::status_message`
+            LibraryVariant::Host => &REQUIRED_SYMBOLS[..4],
+        }
+    }
+}
+
+/// Failed attempt to load the library from a path.
+#[derive(Debug, Clone)]
+pub struct LoadAttempt {
+    /// Path given to the dynamic loader.
+    pub path: OsString,
+    /// Error reported by the dynamic loader (`dlerror`).
+    pub error: String,
+}
+
+impl Display for LoadAttempt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path.to_string_lossy(), self.error)
+    }
+}
+
+/// Loads the library from the first candidate path that works.
+///
+/// Returns the library and the path it has been loaded from, if any, and the failed attempts.
+pub(crate) fn load(candidates: &[OsString]) -> (Option<(libamd_smi, OsString)>, Vec<LoadAttempt>) {
+    let mut attempts = Vec::new();
+    for path in candidates {
+        // SAFETY: Loading the library runs its initialization routines, which are trusted.
+        // The symbols are checked before use (see `missing_symbols`).
+        match unsafe { libamd_smi::new(path) } {
+            Ok(lib) => return (Some((lib, path.clone())), attempts),
+            Err(e) => attempts.push(LoadAttempt {
+                path: path.clone(),
+                error: e.to_string(),
+            }),
+        }
+    }
+    (None, attempts)
+}
+
+/// Returns the required symbols that are not exported by the library.
+pub(crate) fn missing_symbols(lib: &libamd_smi, variant: LibraryVariant) -> Vec<&'static str> {
+    let required = variant.required_symbols();
+    REQUIRED_SYMBOLS
+        .iter()
+        .zip(symbols_present(lib))
+        .filter(|(name, present)| !present && required.contains(name))
+        .map(|(name, _)| *name)
+        .collect()
+}
+
+/// Copies of the library that are used by the instances of [`AmdSmi`](crate::AmdSmi) of the process.
+static LOADED: Mutex<LoadedRegistry> = Mutex::new(LoadedRegistry::new());
+
+/// Copy of the library loaded in the process, see [`loaded_libraries`].
+///
+/// Loading the same file twice, even through different paths, gives the same copy, whose state (initialization
+/// count, handles...) is shared by the instances. Loading different files, for instance two versions of ROCm,
+/// gives distinct copies of the library, with their own state, that talk to the same driver.
+///
+/// The copies are loaded with `dlopen`, in the same namespace: they are not fully isolated. The dependencies of
+/// the library that have the same soname (for instance `libdrm`) are loaded once and shared by all the copies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadedLibrary {
+    /// Path from which the copy has been loaded by its first instance.
+    pub path: OsString,
+    /// Number of instances of `AmdSmi` that use the copy, including their clones only once.
+    pub instances: usize,
+    /// Address of `amdsmi_init` in the copy, which identifies it.
+    id: usize,
+}
+
+/// Registry of the loaded copies, behind [`LOADED`].
+#[derive(Debug, Default)]
+struct LoadedRegistry {
+    libraries: Vec<LoadedLibrary>,
+}
+
+impl LoadedRegistry {
+    const fn new() -> Self {
+        Self {
+            libraries: Vec::new(),
+        }
+    }
+
+    fn register(&mut self, id: usize, path: &OsString) {
+        match self.libraries.iter_mut().find(|l| l.id == id) {
+            Some(library) => library.instances += 1,
+            None => self.libraries.push(LoadedLibrary {
+                path: path.clone(),
+                instances: 1,
+                id,
+            }),
+        }
+    }
+
+    fn unregister(&mut self, id: usize) {
+        if let Some(i) = self.libraries.iter().position(|l| l.id == id) {
+            self.libraries[i].instances -= 1;
+            if self.libraries[i].instances == 0 {
+                self.libraries.remove(i);
+            }
+        }
+    }
+
+    #[cfg(any(feature = "control", test))]
+    fn others(&self, id: usize) -> Vec<LoadedLibrary> {
+        self.libraries
+            .iter()
+            .filter(|l| l.id != id)
+            .cloned()
+            .collect()
+    }
+}
+
+/// Returns the identifier of a copy of the library: the address of its `amdsmi_init` function.
+pub(crate) fn library_id(lib: &libamd_smi) -> usize {
+    lib.amdsmi_init.as_ref().map_or(0, |init| *init as usize)
+}
+
+/// Records that an instance uses the copy `id`, loaded from `path`.
+pub(crate) fn register(id: usize, path: &OsString) {
+    LOADED
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .register(id, path);
+}
+
+/// Records that an instance no longer uses the copy `id`.
+pub(crate) fn unregister(id: usize) {
+    LOADED
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .unregister(id);
+}
+
+/// Returns the copies of the library that are used by the instances of `AmdSmi` of the process, in loading order.
+pub fn loaded_libraries() -> Vec<LoadedLibrary> {
+    LOADED
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .libraries
+        .clone()
+}
+
+/// Returns the loaded copies of the library other than the copy `id`.
+#[cfg(feature = "control")]
+pub(crate) fn other_libraries(id: usize) -> Vec<LoadedLibrary> {
+    LOADED.lock().unwrap_or_else(|e| e.into_inner()).others(id)
+}
+
+/// Result of [`AmdSmi::probe`](crate::AmdSmi::probe): whether the library can be loaded and used,
+/// without initializing it.
+#[derive(Debug, Clone)]
+pub struct AmdProbe {
+    /// Variant of the library that has been probed.
+    pub variant: LibraryVariant,
+    /// Path from which the library has been loaded, or `None` if it could not be loaded.
+    pub loaded_from: Option<OsString>,
+    /// Failed attempts to load the library, before it has been loaded (if it has).
+    pub failed_attempts: Vec<LoadAttempt>,
+    /// Required symbols that are missing from the loaded library.
+    pub missing_symbols: Vec<&'static str>,
+}
+
+impl AmdProbe {
+    /// Probes the library at the given candidate paths.
+    pub(crate) fn run(candidates: &[OsString], variant: LibraryVariant) -> Self {
+        let (loaded, failed_attempts) = load(candidates);
+        match loaded {
+            Some((lib, path)) => Self {
+                variant,
+                loaded_from: Some(path),
+                failed_attempts,
+                missing_symbols: missing_symbols(&lib, variant),
+            },
+            None => Self {
+                variant,
+                loaded_from: None,
+                failed_attempts,
+                missing_symbols: Vec::new(),
+            },
+        }
+    }
+
+    /// Returns `true` if the library has been loaded and exports all the required symbols.
+    pub fn is_usable(&self) -> bool {
+        self.loaded_from.is_some() && self.missing_symbols.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsString;
+
+    use super::{LibraryVariant, LoadedRegistry, REQUIRED_SYMBOLS};
+
+    #[test]
+    fn required_symbols() {
+        assert_eq!(REQUIRED_SYMBOLS[0], "amdsmi_init");
+        assert_eq!(LibraryVariant::Rocm.required_symbols(), REQUIRED_SYMBOLS);
+        // the status messages are optional with the host library
+        let host = LibraryVariant::Host.required_symbols();
+        assert_eq!(host.len(), REQUIRED_SYMBOLS.len() - 1);
+        assert!(!host.contains(&"amdsmi_status_code_to_string"));
+    }
+
+    #[test]
+    fn loaded_copies() {
+        let mut registry = LoadedRegistry::new();
+        let (first, second) = (1, 2);
+        let count = |registry: &LoadedRegistry, id| {
+            registry
+                .libraries
+                .iter()
+                .filter(|l| l.id == id)
+                .map(|l| l.instances)
+                .sum::<usize>()
+        };
+        // the same copy, loaded through two paths
+        registry.register(first, &OsString::from("/opt/rocm/lib/libamd_smi.so"));
+        registry.register(first, &OsString::from("libamd_smi.so"));
+        assert!(registry.others(first).is_empty());
+        registry.register(second, &OsString::from("/opt/rocm-7.2/lib/libamd_smi.so"));
+        assert_eq!((count(&registry, first), count(&registry, second)), (2, 1));
+        let others = registry.others(first);
+        assert_eq!(others.len(), 1);
+        assert_eq!(others[0].id, second);
+        registry.unregister(first);
+        registry.unregister(second);
+        assert_eq!((count(&registry, first), count(&registry, second)), (1, 0));
+        registry.unregister(first);
+        assert!(registry.libraries.is_empty());
+    }
+}
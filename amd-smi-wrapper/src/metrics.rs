@@ -1,19 +1,191 @@
 //! Parameters and results of the queries that provide metrics.
 
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
 use crate::{
     bindings::{
-        amdsmi_asic_info_t, amdsmi_clk_info_t, amdsmi_engine_usage_t, amdsmi_power_info_t,
+        amd_metrics_table_header_t, amdsmi_asic_info_t, amdsmi_bdf_t, amdsmi_board_info_t,
+        amdsmi_clk_info_t, amdsmi_cper_hdr_t, amdsmi_cper_timestamp_t, amdsmi_dpm_policy_t,
+        amdsmi_driver_info_t, amdsmi_engine_usage_t, amdsmi_enumeration_info_t,
+        amdsmi_error_count_t, amdsmi_frequencies_t, amdsmi_fw_info_t, amdsmi_kfd_info_t,
+        amdsmi_link_metrics_t__links, amdsmi_od_volt_freq_data_t, amdsmi_pcie_info_t,
+        amdsmi_power_cap_info_t, amdsmi_power_info_t, amdsmi_power_profile_status_t,
         amdsmi_proc_info_t, amdsmi_proc_info_t_engine_usage_, amdsmi_proc_info_t_memory_usage_,
+        amdsmi_range_t, amdsmi_ras_feature_t, amdsmi_retired_page_record_t, amdsmi_vbios_info_t,
+        amdsmi_xgmi_info_t,
     },
-    utils::c_buffer_to_string,
+    error::AmdError,
+    fan_mode::FanControlMode,
+    timebase::DriverTimestamp,
+    utils::{c_buffer_to_str, c_buffer_to_string, c_buffer_write_string},
 };
 
+pub type AmdCardFormFactor = crate::bindings::amdsmi_card_form_factor_t;
 pub type AmdClkType = crate::bindings::amdsmi_clk_type_t;
+pub type AmdComputePartition = crate::bindings::amdsmi_compute_partition_type_t;
+pub type AmdCperSeverity = crate::bindings::amdsmi_cper_sev_t;
+pub type AmdEventGroup = crate::bindings::amdsmi_event_group_t;
+pub type AmdEventType = crate::bindings::amdsmi_event_type_t;
+pub type AmdFwBlock = crate::bindings::amdsmi_fw_block_t;
+pub type AmdGpuBlock = crate::bindings::amdsmi_gpu_block_t;
+pub type AmdLinkType = crate::bindings::amdsmi_link_type_t;
+pub type AmdMemoryPageStatus = crate::bindings::amdsmi_memory_page_status_t;
+pub type AmdMemoryPartition = crate::bindings::amdsmi_memory_partition_type_t;
 pub type AmdMemoryType = crate::bindings::amdsmi_memory_type_t;
+pub type AmdNotificationType = crate::bindings::amdsmi_evt_notification_type_t;
+pub type AmdPerfLevel = crate::bindings::amdsmi_dev_perf_level_t;
+pub type AmdPowerProfile = crate::bindings::amdsmi_power_profile_preset_masks_t;
+pub type AmdProcessorType = crate::bindings::processor_type_t;
+pub type AmdRasErrState = crate::bindings::amdsmi_ras_err_state_t;
 pub type AmdTemperatureMetric = crate::bindings::amdsmi_temperature_metric_t;
 pub type AmdTemperatureType = crate::bindings::amdsmi_temperature_type_t;
 pub type AmdVoltageMetric = crate::bindings::amdsmi_voltage_metric_t;
 pub type AmdVoltageType = crate::bindings::amdsmi_voltage_type_t;
+pub type AmdXgmiLinkStatus = crate::bindings::amdsmi_xgmi_link_status_type_t;
+pub type AmdXgmiStatus = crate::bindings::amdsmi_xgmi_status_t;
+
+/// All the known clock domains, without duplicates.
+///
+/// `SYS` is not listed, because it is an alias of `GFX`.
+pub const ALL_CLK_TYPES: &[AmdClkType] = &[
+    AmdClkType::AMDSMI_CLK_TYPE_GFX,
+    AmdClkType::AMDSMI_CLK_TYPE_DF,
+    AmdClkType::AMDSMI_CLK_TYPE_DCEF,
+    AmdClkType::AMDSMI_CLK_TYPE_SOC,
+    AmdClkType::AMDSMI_CLK_TYPE_MEM,
+    AmdClkType::AMDSMI_CLK_TYPE_PCIE,
+    AmdClkType::AMDSMI_CLK_TYPE_VCLK0,
+    AmdClkType::AMDSMI_CLK_TYPE_VCLK1,
+    AmdClkType::AMDSMI_CLK_TYPE_DCLK0,
+    AmdClkType::AMDSMI_CLK_TYPE_DCLK1,
+];
+
+/// All the known GPU blocks that can report ECC errors.
+pub const ALL_GPU_BLOCKS: &[AmdGpuBlock] = &[
+    AmdGpuBlock::AMDSMI_GPU_BLOCK_UMC,
+    AmdGpuBlock::AMDSMI_GPU_BLOCK_SDMA,
+    AmdGpuBlock::AMDSMI_GPU_BLOCK_GFX,
+    AmdGpuBlock::AMDSMI_GPU_BLOCK_MMHUB,
+    AmdGpuBlock::AMDSMI_GPU_BLOCK_ATHUB,
+    AmdGpuBlock::AMDSMI_GPU_BLOCK_PCIE_BIF,
+    AmdGpuBlock::AMDSMI_GPU_BLOCK_HDP,
+    AmdGpuBlock::AMDSMI_GPU_BLOCK_XGMI_WAFL,
+    AmdGpuBlock::AMDSMI_GPU_BLOCK_DF,
+    AmdGpuBlock::AMDSMI_GPU_BLOCK_SMN,
+    AmdGpuBlock::AMDSMI_GPU_BLOCK_SEM,
+    AmdGpuBlock::AMDSMI_GPU_BLOCK_MP0,
+    AmdGpuBlock::AMDSMI_GPU_BLOCK_MP1,
+    AmdGpuBlock::AMDSMI_GPU_BLOCK_FUSE,
+    AmdGpuBlock::AMDSMI_GPU_BLOCK_MCA,
+    AmdGpuBlock::AMDSMI_GPU_BLOCK_VCN,
+    AmdGpuBlock::AMDSMI_GPU_BLOCK_JPEG,
+    AmdGpuBlock::AMDSMI_GPU_BLOCK_IH,
+    AmdGpuBlock::AMDSMI_GPU_BLOCK_MPIO,
+];
+
+/// All the known memory types.
+pub const ALL_MEMORY_TYPES: &[AmdMemoryType] = &[
+    AmdMemoryType::AMDSMI_MEM_TYPE_VRAM,
+    AmdMemoryType::AMDSMI_MEM_TYPE_VIS_VRAM,
+    AmdMemoryType::AMDSMI_MEM_TYPE_GTT,
+];
+
+/// All the known temperature sensors, without duplicates.
+///
+/// `JUNCTION` is not listed, because it is an alias of `HOTSPOT`.
+pub const ALL_TEMPERATURE_TYPES: &[AmdTemperatureType] = &[
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_EDGE,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_HOTSPOT,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_VRAM,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_HBM_0,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_HBM_1,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_HBM_2,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_HBM_3,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_PLX,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_GPUBOARD_NODE_RETIMER_X,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_GPUBOARD_NODE_OAM_X_IBC,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_GPUBOARD_NODE_OAM_X_IBC_2,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_GPUBOARD_NODE_OAM_X_VDD18_VR,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_GPUBOARD_NODE_OAM_X_04_HBM_B_VR,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_GPUBOARD_NODE_OAM_X_04_HBM_D_VR,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_GPUBOARD_VDDCR_VDD0,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_GPUBOARD_VDDCR_VDD1,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_GPUBOARD_VDDCR_VDD2,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_GPUBOARD_VDDCR_VDD3,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_GPUBOARD_VDDCR_SOC_A,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_GPUBOARD_VDDCR_SOC_C,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_GPUBOARD_VDDCR_SOCIO_A,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_GPUBOARD_VDDCR_SOCIO_C,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_GPUBOARD_VDD_085_HBM,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_GPUBOARD_VDDCR_11_HBM_B,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_GPUBOARD_VDDCR_11_HBM_D,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_GPUBOARD_VDD_USR,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_GPUBOARD_VDDIO_11_E32,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_UBB_FPGA,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_UBB_FRONT,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_UBB_BACK,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_UBB_OAM7,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_UBB_IBC,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_UBB_UFPGA,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_UBB_OAM1,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_OAM_0_1_HSC,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_OAM_2_3_HSC,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_OAM_4_5_HSC,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_OAM_6_7_HSC,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_UBB_FPGA_0V72_VR,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_UBB_FPGA_3V3_VR,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_RETIMER_0_1_2_3_1V2_VR,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_RETIMER_4_5_6_7_1V2_VR,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_RETIMER_0_1_0V9_VR,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_RETIMER_4_5_0V9_VR,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_RETIMER_2_3_0V9_VR,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_RETIMER_6_7_0V9_VR,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_OAM_0_1_2_3_3V3_VR,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_OAM_4_5_6_7_3V3_VR,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_IBC_HSC,
+    AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_BASEBOARD_IBC,
+];
+
+/// All the known voltage sensors.
+pub const ALL_VOLTAGE_TYPES: &[AmdVoltageType] = &[
+    AmdVoltageType::AMDSMI_VOLT_TYPE_VDDGFX,
+    AmdVoltageType::AMDSMI_VOLT_TYPE_VDDBOARD,
+];
+
+/// All the known voltage metrics.
+pub const ALL_VOLTAGE_METRICS: &[AmdVoltageMetric] = &[
+    AmdVoltageMetric::AMDSMI_VOLT_CURRENT,
+    AmdVoltageMetric::AMDSMI_VOLT_MAX,
+    AmdVoltageMetric::AMDSMI_VOLT_MIN_CRIT,
+    AmdVoltageMetric::AMDSMI_VOLT_MIN,
+    AmdVoltageMetric::AMDSMI_VOLT_MAX_CRIT,
+    AmdVoltageMetric::AMDSMI_VOLT_AVERAGE,
+    AmdVoltageMetric::AMDSMI_VOLT_LOWEST,
+    AmdVoltageMetric::AMDSMI_VOLT_HIGHEST,
+];
+
+/// All the known temperature metrics.
+pub const ALL_TEMPERATURE_METRICS: &[AmdTemperatureMetric] = &[
+    AmdTemperatureMetric::AMDSMI_TEMP_CURRENT,
+    AmdTemperatureMetric::AMDSMI_TEMP_MAX,
+    AmdTemperatureMetric::AMDSMI_TEMP_MIN,
+    AmdTemperatureMetric::AMDSMI_TEMP_MAX_HYST,
+    AmdTemperatureMetric::AMDSMI_TEMP_MIN_HYST,
+    AmdTemperatureMetric::AMDSMI_TEMP_CRITICAL,
+    AmdTemperatureMetric::AMDSMI_TEMP_CRITICAL_HYST,
+    AmdTemperatureMetric::AMDSMI_TEMP_EMERGENCY,
+    AmdTemperatureMetric::AMDSMI_TEMP_EMERGENCY_HYST,
+    AmdTemperatureMetric::AMDSMI_TEMP_CRIT_MIN,
+    AmdTemperatureMetric::AMDSMI_TEMP_CRIT_MIN_HYST,
+    AmdTemperatureMetric::AMDSMI_TEMP_OFFSET,
+    AmdTemperatureMetric::AMDSMI_TEMP_LOWEST,
+    AmdTemperatureMetric::AMDSMI_TEMP_HIGHEST,
+    AmdTemperatureMetric::AMDSMI_TEMP_SHUTDOWN,
+];
 
 /// Parameters about [`amdsmi_asic_info_t`].
 #[derive(Debug, Default, Clone)]
@@ -59,6 +231,105 @@ impl From<amdsmi_asic_info_t> for AmdAsicInfo {
     }
 }
 
+/// Parses the name of a compute partition mode, as returned by the library (for instance `SPX`).
+///
+/// Returns `AMDSMI_COMPUTE_PARTITION_INVALID` for an unknown name.
+pub fn parse_compute_partition(name: &str) -> AmdComputePartition {
+    match name.trim().to_ascii_uppercase().as_str() {
+        "SPX" => AmdComputePartition::AMDSMI_COMPUTE_PARTITION_SPX,
+        "DPX" => AmdComputePartition::AMDSMI_COMPUTE_PARTITION_DPX,
+        "TPX" => AmdComputePartition::AMDSMI_COMPUTE_PARTITION_TPX,
+        "QPX" => AmdComputePartition::AMDSMI_COMPUTE_PARTITION_QPX,
+        "CPX" => AmdComputePartition::AMDSMI_COMPUTE_PARTITION_CPX,
+        _ => AmdComputePartition::AMDSMI_COMPUTE_PARTITION_INVALID,
+    }
+}
+
+/// Parses the name of a memory partition mode, as returned by the library (for instance `NPS1`).
+///
+/// Returns `AMDSMI_MEMORY_PARTITION_UNKNOWN` for an unknown name.
+pub fn parse_memory_partition(name: &str) -> AmdMemoryPartition {
+    match name.trim().to_ascii_uppercase().as_str() {
+        "NPS1" => AmdMemoryPartition::AMDSMI_MEMORY_PARTITION_NPS1,
+        "NPS2" => AmdMemoryPartition::AMDSMI_MEMORY_PARTITION_NPS2,
+        "NPS4" => AmdMemoryPartition::AMDSMI_MEMORY_PARTITION_NPS4,
+        "NPS8" => AmdMemoryPartition::AMDSMI_MEMORY_PARTITION_NPS8,
+        _ => AmdMemoryPartition::AMDSMI_MEMORY_PARTITION_UNKNOWN,
+    }
+}
+
+/// Returns `serial` without the surrounding whitespace if it looks like a real serial number: not empty,
+/// not `N/A`, and not made of zeros only.
+pub(crate) fn valid_serial(serial: &str) -> Option<&str> {
+    let serial = serial.trim();
+    let digits = serial
+        .strip_prefix("0x")
+        .or_else(|| serial.strip_prefix("0X"))
+        .unwrap_or(serial);
+    let valid = !digits.is_empty()
+        && !serial.eq_ignore_ascii_case("N/A")
+        && digits.chars().any(|c| c != '0');
+    valid.then_some(serial)
+}
+
+/// Frequency levels of a clock domain: [`amdsmi_frequencies_t`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AmdFrequencies {
+    /// Whether the clock supports a deep sleep frequency.
+    pub has_deep_sleep: bool,
+    /// Index of the current level in `frequencies`.
+    pub current: usize,
+    /// Frequency of each level, in Hz, in increasing order.
+    pub frequencies: Vec<u64>,
+}
+
+impl From<amdsmi_frequencies_t> for AmdFrequencies {
+    fn from(value: amdsmi_frequencies_t) -> Self {
+        let count = (value.num_supported as usize).min(value.frequency.len());
+        Self {
+            has_deep_sleep: value.has_deep_sleep,
+            current: value.current as usize,
+            frequencies: value.frequency[..count].to_vec(),
+        }
+    }
+}
+
+impl AmdFrequencies {
+    /// Returns the current frequency, in Hz.
+    pub fn current_frequency(&self) -> Option<u64> {
+        self.frequencies.get(self.current).copied()
+    }
+}
+
+/// Parameters about [`amdsmi_board_info_t`].
+#[derive(Debug, Default, Clone)]
+pub struct AmdBoardInfo {
+    /// Model number of the board.
+    pub model_number: String,
+    /// Serial number of the board.
+    pub product_serial: String,
+    /// Field Replaceable Unit identification.
+    pub fru_id: String,
+    /// Commercial name of the board.
+    pub product_name: String,
+    /// Name of the board manufacturer.
+    pub manufacturer_name: String,
+}
+
+impl TryFrom<amdsmi_board_info_t> for AmdBoardInfo {
+    type Error = AmdError;
+
+    fn try_from(value: amdsmi_board_info_t) -> Result<Self, AmdError> {
+        Ok(Self {
+            model_number: c_buffer_to_str(&value.model_number)?.to_owned(),
+            product_serial: c_buffer_to_str(&value.product_serial)?.to_owned(),
+            fru_id: c_buffer_to_str(&value.fru_id)?.to_owned(),
+            product_name: c_buffer_to_str(&value.product_name)?.to_owned(),
+            manufacturer_name: c_buffer_to_str(&value.manufacturer_name)?.to_owned(),
+        })
+    }
+}
+
 /// Parameters about [`amdsmi_clk_info_t`].
 #[derive(Debug, Default, Clone)]
 pub struct AmdClkInfo {
@@ -74,6 +345,98 @@ pub struct AmdClkInfo {
     pub clk_deep_sleep: u8,
 }
 
+impl AmdClkInfo {
+    /// Returns `true` if the clock is locked to a fixed frequency.
+    pub fn is_locked(&self) -> bool {
+        self.clk_locked != 0
+    }
+
+    /// Returns `true` if the clock domain is in deep sleep.
+    pub fn is_deep_sleep(&self) -> bool {
+        self.clk_deep_sleep != 0
+    }
+
+    /// Returns the power state of the clock domain.
+    pub fn state(&self) -> AmdClockState {
+        if self.clk == 0 {
+            AmdClockState::Gated
+        } else if self.is_deep_sleep() {
+            AmdClockState::DeepSleep
+        } else {
+            AmdClockState::Active
+        }
+    }
+}
+
+/// Range of frequencies of a clock, in MHz.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AmdClockRange {
+    pub min: u64,
+    pub max: u64,
+}
+
+impl AmdClockRange {
+    /// Returns `true` if the frequency, in MHz, is in the range.
+    pub fn contains(&self, frequency: u64) -> bool {
+        (self.min..=self.max).contains(&frequency)
+    }
+}
+
+impl From<amdsmi_range_t> for AmdClockRange {
+    fn from(range: amdsmi_range_t) -> Self {
+        Self {
+            min: range.lower_bound,
+            max: range.upper_bound,
+        }
+    }
+}
+
+/// Frequency limits of the graphics (SCLK) and memory (MCLK) clocks of a GPU, from the overdrive
+/// settings of the driver: [`amdsmi_od_volt_freq_data_t`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AmdClockLimits {
+    /// Range currently applied to the graphics clock.
+    pub sclk: AmdClockRange,
+    /// Range currently applied to the memory clock. Only the upper bound is meaningful.
+    pub mclk: AmdClockRange,
+    /// Range that can be applied to the graphics clock.
+    pub sclk_limits: AmdClockRange,
+    /// Range that can be applied to the memory clock.
+    pub mclk_limits: AmdClockRange,
+}
+
+impl From<amdsmi_od_volt_freq_data_t> for AmdClockLimits {
+    fn from(data: amdsmi_od_volt_freq_data_t) -> Self {
+        Self {
+            sclk: data.curr_sclk_range.into(),
+            mclk: data.curr_mclk_range.into(),
+            sclk_limits: data.sclk_freq_limits.into(),
+            mclk_limits: data.mclk_freq_limits.into(),
+        }
+    }
+}
+
+/// Power state of a clock domain, see [`AmdClkInfo::state`].
+///
+/// When the clock domains of an idle GPU are gated or in deep sleep, a power close to zero is expected:
+/// it is not a measurement error.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum AmdClockState {
+    /// The clock is running.
+    Active,
+    /// The clock runs at its deep sleep frequency, much lower than its minimal frequency.
+    DeepSleep,
+    /// The clock is gated: its frequency is zero.
+    Gated,
+}
+
+impl AmdClockState {
+    /// Returns `true` if the clock is in deep sleep or gated.
+    pub fn is_idle(&self) -> bool {
+        !matches!(self, AmdClockState::Active)
+    }
+}
+
 impl From<amdsmi_clk_info_t> for AmdClkInfo {
     fn from(value: amdsmi_clk_info_t) -> Self {
         Self {
@@ -86,10 +449,79 @@ impl From<amdsmi_clk_info_t> for AmdClkInfo {
     }
 }
 
+/// Enumeration of a GPU by the system and the runtimes: [`amdsmi_enumeration_info_t`].
+#[derive(Debug, Default, Clone)]
+pub struct AmdEnumerationInfo {
+    /// Number of the DRM render node, as in `/dev/dri/renderD<drm_render>`.
+    pub drm_render: u32,
+    /// Number of the DRM card, as in `/dev/dri/card<drm_card>`.
+    pub drm_card: u32,
+    /// HSA enumeration index.
+    pub hsa_id: u32,
+    /// HIP enumeration index.
+    pub hip_id: u32,
+    /// HIP unique identifier.
+    pub hip_uuid: String,
+}
+
+impl TryFrom<amdsmi_enumeration_info_t> for AmdEnumerationInfo {
+    type Error = AmdError;
+
+    fn try_from(info: amdsmi_enumeration_info_t) -> Result<Self, AmdError> {
+        Ok(Self {
+            drm_render: info.drm_render,
+            drm_card: info.drm_card,
+            hsa_id: info.hsa_id,
+            hip_id: info.hip_id,
+            hip_uuid: c_buffer_to_str(&info.hip_uuid)?.to_owned(),
+        })
+    }
+}
+
+/// Identification of a GPU by the compute driver (KFD): [`amdsmi_kfd_info_t`].
+///
+/// The values that are not reported are `None`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AmdKfdInfo {
+    /// Identifier of the GPU in the KFD (`gpu_id` in the topology).
+    pub kfd_id: Option<u64>,
+    /// Index of the node of the GPU in the KFD topology, as in `/sys/class/kfd/kfd/topology/nodes/<node_id>`.
+    pub node_id: Option<u32>,
+    /// Index of the current compute partition of the GPU.
+    pub current_partition_id: Option<u32>,
+}
+
+impl From<amdsmi_kfd_info_t> for AmdKfdInfo {
+    fn from(info: amdsmi_kfd_info_t) -> Self {
+        Self {
+            kfd_id: Some(info.kfd_id).filter(|v| *v != u64::MAX),
+            node_id: Some(info.node_id).filter(|v| *v != u32::MAX),
+            current_partition_id: Some(info.current_partition_id).filter(|v| *v != u32::MAX),
+        }
+    }
+}
+
+/// Parameters about a fan of a GPU.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AmdFanInfo {
+    /// Index of the fan sensor.
+    pub sensor_index: u32,
+    /// Fan speed ratio in %.
+    pub speed: u32,
+    /// Fan speed in RPM, if supported by the device.
+    pub rpm: Option<i64>,
+    /// Maximal fan speed, relative to `AMDSMI_MAX_FAN_SPEED`, if supported by the device.
+    pub max_speed: Option<u64>,
+    /// Control mode of the fans of the device, if it can be read.
+    pub control_mode: Option<FanControlMode>,
+}
+
 /// Parameters about energy consumption of a GPU.
 #[derive(Debug, Default, Clone, Copy)]
 pub struct AmdEnergyConsumption {
-    /// The energy consumption value of an AMD GPU device since the last boot in micro Joules.
+    /// The energy counter of an AMD GPU device since the last boot, in units of `resolution`.
+    ///
+    /// Multiply it by `resolution` to get micro Joules, or use [`EnergyReader`](crate::energy::EnergyReader).
     pub energy: u64,
     /// Precision factor of the energy counter in micro Joules.
     pub resolution: f32,
@@ -97,6 +529,372 @@ pub struct AmdEnergyConsumption {
     pub timestamp: u64,
 }
 
+impl AmdEnergyConsumption {
+    /// Returns the value of the counter in µJ.
+    pub fn microjoules(&self) -> f64 {
+        energy_microjoules(self.energy, self.resolution)
+    }
+}
+
+/// Converts a raw energy counter to µJ.
+pub(crate) fn energy_microjoules(counter: u64, resolution: f32) -> f64 {
+    counter as f64 * resolution as f64
+}
+
+/// Energy consumed by a device since the last boot, see
+/// [`ProcessorHandle::device_energy_joules`](crate::handles::ProcessorHandle::device_energy_joules)
+/// and [`EnergyReader`](crate::energy::EnergyReader).
+///
+/// # Overflow and precision
+///
+/// The raw counter has 64 bits: it does not wrap around in practice (at 1 kW, with a resolution of 15.3 µJ,
+/// it would take millions of years), but it is reset to zero when the GPU or its driver is reset.
+///
+/// `joules` is a `f64`, whose precision is about 1 µJ up to 2^53 µJ, i.e. 9 GJ or about 100 days at 1 kW.
+/// For long-running systems, compute the energy between two readings with [`joules_since`](AmdEnergyReading::joules_since),
+/// which uses the raw counters.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct AmdEnergyReading {
+    /// Energy consumed since the last boot, in J.
+    pub joules: f64,
+    /// Raw value of the counter, in units of `resolution`.
+    pub counter: u64,
+    /// Resolution of the counter, in µJ.
+    pub resolution: f32,
+    /// Time at which the driver has read the counter, on the boot clock (see [`timebase`](crate::timebase)),
+    /// if the driver reports it.
+    pub timestamp: Option<DriverTimestamp>,
+}
+
+impl From<AmdEnergyConsumption> for AmdEnergyReading {
+    fn from(consumption: AmdEnergyConsumption) -> Self {
+        Self {
+            joules: consumption.microjoules() / 1e6,
+            counter: consumption.energy,
+            resolution: consumption.resolution,
+            timestamp: (consumption.timestamp != 0)
+                .then_some(DriverTimestamp::from_nanos(consumption.timestamp)),
+        }
+    }
+}
+
+impl AmdEnergyReading {
+    /// Creates a reading from a counter in µJ, with no timestamp (CPU counters).
+    pub fn from_microjoules(counter: u64) -> Self {
+        Self {
+            joules: counter as f64 / 1e6,
+            counter,
+            resolution: 1.0,
+            timestamp: None,
+        }
+    }
+
+    /// Returns the value of the counter in µJ.
+    pub fn microjoules(&self) -> f64 {
+        energy_microjoules(self.counter, self.resolution)
+    }
+
+    /// Returns the energy consumed since a `previous` reading of the same GPU, in J.
+    ///
+    /// Returns `None` if the counter has been reset in between.
+    pub fn joules_since(&self, previous: &AmdEnergyReading) -> Option<f64> {
+        let delta = self.counter.checked_sub(previous.counter)?;
+        Some(energy_microjoules(delta, self.resolution) / 1e6)
+    }
+
+    /// Returns the mean power since a `previous` reading of the same GPU, in W, computed with the timestamps
+    /// of the driver.
+    ///
+    /// Returns `None` if the counter has been reset in between, or if the driver does not timestamp the readings.
+    pub fn watts_since(&self, previous: &AmdEnergyReading) -> Option<f64> {
+        let elapsed = self.timestamp?.duration_since(previous.timestamp?)?;
+        if elapsed.is_zero() {
+            return None;
+        }
+        Some(self.joules_since(previous)? / elapsed.as_secs_f64())
+    }
+}
+
+/// Time during which an engine of a GPU has been busy, see [`ProcessorHandle::device_busy_time`](crate::handles::ProcessorHandle::device_busy_time)
+/// for the graphic engine and [`ProcessorHandle::device_memory_busy_time`](crate::handles::ProcessorHandle::device_memory_busy_time)
+/// for the memory controller.
+///
+/// Every millisecond, the firmware adds the activity of the engine during that millisecond, in %, to the counter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmdBusyTime {
+    /// Raw value of the counter: sum of the activities in %, one per millisecond.
+    pub counter: u64,
+    /// Time at which the counter has been read, on the boot clock (see [`timebase`](crate::timebase)).
+    pub timestamp: DriverTimestamp,
+}
+
+impl AmdBusyTime {
+    /// Returns the accumulated busy time.
+    pub fn busy(&self) -> Duration {
+        // each unit is 1% of 1 ms
+        Duration::from_micros(self.counter * 10)
+    }
+
+    /// Returns the fraction of time during which the engine has been busy since a `previous` reading of the
+    /// same GPU, between 0 and 1.
+    ///
+    /// Returns `None` if the counter has been reset in between, or if no time has elapsed.
+    pub fn duty_cycle_since(&self, previous: &AmdBusyTime) -> Option<f64> {
+        let busy = self.busy().checked_sub(previous.busy())?;
+        let elapsed = self.timestamp.duration_since(previous.timestamp)?;
+        if elapsed.is_zero() {
+            return None;
+        }
+        Some((busy.as_secs_f64() / elapsed.as_secs_f64()).min(1.0))
+    }
+}
+
+/// Number of ECC (Error Correction Code) errors of a GPU since boot: [`amdsmi_error_count_t`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AmdEccCount {
+    /// Number of errors that have been corrected.
+    pub correctable: u64,
+    /// Number of errors that could not be corrected.
+    pub uncorrectable: u64,
+    /// Number of errors whose handling has been deferred.
+    pub deferred: u64,
+}
+
+impl From<amdsmi_error_count_t> for AmdEccCount {
+    fn from(count: amdsmi_error_count_t) -> Self {
+        Self {
+            correctable: count.correctable_count,
+            uncorrectable: count.uncorrectable_count,
+            deferred: count.deferred_count,
+        }
+    }
+}
+
+/// Number of ECC errors of a block of a GPU, see
+/// [`ProcessorHandle::device_block_ecc_counts`](crate::handles::ProcessorHandle::device_block_ecc_counts).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmdBlockEccCount {
+    /// Block of the GPU.
+    pub block: AmdGpuBlock,
+    /// Counts of errors of the block since boot.
+    pub count: AmdEccCount,
+}
+
+/// RAS (Reliability, Availability, Serviceability) features of a GPU: [`amdsmi_ras_feature_t`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AmdRasFeatures {
+    /// Version of the RAS EEPROM, which stores the retired pages.
+    pub eeprom_version: u32,
+    /// Mask of the supported ECC correction schemes, see the methods of `AmdRasFeatures`.
+    pub ecc_correction_schema: u32,
+}
+
+impl AmdRasFeatures {
+    /// Returns `true` if the parity errors are detected.
+    pub fn parity(&self) -> bool {
+        self.ecc_correction_schema & 1 != 0
+    }
+
+    /// Returns `true` if the single-bit errors are corrected.
+    pub fn single_bit_correction(&self) -> bool {
+        self.ecc_correction_schema & (1 << 1) != 0
+    }
+
+    /// Returns `true` if the double-bit errors are detected.
+    pub fn double_bit_detection(&self) -> bool {
+        self.ecc_correction_schema & (1 << 2) != 0
+    }
+
+    /// Returns `true` if the uncorrectable errors are isolated by poisoning the memory.
+    pub fn poison(&self) -> bool {
+        self.ecc_correction_schema & (1 << 3) != 0
+    }
+}
+
+impl From<amdsmi_ras_feature_t> for AmdRasFeatures {
+    fn from(features: amdsmi_ras_feature_t) -> Self {
+        Self {
+            eeprom_version: features.ras_eeprom_version,
+            ecc_correction_schema: features.ecc_correction_schema_flag,
+        }
+    }
+}
+
+/// Memory page retired because of ECC errors: [`amdsmi_retired_page_record_t`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmdRetiredPage {
+    /// Start address of the page.
+    pub address: u64,
+    /// Size of the page, in bytes.
+    pub size: u64,
+    /// Whether the page has been reserved, so that it is no longer used.
+    pub status: AmdMemoryPageStatus,
+}
+
+impl From<amdsmi_retired_page_record_t> for AmdRetiredPage {
+    fn from(record: amdsmi_retired_page_record_t) -> Self {
+        Self {
+            address: record.page_address,
+            size: record.page_size,
+            status: record.status,
+        }
+    }
+}
+
+/// [`AmdEccCount`] read at a given time, to compute error rates, see
+/// [`ProcessorHandle::device_ecc_snapshot`](crate::handles::ProcessorHandle::device_ecc_snapshot).
+///
+/// # Example
+/// ```no_run
+/// use std::{thread, time::Duration};
+/// use amd_smi_wrapper::{AmdSmi, AmdInitFlags, AmdInterface, handles::{ProcessorHandle, SocketHandle}};
+///
+/// let amdsmi = AmdSmi::init(AmdInitFlags::AMDSMI_INIT_AMD_GPUS).unwrap();
+/// let socket = &amdsmi.socket_handles().unwrap()[0];
+/// let device = &socket.processor_handles().unwrap()[0];
+///
+/// let previous = device.device_ecc_snapshot().unwrap();
+/// thread::sleep(Duration::from_secs(60));
+/// let current = device.device_ecc_snapshot().unwrap();
+/// if let Some(rate) = current.rate_since(&previous) {
+///     println!("{} correctable errors/hour", rate.correctable);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmdEccSnapshot {
+    /// Counts of errors.
+    pub count: AmdEccCount,
+    /// Time at which the counts have been read.
+    pub time: Instant,
+}
+
+impl AmdEccSnapshot {
+    /// Returns the error rates between a `previous` snapshot of the same GPU and this one, in errors per hour.
+    ///
+    /// Returns `None` if `previous` is not older than this snapshot, or if the counters have been reset in between.
+    pub fn rate_since(&self, previous: &AmdEccSnapshot) -> Option<AmdEccRate> {
+        let hours = self
+            .time
+            .checked_duration_since(previous.time)?
+            .as_secs_f64()
+            / 3600.0;
+        if hours <= 0.0 {
+            return None;
+        }
+        let rate = |current: u64, previous: u64| {
+            current
+                .checked_sub(previous)
+                .map(|delta| delta as f64 / hours)
+        };
+        Some(AmdEccRate {
+            correctable: rate(self.count.correctable, previous.count.correctable)?,
+            uncorrectable: rate(self.count.uncorrectable, previous.count.uncorrectable)?,
+            deferred: rate(self.count.deferred, previous.count.deferred)?,
+        })
+    }
+}
+
+/// Rates of ECC errors of a GPU, in errors per hour, see [`AmdEccSnapshot::rate_since`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct AmdEccRate {
+    /// Rate of the errors that have been corrected.
+    pub correctable: f64,
+    /// Rate of the errors that could not be corrected.
+    pub uncorrectable: f64,
+    /// Rate of the errors whose handling has been deferred.
+    pub deferred: f64,
+}
+
+/// Alert level of a temperature sensor, from the limits of its [`AmdThermalMargin`].
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ThermalLevel {
+    /// The temperature is below the critical limit.
+    #[default]
+    Normal,
+    /// The critical limit has been reached: the GPU is throttled.
+    Critical,
+    /// The emergency limit has been reached.
+    Emergency,
+    /// The shutdown limit has been reached: the GPU is about to be shut down.
+    Shutdown,
+}
+
+/// Temperature of a sensor compared to its limits, in Celsius degrees, see
+/// [`ProcessorHandle::device_thermal_margin`](crate::handles::ProcessorHandle::device_thermal_margin).
+///
+/// The limits that the sensor does not report are `None`. The hysteresis limits are absolute temperatures:
+/// an alert raised at a limit is cleared when the temperature falls below the hysteresis of the limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmdThermalMargin {
+    /// Sensor of the temperatures.
+    pub sensor: AmdTemperatureType,
+    /// Current temperature.
+    pub current: i64,
+    /// Critical limit.
+    pub critical: Option<i64>,
+    /// Temperature below which the critical alert is cleared.
+    pub critical_hyst: Option<i64>,
+    /// Emergency limit.
+    pub emergency: Option<i64>,
+    /// Temperature below which the emergency alert is cleared.
+    pub emergency_hyst: Option<i64>,
+    /// Shutdown limit.
+    pub shutdown: Option<i64>,
+}
+
+impl AmdThermalMargin {
+    /// Returns the headroom before the critical limit, negative if it is exceeded.
+    pub fn critical_margin(&self) -> Option<i64> {
+        self.critical.map(|limit| limit - self.current)
+    }
+
+    /// Returns the headroom before the emergency limit, negative if it is exceeded.
+    pub fn emergency_margin(&self) -> Option<i64> {
+        self.emergency.map(|limit| limit - self.current)
+    }
+
+    /// Returns the headroom before the shutdown limit, negative if it is exceeded.
+    pub fn shutdown_margin(&self) -> Option<i64> {
+        self.shutdown.map(|limit| limit - self.current)
+    }
+
+    /// Returns the headroom before the lowest limit reported by the sensor.
+    pub fn margin(&self) -> Option<i64> {
+        [
+            self.critical_margin(),
+            self.emergency_margin(),
+            self.shutdown_margin(),
+        ]
+        .into_iter()
+        .flatten()
+        .min()
+    }
+
+    /// Returns the alert level of the sensor, given the level of the `previous` reading.
+    ///
+    /// A level is raised as soon as its limit is reached, and is kept until the temperature falls below
+    /// its hysteresis (or below the limit if the hysteresis is unknown), so that a temperature oscillating
+    /// around a limit does not raise an alert at each reading.
+    pub fn level(&self, previous: ThermalLevel) -> ThermalLevel {
+        let limits = [
+            (ThermalLevel::Critical, self.critical, self.critical_hyst),
+            (ThermalLevel::Emergency, self.emergency, self.emergency_hyst),
+            (ThermalLevel::Shutdown, self.shutdown, None),
+        ];
+        limits
+            .into_iter()
+            .filter_map(|(level, limit, hyst)| {
+                let limit = limit?;
+                // a hysteresis above the limit is invalid
+                let clear = hyst.map_or(limit, |hyst| hyst.min(limit));
+                let reached = self.current >= limit || (level <= previous && self.current >= clear);
+                reached.then_some(level)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+}
+
 /// Parameters about the engine activity usage: [`amdsmi_engine_usage_t`].
 #[derive(Debug, Default, Clone, Copy)]
 pub struct AmdEngineUsage {
@@ -118,17 +916,294 @@ impl From<amdsmi_engine_usage_t> for AmdEngineUsage {
     }
 }
 
+/// Memory usage of a GPU, for a given memory type.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AmdMemoryUsage {
+    /// Used memory in bytes.
+    pub used: u64,
+    /// Total memory in bytes.
+    pub total: u64,
+}
+
+impl AmdMemoryUsage {
+    /// Returns the fraction of the memory that is used, between 0 and 1, or `None` if the total is unknown.
+    pub fn ratio(&self) -> Option<f64> {
+        (self.total > 0).then(|| self.used as f64 / self.total as f64)
+    }
+}
+
+/// Memory pressure on a GPU: usage of the VRAM and of the GTT, and evictions of the processes.
+///
+/// The GTT (Graphics Translation Table) is the system memory mapped for the GPU. When the VRAM is full,
+/// the driver moves buffers to the GTT and evicts the queues of the processes while it does so:
+/// a high GTT usage and a growing eviction time are signs that the GPU memory is overcommitted.
+///
+/// See [`ProcessorHandle::device_memory_pressure`](crate::handles::ProcessorHandle::device_memory_pressure).
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AmdMemoryPressure {
+    /// Usage of the VRAM, or `None` if the device does not report it.
+    pub vram: Option<AmdMemoryUsage>,
+    /// Usage of the GTT, or `None` if the device does not report it.
+    pub gtt: Option<AmdMemoryUsage>,
+    /// GTT memory used by the processes, in bytes.
+    pub process_gtt: u64,
+    /// Time during which the queues of each process have been evicted, in milliseconds, as `(pid, time)`.
+    ///
+    /// The counters are maintained by the kernel driver (KFD) and grow for the lifetime of the processes.
+    /// Only the processes that have been evicted at least once are listed.
+    pub evictions: Vec<(u32, u32)>,
+}
+
+impl AmdMemoryPressure {
+    pub(crate) fn new(
+        vram: Option<AmdMemoryUsage>,
+        gtt: Option<AmdMemoryUsage>,
+        processes: &[AmdProcess],
+    ) -> Self {
+        Self {
+            vram,
+            gtt,
+            process_gtt: processes.iter().map(|p| p.memory_usage.gtt_mem).sum(),
+            evictions: processes
+                .iter()
+                .filter(|p| p.evicted_time > 0)
+                .map(|p| (p.pid, p.evicted_time))
+                .collect(),
+        }
+    }
+
+    /// Returns the total eviction time of the processes, in milliseconds.
+    pub fn total_evicted_time(&self) -> u64 {
+        self.evictions.iter().map(|(_, time)| *time as u64).sum()
+    }
+}
+
+/// PCI address of a GPU, decoded from the BDF identifier returned by the library.
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AmdPciAddress {
+    /// PCI domain.
+    pub domain: u32,
+    /// PCI bus.
+    pub bus: u8,
+    /// PCI device.
+    pub device: u8,
+    /// PCI function.
+    pub function: u8,
+    /// Partition identifier, when the GPU is partitioned.
+    pub partition: u8,
+}
+
+impl AmdPciAddress {
+    /// Decodes a BDF identifier, as returned by `amdsmi_get_gpu_bdf_id`.
+    pub fn from_bdf_id(bdf_id: u64) -> Self {
+        Self {
+            domain: (bdf_id >> 32) as u32,
+            partition: ((bdf_id >> 28) & 0xf) as u8,
+            bus: ((bdf_id >> 8) & 0xff) as u8,
+            device: ((bdf_id >> 3) & 0x1f) as u8,
+            function: (bdf_id & 0x7) as u8,
+        }
+    }
+
+    /// Returns the address of the physical GPU, without the partition identifier.
+    ///
+    /// All the partitions of a GPU have the same physical address.
+    pub fn physical(&self) -> Self {
+        Self {
+            partition: 0,
+            ..*self
+        }
+    }
+
+    /// Decodes a BDF structure of the library, which has no partition identifier.
+    pub fn from_bdf(bdf: amdsmi_bdf_t) -> Self {
+        // SAFETY: All the variants of the union are views of the same 64-bit integer.
+        let bdf = unsafe { bdf.as_uint };
+        Self {
+            domain: (bdf >> 16) as u32,
+            bus: (bdf >> 8) as u8,
+            device: ((bdf >> 3) & 0x1f) as u8,
+            function: (bdf & 0x7) as u8,
+            partition: 0,
+        }
+    }
+
+    /// Returns the sysfs directory of the PCI device, for instance `/sys/bus/pci/devices/0000:03:00.0`.
+    pub fn sysfs_path(&self) -> PathBuf {
+        PathBuf::from(format!("/sys/bus/pci/devices/{self}"))
+    }
+}
+
+/// Formats the address like the kernel does, for instance `0000:03:00.0`. The partition is not included.
+impl std::fmt::Display for AmdPciAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:04x}:{:02x}:{:02x}.{:x}",
+            self.domain, self.bus, self.device, self.function
+        )
+    }
+}
+
+/// Compute partition of a GPU, see [`ProcessorHandle::device_partition`](crate::handles::ProcessorHandle::device_partition).
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct AmdDevicePartition {
+    /// Address of the physical GPU, shared by all its partitions.
+    pub physical: AmdPciAddress,
+    /// Index of the partition in the physical GPU, 0 if the GPU is not partitioned.
+    pub partition_id: u32,
+}
+
+/// Formats the partition for the logs, for instance `0000:03:00.0 partition 2`.
+impl std::fmt::Display for AmdDevicePartition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} partition {}", self.physical, self.partition_id)
+    }
+}
+
+/// Description of a socket, see
+/// [`SocketHandle::socket_metadata`](crate::handles::SocketHandle::socket_metadata).
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AmdSocketMetadata {
+    /// Identifier of the socket, given by the library.
+    pub id: String,
+    /// Number of processors of the socket, by type.
+    pub processor_counts: HashMap<AmdProcessorType, usize>,
+    /// Index of the OAM (OCP Accelerator Module) slot of the socket, for the GPUs that are OAM modules.
+    pub oam_id: Option<u32>,
+}
+
+/// Identification of a GPU, to label its metrics and logs.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AmdDeviceIdentity {
+    /// UUID of the GPU.
+    pub uuid: String,
+    /// PCI address of the GPU.
+    pub pci_address: AmdPciAddress,
+    /// GPU chip serial number.
+    pub asic_serial: String,
+    /// Serial number of the board, if the board information is available.
+    pub board_serial: Option<String>,
+    /// Model name of the GPU.
+    pub market_name: String,
+}
+
+impl AmdDeviceIdentity {
+    /// Returns the identification as a list of `(key, value)` labels.
+    ///
+    /// The board serial is omitted if it is unknown.
+    pub fn labels(&self) -> Vec<(&'static str, String)> {
+        let mut labels = vec![
+            ("uuid", self.uuid.clone()),
+            ("bdf", self.pci_address.to_string()),
+            ("asic_serial", self.asic_serial.clone()),
+            ("market_name", self.market_name.clone()),
+        ];
+        if let Some(serial) = &self.board_serial {
+            labels.push(("board_serial", serial.clone()));
+        }
+        labels
+    }
+}
+
 /// Parameters about PCI bus traffic by a GPU.
 #[derive(Debug, Default, Clone, Copy)]
 pub struct AmdPciTraffic {
-    /// Number of bytes sent.
+    /// Number of bytes sent during the last second, measured by the driver.
     pub sent: u64,
-    /// Number of bytes received.
+    /// Number of bytes received during the last second, measured by the driver.
     pub received: u64,
     /// Maximum packet size.
     pub max_pkt_sz: u64,
 }
 
+/// PCIe link of a GPU: [`amdsmi_pcie_info_t`].
+///
+/// The values that the device does not report are `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmdPcieInfo {
+    /// Maximal number of lanes.
+    pub max_width: Option<u16>,
+    /// Maximal speed, in GT/s or in MT/s depending on the library, see [`max_speed_gts`](Self::max_speed_gts).
+    pub max_speed: Option<u32>,
+    /// PCIe generation of the link.
+    pub interface_version: Option<u32>,
+    /// Maximal PCIe generation supported by the device.
+    pub max_interface_version: Option<u32>,
+    /// Form factor of the card.
+    pub slot_type: AmdCardFormFactor,
+    /// Current number of lanes.
+    pub width: Option<u16>,
+    /// Current speed, in MT/s.
+    pub speed: Option<u32>,
+    /// Current instantaneous bandwidth, in Mb/s.
+    pub bandwidth: Option<u32>,
+    /// Number of replays issued on the link, since boot.
+    pub replay_count: Option<u64>,
+    /// Number of transitions of the link from the L0 state to the recovery state, since boot.
+    pub l0_to_recovery_count: Option<u64>,
+    /// Number of replay rollovers issued on the link, since boot.
+    pub replay_roll_over_count: Option<u64>,
+    /// Number of NAKs sent by the device, since boot.
+    pub nak_sent_count: Option<u64>,
+    /// Number of NAKs received by the device, since boot.
+    pub nak_received_count: Option<u64>,
+    /// Number of recoveries initiated by the other end of the link, since boot.
+    pub other_end_recovery_count: Option<u32>,
+}
+
+impl From<amdsmi_pcie_info_t> for AmdPcieInfo {
+    fn from(info: amdsmi_pcie_info_t) -> Self {
+        // the library fills the values that the device does not report with the maximal value of their type
+        let u16 = |v: u16| Some(v).filter(|v| *v != u16::MAX);
+        let u32 = |v: u32| Some(v).filter(|v| *v != u32::MAX);
+        let u64 = |v: u64| Some(v).filter(|v| *v != u64::MAX);
+        let (s, m) = (info.pcie_static, info.pcie_metric);
+        Self {
+            max_width: u16(s.max_pcie_width),
+            max_speed: u32(s.max_pcie_speed),
+            interface_version: u32(s.pcie_interface_version),
+            max_interface_version: u32(s.max_pcie_interface_version),
+            slot_type: s.slot_type,
+            width: u16(m.pcie_width),
+            speed: u32(m.pcie_speed),
+            bandwidth: u32(m.pcie_bandwidth),
+            replay_count: u64(m.pcie_replay_count),
+            l0_to_recovery_count: u64(m.pcie_l0_to_recovery_count),
+            replay_roll_over_count: u64(m.pcie_replay_roll_over_count),
+            nak_sent_count: u64(m.pcie_nak_sent_count),
+            nak_received_count: u64(m.pcie_nak_received_count),
+            other_end_recovery_count: u32(m.pcie_lc_perf_other_end_recovery_count),
+        }
+    }
+}
+
+impl AmdPcieInfo {
+    /// Returns the current speed of the link, in GT/s.
+    pub fn speed_gts(&self) -> Option<f64> {
+        self.speed.map(|mts| mts as f64 / 1000.0)
+    }
+
+    /// Returns the maximal speed of the link, in GT/s.
+    ///
+    /// The library documents the maximal speed in GT/s, but some versions report it in MT/s, like the current
+    /// speed. No PCIe generation reaches 1000 GT/s: the larger values are in MT/s, and are converted.
+    pub fn max_speed_gts(&self) -> Option<f64> {
+        self.max_speed.map(|speed| match speed {
+            0..1000 => speed as f64,
+            mts => mts as f64 / 1000.0,
+        })
+    }
+
+    /// Returns `true` if the link runs below its maximal width or speed, which happens when the GPU
+    /// saves power, or when the link is degraded.
+    pub fn is_downgraded(&self) -> Option<bool> {
+        let width = self.width? < self.max_width?;
+        let speed = self.speed_gts()? < self.max_speed_gts()?;
+        Some(width || speed)
+    }
+}
+
 /// Parameters about engine activity usage by process: [`amdsmi_proc_info_t_memory_usage_`].
 #[derive(Debug, Default, Clone, Copy)]
 pub struct AmdProcessEngineUsage {
@@ -179,9 +1254,11 @@ pub struct AmdProcess {
     pub mem: u64,
     pub engine_usage: AmdProcessEngineUsage,
     pub memory_usage: AmdProcessMemoryUsage,
-    /// ASCII name of the process container.
+    /// ASCII name of the process container, empty if the process does not run in a container.
+    ///
+    /// See [`AmdProcess::container`].
     pub container_name: String,
-    /// Number of compute units utilized.
+    /// Number of compute units utilized by the process.
     pub cu_occupancy: u32,
     /// Time that queues are evicted on a GPU in milliseconds.
     pub evicted_time: u32,
@@ -202,6 +1279,198 @@ impl From<amdsmi_proc_info_t> for AmdProcess {
     }
 }
 
+impl AmdProcess {
+    /// Replaces the content of this structure by `value`, reusing the allocated strings.
+    pub(crate) fn update_from(&mut self, value: &amdsmi_proc_info_t) {
+        c_buffer_write_string(&value.name, &mut self.name);
+        self.pid = value.pid;
+        self.mem = value.mem;
+        self.engine_usage = value.engine_usage.into();
+        self.memory_usage = value.memory_usage.into();
+        c_buffer_write_string(&value.container_name, &mut self.container_name);
+        self.cu_occupancy = value.cu_occupancy;
+        self.evicted_time = value.evicted_time;
+    }
+
+    /// Returns the name of the container in which the process runs, or `None` if it does not run in a container.
+    pub fn container(&self) -> Option<&str> {
+        Some(self.container_name.as_str()).filter(|name| !name.is_empty())
+    }
+
+    /// Returns `true` if the process runs in a container.
+    pub fn is_containerized(&self) -> bool {
+        self.container().is_some()
+    }
+
+    /// Returns the fraction of the compute units of the GPU that are utilized by the process, between 0 and 1.
+    ///
+    /// `total_compute_units` is the number of compute units of the GPU,
+    /// see [`AmdAsicInfo::num_of_compute_units`].
+    pub fn cu_occupancy_ratio(&self, total_compute_units: u32) -> Option<f64> {
+        (total_compute_units > 0).then(|| self.cu_occupancy as f64 / total_compute_units as f64)
+    }
+}
+
+/// Selection of the processes returned by
+/// [`ProcessorHandle::device_process_list_filtered`](crate::handles::ProcessorHandle::device_process_list_filtered).
+///
+/// The conditions are combined: a process is kept if it satisfies all of them.
+/// The default filter keeps every process.
+#[derive(Debug, Default, Clone)]
+pub struct ProcessFilter {
+    pids: Option<HashSet<u32>>,
+    min_vram: u64,
+    containerized_only: bool,
+}
+
+impl ProcessFilter {
+    /// Creates a filter that keeps every process.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps only the processes whose pid is in `pids`.
+    pub fn pids(mut self, pids: impl IntoIterator<Item = u32>) -> Self {
+        self.pids = Some(pids.into_iter().collect());
+        self
+    }
+
+    /// Keeps only the processes that use at least `bytes` of VRAM.
+    pub fn min_vram(mut self, bytes: u64) -> Self {
+        self.min_vram = bytes;
+        self
+    }
+
+    /// Keeps only the processes that run in a container.
+    pub fn containerized_only(mut self) -> Self {
+        self.containerized_only = true;
+        self
+    }
+
+    /// Returns `true` if the process satisfies the conditions of the filter.
+    pub fn matches(&self, process: &AmdProcess) -> bool {
+        self.pids
+            .as_ref()
+            .is_none_or(|pids| pids.contains(&process.pid))
+            && process.memory_usage.vram_mem >= self.min_vram
+            && (!self.containerized_only || process.is_containerized())
+    }
+}
+
+/// All the known power profiles.
+pub const ALL_POWER_PROFILES: &[AmdPowerProfile] = &[
+    AmdPowerProfile::AMDSMI_PWR_PROF_PRST_CUSTOM_MASK,
+    AmdPowerProfile::AMDSMI_PWR_PROF_PRST_VIDEO_MASK,
+    AmdPowerProfile::AMDSMI_PWR_PROF_PRST_POWER_SAVING_MASK,
+    AmdPowerProfile::AMDSMI_PWR_PROF_PRST_COMPUTE_MASK,
+    AmdPowerProfile::AMDSMI_PWR_PROF_PRST_VR_MASK,
+    AmdPowerProfile::AMDSMI_PWR_PROF_PRST_3D_FULL_SCR_MASK,
+    AmdPowerProfile::AMDSMI_PWR_PROF_PRST_BOOTUP_DEFAULT,
+];
+
+/// Power profiles of a GPU: [`amdsmi_power_profile_status_t`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmdPowerProfileStatus {
+    /// Bit mask of the profiles supported by the device, see [`is_available`](Self::is_available).
+    pub available_profiles: u64,
+    /// Profile that is currently active.
+    pub current: AmdPowerProfile,
+    /// Number of profiles supported by the device.
+    pub num_profiles: u32,
+}
+
+impl From<amdsmi_power_profile_status_t> for AmdPowerProfileStatus {
+    fn from(status: amdsmi_power_profile_status_t) -> Self {
+        Self {
+            available_profiles: status.available_profiles,
+            current: status.current,
+            num_profiles: status.num_profiles,
+        }
+    }
+}
+
+impl AmdPowerProfileStatus {
+    /// Returns `true` if the device supports the given profile.
+    pub fn is_available(&self, profile: AmdPowerProfile) -> bool {
+        self.available_profiles & profile.0 != 0
+    }
+
+    /// Returns the profiles supported by the device.
+    pub fn available(&self) -> Vec<AmdPowerProfile> {
+        ALL_POWER_PROFILES
+            .iter()
+            .copied()
+            .filter(|p| self.is_available(*p))
+            .collect()
+    }
+}
+
+/// Policy of a power management feature, for instance a SoC P-state policy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmdDpmPolicyEntry {
+    /// Identifier of the policy, given to the library to select it.
+    pub id: u32,
+    /// Description of the policy.
+    pub description: String,
+}
+
+/// Policies supported by a power management feature, and the current one: [`amdsmi_dpm_policy_t`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmdDpmPolicy {
+    /// Index of the current policy in `policies`.
+    pub current: usize,
+    /// Supported policies.
+    pub policies: Vec<AmdDpmPolicyEntry>,
+}
+
+impl TryFrom<&amdsmi_dpm_policy_t> for AmdDpmPolicy {
+    type Error = AmdError;
+
+    /// Converts the policies, or returns [`AmdError::InvalidString`] if a description is not valid UTF-8.
+    ///
+    /// Only the first `num_supported` policies are kept, up to the size of the array.
+    fn try_from(policy: &amdsmi_dpm_policy_t) -> Result<Self, AmdError> {
+        let count = (policy.num_supported as usize).min(policy.policies.len());
+        Ok(Self {
+            current: policy.current as usize,
+            policies: policy.policies[..count]
+                .iter()
+                .map(|p| {
+                    Ok(AmdDpmPolicyEntry {
+                        id: p.policy_id,
+                        description: c_buffer_to_str(&p.policy_description)?.to_owned(),
+                    })
+                })
+                .collect::<Result<_, AmdError>>()?,
+        })
+    }
+}
+
+impl AmdDpmPolicy {
+    /// Returns the current policy, if the library reported a valid index.
+    pub fn current_policy(&self) -> Option<&AmdDpmPolicyEntry> {
+        self.policies.get(self.current)
+    }
+}
+
+/// Power management features of a GPU, and whether they are active.
+///
+/// The features that the device does not report are `None`.
+/// See [`ProcessorHandle::device_power_management`](crate::handles::ProcessorHandle::device_power_management).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmdPowerManagement {
+    /// Whether the dynamic power management (DPM) is enabled.
+    pub dpm: bool,
+    /// Whether the power of the GPU is capped.
+    pub power_capping: Option<bool>,
+    /// Whether the GPU can switch between several power profiles.
+    pub profile_switching: Option<bool>,
+    /// Policy of the SoC P-states.
+    pub soc_pstate: Option<AmdDpmPolicy>,
+    /// Policy of the XGMI per-link power down (PLPD).
+    pub xgmi_plpd: Option<AmdDpmPolicy>,
+}
+
 /// Parameters about power consumption: [`amdsmi_power_info_t`].
 #[derive(Debug, Default, Clone, Copy)]
 pub struct AmdPowerConsumption {
@@ -234,3 +1503,614 @@ impl From<amdsmi_power_info_t> for AmdPowerConsumption {
         }
     }
 }
+
+/// Voltages of the power rails of a GPU, in mV, see
+/// [`ProcessorHandle::device_rail_voltages`](crate::handles::ProcessorHandle::device_rail_voltages).
+///
+/// The rails that are not reported by the GPU are `None`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AmdRailVoltages {
+    /// Voltage of the graphic core.
+    pub gfx: Option<u64>,
+    /// Voltage of the SoC.
+    pub soc: Option<u64>,
+    /// Voltage of the memory.
+    pub mem: Option<u64>,
+}
+
+impl AmdRailVoltages {
+    /// Reads the voltages of [`AmdPowerConsumption`], in which the unsupported rails are set to 0 or `UINT32_MAX`.
+    pub fn from_power(power: &AmdPowerConsumption) -> Self {
+        let valid = |v: u64| Some(v).filter(|v| *v != 0 && *v != u32::MAX as u64 && *v != u64::MAX);
+        Self {
+            gfx: valid(power.gfx_voltage),
+            soc: valid(power.soc_voltage),
+            mem: valid(power.mem_voltage),
+        }
+    }
+
+    /// Fills the missing voltages with those of `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            gfx: self.gfx.or(other.gfx),
+            soc: self.soc.or(other.soc),
+            mem: self.mem.or(other.mem),
+        }
+    }
+}
+
+/// Power cap configuration of a power sensor of a GPU: [`amdsmi_power_cap_info_t`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AmdPowerCapInfo {
+    /// Current power cap in µW.
+    pub power_cap: u64,
+    /// Default power cap in µW.
+    pub default_power_cap: u64,
+    /// DPM power cap in MHz.
+    pub dpm_cap: u64,
+    /// Minimal power cap in µW.
+    pub min_power_cap: u64,
+    /// Maximal power cap in µW.
+    pub max_power_cap: u64,
+}
+
+impl From<amdsmi_power_cap_info_t> for AmdPowerCapInfo {
+    fn from(info: amdsmi_power_cap_info_t) -> Self {
+        Self {
+            power_cap: info.power_cap,
+            default_power_cap: info.default_power_cap,
+            dpm_cap: info.dpm_cap,
+            min_power_cap: info.min_power_cap,
+            max_power_cap: info.max_power_cap,
+        }
+    }
+}
+
+/// Power sensor of a GPU.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AmdPowerSensor {
+    /// Index of the power sensor.
+    pub sensor_index: u32,
+    /// Power cap configuration of the sensor.
+    pub cap: AmdPowerCapInfo,
+}
+
+/// Parameters about the video BIOS: [`amdsmi_vbios_info_t`].
+#[derive(Debug, Default, Clone)]
+pub struct AmdVbiosInfo {
+    /// Name of the VBIOS.
+    pub name: String,
+    /// Build date of the VBIOS.
+    pub build_date: String,
+    /// Part number of the VBIOS.
+    pub part_number: String,
+    /// Version of the VBIOS.
+    pub version: String,
+    /// Version of the Unified BootLoader.
+    pub boot_firmware: String,
+}
+
+impl TryFrom<amdsmi_vbios_info_t> for AmdVbiosInfo {
+    type Error = AmdError;
+
+    fn try_from(value: amdsmi_vbios_info_t) -> Result<Self, AmdError> {
+        Ok(Self {
+            name: c_buffer_to_str(&value.name)?.to_owned(),
+            build_date: c_buffer_to_str(&value.build_date)?.to_owned(),
+            part_number: c_buffer_to_str(&value.part_number)?.to_owned(),
+            version: c_buffer_to_str(&value.version)?.to_owned(),
+            boot_firmware: c_buffer_to_str(&value.boot_firmware)?.to_owned(),
+        })
+    }
+}
+
+/// Header of the GPU metrics table: [`amd_metrics_table_header_t`].
+///
+/// The revisions identify the layout of the table, and must be checked before interpreting its fields.
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct AmdMetricsHeader {
+    /// Size of the metrics table, in bytes.
+    pub structure_size: u16,
+    /// Format revision (major version) of the table.
+    pub format_revision: u8,
+    /// Content revision (minor version) of the table.
+    pub content_revision: u8,
+}
+
+impl From<amd_metrics_table_header_t> for AmdMetricsHeader {
+    fn from(header: amd_metrics_table_header_t) -> Self {
+        Self {
+            structure_size: header.structure_size,
+            format_revision: header.format_revision,
+            content_revision: header.content_revision,
+        }
+    }
+}
+
+/// Information about the kernel driver of a GPU: [`amdsmi_driver_info_t`].
+#[derive(Debug, Default, Clone)]
+pub struct AmdDriverInfo {
+    /// Name of the driver, for instance `amdgpu`.
+    pub name: String,
+    /// Version of the driver.
+    pub version: String,
+    /// Release date of the driver.
+    pub date: String,
+}
+
+impl TryFrom<amdsmi_driver_info_t> for AmdDriverInfo {
+    type Error = AmdError;
+
+    fn try_from(info: amdsmi_driver_info_t) -> Result<Self, AmdError> {
+        Ok(Self {
+            name: c_buffer_to_str(&info.driver_name)?.to_owned(),
+            version: c_buffer_to_str(&info.driver_version)?.to_owned(),
+            date: c_buffer_to_str(&info.driver_date)?.to_owned(),
+        })
+    }
+}
+
+/// Version of a firmware block of a GPU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmdFirmwareInfo {
+    /// Firmware block.
+    pub block: AmdFwBlock,
+    /// Version of the firmware, in a format that depends on the block.
+    pub version: u64,
+}
+
+/// Converts a [`amdsmi_fw_info_t`] to the list of the firmwares that it contains.
+pub(crate) fn firmware_list(info: &amdsmi_fw_info_t) -> Vec<AmdFirmwareInfo> {
+    let count = (info.num_fw_info as usize).min(info.fw_info_list.len());
+    info.fw_info_list[..count]
+        .iter()
+        .map(|fw| AmdFirmwareInfo {
+            block: fw.fw_id,
+            version: fw.fw_version,
+        })
+        .collect()
+}
+
+/// Version of the AMD SMI library.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AmdVersion {
+    pub major: u32,
+    pub minor: u32,
+    /// Patch, build or stepping version.
+    pub release: u32,
+    /// Full build version.
+    pub build: String,
+}
+
+impl std::fmt::Display for AmdVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.release)
+    }
+}
+
+/// Parameters about the XGMI links of a GPU: [`amdsmi_xgmi_info_t`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AmdXgmiInfo {
+    /// Number of XGMI lanes.
+    pub xgmi_lanes: u8,
+    /// Identifier of the XGMI hive that contains the GPU.
+    pub xgmi_hive_id: u64,
+    /// Identifier of the GPU in the hive.
+    pub xgmi_node_id: u64,
+    /// Index of the GPU in the hive.
+    pub index: u32,
+}
+
+impl From<amdsmi_xgmi_info_t> for AmdXgmiInfo {
+    fn from(info: amdsmi_xgmi_info_t) -> Self {
+        Self {
+            xgmi_lanes: info.xgmi_lanes,
+            xgmi_hive_id: info.xgmi_hive_id,
+            xgmi_node_id: info.xgmi_node_id,
+            index: info.index,
+        }
+    }
+}
+
+/// Metrics of a link from a GPU to another one: [`amdsmi_link_metrics_t__links`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmdLinkMetrics {
+    /// PCI address of the GPU at the other end of the link.
+    pub peer: AmdPciAddress,
+    /// Type of the link.
+    pub link_type: AmdLinkType,
+    /// Current speed of the link, in Gb/s.
+    pub bit_rate: u32,
+    /// Maximal bandwidth of the link, in Gb/s.
+    pub max_bandwidth: u32,
+    /// Data received through the link since boot, in KB.
+    pub read_kb: u64,
+    /// Data sent through the link since boot, in KB.
+    pub write_kb: u64,
+}
+
+impl From<&amdsmi_link_metrics_t__links> for AmdLinkMetrics {
+    fn from(link: &amdsmi_link_metrics_t__links) -> Self {
+        Self {
+            peer: AmdPciAddress::from_bdf(link.bdf),
+            link_type: link.link_type,
+            bit_rate: link.bit_rate,
+            max_bandwidth: link.max_bandwidth,
+            read_kb: link.read,
+            write_kb: link.write,
+        }
+    }
+}
+
+/// Returns the severity mask that selects the given CPER severities, for [`ProcessorHandle::device_cper_entries`](crate::handles::ProcessorHandle::device_cper_entries).
+pub fn cper_severity_mask(severities: &[AmdCperSeverity]) -> u32 {
+    severities.iter().fold(0, |mask, sev| mask | (1 << sev.0))
+}
+
+/// Timestamp of a CPER entry: [`amdsmi_cper_timestamp_t`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AmdCperTimestamp {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hours: u8,
+    pub minutes: u8,
+    pub seconds: u8,
+}
+
+impl From<amdsmi_cper_timestamp_t> for AmdCperTimestamp {
+    fn from(ts: amdsmi_cper_timestamp_t) -> Self {
+        Self {
+            year: ts.century as u16 * 100 + ts.year as u16,
+            month: ts.month,
+            day: ts.day,
+            hours: ts.hours,
+            minutes: ts.minutes,
+            seconds: ts.seconds,
+        }
+    }
+}
+
+/// Common Platform Error Record (CPER) entry, which describes a RAS event of a GPU.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmdCperEntry {
+    /// Severity of the error.
+    pub severity: AmdCperSeverity,
+    /// Revision of the record format.
+    pub revision: u16,
+    /// Number of sections in the record.
+    pub section_count: u16,
+    /// Time at which the error occurred.
+    pub timestamp: AmdCperTimestamp,
+    /// Identifier of the platform.
+    pub platform_id: String,
+    /// Identifier of the creator of the record.
+    pub creator_id: String,
+    /// Unique identifier of the record.
+    pub record_id: String,
+    /// Type of the notification (CMC, MCE...), as a GUID.
+    pub notify_type: [u8; 16],
+    /// The whole record (header and sections), as written by the driver.
+    pub data: Vec<u8>,
+}
+
+impl AmdCperEntry {
+    /// Creates an entry from its parsed header and its raw bytes.
+    ///
+    /// Returns [`AmdError::InvalidString`] if one of the identifiers is not valid UTF-8.
+    pub(crate) fn new(header: &amdsmi_cper_hdr_t, data: Vec<u8>) -> Result<Self, AmdError> {
+        // copy the packed fields before using them
+        let revision = header.revision;
+        let section_count = header.sec_cnt;
+        Ok(Self {
+            severity: header.error_severity,
+            revision,
+            section_count,
+            timestamp: AmdCperTimestamp::from(header.timestamp),
+            platform_id: c_buffer_to_str(&header.platform_id)?.to_owned(),
+            creator_id: c_buffer_to_str(&header.creator_id)?.to_owned(),
+            record_id: c_buffer_to_str(&header.record_id)?.to_owned(),
+            notify_type: header.notify_type.b,
+            data,
+        })
+    }
+}
+
+/// CPER entries retrieved from a GPU, and the cursor to use to retrieve the next ones.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AmdCperEntries {
+    pub entries: Vec<AmdCperEntry>,
+    /// Cursor to pass to the next call of [`ProcessorHandle::device_cper_entries`](crate::handles::ProcessorHandle::device_cper_entries).
+    pub cursor: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{
+        AmdBusyTime, AmdComputePartition, AmdDevicePartition, AmdDpmPolicy, AmdEccCount,
+        AmdEccSnapshot, AmdEnergyConsumption, AmdEnergyReading, AmdMemoryPartition,
+        AmdMemoryPressure, AmdMemoryUsage, AmdPciAddress, AmdPcieInfo, AmdProcess,
+        AmdProcessMemoryUsage, AmdRasFeatures, AmdTemperatureType, AmdThermalMargin, ProcessFilter,
+        ThermalLevel, parse_compute_partition, parse_memory_partition, valid_serial,
+    };
+    use crate::{
+        bindings::{amdsmi_dpm_policy_t, amdsmi_pcie_info_t},
+        error::AmdError,
+        timebase::DriverTimestamp,
+    };
+
+    #[test]
+    fn pci_address() {
+        let address =
+            AmdPciAddress::from_bdf_id((1 << 32) | (2 << 28) | (0xc3 << 8) | (3 << 3) | 1);
+        assert_eq!(address.domain, 1);
+        assert_eq!(address.partition, 2);
+        assert_eq!(address.bus, 0xc3);
+        assert_eq!(address.device, 3);
+        assert_eq!(address.function, 1);
+        assert_eq!(address.to_string(), "0001:c3:03.1");
+
+        let partition = AmdDevicePartition {
+            physical: address.physical(),
+            partition_id: 2,
+        };
+        assert_eq!(partition.to_string(), "0001:c3:03.1 partition 2");
+
+        let bdf = crate::bindings::amdsmi_bdf_t {
+            as_uint: (1 << 16) | (0xc3 << 8) | (3 << 3) | 1,
+        };
+        assert_eq!(AmdPciAddress::from_bdf(bdf), address.physical());
+    }
+
+    #[test]
+    fn energy_reading() {
+        let reading = |energy, timestamp| {
+            AmdEnergyReading::from(AmdEnergyConsumption {
+                energy,
+                resolution: 15.25,
+                timestamp,
+            })
+        };
+        let previous = reading(1_000_000, 1_000_000_000);
+        let current = reading(3_000_000, 3_000_000_000);
+        assert_eq!(previous.joules, 15.25);
+        assert_eq!(current.joules_since(&previous), Some(30.5));
+        assert_eq!(previous.joules_since(&current), None);
+        assert_eq!(current.watts_since(&previous), Some(15.25));
+        assert_eq!(reading(3_000_000, 0).watts_since(&previous), None);
+    }
+
+    #[test]
+    fn busy_time() {
+        let reading = |counter, millis: u64| AmdBusyTime {
+            counter,
+            timestamp: DriverTimestamp::from_nanos(millis * 1_000_000),
+        };
+        let previous = reading(10_000, 1000);
+        let current = reading(40_000, 2000);
+        assert_eq!(current.busy(), Duration::from_millis(400));
+        assert_eq!(current.duty_cycle_since(&previous), Some(0.3));
+        assert_eq!(previous.duty_cycle_since(&current), None);
+        assert_eq!(current.duty_cycle_since(&current), None);
+    }
+
+    #[test]
+    fn serial_validation() {
+        assert_eq!(valid_serial("0x1a2b3c4d"), Some("0x1a2b3c4d"));
+        assert_eq!(valid_serial(" 692312000123\n"), Some("692312000123"));
+        assert_eq!(valid_serial(""), None);
+        assert_eq!(valid_serial("  "), None);
+        assert_eq!(valid_serial(" N/A "), None);
+        assert_eq!(valid_serial("0x0000000000000000"), None);
+        assert_eq!(valid_serial("0"), None);
+    }
+
+    #[test]
+    fn ecc_rate() {
+        let start = Instant::now();
+        let snapshot = |minutes: u64, correctable| AmdEccSnapshot {
+            count: AmdEccCount {
+                correctable,
+                uncorrectable: 1,
+                deferred: 0,
+            },
+            time: start + Duration::from_secs(minutes * 60),
+        };
+        let previous = snapshot(0, 10);
+        let current = snapshot(30, 16);
+        let rate = current.rate_since(&previous).unwrap();
+        assert_eq!(rate.correctable, 12.0);
+        assert_eq!(rate.uncorrectable, 0.0);
+        assert_eq!(previous.rate_since(&current), None);
+        assert_eq!(previous.rate_since(&previous), None);
+        // reset of the counters
+        assert_eq!(snapshot(60, 2).rate_since(&current), None);
+    }
+
+    #[test]
+    fn ras_features() {
+        let features = AmdRasFeatures::from(crate::bindings::amdsmi_ras_feature_t {
+            ras_eeprom_version: 3,
+            ecc_correction_schema_flag: 0b1010,
+        });
+        assert_eq!(features.eeprom_version, 3);
+        assert!(!features.parity());
+        assert!(features.single_bit_correction());
+        assert!(!features.double_bit_detection());
+        assert!(features.poison());
+    }
+
+    #[test]
+    fn thermal_margin() {
+        let margin = |current| AmdThermalMargin {
+            sensor: AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_HOTSPOT,
+            current,
+            critical: Some(100),
+            critical_hyst: Some(95),
+            emergency: None,
+            emergency_hyst: None,
+            shutdown: Some(110),
+        };
+        assert_eq!(margin(80).margin(), Some(20));
+        assert_eq!(margin(105).critical_margin(), Some(-5));
+        assert_eq!(margin(80).emergency_margin(), None);
+
+        assert_eq!(margin(99).level(ThermalLevel::Normal), ThermalLevel::Normal);
+        assert_eq!(
+            margin(100).level(ThermalLevel::Normal),
+            ThermalLevel::Critical
+        );
+        // kept until the temperature falls below the hysteresis
+        assert_eq!(
+            margin(96).level(ThermalLevel::Critical),
+            ThermalLevel::Critical
+        );
+        assert_eq!(
+            margin(94).level(ThermalLevel::Critical),
+            ThermalLevel::Normal
+        );
+        assert_eq!(
+            margin(112).level(ThermalLevel::Critical),
+            ThermalLevel::Shutdown
+        );
+        assert_eq!(
+            margin(105).level(ThermalLevel::Shutdown),
+            ThermalLevel::Critical
+        );
+    }
+
+    #[test]
+    fn pcie_downgrade() {
+        let pcie = |max_speed, speed, width| {
+            // SAFETY: The structure only contains integers, for which zero is valid.
+            let mut raw: amdsmi_pcie_info_t = unsafe { std::mem::zeroed() };
+            raw.pcie_static.max_pcie_width = 16;
+            raw.pcie_static.max_pcie_speed = max_speed;
+            raw.pcie_metric.pcie_width = width;
+            raw.pcie_metric.pcie_speed = speed;
+            AmdPcieInfo::from(raw)
+        };
+        // maximal speed in GT/s, or in MT/s like the current speed
+        for max_speed in [32, 32000] {
+            assert_eq!(pcie(max_speed, 32000, 16).max_speed_gts(), Some(32.0));
+            assert_eq!(pcie(max_speed, 32000, 16).is_downgraded(), Some(false));
+            assert_eq!(pcie(max_speed, 2500, 16).is_downgraded(), Some(true));
+            assert_eq!(pcie(max_speed, 32000, 8).is_downgraded(), Some(true));
+        }
+        assert_eq!(pcie(32, u32::MAX, 16).is_downgraded(), None);
+    }
+
+    #[test]
+    fn dpm_policy() {
+        // SAFETY: The structure only contains integers and arrays of integers.
+        let mut raw: amdsmi_dpm_policy_t = unsafe { std::mem::zeroed() };
+        for (i, entry) in raw.policies.iter_mut().enumerate() {
+            entry.policy_id = i as u32;
+            entry.policy_description[0] = b'a' as _;
+        }
+        raw.current = 1;
+
+        // more policies than the array can hold
+        raw.num_supported = 100;
+        let policy = AmdDpmPolicy::try_from(&raw).unwrap();
+        assert_eq!(policy.policies.len(), raw.policies.len());
+        assert_eq!(policy.current_policy().unwrap().id, 1);
+        assert_eq!(policy.current_policy().unwrap().description, "a");
+
+        raw.num_supported = 2;
+        raw.current = 2;
+        let policy = AmdDpmPolicy::try_from(&raw).unwrap();
+        assert_eq!(policy.policies.len(), 2);
+        assert_eq!(policy.current_policy(), None);
+
+        raw.policies[1].policy_description[0] = 0xFFu8 as _;
+        assert!(matches!(
+            AmdDpmPolicy::try_from(&raw),
+            Err(AmdError::InvalidString(_))
+        ));
+    }
+
+    #[test]
+    fn memory_pressure() {
+        let process = |pid, gtt_mem, evicted_time| AmdProcess {
+            pid,
+            memory_usage: AmdProcessMemoryUsage {
+                gtt_mem,
+                ..Default::default()
+            },
+            evicted_time,
+            ..Default::default()
+        };
+        let gtt = AmdMemoryUsage {
+            used: 3 << 30,
+            total: 4 << 30,
+        };
+        let pressure = AmdMemoryPressure::new(
+            None,
+            Some(gtt),
+            &[
+                process(10, 1 << 30, 0),
+                process(11, 2 << 30, 250),
+                process(12, 0, 50),
+            ],
+        );
+        assert_eq!(pressure.process_gtt, 3 << 30);
+        assert_eq!(pressure.evictions, vec![(11, 250), (12, 50)]);
+        assert_eq!(pressure.total_evicted_time(), 300);
+        assert_eq!(pressure.gtt.unwrap().ratio(), Some(0.75));
+    }
+
+    #[test]
+    fn process_filter() {
+        let process = |pid, vram_mem, container: &str| AmdProcess {
+            pid,
+            memory_usage: AmdProcessMemoryUsage {
+                vram_mem,
+                ..Default::default()
+            },
+            container_name: container.to_owned(),
+            ..Default::default()
+        };
+        let processes = [
+            process(1, 100, ""),
+            process(2, 2000, "job-a"),
+            process(3, 3000, ""),
+        ];
+        let pids = |filter: ProcessFilter| -> Vec<u32> {
+            processes
+                .iter()
+                .filter(|p| filter.matches(p))
+                .map(|p| p.pid)
+                .collect()
+        };
+
+        assert_eq!(pids(ProcessFilter::new()), vec![1, 2, 3]);
+        assert_eq!(pids(ProcessFilter::new().pids([1, 3])), vec![1, 3]);
+        assert_eq!(pids(ProcessFilter::new().min_vram(1000)), vec![2, 3]);
+        assert_eq!(pids(ProcessFilter::new().containerized_only()), vec![2]);
+        assert_eq!(
+            pids(ProcessFilter::new().pids([1, 2]).min_vram(1000)),
+            vec![2]
+        );
+        assert_eq!(processes[1].container(), Some("job-a"));
+        assert_eq!(processes[0].container(), None);
+    }
+
+    #[test]
+    fn partitions() {
+        assert_eq!(
+            parse_compute_partition("CPX"),
+            AmdComputePartition::AMDSMI_COMPUTE_PARTITION_CPX
+        );
+        assert_eq!(
+            parse_compute_partition("XPX"),
+            AmdComputePartition::AMDSMI_COMPUTE_PARTITION_INVALID
+        );
+        assert_eq!(
+            parse_memory_partition("nps4\n"),
+            AmdMemoryPartition::AMDSMI_MEMORY_PARTITION_NPS4
+        );
+    }
+}
@@ -0,0 +1,290 @@
+//! Stable names of the metrics, shared by the exporters.
+//!
+//! Each [`MetricKind`] has a Prometheus name (for instance `amd_gpu_power_watts`) and an OpenTelemetry
+//! name (for instance `hw.power`), in a base unit: the values read by the library must be multiplied by
+//! [`MetricName::scale`], or by [`MetricName::scale_for`] the [`UnitSystem`] of the library. The metrics of the same family (temperature of each sensor, activity of each
+//! engine...) share a name and are distinguished by labels, see [`metric_labels`].
+//!
+//! These names are part of the stable API: custom exporters should use them, so that all the consumers
+//! of this crate emit the same series.
+//!
+//! | Metric                       | Prometheus                                  | OpenTelemetry           | Labels      |
+//! |------------------------------|---------------------------------------------|-------------------------|-------------|
+//! | `Power`                      | `amd_gpu_power_watts`                       | `hw.power`              |             |
+//! | `Energy`                     | `amd_gpu_energy_joules_total`               | `hw.energy`             |             |
+//! | `Temperature`                | `amd_gpu_temperature_celsius`               | `hw.temperature`        | `sensor`    |
+//! | `Voltage`                    | `amd_gpu_voltage_volts`                     | `hw.voltage`            | `sensor`    |
+//! | `Clock`                      | `amd_gpu_clock_hertz`                       | `hw.gpu.clock`          | `clock`     |
+//! | `MemoryUsed`                 | `amd_gpu_memory_used_bytes`                 | `hw.gpu.memory.usage`   | `memory`    |
+//! | `GfxActivity`, `MmActivity`, `UmcActivity` | `amd_gpu_utilization_ratio`   | `hw.gpu.utilization`    | `task`      |
+//! | `FanSpeed`                   | `amd_gpu_fan_speed_ratio`                   | `hw.fan.speed_ratio`    | `fan`       |
+//! | `PciSent`, `PciReceived`     | `amd_gpu_pci_throughput_bytes_per_second`   | `hw.gpu.io.throughput`  | `direction` |
+
+use crate::{
+    dynamic::{MetricKind, MetricSemantics},
+    metrics::{AmdClkType, AmdMemoryType, AmdTemperatureType, AmdVoltageType},
+    units::UnitSystem,
+};
+
+/// OpenTelemetry name of the power.
+pub const OTEL_POWER: &str = "hw.power";
+/// OpenTelemetry name of the energy.
+pub const OTEL_ENERGY: &str = "hw.energy";
+/// OpenTelemetry name of the utilization of the engines.
+pub const OTEL_UTILIZATION: &str = "hw.gpu.utilization";
+/// OpenTelemetry name of the used memory.
+pub const OTEL_MEMORY_USAGE: &str = "hw.gpu.memory.usage";
+/// OpenTelemetry name of the throughput of the PCI bus.
+pub const OTEL_PCI_THROUGHPUT: &str = "hw.gpu.io.throughput";
+
+/// Names of a [`MetricKind`] in the exporters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricName {
+    /// Name of the Prometheus metric, suffixed by its unit (and `_total` for the counters).
+    pub prometheus: &'static str,
+    /// Name of the OpenTelemetry instrument.
+    pub otel: &'static str,
+    /// Unit of the OpenTelemetry instrument, in the UCUM notation.
+    pub unit: &'static str,
+    /// Description of the metric.
+    pub description: &'static str,
+    /// Factor that converts a value in the native unit of the library to the unit of the name,
+    /// for instance `1e-6` for µJ to J.
+    ///
+    /// The values read with [`UnitSystem::Si`] are already in the unit of the name,
+    /// use [`scale_for`](MetricName::scale_for) to get the factor of a unit system.
+    pub scale: f64,
+    /// How the values evolve over time.
+    pub semantics: MetricSemantics,
+}
+
+impl MetricName {
+    /// Returns the factor that converts a value in the given unit system to the unit of the name.
+    ///
+    /// The SI units of [`UnitSystem::Si`] are those of the names, except for the ratios,
+    /// which are converted from % to a fraction.
+    pub fn scale_for(&self, units: UnitSystem) -> f64 {
+        match units {
+            UnitSystem::Native => self.scale,
+            UnitSystem::Si if self.unit == "1" => self.scale,
+            UnitSystem::Si => 1.0,
+        }
+    }
+}
+
+/// Returns the names of a metric.
+pub fn metric_name(kind: MetricKind) -> MetricName {
+    let (prometheus, otel, unit, description, scale) = match kind {
+        MetricKind::Power => (
+            "amd_gpu_power_watts",
+            OTEL_POWER,
+            "W",
+            "Instantaneous power consumed by the GPU",
+            1.0,
+        ),
+        MetricKind::Energy => (
+            "amd_gpu_energy_joules_total",
+            OTEL_ENERGY,
+            "J",
+            "Energy consumed by the GPU",
+            1e-6,
+        ),
+        MetricKind::Temperature(_) => (
+            "amd_gpu_temperature_celsius",
+            "hw.temperature",
+            "Cel",
+            "Temperature of a sensor of the GPU",
+            1.0,
+        ),
+        MetricKind::Voltage(_) => (
+            "amd_gpu_voltage_volts",
+            "hw.voltage",
+            "V",
+            "Voltage of a rail of the GPU",
+            1e-3,
+        ),
+        MetricKind::Clock(_) => (
+            "amd_gpu_clock_hertz",
+            "hw.gpu.clock",
+            "Hz",
+            "Frequency of a clock domain of the GPU",
+            1e6,
+        ),
+        MetricKind::MemoryUsed(_) => (
+            "amd_gpu_memory_used_bytes",
+            OTEL_MEMORY_USAGE,
+            "By",
+            "GPU memory used",
+            1.0,
+        ),
+        MetricKind::GfxActivity | MetricKind::MmActivity | MetricKind::UmcActivity => (
+            "amd_gpu_utilization_ratio",
+            OTEL_UTILIZATION,
+            "1",
+            "Fraction of time spent in a specific task",
+            0.01,
+        ),
+        MetricKind::FanSpeed(_) => (
+            "amd_gpu_fan_speed_ratio",
+            "hw.fan.speed_ratio",
+            "1",
+            "Speed of a fan, relative to its maximal speed",
+            0.01,
+        ),
+        MetricKind::PciSent | MetricKind::PciReceived => (
+            "amd_gpu_pci_throughput_bytes_per_second",
+            OTEL_PCI_THROUGHPUT,
+            "By/s",
+            "Bytes transferred on the PCI bus during the last second",
+            1.0,
+        ),
+    };
+    MetricName {
+        prometheus,
+        otel,
+        unit,
+        description,
+        scale,
+        semantics: kind.metadata().semantics,
+    }
+}
+
+/// Label of a metric, with its Prometheus and OpenTelemetry keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetricLabel {
+    /// Key of the Prometheus label.
+    pub prometheus: &'static str,
+    /// Key of the OpenTelemetry attribute.
+    pub otel: &'static str,
+    /// Value of the label.
+    pub value: String,
+}
+
+impl MetricLabel {
+    fn new(prometheus: &'static str, otel: &'static str, value: impl Into<String>) -> Self {
+        Self {
+            prometheus,
+            otel,
+            value: value.into(),
+        }
+    }
+}
+
+/// Returns the labels that distinguish a metric from the others of the same name.
+///
+/// The labels of the device (UUID, PCI address...) are not included.
+pub fn metric_labels(kind: MetricKind) -> Vec<MetricLabel> {
+    let label = match kind {
+        MetricKind::Power | MetricKind::Energy => return Vec::new(),
+        MetricKind::Temperature(sensor) => {
+            MetricLabel::new("sensor", "sensor", temperature_sensor_name(sensor))
+        }
+        MetricKind::Voltage(sensor) => {
+            MetricLabel::new("sensor", "sensor", voltage_sensor_name(sensor))
+        }
+        MetricKind::Clock(clock) => {
+            MetricLabel::new("clock", "hw.gpu.clock.type", clock_name(clock))
+        }
+        MetricKind::MemoryUsed(memory) => {
+            MetricLabel::new("memory", "hw.gpu.memory.type", memory_name(memory))
+        }
+        MetricKind::GfxActivity => MetricLabel::new("task", "hw.gpu.task", "general"),
+        MetricKind::MmActivity => MetricLabel::new("task", "hw.gpu.task", "memory_management"),
+        MetricKind::UmcActivity => MetricLabel::new("task", "hw.gpu.task", "memory_controller"),
+        MetricKind::FanSpeed(index) => MetricLabel::new("fan", "fan", index.to_string()),
+        MetricKind::PciSent => MetricLabel::new("direction", "network.io.direction", "transmit"),
+        MetricKind::PciReceived => MetricLabel::new("direction", "network.io.direction", "receive"),
+    };
+    vec![label]
+}
+
+/// Returns the name of a temperature sensor, in snake case, for instance `hotspot`.
+///
+/// The sensors of the boards have no stable name, they are named after their index: `sensor_<n>`.
+pub fn temperature_sensor_name(sensor: AmdTemperatureType) -> String {
+    let name = match sensor {
+        AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_EDGE => "edge",
+        AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_HOTSPOT => "hotspot",
+        AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_VRAM => "vram",
+        AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_HBM_0 => "hbm_0",
+        AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_HBM_1 => "hbm_1",
+        AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_HBM_2 => "hbm_2",
+        AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_HBM_3 => "hbm_3",
+        AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_PLX => "plx",
+        other => return format!("sensor_{}", other.0),
+    };
+    name.to_owned()
+}
+
+/// Returns the name of a voltage sensor, in snake case, for instance `vddgfx`.
+pub fn voltage_sensor_name(sensor: AmdVoltageType) -> String {
+    match sensor {
+        AmdVoltageType::AMDSMI_VOLT_TYPE_VDDGFX => "vddgfx".to_owned(),
+        AmdVoltageType::AMDSMI_VOLT_TYPE_VDDBOARD => "vddboard".to_owned(),
+        other => format!("sensor_{}", other.0),
+    }
+}
+
+/// Returns the name of a clock domain, in snake case, for instance `gfx`.
+pub fn clock_name(clock: AmdClkType) -> String {
+    let name = match clock {
+        AmdClkType::AMDSMI_CLK_TYPE_GFX => "gfx",
+        AmdClkType::AMDSMI_CLK_TYPE_DF => "df",
+        AmdClkType::AMDSMI_CLK_TYPE_DCEF => "dcef",
+        AmdClkType::AMDSMI_CLK_TYPE_SOC => "soc",
+        AmdClkType::AMDSMI_CLK_TYPE_MEM => "mem",
+        AmdClkType::AMDSMI_CLK_TYPE_PCIE => "pcie",
+        AmdClkType::AMDSMI_CLK_TYPE_VCLK0 => "vclk0",
+        AmdClkType::AMDSMI_CLK_TYPE_VCLK1 => "vclk1",
+        AmdClkType::AMDSMI_CLK_TYPE_DCLK0 => "dclk0",
+        AmdClkType::AMDSMI_CLK_TYPE_DCLK1 => "dclk1",
+        other => return format!("clock_{}", other.0),
+    };
+    name.to_owned()
+}
+
+/// Returns the name of a memory pool, in snake case, for instance `vram`.
+pub fn memory_name(memory: AmdMemoryType) -> String {
+    let name = match memory {
+        AmdMemoryType::AMDSMI_MEM_TYPE_VRAM => "vram",
+        AmdMemoryType::AMDSMI_MEM_TYPE_VIS_VRAM => "vis_vram",
+        AmdMemoryType::AMDSMI_MEM_TYPE_GTT => "gtt",
+        other => return format!("memory_{}", other.0),
+    };
+    name.to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{metric_labels, metric_name};
+    use crate::{dynamic::MetricKind, metrics::AmdTemperatureType, units::UnitSystem};
+
+    #[test]
+    fn names() {
+        let energy = metric_name(MetricKind::Energy);
+        assert_eq!(energy.prometheus, "amd_gpu_energy_joules_total");
+        assert_eq!(energy.otel, "hw.energy");
+        assert_eq!(2_500_000.0 * energy.scale, 2.5);
+        assert_eq!(energy.scale_for(UnitSystem::Native), 1e-6);
+        assert_eq!(energy.scale_for(UnitSystem::Si), 1.0);
+        let activity = metric_name(MetricKind::GfxActivity);
+        assert_eq!(activity.scale_for(UnitSystem::Si), 0.01);
+
+        let hotspot = MetricKind::Temperature(AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_HOTSPOT);
+        assert_eq!(
+            metric_name(hotspot).prometheus,
+            "amd_gpu_temperature_celsius"
+        );
+        let labels = metric_labels(hotspot);
+        assert_eq!(labels.len(), 1);
+        assert_eq!(
+            (labels[0].prometheus, labels[0].value.as_str()),
+            ("sensor", "hotspot")
+        );
+        assert!(metric_labels(MetricKind::Power).is_empty());
+        assert_eq!(
+            metric_name(MetricKind::GfxActivity),
+            metric_name(MetricKind::UmcActivity)
+        );
+    }
+}
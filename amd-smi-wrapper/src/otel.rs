@@ -0,0 +1,158 @@
+//! Export of the metrics as OpenTelemetry instruments (requires the `otel` feature).
+//!
+//! The instruments follow the semantic conventions for hardware metrics (`hw.*`), with the names of [`crate::naming`].
+//! Each measurement carries the attributes of its device (see [`device_attributes`]), and the
+//! attributes of the host (see [`host_attributes`]) are meant to be added to the resource of the meter provider.
+
+use opentelemetry::{
+    KeyValue,
+    metrics::{Counter, Gauge, Meter},
+};
+
+use crate::{
+    dynamic::MetricKind,
+    error::AmdError,
+    handles::ProcessorHandle,
+    metrics::AmdMemoryType,
+    naming::{metric_labels, metric_name},
+    snapshot::{DeviceSnapshot, SnapshotDiff},
+};
+
+/// Returns the attributes that identify a device: `hw.id` (UUID), `hw.type`, `hw.vendor`, `hw.model`,
+/// `hw.serial_number` (board serial, if known) and `hw.gpu.bdf` (PCI address).
+pub fn device_attributes<P: ProcessorHandle + ?Sized>(
+    device: &P,
+) -> Result<Vec<KeyValue>, AmdError> {
+    let identity = device.device_identity()?;
+    let mut attributes = vec![
+        KeyValue::new("hw.id", identity.uuid),
+        KeyValue::new("hw.type", "gpu"),
+        KeyValue::new("hw.vendor", "AMD"),
+        KeyValue::new("hw.model", identity.market_name),
+        KeyValue::new("hw.gpu.bdf", identity.pci_address.to_string()),
+    ];
+    if let Some(serial) = identity.board_serial {
+        attributes.push(KeyValue::new("hw.serial_number", serial));
+    }
+    Ok(attributes)
+}
+
+/// Returns the resource attributes of the host: `host.name`.
+pub fn host_attributes() -> Vec<KeyValue> {
+    let hostname = std::fs::read_to_string("/proc/sys/kernel/hostname")
+        .ok()
+        .or_else(|| std::env::var("HOSTNAME").ok());
+    match hostname {
+        Some(name) => vec![KeyValue::new("host.name", name.trim().to_owned())],
+        None => Vec::new(),
+    }
+}
+
+/// Publishes [`DeviceSnapshot`]s as OpenTelemetry instruments.
+///
+/// The names, units and descriptions of the instruments are those of [`metric_name`].
+pub struct OtelExporter {
+    power: Gauge<f64>,
+    energy: Counter<f64>,
+    utilization: Gauge<f64>,
+    memory_usage: Gauge<u64>,
+    pci_throughput: Gauge<f64>,
+}
+
+impl OtelExporter {
+    /// Creates the instruments with the given meter.
+    pub fn new(meter: &Meter) -> Self {
+        let power = metric_name(MetricKind::Power);
+        let energy = metric_name(MetricKind::Energy);
+        let utilization = metric_name(MetricKind::GfxActivity);
+        let memory_usage = metric_name(MetricKind::MemoryUsed(AmdMemoryType::AMDSMI_MEM_TYPE_VRAM));
+        let pci_throughput = metric_name(MetricKind::PciSent);
+        Self {
+            power: meter
+                .f64_gauge(power.otel)
+                .with_unit(power.unit)
+                .with_description(power.description)
+                .build(),
+            energy: meter
+                .f64_counter(energy.otel)
+                .with_unit(energy.unit)
+                .with_description(energy.description)
+                .build(),
+            utilization: meter
+                .f64_gauge(utilization.otel)
+                .with_unit(utilization.unit)
+                .with_description(utilization.description)
+                .build(),
+            memory_usage: meter
+                .u64_gauge(memory_usage.otel)
+                .with_unit(memory_usage.unit)
+                .with_description(memory_usage.description)
+                .build(),
+            pci_throughput: meter
+                .f64_gauge(pci_throughput.otel)
+                .with_unit(pci_throughput.unit)
+                .with_description(pci_throughput.description)
+                .build(),
+        }
+    }
+
+    /// Records the metrics of a snapshot, with the attributes of its device.
+    ///
+    /// The energy counter is incremented by `diff`, the difference with the previous snapshot of the device.
+    /// Pass `None` for the first snapshot.
+    pub fn record(
+        &self,
+        attributes: &[KeyValue],
+        snapshot: &DeviceSnapshot,
+        diff: Option<&SnapshotDiff>,
+    ) {
+        if let Some(power) = snapshot.power {
+            let scale = metric_name(MetricKind::Power).scale;
+            self.power.record(power as f64 * scale, attributes);
+        }
+        if let Some(activity) = snapshot.activity {
+            for (kind, value) in [
+                (MetricKind::GfxActivity, activity.gfx_activity),
+                (MetricKind::MmActivity, activity.mm_activity),
+                (MetricKind::UmcActivity, activity.umc_activity),
+            ] {
+                self.utilization.record(
+                    value as f64 * metric_name(kind).scale,
+                    &with_labels(attributes, kind),
+                );
+            }
+        }
+        if let Some(used) = snapshot.vram_used {
+            let kind = MetricKind::MemoryUsed(AmdMemoryType::AMDSMI_MEM_TYPE_VRAM);
+            self.memory_usage
+                .record(used, &with_labels(attributes, kind));
+        }
+        if let Some(pci) = snapshot.pci {
+            for (kind, value) in [
+                (MetricKind::PciSent, pci.sent),
+                (MetricKind::PciReceived, pci.received),
+            ] {
+                self.pci_throughput.record(
+                    value as f64 * metric_name(kind).scale,
+                    &with_labels(attributes, kind),
+                );
+            }
+        }
+
+        if let Some(energy) = diff.and_then(|diff| diff.energy) {
+            let scale = metric_name(MetricKind::Energy).scale;
+            self.energy.add(energy as f64 * scale, attributes);
+        }
+    }
+}
+
+/// Returns the attributes of the device with the labels of the metric, see [`metric_labels`].
+fn with_labels(attributes: &[KeyValue], kind: MetricKind) -> Vec<KeyValue> {
+    let mut res = attributes.to_vec();
+    res.extend(
+        metric_labels(kind)
+            .into_iter()
+            .map(|label| KeyValue::new(label.otel, label.value)),
+    );
+    res
+}
@@ -0,0 +1,86 @@
+//! Runtime power state of the GPUs.
+//!
+//! An idle GPU can be suspended by the kernel (runtime power management). Querying its metrics
+//! wakes it up, which distorts the measurements of the idle power. The runtime state is read from sysfs,
+//! without touching the device.
+
+use std::{fmt, fs, path::Path};
+
+/// Runtime power state of a device, as reported by the kernel in `power/runtime_status`.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum RuntimePowerState {
+    /// The device is powered on.
+    Active,
+    /// The device is suspended.
+    Suspended,
+    /// The device is being suspended.
+    Suspending,
+    /// The device is being resumed.
+    Resuming,
+    /// The last suspend or resume failed, the state is not managed anymore.
+    Error,
+    /// The runtime power management is disabled for this device.
+    Unsupported,
+}
+
+impl RuntimePowerState {
+    /// Parses the content of the `runtime_status` file.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "active" => Some(Self::Active),
+            "suspended" => Some(Self::Suspended),
+            "suspending" => Some(Self::Suspending),
+            "resuming" => Some(Self::Resuming),
+            "error" => Some(Self::Error),
+            "unsupported" => Some(Self::Unsupported),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the device is suspended or being suspended: querying it would wake it up.
+    pub fn is_suspended(&self) -> bool {
+        matches!(self, Self::Suspended | Self::Suspending)
+    }
+}
+
+impl fmt::Display for RuntimePowerState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Active => "active",
+            Self::Suspended => "suspended",
+            Self::Suspending => "suspending",
+            Self::Resuming => "resuming",
+            Self::Error => "error",
+            Self::Unsupported => "unsupported",
+        })
+    }
+}
+
+/// Reads the runtime power state of the device whose sysfs directory is `sysfs_path`.
+///
+/// Returns `None` if the state cannot be read (no sysfs, or unknown value).
+pub(crate) fn read_runtime_power_state(sysfs_path: &Path) -> Option<RuntimePowerState> {
+    let value = fs::read_to_string(sysfs_path.join("power/runtime_status")).ok()?;
+    RuntimePowerState::parse(&value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RuntimePowerState;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            RuntimePowerState::parse("suspended\n"),
+            Some(RuntimePowerState::Suspended)
+        );
+        assert_eq!(
+            RuntimePowerState::parse("active"),
+            Some(RuntimePowerState::Active)
+        );
+        assert_eq!(RuntimePowerState::parse("on"), None);
+        assert!(RuntimePowerState::Suspending.is_suspended());
+        assert!(!RuntimePowerState::Resuming.is_suspended());
+        assert!(!RuntimePowerState::Unsupported.is_suspended());
+    }
+}
@@ -0,0 +1,176 @@
+//! Pre-flight check of the privileges of the process.
+//!
+//! Some functions of the library require root access (or `CAP_SYS_ADMIN`), and fail with `NO_PERM` otherwise:
+//! the control of the devices, the performance counters, the error records... [`AmdSmi::check_privileges`]
+//! inspects the credentials of the process once, so that a monitoring agent can log a clear warning at startup
+//! instead of failing later.
+//!
+//! # Example
+//! ```no_run
+//! use amd_smi_wrapper::{AmdSmi, AmdInitFlags};
+//!
+//! let amdsmi = AmdSmi::init(AmdInitFlags::AMDSMI_INIT_AMD_GPUS).unwrap();
+//! if let Some(warning) = amdsmi.check_privileges().warning() {
+//!     eprintln!("warning: {warning}");
+//! }
+//! ```
+
+use std::fs;
+
+use crate::AmdSmi;
+
+/// Capability that grants the privileged functions of the library.
+const CAP_SYS_ADMIN: u32 = 21;
+
+/// Functions of this crate that require root access, with what fails without it.
+pub const PRIVILEGED_FUNCTIONS: &[(&str, &str)] = &[
+    (
+        "device_create_counter",
+        "performance counters, XGMI throughput",
+    ),
+    ("device_cper_entries", "error records (CPER)"),
+    (
+        "device_process_list",
+        "processes of the other users (only the own processes are listed)",
+    ),
+];
+
+/// Privileges of the process, see [`AmdSmi::check_privileges`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrivilegeReport {
+    /// Effective user id of the process.
+    pub euid: u32,
+    /// Effective capabilities of the process, or `None` if they cannot be read.
+    pub capabilities: Option<u64>,
+    /// Whether the crate has been built with the functions that change the settings of the devices
+    /// (feature `control`).
+    pub control_enabled: bool,
+    /// Reason why the control is refused by this instance even with the privileges, if any,
+    /// for instance because another copy of the library is loaded.
+    pub control_blocked: Option<String>,
+}
+
+impl PrivilegeReport {
+    /// Returns `true` if the process has `CAP_SYS_ADMIN` in its effective capabilities.
+    ///
+    /// The euid is only used when the capabilities cannot be read: root can be deprived of the capabilities
+    /// (in a container for instance), and another user can be granted them.
+    pub fn is_privileged(&self) -> bool {
+        match self.capabilities {
+            Some(capabilities) => capabilities & (1 << CAP_SYS_ADMIN) != 0,
+            None => self.euid == 0,
+        }
+    }
+
+    /// Returns `true` if the control functions can be used: they are built, the process is privileged,
+    /// and the instance does not refuse them.
+    pub fn can_control(&self) -> bool {
+        self.control_enabled && self.is_privileged() && self.control_blocked.is_none()
+    }
+
+    /// Returns the functions that will fail with `NO_PERM`, see [`PRIVILEGED_FUNCTIONS`].
+    pub fn restricted_functions(&self) -> &'static [(&'static str, &'static str)] {
+        if self.is_privileged() {
+            &[]
+        } else {
+            PRIVILEGED_FUNCTIONS
+        }
+    }
+
+    /// Returns a message that describes the limitations of the process, or `None` if it has none.
+    pub fn warning(&self) -> Option<String> {
+        let mut limits: Vec<String> = self
+            .restricted_functions()
+            .iter()
+            .map(|(_, what)| (*what).to_owned())
+            .collect();
+        if self.control_enabled && !self.is_privileged() {
+            limits.insert(0, String::from("control of the devices"));
+        }
+        if let Some(reason) = &self.control_blocked {
+            limits.push(format!("control of the devices: {reason}"));
+        }
+        (!limits.is_empty()).then(|| {
+            format!(
+                "the following features are not available to the process (euid {}): {}",
+                self.euid,
+                limits.join("; ")
+            )
+        })
+    }
+}
+
+impl AmdSmi {
+    /// Inspects the privileges of the process, to know which functions of the library will fail with `NO_PERM`.
+    ///
+    /// No privileged call is made: the check relies on the credentials of the process.
+    pub fn check_privileges(&self) -> PrivilegeReport {
+        // SAFETY: geteuid has no precondition and cannot fail.
+        let euid = unsafe { libc::geteuid() };
+        let capabilities = fs::read_to_string("/proc/self/status")
+            .ok()
+            .and_then(|status| parse_effective_capabilities(&status));
+        PrivilegeReport {
+            euid,
+            capabilities,
+            control_enabled: cfg!(feature = "control"),
+            control_blocked: self.control_blocked(),
+        }
+    }
+
+    #[cfg(feature = "control")]
+    fn control_blocked(&self) -> Option<String> {
+        self.check_control().err().map(|e| e.to_string())
+    }
+
+    #[cfg(not(feature = "control"))]
+    fn control_blocked(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Parses the effective capabilities (`CapEff`) from the content of `/proc/<pid>/status`.
+fn parse_effective_capabilities(status: &str) -> Option<u64> {
+    let value = status
+        .lines()
+        .find_map(|line| line.strip_prefix("CapEff:"))?;
+    u64::from_str_radix(value.trim(), 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PrivilegeReport, parse_effective_capabilities};
+
+    #[test]
+    fn privileges() {
+        let status = "Name:\tagent\nCapInh:\t0000000000000000\nCapEff:\t0000000000200000\n";
+        assert_eq!(parse_effective_capabilities(status), Some(1 << 21));
+        assert_eq!(parse_effective_capabilities("Name:\tagent\n"), None);
+
+        let mut report = PrivilegeReport {
+            euid: 1000,
+            capabilities: Some(0),
+            control_enabled: true,
+            control_blocked: None,
+        };
+        assert!(!report.can_control());
+        assert!(!report.restricted_functions().is_empty());
+        assert!(
+            report
+                .warning()
+                .unwrap()
+                .contains("(euid 1000): control of the devices; performance counters")
+        );
+
+        report.capabilities = Some(1 << 21);
+        assert!(report.can_control());
+        assert_eq!(report.warning(), None);
+
+        // root without the capability
+        report.euid = 0;
+        report.capabilities = Some(0);
+        assert!(!report.is_privileged());
+        report.capabilities = None;
+        assert!(report.is_privileged());
+    }
+}
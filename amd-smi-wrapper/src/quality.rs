@@ -0,0 +1,153 @@
+//! Quality of the power and energy readings.
+//!
+//! The readings of the library are not all equal: the energy is an accumulator with a coarse resolution, the
+//! power of the recent GPUs is instantaneous, the power of the older ones is averaged by the firmware. The
+//! [`ReadingQuality`] of a reading describes it, so that statistical processing can weight the samples:
+//! an averaged power should not be averaged again over a window shorter than the window of the firmware, and two
+//! energy readings taken within the same update interval of the firmware have the same value.
+
+use std::time::Duration;
+
+use crate::metrics::{AmdEnergyReading, AmdPowerConsumption};
+
+/// How the firmware produces a value.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ReadingKind {
+    /// Value measured at the time of the update.
+    Instantaneous,
+    /// Mean of the values measured during a window of the firmware.
+    Averaged,
+    /// Sum of the values since the boot (energy counter).
+    Accumulated,
+}
+
+/// Quality metadata of a reading.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReadingQuality {
+    /// How the value has been produced.
+    pub kind: ReadingKind,
+    /// Smallest change of the value that can be reported, in the unit of the reading (W or J).
+    pub resolution: f64,
+    /// Interval between two updates of the value by the firmware, if it is known.
+    /// Only [`energy_quality`] estimates it, see [`estimate_update_interval`].
+    pub update_interval: Option<Duration>,
+}
+
+impl AmdPowerConsumption {
+    /// Returns the quality of [`socket_power`](AmdPowerConsumption::socket_power).
+    ///
+    /// The MI300 and later GPUs report the current power, which is instantaneous. The other GPUs only report
+    /// the power averaged by the firmware, and leave the current power to 0 or `u32::MAX`.
+    pub fn quality(&self) -> ReadingQuality {
+        let kind = if self.current_socket_power != 0 && self.current_socket_power != u32::MAX {
+            ReadingKind::Instantaneous
+        } else {
+            ReadingKind::Averaged
+        };
+        ReadingQuality {
+            kind,
+            resolution: 1.0,
+            update_interval: None,
+        }
+    }
+}
+
+impl AmdEnergyReading {
+    /// Returns the quality of [`joules`](AmdEnergyReading::joules): an accumulator whose resolution
+    /// is the resolution of the counter.
+    pub fn quality(&self) -> ReadingQuality {
+        ReadingQuality {
+            kind: ReadingKind::Accumulated,
+            resolution: self.resolution as f64 / 1e6,
+            update_interval: None,
+        }
+    }
+}
+
+/// Returns the quality of the last of successive energy readings of a GPU, with the
+/// [`update_interval`](ReadingQuality::update_interval) estimated from all of them by [`estimate_update_interval`].
+///
+/// Returns `None` if there is no reading.
+pub fn energy_quality(readings: &[AmdEnergyReading]) -> Option<ReadingQuality> {
+    let last = readings.last()?;
+    Some(ReadingQuality {
+        update_interval: estimate_update_interval(readings),
+        ..last.quality()
+    })
+}
+
+/// Estimates the interval at which the firmware updates the energy counter, from successive readings of a GPU
+/// timestamped by the driver, taken faster than the updates.
+///
+/// The estimate is the median of the intervals between the readings where the counter changes. Returns `None`
+/// if the readings are not timestamped, or if the counter changes less than twice.
+pub fn estimate_update_interval(readings: &[AmdEnergyReading]) -> Option<Duration> {
+    let mut updates = Vec::new();
+    for pair in readings.windows(2) {
+        if pair[1].counter != pair[0].counter {
+            updates.push(pair[1].timestamp?);
+        }
+    }
+    let mut intervals: Vec<Duration> = updates
+        .windows(2)
+        .filter_map(|pair| pair[1].duration_since(pair[0]))
+        .collect();
+    if intervals.is_empty() {
+        return None;
+    }
+    intervals.sort();
+    Some(intervals[intervals.len() / 2])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{ReadingKind, energy_quality, estimate_update_interval};
+    use crate::{
+        metrics::{AmdEnergyReading, AmdPowerConsumption},
+        timebase::DriverTimestamp,
+    };
+
+    #[test]
+    fn update_interval() {
+        // counter updated every 1 ms, read every 0.4 ms
+        let readings: Vec<_> = (0..20u64)
+            .map(|i| {
+                let nanos = i * 400_000;
+                AmdEnergyReading {
+                    joules: 0.0,
+                    counter: nanos / 1_000_000,
+                    resolution: 15.3,
+                    timestamp: Some(DriverTimestamp::from_nanos(nanos)),
+                }
+            })
+            .collect();
+        let interval = estimate_update_interval(&readings).unwrap();
+        assert!(interval >= Duration::from_micros(800) && interval <= Duration::from_micros(1200));
+        assert_eq!(readings[0].quality().kind, ReadingKind::Accumulated);
+        assert_eq!(estimate_update_interval(&readings[..2]), None);
+        assert_eq!(
+            energy_quality(&readings).unwrap().update_interval,
+            Some(interval)
+        );
+        assert_eq!(energy_quality(&[]), None);
+    }
+
+    #[test]
+    fn power_kind() {
+        let power = |current_socket_power| AmdPowerConsumption {
+            socket_power: 250,
+            current_socket_power,
+            average_socket_power: 250,
+            gfx_voltage: 0,
+            soc_voltage: 0,
+            mem_voltage: 0,
+            power_limit: 300,
+        };
+        assert_eq!(power(250).quality().kind, ReadingKind::Instantaneous);
+        assert_eq!(power(0).quality().kind, ReadingKind::Averaged);
+        assert_eq!(power(u32::MAX).quality().kind, ReadingKind::Averaged);
+        assert_eq!(power(250).quality().update_interval, None);
+    }
+}
@@ -0,0 +1,272 @@
+//! Registry of the GPUs, shared by the components of an application.
+//!
+//! Each enumeration of the processors calls the library several times per socket, and each component
+//! that enumerates holds its own handles, with their own caches. A [`DeviceRegistry`] enumerates once,
+//! indexes the GPUs by PCI address and UUID, and gives the same [`SharedHandle`] to all the components.
+//! It is `Send` and `Sync`: share it behind an `Arc`.
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex, MutexGuard, RwLock},
+};
+
+use crate::{
+    AmdSmi,
+    error::{AmdError, ResultExt},
+    handles::{AmdProcessorHandle, ProcessorHandle},
+    metrics::{AmdPciAddress, AmdProcessorType},
+};
+
+/// Handle of a GPU shared by the users of a [`DeviceRegistry`], with its caches (identity, sensors...).
+///
+/// The handle is used by one thread at a time: [`lock`](Self::lock) it for a sequence of queries.
+pub struct SharedHandle(Mutex<AmdProcessorHandle>);
+
+// SAFETY: The handle is only accessed through the mutex, by one thread at a time, so its caches are never
+// accessed concurrently. Its raw handle is an opaque identifier of the processor, that the library accepts
+// from any thread.
+unsafe impl Send for SharedHandle {}
+// SAFETY: See above, the mutex gives access to one thread at a time.
+unsafe impl Sync for SharedHandle {}
+
+impl SharedHandle {
+    fn new(handle: AmdProcessorHandle) -> Arc<Self> {
+        Arc::new(Self(Mutex::new(handle)))
+    }
+
+    /// Locks the handle, waiting for the other users to release it.
+    pub fn lock(&self) -> MutexGuard<'_, AmdProcessorHandle> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// GPUs known by the registry, in a generation of the library.
+#[derive(Default)]
+struct Entries {
+    generation: u64,
+    by_address: BTreeMap<AmdPciAddress, Arc<SharedHandle>>,
+    by_uuid: HashMap<String, AmdPciAddress>,
+}
+
+/// Changes of the GPUs found by [`DeviceRegistry::refresh`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RegistryChanges {
+    /// GPUs that have appeared since the previous enumeration.
+    pub added: Vec<AmdPciAddress>,
+    /// GPUs that have disappeared since the previous enumeration.
+    ///
+    /// A GPU that cannot be queried anymore is reported as removed, and its error is in `failures`.
+    pub removed: Vec<AmdPciAddress>,
+    /// Errors of the processors that could not be enumerated. They are skipped by the registry.
+    pub failures: Vec<String>,
+}
+
+impl RegistryChanges {
+    /// Compares two lists of addresses, sorted in ascending order.
+    fn between<'a>(
+        old: impl Iterator<Item = &'a AmdPciAddress>,
+        new: impl Iterator<Item = &'a AmdPciAddress>,
+    ) -> Self {
+        let mut changes = Self::default();
+        let (mut old, mut new) = (old.peekable(), new.peekable());
+        loop {
+            match (old.peek(), new.peek()) {
+                (Some(o), Some(n)) => match o.cmp(n) {
+                    Ordering::Less => changes.removed.extend(old.next()),
+                    Ordering::Greater => changes.added.extend(new.next()),
+                    Ordering::Equal => {
+                        old.next();
+                        new.next();
+                    }
+                },
+                (Some(_), None) => changes.removed.extend(old.next()),
+                (None, Some(_)) => changes.added.extend(new.next()),
+                (None, None) => return changes,
+            }
+        }
+    }
+
+    /// Returns `true` if no GPU has appeared or disappeared.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// GPUs of the system, indexed by PCI address and UUID.
+///
+/// The GPUs are enumerated by [`new`](Self::new) and [`refresh`](Self::refresh). The registry returns the same
+/// [`SharedHandle`] for a GPU to all its users, so that they share the caches of the handle. The handles are kept
+/// across the refreshes, and resolve their processor again after a re-initialization of the library
+/// (see [`AmdSmi::set_auto_recovery`]).
+///
+/// # Example
+/// ```no_run
+/// use std::sync::Arc;
+/// use amd_smi_wrapper::{AmdSmi, AmdInitFlags, handles::ProcessorHandle, registry::DeviceRegistry};
+///
+/// let amdsmi = AmdSmi::init(AmdInitFlags::AMDSMI_INIT_AMD_GPUS).unwrap();
+/// let registry = Arc::new(DeviceRegistry::new(&amdsmi).unwrap());
+/// for address in registry.addresses() {
+///     let device = registry.by_pci_address(&address).unwrap().unwrap();
+///     println!("{address}: {:?}", device.lock().device_uuid());
+/// }
+/// ```
+pub struct DeviceRegistry {
+    amdsmi: AmdSmi,
+    entries: RwLock<Entries>,
+}
+
+impl DeviceRegistry {
+    /// Enumerates the GPUs.
+    pub fn new(amdsmi: &AmdSmi) -> Result<Self, AmdError> {
+        let registry = Self {
+            amdsmi: amdsmi.clone(),
+            entries: RwLock::new(Entries::default()),
+        };
+        registry.refresh()?;
+        Ok(registry)
+    }
+
+    /// Enumerates the GPUs again, for instance after a hot-plug, and returns the changes.
+    ///
+    /// The processors that cannot be queried are skipped, and their errors are reported in the changes.
+    pub fn refresh(&self) -> Result<RegistryChanges, AmdError> {
+        let generation = self.amdsmi.generation();
+        let mut fresh = Entries {
+            generation,
+            ..Default::default()
+        };
+        let mut failures = Vec::new();
+        for socket in self.amdsmi.raw_socket_handles()? {
+            for raw in self.amdsmi.raw_processor_handles(socket)? {
+                let handle = AmdProcessorHandle::new(self.amdsmi.clone(), raw);
+                match Self::identify(&handle) {
+                    Ok(Some((address, uuid))) => {
+                        if let Some(uuid) = uuid {
+                            fresh.by_uuid.insert(uuid, address);
+                        }
+                        fresh.by_address.insert(address, SharedHandle::new(handle));
+                    }
+                    Ok(None) => {}
+                    Err(e) => failures.push(e.to_string()),
+                }
+            }
+        }
+
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        let mut changes =
+            RegistryChanges::between(entries.by_address.keys(), fresh.by_address.keys());
+        changes.failures = failures;
+        // keep the handles of the known GPUs, with their caches
+        for (address, handle) in fresh.by_address.iter_mut() {
+            if let Some(known) = entries.by_address.get(address) {
+                *handle = Arc::clone(known);
+            }
+        }
+        *entries = fresh;
+        Ok(changes)
+    }
+
+    /// Returns the PCI address and the UUID of a processor, or `None` if it is not a GPU.
+    fn identify(
+        handle: &AmdProcessorHandle,
+    ) -> Result<Option<(AmdPciAddress, Option<String>)>, AmdError> {
+        if handle.processor_type()? != AmdProcessorType::AMDSMI_PROCESSOR_TYPE_AMD_GPU {
+            return Ok(None);
+        }
+        let address = handle.device_pci_address()?;
+        let uuid = handle.device_uuid().optional()?;
+        Ok(Some((address, uuid)))
+    }
+
+    /// Refreshes the registry if the library has been re-initialized since the last enumeration.
+    fn refresh_if_stale(&self) -> Result<(), AmdError> {
+        let generation = self
+            .entries
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .generation;
+        if generation != self.amdsmi.generation() {
+            self.refresh()?;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of GPUs.
+    pub fn len(&self) -> usize {
+        self.entries
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .by_address
+            .len()
+    }
+
+    /// Returns `true` if no GPU has been found.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the PCI addresses of the GPUs, in ascending order.
+    pub fn addresses(&self) -> Vec<AmdPciAddress> {
+        let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+        entries.by_address.keys().copied().collect()
+    }
+
+    /// Returns the handle of the GPU at the PCI address `address`, or `None` if there is no such GPU.
+    pub fn by_pci_address(
+        &self,
+        address: &AmdPciAddress,
+    ) -> Result<Option<Arc<SharedHandle>>, AmdError> {
+        self.refresh_if_stale()?;
+        let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+        Ok(entries.by_address.get(address).cloned())
+    }
+
+    /// Returns the handle of the GPU with the UUID `uuid`, or `None` if there is no such GPU.
+    pub fn by_uuid(&self, uuid: &str) -> Result<Option<Arc<SharedHandle>>, AmdError> {
+        self.refresh_if_stale()?;
+        let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+        Ok(entries
+            .by_uuid
+            .get(uuid)
+            .and_then(|address| entries.by_address.get(address))
+            .cloned())
+    }
+
+    /// Returns the handle of each GPU, ordered by PCI address.
+    pub fn handles(&self) -> Result<Vec<Arc<SharedHandle>>, AmdError> {
+        self.refresh_if_stale()?;
+        let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+        Ok(entries.by_address.values().cloned().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DeviceRegistry, RegistryChanges, SharedHandle};
+    use crate::metrics::AmdPciAddress;
+
+    #[test]
+    fn changes() {
+        let address = |bus: u64| AmdPciAddress::from_bdf_id(bus << 8);
+        let old = [address(1), address(2), address(3)];
+        let new = [address(2), address(3), address(4)];
+        let changes = RegistryChanges::between(old.iter(), new.iter());
+        assert_eq!(changes.added, vec![address(4)]);
+        assert_eq!(changes.removed, vec![address(1)]);
+        assert!(RegistryChanges::between(old.iter(), old.iter()).is_empty());
+        let changes = RegistryChanges::between([].iter(), old.iter());
+        assert_eq!(changes.added, old);
+        let changes = RegistryChanges::between(old.iter(), [address(0), address(5)].iter());
+        assert_eq!(changes.added, vec![address(0), address(5)]);
+        assert_eq!(changes.removed, old);
+    }
+
+    #[test]
+    fn shareable() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<DeviceRegistry>();
+        assert_send_sync::<SharedHandle>();
+    }
+}
@@ -0,0 +1,152 @@
+//! Machine-readable description of the capabilities of a device.
+//!
+//! The report is meant to be attached to bug reports, or compared between nodes of a cluster.
+//! It is serialized to JSON by [`AmdCapabilityReport::to_json`].
+
+use crate::{
+    dynamic::{self, MetricKind},
+    error::{AmdError, ResultExt},
+    handles::ProcessorHandle,
+    json::JsonWriter,
+    metrics::{AmdDriverInfo, AmdFirmwareInfo, AmdVersion},
+};
+
+/// Capabilities of a device: supported metrics, versions of the software stack and partition modes.
+///
+/// The parts that are not available on the device are `None`.
+#[derive(Debug, Clone)]
+pub struct AmdCapabilityReport {
+    /// Version of the AMD SMI library.
+    pub library_version: AmdVersion,
+    /// Kernel driver of the device.
+    pub driver: Option<AmdDriverInfo>,
+    /// Versions of the firmware blocks.
+    pub firmwares: Vec<AmdFirmwareInfo>,
+    /// Compute partition mode.
+    pub compute_partition: Option<String>,
+    /// Memory partition mode.
+    pub memory_partition: Option<String>,
+    /// Metrics that can be read with [`ProcessorHandle::device_read_metric`].
+    pub supported_metrics: Vec<MetricKind>,
+}
+
+/// Builds the capability report of a device.
+pub(crate) fn capability_report<P: ProcessorHandle + ?Sized>(
+    device: &P,
+) -> Result<AmdCapabilityReport, AmdError> {
+    Ok(AmdCapabilityReport {
+        library_version: device.lib_version()?,
+        driver: device.device_driver_info().optional()?,
+        firmwares: device.device_firmwares().optional()?.unwrap_or_default(),
+        compute_partition: device.device_compute_partition_name().optional()?,
+        memory_partition: device.device_memory_partition_name().optional()?,
+        supported_metrics: dynamic::supported_metrics(device)?,
+    })
+}
+
+impl AmdCapabilityReport {
+    /// Serializes the report to a JSON object.
+    ///
+    /// The metrics are identified by the name of their metadata, followed by the index of
+    /// the sensor in brackets when the metric has several sensors (e.g. `temperature[1]`).
+    pub fn to_json(&self) -> String {
+        let mut json = JsonWriter::new();
+        json.begin_object();
+        let v = &self.library_version;
+        json.key("library_version").begin_object();
+        json.key("major").number(v.major);
+        json.key("minor").number(v.minor);
+        json.key("release").number(v.release);
+        json.key("build").string(&v.build);
+        json.end_object();
+
+        json.key("driver").option(self.driver.as_ref(), |json, d| {
+            json.begin_object();
+            json.key("name").string(&d.name);
+            json.key("version").string(&d.version);
+            json.key("date").string(&d.date);
+            json.end_object();
+        });
+
+        json.key("firmwares").begin_array();
+        for fw in &self.firmwares {
+            json.begin_object();
+            json.key("block").number(fw.block.0);
+            json.key("version").number(fw.version);
+            json.end_object();
+        }
+        json.end_array();
+
+        for (key, value) in [
+            ("compute_partition", &self.compute_partition),
+            ("memory_partition", &self.memory_partition),
+        ] {
+            json.key(key).option(value.as_deref(), |json, s| {
+                json.string(s);
+            });
+        }
+
+        json.key("supported_metrics").begin_array();
+        for kind in &self.supported_metrics {
+            json.string(&metric_label(kind));
+        }
+        json.end_array();
+        json.end_object();
+        json.finish()
+    }
+}
+
+/// Returns the label of a metric in the report.
+fn metric_label(kind: &MetricKind) -> String {
+    let name = kind.metadata().name;
+    let index = match kind {
+        MetricKind::Temperature(t) => Some(t.0),
+        MetricKind::Voltage(t) => Some(t.0),
+        MetricKind::Clock(t) => Some(t.0),
+        MetricKind::MemoryUsed(t) => Some(t.0),
+        MetricKind::FanSpeed(i) => Some(*i),
+        _ => None,
+    };
+    match index {
+        Some(i) => format!("{name}[{i}]"),
+        None => name.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AmdCapabilityReport;
+    use crate::{dynamic::MetricKind, metrics::*};
+
+    #[test]
+    fn to_json() {
+        let report = AmdCapabilityReport {
+            library_version: AmdVersion {
+                major: 26,
+                minor: 1,
+                release: 0,
+                build: "26.1.0+\"dev\"".to_owned(),
+            },
+            driver: None,
+            firmwares: vec![AmdFirmwareInfo {
+                block: AmdFwBlock::AMDSMI_FW_ID_SMU,
+                version: 42,
+            }],
+            compute_partition: Some("SPX".to_owned()),
+            memory_partition: None,
+            supported_metrics: vec![
+                MetricKind::Power,
+                MetricKind::Temperature(AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_JUNCTION),
+            ],
+        };
+        assert_eq!(
+            report.to_json(),
+            concat!(
+                r#"{"library_version":{"major":26,"minor":1,"release":0,"build":"26.1.0+\"dev\""},"#,
+                r#""driver":null,"firmwares":[{"block":1,"version":42}],"#,
+                r#""compute_partition":"SPX","memory_partition":null,"#,
+                r#""supported_metrics":["power","temperature[1]"]}"#
+            )
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! Translation between the device indexes of the ROCm runtimes (HIP, ROCr) and the processor handles.
+//!
+//! The runtimes enumerate the GPUs in the HSA order, then filter and reorder them according to
+//! the environment variables `ROCR_VISIBLE_DEVICES` and `HIP_VISIBLE_DEVICES`.
+//! The index of a device in a framework (PyTorch, TensorFlow...) is its position in the resulting list.
+
+use crate::{error::AmdError, handles::ProcessorHandle};
+
+/// Environment variable read by the ROCr runtime.
+pub const ROCR_VISIBLE_DEVICES: &str = "ROCR_VISIBLE_DEVICES";
+
+/// Environment variable read by the HIP runtime.
+pub const HIP_VISIBLE_DEVICES: &str = "HIP_VISIBLE_DEVICES";
+
+/// Orders the processors like the ROCm runtimes do, according to the environment of the current process.
+///
+/// The device with the HIP index `i` is at position `i` in the returned list.
+/// Processors that are hidden by the environment variables are removed.
+pub fn runtime_device_order<P: ProcessorHandle>(processors: Vec<P>) -> Result<Vec<P>, AmdError> {
+    let rocr = std::env::var(ROCR_VISIBLE_DEVICES).ok();
+    let hip = std::env::var(HIP_VISIBLE_DEVICES).ok();
+    runtime_device_order_with(processors, rocr.as_deref(), hip.as_deref())
+}
+
+/// Orders the processors like the ROCm runtimes do, with the given values of
+/// `ROCR_VISIBLE_DEVICES` and `HIP_VISIBLE_DEVICES` (`None` if unset).
+pub fn runtime_device_order_with<P: ProcessorHandle>(
+    processors: Vec<P>,
+    rocr_visible_devices: Option<&str>,
+    hip_visible_devices: Option<&str>,
+) -> Result<Vec<P>, AmdError> {
+    // HSA order
+    let mut devices = Vec::with_capacity(processors.len());
+    for processor in processors {
+        let info = processor.device_enumeration_info()?;
+        devices.push((info.hsa_id, info.hip_uuid, processor));
+    }
+    devices.sort_by_key(|(hsa_id, _, _)| *hsa_id);
+
+    // ROCr filtering: indexes or UUIDs
+    if let Some(value) = rocr_visible_devices {
+        let uuids: Vec<&str> = devices.iter().map(|(_, uuid, _)| uuid.as_str()).collect();
+        let selected = visible_devices(value, &uuids);
+        devices = select(devices, &selected);
+    }
+
+    // HIP filtering: indexes or UUIDs, relative to the devices that are visible to ROCr
+    if let Some(value) = hip_visible_devices {
+        let uuids: Vec<&str> = devices.iter().map(|(_, uuid, _)| uuid.as_str()).collect();
+        let selected = visible_devices(value, &uuids);
+        devices = select(devices, &selected);
+    }
+
+    Ok(devices
+        .into_iter()
+        .map(|(_, _, processor)| processor)
+        .collect())
+}
+
+/// Keeps the elements at the given positions, in the given order.
+fn select<T>(items: Vec<T>, positions: &[usize]) -> Vec<T> {
+    let mut items: Vec<Option<T>> = items.into_iter().map(Some).collect();
+    positions.iter().filter_map(|&i| items[i].take()).collect()
+}
+
+/// Parses a list of visible devices, and returns the positions of the selected devices, in order.
+///
+/// Like the runtimes, the parsing stops at the first invalid entry (unknown index or UUID).
+/// A device that is listed twice is only selected once.
+pub(crate) fn visible_devices(value: &str, uuids: &[&str]) -> Vec<usize> {
+    let mut selected = Vec::new();
+    for entry in value.split(',').map(str::trim) {
+        let position = match entry.parse::<usize>() {
+            Ok(i) if i < uuids.len() => Some(i),
+            Ok(_) => None,
+            Err(_) => uuids.iter().position(|uuid| uuid_matches(uuid, entry)),
+        };
+        match position {
+            Some(i) if !selected.contains(&i) => selected.push(i),
+            Some(_) => (),
+            None => break,
+        }
+    }
+    selected
+}
+
+fn uuid_matches(uuid: &str, entry: &str) -> bool {
+    let strip = |s: &str| s.strip_prefix("GPU-").unwrap_or(s).to_ascii_lowercase();
+    !entry.is_empty() && strip(uuid) == strip(entry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::visible_devices;
+
+    #[test]
+    fn parse_visible_devices() {
+        let uuids = ["GPU-aaaa", "GPU-bbbb", "GPU-cccc"];
+        assert_eq!(visible_devices("0,1,2", &uuids), vec![0, 1, 2]);
+        assert_eq!(visible_devices("2,0", &uuids), vec![2, 0]);
+        assert_eq!(visible_devices("1, 1", &uuids), vec![1]);
+        assert_eq!(visible_devices("GPU-cccc,0", &uuids), vec![2, 0]);
+        assert_eq!(visible_devices("BBBB", &uuids), vec![1]);
+        // stops at the first invalid entry
+        assert_eq!(visible_devices("1,5,0", &uuids), vec![1]);
+        assert_eq!(visible_devices("GPU-dddd,0", &uuids), Vec::<usize>::new());
+        assert_eq!(visible_devices("", &uuids), Vec::<usize>::new());
+    }
+}
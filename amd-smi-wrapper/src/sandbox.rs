@@ -0,0 +1,274 @@
+//! Files used by the library, to build the allow-lists of sandboxed services.
+//!
+//! The library opens the device files of the DRM and of the compute driver (KFD), and reads (or writes, for the
+//! control) the sysfs directories of the GPUs. Services hardened with systemd (`DevicePolicy=closed`,
+//! `ProtectKernelTunables=`...) or seccomp must allow these paths. [`AmdSmi::required_paths`] derives them from
+//! the enumeration of the GPUs, and [`AccessList::to_systemd`] formats them as directives of a unit file.
+//!
+//! # Example
+//! ```no_run
+//! use amd_smi_wrapper::{AmdSmi, AmdInitFlags};
+//!
+//! let amdsmi = AmdSmi::init(AmdInitFlags::AMDSMI_INIT_AMD_GPUS).unwrap();
+//! print!("{}", amdsmi.required_paths().unwrap().to_systemd());
+//! ```
+
+use std::{
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    AmdSmi, error::AmdError, handles::ProcessorHandle, kfd::KFD_TOPOLOGY_PATH,
+    metrics::AmdProcessorType,
+};
+
+/// Device file of the compute driver.
+pub const KFD_DEVICE_PATH: &str = "/dev/kfd";
+
+/// Kind of a path used by the library.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum PathKind {
+    /// Device file in `/dev`.
+    DeviceFile,
+    /// File or directory of sysfs.
+    Sysfs,
+    /// The shared library itself.
+    Library,
+}
+
+/// Access to a path needed by the library.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum PathAccess {
+    /// The path is only read.
+    Read,
+    /// The path is read and written, or the device file is opened in read-write mode.
+    ReadWrite,
+}
+
+/// Path used by the library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequiredPath {
+    /// Path, with the symbolic links resolved when possible.
+    pub path: PathBuf,
+    /// Kind of the path.
+    pub kind: PathKind,
+    /// Access needed by the library.
+    pub access: PathAccess,
+    /// What the path is used for.
+    pub reason: &'static str,
+}
+
+/// Paths used by the library, without duplicates, in the order of discovery.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AccessList {
+    pub paths: Vec<RequiredPath>,
+}
+
+impl AccessList {
+    /// Adds a path, unless it is already in the list. If it is, the broadest access is kept.
+    pub fn add(
+        &mut self,
+        path: impl Into<PathBuf>,
+        kind: PathKind,
+        access: PathAccess,
+        reason: &'static str,
+    ) {
+        let path = path.into();
+        match self.paths.iter_mut().find(|p| p.path == path) {
+            Some(existing) => {
+                if access == PathAccess::ReadWrite {
+                    existing.access = access;
+                }
+            }
+            None => self.paths.push(RequiredPath {
+                path,
+                kind,
+                access,
+                reason,
+            }),
+        }
+    }
+
+    /// Returns the paths of a given kind.
+    pub fn of_kind(&self, kind: PathKind) -> impl Iterator<Item = &Path> + '_ {
+        self.paths
+            .iter()
+            .filter(move |p| p.kind == kind)
+            .map(|p| p.path.as_path())
+    }
+
+    /// Formats the list as directives of a systemd unit: `DeviceAllow=` for the device files,
+    /// `ReadWritePaths=` and `ReadOnlyPaths=` for the other paths.
+    pub fn to_systemd(&self) -> String {
+        let mut res = String::new();
+        for p in &self.paths {
+            let path = p.path.display();
+            match (p.kind, p.access) {
+                (PathKind::DeviceFile, PathAccess::Read) => writeln!(res, "DeviceAllow={path} r"),
+                (PathKind::DeviceFile, PathAccess::ReadWrite) => {
+                    writeln!(res, "DeviceAllow={path} rw")
+                }
+                (_, PathAccess::Read) => writeln!(res, "ReadOnlyPaths={path}"),
+                (_, PathAccess::ReadWrite) => writeln!(res, "ReadWritePaths={path}"),
+            }
+            .unwrap();
+        }
+        res
+    }
+}
+
+impl AmdSmi {
+    /// Lists the device files and the sysfs paths that the library needs, for the GPUs of this instance.
+    ///
+    /// The sysfs directories of the GPUs are writable if the crate is built with the feature `control`.
+    /// The symbolic links of sysfs are resolved, when possible, because the sandboxes apply to the real paths.
+    pub fn required_paths(&self) -> Result<AccessList, AmdError> {
+        let sysfs_access = if cfg!(feature = "control") {
+            PathAccess::ReadWrite
+        } else {
+            PathAccess::Read
+        };
+        let mut list = AccessList::default();
+        let library = Path::new(self.library_path());
+        let library = if library.is_absolute() {
+            Some(resolve(library))
+        } else {
+            // found by the dynamic loader in its search paths
+            fs::read_to_string("/proc/self/maps")
+                .ok()
+                .and_then(|maps| find_mapped_library(&maps, library))
+        };
+        if let Some(library) = library {
+            list.add(
+                library,
+                PathKind::Library,
+                PathAccess::Read,
+                "shared library",
+            );
+        }
+        list.add(
+            KFD_DEVICE_PATH,
+            PathKind::DeviceFile,
+            PathAccess::ReadWrite,
+            "compute driver: processes, counters",
+        );
+        list.add(
+            resolve(Path::new(KFD_TOPOLOGY_PATH)),
+            PathKind::Sysfs,
+            PathAccess::Read,
+            "topology of the compute driver",
+        );
+        list.add(
+            "/sys/module/amdgpu",
+            PathKind::Sysfs,
+            PathAccess::Read,
+            "version of the kernel driver",
+        );
+        for device in self.iter_processors()? {
+            let device = device?;
+            if device.processor_type()? != AmdProcessorType::AMDSMI_PROCESSOR_TYPE_AMD_GPU {
+                continue;
+            }
+            let info = device.device_enumeration_info()?;
+            list.add(
+                format!("/dev/dri/card{}", info.drm_card),
+                PathKind::DeviceFile,
+                PathAccess::ReadWrite,
+                "DRM card: queries of the driver",
+            );
+            list.add(
+                device.device_drm_render_path()?,
+                PathKind::DeviceFile,
+                PathAccess::ReadWrite,
+                "DRM render node: metrics and processes",
+            );
+            list.add(
+                resolve(&device.device_sysfs_path()?),
+                PathKind::Sysfs,
+                sysfs_access,
+                "sysfs of the device: sensors, clocks, settings",
+            );
+        }
+        Ok(list)
+    }
+}
+
+/// Finds the file of a library in the memory mappings of the process (content of `/proc/self/maps`).
+///
+/// The mappings show the real file, whose name can have a version suffix (`libamd_smi.so.25.0`)
+/// when the library has been loaded through a symbolic link.
+fn find_mapped_library(maps: &str, library: &Path) -> Option<PathBuf> {
+    let name = library.file_name()?.to_str()?;
+    maps.lines()
+        // the path is the 6th column, and may contain spaces
+        .filter_map(|line| line.splitn(6, ' ').nth(5).map(str::trim_start))
+        .map(Path::new)
+        .find(|path| {
+            path.is_absolute()
+                && path.file_name().and_then(|f| f.to_str()).is_some_and(|f| {
+                    f == name || f.strip_prefix(name).is_some_and(|v| v.starts_with('.'))
+                })
+        })
+        .map(Path::to_owned)
+}
+
+/// Resolves the symbolic links of a path, or returns it unchanged if it does not exist.
+fn resolve(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{AccessList, PathAccess, PathKind, find_mapped_library};
+
+    #[test]
+    fn systemd() {
+        let mut list = AccessList::default();
+        list.add("/dev/kfd", PathKind::DeviceFile, PathAccess::ReadWrite, "");
+        list.add(
+            "/sys/devices/pci0000:00/0000:03:00.0",
+            PathKind::Sysfs,
+            PathAccess::Read,
+            "",
+        );
+        list.add(
+            "/sys/devices/pci0000:00/0000:03:00.0",
+            PathKind::Sysfs,
+            PathAccess::ReadWrite,
+            "",
+        );
+        list.add("/sys/module/amdgpu", PathKind::Sysfs, PathAccess::Read, "");
+        assert_eq!(list.paths.len(), 3);
+        assert_eq!(
+            list.to_systemd(),
+            "DeviceAllow=/dev/kfd rw\n\
+             ReadWritePaths=/sys/devices/pci0000:00/0000:03:00.0\n\
+             ReadOnlyPaths=/sys/module/amdgpu\n"
+        );
+        assert_eq!(list.of_kind(PathKind::DeviceFile).count(), 1);
+    }
+
+    #[test]
+    fn mapped_library() {
+        let maps = "\
+55d0c0a00000-55d0c0a2a000 r--p 00000000 00:1f 1234  /usr/bin/agent
+7f1c2e000000-7f1c2e100000 r--p 00000000 00:1f 5678  /opt/rocm/lib/libamd_smi.so.25.0
+7f1c2e100000-7f1c2e200000 r-xp 00100000 00:1f 5678  /opt/rocm/lib/libamd_smi.so.25.0
+7f1c2f000000-7f1c2f001000 rw-p 00000000 00:00 0 
+7ffd1c000000-7ffd1c021000 rw-p 00000000 00:00 0  [stack]
+";
+        assert_eq!(
+            find_mapped_library(maps, Path::new("libamd_smi.so")).as_deref(),
+            Some(Path::new("/opt/rocm/lib/libamd_smi.so.25.0"))
+        );
+        assert_eq!(
+            find_mapped_library(maps, Path::new("libamd_smi.so.2")),
+            None
+        );
+        assert_eq!(find_mapped_library(maps, Path::new("libother.so")), None);
+    }
+}
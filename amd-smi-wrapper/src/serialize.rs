@@ -0,0 +1,298 @@
+//! Serialization of the calls to the library.
+//!
+//! Some versions of the AMD SMI library are not thread-safe. With the `serialize-ffi` feature,
+//! every call to the library is made while holding a lock, and the contention on this lock is measured
+//! (see [`AmdSmi::ffi_lock_stats`](crate::AmdSmi::ffi_lock_stats)).
+//!
+//! The global state of the library is shared by all the instances of [`AmdSmi`](crate::AmdSmi) that use the
+//! same copy of the library (see [`loaded_libraries`](crate::loader::loaded_libraries)): the lock is a
+//! process-wide lock per copy, shared by these instances, and also held during the initialization and the
+//! shutdown of the library.
+//!
+//! Without the feature, the lock does nothing and costs nothing.
+
+#[cfg(feature = "serialize-ffi")]
+pub(crate) use enabled::FfiLock;
+#[cfg(feature = "serialize-ffi")]
+pub use enabled::{FfiLockStats, PoisonDiagnostic};
+
+#[cfg(not(feature = "serialize-ffi"))]
+pub(crate) use disabled::FfiLock;
+
+#[cfg(not(feature = "serialize-ffi"))]
+mod disabled {
+    /// Lock that does nothing, used when the `serialize-ffi` feature is disabled.
+    #[derive(Debug, Clone)]
+    pub(crate) struct FfiLock;
+
+    pub(crate) struct FfiGuard;
+
+    impl FfiLock {
+        pub(crate) fn for_library(_library_id: usize) -> Self {
+            FfiLock
+        }
+
+        #[inline(always)]
+        pub(crate) fn lock(&self) -> FfiGuard {
+            FfiGuard
+        }
+    }
+}
+
+#[cfg(feature = "serialize-ffi")]
+mod enabled {
+    use std::{
+        cell::RefCell,
+        ops::Deref,
+        sync::{
+            Arc, LockResult, Mutex, MutexGuard, TryLockError, Weak,
+            atomic::{AtomicU64, Ordering},
+        },
+        thread,
+        time::{Duration, Instant, SystemTime},
+    };
+
+    thread_local! {
+        /// Addresses of the locks held by the current thread, which makes each lock reentrant.
+        ///
+        /// Each copy of the library has its own lock: holding one of them must not skip the others.
+        static HELD: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Locks of the copies of the library, by identifier of the copy (see [`crate::loader::library_id`]).
+    ///
+    /// The locks are kept while an instance uses them, so that a copy loaded again gets a fresh lock.
+    static LOCKS: Mutex<Vec<(usize, Weak<SharedLock>)>> = Mutex::new(Vec::new());
+
+    /// Lock held during each call to the library, shared by the instances that use the same copy of the library.
+    #[derive(Debug, Clone)]
+    pub(crate) struct FfiLock {
+        shared: Arc<SharedLock>,
+    }
+
+    #[derive(Debug, Default)]
+    pub(crate) struct SharedLock {
+        mutex: Mutex<()>,
+        acquisitions: AtomicU64,
+        contended: AtomicU64,
+        wait_nanos: AtomicU64,
+        poisonings: AtomicU64,
+        last_poisoning: Mutex<Option<PoisonDiagnostic>>,
+    }
+
+    /// Guard of an [`FfiLock`]: the lock is released when the guard is dropped.
+    pub(crate) struct FfiGuard<'a> {
+        lock: &'a SharedLock,
+        /// `None` if the lock was already held by the current thread.
+        guard: Option<MutexGuard<'a, ()>>,
+    }
+
+    impl FfiLock {
+        /// Returns the lock of the copy of the library identified by `library_id`, shared with the other
+        /// instances that use this copy.
+        pub(crate) fn for_library(library_id: usize) -> Self {
+            let mut locks = LOCKS.lock().unwrap_or_else(|e| e.into_inner());
+            locks.retain(|(_, lock)| lock.strong_count() > 0);
+            if let Some(shared) = locks
+                .iter()
+                .find(|(id, _)| *id == library_id)
+                .and_then(|(_, lock)| lock.upgrade())
+            {
+                return Self { shared };
+            }
+            let shared = Arc::new(SharedLock::default());
+            locks.push((library_id, Arc::downgrade(&shared)));
+            Self { shared }
+        }
+    }
+
+    impl Deref for FfiLock {
+        type Target = SharedLock;
+
+        fn deref(&self) -> &SharedLock {
+            &self.shared
+        }
+    }
+
+    impl SharedLock {
+        /// Acquires the lock, unless the current thread already holds it.
+        ///
+        /// If a thread has panicked while holding the lock, the poisoning is recorded and the lock is recovered:
+        /// the lock protects the library, not data of the wrapper, so there is nothing to repair.
+        pub(crate) fn lock(&self) -> FfiGuard<'_> {
+            if HELD.with_borrow(|held| held.contains(&self.address())) {
+                return FfiGuard {
+                    lock: self,
+                    guard: None,
+                };
+            }
+            let guard = match self.mutex.try_lock() {
+                Ok(guard) => guard,
+                Err(TryLockError::Poisoned(e)) => self.recover(Err(e)),
+                Err(TryLockError::WouldBlock) => {
+                    let start = Instant::now();
+                    let guard = self.recover(self.mutex.lock());
+                    self.contended.fetch_add(1, Ordering::Relaxed);
+                    self.wait_nanos
+                        .fetch_add(start.elapsed().as_nanos() as u64, Ordering::Relaxed);
+                    guard
+                }
+            };
+            self.acquisitions.fetch_add(1, Ordering::Relaxed);
+            HELD.with_borrow_mut(|held| held.push(self.address()));
+            FfiGuard {
+                lock: self,
+                guard: Some(guard),
+            }
+        }
+
+        /// Identifies the lock in [`HELD`].
+        fn address(&self) -> usize {
+            self as *const SharedLock as usize
+        }
+
+        fn recover<'a>(&'a self, result: LockResult<MutexGuard<'a, ()>>) -> MutexGuard<'a, ()> {
+            result.unwrap_or_else(|e| {
+                self.poisonings.fetch_add(1, Ordering::Relaxed);
+                self.mutex.clear_poison();
+                e.into_inner()
+            })
+        }
+
+        /// Returns the statistics of the lock since its creation by the first instance that uses the copy.
+        pub(crate) fn stats(&self) -> FfiLockStats {
+            FfiLockStats {
+                acquisitions: self.acquisitions.load(Ordering::Relaxed),
+                contended: self.contended.load(Ordering::Relaxed),
+                total_wait: Duration::from_nanos(self.wait_nanos.load(Ordering::Relaxed)),
+                poisonings: self.poisonings.load(Ordering::Relaxed),
+                last_poisoning: self
+                    .last_poisoning
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .clone(),
+            }
+        }
+    }
+
+    impl Drop for FfiGuard<'_> {
+        fn drop(&mut self) {
+            if self.guard.is_none() {
+                return;
+            }
+            let address = self.lock.address();
+            HELD.with_borrow_mut(|held| held.retain(|a| *a != address));
+            if thread::panicking() {
+                // the mutex is about to be poisoned: remember who did it
+                let diagnostic = PoisonDiagnostic {
+                    thread: thread::current().name().map(str::to_owned),
+                    time: SystemTime::now(),
+                };
+                *self
+                    .lock
+                    .last_poisoning
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner()) = Some(diagnostic);
+            }
+        }
+    }
+
+    /// Statistics of the lock that serializes the calls to the library.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct FfiLockStats {
+        /// Number of times the lock has been acquired.
+        pub acquisitions: u64,
+        /// Number of acquisitions that had to wait for another thread.
+        pub contended: u64,
+        /// Total time spent waiting for the lock.
+        pub total_wait: Duration,
+        /// Number of times the lock has been found poisoned, i.e. a thread had panicked while holding it.
+        pub poisonings: u64,
+        /// Last panic that happened while holding the lock.
+        pub last_poisoning: Option<PoisonDiagnostic>,
+    }
+
+    impl FfiLockStats {
+        /// Returns the fraction of the acquisitions that had to wait, between 0 and 1.
+        pub fn contention_ratio(&self) -> f64 {
+            if self.acquisitions == 0 {
+                0.0
+            } else {
+                self.contended as f64 / self.acquisitions as f64
+            }
+        }
+    }
+
+    /// Information about a panic that happened while holding the lock.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct PoisonDiagnostic {
+        /// Name of the thread that panicked, if it has one.
+        pub thread: Option<String>,
+        /// Time of the panic.
+        pub time: SystemTime,
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::FfiLock;
+
+        #[test]
+        fn poisoning() {
+            let lock = FfiLock::for_library(1);
+            std::thread::scope(|s| {
+                let res = std::thread::Builder::new()
+                    .name(String::from("scraper"))
+                    .spawn_scoped(s, || {
+                        let _guard = lock.lock();
+                        // reentrant
+                        let _inner = lock.lock();
+                        panic!("failure in the library");
+                    })
+                    .unwrap()
+                    .join();
+                assert!(res.is_err());
+            });
+            drop(lock.lock());
+            let stats = lock.stats();
+            assert_eq!(stats.acquisitions, 2);
+            assert_eq!(stats.poisonings, 1);
+            let diagnostic = stats.last_poisoning.unwrap();
+            assert_eq!(diagnostic.thread.as_deref(), Some("scraper"));
+        }
+
+        #[test]
+        fn shared_per_library() {
+            // the identifiers are addresses of functions, these ones cannot collide with a real library
+            let first = FfiLock::for_library(2);
+            let same = FfiLock::for_library(2);
+            {
+                let _first = first.lock();
+                // another instance of the same copy: reentrant for this thread, held for the others
+                let _same = same.lock();
+                std::thread::scope(|s| {
+                    s.spawn(|| assert!(same.mutex.try_lock().is_err()));
+                });
+            }
+            assert_eq!(same.stats().acquisitions, 1);
+            drop((first, same));
+            assert_eq!(FfiLock::for_library(2).stats().acquisitions, 0);
+        }
+
+        #[test]
+        fn reentrancy_per_lock() {
+            let first = FfiLock::for_library(3);
+            let second = FfiLock::for_library(4);
+            {
+                let _first = first.lock();
+                let _reentrant = first.lock();
+                // another copy of the library: its lock is still acquired
+                let _second = second.lock();
+                assert!(second.mutex.try_lock().is_err());
+            }
+            assert_eq!(first.stats().acquisitions, 1);
+            assert_eq!(second.stats().acquisitions, 1);
+            assert!(first.mutex.try_lock().is_ok());
+            assert!(second.mutex.try_lock().is_ok());
+        }
+    }
+}
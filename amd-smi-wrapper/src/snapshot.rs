@@ -0,0 +1,276 @@
+//! Point-in-time captures of the metrics of a device, and their differences.
+//!
+//! Watch-style tools take a [`DeviceSnapshot`] periodically, and compute the rates between two
+//! consecutive snapshots with [`DeviceSnapshot::diff`].
+
+use std::time::{Duration, Instant};
+
+use crate::{
+    error::{AmdError, ResultExt},
+    handles::ProcessorHandle,
+    metrics::{AmdEngineUsage, AmdMemoryType, AmdPciTraffic, AmdProcess},
+};
+
+/// Metrics of a device at a given instant.
+///
+/// The metrics that are not available on the device are `None`.
+#[derive(Debug, Clone)]
+pub struct DeviceSnapshot {
+    /// Instant at which the snapshot has been taken.
+    pub timestamp: Instant,
+    /// Socket power in W.
+    pub power: Option<u64>,
+    /// Energy counter in µJ.
+    pub energy: Option<u64>,
+    /// Activity of the engines.
+    pub activity: Option<AmdEngineUsage>,
+    /// PCI bus traffic during the last second.
+    pub pci: Option<AmdPciTraffic>,
+    /// Used VRAM in bytes.
+    pub vram_used: Option<u64>,
+    /// Processes that use the device.
+    pub processes: Option<Vec<AmdProcess>>,
+}
+
+impl Default for DeviceSnapshot {
+    /// Returns an empty snapshot, taken now.
+    fn default() -> Self {
+        Self {
+            timestamp: Instant::now(),
+            power: None,
+            energy: None,
+            activity: None,
+            pci: None,
+            vram_used: None,
+            processes: None,
+        }
+    }
+}
+
+impl DeviceSnapshot {
+    /// Reads the metrics of the device.
+    pub fn capture<P: ProcessorHandle + ?Sized>(device: &P) -> Result<Self, AmdError> {
+        let mut snapshot = Self::default();
+        snapshot.update(device)?;
+        Ok(snapshot)
+    }
+
+    /// Reads the metrics of the device again, reusing the allocations of this snapshot (list of processes).
+    ///
+    /// The metrics that are not available anymore become `None`.
+    pub fn update<P: ProcessorHandle + ?Sized>(&mut self, device: &P) -> Result<(), AmdError> {
+        let energy = device.device_energy_consumption().optional()?;
+        self.timestamp = Instant::now();
+        self.power = device
+            .device_power_consumption()
+            .optional()?
+            .map(|p| p.socket_power);
+        self.energy = energy.map(|e| e.microjoules() as u64);
+        self.activity = device.device_activity().optional()?;
+        self.pci = device.device_pci_usage().optional()?;
+        self.vram_used = device
+            .device_memory_usage(AmdMemoryType::AMDSMI_MEM_TYPE_VRAM)
+            .optional()?;
+
+        let mut processes = self.processes.take().unwrap_or_default();
+        if device
+            .device_process_list_into(&mut processes)
+            .optional()?
+            .is_some()
+        {
+            self.processes = Some(processes);
+        }
+        Ok(())
+    }
+
+    /// Computes the evolution of the metrics between an `older` snapshot of the same device and this one.
+    ///
+    /// A delta is `None` if the metric is missing from one of the snapshots.
+    pub fn diff(&self, older: &DeviceSnapshot) -> SnapshotDiff {
+        let elapsed = self.timestamp.saturating_duration_since(older.timestamp);
+        let seconds = elapsed.as_secs_f64();
+
+        // a counter that decreases has been reset: the delta is unknown
+        let energy = match (older.energy, self.energy) {
+            (Some(old), Some(new)) => new.checked_sub(old),
+            _ => None,
+        };
+        let average_power = energy
+            .filter(|_| seconds > 0.0)
+            .map(|uj| uj as f64 / 1e6 / seconds);
+
+        // the traffic is measured by the driver over one second: use the mean of both measurements
+        let (pci_sent_rate, pci_received_rate) = match (older.pci, self.pci) {
+            (Some(old), Some(new)) => (
+                Some((old.sent as f64 + new.sent as f64) / 2.0),
+                Some((old.received as f64 + new.received as f64) / 2.0),
+            ),
+            _ => (None, None),
+        };
+
+        let vram_used = match (older.vram_used, self.vram_used) {
+            (Some(old), Some(new)) => Some(new as i64 - old as i64),
+            _ => None,
+        };
+
+        let (started_processes, ended_processes) = match (&older.processes, &self.processes) {
+            (Some(old), Some(new)) => (pids_not_in(new, old), pids_not_in(old, new)),
+            _ => (Vec::new(), Vec::new()),
+        };
+
+        SnapshotDiff {
+            elapsed,
+            energy,
+            average_power,
+            pci_sent_rate,
+            pci_received_rate,
+            vram_used,
+            started_processes,
+            ended_processes,
+        }
+    }
+}
+
+/// Returns the pids of the processes of `a` that are not in `b`.
+fn pids_not_in(a: &[AmdProcess], b: &[AmdProcess]) -> Vec<u32> {
+    a.iter()
+        .map(|p| p.pid)
+        .filter(|pid| !b.iter().any(|p| p.pid == *pid))
+        .collect()
+}
+
+/// Evolution of the metrics of a device between two snapshots, see [`DeviceSnapshot::diff`].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SnapshotDiff {
+    /// Time elapsed between the two snapshots.
+    pub elapsed: Duration,
+    /// Energy consumed between the two snapshots, in µJ.
+    pub energy: Option<u64>,
+    /// Average power between the two snapshots, in W, computed from the energy.
+    pub average_power: Option<f64>,
+    /// Bytes sent per second on the PCI bus.
+    pub pci_sent_rate: Option<f64>,
+    /// Bytes received per second on the PCI bus.
+    pub pci_received_rate: Option<f64>,
+    /// Variation of the used VRAM, in bytes.
+    pub vram_used: Option<i64>,
+    /// Pids of the processes that have started to use the device.
+    pub started_processes: Vec<u32>,
+    /// Pids of the processes that have stopped using the device.
+    pub ended_processes: Vec<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::DeviceSnapshot;
+    use crate::metrics::{AmdPciTraffic, AmdProcess};
+
+    fn snapshot(timestamp: Instant, energy: u64, pids: &[u32]) -> DeviceSnapshot {
+        DeviceSnapshot {
+            timestamp,
+            power: None,
+            energy: Some(energy),
+            activity: None,
+            pci: Some(AmdPciTraffic {
+                sent: 1000,
+                received: 3000,
+                max_pkt_sz: 0,
+            }),
+            vram_used: Some(1024),
+            processes: Some(
+                pids.iter()
+                    .map(|&pid| AmdProcess {
+                        pid,
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    #[test]
+    fn diff() {
+        let t0 = Instant::now();
+        let older = snapshot(t0, 1_000_000, &[1, 2]);
+        let newer = snapshot(t0 + Duration::from_secs(2), 101_000_000, &[2, 3]);
+        let diff = newer.diff(&older);
+        assert_eq!(diff.elapsed, Duration::from_secs(2));
+        assert_eq!(diff.energy, Some(100_000_000));
+        assert_eq!(diff.average_power, Some(50.0));
+        assert_eq!(diff.pci_sent_rate, Some(1000.0));
+        assert_eq!(diff.vram_used, Some(0));
+        assert_eq!(diff.started_processes, vec![3]);
+        assert_eq!(diff.ended_processes, vec![1]);
+
+        // reset of the energy counter
+        let diff = older.diff(&newer);
+        assert_eq!(diff.energy, None);
+        assert_eq!(diff.average_power, None);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn capture_and_update() {
+        use crate::{
+            error::{AmdError, AmdStatus},
+            handles::MockProcessorHandle,
+            metrics::{AmdEnergyConsumption, AmdPowerConsumption},
+        };
+
+        let unavailable = || AmdError::Status {
+            status: AmdStatus::AMDSMI_STATUS_NOT_SUPPORTED,
+            message: None,
+        };
+        let mut device = MockProcessorHandle::new();
+        device.expect_device_energy_consumption().returning(|| {
+            Ok(AmdEnergyConsumption {
+                energy: 1000,
+                resolution: 15.3,
+                timestamp: 0,
+            })
+        });
+        device.expect_device_power_consumption().returning(|| {
+            Ok(AmdPowerConsumption {
+                socket_power: 120,
+                ..Default::default()
+            })
+        });
+        device
+            .expect_device_activity()
+            .returning(move || Err(unavailable()));
+        device
+            .expect_device_pci_usage()
+            .returning(move || Err(unavailable()));
+        device.expect_device_memory_usage().returning(|_| Ok(1024));
+        device
+            .expect_device_process_list_into()
+            .times(1)
+            .returning(|processes| {
+                processes.clear();
+                processes.push(AmdProcess {
+                    pid: 1,
+                    ..Default::default()
+                });
+                Ok(())
+            });
+        device
+            .expect_device_process_list_into()
+            .returning(move |_| Err(unavailable()));
+
+        let mut snapshot = DeviceSnapshot::capture(&device).unwrap();
+        assert_eq!(snapshot.power, Some(120));
+        assert_eq!(snapshot.energy, Some(15300));
+        assert!(snapshot.activity.is_none());
+        assert!(snapshot.pci.is_none());
+        assert_eq!(snapshot.vram_used, Some(1024));
+        assert_eq!(snapshot.processes.as_ref().map(Vec::len), Some(1));
+
+        // the list of processes is not available anymore
+        let before = snapshot.timestamp;
+        snapshot.update(&device).unwrap();
+        assert!(snapshot.timestamp >= before);
+        assert!(snapshot.processes.is_none());
+    }
+}
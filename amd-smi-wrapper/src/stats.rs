@@ -0,0 +1,214 @@
+//! Rolling statistics, to downsample the metrics.
+//!
+//! When the metrics are sampled more often than they are exported, the samples of the last
+//! export period are kept in a [`RollingWindow`], and summarized by [`WindowStats`] at export time.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    dynamic::{MetricKind, MetricValue},
+    metrics::AmdMemoryType,
+    snapshot::DeviceSnapshot,
+};
+
+/// Samples of a metric over a sliding period of time.
+#[derive(Debug, Clone)]
+pub struct RollingWindow {
+    duration: Duration,
+    samples: VecDeque<(Instant, f64)>,
+}
+
+impl RollingWindow {
+    /// Creates an empty window that keeps the samples of the last `duration`.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Returns the duration of the window.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Adds a sample, and removes the samples that are older than the duration of the window.
+    ///
+    /// The samples must be pushed in chronological order.
+    pub fn push(&mut self, timestamp: Instant, value: f64) {
+        self.samples.push_back((timestamp, value));
+        while let Some(&(t, _)) = self.samples.front() {
+            if timestamp.saturating_duration_since(t) > self.duration {
+                self.samples.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Returns the number of samples in the window.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns `true` if the window contains no sample.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Removes all the samples.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Computes the statistics of the samples in the window, or `None` if it is empty.
+    pub fn stats(&self) -> Option<WindowStats> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        let mut values: Vec<f64> = self.samples.iter().map(|(_, v)| *v).collect();
+        values.sort_by(f64::total_cmp);
+        let count = values.len();
+        Some(WindowStats {
+            count,
+            min: values[0],
+            max: values[count - 1],
+            mean: values.iter().sum::<f64>() / count as f64,
+            p95: percentile(&values, 0.95),
+        })
+    }
+}
+
+/// Returns the `q`-quantile of sorted values, with the nearest-rank method.
+fn percentile(sorted: &[f64], q: f64) -> f64 {
+    let rank = (q * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Statistics of the samples of a [`RollingWindow`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowStats {
+    /// Number of samples.
+    pub count: usize,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    /// 95th percentile.
+    pub p95: f64,
+}
+
+/// Rolling windows of several metrics, with the same duration.
+#[derive(Debug, Clone)]
+pub struct MetricAggregator {
+    duration: Duration,
+    windows: HashMap<MetricKind, RollingWindow>,
+}
+
+impl MetricAggregator {
+    /// Creates an aggregator that keeps the samples of the last `duration`.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            windows: HashMap::new(),
+        }
+    }
+
+    /// Adds a sample of a metric.
+    pub fn push(&mut self, timestamp: Instant, kind: MetricKind, value: MetricValue) {
+        self.windows
+            .entry(kind)
+            .or_insert_with(|| RollingWindow::new(self.duration))
+            .push(timestamp, value.as_f64());
+    }
+
+    /// Adds the samples of several metrics, taken at the same time.
+    pub fn push_all(
+        &mut self,
+        timestamp: Instant,
+        values: impl IntoIterator<Item = (MetricKind, MetricValue)>,
+    ) {
+        for (kind, value) in values {
+            self.push(timestamp, kind, value);
+        }
+    }
+
+    /// Adds the gauges of a [`DeviceSnapshot`]: power, activities, PCI traffic and used VRAM.
+    pub fn push_snapshot(&mut self, snapshot: &DeviceSnapshot) {
+        let mut values = Vec::new();
+        if let Some(power) = snapshot.power {
+            values.push((MetricKind::Power, MetricValue::Unsigned(power)));
+        }
+        if let Some(activity) = snapshot.activity {
+            values.extend([
+                (
+                    MetricKind::GfxActivity,
+                    MetricValue::Unsigned(activity.gfx_activity as u64),
+                ),
+                (
+                    MetricKind::MmActivity,
+                    MetricValue::Unsigned(activity.mm_activity as u64),
+                ),
+                (
+                    MetricKind::UmcActivity,
+                    MetricValue::Unsigned(activity.umc_activity as u64),
+                ),
+            ]);
+        }
+        if let Some(pci) = snapshot.pci {
+            values.push((MetricKind::PciSent, MetricValue::Unsigned(pci.sent)));
+            values.push((MetricKind::PciReceived, MetricValue::Unsigned(pci.received)));
+        }
+        if let Some(used) = snapshot.vram_used {
+            let kind = MetricKind::MemoryUsed(AmdMemoryType::AMDSMI_MEM_TYPE_VRAM);
+            values.push((kind, MetricValue::Unsigned(used)));
+        }
+        self.push_all(snapshot.timestamp, values);
+    }
+
+    /// Computes the statistics of a metric, or `None` if it has no sample.
+    pub fn stats(&self, kind: MetricKind) -> Option<WindowStats> {
+        self.windows.get(&kind)?.stats()
+    }
+
+    /// Computes the statistics of all the metrics that have samples.
+    pub fn all_stats(&self) -> HashMap<MetricKind, WindowStats> {
+        self.windows
+            .iter()
+            .filter_map(|(kind, window)| Some((*kind, window.stats()?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::RollingWindow;
+
+    #[test]
+    fn rolling_window() {
+        let t0 = Instant::now();
+        let mut window = RollingWindow::new(Duration::from_secs(10));
+        assert_eq!(window.stats(), None);
+
+        for i in 0..20 {
+            window.push(t0 + Duration::from_secs(i), i as f64);
+        }
+        // only the samples of t=9..=19 are kept
+        let stats = window.stats().unwrap();
+        assert_eq!(stats.count, 11);
+        assert_eq!(stats.min, 9.0);
+        assert_eq!(stats.max, 19.0);
+        assert_eq!(stats.mean, 14.0);
+        assert_eq!(stats.p95, 19.0);
+
+        window.clear();
+        for i in 1..=100 {
+            window.push(t0, i as f64);
+        }
+        assert_eq!(window.stats().unwrap().p95, 95.0);
+    }
+}
@@ -0,0 +1,295 @@
+//! Summary of all the GPUs of a node, meant as the payload of the periodic heartbeats of monitoring agents.
+//!
+//! A [`NodeSummary`] is collected in one call, and serialized to JSON by [`NodeSummary::to_json`].
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{
+    AmdSmi,
+    error::{AmdError, ResultExt},
+    handles::ProcessorHandle,
+    json::JsonWriter,
+    metrics::{
+        AmdDeviceIdentity, AmdMemoryType, AmdProcessorType, AmdTemperatureMetric,
+        AmdTemperatureType, AmdVersion,
+    },
+};
+
+/// Identity and key metrics of a GPU.
+///
+/// The metrics that are not available on the device are `None`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceSummary {
+    /// Identification of the GPU.
+    pub identity: AmdDeviceIdentity,
+    /// Socket power, in W.
+    pub power: Option<u64>,
+    /// Temperature of the hotspot, or of the edge if the hotspot is not available, in °C.
+    pub temperature: Option<i64>,
+    /// Activity of the graphic core, in %.
+    pub gfx_activity: Option<u32>,
+    /// Used VRAM, in bytes.
+    pub vram_used: Option<u64>,
+    /// Total VRAM, in bytes.
+    pub vram_total: Option<u64>,
+    /// Number of processes that use the GPU.
+    pub process_count: Option<usize>,
+}
+
+impl DeviceSummary {
+    /// Reads the identity and the key metrics of a device.
+    pub fn collect<P: ProcessorHandle + ?Sized>(device: &P) -> Result<Self, AmdError> {
+        let mut temperature = None;
+        for sensor in [
+            AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_HOTSPOT,
+            AmdTemperatureType::AMDSMI_TEMPERATURE_TYPE_EDGE,
+        ] {
+            temperature = device
+                .device_temperature(sensor, AmdTemperatureMetric::AMDSMI_TEMP_CURRENT)
+                .optional()?;
+            if temperature.is_some() {
+                break;
+            }
+        }
+        Ok(Self {
+            identity: device.device_identity()?,
+            power: device
+                .device_power_consumption()
+                .optional()?
+                .map(|p| p.socket_power),
+            temperature,
+            gfx_activity: device.device_activity().optional()?.map(|a| a.gfx_activity),
+            vram_used: device
+                .device_memory_usage(AmdMemoryType::AMDSMI_MEM_TYPE_VRAM)
+                .optional()?,
+            vram_total: device
+                .device_memory_total(AmdMemoryType::AMDSMI_MEM_TYPE_VRAM)
+                .optional()?,
+            process_count: device.device_process_list().optional()?.map(|p| p.len()),
+        })
+    }
+}
+
+/// Totals of the GPUs of a node. The unavailable metrics count as zero.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NodeTotals {
+    /// Number of GPUs.
+    pub devices: usize,
+    /// Socket power of all the GPUs, in W.
+    pub power: u64,
+    /// Used VRAM of all the GPUs, in bytes.
+    pub vram_used: u64,
+    /// Total VRAM of all the GPUs, in bytes.
+    pub vram_total: u64,
+    /// Number of processes that use the GPUs, counted once per GPU.
+    pub process_count: usize,
+}
+
+impl NodeTotals {
+    /// Computes the totals of a list of devices.
+    pub fn of(devices: &[DeviceSummary]) -> Self {
+        devices.iter().fold(
+            Self {
+                devices: devices.len(),
+                ..Default::default()
+            },
+            |total, d| Self {
+                power: total.power + d.power.unwrap_or(0),
+                vram_used: total.vram_used + d.vram_used.unwrap_or(0),
+                vram_total: total.vram_total + d.vram_total.unwrap_or(0),
+                process_count: total.process_count + d.process_count.unwrap_or(0),
+                ..total
+            },
+        )
+    }
+}
+
+/// Device whose summary could not be collected, see [`NodeSummary::failures`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceFailure {
+    /// Index of the device in the processors of the library.
+    pub index: usize,
+    /// Description of the error.
+    pub error: String,
+}
+
+/// Summary of all the GPUs of a node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeSummary {
+    /// Name of the host, if it is known.
+    pub hostname: Option<String>,
+    /// Time at which the summary has been collected.
+    pub timestamp: SystemTime,
+    /// Version of the AMD SMI library.
+    pub library_version: AmdVersion,
+    /// Totals of the GPUs.
+    pub totals: NodeTotals,
+    /// Summary of each GPU, in the order of the library.
+    pub devices: Vec<DeviceSummary>,
+    /// Devices whose summary could not be collected. They are not counted in the totals.
+    pub failures: Vec<DeviceFailure>,
+}
+
+impl NodeSummary {
+    /// Collects the summary of all the GPUs of the node. The other processors (CPUs) are skipped.
+    ///
+    /// A device that fails does not fail the summary: its error is recorded in [`failures`](NodeSummary::failures).
+    pub fn collect(amdsmi: &AmdSmi) -> Result<Self, AmdError> {
+        let mut devices = Vec::new();
+        let mut failures = Vec::new();
+        for (index, device) in amdsmi.iter_processors()?.enumerate() {
+            let summary = device.and_then(|device| {
+                let is_gpu =
+                    device.processor_type()? == AmdProcessorType::AMDSMI_PROCESSOR_TYPE_AMD_GPU;
+                is_gpu.then(|| DeviceSummary::collect(&device)).transpose()
+            });
+            match summary {
+                Ok(Some(summary)) => devices.push(summary),
+                Ok(None) => {}
+                Err(e) => failures.push(DeviceFailure {
+                    index,
+                    error: e.to_string(),
+                }),
+            }
+        }
+        Ok(Self {
+            hostname: hostname(),
+            timestamp: SystemTime::now(),
+            library_version: amdsmi.lib_version()?,
+            totals: NodeTotals::of(&devices),
+            devices,
+            failures,
+        })
+    }
+
+    /// Serializes the summary to a JSON object.
+    ///
+    /// The timestamp is written in ms since the Unix epoch, the unavailable metrics are `null`.
+    pub fn to_json(&self) -> String {
+        let mut json = JsonWriter::new();
+        json.begin_object();
+        json.key("hostname")
+            .option(self.hostname.as_deref(), |json, name| {
+                json.string(name);
+            });
+        let timestamp = self
+            .timestamp
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        json.key("timestamp").number(timestamp);
+        json.key("library_version")
+            .string(&self.library_version.to_string());
+
+        let t = &self.totals;
+        json.key("totals").begin_object();
+        json.key("devices").number(t.devices);
+        json.key("power").number(t.power);
+        json.key("vram_used").number(t.vram_used);
+        json.key("vram_total").number(t.vram_total);
+        json.key("process_count").number(t.process_count);
+        json.end_object();
+
+        json.key("devices").begin_array();
+        for d in &self.devices {
+            let id = &d.identity;
+            json.begin_object();
+            json.key("uuid").string(&id.uuid);
+            json.key("pci_address").string(&id.pci_address.to_string());
+            json.key("market_name").string(&id.market_name);
+            json.key("serial")
+                .option(id.board_serial.as_deref(), |json, serial| {
+                    json.string(serial);
+                });
+            for (key, value) in [
+                ("power", d.power.map(|v| v.to_string())),
+                ("temperature", d.temperature.map(|v| v.to_string())),
+                ("gfx_activity", d.gfx_activity.map(|v| v.to_string())),
+                ("vram_used", d.vram_used.map(|v| v.to_string())),
+                ("vram_total", d.vram_total.map(|v| v.to_string())),
+                ("process_count", d.process_count.map(|v| v.to_string())),
+            ] {
+                json.key(key).option(value, |json, v| {
+                    json.number(v);
+                });
+            }
+            json.end_object();
+        }
+        json.end_array();
+
+        json.key("failures").begin_array();
+        for failure in &self.failures {
+            json.begin_object();
+            json.key("index").number(failure.index);
+            json.key("error").string(&failure.error);
+            json.end_object();
+        }
+        json.end_array();
+        json.end_object();
+        json.finish()
+    }
+}
+
+/// Returns the name of the host.
+fn hostname() -> Option<String> {
+    let name = std::fs::read_to_string("/proc/sys/kernel/hostname")
+        .ok()
+        .or_else(|| std::env::var("HOSTNAME").ok())?;
+    let name = name.trim();
+    (!name.is_empty()).then(|| name.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{DeviceFailure, DeviceSummary, NodeSummary, NodeTotals};
+    use crate::metrics::{AmdDeviceIdentity, AmdPciAddress, AmdVersion};
+
+    #[test]
+    fn json() {
+        let device = |power, vram_used| DeviceSummary {
+            identity: AmdDeviceIdentity {
+                uuid: String::from("uuid"),
+                pci_address: AmdPciAddress::from_bdf_id(0xc100),
+                asic_serial: String::from("asic"),
+                board_serial: None,
+                market_name: String::from("MI300X"),
+            },
+            power,
+            temperature: Some(45),
+            gfx_activity: None,
+            vram_used,
+            vram_total: Some(1000),
+            process_count: Some(1),
+        };
+        let devices = vec![device(Some(300), Some(200)), device(None, Some(100))];
+        let summary = NodeSummary {
+            hostname: Some(String::from("node-1")),
+            timestamp: UNIX_EPOCH + Duration::from_secs(2),
+            library_version: AmdVersion {
+                major: 26,
+                minor: 2,
+                release: 0,
+                build: String::from("26.2.0"),
+            },
+            totals: NodeTotals::of(&devices),
+            devices,
+            failures: vec![DeviceFailure {
+                index: 2,
+                error: String::from("the device is suspended"),
+            }],
+        };
+        assert_eq!(summary.totals.power, 300);
+        assert_eq!(summary.totals.vram_used, 300);
+        assert_eq!(summary.totals.vram_total, 2000);
+
+        let json = summary.to_json();
+        assert!(json.starts_with("{\"hostname\":\"node-1\",\"timestamp\":2000,"));
+        assert!(json.contains("\"totals\":{\"devices\":2,\"power\":300,"));
+        assert!(json.contains("\"serial\":null,\"power\":null,\"temperature\":45,"));
+        assert!(
+            json.ends_with("\"failures\":[{\"index\":2,\"error\":\"the device is suspended\"}]}")
+        );
+    }
+}
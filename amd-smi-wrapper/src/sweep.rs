@@ -0,0 +1,351 @@
+//! Frequency sweeps, to characterize the energy efficiency of a workload.
+//!
+//! A [`FrequencySweep`] runs a workload at each frequency level of a clock domain of a GPU,
+//! and samples the power and the energy of the GPU during each run.
+
+use std::{
+    panic,
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    control::{ClockLevelsGuard, PerfLevelGuard, ProcessorControl},
+    error::{AmdError, ResultExt},
+    handles::ProcessorHandle,
+    metrics::{AmdClkType, AmdPerfLevel},
+};
+
+/// Runs a workload at several frequency levels of a clock domain, and measures its energy consumption.
+///
+/// # Example
+/// ```no_run
+/// use amd_smi_wrapper::{AmdSmi, AmdInitFlags, metrics::AmdClkType, sweep::FrequencySweep};
+///
+/// let amdsmi = AmdSmi::init(AmdInitFlags::AMDSMI_INIT_AMD_GPUS).unwrap();
+/// let device = amdsmi.iter_processors().unwrap().next().unwrap().unwrap();
+/// let profile = FrequencySweep::new(AmdClkType::AMDSMI_CLK_TYPE_GFX)
+///     .run(&device, |point| {
+///         println!("running at {} MHz", point.frequency / 1_000_000);
+///         // ... run the workload ...
+///     })
+///     .unwrap();
+/// for point in profile {
+///     println!("{} Hz: {:?} J in {:?}, peak {:?} W", point.frequency, point.energy, point.duration, point.peak_power());
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrequencySweep {
+    clock: AmdClkType,
+    apply: bool,
+    levels: Option<Vec<usize>>,
+    sample_interval: Duration,
+}
+
+impl FrequencySweep {
+    /// Creates a sweep of all the frequency levels of the clock domain `clock`.
+    pub fn new(clock: AmdClkType) -> Self {
+        Self {
+            clock,
+            apply: true,
+            levels: None,
+            sample_interval: Duration::from_millis(100),
+        }
+    }
+
+    /// Chooses whether to apply each frequency level before running the workload (the default).
+    ///
+    /// Applying a level requires root access. If the levels are not applied, the workload runs once per level
+    /// at the frequency chosen by the driver, which is useful to measure the variability of the workload.
+    pub fn apply(mut self, apply: bool) -> Self {
+        self.apply = apply;
+        self
+    }
+
+    /// Only sweeps the given levels (indexes in [`AmdFrequencies::frequencies`](crate::metrics::AmdFrequencies::frequencies)),
+    /// in the given order.
+    pub fn levels(mut self, levels: impl IntoIterator<Item = usize>) -> Self {
+        self.levels = Some(levels.into_iter().collect());
+        self
+    }
+
+    /// Sets the interval between two samples of the power and the energy during the workload (100 ms by default).
+    pub fn sample_interval(mut self, interval: Duration) -> Self {
+        self.sample_interval = interval;
+        self
+    }
+
+    /// Runs the sweep: for each level, applies it (if enabled), calls `workload`, and samples the power and
+    /// the energy of the GPU until the call returns.
+    ///
+    /// `workload` runs on another thread, while the calling thread samples the GPU. It is called with the
+    /// level and the frequency being measured, the measurements of the point are empty.
+    ///
+    /// When the frequency levels are applied, all the levels are allowed again and the performance level of
+    /// the GPU is restored at the end of the sweep, even if `workload` panics (see [`ClockLevelsGuard`]).
+    pub fn run<P, F>(&self, device: &P, mut workload: F) -> Result<Vec<SweepPoint>, AmdError>
+    where
+        P: ProcessorHandle + ProcessorControl,
+        F: FnMut(&SweepPoint) + Send,
+    {
+        let frequencies = device.device_clock_frequencies(self.clock)?.frequencies;
+        let levels = match &self.levels {
+            Some(levels) => levels.clone(),
+            None => (0..frequencies.len()).collect(),
+        };
+        let _perf_level = if self.apply {
+            Some(PerfLevelGuard::set(
+                device,
+                AmdPerfLevel::AMDSMI_DEV_PERF_LEVEL_MANUAL,
+            )?)
+        } else {
+            None
+        };
+        // declared after the performance level, to be restored before it
+        let mut clock_levels: Option<ClockLevelsGuard<P>> = None;
+
+        let mut profile = Vec::with_capacity(levels.len());
+        for level in levels {
+            let Some(&frequency) = frequencies.get(level) else {
+                continue;
+            };
+            if self.apply {
+                match &clock_levels {
+                    Some(guard) => guard.change(1 << level)?,
+                    None => {
+                        clock_levels = Some(ClockLevelsGuard::set(device, self.clock, 1 << level)?)
+                    }
+                }
+            }
+            let mut point = SweepPoint {
+                level,
+                frequency,
+                duration: Duration::ZERO,
+                energy: None,
+                samples: Vec::new(),
+            };
+            self.measure(device, &mut point, &mut workload)?;
+            profile.push(point);
+        }
+        Ok(profile)
+    }
+
+    /// Calls `workload` on another thread, and samples the GPU until it returns.
+    fn measure<P, F>(
+        &self,
+        device: &P,
+        point: &mut SweepPoint,
+        workload: &mut F,
+    ) -> Result<(), AmdError>
+    where
+        P: ProcessorHandle,
+        F: FnMut(&SweepPoint) + Send,
+    {
+        let mut previous = device.device_energy_joules()?;
+        let mut energy = Some(0.0);
+        let mut samples = Vec::new();
+        let start = Instant::now();
+        let duration = thread::scope(|scope| {
+            let (done, finished) = mpsc::channel();
+            let target = &*point;
+            let running = scope.spawn(move || {
+                let start = Instant::now();
+                workload(target);
+                let duration = start.elapsed();
+                let _ = done.send(());
+                duration
+            });
+            loop {
+                // also stops if the workload panics, which drops the sender
+                let last = !matches!(
+                    finished.recv_timeout(self.sample_interval),
+                    Err(RecvTimeoutError::Timeout)
+                );
+                let reading = device.device_energy_joules()?;
+                // the energy of an interval in which the counter has been reset is unknown
+                energy = energy
+                    .zip(reading.joules_since(&previous))
+                    .map(|(a, b)| a + b);
+                previous = reading;
+                if let Some(power) = device.device_power_consumption().optional()? {
+                    samples.push(PowerSample {
+                        elapsed: start.elapsed(),
+                        power: power.socket_power,
+                    });
+                }
+                if last {
+                    break;
+                }
+            }
+            Ok(running.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+        })?;
+        point.duration = duration;
+        point.energy = energy;
+        point.samples = samples;
+        Ok(())
+    }
+}
+
+/// Measurement of a workload at a frequency level, see [`FrequencySweep::run`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SweepPoint {
+    /// Index of the frequency level.
+    pub level: usize,
+    /// Frequency of the level, in Hz.
+    pub frequency: u64,
+    /// Duration of the workload.
+    pub duration: Duration,
+    /// Energy consumed by the GPU during the workload, in J, or `None` if the counter has been reset.
+    ///
+    /// It is the sum of the energies of the sampling intervals, the last one ending when the workload returns.
+    pub energy: Option<f64>,
+    /// Samples of the power of the GPU during the workload.
+    pub samples: Vec<PowerSample>,
+}
+
+/// Power of the GPU at an instant of a workload, see [`SweepPoint::samples`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PowerSample {
+    /// Time elapsed since the start of the workload.
+    pub elapsed: Duration,
+    /// Socket power, in W.
+    pub power: u64,
+}
+
+impl SweepPoint {
+    /// Returns the mean power of the GPU during the workload, in W.
+    pub fn mean_power(&self) -> Option<f64> {
+        let seconds = self.duration.as_secs_f64();
+        (seconds > 0.0).then_some(self.energy? / seconds)
+    }
+
+    /// Returns the highest power sampled during the workload, in W.
+    pub fn peak_power(&self) -> Option<u64> {
+        self.samples.iter().map(|s| s.power).max()
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use std::{
+        panic::{self, AssertUnwindSafe},
+        sync::{
+            Arc,
+            atomic::{AtomicU64, Ordering},
+        },
+        thread,
+        time::Duration,
+    };
+
+    use mockall::{Sequence, predicate::eq};
+
+    use super::FrequencySweep;
+    use crate::{
+        control::MockControlledDevice,
+        metrics::{
+            AmdClkType, AmdEnergyConsumption, AmdFrequencies, AmdPerfLevel, AmdPowerConsumption,
+        },
+    };
+
+    const GFX: AmdClkType = AmdClkType::AMDSMI_CLK_TYPE_GFX;
+    const AUTO: AmdPerfLevel = AmdPerfLevel::AMDSMI_DEV_PERF_LEVEL_AUTO;
+    const MANUAL: AmdPerfLevel = AmdPerfLevel::AMDSMI_DEV_PERF_LEVEL_MANUAL;
+
+    /// Device with two frequency levels, that consumes 1 J between two reads of its energy counter.
+    fn device() -> MockControlledDevice {
+        let mut device = MockControlledDevice::new();
+        device.expect_device_clock_frequencies().returning(|_| {
+            Ok(AmdFrequencies {
+                has_deep_sleep: false,
+                current: 0,
+                frequencies: vec![500_000_000, 1_000_000_000],
+            })
+        });
+        device.expect_device_perf_level().returning(|| Ok(AUTO));
+        let counter = Arc::new(AtomicU64::new(0));
+        device
+            .expect_device_energy_consumption()
+            .returning(move || {
+                Ok(AmdEnergyConsumption {
+                    energy: counter.fetch_add(1_000_000, Ordering::Relaxed),
+                    resolution: 1.0,
+                    timestamp: 0,
+                })
+            });
+        device.expect_device_power_consumption().returning(|| {
+            Ok(AmdPowerConsumption {
+                socket_power: 100,
+                ..Default::default()
+            })
+        });
+        device
+    }
+
+    fn expect_level(device: &mut MockControlledDevice, seq: &mut Sequence, level: AmdPerfLevel) {
+        device
+            .expect_set_perf_level()
+            .with(eq(level))
+            .times(1)
+            .in_sequence(seq)
+            .returning(|_| Ok(()));
+    }
+
+    fn expect_mask(device: &mut MockControlledDevice, seq: &mut Sequence, mask: u64) {
+        device
+            .expect_set_clock_levels()
+            .with(eq(GFX), eq(mask))
+            .times(1)
+            .in_sequence(seq)
+            .returning(|_, _| Ok(()));
+    }
+
+    #[test]
+    fn sweep() {
+        let mut device = device();
+        let mut seq = Sequence::new();
+        expect_level(&mut device, &mut seq, MANUAL);
+        expect_mask(&mut device, &mut seq, 0b01);
+        expect_mask(&mut device, &mut seq, 0b10);
+        // all the levels are allowed again, then the performance level is restored
+        expect_mask(&mut device, &mut seq, 0b11);
+        expect_level(&mut device, &mut seq, AUTO);
+
+        let mut runs = Vec::new();
+        let profile = FrequencySweep::new(GFX)
+            .sample_interval(Duration::from_millis(5))
+            .run(&device, |point| {
+                runs.push(point.frequency);
+                thread::sleep(Duration::from_millis(30));
+            })
+            .unwrap();
+        device.checkpoint();
+
+        assert_eq!(runs, vec![500_000_000, 1_000_000_000]);
+        assert_eq!(profile.len(), 2);
+        for (level, point) in profile.iter().enumerate() {
+            assert_eq!(point.level, level);
+            assert!(point.duration >= Duration::from_millis(30));
+            // sampled during the workload: one energy read per sample
+            assert!(point.samples.len() > 1);
+            assert_eq!(point.energy, Some(point.samples.len() as f64));
+            assert_eq!(point.peak_power(), Some(100));
+        }
+    }
+
+    #[test]
+    fn restore_on_panic() {
+        let mut device = device();
+        let mut seq = Sequence::new();
+        expect_level(&mut device, &mut seq, MANUAL);
+        expect_mask(&mut device, &mut seq, 0b01);
+        expect_mask(&mut device, &mut seq, 0b11);
+        expect_level(&mut device, &mut seq, AUTO);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            FrequencySweep::new(GFX).run(&device, |_| panic!("workload failure"))
+        }));
+        assert!(result.is_err());
+        device.checkpoint();
+    }
+}
@@ -0,0 +1,275 @@
+//! Decoding of the throttle status of the GPUs.
+
+use std::fmt;
+
+/// Reason why a GPU is throttled.
+///
+/// Each reason corresponds to a bit of the independent throttle status (`indep_throttle_status`)
+/// reported in the GPU metrics, as defined by the amdgpu driver.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ThrottleReason {
+    // power
+    Ppt0,
+    Ppt1,
+    Ppt2,
+    Ppt3,
+    Spl,
+    Fppt,
+    Sppt,
+    SpptApu,
+    // current
+    TdcGfx,
+    TdcSoc,
+    TdcMem,
+    TdcVdd,
+    TdcCvip,
+    EdcCpu,
+    EdcGfx,
+    Apcc,
+    // temperature
+    TempGpu,
+    TempCore,
+    TempMem,
+    TempEdge,
+    TempHotspot,
+    TempSoc,
+    TempVrGfx,
+    TempVrSoc,
+    TempVrMem0,
+    TempVrMem1,
+    TempLiquid0,
+    TempLiquid1,
+    VrHot0,
+    VrHot1,
+    ProcHotCpu,
+    ProcHotGfx,
+    // other
+    Ppm,
+    Fit,
+}
+
+impl ThrottleReason {
+    /// All the known reasons, in the order of their bits.
+    pub const ALL: &[ThrottleReason] = &[
+        Self::Ppt0,
+        Self::Ppt1,
+        Self::Ppt2,
+        Self::Ppt3,
+        Self::Spl,
+        Self::Fppt,
+        Self::Sppt,
+        Self::SpptApu,
+        Self::TdcGfx,
+        Self::TdcSoc,
+        Self::TdcMem,
+        Self::TdcVdd,
+        Self::TdcCvip,
+        Self::EdcCpu,
+        Self::EdcGfx,
+        Self::Apcc,
+        Self::TempGpu,
+        Self::TempCore,
+        Self::TempMem,
+        Self::TempEdge,
+        Self::TempHotspot,
+        Self::TempSoc,
+        Self::TempVrGfx,
+        Self::TempVrSoc,
+        Self::TempVrMem0,
+        Self::TempVrMem1,
+        Self::TempLiquid0,
+        Self::TempLiquid1,
+        Self::VrHot0,
+        Self::VrHot1,
+        Self::ProcHotCpu,
+        Self::ProcHotGfx,
+        Self::Ppm,
+        Self::Fit,
+    ];
+
+    /// Returns the index of the bit that corresponds to this reason.
+    pub fn bit(&self) -> u32 {
+        match self {
+            Self::Ppt0 => 0,
+            Self::Ppt1 => 1,
+            Self::Ppt2 => 2,
+            Self::Ppt3 => 3,
+            Self::Spl => 4,
+            Self::Fppt => 5,
+            Self::Sppt => 6,
+            Self::SpptApu => 7,
+            Self::TdcGfx => 16,
+            Self::TdcSoc => 17,
+            Self::TdcMem => 18,
+            Self::TdcVdd => 19,
+            Self::TdcCvip => 20,
+            Self::EdcCpu => 21,
+            Self::EdcGfx => 22,
+            Self::Apcc => 23,
+            Self::TempGpu => 32,
+            Self::TempCore => 33,
+            Self::TempMem => 34,
+            Self::TempEdge => 35,
+            Self::TempHotspot => 36,
+            Self::TempSoc => 37,
+            Self::TempVrGfx => 38,
+            Self::TempVrSoc => 39,
+            Self::TempVrMem0 => 40,
+            Self::TempVrMem1 => 41,
+            Self::TempLiquid0 => 42,
+            Self::TempLiquid1 => 43,
+            Self::VrHot0 => 44,
+            Self::VrHot1 => 45,
+            Self::ProcHotCpu => 46,
+            Self::ProcHotGfx => 47,
+            Self::Ppm => 56,
+            Self::Fit => 57,
+        }
+    }
+
+    /// Returns the name of the reason, as used by the driver (for instance `PPT0` or `TEMP_HOTSPOT`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Ppt0 => "PPT0",
+            Self::Ppt1 => "PPT1",
+            Self::Ppt2 => "PPT2",
+            Self::Ppt3 => "PPT3",
+            Self::Spl => "SPL",
+            Self::Fppt => "FPPT",
+            Self::Sppt => "SPPT",
+            Self::SpptApu => "SPPT_APU",
+            Self::TdcGfx => "TDC_GFX",
+            Self::TdcSoc => "TDC_SOC",
+            Self::TdcMem => "TDC_MEM",
+            Self::TdcVdd => "TDC_VDD",
+            Self::TdcCvip => "TDC_CVIP",
+            Self::EdcCpu => "EDC_CPU",
+            Self::EdcGfx => "EDC_GFX",
+            Self::Apcc => "APCC",
+            Self::TempGpu => "TEMP_GPU",
+            Self::TempCore => "TEMP_CORE",
+            Self::TempMem => "TEMP_MEM",
+            Self::TempEdge => "TEMP_EDGE",
+            Self::TempHotspot => "TEMP_HOTSPOT",
+            Self::TempSoc => "TEMP_SOC",
+            Self::TempVrGfx => "TEMP_VR_GFX",
+            Self::TempVrSoc => "TEMP_VR_SOC",
+            Self::TempVrMem0 => "TEMP_VR_MEM0",
+            Self::TempVrMem1 => "TEMP_VR_MEM1",
+            Self::TempLiquid0 => "TEMP_LIQUID0",
+            Self::TempLiquid1 => "TEMP_LIQUID1",
+            Self::VrHot0 => "VRHOT0",
+            Self::VrHot1 => "VRHOT1",
+            Self::ProcHotCpu => "PROCHOT_CPU",
+            Self::ProcHotGfx => "PROCHOT_GFX",
+            Self::Ppm => "PPM",
+            Self::Fit => "FIT",
+        }
+    }
+
+    /// Returns `true` if the GPU is throttled because of its power consumption (PPT, SPL...).
+    pub fn is_power(&self) -> bool {
+        self.bit() < 16
+    }
+
+    /// Returns `true` if the GPU is throttled because of its current (TDC, EDC...).
+    pub fn is_current(&self) -> bool {
+        (16..32).contains(&self.bit())
+    }
+
+    /// Returns `true` if the GPU is throttled because of a temperature (including VRHOT and PROCHOT).
+    pub fn is_thermal(&self) -> bool {
+        (32..56).contains(&self.bit())
+    }
+}
+
+impl fmt::Display for ThrottleReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Throttle status of a GPU, decoded from the raw bitmask.
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ThrottleStatus(pub u64);
+
+impl ThrottleStatus {
+    /// Returns `true` if the GPU is not throttled.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if the given reason is set.
+    pub fn contains(&self, reason: ThrottleReason) -> bool {
+        self.0 & (1 << reason.bit()) != 0
+    }
+
+    /// Iterates over the known reasons that are set.
+    pub fn iter(&self) -> impl Iterator<Item = ThrottleReason> + '_ {
+        ThrottleReason::ALL
+            .iter()
+            .copied()
+            .filter(|r| self.contains(*r))
+    }
+
+    /// Returns the bits that are set but do not correspond to any known reason.
+    pub fn unknown_bits(&self) -> u64 {
+        let known = ThrottleReason::ALL
+            .iter()
+            .fold(0u64, |acc, r| acc | (1 << r.bit()));
+        self.0 & !known
+    }
+}
+
+impl From<u64> for ThrottleStatus {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl fmt::Display for ThrottleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("none");
+        }
+        let mut first = true;
+        for reason in self.iter() {
+            if !first {
+                f.write_str(" | ")?;
+            }
+            write!(f, "{reason}")?;
+            first = false;
+        }
+        let unknown = self.unknown_bits();
+        if unknown != 0 {
+            if !first {
+                f.write_str(" | ")?;
+            }
+            write!(f, "{unknown:#x}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ThrottleReason, ThrottleStatus};
+
+    #[test]
+    fn decode() {
+        let status = ThrottleStatus::from((1 << 0) | (1 << 36) | (1 << 62));
+        assert!(status.contains(ThrottleReason::Ppt0));
+        assert!(status.contains(ThrottleReason::TempHotspot));
+        assert!(!status.contains(ThrottleReason::Ppt1));
+        assert_eq!(
+            status.iter().collect::<Vec<_>>(),
+            vec![ThrottleReason::Ppt0, ThrottleReason::TempHotspot]
+        );
+        assert_eq!(status.unknown_bits(), 1 << 62);
+        assert_eq!(
+            status.to_string(),
+            "PPT0 | TEMP_HOTSPOT | 0x4000000000000000"
+        );
+        assert_eq!(ThrottleStatus(0).to_string(), "none");
+    }
+}
@@ -0,0 +1,163 @@
+//! Timestamps attached by the driver to the counters, and their conversion to the clocks of the host.
+//!
+//! The driver timestamps the GPU metrics table (see [`AmdGpuMetrics::timestamp`](crate::gpu_metrics::AmdGpuMetrics::timestamp))
+//! and the energy counter (see [`AmdEnergyReading::timestamp`](crate::metrics::AmdEnergyReading::timestamp))
+//! with the boot clock of the kernel (`CLOCK_BOOTTIME`), which is shared by all the devices. Using these
+//! timestamps instead of the time of the query removes the latency of the library from the computed rates,
+//! and aligns the samples of several devices on a common timeline.
+//!
+//! A [`TimeBase`] records the boot clock together with the wall clock and the monotonic clock of Rust,
+//! to translate the timestamps to [`SystemTime`] and [`Instant`].
+//!
+//! # Example
+//! ```no_run
+//! use amd_smi_wrapper::{AmdSmi, AmdInitFlags, handles::ProcessorHandle, timebase::TimeBase};
+//!
+//! let amdsmi = AmdSmi::init(AmdInitFlags::AMDSMI_INIT_AMD_GPUS).unwrap();
+//! let base = TimeBase::now().expect("CLOCK_BOOTTIME should be supported");
+//! for device in amdsmi.iter_processors().unwrap().filter_map(Result::ok) {
+//!     let energy = device.device_energy_joules().unwrap();
+//!     if let Some(timestamp) = energy.timestamp {
+//!         println!("{} J at {:?}", energy.joules, base.to_system_time(timestamp));
+//!     }
+//! }
+//! ```
+
+use std::time::{Duration, Instant, SystemTime};
+
+/// Timestamp attached by the driver to a reading: time since the boot, in ns (`CLOCK_BOOTTIME`).
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DriverTimestamp(u64);
+
+impl DriverTimestamp {
+    /// Creates a timestamp from a number of ns since the boot.
+    pub fn from_nanos(nanos: u64) -> Self {
+        Self(nanos)
+    }
+
+    /// Returns the number of ns since the boot.
+    pub fn as_nanos(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the time elapsed since the boot.
+    pub fn since_boot(&self) -> Duration {
+        Duration::from_nanos(self.0)
+    }
+
+    /// Returns the time elapsed from `earlier` to this timestamp, or `None` if `earlier` is later.
+    pub fn duration_since(&self, earlier: DriverTimestamp) -> Option<Duration> {
+        self.0.checked_sub(earlier.0).map(Duration::from_nanos)
+    }
+
+    /// Returns a timestamp of the boot clock (which includes the time spent in suspend), taken now.
+    ///
+    /// Returns `None` if the boot clock cannot be read (before Linux 2.6.39, or in a restricted sandbox).
+    pub fn now() -> Option<Self> {
+        let mut ts = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        // SAFETY: The pointer is valid for the duration of the call.
+        let result = unsafe { libc::clock_gettime(libc::CLOCK_BOOTTIME, &mut ts) };
+        if result != 0 {
+            return None;
+        }
+        let secs = u64::try_from(ts.tv_sec).ok()?;
+        let nanos = u64::try_from(ts.tv_nsec).ok()?;
+        Some(Self(secs.checked_mul(1_000_000_000)?.checked_add(nanos)?))
+    }
+}
+
+/// Correspondence between the boot clock of the driver and the clocks of Rust, recorded at the same time.
+///
+/// The wall clock can be adjusted (NTP...) after the creation of the base: create a new base regularly
+/// for long-running collections. The monotonic clock does not advance during suspend, unlike the boot clock:
+/// create a new base after a resume.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeBase {
+    boot: DriverTimestamp,
+    system: SystemTime,
+    instant: Instant,
+}
+
+impl TimeBase {
+    /// Records the current time of the clocks.
+    ///
+    /// Returns `None` if the boot clock cannot be read, see [`DriverTimestamp::now`].
+    pub fn now() -> Option<Self> {
+        let instant = Instant::now();
+        let system = SystemTime::now();
+        let boot = DriverTimestamp::now()?;
+        Some(Self {
+            boot,
+            system,
+            instant,
+        })
+    }
+
+    /// Returns the time of the boot clock at the creation of the base.
+    pub fn boot(&self) -> DriverTimestamp {
+        self.boot
+    }
+
+    /// Translates a timestamp of the driver to the wall clock.
+    ///
+    /// Returns `None` if the time cannot be represented, i.e. if the wall clock was set before the Unix epoch.
+    pub fn to_system_time(&self, timestamp: DriverTimestamp) -> Option<SystemTime> {
+        match timestamp.duration_since(self.boot) {
+            Some(after) => self.system.checked_add(after),
+            None => self
+                .system
+                .checked_sub(self.boot.duration_since(timestamp).unwrap_or_default()),
+        }
+    }
+
+    /// Translates a timestamp of the driver to the monotonic clock.
+    ///
+    /// Returns `None` if the instant cannot be represented, i.e. if the timestamp is before the start of the monotonic clock.
+    pub fn to_instant(&self, timestamp: DriverTimestamp) -> Option<Instant> {
+        match timestamp.duration_since(self.boot) {
+            Some(after) => self.instant.checked_add(after),
+            None => self
+                .instant
+                .checked_sub(self.boot.duration_since(timestamp).unwrap_or_default()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant, SystemTime};
+
+    use super::{DriverTimestamp, TimeBase};
+
+    #[test]
+    fn translation() {
+        let base = TimeBase {
+            boot: DriverTimestamp::from_nanos(10_000_000_000),
+            system: SystemTime::UNIX_EPOCH + Duration::from_secs(1000),
+            instant: Instant::now(),
+        };
+        let later = DriverTimestamp::from_nanos(12_500_000_000);
+        let earlier = DriverTimestamp::from_nanos(9_000_000_000);
+        assert_eq!(
+            base.to_system_time(later),
+            Some(SystemTime::UNIX_EPOCH + Duration::from_millis(1_002_500))
+        );
+        assert_eq!(
+            base.to_system_time(earlier),
+            Some(SystemTime::UNIX_EPOCH + Duration::from_secs(999))
+        );
+        assert_eq!(
+            base.to_instant(later).unwrap() - base.instant,
+            Duration::from_millis(2500)
+        );
+        assert_eq!(
+            later.duration_since(earlier),
+            Some(Duration::from_millis(3500))
+        );
+        assert_eq!(earlier.duration_since(later), None);
+        assert!(DriverTimestamp::now().is_some());
+    }
+}
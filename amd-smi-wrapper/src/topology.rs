@@ -0,0 +1,387 @@
+//! Grouping of the processors according to the physical topology of the system.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs,
+};
+
+use crate::{
+    AmdInterface,
+    error::{AmdError, ResultExt},
+    handles::{ProcessorHandle, SocketHandle},
+    metrics::{AmdMemoryType, AmdPciAddress, AmdProcessorType, AmdTemperatureMetric},
+};
+
+/// Processor handle type of an [`AmdInterface`].
+pub type InterfaceProcessorHandle<A> =
+    <<A as AmdInterface>::SocketHandle as SocketHandle>::ProcessorHandle;
+
+/// Topology queries, available on every [`AmdInterface`] (including the mock implementation).
+pub trait AmdTopology: AmdInterface {
+    /// Groups the processors of all the sockets by [`XgmiHive`].
+    ///
+    /// Processors that do not belong to a hive (the query is not supported, or the hive id is 0) are ignored.
+    /// The hives are ordered by id.
+    fn xgmi_hives(&self) -> Result<Vec<XgmiHive<InterfaceProcessorHandle<Self>>>, AmdError>;
+
+    /// Computes the [`AggregateMetrics`] of all the processors of all the sockets.
+    fn system_aggregate(&self) -> Result<AggregateMetrics, AmdError>;
+
+    /// Groups the processors of all the sockets by [`PhysicalGpu`], i.e. groups the partitions of each GPU.
+    ///
+    /// The physical GPUs are ordered by PCI address.
+    fn physical_gpus(&self) -> Result<Vec<PhysicalGpu<InterfaceProcessorHandle<Self>>>, AmdError>;
+
+    /// Lists the processors of all the sockets, like [`processor_handles`](SocketHandle::processor_handles)
+    /// on each socket, but the sockets and the processors that cannot be resolved (see
+    /// [`socket_handles_partial`](AmdInterface::socket_handles_partial) and
+    /// [`processor_handles_partial`](SocketHandle::processor_handles_partial)) are reported in
+    /// [`PartialEnumeration::errors`] instead of failing the whole enumeration.
+    ///
+    /// An error is returned only if the sockets themselves cannot be listed.
+    fn processors_partial(
+        &self,
+    ) -> Result<PartialEnumeration<InterfaceProcessorHandle<Self>>, AmdError>;
+
+    /// Groups the GPUs of all the sockets by the NUMA node of the host that is the closest to them.
+    ///
+    /// The GPUs whose NUMA node is not available are grouped in a node `None`, which comes first.
+    /// The other processors (CPUs) are ignored.
+    fn devices_by_numa_node(
+        &self,
+    ) -> Result<Vec<NumaNodeGroup<InterfaceProcessorHandle<Self>>>, AmdError>;
+}
+
+impl<A: AmdInterface> AmdTopology for A {
+    fn xgmi_hives(&self) -> Result<Vec<XgmiHive<InterfaceProcessorHandle<Self>>>, AmdError> {
+        xgmi_hives(self.socket_handles()?)
+    }
+
+    fn system_aggregate(&self) -> Result<AggregateMetrics, AmdError> {
+        aggregate(&all_processors(self)?)
+    }
+
+    fn physical_gpus(&self) -> Result<Vec<PhysicalGpu<InterfaceProcessorHandle<Self>>>, AmdError> {
+        let mut partitions = Vec::new();
+        for processor in all_processors(self)? {
+            partitions.push((processor.device_pci_address()?, processor));
+        }
+        partitions.sort_by_key(|(address, _)| address.partition);
+
+        let mut gpus: BTreeMap<AmdPciAddress, PhysicalGpu<_>> = BTreeMap::new();
+        for (address, processor) in partitions {
+            let address = address.physical();
+            gpus.entry(address)
+                .or_insert_with(|| PhysicalGpu {
+                    address,
+                    partitions: Vec::new(),
+                })
+                .partitions
+                .push(processor);
+        }
+        Ok(gpus.into_values().collect())
+    }
+
+    fn devices_by_numa_node(
+        &self,
+    ) -> Result<Vec<NumaNodeGroup<InterfaceProcessorHandle<Self>>>, AmdError> {
+        let mut groups: BTreeMap<Option<u32>, Vec<_>> = BTreeMap::new();
+        for processor in all_processors(self)? {
+            if processor.processor_type()? != AmdProcessorType::AMDSMI_PROCESSOR_TYPE_AMD_GPU {
+                continue;
+            }
+            // the driver reports -1 for the systems without NUMA
+            let node = processor
+                .device_numa_node()
+                .optional()?
+                .filter(|node| *node != u32::MAX);
+            groups.entry(node).or_default().push(processor);
+        }
+        Ok(groups
+            .into_iter()
+            .map(|(node, processors)| NumaNodeGroup { node, processors })
+            .collect())
+    }
+
+    fn processors_partial(
+        &self,
+    ) -> Result<PartialEnumeration<InterfaceProcessorHandle<Self>>, AmdError> {
+        let mut res = PartialEnumeration {
+            processors: Vec::new(),
+            errors: Vec::new(),
+        };
+        for (socket_index, socket) in self.socket_handles_partial()?.into_iter().enumerate() {
+            let processors = match socket.and_then(|s| s.processor_handles_partial()) {
+                Ok(processors) => processors,
+                Err(error) => {
+                    res.errors.push(EnumerationError {
+                        socket_index,
+                        processor_index: None,
+                        error,
+                    });
+                    continue;
+                }
+            };
+            for (processor_index, processor) in processors.into_iter().enumerate() {
+                match processor {
+                    Ok(processor) => res.processors.push(processor),
+                    Err(error) => res.errors.push(EnumerationError {
+                        socket_index,
+                        processor_index: Some(processor_index),
+                        error,
+                    }),
+                }
+            }
+        }
+        Ok(res)
+    }
+}
+
+/// Result of an enumeration that continues when some sockets or processors fail,
+/// see [`AmdTopology::processors_partial`].
+#[derive(Debug)]
+pub struct PartialEnumeration<P> {
+    /// Processors that have been enumerated.
+    pub processors: Vec<P>,
+    /// Errors of the sockets and of the processors that could not be enumerated.
+    pub errors: Vec<EnumerationError>,
+}
+
+impl<P> PartialEnumeration<P> {
+    /// Returns `true` if all the sockets and processors have been enumerated.
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Failure to resolve a socket, to list its processors, or to resolve one of them.
+#[derive(Debug)]
+pub struct EnumerationError {
+    /// Index of the socket, in the order of [`AmdInterface::socket_handles`].
+    pub socket_index: usize,
+    /// Index of the processor in the socket, in the order of [`SocketHandle::processor_handles`],
+    /// or `None` if the whole socket failed.
+    pub processor_index: Option<usize>,
+    pub error: AmdError,
+}
+
+fn all_processors<A: AmdInterface + ?Sized>(
+    amdsmi: &A,
+) -> Result<Vec<InterfaceProcessorHandle<A>>, AmdError> {
+    let mut processors = Vec::new();
+    for socket in amdsmi.socket_handles()? {
+        processors.extend(socket.processor_handles()?);
+    }
+    Ok(processors)
+}
+
+/// Physical GPU, which contains one or several partitions.
+///
+/// When the GPU is not partitioned, there is only one partition. Otherwise, each partition
+/// has its own processor handle, but some metrics are shared (see [`MetricKind::scope`](crate::dynamic::MetricKind::scope)).
+#[derive(Debug)]
+pub struct PhysicalGpu<P> {
+    /// PCI address of the GPU, without the partition identifier.
+    pub address: AmdPciAddress,
+    /// Processors of the partitions, ordered by partition identifier.
+    pub partitions: Vec<P>,
+}
+
+impl<P> PhysicalGpu<P> {
+    /// Returns `true` if the GPU is divided in several partitions.
+    pub fn is_partitioned(&self) -> bool {
+        self.partitions.len() > 1
+    }
+}
+
+/// GPUs that are the closest to a NUMA node of the host.
+#[derive(Debug)]
+pub struct NumaNodeGroup<P> {
+    /// Index of the NUMA node, or `None` if it is not available.
+    pub node: Option<u32>,
+    /// GPUs of the node, in the order of the enumeration.
+    pub processors: Vec<P>,
+}
+
+impl<P> NumaNodeGroup<P> {
+    /// Returns the CPUs of the NUMA node, read from sysfs, or `None` if they are not available.
+    pub fn cpus(&self) -> Option<Vec<u32>> {
+        let node = self.node?;
+        let list =
+            fs::read_to_string(format!("/sys/devices/system/node/node{node}/cpulist")).ok()?;
+        parse_cpu_list(&list)
+    }
+}
+
+/// Parses a list of CPUs in the format of the kernel, for instance `0-3,8,10-11`.
+fn parse_cpu_list(list: &str) -> Option<Vec<u32>> {
+    let mut cpus = Vec::new();
+    for range in list.trim().split(',').filter(|r| !r.is_empty()) {
+        match range.split_once('-') {
+            Some((first, last)) => cpus.extend(first.parse::<u32>().ok()?..=last.parse().ok()?),
+            None => cpus.push(range.parse().ok()?),
+        }
+    }
+    Some(cpus)
+}
+
+/// Group of GPUs that are connected together by XGMI links.
+#[derive(Debug)]
+pub struct XgmiHive<P> {
+    /// Identifier of the hive.
+    pub hive_id: u64,
+    /// Processors of the hive, ordered by their index in the hive.
+    pub processors: Vec<P>,
+}
+
+impl<P: ProcessorHandle> XgmiHive<P> {
+    /// Computes the [`AggregateMetrics`] of the processors of the hive.
+    pub fn aggregate(&self) -> Result<AggregateMetrics, AmdError> {
+        aggregate(&self.processors)
+    }
+}
+
+/// Metrics of a group of processors (a hive, or the whole system), rolled up.
+///
+/// Each metric only includes the processors that support it.
+/// The package-level metrics (power, energy) are counted once per physical GPU, even if it is partitioned.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AggregateMetrics {
+    /// Number of processors in the group.
+    pub processors: usize,
+    /// Total socket power in W.
+    pub power: u64,
+    /// Sum of the energy counters in µJ.
+    pub energy: u64,
+    /// Maximal current temperature in °C, among all the sensors of all the processors.
+    pub max_temperature: Option<i64>,
+    /// Total used VRAM in bytes.
+    pub vram_used: u64,
+    /// Total VRAM in bytes.
+    pub vram_total: u64,
+}
+
+impl AggregateMetrics {
+    /// Returns the energy consumed by the group since a previous aggregate, in µJ.
+    ///
+    /// The group must contain the same processors in both aggregates.
+    pub fn energy_delta(&self, previous: &AggregateMetrics) -> u64 {
+        self.energy.saturating_sub(previous.energy)
+    }
+}
+
+/// Computes the [`AggregateMetrics`] of the given processors.
+pub fn aggregate<P: ProcessorHandle>(processors: &[P]) -> Result<AggregateMetrics, AmdError> {
+    let mut res = AggregateMetrics {
+        processors: processors.len(),
+        ..Default::default()
+    };
+    let mut packages = HashSet::new();
+    for processor in processors {
+        // count the package-level metrics only once per physical GPU
+        let first_partition = match processor.device_pci_address().optional()? {
+            Some(address) => packages.insert(address.physical()),
+            None => true,
+        };
+        if first_partition {
+            if let Some(power) = processor.device_power_consumption().optional()? {
+                res.power += power.socket_power;
+            }
+            if let Some(energy) = processor.device_energy_consumption().optional()? {
+                res.energy += energy.microjoules() as u64;
+            }
+        }
+        let temperatures =
+            processor.device_temperatures(AmdTemperatureMetric::AMDSMI_TEMP_CURRENT)?;
+        if let Some(max) = temperatures.values().max() {
+            res.max_temperature = Some(res.max_temperature.map_or(*max, |m| m.max(*max)));
+        }
+        let vram = AmdMemoryType::AMDSMI_MEM_TYPE_VRAM;
+        if let Some(used) = processor.device_memory_usage(vram).optional()? {
+            res.vram_used += used;
+        }
+        if let Some(total) = processor.device_memory_total(vram).optional()? {
+            res.vram_total += total;
+        }
+    }
+    Ok(res)
+}
+
+/// Groups the processors of the given sockets by XGMI hive.
+fn xgmi_hives<S: SocketHandle>(
+    sockets: Vec<S>,
+) -> Result<Vec<XgmiHive<S::ProcessorHandle>>, AmdError> {
+    let mut hives: BTreeMap<u64, Vec<(u32, S::ProcessorHandle)>> = BTreeMap::new();
+    for socket in sockets {
+        for processor in socket.processor_handles()? {
+            let Some(info) = processor.device_xgmi_info().optional()? else {
+                continue;
+            };
+            if info.xgmi_hive_id == 0 {
+                continue;
+            }
+            hives
+                .entry(info.xgmi_hive_id)
+                .or_default()
+                .push((info.index, processor));
+        }
+    }
+    Ok(hives
+        .into_iter()
+        .map(|(hive_id, mut processors)| {
+            processors.sort_by_key(|(index, _)| *index);
+            XgmiHive {
+                hive_id,
+                processors: processors.into_iter().map(|(_, p)| p).collect(),
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_cpu_list;
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn partial_enumeration() {
+        use super::AmdTopology;
+        use crate::{
+            MockAmdInterface,
+            error::{AmdError, AmdStatus},
+            handles::{MockProcessorHandle, MockSocketHandle},
+        };
+
+        let failure = || AmdError::Status {
+            status: AmdStatus::AMDSMI_STATUS_DRM_ERROR,
+            message: None,
+        };
+        let mut amd = MockAmdInterface::new();
+        amd.expect_socket_handles_partial().returning(move || {
+            let mut socket = MockSocketHandle::new();
+            socket
+                .expect_processor_handles_partial()
+                .returning(move || Ok(vec![Ok(MockProcessorHandle::new()), Err(failure())]));
+            Ok(vec![Ok(socket), Err(failure())])
+        });
+
+        let res = amd.processors_partial().unwrap();
+        assert_eq!(res.processors.len(), 1);
+        let errors: Vec<_> = res
+            .errors
+            .iter()
+            .map(|e| (e.socket_index, e.processor_index))
+            .collect();
+        assert_eq!(errors, vec![(0, Some(1)), (1, None)]);
+    }
+
+    #[test]
+    fn cpu_list() {
+        assert_eq!(
+            parse_cpu_list("0-3,8,10-11\n"),
+            Some(vec![0, 1, 2, 3, 8, 10, 11])
+        );
+        assert_eq!(parse_cpu_list(""), Some(vec![]));
+        assert_eq!(parse_cpu_list("0-a"), None);
+    }
+}
@@ -0,0 +1,156 @@
+//! Normalization of the units reported by the library.
+//!
+//! The library reports each metric in the unit of the driver: µJ for the energy, mV for the voltages,
+//! MHz for the clocks, µW for the power caps... With [`UnitSystem::Si`], selected at construction with
+//! [`AmdSmiBuilder::unit_system`](crate::builder::AmdSmiBuilder::unit_system), the dynamic reads
+//! ([`ProcessorHandle::device_read_metric`](crate::handles::ProcessorHandle::device_read_metric))
+//! return floats in SI units (J, W, °C, V, Hz), and
+//! [`ProcessorHandle::device_metric_unit`](crate::handles::ProcessorHandle::device_metric_unit)
+//! gives the unit of the values.
+//!
+//! The unit system also applies to the typed getters that return a [`Quantity`]: the energy, power, power cap,
+//! temperatures, voltages and clocks of the GPUs, for instance
+//! [`ProcessorHandle::device_power_quantity`](crate::handles::ProcessorHandle::device_power_quantity),
+//! and the temperature and energy of the CPU sockets.
+//!
+//! # Scope
+//!
+//! The unit system applies **only** to these two interfaces. All the other getters, which return integers or the
+//! structures of the library ([`AmdPowerCapInfo`](crate::metrics::AmdPowerCapInfo), the metrics table,
+//! the functions of [`CpuProcessorHandle`](crate::cpu::CpuProcessorHandle)...), keep the units of the library,
+//! whatever the unit system; [`to_si`] converts their values.
+
+use std::fmt;
+
+use crate::{
+    dynamic::{MetricUnit, MetricValue},
+    loader::LibraryVariant,
+};
+
+/// Units of the values returned by the dynamic reads and by the getters of [`Quantity`].
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum UnitSystem {
+    /// Units of the library, see [`MetricKind`](crate::dynamic::MetricKind). The values are not converted.
+    #[default]
+    Native,
+    /// SI base units: J, W, °C, V and Hz. The values are converted to floats.
+    Si,
+}
+
+impl UnitSystem {
+    /// Returns the unit of the values of a metric whose native unit is `unit`.
+    pub fn unit(&self, unit: MetricUnit) -> MetricUnit {
+        match self {
+            UnitSystem::Native => unit,
+            UnitSystem::Si => unit.si().0,
+        }
+    }
+
+    /// Converts a value in the native unit `unit` to this unit system.
+    pub fn convert(&self, value: MetricValue, unit: MetricUnit) -> MetricValue {
+        match self {
+            UnitSystem::Native => value,
+            UnitSystem::Si => MetricValue::Float(to_si(value.as_f64(), unit)),
+        }
+    }
+
+    /// Converts a value in the native unit `unit` to a [`Quantity`] in this unit system.
+    pub fn quantity(&self, value: f64, unit: MetricUnit) -> Quantity {
+        match self {
+            UnitSystem::Native => Quantity { value, unit },
+            UnitSystem::Si => {
+                let (unit, factor) = unit.si();
+                Quantity {
+                    value: value * factor,
+                    unit,
+                }
+            }
+        }
+    }
+}
+
+/// Value of a metric with its unit, which depends on the [`UnitSystem`] of the library.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantity {
+    /// Value, in `unit`.
+    pub value: f64,
+    /// Unit of the value: the native unit of the metric, or the corresponding SI unit.
+    pub unit: MetricUnit,
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.value, self.unit.symbol())
+    }
+}
+
+impl MetricUnit {
+    /// Returns the SI unit that corresponds to this unit, and the factor that converts a value to it.
+    pub fn si(&self) -> (MetricUnit, f64) {
+        match self {
+            MetricUnit::MicroWatt => (MetricUnit::Watt, 1e-6),
+            MetricUnit::MicroJoule => (MetricUnit::Joule, 1e-6),
+            MetricUnit::MilliCelsius => (MetricUnit::Celsius, 1e-3),
+            MetricUnit::MilliVolt => (MetricUnit::Volt, 1e-3),
+            MetricUnit::MegaHertz => (MetricUnit::Hertz, 1e6),
+            unit => (*unit, 1.0),
+        }
+    }
+}
+
+/// Converts a value in `unit` to the corresponding SI unit, for instance µJ to J.
+pub fn to_si(value: f64, unit: MetricUnit) -> f64 {
+    value * unit.si().1
+}
+
+/// Returns the unit of the power caps of [`AmdPowerCapInfo`](crate::metrics::AmdPowerCapInfo):
+/// µW for the ROCm library, W for the library of the host driver.
+pub fn power_cap_unit(variant: LibraryVariant) -> MetricUnit {
+    match variant {
+        LibraryVariant::Rocm => MetricUnit::MicroWatt,
+        LibraryVariant::Host => MetricUnit::Watt,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Quantity, UnitSystem, to_si};
+    use crate::dynamic::{MetricUnit, MetricValue};
+
+    #[test]
+    fn conversions() {
+        assert_eq!(to_si(2_500_000.0, MetricUnit::MicroJoule), 2.5);
+        assert_eq!(to_si(850.0, MetricUnit::MilliVolt), 0.85);
+        assert_eq!(to_si(2100.0, MetricUnit::MegaHertz), 2.1e9);
+        assert_eq!(to_si(42.0, MetricUnit::Percent), 42.0);
+        assert_eq!(to_si(45_500.0, MetricUnit::MilliCelsius), 45.5);
+        assert_eq!(to_si(300_000_000.0, MetricUnit::MicroWatt), 300.0);
+
+        let value = MetricValue::Signed(750);
+        assert_eq!(
+            UnitSystem::Native.convert(value, MetricUnit::MilliVolt),
+            value
+        );
+        assert_eq!(
+            UnitSystem::Si.convert(value, MetricUnit::MilliVolt),
+            MetricValue::Float(0.75)
+        );
+        assert_eq!(UnitSystem::Si.unit(MetricUnit::MilliVolt), MetricUnit::Volt);
+
+        let energy = UnitSystem::Si.quantity(2_500_000.0, MetricUnit::MicroJoule);
+        assert_eq!(
+            energy,
+            Quantity {
+                value: 2.5,
+                unit: MetricUnit::Joule
+            }
+        );
+        assert_eq!(energy.to_string(), "2.5 J");
+        assert_eq!(
+            UnitSystem::Native
+                .quantity(850.0, MetricUnit::MilliVolt)
+                .to_string(),
+            "850 mV"
+        );
+    }
+}
@@ -1,22 +1,112 @@
+//! Helpers to read the data written by the AMD SMI library.
+//!
+//! They are used by the wrappers of this crate, and can be used to wrap other functions of the library
+//! through the low-level bindings.
+
 use std::ffi::c_char;
 
-/// Converts a C string to an owned Rust String, with a length limit (the size of `buffer`).
-pub fn c_buffer_to_string(buffer: &[c_char]) -> String {
+use crate::error::AmdError;
+
+/// Buffer of `N` characters for the library functions that write a string into a caller-provided buffer.
+///
+/// The string ends at the first null byte: anything after an embedded null is ignored.
+/// If the library does not null-terminate the string, it ends at the length reported by the library
+/// (see [`set_reported_len`](Self::set_reported_len)), or at the end of the buffer.
+pub struct StringBuffer<const N: usize> {
+    buffer: [c_char; N],
+    reported_len: usize,
+}
+
+impl<const N: usize> StringBuffer<N> {
+    /// Creates a buffer filled with null bytes.
+    pub fn new() -> Self {
+        Self {
+            buffer: [0; N],
+            reported_len: N,
+        }
+    }
+
+    /// Returns a pointer to the buffer, to pass to the library.
+    pub fn as_mut_ptr(&mut self) -> *mut c_char {
+        self.buffer.as_mut_ptr()
+    }
+
+    /// Returns the size of the buffer, to pass to the library.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Sets the length of the string, as reported by the library (when the function provides it).
+    pub fn set_reported_len(&mut self, len: usize) {
+        self.reported_len = len;
+    }
+
+    /// Returns `true` if the string does not fit in the buffer, i.e. the library wrote no null terminator
+    /// or reported a length that is larger than the buffer.
+    pub fn is_truncated(&self) -> bool {
+        self.reported_len > N || !self.buffer.contains(&0)
+    }
+
+    /// Returns the bytes of the string, without the null terminator.
+    pub fn as_bytes(&self) -> &[u8] {
+        let limit = self.reported_len.min(N);
+        c_buffer_to_bytes(&self.buffer[..limit])
+    }
+
+    /// Converts the string to UTF-8, or returns [`AmdError::InvalidString`].
+    pub fn to_str(&self) -> Result<&str, AmdError> {
+        std::str::from_utf8(self.as_bytes()).map_err(AmdError::InvalidString)
+    }
+
+    /// Converts the string to an owned `String`, or returns [`AmdError::InvalidString`].
+    pub fn to_string(&self) -> Result<String, AmdError> {
+        self.to_str().map(str::to_owned)
+    }
+
+    /// Converts the string to an owned `String`, replacing invalid UTF-8 sequences.
+    pub fn to_string_lossy(&self) -> String {
+        String::from_utf8_lossy(self.as_bytes()).into_owned()
+    }
+}
+
+impl<const N: usize> Default for StringBuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the bytes of a C string, without the null terminator, with a length limit (the size of `buffer`).
+pub fn c_buffer_to_bytes(buffer: &[c_char]) -> &[u8] {
     // cap the length to the size of the buffer
     let length = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
     let chars = &buffer[..length];
     // convert to bytes
     // SAFETY: `c_char` is either `i8` or `u8`, and a slice of that can be converted safely to a slice of `u8`s
-    let bytes = unsafe { &*(chars as *const [c_char] as *const [u8]) };
+    unsafe { &*(chars as *const [c_char] as *const [u8]) }
+}
+
+/// Converts a C string to an owned Rust String, with a length limit (the size of `buffer`).
+pub fn c_buffer_to_string(buffer: &[c_char]) -> String {
     // convert to utf-8
-    String::from_utf8_lossy(bytes).into_owned()
+    String::from_utf8_lossy(c_buffer_to_bytes(buffer)).into_owned()
+}
+
+/// Converts a C string to UTF-8, with a length limit (the size of `buffer`), or returns [`AmdError::InvalidString`].
+pub fn c_buffer_to_str(buffer: &[c_char]) -> Result<&str, AmdError> {
+    std::str::from_utf8(c_buffer_to_bytes(buffer)).map_err(AmdError::InvalidString)
+}
+
+/// Replaces the content of `out` by a C string, reusing the allocation of `out`.
+pub fn c_buffer_write_string(buffer: &[c_char], out: &mut String) {
+    out.clear();
+    out.push_str(&String::from_utf8_lossy(c_buffer_to_bytes(buffer)));
 }
 
 #[cfg(test)]
 mod tests {
     use std::ffi::c_char;
 
-    use super::c_buffer_to_string;
+    use super::{StringBuffer, c_buffer_to_bytes, c_buffer_to_str, c_buffer_to_string};
 
     fn c_array(bytes: &[u8]) -> &[c_char] {
         unsafe { &*(bytes as *const [u8] as *const [c_char]) }
@@ -30,4 +120,54 @@ mod tests {
         assert_eq!(c_buffer_to_string(c_array(invalid)), "Hello �World"); // invalid utf-8 chars
         assert_eq!(c_buffer_to_string(c_array(b"\0\0\0\0")), ""); // multiple nulls
     }
+
+    #[test]
+    fn checked_c_buffer_to_str() {
+        assert_eq!(c_buffer_to_str(c_array(b"abc\0def")).unwrap(), "abc");
+        assert!(c_buffer_to_str(c_array(b"Hello \xF0\x90\x80World\0")).is_err());
+    }
+
+    #[test]
+    fn raw_c_buffer_to_bytes() {
+        assert_eq!(c_buffer_to_bytes(c_array(b"abc\0def")), b"abc");
+        assert_eq!(c_buffer_to_bytes(c_array(b"\xF0\x90\0")), b"\xF0\x90"); // kept as-is
+    }
+
+    fn filled<const N: usize>(bytes: &[u8]) -> StringBuffer<N> {
+        let mut buffer = StringBuffer::<N>::new();
+        for (i, b) in bytes.iter().enumerate() {
+            // SAFETY: the test data is never longer than the buffer
+            unsafe { *buffer.as_mut_ptr().add(i) = *b as c_char };
+        }
+        buffer
+    }
+
+    #[test]
+    fn string_buffer() {
+        let buffer = filled::<8>(b"abc");
+        assert_eq!(buffer.to_str().unwrap(), "abc");
+        assert!(!buffer.is_truncated());
+
+        // embedded null
+        let buffer = filled::<8>(b"ab\0cd");
+        assert_eq!(buffer.as_bytes(), b"ab");
+
+        // not null-terminated
+        let buffer = filled::<4>(b"abcd");
+        assert_eq!(buffer.to_str().unwrap(), "abcd");
+        assert!(buffer.is_truncated());
+
+        // length reported by the library
+        let mut buffer = filled::<8>(b"abcdefgh");
+        buffer.set_reported_len(5);
+        assert_eq!(buffer.as_bytes(), b"abcde");
+        buffer.set_reported_len(12);
+        assert_eq!(buffer.as_bytes(), b"abcdefgh");
+        assert!(buffer.is_truncated());
+
+        // invalid utf-8
+        let buffer = filled::<8>(b"a\xF0\x90b");
+        assert!(buffer.to_str().is_err());
+        assert_eq!(buffer.to_string_lossy(), "a\u{FFFD}b");
+    }
 }
@@ -1,62 +1,78 @@
 use std::path::{Path, PathBuf};
 
-use bindgen::{Builder, EnumVariation, callbacks::ParseCallbacks};
 use clap::Parser;
 
-const LIB: &str = "libamd_smi";
+// same configuration as the build script of the sys crate
+#[path = "../../amd-smi-wrapper-sys/generate.rs"]
+mod generate;
+
+use generate::HEADER_VERSION_VAR;
+
+/// Directory of the sys crate, which contains the vendored headers and the committed bindings.
+const SYS_CRATE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../amd-smi-wrapper-sys");
 
 fn main() {
     let args = Args::parse();
+    let header = args.header();
+    let whitelist = args
+        .whitelist
+        .clone()
+        .unwrap_or_else(|| generate::whitelist(&args.input_dir()));
 
-    let mut builder = Builder::default()
-        .header(args.input_header.to_str().unwrap())
-        .parse_callbacks(Box::new(DocFix))
-        .dynamic_library_name(LIB)
-        .default_enum_style(EnumVariation::NewType {
-            is_bitfield: false,
-            is_global: false,
-        });
-    builder = parse_whitelist(&args.whitelist, builder);
-    builder
+    generate::builder(&header, &whitelist)
         .generate()
         .expect("failed to generate the bindings")
         .write_to_file(args.output)
         .expect("failed to write the bindings");
 }
 
-#[derive(Debug)]
-struct DocFix;
+#[derive(Parser)]
+struct Args {
+    /// Header to generate the bindings from.
+    #[arg(short, long, conflicts_with = "header_version")]
+    input_header: Option<PathBuf>,
 
-impl ParseCallbacks for DocFix {
-    fn process_comment(&self, comment: &str) -> Option<String> {
-        // Transform C/C++ documentation to avoid Rust doc-test errors
-        Some(format!("```text\n{comment}\n```"))
-    }
-}
+    /// Version of ROCm whose vendored header (`<input-dir>/amdsmi-rocm-<version>.h`) is used,
+    /// read from `AMD_SMI_HEADER_VERSION` if not given.
+    #[arg(long)]
+    header_version: Option<String>,
 
-fn parse_whitelist(path: &Path, mut builder: bindgen::Builder) -> bindgen::Builder {
-    let content = std::fs::read_to_string(path).expect("failed to read whitelist");
-    for line in content.lines() {
-        let line = line.trim_ascii();
-        if !line.is_empty() && !line.starts_with("#") {
-            builder = builder.allowlist_item(line);
-        }
-    }
-    builder
-}
+    /// Directory of the vendored headers, `amd-smi-wrapper-sys/headers` by default.
+    #[arg(long)]
+    input_dir: Option<PathBuf>,
 
-#[derive(Parser)]
-struct Args {
+    /// Whitelist of the symbols to generate, `<input-dir>/whitelist.txt` by default.
     #[arg(short, long)]
-    input_header: PathBuf,
-
-    #[arg(short, long, default_value = "bindings-generator/input/whitelist.txt")]
-    whitelist: PathBuf,
+    whitelist: Option<PathBuf>,
 
     #[arg(
         short,
         long,
-        default_value = "amd-smi-wrapper-sys/src/versions/latest.rs"
+        default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/../amd-smi-wrapper-sys/src/versions/latest.rs")
     )]
     output: PathBuf,
 }
+
+impl Args {
+    /// Returns the directory of the vendored headers.
+    fn input_dir(&self) -> PathBuf {
+        self.input_dir
+            .clone()
+            .unwrap_or_else(|| generate::headers_dir(Path::new(SYS_CRATE_DIR)))
+    }
+
+    /// Returns the header to use: the given one, or the vendored header of the selected version.
+    fn header(&self) -> PathBuf {
+        if let Some(header) = &self.input_header {
+            return header.clone();
+        }
+        let version = self
+            .header_version
+            .clone()
+            .or_else(|| std::env::var(HEADER_VERSION_VAR).ok())
+            .unwrap_or_else(|| {
+                panic!("no header: use --input-header, --header-version or {HEADER_VERSION_VAR}")
+            });
+        generate::vendored_header(&self.input_dir(), &version).unwrap_or_else(|e| panic!("{e}"))
+    }
+}